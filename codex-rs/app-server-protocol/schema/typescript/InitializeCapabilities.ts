@@ -18,6 +18,12 @@ requestAttestation: boolean,
  * Allow downstream MCP servers to request OpenAI extended form elicitations.
  */
 mcpServerOpenaiFormElicitation?: boolean,
+/**
+ * Opt into `thread/turnCompleted` end-of-turn digest notifications. Off
+ * by default so clients that do not render them are not sent the extra
+ * per-turn traffic.
+ */
+threadTurnCompletedNotifications?: boolean,
 /**
  * Exact notification method names that should be suppressed for this
  * connection (for example `thread/started`).
@@ -0,0 +1,9 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Built-in plugin integrations that ship with the server, as opposed to
+ * marketplace plugins managed through `plugin/list`.
+ */
+export type BuiltinPluginId = "statusline" | "translation";
@@ -0,0 +1,23 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { JsonValue } from "../serde_json/JsonValue";
+import type { BuiltinPluginId } from "./BuiltinPluginId";
+import type { TranslationTestResponse } from "./TranslationTestResponse";
+
+/**
+ * Configuration status of one built-in plugin, so clients can hide UI
+ * affordances for plugins the user never set up. The `settings` value is a
+ * sanitized summary: secrets such as API keys are reported as presence flags,
+ * never as values.
+ */
+export type BuiltinPluginInfo = { id: BuiltinPluginId,
+/**
+ * Whether a configuration file for the plugin exists under CODEX_HOME.
+ */
+configured: boolean, settings: JsonValue,
+/**
+ * Result of the most recent `translation/test` run in this session;
+ * `None` for plugins without a health check or before the first run.
+ */
+lastHealthCheck?: TranslationTestResponse | null, };
@@ -8,7 +8,30 @@ import type { ModelServiceTier } from "./ModelServiceTier";
 import type { ModelUpgradeInfo } from "./ModelUpgradeInfo";
 import type { ReasoningEffortOption } from "./ReasoningEffortOption";
 
-export type Model = { id: string, model: string, upgrade: string | null, upgradeInfo: ModelUpgradeInfo | null, availabilityNux: ModelAvailabilityNux | null, displayName: string, description: string, hidden: boolean, supportedReasoningEfforts: Array<ReasoningEffortOption>, defaultReasoningEffort: ReasoningEffort, inputModalities: Array<InputModality>, supportsPersonality: boolean,
+export type Model = { id: string, model: string, upgrade: string | null, upgradeInfo: ModelUpgradeInfo | null,
+/**
+ * Whether the catalog marks this model as deprecated.
+ */
+deprecated: boolean,
+/**
+ * Catalog-provided message shown when the model is deprecated.
+ */
+deprecationMessage?: string | null,
+/**
+ * Ordered upgrade chain ending at the recommended current model; empty
+ * when the model is already current.
+ */
+upgradeChain: Array<string>, availabilityNux: ModelAvailabilityNux | null, displayName: string, description: string, hidden: boolean,
+/**
+ * Reasoning efforts the model accepts; omitted for models without
+ * reasoning support.
+ */
+supportedReasoningEfforts?: Array<ReasoningEffortOption>,
+/**
+ * Effort applied when the client does not pick one; omitted for models
+ * without reasoning support.
+ */
+defaultReasoningEffort?: ReasoningEffort | null, inputModalities: Array<InputModality>, supportsPersonality: boolean,
 /**
  * Deprecated: use `serviceTiers` instead.
  */
@@ -16,4 +39,20 @@ additionalSpeedTiers: Array<string>, serviceTiers: Array<ModelServiceTier>,
 /**
  * Catalog default service tier id for this model, when one is configured.
  */
-defaultServiceTier: string | null, isDefault: boolean, };
+defaultServiceTier: string | null,
+/**
+ * Context window in tokens, when the catalog provides one.
+ */
+contextWindow?: bigint | null,
+/**
+ * Maximum number of output tokens per response, when known.
+ */
+maxOutputTokens?: bigint | null,
+/**
+ * Whether the model can invoke multiple tools in parallel.
+ */
+supportsParallelToolCalls: boolean,
+/**
+ * Whether the model emits reasoning summaries.
+ */
+supportsReasoningSummaries: boolean, isDefault: boolean, };
@@ -0,0 +1,8 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * How a `model/resolve` query matched the returned catalog entry.
+ */
+export type ModelAliasKind = "exact" | "family_latest" | "prefix";
@@ -0,0 +1,15 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ModelGetParams = {
+/**
+ * Model id or model slug; either form resolves to the same record.
+ */
+id: string,
+/**
+ * Named config profile (`<name>.config.toml` under `CODEX_HOME`) whose
+ * settings decide the returned `isDefault` flag; defaults to the server's
+ * active profile.
+ */
+configProfile?: string | null, };
@@ -0,0 +1,30 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ModelCatalogSource } from "./ModelCatalogSource";
+
+/**
+ * Catalog-wide metadata for a `model/list` call. It is computed before
+ * pagination, so every page of the same listing carries the same values.
+ */
+export type ModelListMeta = {
+/**
+ * Number of models matching the filters across all pages.
+ */
+total: number,
+/**
+ * Number of filter-matching models that are hidden from the picker.
+ * They only appear in `data` when `includeHidden` is set, but are
+ * counted here either way.
+ */
+hiddenCount: number,
+/**
+ * When the served cache snapshot was fetched from the models endpoint,
+ * as an RFC 3339 timestamp. None when serving the compiled-in baseline.
+ */
+cacheFetchedAt?: string | null,
+/**
+ * Where the served catalog came from: `builtin` means the compiled-in
+ * baseline was used because the on-disk cache was missing or unreadable.
+ */
+source: ModelCatalogSource, };
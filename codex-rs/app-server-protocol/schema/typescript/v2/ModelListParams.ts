@@ -1,17 +1,55 @@
 // GENERATED CODE! DO NOT MODIFY BY HAND!
 
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ModelSortBy } from "./ModelSortBy";
+import type { SortDirection } from "./SortDirection";
 
 export type ModelListParams = {
 /**
- * Opaque pagination cursor returned by a previous call.
+ * Opaque pagination cursor returned by a previous call. Cursors are
+ * bound to the catalog snapshot they were minted from; when the catalog
+ * refreshes in between, the request fails with a `cursorExpired` error
+ * instead of silently mixing snapshots.
  */
 cursor?: string | null,
 /**
- * Optional page size; defaults to a reasonable server-side value.
+ * Page size between 1 and 100 inclusive; defaults to 20. Values outside
+ * the range are rejected.
  */
 limit?: number | null,
 /**
  * When true, include models that are hidden from the default picker list.
  */
-includeHidden?: boolean | null, };
+includeHidden?: boolean | null,
+/**
+ * Only return models from this provider. An unknown provider yields an
+ * empty list rather than an error.
+ */
+provider?: string | null,
+/**
+ * Filter on whether the model supports reasoning efforts.
+ */
+supportsReasoning?: boolean | null,
+/**
+ * Only return models whose id starts with this prefix.
+ */
+idPrefix?: string | null,
+/**
+ * Sort key applied before pagination; defaults to priority.
+ */
+sortBy?: ModelSortBy | null,
+/**
+ * Sort direction; defaults to ascending.
+ */
+order?: SortDirection | null,
+/**
+ * Etag from a previous response; when it still matches the current list,
+ * the server returns a `notModified` short-circuit instead of the data.
+ */
+ifNoneMatch?: string | null,
+/**
+ * Named config profile (`<name>.config.toml` under `CODEX_HOME`) whose
+ * settings decide which model carries `isDefault`; defaults to the
+ * server's active profile.
+ */
+configProfile?: string | null, };
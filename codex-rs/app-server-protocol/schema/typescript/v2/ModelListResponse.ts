@@ -2,10 +2,25 @@
 
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { Model } from "./Model";
+import type { ModelListMeta } from "./ModelListMeta";
 
 export type ModelListResponse = { data: Array<Model>,
 /**
  * Opaque cursor to pass to the next call to continue after the last item.
  * If None, there are no more items to return.
  */
-nextCursor: string | null, };
+nextCursor: string | null,
+/**
+ * Opaque fingerprint of the filtered, sorted list. It changes when the
+ * cached catalog refreshes or when the filter parameters differ.
+ */
+etag: string,
+/**
+ * True when `ifNoneMatch` matched; `data` is empty and the client should
+ * keep using its cached list.
+ */
+notModified: boolean,
+/**
+ * Catalog-wide metadata; identical on every page of the same listing.
+ */
+meta: ModelListMeta, };
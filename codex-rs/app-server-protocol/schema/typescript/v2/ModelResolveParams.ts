@@ -0,0 +1,10 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ModelResolveParams = {
+/**
+ * Free-form model reference: a canonical id, a model slug, a family
+ * name covering dated snapshots, or a unique id prefix.
+ */
+query: string, };
@@ -0,0 +1,19 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Model } from "./Model";
+import type { ModelAliasKind } from "./ModelAliasKind";
+
+export type ModelResolveResponse = {
+/**
+ * Canonical id of the resolved model.
+ */
+id: string,
+/**
+ * How the query matched the catalog entry.
+ */
+aliasKind: ModelAliasKind,
+/**
+ * Full record for the resolved model.
+ */
+model: Model, };
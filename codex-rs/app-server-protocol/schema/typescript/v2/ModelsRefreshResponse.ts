@@ -0,0 +1,18 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ModelsRefreshResponse = {
+/**
+ * Whether the remote catalog changed compared to the previously cached
+ * copy.
+ */
+updated: boolean,
+/**
+ * Number of models in the refreshed catalog, including hidden models.
+ */
+modelCount: number,
+/**
+ * RFC 3339 timestamp recorded when the refresh completed.
+ */
+fetchedAt: string, };
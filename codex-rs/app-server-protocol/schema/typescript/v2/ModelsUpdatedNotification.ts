@@ -0,0 +1,11 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Notification emitted when a `models/refresh` observes catalog changes.
+ *
+ * Treat this as an invalidation signal and re-run `model/list` when fresh
+ * model metadata is needed.
+ */
+export type ModelsUpdatedNotification = Record<string, never>;
@@ -0,0 +1,11 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { JsonValue } from "../serde_json/JsonValue";
+
+/**
+ * Current statusline configuration as JSON mirroring the `CxLineConfig`
+ * serde model. An empty object means the config file does not exist yet and
+ * every field takes its default value.
+ */
+export type StatuslineGetConfigResponse = { config: JsonValue, };
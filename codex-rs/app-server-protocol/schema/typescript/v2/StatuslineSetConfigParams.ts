@@ -0,0 +1,10 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { JsonValue } from "../serde_json/JsonValue";
+
+/**
+ * Replacement statusline configuration as JSON mirroring the `CxLineConfig`
+ * serde model; validated and written atomically to the config file.
+ */
+export type StatuslineSetConfigParams = { config: JsonValue, };
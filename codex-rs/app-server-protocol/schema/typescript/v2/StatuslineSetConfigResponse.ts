@@ -0,0 +1,15 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { StatuslineValidationError } from "./StatuslineValidationError";
+
+export type StatuslineSetConfigResponse = {
+/**
+ * Whether the replacement config was written to disk. False when
+ * validation reported errors; the previous config is left untouched.
+ */
+applied: boolean,
+/**
+ * Per-field validation errors, empty when the config was applied.
+ */
+errors: Array<StatuslineValidationError>, };
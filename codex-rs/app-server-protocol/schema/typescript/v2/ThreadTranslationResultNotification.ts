@@ -0,0 +1,29 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TranslationResultKind } from "./TranslationResultKind";
+import type { TranslationTestErrorCode } from "./TranslationTestErrorCode";
+
+/**
+ * Notification streamed after a reasoning item completes on a thread that
+ * opted into reasoning translation via `thread/start`. Exactly one of
+ * `translatedText` and `code` is set.
+ */
+export type ThreadTranslationResultNotification = { threadId: string,
+/**
+ * Id of the completed reasoning item the translation belongs to.
+ */
+itemId: string,
+/**
+ * Which part of the reasoning item was translated.
+ */
+kind: TranslationResultKind,
+/**
+ * Translated text when the translation succeeded.
+ */
+translatedText?: string | null,
+/**
+ * Failure category when the translation failed, using the same codes as
+ * `translation/test`.
+ */
+code?: TranslationTestErrorCode | null, };
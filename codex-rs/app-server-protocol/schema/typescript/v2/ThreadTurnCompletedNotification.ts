@@ -0,0 +1,24 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ThreadTokenUsage } from "./ThreadTokenUsage";
+
+/**
+ * Compact end-of-turn digest sent as `thread/turnCompleted` after the
+ * matching `turn/completed` notification. Only delivered to clients that
+ * opted in via the `threadTurnCompletedNotifications` initialize capability.
+ */
+export type ThreadTurnCompletedNotification = { threadId: string, turnId: string,
+/**
+ * Duration between turn start and completion in milliseconds, if known.
+ */
+durationMs: number | null,
+/**
+ * Thread token usage as of the turn's last model response, when the
+ * turn made at least one model request.
+ */
+tokenUsage?: ThreadTokenUsage | null,
+/**
+ * First line of the final agent message, when the turn produced one.
+ */
+summary?: string | null, };
@@ -0,0 +1,8 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Which part of a reasoning item a streamed translation applies to.
+ */
+export type TranslationResultKind = "reasoningSummary" | "reasoningContent";
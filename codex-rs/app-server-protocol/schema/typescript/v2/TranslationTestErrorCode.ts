@@ -0,0 +1,9 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Failure categories for `translation/test`, mirroring the TUI's
+ * `TranslationError` variants.
+ */
+export type TranslationTestErrorCode = "api_key_not_found" | "network" | "api" | "parse" | "timeout" | "unsupported_provider" | "invalid_config";
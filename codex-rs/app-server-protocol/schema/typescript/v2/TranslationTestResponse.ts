@@ -0,0 +1,26 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TranslationTestErrorCode } from "./TranslationTestErrorCode";
+
+export type TranslationTestResponse = {
+/**
+ * Whether the configured translator produced a translation.
+ */
+ok: boolean,
+/**
+ * Wall-clock time spent on the test translation, in milliseconds.
+ */
+latencyMs: bigint,
+/**
+ * The translated sample text when the test succeeded.
+ */
+translatedSample?: string | null,
+/**
+ * Machine-readable failure category when the test failed.
+ */
+code?: TranslationTestErrorCode | null,
+/**
+ * Human-readable failure message when the test failed.
+ */
+message?: string | null, };
@@ -0,0 +1,16 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ThreadUsage } from "./ThreadUsage";
+import type { UsageRateLimits } from "./UsageRateLimits";
+
+export type UsageGetResponse = {
+/**
+ * Most recent rate-limit snapshot observed on a model response; `None`
+ * until the first response carrying rate-limit headers arrives.
+ */
+rateLimits?: UsageRateLimits | null,
+/**
+ * Cumulative session token usage per loaded thread, sorted by thread id.
+ */
+threads: Array<ThreadUsage>, };
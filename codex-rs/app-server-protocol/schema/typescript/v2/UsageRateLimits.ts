@@ -0,0 +1,30 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PlanType } from "../PlanType";
+
+/**
+ * Simplified view of the latest rate-limit headers, shaped for usage
+ * displays such as the TUI statusline's usage segment.
+ */
+export type UsageRateLimits = {
+/**
+ * Percentage (0-100) of the hourly (primary) window consumed.
+ */
+hourlyUsedPercent: number | null,
+/**
+ * Unix timestamp (seconds since epoch) when the hourly window resets.
+ */
+hourlyResetsAt: number | null,
+/**
+ * Percentage (0-100) of the weekly (secondary) window consumed.
+ */
+weeklyUsedPercent: number | null,
+/**
+ * Unix timestamp (seconds since epoch) when the weekly window resets.
+ */
+weeklyResetsAt: number | null,
+/**
+ * Account plan when the backend reported one.
+ */
+planType: PlanType | null, };
@@ -0,0 +1,9 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { UsageRateLimits } from "./UsageRateLimits";
+
+/**
+ * Emitted whenever a model response carries fresh rate-limit headers.
+ */
+export type UsageUpdatedNotification = { rateLimits: UsageRateLimits, };
@@ -35,6 +35,10 @@ export type { AppsListResponse } from "./AppsListResponse";
 export type { AskForApproval } from "./AskForApproval";
 export type { AttestationGenerateParams } from "./AttestationGenerateParams";
 export type { AttestationGenerateResponse } from "./AttestationGenerateResponse";
+export type { BuiltinPluginId } from "./BuiltinPluginId";
+export type { BuiltinPluginInfo } from "./BuiltinPluginInfo";
+export type { BuiltinPluginsListParams } from "./BuiltinPluginsListParams";
+export type { BuiltinPluginsListResponse } from "./BuiltinPluginsListResponse";
 export type { AutoReviewDecisionSource } from "./AutoReviewDecisionSource";
 export type { ByteRange } from "./ByteRange";
 export type { CancelLoginAccountParams } from "./CancelLoginAccountParams";
@@ -256,19 +260,30 @@ export type { MemoryCitationEntry } from "./MemoryCitationEntry";
 export type { MergeStrategy } from "./MergeStrategy";
 export type { MigrationDetails } from "./MigrationDetails";
 export type { Model } from "./Model";
+export type { ModelAliasKind } from "./ModelAliasKind";
 export type { ModelAvailabilityNux } from "./ModelAvailabilityNux";
+export type { ModelCatalogSource } from "./ModelCatalogSource";
+export type { ModelGetParams } from "./ModelGetParams";
+export type { ModelGetResponse } from "./ModelGetResponse";
+export type { ModelListMeta } from "./ModelListMeta";
 export type { ModelListParams } from "./ModelListParams";
 export type { ModelListResponse } from "./ModelListResponse";
 export type { ModelProviderCapabilitiesReadParams } from "./ModelProviderCapabilitiesReadParams";
 export type { ModelProviderCapabilitiesReadResponse } from "./ModelProviderCapabilitiesReadResponse";
 export type { ModelRerouteReason } from "./ModelRerouteReason";
 export type { ModelReroutedNotification } from "./ModelReroutedNotification";
+export type { ModelResolveParams } from "./ModelResolveParams";
+export type { ModelResolveResponse } from "./ModelResolveResponse";
 export type { ModelSafetyBufferingUpdatedNotification } from "./ModelSafetyBufferingUpdatedNotification";
 export type { ModelServiceTier } from "./ModelServiceTier";
+export type { ModelSortBy } from "./ModelSortBy";
 export type { ModelUpgradeInfo } from "./ModelUpgradeInfo";
 export type { ModelVerification } from "./ModelVerification";
 export type { ModelVerificationNotification } from "./ModelVerificationNotification";
+export type { ModelsRefreshParams } from "./ModelsRefreshParams";
+export type { ModelsRefreshResponse } from "./ModelsRefreshResponse";
 export type { ModelsRequirements } from "./ModelsRequirements";
+export type { ModelsUpdatedNotification } from "./ModelsUpdatedNotification";
 export type { NetworkAccess } from "./NetworkAccess";
 export type { NetworkApprovalContext } from "./NetworkApprovalContext";
 export type { NetworkApprovalProtocol } from "./NetworkApprovalProtocol";
@@ -385,6 +400,14 @@ export type { SkillsListParams } from "./SkillsListParams";
 export type { SkillsListResponse } from "./SkillsListResponse";
 export type { SortDirection } from "./SortDirection";
 export type { SpendControlLimitSnapshot } from "./SpendControlLimitSnapshot";
+export type { StatuslineGetConfigParams } from "./StatuslineGetConfigParams";
+export type { StatuslineGetConfigResponse } from "./StatuslineGetConfigResponse";
+export type { StatuslineListThemesParams } from "./StatuslineListThemesParams";
+export type { StatuslineListThemesResponse } from "./StatuslineListThemesResponse";
+export type { StatuslineSetConfigParams } from "./StatuslineSetConfigParams";
+export type { StatuslineSetConfigResponse } from "./StatuslineSetConfigResponse";
+export type { StatuslineTheme } from "./StatuslineTheme";
+export type { StatuslineValidationError } from "./StatuslineValidationError";
 export type { SubAgentActivityKind } from "./SubAgentActivityKind";
 export type { SubagentMigration } from "./SubagentMigration";
 export type { TerminalInteractionNotification } from "./TerminalInteractionNotification";
@@ -464,12 +487,15 @@ export type { ThreadStatus } from "./ThreadStatus";
 export type { ThreadStatusChangedNotification } from "./ThreadStatusChangedNotification";
 export type { ThreadTokenUsage } from "./ThreadTokenUsage";
 export type { ThreadTokenUsageUpdatedNotification } from "./ThreadTokenUsageUpdatedNotification";
+export type { ThreadTranslationResultNotification } from "./ThreadTranslationResultNotification";
+export type { ThreadTurnCompletedNotification } from "./ThreadTurnCompletedNotification";
 export type { ThreadUnarchiveParams } from "./ThreadUnarchiveParams";
 export type { ThreadUnarchiveResponse } from "./ThreadUnarchiveResponse";
 export type { ThreadUnarchivedNotification } from "./ThreadUnarchivedNotification";
 export type { ThreadUnsubscribeParams } from "./ThreadUnsubscribeParams";
 export type { ThreadUnsubscribeResponse } from "./ThreadUnsubscribeResponse";
 export type { ThreadUnsubscribeStatus } from "./ThreadUnsubscribeStatus";
+export type { ThreadUsage } from "./ThreadUsage";
 export type { TokenUsageBreakdown } from "./TokenUsageBreakdown";
 export type { ToolRequestUserInputAnswer } from "./ToolRequestUserInputAnswer";
 export type { ToolRequestUserInputOption } from "./ToolRequestUserInputOption";
@@ -477,6 +503,10 @@ export type { ToolRequestUserInputParams } from "./ToolRequestUserInputParams";
 export type { ToolRequestUserInputQuestion } from "./ToolRequestUserInputQuestion";
 export type { ToolRequestUserInputResponse } from "./ToolRequestUserInputResponse";
 export type { ToolsV2 } from "./ToolsV2";
+export type { TranslationResultKind } from "./TranslationResultKind";
+export type { TranslationTestErrorCode } from "./TranslationTestErrorCode";
+export type { TranslationTestParams } from "./TranslationTestParams";
+export type { TranslationTestResponse } from "./TranslationTestResponse";
 export type { Turn } from "./Turn";
 export type { TurnCompletedNotification } from "./TurnCompletedNotification";
 export type { TurnDiffUpdatedNotification } from "./TurnDiffUpdatedNotification";
@@ -496,6 +526,10 @@ export type { TurnStatus } from "./TurnStatus";
 export type { TurnSteerParams } from "./TurnSteerParams";
 export type { TurnSteerResponse } from "./TurnSteerResponse";
 export type { TurnsPage } from "./TurnsPage";
+export type { UsageGetParams } from "./UsageGetParams";
+export type { UsageGetResponse } from "./UsageGetResponse";
+export type { UsageRateLimits } from "./UsageRateLimits";
+export type { UsageUpdatedNotification } from "./UsageUpdatedNotification";
 export type { UserInput } from "./UserInput";
 export type { WarningNotification } from "./WarningNotification";
 export type { WebSearchAction } from "./WebSearchAction";
@@ -866,6 +866,21 @@ client_request_definitions! {
         serialization: None,
         response: v2::ModelListResponse,
     },
+    ModelGet => "model/get" {
+        params: v2::ModelGetParams,
+        serialization: None,
+        response: v2::ModelGetResponse,
+    },
+    ModelResolve => "model/resolve" {
+        params: v2::ModelResolveParams,
+        serialization: None,
+        response: v2::ModelResolveResponse,
+    },
+    ModelsRefresh => "models/refresh" {
+        params: v2::ModelsRefreshParams,
+        serialization: None,
+        response: v2::ModelsRefreshResponse,
+    },
     ModelProviderCapabilitiesRead => "modelProvider/capabilities/read" {
         params: v2::ModelProviderCapabilitiesReadParams,
         serialization: None,
@@ -1146,6 +1161,40 @@ client_request_definitions! {
         response: v2::ConfigRequirementsReadResponse,
     },
 
+    StatuslineGetConfig => "statusline/getConfig" {
+        params: v2::StatuslineGetConfigParams,
+        serialization: global_shared_read("statusline"),
+        response: v2::StatuslineGetConfigResponse,
+    },
+    StatuslineSetConfig => "statusline/setConfig" {
+        params: v2::StatuslineSetConfigParams,
+        serialization: global("statusline"),
+        response: v2::StatuslineSetConfigResponse,
+    },
+    StatuslineListThemes => "statusline/listThemes" {
+        params: v2::StatuslineListThemesParams,
+        serialization: global_shared_read("statusline"),
+        response: v2::StatuslineListThemesResponse,
+    },
+
+    TranslationTest => "translation/test" {
+        params: v2::TranslationTestParams,
+        serialization: global_shared_read("translation"),
+        response: v2::TranslationTestResponse,
+    },
+
+    BuiltinPluginsList => "builtinPlugins/list" {
+        params: v2::BuiltinPluginsListParams,
+        serialization: None,
+        response: v2::BuiltinPluginsListResponse,
+    },
+
+    UsageGet => "usage/get" {
+        params: v2::UsageGetParams,
+        serialization: None,
+        response: v2::UsageGetResponse,
+    },
+
     GetAccount => "account/read" {
         params: v2::GetAccountParams,
         serialization: global("account-auth"),
@@ -1629,6 +1678,7 @@ server_notification_definitions! {
     TurnStarted => "turn/started" (v2::TurnStartedNotification),
     HookStarted => "hook/started" (v2::HookStartedNotification),
     TurnCompleted => "turn/completed" (v2::TurnCompletedNotification),
+    ThreadTurnCompleted => "thread/turnCompleted" (v2::ThreadTurnCompletedNotification),
     HookCompleted => "hook/completed" (v2::HookCompletedNotification),
     TurnDiffUpdated => "turn/diff/updated" (v2::TurnDiffUpdatedNotification),
     TurnPlanUpdated => "turn/plan/updated" (v2::TurnPlanUpdatedNotification),
@@ -1660,6 +1710,7 @@ server_notification_definitions! {
     McpServerStatusUpdated => "mcpServer/startupStatus/updated" (v2::McpServerStatusUpdatedNotification),
     AccountUpdated => "account/updated" (v2::AccountUpdatedNotification),
     AccountRateLimitsUpdated => "account/rateLimits/updated" (v2::AccountRateLimitsUpdatedNotification),
+    UsageUpdated => "usage/updated" (v2::UsageUpdatedNotification),
     AppListUpdated => "app/list/updated" (v2::AppListUpdatedNotification),
     RemoteControlStatusChanged => "remoteControl/status/changed" (v2::RemoteControlStatusChangedNotification),
     ExternalAgentConfigImportProgress => "externalAgentConfig/import/progress" (v2::ExternalAgentConfigImportProgressNotification),
@@ -1672,6 +1723,8 @@ server_notification_definitions! {
     ContextCompacted => "thread/compacted" (v2::ContextCompactedNotification),
     ModelRerouted => "model/rerouted" (v2::ModelReroutedNotification),
     ModelVerification => "model/verification" (v2::ModelVerificationNotification),
+    ModelsUpdated => "models/updated" (v2::ModelsUpdatedNotification),
+    ThreadTranslationResult => "thread/translationResult" (v2::ThreadTranslationResultNotification),
     #[experimental("turn/moderationMetadata")]
     TurnModerationMetadata => "turn/moderationMetadata" (v2::TurnModerationMetadataNotification),
     ModelSafetyBufferingUpdated => "model/safetyBuffering/updated" (v2::ModelSafetyBufferingUpdatedNotification),
@@ -2209,6 +2262,7 @@ mod tests {
                     experimental_api: true,
                     request_attestation: true,
                     mcp_server_openai_form_elicitation: true,
+                    thread_turn_completed_notifications: false,
                     opt_out_notification_methods: Some(vec![
                         "thread/started".to_string(),
                         "item/agentMessage/delta".to_string(),
@@ -2280,6 +2334,7 @@ mod tests {
                         experimental_api: true,
                         request_attestation: true,
                         mcp_server_openai_form_elicitation: true,
+                        thread_turn_completed_notifications: false,
                         opt_out_notification_methods: Some(vec![
                             "thread/started".to_string(),
                             "item/agentMessage/delta".to_string(),
@@ -53,6 +53,11 @@ pub struct InitializeCapabilities {
     /// Allow downstream MCP servers to request OpenAI extended form elicitations.
     #[serde(default, skip_serializing_if = "std::ops::Not::not")]
     pub mcp_server_openai_form_elicitation: bool,
+    /// Opt into `thread/turnCompleted` end-of-turn digest notifications. Off
+    /// by default so clients that do not render them are not sent the extra
+    /// per-turn traffic.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub thread_turn_completed_notifications: bool,
     /// Exact notification method names that should be suppressed for this
     /// connection (for example `thread/started`).
     #[ts(optional = nullable)]
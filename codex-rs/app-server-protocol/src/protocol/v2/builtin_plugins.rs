@@ -0,0 +1,47 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+use ts_rs::TS;
+
+use crate::protocol::v2::TranslationTestResponse;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct BuiltinPluginsListParams {}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct BuiltinPluginsListResponse {
+    pub plugins: Vec<BuiltinPluginInfo>,
+}
+
+/// Built-in plugin integrations that ship with the server, as opposed to
+/// marketplace plugins managed through `plugin/list`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, JsonSchema, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export_to = "v2/")]
+pub enum BuiltinPluginId {
+    Statusline,
+    Translation,
+}
+
+/// Configuration status of one built-in plugin, so clients can hide UI
+/// affordances for plugins the user never set up. The `settings` value is a
+/// sanitized summary: secrets such as API keys are reported as presence flags,
+/// never as values.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct BuiltinPluginInfo {
+    pub id: BuiltinPluginId,
+    /// Whether a configuration file for the plugin exists under CODEX_HOME.
+    pub configured: bool,
+    pub settings: JsonValue,
+    /// Result of the most recent `translation/test` run in this session;
+    /// `None` for plugins without a health check or before the first run.
+    #[ts(optional = nullable)]
+    pub last_health_check: Option<TranslationTestResponse>,
+}
@@ -3,6 +3,7 @@ mod shared;
 mod account;
 mod apps;
 mod attestation;
+mod builtin_plugins;
 mod collaboration_mode;
 mod command_exec;
 mod config;
@@ -22,14 +23,18 @@ mod process;
 mod realtime;
 mod remote_control;
 mod review;
+mod statusline;
 mod thread;
 mod thread_data;
+mod translation;
 mod turn;
+mod usage;
 mod windows_sandbox;
 
 pub use account::*;
 pub use apps::*;
 pub use attestation::*;
+pub use builtin_plugins::*;
 pub use collaboration_mode::*;
 pub use command_exec::*;
 pub use config::*;
@@ -50,9 +55,12 @@ pub use realtime::*;
 pub use remote_control::*;
 pub use review::*;
 pub use shared::*;
+pub use statusline::*;
 pub use thread::*;
 pub use thread_data::*;
+pub use translation::*;
 pub use turn::*;
+pub use usage::*;
 pub use windows_sandbox::*;
 
 #[cfg(test)]
@@ -1,6 +1,8 @@
+use super::SortDirection;
 use super::shared::v2_enum_from_core;
 use codex_protocol::openai_models::InputModality;
 use codex_protocol::openai_models::ModelAvailabilityNux as CoreModelAvailabilityNux;
+use codex_protocol::openai_models::ModelCatalogSource as CoreModelCatalogSource;
 use codex_protocol::openai_models::ReasoningEffort;
 use codex_protocol::openai_models::default_input_modalities;
 use codex_protocol::protocol::ModelRerouteReason as CoreModelRerouteReason;
@@ -23,6 +25,14 @@ v2_enum_from_core!(
     }
 );
 
+v2_enum_from_core!(
+    /// Where the served model catalog came from.
+    pub enum ModelCatalogSource from CoreModelCatalogSource {
+        Builtin,
+        Cache
+    }
+);
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default, JsonSchema, TS)]
 #[serde(rename_all = "camelCase")]
 #[ts(export_to = "v2/")]
@@ -41,17 +51,136 @@ pub struct ModelProviderCapabilitiesReadResponse {
 #[serde(rename_all = "camelCase")]
 #[ts(export_to = "v2/")]
 pub struct ModelListParams {
-    /// Opaque pagination cursor returned by a previous call.
+    /// Opaque pagination cursor returned by a previous call. Cursors are
+    /// bound to the catalog snapshot they were minted from; when the catalog
+    /// refreshes in between, the request fails with a `cursorExpired` error
+    /// instead of silently mixing snapshots.
     #[ts(optional = nullable)]
     pub cursor: Option<String>,
-    /// Optional page size; defaults to a reasonable server-side value.
+    /// Page size between 1 and 100 inclusive; defaults to 20. Values outside
+    /// the range are rejected.
     #[ts(optional = nullable)]
     pub limit: Option<u32>,
     /// When true, include models that are hidden from the default picker list.
     #[ts(optional = nullable)]
     pub include_hidden: Option<bool>,
+    /// Only return models from this provider. An unknown provider yields an
+    /// empty list rather than an error.
+    #[ts(optional = nullable)]
+    pub provider: Option<String>,
+    /// Filter on whether the model supports reasoning efforts.
+    #[ts(optional = nullable)]
+    pub supports_reasoning: Option<bool>,
+    /// Only return models whose id starts with this prefix.
+    #[ts(optional = nullable)]
+    pub id_prefix: Option<String>,
+    /// Sort key applied before pagination; defaults to priority.
+    #[ts(optional = nullable)]
+    pub sort_by: Option<ModelSortBy>,
+    /// Sort direction; defaults to ascending.
+    #[ts(optional = nullable)]
+    pub order: Option<SortDirection>,
+    /// Etag from a previous response; when it still matches the current list,
+    /// the server returns a `notModified` short-circuit instead of the data.
+    #[ts(optional = nullable)]
+    pub if_none_match: Option<String>,
+    /// Named config profile (`<name>.config.toml` under `CODEX_HOME`) whose
+    /// settings decide which model carries `isDefault`; defaults to the
+    /// server's active profile.
+    #[ts(optional = nullable)]
+    pub config_profile: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, JsonSchema, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export_to = "v2/")]
+pub enum ModelSortBy {
+    Priority,
+    Id,
+    ReleaseDate,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct ModelGetParams {
+    /// Model id or model slug; either form resolves to the same record.
+    pub id: String,
+    /// Named config profile (`<name>.config.toml` under `CODEX_HOME`) whose
+    /// settings decide the returned `isDefault` flag; defaults to the server's
+    /// active profile.
+    #[ts(optional = nullable)]
+    pub config_profile: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct ModelGetResponse {
+    pub model: Model,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct ModelResolveParams {
+    /// Free-form model reference: a canonical id, a model slug, a family
+    /// name covering dated snapshots, or a unique id prefix.
+    pub query: String,
+}
+
+/// How a `model/resolve` query matched the returned catalog entry.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, JsonSchema, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export_to = "v2/")]
+pub enum ModelAliasKind {
+    /// The query equaled a model id or slug verbatim.
+    Exact,
+    /// The query named a model family; the newest member was chosen.
+    FamilyLatest,
+    /// The query uniquely prefixed a single model id.
+    Prefix,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct ModelResolveResponse {
+    /// Canonical id of the resolved model.
+    pub id: String,
+    /// How the query matched the catalog entry.
+    pub alias_kind: ModelAliasKind,
+    /// Full record for the resolved model.
+    pub model: Model,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct ModelsRefreshParams {}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct ModelsRefreshResponse {
+    /// Whether the remote catalog changed compared to the previously cached
+    /// copy.
+    pub updated: bool,
+    /// Number of models in the refreshed catalog, including hidden models.
+    pub model_count: u32,
+    /// RFC 3339 timestamp recorded when the refresh completed.
+    pub fetched_at: String,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+/// Notification emitted when a `models/refresh` observes catalog changes.
+///
+/// Treat this as an invalidation signal and re-run `model/list` when fresh
+/// model metadata is needed.
+pub struct ModelsUpdatedNotification {}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
 #[serde(rename_all = "camelCase")]
 #[ts(export_to = "v2/")]
@@ -84,12 +213,31 @@ pub struct Model {
     pub model: String,
     pub upgrade: Option<String>,
     pub upgrade_info: Option<ModelUpgradeInfo>,
+    /// Whether the catalog marks this model as deprecated.
+    #[serde(default)]
+    pub deprecated: bool,
+    /// Catalog-provided message shown when the model is deprecated.
+    #[serde(default)]
+    #[ts(optional = nullable)]
+    pub deprecation_message: Option<String>,
+    /// Ordered upgrade chain ending at the recommended current model; empty
+    /// when the model is already current.
+    #[serde(default)]
+    pub upgrade_chain: Vec<String>,
     pub availability_nux: Option<ModelAvailabilityNux>,
     pub display_name: String,
     pub description: String,
     pub hidden: bool,
+    /// Reasoning efforts the model accepts; omitted for models without
+    /// reasoning support.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[ts(optional)]
     pub supported_reasoning_efforts: Vec<ReasoningEffortOption>,
-    pub default_reasoning_effort: ReasoningEffort,
+    /// Effort applied when the client does not pick one; omitted for models
+    /// without reasoning support.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional = nullable)]
+    pub default_reasoning_effort: Option<ReasoningEffort>,
     #[serde(default = "default_input_modalities")]
     pub input_modalities: Vec<InputModality>,
     #[serde(default)]
@@ -102,6 +250,20 @@ pub struct Model {
     /// Catalog default service tier id for this model, when one is configured.
     #[serde(default)]
     pub default_service_tier: Option<String>,
+    /// Context window in tokens, when the catalog provides one.
+    #[serde(default)]
+    #[ts(optional = nullable)]
+    pub context_window: Option<i64>,
+    /// Maximum number of output tokens per response, when known.
+    #[serde(default)]
+    #[ts(optional = nullable)]
+    pub max_output_tokens: Option<i64>,
+    /// Whether the model can invoke multiple tools in parallel.
+    #[serde(default)]
+    pub supports_parallel_tool_calls: bool,
+    /// Whether the model emits reasoning summaries.
+    #[serde(default)]
+    pub supports_reasoning_summaries: bool,
     // Only one model should be marked as default.
     pub is_default: bool,
 }
@@ -124,6 +286,27 @@ pub struct ReasoningEffortOption {
     pub description: String,
 }
 
+/// Catalog-wide metadata for a `model/list` call. It is computed before
+/// pagination, so every page of the same listing carries the same values.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct ModelListMeta {
+    /// Number of models matching the filters across all pages.
+    pub total: u32,
+    /// Number of filter-matching models that are hidden from the picker.
+    /// They only appear in `data` when `includeHidden` is set, but are
+    /// counted here either way.
+    pub hidden_count: u32,
+    /// When the served cache snapshot was fetched from the models endpoint,
+    /// as an RFC 3339 timestamp. None when serving the compiled-in baseline.
+    #[ts(optional = nullable)]
+    pub cache_fetched_at: Option<String>,
+    /// Where the served catalog came from: `builtin` means the compiled-in
+    /// baseline was used because the on-disk cache was missing or unreadable.
+    pub source: ModelCatalogSource,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
 #[serde(rename_all = "camelCase")]
 #[ts(export_to = "v2/")]
@@ -132,6 +315,14 @@ pub struct ModelListResponse {
     /// Opaque cursor to pass to the next call to continue after the last item.
     /// If None, there are no more items to return.
     pub next_cursor: Option<String>,
+    /// Opaque fingerprint of the filtered, sorted list. It changes when the
+    /// cached catalog refreshes or when the filter parameters differ.
+    pub etag: String,
+    /// True when `ifNoneMatch` matched; `data` is empty and the client should
+    /// keep using its cached list.
+    pub not_modified: bool,
+    /// Catalog-wide metadata; identical on every page of the same listing.
+    pub meta: ModelListMeta,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema, TS)]
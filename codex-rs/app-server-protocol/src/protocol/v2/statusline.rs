@@ -0,0 +1,70 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+use ts_rs::TS;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct StatuslineGetConfigParams {}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+/// Current statusline configuration as JSON mirroring the `CxLineConfig`
+/// serde model. An empty object means the config file does not exist yet and
+/// every field takes its default value.
+pub struct StatuslineGetConfigResponse {
+    pub config: JsonValue,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+/// Replacement statusline configuration as JSON mirroring the `CxLineConfig`
+/// serde model; validated and written atomically to the config file.
+pub struct StatuslineSetConfigParams {
+    pub config: JsonValue,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct StatuslineSetConfigResponse {
+    /// Whether the replacement config was written to disk. False when
+    /// validation reported errors; the previous config is left untouched.
+    pub applied: bool,
+    /// Per-field validation errors, empty when the config was applied.
+    pub errors: Vec<StatuslineValidationError>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct StatuslineValidationError {
+    /// Dotted path to the offending field, e.g. `segments.model.enabled`.
+    pub field: String,
+    pub message: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct StatuslineListThemesParams {}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct StatuslineListThemesResponse {
+    pub themes: Vec<StatuslineTheme>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct StatuslineTheme {
+    pub name: String,
+    /// True for themes bundled with the TUI; false for user theme files.
+    pub built_in: bool,
+}
@@ -145,6 +145,12 @@ pub struct ThreadStartParams {
     #[experimental("thread/start.experimentalRawEvents")]
     #[serde(default, skip_serializing_if = "std::ops::Not::not")]
     pub experimental_raw_events: bool,
+    /// If true, translate completed reasoning items through the translation
+    /// plugin configured in `translation.toml` and stream the results as
+    /// `thread/translationResult` notifications. Off by default so clients
+    /// that do not render translations never pay the provider latency.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub translate_reasoning: bool,
 }
 
 #[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, JsonSchema, TS)]
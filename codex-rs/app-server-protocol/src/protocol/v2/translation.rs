@@ -0,0 +1,73 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use ts_rs::TS;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct TranslationTestParams {}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct TranslationTestResponse {
+    /// Whether the configured translator produced a translation.
+    pub ok: bool,
+    /// Wall-clock time spent on the test translation, in milliseconds.
+    pub latency_ms: u64,
+    /// The translated sample text when the test succeeded.
+    #[ts(optional = nullable)]
+    pub translated_sample: Option<String>,
+    /// Machine-readable failure category when the test failed.
+    #[ts(optional = nullable)]
+    pub code: Option<TranslationTestErrorCode>,
+    /// Human-readable failure message when the test failed.
+    #[ts(optional = nullable)]
+    pub message: Option<String>,
+}
+
+/// Failure categories for `translation/test`, mirroring the TUI's
+/// `TranslationError` variants.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, JsonSchema, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export_to = "v2/", rename_all = "snake_case")]
+pub enum TranslationTestErrorCode {
+    ApiKeyNotFound,
+    Network,
+    Api,
+    Parse,
+    Timeout,
+    UnsupportedProvider,
+    InvalidConfig,
+}
+
+/// Which part of a reasoning item a streamed translation applies to.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub enum TranslationResultKind {
+    ReasoningSummary,
+    ReasoningContent,
+}
+
+/// Notification streamed after a reasoning item completes on a thread that
+/// opted into reasoning translation via `thread/start`. Exactly one of
+/// `translatedText` and `code` is set.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct ThreadTranslationResultNotification {
+    pub thread_id: String,
+    /// Id of the completed reasoning item the translation belongs to.
+    pub item_id: String,
+    /// Which part of the reasoning item was translated.
+    pub kind: TranslationResultKind,
+    /// Translated text when the translation succeeded.
+    #[ts(optional = nullable)]
+    pub translated_text: Option<String>,
+    /// Failure category when the translation failed, using the same codes as
+    /// `translation/test`.
+    #[ts(optional = nullable)]
+    pub code: Option<TranslationTestErrorCode>,
+}
@@ -1,6 +1,7 @@
 use super::ApprovalsReviewer;
 use super::AskForApproval;
 use super::SandboxPolicy;
+use super::ThreadTokenUsage;
 use super::Turn;
 use codex_experimental_api_macros::ExperimentalApi;
 use codex_protocol::config_types::CollaborationMode;
@@ -394,6 +395,27 @@ pub struct TurnCompletedNotification {
     pub turn: Turn,
 }
 
+/// Compact end-of-turn digest sent as `thread/turnCompleted` after the
+/// matching `turn/completed` notification. Only delivered to clients that
+/// opted in via the `threadTurnCompletedNotifications` initialize capability.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct ThreadTurnCompletedNotification {
+    pub thread_id: String,
+    pub turn_id: String,
+    /// Duration between turn start and completion in milliseconds, if known.
+    #[ts(type = "number | null")]
+    pub duration_ms: Option<i64>,
+    /// Thread token usage as of the turn's last model response, when the
+    /// turn made at least one model request.
+    #[ts(optional = nullable)]
+    pub token_usage: Option<ThreadTokenUsage>,
+    /// First line of the final agent message, when the turn produced one.
+    #[ts(optional = nullable)]
+    pub summary: Option<String>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
 #[serde(rename_all = "camelCase")]
 #[ts(export_to = "v2/")]
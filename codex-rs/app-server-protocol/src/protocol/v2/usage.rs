@@ -0,0 +1,73 @@
+use codex_protocol::account::PlanType;
+use codex_protocol::protocol::RateLimitSnapshot as CoreRateLimitSnapshot;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use ts_rs::TS;
+
+use crate::protocol::v2::ThreadTokenUsage;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct UsageGetParams {}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct UsageGetResponse {
+    /// Most recent rate-limit snapshot observed on a model response; `None`
+    /// until the first response carrying rate-limit headers arrives.
+    #[ts(optional = nullable)]
+    pub rate_limits: Option<UsageRateLimits>,
+    /// Cumulative session token usage per loaded thread, sorted by thread id.
+    pub threads: Vec<ThreadUsage>,
+}
+
+/// Simplified view of the latest rate-limit headers, shaped for usage
+/// displays such as the TUI statusline's usage segment.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct UsageRateLimits {
+    /// Percentage (0-100) of the hourly (primary) window consumed.
+    pub hourly_used_percent: Option<f64>,
+    /// Unix timestamp (seconds since epoch) when the hourly window resets.
+    #[ts(type = "number | null")]
+    pub hourly_resets_at: Option<i64>,
+    /// Percentage (0-100) of the weekly (secondary) window consumed.
+    pub weekly_used_percent: Option<f64>,
+    /// Unix timestamp (seconds since epoch) when the weekly window resets.
+    #[ts(type = "number | null")]
+    pub weekly_resets_at: Option<i64>,
+    /// Account plan when the backend reported one.
+    pub plan_type: Option<PlanType>,
+}
+
+impl From<CoreRateLimitSnapshot> for UsageRateLimits {
+    fn from(value: CoreRateLimitSnapshot) -> Self {
+        Self {
+            hourly_used_percent: value.primary.as_ref().map(|window| window.used_percent),
+            hourly_resets_at: value.primary.as_ref().and_then(|window| window.resets_at),
+            weekly_used_percent: value.secondary.as_ref().map(|window| window.used_percent),
+            weekly_resets_at: value.secondary.as_ref().and_then(|window| window.resets_at),
+            plan_type: value.plan_type,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct ThreadUsage {
+    pub thread_id: String,
+    pub token_usage: ThreadTokenUsage,
+}
+
+/// Emitted whenever a model response carries fresh rate-limit headers.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct UsageUpdatedNotification {
+    pub rate_limits: UsageRateLimits,
+}
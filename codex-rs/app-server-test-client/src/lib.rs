@@ -1671,6 +1671,7 @@ impl CodexClient {
                             .collect(),
                     ),
                     mcp_server_openai_form_elicitation: false,
+                    thread_turn_completed_notifications: false,
                 }),
             },
         };
@@ -11,6 +11,7 @@ use crate::thread_state::TurnSummary;
 use crate::thread_state::resolve_server_request_on_thread_listener;
 use crate::thread_status::ThreadWatchActiveGuard;
 use crate::thread_status::ThreadWatchManager;
+use crate::usage::UsageTracker;
 use codex_app_server_protocol::AccountRateLimitsUpdatedNotification;
 use codex_app_server_protocol::AdditionalPermissionProfile as V2AdditionalPermissionProfile;
 use codex_app_server_protocol::CodexErrorInfo as V2CodexErrorInfo;
@@ -65,6 +66,7 @@ use codex_app_server_protocol::ThreadSettingsUpdatedNotification;
 use codex_app_server_protocol::ThreadStatus;
 use codex_app_server_protocol::ThreadTokenUsage;
 use codex_app_server_protocol::ThreadTokenUsageUpdatedNotification;
+use codex_app_server_protocol::ThreadTurnCompletedNotification;
 use codex_app_server_protocol::ToolRequestUserInputOption;
 use codex_app_server_protocol::ToolRequestUserInputParams;
 use codex_app_server_protocol::ToolRequestUserInputQuestion;
@@ -80,6 +82,7 @@ use codex_app_server_protocol::TurnPlanStep;
 use codex_app_server_protocol::TurnPlanUpdatedNotification;
 use codex_app_server_protocol::TurnStartedNotification;
 use codex_app_server_protocol::TurnStatus;
+use codex_app_server_protocol::UsageUpdatedNotification;
 use codex_app_server_protocol::WarningNotification;
 use codex_app_server_protocol::build_item_from_guardian_event;
 use codex_app_server_protocol::guardian_auto_approval_review_notification;
@@ -143,6 +146,7 @@ pub(crate) async fn apply_bespoke_event_handling(
     thread_watch_manager: ThreadWatchManager,
     thread_list_state_permit: Arc<tokio::sync::Semaphore>,
     fallback_model_provider: String,
+    usage_tracker: Arc<UsageTracker>,
 ) {
     let Event {
         id: event_turn_id,
@@ -873,8 +877,14 @@ pub(crate) async fn apply_bespoke_event_handling(
                 .await;
         }
         EventMsg::TokenCount(token_count_event) => {
-            handle_token_count_event(conversation_id, event_turn_id, token_count_event, &outgoing)
-                .await;
+            handle_token_count_event(
+                conversation_id,
+                event_turn_id,
+                token_count_event,
+                &outgoing,
+                &usage_tracker,
+            )
+            .await;
         }
         EventMsg::Error(ev) => {
             thread_watch_manager
@@ -1245,6 +1255,33 @@ async fn emit_turn_completed_with_status(
         .await;
 }
 
+/// First non-empty line of the final agent message, used as the digest line in
+/// `thread/turnCompleted` notifications.
+fn one_line_summary(last_agent_message: Option<&str>) -> Option<String> {
+    let first_line = last_agent_message?.lines().next()?.trim();
+    (!first_line.is_empty()).then(|| first_line.to_string())
+}
+
+async fn emit_thread_turn_completed(
+    conversation_id: ThreadId,
+    turn_id: String,
+    duration_ms: Option<i64>,
+    token_usage: Option<ThreadTokenUsage>,
+    summary: Option<String>,
+    outgoing: &ThreadScopedOutgoingMessageSender,
+) {
+    let notification = ThreadTurnCompletedNotification {
+        thread_id: conversation_id.to_string(),
+        turn_id,
+        duration_ms,
+        token_usage,
+        summary,
+    };
+    outgoing
+        .send_server_notification(ServerNotification::ThreadTurnCompleted(notification))
+        .await;
+}
+
 async fn apply_canonical_item_completed_side_effects(
     thread_manager: &Arc<ThreadManager>,
     thread_watch_manager: &ThreadWatchManager,
@@ -1422,7 +1459,7 @@ async fn handle_turn_complete(
 
     emit_turn_completed_with_status(
         conversation_id,
-        event_turn_id,
+        event_turn_id.clone(),
         TurnCompletionMetadata {
             status,
             error,
@@ -1433,6 +1470,16 @@ async fn handle_turn_complete(
         outgoing,
     )
     .await;
+
+    emit_thread_turn_completed(
+        conversation_id,
+        event_turn_id,
+        turn_complete_event.duration_ms,
+        turn_summary.last_token_usage,
+        one_line_summary(turn_complete_event.last_agent_message.as_deref()),
+        outgoing,
+    )
+    .await;
 }
 
 async fn handle_turn_interrupted(
@@ -1446,7 +1493,7 @@ async fn handle_turn_interrupted(
 
     emit_turn_completed_with_status(
         conversation_id,
-        event_turn_id,
+        event_turn_id.clone(),
         TurnCompletionMetadata {
             status: TurnStatus::Interrupted,
             error: None,
@@ -1457,6 +1504,16 @@ async fn handle_turn_interrupted(
         outgoing,
     )
     .await;
+
+    emit_thread_turn_completed(
+        conversation_id,
+        event_turn_id,
+        turn_aborted_event.duration_ms,
+        turn_summary.last_token_usage,
+        /*summary*/ None,
+        outgoing,
+    )
+    .await;
 }
 
 async fn handle_thread_rollback_failed(
@@ -1516,6 +1573,7 @@ async fn handle_token_count_event(
     turn_id: String,
     token_count_event: TokenCountEvent,
     outgoing: &ThreadScopedOutgoingMessageSender,
+    usage_tracker: &UsageTracker,
 ) {
     let TokenCountEvent { info, rate_limits } = token_count_event;
     if let Some(token_usage) = info.map(ThreadTokenUsage::from) {
@@ -1529,13 +1587,19 @@ async fn handle_token_count_event(
             .await;
     }
     if let Some(rate_limits) = rate_limits {
+        usage_tracker.record_rate_limits(rate_limits.clone()).await;
         outgoing
             .send_server_notification(ServerNotification::AccountRateLimitsUpdated(
                 AccountRateLimitsUpdatedNotification {
-                    rate_limits: rate_limits.into(),
+                    rate_limits: rate_limits.clone().into(),
                 },
             ))
             .await;
+        outgoing
+            .send_server_notification(ServerNotification::UsageUpdated(UsageUpdatedNotification {
+                rate_limits: rate_limits.into(),
+            }))
+            .await;
     }
 }
 
@@ -2044,6 +2108,7 @@ mod tests {
     use crate::outgoing_message::OutgoingEnvelope;
     use crate::outgoing_message::OutgoingMessage;
     use crate::outgoing_message::OutgoingMessageSender;
+    use anyhow::Context;
     use anyhow::Result;
     use anyhow::anyhow;
     use anyhow::bail;
@@ -2290,6 +2355,7 @@ mod tests {
                 self.thread_watch_manager.clone(),
                 Arc::new(tokio::sync::Semaphore::new(/*permits*/ 1)),
                 "test-provider".to_string(),
+                Arc::new(UsageTracker::default()),
             )
             .await;
         }
@@ -3249,6 +3315,7 @@ mod tests {
             thread_watch_manager,
             Arc::new(tokio::sync::Semaphore::new(/*permits*/ 1)),
             "test-provider".to_string(),
+            Arc::new(UsageTracker::default()),
         )
         .await;
 
@@ -3323,6 +3390,7 @@ mod tests {
             thread_watch_manager.clone(),
             Arc::new(tokio::sync::Semaphore::new(/*permits*/ 1)),
             "test-provider".to_string(),
+            Arc::new(UsageTracker::default()),
         )
         .await;
 
@@ -3412,6 +3480,7 @@ mod tests {
             ThreadWatchManager::new(),
             Arc::new(tokio::sync::Semaphore::new(/*permits*/ 1)),
             "test-provider".to_string(),
+            Arc::new(UsageTracker::default()),
         )
         .await;
 
@@ -3498,6 +3567,89 @@ mod tests {
             }
             other => bail!("unexpected message: {other:?}"),
         }
+        let msg = recv_broadcast_message(&mut rx).await?;
+        match msg {
+            OutgoingMessage::AppServerNotification(ServerNotification::ThreadTurnCompleted(n)) => {
+                assert_eq!(n.thread_id, conversation_id.to_string());
+                assert_eq!(n.turn_id, event_turn_id);
+                assert_eq!(n.duration_ms, Some(TEST_TURN_DURATION_MS));
+                assert_eq!(n.token_usage, None);
+                assert_eq!(n.summary, None);
+            }
+            other => bail!("unexpected message: {other:?}"),
+        }
+        assert!(rx.try_recv().is_err(), "no extra messages expected");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_handle_turn_complete_digest_carries_usage_and_summary_first_line() -> Result<()> {
+        let conversation_id = ThreadId::new();
+        let event_turn_id = "complete_digest1".to_string();
+        let (tx, mut rx) = mpsc::channel(CHANNEL_CAPACITY);
+        let outgoing = Arc::new(OutgoingMessageSender::new(
+            tx,
+            codex_analytics::AnalyticsEventsClient::disabled(),
+        ));
+        let outgoing = ThreadScopedOutgoingMessageSender::new(
+            outgoing,
+            vec![ConnectionId(1)],
+            ThreadId::new(),
+        );
+        let thread_state = new_thread_state();
+        let info = TokenUsageInfo {
+            total_token_usage: TokenUsage {
+                input_tokens: 100,
+                cached_input_tokens: 25,
+                output_tokens: 50,
+                reasoning_output_tokens: 9,
+                total_tokens: 200,
+            },
+            last_token_usage: TokenUsage {
+                input_tokens: 10,
+                cached_input_tokens: 5,
+                output_tokens: 7,
+                reasoning_output_tokens: 1,
+                total_tokens: 23,
+            },
+            model_context_window: Some(4096),
+        };
+        thread_state.lock().await.track_current_turn_event(
+            &event_turn_id,
+            &EventMsg::TokenCount(TokenCountEvent {
+                info: Some(info.clone()),
+                rate_limits: None,
+            }),
+        );
+
+        handle_turn_complete(
+            conversation_id,
+            event_turn_id.clone(),
+            TurnCompleteEvent {
+                last_agent_message: Some("All tests pass.\n\nDetails follow.".to_string()),
+                ..turn_complete_event(&event_turn_id)
+            },
+            &outgoing,
+            &thread_state,
+        )
+        .await;
+
+        let msg = recv_broadcast_message(&mut rx).await?;
+        let OutgoingMessage::AppServerNotification(ServerNotification::TurnCompleted(_)) = msg
+        else {
+            bail!("unexpected message: {msg:?}");
+        };
+        let msg = recv_broadcast_message(&mut rx).await?;
+        match msg {
+            OutgoingMessage::AppServerNotification(ServerNotification::ThreadTurnCompleted(n)) => {
+                assert_eq!(n.thread_id, conversation_id.to_string());
+                assert_eq!(n.turn_id, event_turn_id);
+                assert_eq!(n.duration_ms, Some(TEST_TURN_DURATION_MS));
+                assert_eq!(n.token_usage, Some(ThreadTokenUsage::from(info)));
+                assert_eq!(n.summary.as_deref(), Some("All tests pass."));
+            }
+            other => bail!("unexpected message: {other:?}"),
+        }
         assert!(rx.try_recv().is_err(), "no extra messages expected");
         Ok(())
     }
@@ -3548,6 +3700,14 @@ mod tests {
             }
             other => bail!("unexpected message: {other:?}"),
         }
+        let msg = recv_broadcast_message(&mut rx).await?;
+        match msg {
+            OutgoingMessage::AppServerNotification(ServerNotification::ThreadTurnCompleted(n)) => {
+                assert_eq!(n.turn_id, event_turn_id);
+                assert_eq!(n.summary, None);
+            }
+            other => bail!("unexpected message: {other:?}"),
+        }
         assert!(rx.try_recv().is_err(), "no extra messages expected");
         Ok(())
     }
@@ -3605,6 +3765,13 @@ mod tests {
             }
             other => bail!("unexpected message: {other:?}"),
         }
+        let msg = recv_broadcast_message(&mut rx).await?;
+        match msg {
+            OutgoingMessage::AppServerNotification(ServerNotification::ThreadTurnCompleted(n)) => {
+                assert_eq!(n.turn_id, event_turn_id);
+            }
+            other => bail!("unexpected message: {other:?}"),
+        }
         assert!(rx.try_recv().is_err(), "no extra messages expected");
         Ok(())
     }
@@ -3708,6 +3875,7 @@ mod tests {
             rate_limit_reached_type: None,
         };
 
+        let usage_tracker = UsageTracker::default();
         handle_token_count_event(
             conversation_id,
             turn_id.clone(),
@@ -3716,6 +3884,7 @@ mod tests {
                 rate_limits: Some(rate_limits),
             },
             &outgoing,
+            &usage_tracker,
         )
         .await;
 
@@ -3747,6 +3916,22 @@ mod tests {
             }
             other => bail!("unexpected notification: {other:?}"),
         }
+
+        let third = recv_broadcast_message(&mut rx).await?;
+        match third {
+            OutgoingMessage::AppServerNotification(ServerNotification::UsageUpdated(payload)) => {
+                assert_eq!(payload.rate_limits.hourly_used_percent, Some(42.5));
+                assert_eq!(payload.rate_limits.hourly_resets_at, Some(1700000000));
+                assert_eq!(payload.rate_limits.weekly_used_percent, None);
+            }
+            other => bail!("unexpected notification: {other:?}"),
+        }
+
+        let recorded = usage_tracker
+            .latest_rate_limits()
+            .await
+            .context("tracker should record the snapshot")?;
+        assert_eq!(recorded.limit_id.as_deref(), Some("codex"));
         Ok(())
     }
 
@@ -3773,6 +3958,7 @@ mod tests {
                 rate_limits: None,
             },
             &outgoing,
+            &UsageTracker::default(),
         )
         .await;
 
@@ -3872,6 +4058,14 @@ mod tests {
             other => bail!("unexpected message: {other:?}"),
         }
 
+        let msg = recv_broadcast_message(&mut rx).await?;
+        match msg {
+            OutgoingMessage::AppServerNotification(ServerNotification::ThreadTurnCompleted(n)) => {
+                assert_eq!(n.turn_id, a_turn1);
+            }
+            other => bail!("unexpected message: {other:?}"),
+        }
+
         // Verify: B turn 1
         let msg = recv_broadcast_message(&mut rx).await?;
         match msg {
@@ -3890,6 +4084,14 @@ mod tests {
             other => bail!("unexpected message: {other:?}"),
         }
 
+        let msg = recv_broadcast_message(&mut rx).await?;
+        match msg {
+            OutgoingMessage::AppServerNotification(ServerNotification::ThreadTurnCompleted(n)) => {
+                assert_eq!(n.turn_id, b_turn1);
+            }
+            other => bail!("unexpected message: {other:?}"),
+        }
+
         // Verify: A turn 2
         let msg = recv_broadcast_message(&mut rx).await?;
         match msg {
@@ -3901,6 +4103,14 @@ mod tests {
             other => bail!("unexpected message: {other:?}"),
         }
 
+        let msg = recv_broadcast_message(&mut rx).await?;
+        match msg {
+            OutgoingMessage::AppServerNotification(ServerNotification::ThreadTurnCompleted(n)) => {
+                assert_eq!(n.turn_id, a_turn2);
+            }
+            other => bail!("unexpected message: {other:?}"),
+        }
+
         assert!(rx.try_recv().is_err(), "no extra messages expected");
         Ok(())
     }
@@ -3,10 +3,12 @@ use codex_cloud_config::cloud_config_bundle_loader;
 use codex_config::CloudConfigBundleLoader;
 use codex_config::ConfigLayerStack;
 use codex_config::LoaderOverrides;
+use codex_config::ProfileV2Name;
 use codex_config::ThreadConfigLoader;
 use codex_config::loader::load_config_layers_state;
 use codex_core::config::Config;
 use codex_core::config::ConfigOverrides;
+use codex_core::config::resolve_profile_v2_config_path;
 use codex_exec_server::LOCAL_FS;
 use codex_features::feature_for_key;
 use codex_login::AuthManager;
@@ -148,6 +150,20 @@ impl ConfigManager {
         .await
     }
 
+    /// Loads config as if the server had been started with `--profile
+    /// <profile>`: the profile's `<name>.config.toml` is layered on top of the
+    /// base user config.
+    pub(crate) async fn load_latest_config_for_profile(
+        &self,
+        profile: &ProfileV2Name,
+    ) -> std::io::Result<Config> {
+        let mut manager = self.clone();
+        manager.loader_overrides.user_config_path =
+            Some(resolve_profile_v2_config_path(&manager.codex_home, profile));
+        manager.loader_overrides.user_config_profile = Some(profile.clone());
+        manager.load_latest_config(/*fallback_cwd*/ None).await
+    }
+
     pub(crate) async fn load_latest_config_for_thread(
         &self,
         thread_config: &Config,
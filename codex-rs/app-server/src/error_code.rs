@@ -5,6 +5,11 @@ pub(crate) const METHOD_NOT_FOUND_ERROR_CODE: i64 = -32601;
 pub const INVALID_PARAMS_ERROR_CODE: i64 = -32602;
 pub(crate) const INTERNAL_ERROR_CODE: i64 = -32603;
 pub(crate) const OVERLOADED_ERROR_CODE: i64 = -32001;
+/// Matches the not-found code used by exec-server RPCs.
+pub(crate) const NOT_FOUND_ERROR_CODE: i64 = -32004;
+/// Returned when a pagination cursor references a catalog snapshot that has
+/// since been replaced; clients should restart pagination from the first page.
+pub(crate) const CURSOR_EXPIRED_ERROR_CODE: i64 = -32005;
 pub const INPUT_TOO_LARGE_ERROR_CODE: &str = "input_too_large";
 
 pub(crate) fn invalid_request(message: impl Into<String>) -> JSONRPCErrorError {
@@ -23,6 +28,14 @@ pub(crate) fn internal_error(message: impl Into<String>) -> JSONRPCErrorError {
     error(INTERNAL_ERROR_CODE, message)
 }
 
+pub(crate) fn not_found(message: impl Into<String>) -> JSONRPCErrorError {
+    error(NOT_FOUND_ERROR_CODE, message)
+}
+
+pub(crate) fn cursor_expired(message: impl Into<String>) -> JSONRPCErrorError {
+    error(CURSOR_EXPIRED_ERROR_CODE, message)
+}
+
 fn error(code: i64, message: impl Into<String>) -> JSONRPCErrorError {
     JSONRPCErrorError {
         code,
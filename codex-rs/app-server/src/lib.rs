@@ -111,9 +111,12 @@ mod request_processors;
 mod request_serialization;
 mod server_request_error;
 mod skills_watcher;
+mod statusline;
 mod thread_state;
 mod thread_status;
+mod translation;
 mod transport;
+mod usage;
 
 pub use crate::error_code::INPUT_TOO_LARGE_ERROR_CODE;
 pub use crate::error_code::INVALID_PARAMS_ERROR_CODE;
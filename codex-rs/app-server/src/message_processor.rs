@@ -48,6 +48,7 @@ use crate::thread_state::ConnectionCapabilities;
 use crate::thread_state::ThreadStateManager;
 use crate::transport::AppServerTransport;
 use crate::transport::RemoteControlHandle;
+use crate::usage::UsageTracker;
 use codex_analytics::AnalyticsEventsClient;
 use codex_analytics::AppServerRpcTransport;
 use codex_app_server_protocol::ClientNotification;
@@ -305,6 +306,7 @@ impl MessageProcessor {
             .plugins_manager()
             .set_analytics_events_client(analytics_events_client.clone());
         let skills_watcher = SkillsWatcher::new(thread_manager.skills_service(), outgoing.clone());
+        let usage_tracker = Arc::new(UsageTracker::default());
 
         let pending_thread_unloads = Arc::new(Mutex::new(HashSet::new()));
         let thread_watch_manager =
@@ -319,6 +321,7 @@ impl MessageProcessor {
             outgoing.clone(),
             Arc::clone(&config),
             config_manager.clone(),
+            Arc::clone(&usage_tracker),
         );
         let apps_processor = AppsRequestProcessor::new(
             auth_manager.clone(),
@@ -409,6 +412,7 @@ impl MessageProcessor {
             state_db.clone(),
             log_db,
             Arc::clone(&skills_watcher),
+            Arc::clone(&usage_tracker),
             config_warnings,
         );
         let turn_processor = TurnRequestProcessor::new(
@@ -424,6 +428,7 @@ impl MessageProcessor {
             thread_watch_manager,
             thread_list_state_permit,
             Arc::clone(&skills_watcher),
+            usage_tracker,
         );
         if matches!(plugin_startup_tasks, crate::PluginStartupTasks::Start) {
             // Keep plugin startup warmups aligned at app-server startup.
@@ -1005,6 +1010,31 @@ impl MessageProcessor {
                 .model_provider_capabilities_read()
                 .await
                 .map(|response| Some(response.into())),
+            ClientRequest::StatuslineGetConfig { params, .. } => self
+                .config_processor
+                .statusline_get_config(params)
+                .await
+                .map(|response| Some(response.into())),
+            ClientRequest::StatuslineSetConfig { params, .. } => self
+                .config_processor
+                .statusline_set_config(params)
+                .await
+                .map(|response| Some(response.into())),
+            ClientRequest::StatuslineListThemes { params, .. } => self
+                .config_processor
+                .statusline_list_themes(params)
+                .await
+                .map(|response| Some(response.into())),
+            ClientRequest::TranslationTest { params, .. } => self
+                .config_processor
+                .translation_test(params)
+                .await
+                .map(|response| Some(response.into())),
+            ClientRequest::BuiltinPluginsList { params, .. } => self
+                .config_processor
+                .builtin_plugins_list(params)
+                .await
+                .map(|response| Some(response.into())),
             ClientRequest::ThreadStart { params, .. } => {
                 self.thread_processor
                     .thread_start(
@@ -1219,6 +1249,15 @@ impl MessageProcessor {
             ClientRequest::ModelList { params, .. } => {
                 self.catalog_processor.model_list(params).await
             }
+            ClientRequest::ModelGet { params, .. } => {
+                self.catalog_processor.model_get(params).await
+            }
+            ClientRequest::ModelResolve { params, .. } => {
+                self.catalog_processor.model_resolve(params).await
+            }
+            ClientRequest::ModelsRefresh { params, .. } => {
+                self.catalog_processor.models_refresh(params).await
+            }
             ClientRequest::ExperimentalFeatureList { params, .. } => {
                 self.catalog_processor
                     .experimental_feature_list(params)
@@ -1337,6 +1376,9 @@ impl MessageProcessor {
             ClientRequest::GetAccountRateLimits { .. } => {
                 self.account_processor.get_account_rate_limits().await
             }
+            ClientRequest::UsageGet { params, .. } => {
+                self.account_processor.usage_get(params).await
+            }
             ClientRequest::ConsumeAccountRateLimitResetCredit { params, .. } => {
                 self.account_processor
                     .consume_account_rate_limit_reset_credit(params)
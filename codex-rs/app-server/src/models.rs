@@ -15,16 +15,30 @@ pub async fn supported_models(
     include_hidden: bool,
     http_client_factory: HttpClientFactory,
 ) -> Vec<Model> {
+    let catalog = supported_model_presets(thread_manager, true, http_client_factory).await;
+    catalog
+        .clone()
+        .into_iter()
+        .filter(|preset| include_hidden || preset.show_in_picker)
+        .map(|preset| model_from_preset(preset, &catalog))
+        .collect()
+}
+
+pub async fn supported_model_presets(
+    thread_manager: Arc<ThreadManager>,
+    include_hidden: bool,
+    http_client_factory: HttpClientFactory,
+) -> Vec<ModelPreset> {
     thread_manager
         .list_models(RefreshStrategy::OnlineIfUncached, http_client_factory)
         .await
         .into_iter()
         .filter(|preset| include_hidden || preset.show_in_picker)
-        .map(model_from_preset)
         .collect()
 }
 
-fn model_from_preset(preset: ModelPreset) -> Model {
+pub fn model_from_preset(preset: ModelPreset, catalog: &[ModelPreset]) -> Model {
+    let supports_reasoning = !preset.supported_reasoning_efforts.is_empty();
     Model {
         id: preset.id.to_string(),
         model: preset.model.to_string(),
@@ -35,6 +49,9 @@ fn model_from_preset(preset: ModelPreset) -> Model {
             model_link: upgrade.model_link.clone(),
             migration_markdown: upgrade.migration_markdown.clone(),
         }),
+        deprecated: preset.deprecated,
+        deprecation_message: preset.deprecation_message.clone(),
+        upgrade_chain: preset.upgrade_chain(catalog),
         availability_nux: preset.availability_nux.map(Into::into),
         display_name: preset.display_name.to_string(),
         description: preset.description.to_string(),
@@ -42,7 +59,7 @@ fn model_from_preset(preset: ModelPreset) -> Model {
         supported_reasoning_efforts: reasoning_efforts_from_preset(
             preset.supported_reasoning_efforts,
         ),
-        default_reasoning_effort: preset.default_reasoning_effort,
+        default_reasoning_effort: supports_reasoning.then_some(preset.default_reasoning_effort),
         input_modalities: preset.input_modalities,
         supports_personality: preset.supports_personality,
         additional_speed_tiers: preset.additional_speed_tiers,
@@ -56,6 +73,10 @@ fn model_from_preset(preset: ModelPreset) -> Model {
             })
             .collect(),
         default_service_tier: preset.default_service_tier,
+        context_window: preset.context_window,
+        max_output_tokens: preset.max_output_tokens,
+        supports_parallel_tool_calls: preset.supports_parallel_tool_calls,
+        supports_reasoning_summaries: preset.supports_reasoning_summaries,
         is_default: preset.is_default,
     }
 }
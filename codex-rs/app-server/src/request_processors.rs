@@ -4,6 +4,8 @@ use crate::command_exec::StartCommandExecParams;
 use crate::config_manager::ConfigManager;
 use crate::error_code::INPUT_TOO_LARGE_ERROR_CODE;
 use crate::error_code::invalid_params;
+use crate::models::model_from_preset;
+use crate::models::supported_model_presets;
 use crate::models::supported_models;
 use crate::outgoing_message::ConnectionId;
 use crate::outgoing_message::ConnectionRequestId;
@@ -13,8 +15,10 @@ use crate::outgoing_message::ThreadScopedOutgoingMessageSender;
 use crate::skills_watcher::SkillsWatcher;
 use crate::thread_status::ThreadWatchManager;
 use crate::thread_status::resolve_thread_status;
+use crate::usage::UsageTracker;
 use chrono::Duration as ChronoDuration;
 use chrono::SecondsFormat;
+use chrono::Utc;
 use codex_analytics::AnalyticsEventsClient;
 use codex_analytics::AnalyticsJsonRpcError;
 use codex_analytics::InputError;
@@ -116,8 +120,19 @@ use codex_app_server_protocol::McpServerToolCallResponse;
 use codex_app_server_protocol::MemoryResetResponse;
 use codex_app_server_protocol::MockExperimentalMethodParams;
 use codex_app_server_protocol::MockExperimentalMethodResponse;
+use codex_app_server_protocol::Model;
+use codex_app_server_protocol::ModelAliasKind;
+use codex_app_server_protocol::ModelGetParams;
+use codex_app_server_protocol::ModelGetResponse;
+use codex_app_server_protocol::ModelListMeta;
 use codex_app_server_protocol::ModelListParams;
 use codex_app_server_protocol::ModelListResponse;
+use codex_app_server_protocol::ModelResolveParams;
+use codex_app_server_protocol::ModelResolveResponse;
+use codex_app_server_protocol::ModelSortBy;
+use codex_app_server_protocol::ModelsRefreshParams;
+use codex_app_server_protocol::ModelsRefreshResponse;
+use codex_app_server_protocol::ModelsUpdatedNotification;
 use codex_app_server_protocol::PermissionProfileListParams;
 use codex_app_server_protocol::PermissionProfileListResponse;
 use codex_app_server_protocol::PermissionProfileSummary;
@@ -275,6 +290,7 @@ use codex_app_server_protocol::ThreadUnarchivedNotification;
 use codex_app_server_protocol::ThreadUnsubscribeParams;
 use codex_app_server_protocol::ThreadUnsubscribeResponse;
 use codex_app_server_protocol::ThreadUnsubscribeStatus;
+use codex_app_server_protocol::ThreadUsage;
 use codex_app_server_protocol::Turn;
 use codex_app_server_protocol::TurnEnvironmentParams;
 use codex_app_server_protocol::TurnError;
@@ -286,6 +302,9 @@ use codex_app_server_protocol::TurnStartResponse;
 use codex_app_server_protocol::TurnStatus;
 use codex_app_server_protocol::TurnSteerParams;
 use codex_app_server_protocol::TurnSteerResponse;
+use codex_app_server_protocol::UsageGetParams;
+use codex_app_server_protocol::UsageGetResponse;
+use codex_app_server_protocol::UsageRateLimits;
 use codex_app_server_protocol::UserInput as V2UserInput;
 use codex_app_server_protocol::WindowsSandboxReadiness;
 use codex_app_server_protocol::WindowsSandboxReadinessResponse;
@@ -401,6 +420,7 @@ use codex_mcp::resolve_oauth_scopes;
 use codex_memories_write::clear_memory_roots_contents;
 use codex_model_provider::create_model_provider;
 use codex_models_manager::collaboration_mode_presets::builtin_collaboration_mode_presets;
+use codex_models_manager::manager::RefreshStrategy;
 use codex_protocol::ThreadId;
 use codex_protocol::config_types::CollaborationMode;
 use codex_protocol::config_types::ForcedLoginMethod;
@@ -413,6 +433,7 @@ use codex_protocol::error::Result as CodexResult;
 #[cfg(test)]
 use codex_protocol::items::TurnItem;
 use codex_protocol::models::ResponseItem;
+use codex_protocol::openai_models::ModelPreset;
 use codex_protocol::openai_models::ReasoningEffort;
 #[cfg(test)]
 use codex_protocol::permissions::FileSystemSandboxPolicy;
@@ -543,8 +564,10 @@ pub(crate) use thread_processor::ThreadRequestProcessor;
 pub(crate) use turn_processor::TurnRequestProcessor;
 pub(crate) use windows_sandbox_processor::WindowsSandboxRequestProcessor;
 
+use crate::error_code::cursor_expired;
 use crate::error_code::internal_error;
 use crate::error_code::invalid_request;
+use crate::error_code::not_found;
 use crate::filters::compute_source_filters;
 use crate::filters::source_kind_matches;
 use crate::thread_state::ConnectionCapabilities;
@@ -70,6 +70,7 @@ pub(crate) struct AccountRequestProcessor {
     outgoing: Arc<OutgoingMessageSender>,
     config: Arc<Config>,
     config_manager: ConfigManager,
+    usage_tracker: Arc<UsageTracker>,
     active_login: Arc<Mutex<Option<ActiveLogin>>>,
 }
 
@@ -80,6 +81,7 @@ impl AccountRequestProcessor {
         outgoing: Arc<OutgoingMessageSender>,
         config: Arc<Config>,
         config_manager: ConfigManager,
+        usage_tracker: Arc<UsageTracker>,
     ) -> Self {
         Self {
             auth_manager,
@@ -87,6 +89,7 @@ impl AccountRequestProcessor {
             outgoing,
             config,
             config_manager,
+            usage_tracker,
             active_login: Arc::new(Mutex::new(None)),
         }
     }
@@ -149,6 +152,53 @@ impl AccountRequestProcessor {
             .map(|response| Some(response.into()))
     }
 
+    pub(crate) async fn usage_get(
+        &self,
+        params: UsageGetParams,
+    ) -> Result<Option<ClientResponsePayload>, JSONRPCErrorError> {
+        let UsageGetParams {} = params;
+        self.usage_get_response()
+            .await
+            .map(|response| Some(response.into()))
+    }
+
+    async fn usage_get_response(&self) -> Result<UsageGetResponse, JSONRPCErrorError> {
+        let mut thread_ids = self.thread_manager.list_thread_ids().await;
+        thread_ids.sort();
+
+        let mut threads = Vec::new();
+        let mut fallback_rate_limits = None;
+        for thread_id in thread_ids {
+            let Ok(conversation) = self.thread_manager.get_thread(thread_id).await else {
+                continue;
+            };
+            let (info, rate_limits) = conversation.token_info_and_rate_limits().await;
+            if let Some(info) = info {
+                threads.push(ThreadUsage {
+                    thread_id: thread_id.to_string(),
+                    token_usage: info.into(),
+                });
+            }
+            if fallback_rate_limits.is_none() {
+                fallback_rate_limits = rate_limits;
+            }
+        }
+
+        // Prefer the snapshot recorded from live token-count events; fall back
+        // to per-thread caches (populated on resume) when none arrived yet.
+        let rate_limits = self
+            .usage_tracker
+            .latest_rate_limits()
+            .await
+            .or(fallback_rate_limits)
+            .map(UsageRateLimits::from);
+
+        Ok(UsageGetResponse {
+            rate_limits,
+            threads,
+        })
+    }
+
     pub(crate) async fn get_workspace_messages(
         &self,
     ) -> Result<Option<ClientResponsePayload>, JSONRPCErrorError> {
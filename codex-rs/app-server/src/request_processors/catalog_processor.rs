@@ -1,6 +1,12 @@
 use super::*;
+use codex_config::ProfileV2Name;
 use codex_core::config::permission_profile_catalog;
+use codex_core::config::resolve_profile_v2_config_path;
+use codex_models_manager::resolve::ModelAliasKind as CoreModelAliasKind;
+use codex_models_manager::resolve::ResolveModelError;
 use futures::StreamExt;
+use sha2::Digest;
+use sha2::Sha256;
 
 #[derive(Clone)]
 pub(crate) struct CatalogRequestProcessor {
@@ -11,6 +17,15 @@ pub(crate) struct CatalogRequestProcessor {
     pub(super) config: Arc<Config>,
     pub(super) config_manager: ConfigManager,
     pub(super) workspace_settings_cache: Arc<workspace_settings::WorkspaceSettingsCache>,
+    /// Serializes `models/refresh` calls so concurrent requests coalesce into
+    /// a single fetch; holds the outcome of the most recent refresh.
+    models_refresh_state: Arc<Mutex<Option<ModelsRefreshOutcome>>>,
+}
+
+#[derive(Clone)]
+struct ModelsRefreshOutcome {
+    finished_at: Instant,
+    response: ModelsRefreshResponse,
 }
 
 const SKILLS_LIST_CWD_CONCURRENCY: usize = 5;
@@ -96,6 +111,95 @@ fn errors_to_info(
         .collect()
 }
 
+/// Page-size bounds for `model/list`; requests outside the range are rejected
+/// so a `limit` of 0 can never produce a non-advancing page.
+const MODEL_LIST_MIN_LIMIT: u32 = 1;
+const MODEL_LIST_MAX_LIMIT: u32 = 100;
+/// Page size used when `model/list` is called without an explicit `limit`.
+const MODEL_LIST_DEFAULT_LIMIT: usize = 20;
+
+/// Server-side model/list filters, applied before pagination.
+/// The catalog is OpenAI-only today, so any other provider matches nothing.
+fn model_matches_filters(
+    model: &Model,
+    provider: Option<&str>,
+    supports_reasoning: Option<bool>,
+    id_prefix: Option<&str>,
+) -> bool {
+    if let Some(provider) = provider
+        && !provider.eq_ignore_ascii_case("openai")
+    {
+        return false;
+    }
+    if let Some(supports_reasoning) = supports_reasoning
+        && model.supported_reasoning_efforts.is_empty() == supports_reasoning
+    {
+        return false;
+    }
+    if let Some(id_prefix) = id_prefix
+        && !model.id.starts_with(id_prefix)
+    {
+        return false;
+    }
+    true
+}
+
+fn model_list_sort_token(sort_by: ModelSortBy, order: SortDirection) -> String {
+    let sort_by = match sort_by {
+        ModelSortBy::Priority => "priority",
+        ModelSortBy::Id => "id",
+        ModelSortBy::ReleaseDate => "release_date",
+    };
+    let order = match order {
+        SortDirection::Asc => "asc",
+        SortDirection::Desc => "desc",
+    };
+    format!("{sort_by}:{order}")
+}
+
+/// Fingerprints the filtered, sorted list together with the parameters that
+/// shaped it. Pagination inputs are deliberately excluded so every page of the
+/// same view shares one etag; a catalog refresh or a different filter changes
+/// the hash.
+fn model_list_etag(
+    models: &[Model],
+    include_hidden: bool,
+    provider: Option<&str>,
+    supports_reasoning: Option<bool>,
+    id_prefix: Option<&str>,
+    sort_token: &str,
+) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(serde_json::to_vec(models).unwrap_or_default());
+    hasher.update(format!(
+        "include_hidden:{include_hidden};provider:{provider:?};supports_reasoning:{supports_reasoning:?};id_prefix:{id_prefix:?};sort:{sort_token}"
+    ));
+    let hash = hasher.finalize();
+    hash.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+/// Decoded `model/list` cursor: the snapshot fingerprint it was minted
+/// against plus the pagination offset within that snapshot.
+struct ModelListCursor {
+    etag: String,
+    offset: usize,
+}
+
+/// Returns the decoded cursor when it was created under the sort identified
+/// by `sort_token`; `None` covers both malformed cursors and cursors created
+/// under a different sort. Snapshot staleness is the caller's check — a
+/// stale cursor is well-formed, it just references a replaced snapshot.
+fn parse_model_list_cursor(cursor: &str, sort_token: &str) -> Option<ModelListCursor> {
+    let (etag, offset) = cursor
+        .strip_prefix(sort_token)?
+        .strip_prefix(':')?
+        .split_once(':')?;
+    Some(ModelListCursor {
+        etag: etag.to_string(),
+        offset: offset.parse::<usize>().ok()?,
+    })
+}
+
 impl CatalogRequestProcessor {
     pub(crate) fn new(
         outgoing: Arc<OutgoingMessageSender>,
@@ -114,6 +218,7 @@ impl CatalogRequestProcessor {
             config,
             config_manager,
             workspace_settings_cache,
+            models_refresh_state: Arc::new(Mutex::new(None)),
         }
     }
 
@@ -157,15 +262,90 @@ impl CatalogRequestProcessor {
         &self,
         params: ModelListParams,
     ) -> Result<Option<ClientResponsePayload>, JSONRPCErrorError> {
+        let pinned_default_model = self
+            .profile_pinned_model(params.config_profile.as_deref())
+            .await?;
         Self::list_models(
             self.thread_manager.clone(),
             self.config.http_client_factory(),
             params,
+            pinned_default_model,
         )
         .await
         .map(|response| Some(response.into()))
     }
 
+    pub(crate) async fn model_get(
+        &self,
+        params: ModelGetParams,
+    ) -> Result<Option<ClientResponsePayload>, JSONRPCErrorError> {
+        let pinned_default_model = self
+            .profile_pinned_model(params.config_profile.as_deref())
+            .await?;
+        Self::get_model(
+            self.thread_manager.clone(),
+            self.config.http_client_factory(),
+            params,
+            pinned_default_model,
+        )
+        .await
+        .map(|response| Some(response.into()))
+    }
+
+    /// Resolves the model pinned by a named config profile
+    /// (`<name>.config.toml` under `CODEX_HOME`). Returns `None` both when no
+    /// profile was requested and when the profile does not pin a model.
+    async fn profile_pinned_model(
+        &self,
+        config_profile: Option<&str>,
+    ) -> Result<Option<String>, JSONRPCErrorError> {
+        let Some(config_profile) = config_profile else {
+            return Ok(None);
+        };
+        let profile: ProfileV2Name = config_profile.parse().map_err(|err| {
+            invalid_request(format!("invalid config profile `{config_profile}`: {err}"))
+        })?;
+        let profile_config_path =
+            resolve_profile_v2_config_path(self.config_manager.codex_home(), &profile);
+        if !profile_config_path.as_path().exists() {
+            return Err(invalid_request(format!(
+                "config profile not found: {config_profile}"
+            )));
+        }
+        let config = self
+            .config_manager
+            .load_latest_config_for_profile(&profile)
+            .await
+            .map_err(|err| {
+                internal_error(format!(
+                    "failed to load config profile `{config_profile}`: {err}"
+                ))
+            })?;
+        Ok(config.model)
+    }
+
+    pub(crate) async fn model_resolve(
+        &self,
+        params: ModelResolveParams,
+    ) -> Result<Option<ClientResponsePayload>, JSONRPCErrorError> {
+        Self::resolve_model(
+            self.thread_manager.clone(),
+            self.config.http_client_factory(),
+            params,
+        )
+        .await
+        .map(|response| Some(response.into()))
+    }
+
+    pub(crate) async fn models_refresh(
+        &self,
+        params: ModelsRefreshParams,
+    ) -> Result<Option<ClientResponsePayload>, JSONRPCErrorError> {
+        self.models_refresh_response(params)
+            .await
+            .map(|response| Some(response.into()))
+    }
+
     pub(crate) async fn experimental_feature_list(
         &self,
         params: ExperimentalFeatureListParams,
@@ -253,33 +433,178 @@ impl CatalogRequestProcessor {
         thread_manager: Arc<ThreadManager>,
         http_client_factory: codex_http_client::HttpClientFactory,
         params: ModelListParams,
+        pinned_default_model: Option<String>,
     ) -> Result<ModelListResponse, JSONRPCErrorError> {
         let ModelListParams {
             limit,
             cursor,
             include_hidden,
+            provider,
+            supports_reasoning,
+            id_prefix,
+            sort_by,
+            order,
+            if_none_match,
+            // Resolved to `pinned_default_model` by the caller.
+            config_profile: _,
         } = params;
-        let models = supported_models(
-            thread_manager,
-            include_hidden.unwrap_or(false),
-            http_client_factory,
-        )
-        .await;
+        let sort_by = sort_by.unwrap_or(ModelSortBy::Priority);
+        let order = order.unwrap_or(SortDirection::Asc);
+        // Bounds are checked before any catalog work so an invalid page size
+        // fails the same way regardless of catalog state.
+        let effective_limit = match limit {
+            Some(limit) if !(MODEL_LIST_MIN_LIMIT..=MODEL_LIST_MAX_LIMIT).contains(&limit) => {
+                return Err(invalid_request(format!(
+                    "limit must be between {MODEL_LIST_MIN_LIMIT} and {MODEL_LIST_MAX_LIMIT}: {limit}"
+                )));
+            }
+            Some(limit) => limit as usize,
+            None => MODEL_LIST_DEFAULT_LIMIT,
+        };
+        // Resolve upgrade chains against the full catalog so chains can pass
+        // through models hidden from the picker.
+        let models_manager = thread_manager.get_models_manager();
+        let catalog = supported_model_presets(thread_manager, true, http_client_factory).await;
+        // Read the source and freshness after the catalog fetch so they
+        // reflect the load that produced this snapshot.
+        let source = models_manager.catalog_source().await.into();
+        let cache_fetched_at = models_manager
+            .catalog_fetched_at()
+            .await
+            .map(|fetched_at| fetched_at.to_rfc3339_opts(SecondsFormat::Millis, true));
+        let include_hidden = include_hidden.unwrap_or(false);
+        // Hidden models stay in the working set until after filtering so
+        // `hiddenCount` reports how many matched overall, not per page.
+        let mut presets: Vec<ModelPreset> = catalog.clone();
+        // Sort before filtering and pagination; the sorts are stable so ties
+        // keep the catalog priority order.
+        let descending = matches!(order, SortDirection::Desc);
+        match sort_by {
+            ModelSortBy::Priority => {
+                // The catalog is already ranked by priority.
+                if descending {
+                    presets.reverse();
+                }
+            }
+            ModelSortBy::Id => presets.sort_by(|a, b| {
+                let ordering = a.id.cmp(&b.id);
+                if descending {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            }),
+            ModelSortBy::ReleaseDate => presets.sort_by(|a, b| {
+                // Models without a release date sort last in either direction.
+                match (&a.release_date, &b.release_date) {
+                    (Some(a), Some(b)) => {
+                        let ordering = a.cmp(b);
+                        if descending {
+                            ordering.reverse()
+                        } else {
+                            ordering
+                        }
+                    }
+                    (Some(_), None) => std::cmp::Ordering::Less,
+                    (None, Some(_)) => std::cmp::Ordering::Greater,
+                    (None, None) => std::cmp::Ordering::Equal,
+                }
+            }),
+        }
+        // Filter before pagination so cursors stay consistent within a
+        // filtered view.
+        let matching: Vec<(bool, Model)> = presets
+            .into_iter()
+            .map(|preset| {
+                let hidden = !preset.show_in_picker;
+                (hidden, model_from_preset(preset, &catalog))
+            })
+            .filter(|(_, model)| {
+                model_matches_filters(
+                    model,
+                    provider.as_deref(),
+                    supports_reasoning,
+                    id_prefix.as_deref(),
+                )
+            })
+            .collect();
+        let hidden_count = matching.iter().filter(|(hidden, _)| *hidden).count();
+        let mut models: Vec<Model> = matching
+            .into_iter()
+            .filter(|(hidden, _)| include_hidden || !hidden)
+            .map(|(_, model)| model)
+            .collect();
+        // A profile that pins a model moves the default flag onto it; a pinned
+        // model outside the catalog leaves the catalog default untouched. This
+        // happens before the etag is computed so each profile gets its own
+        // fingerprint.
+        if let Some(pinned) = pinned_default_model.as_deref() {
+            let pinned_index = models
+                .iter()
+                .position(|model| model.id == pinned)
+                .or_else(|| models.iter().position(|model| model.model == pinned));
+            if let Some(pinned_index) = pinned_index {
+                for (index, model) in models.iter_mut().enumerate() {
+                    model.is_default = index == pinned_index;
+                }
+            }
+        }
         let total = models.len();
+        // Built before any pagination shortcut so every page — including
+        // `notModified` and empty responses — reports the same metadata.
+        let meta = ModelListMeta {
+            total: total as u32,
+            hidden_count: hidden_count as u32,
+            cache_fetched_at,
+            source,
+        };
+
+        // Cursors encode the sort they were created under so a cursor from
+        // one sort cannot silently produce inconsistent pages in another.
+        let sort_token = model_list_sort_token(sort_by, order);
+        let etag = model_list_etag(
+            &models,
+            include_hidden,
+            provider.as_deref(),
+            supports_reasoning,
+            id_prefix.as_deref(),
+            &sort_token,
+        );
+        if if_none_match.as_deref() == Some(etag.as_str()) {
+            return Ok(ModelListResponse {
+                data: Vec::new(),
+                next_cursor: None,
+                etag,
+                not_modified: true,
+                meta: meta.clone(),
+            });
+        }
 
         if total == 0 {
             return Ok(ModelListResponse {
                 data: Vec::new(),
                 next_cursor: None,
+                etag,
+                not_modified: false,
+                meta: meta.clone(),
             });
         }
 
-        let effective_limit = limit.unwrap_or(total as u32).max(1) as usize;
         let effective_limit = effective_limit.min(total);
         let start = match cursor {
-            Some(cursor) => cursor
-                .parse::<usize>()
-                .map_err(|_| invalid_request(format!("invalid cursor: {cursor}")))?,
+            Some(cursor) => {
+                let parsed = parse_model_list_cursor(&cursor, &sort_token)
+                    .ok_or_else(|| invalid_request(format!("invalid cursor: {cursor}")))?;
+                // A cursor is only valid against the snapshot it was minted
+                // from; serving it against a refreshed catalog could skip or
+                // duplicate entries, so stale cursors fail loudly instead.
+                if parsed.etag != etag {
+                    return Err(cursor_expired(
+                        "cursor references a replaced model list snapshot; restart pagination from the first page",
+                    ));
+                }
+                parsed.offset
+            }
             None => 0,
         };
 
@@ -291,17 +616,143 @@ impl CatalogRequestProcessor {
 
         let end = start.saturating_add(effective_limit).min(total);
         let items = models[start..end].to_vec();
+        // Embed the snapshot fingerprint so the next page can detect a
+        // catalog refresh that happened in between.
         let next_cursor = if end < total {
-            Some(end.to_string())
+            Some(format!("{sort_token}:{etag}:{end}"))
         } else {
             None
         };
         Ok(ModelListResponse {
             data: items,
             next_cursor,
+            etag,
+            not_modified: false,
+            meta,
+        })
+    }
+
+    async fn get_model(
+        thread_manager: Arc<ThreadManager>,
+        http_client_factory: codex_http_client::HttpClientFactory,
+        params: ModelGetParams,
+        pinned_default_model: Option<String>,
+    ) -> Result<ModelGetResponse, JSONRPCErrorError> {
+        let ModelGetParams {
+            id,
+            // Resolved to `pinned_default_model` by the caller.
+            config_profile: _,
+        } = params;
+        // Include hidden models so snapshots that are not in the picker still
+        // resolve.
+        let models = supported_models(
+            thread_manager,
+            /*include_hidden*/ true,
+            http_client_factory,
+        )
+        .await;
+        // Exact id first, then the model slug as an alias; both forms return
+        // the same record.
+        models
+            .iter()
+            .find(|model| model.id == id)
+            .or_else(|| models.iter().find(|model| model.model == id))
+            .cloned()
+            .map(|mut model| {
+                // Mirror `list_models`: a profile pinning a model decides the
+                // default flag for that profile's view.
+                if let Some(pinned) = pinned_default_model.as_deref() {
+                    model.is_default = model.id == pinned || model.model == pinned;
+                }
+                ModelGetResponse { model }
+            })
+            .ok_or_else(|| not_found(format!("model not found: {id}")))
+    }
+
+    async fn resolve_model(
+        thread_manager: Arc<ThreadManager>,
+        http_client_factory: codex_http_client::HttpClientFactory,
+        params: ModelResolveParams,
+    ) -> Result<ModelResolveResponse, JSONRPCErrorError> {
+        let ModelResolveParams { query } = params;
+        // Resolve against the full catalog so hidden snapshots canonicalize
+        // the same way they do for `model/get`.
+        let catalog = supported_model_presets(thread_manager, true, http_client_factory).await;
+        let resolved = codex_models_manager::resolve::resolve_model(&query, &catalog).map_err(
+            |err| match err {
+                ResolveModelError::Ambiguous { candidates } => invalid_request(format!(
+                    "ambiguous model reference `{query}`; candidates: {}",
+                    candidates.join(", ")
+                )),
+                ResolveModelError::NotFound { suggestions } => {
+                    if suggestions.is_empty() {
+                        not_found(format!("model not found: {query}"))
+                    } else {
+                        not_found(format!(
+                            "model not found: {query}; closest matches: {}",
+                            suggestions.join(", ")
+                        ))
+                    }
+                }
+            },
+        )?;
+        let alias_kind = match resolved.alias_kind {
+            CoreModelAliasKind::Exact => ModelAliasKind::Exact,
+            CoreModelAliasKind::FamilyLatest => ModelAliasKind::FamilyLatest,
+            CoreModelAliasKind::Prefix => ModelAliasKind::Prefix,
+        };
+        let model = model_from_preset(resolved.preset, &catalog);
+        Ok(ModelResolveResponse {
+            id: model.id.clone(),
+            alias_kind,
+            model,
         })
     }
 
+    async fn models_refresh_response(
+        &self,
+        params: ModelsRefreshParams,
+    ) -> Result<ModelsRefreshResponse, JSONRPCErrorError> {
+        let ModelsRefreshParams {} = params;
+        let requested_at = Instant::now();
+        let mut state = self.models_refresh_state.lock().await;
+        if let Some(outcome) = state.as_ref()
+            && outcome.finished_at >= requested_at
+        {
+            // Another request finished a refresh while we waited for the
+            // lock; its result is at least as fresh as what we would fetch.
+            return Ok(outcome.response.clone());
+        }
+
+        let models_manager = self.thread_manager.get_models_manager();
+        let before = models_manager.get_remote_models().await;
+        let presets = self
+            .thread_manager
+            .list_models(RefreshStrategy::Online, self.config.http_client_factory())
+            .await;
+        let after = models_manager.get_remote_models().await;
+
+        let response = ModelsRefreshResponse {
+            updated: before != after,
+            model_count: presets.len() as u32,
+            fetched_at: Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true),
+        };
+        *state = Some(ModelsRefreshOutcome {
+            finished_at: Instant::now(),
+            response: response.clone(),
+        });
+        drop(state);
+
+        if response.updated {
+            self.outgoing
+                .send_server_notification(ServerNotification::ModelsUpdated(
+                    ModelsUpdatedNotification {},
+                ))
+                .await;
+        }
+        Ok(response)
+    }
+
     async fn list_collaboration_modes(
         thread_manager: Arc<ThreadManager>,
         params: CollaborationModeListParams,
@@ -6,7 +6,13 @@ use crate::error_code::internal_error;
 use crate::error_code::invalid_request;
 use crate::outgoing_message::ConnectionRequestId;
 use crate::outgoing_message::OutgoingMessageSender;
+use crate::statusline;
+use crate::translation;
 use codex_analytics::AnalyticsEventsClient;
+use codex_app_server_protocol::BuiltinPluginId;
+use codex_app_server_protocol::BuiltinPluginInfo;
+use codex_app_server_protocol::BuiltinPluginsListParams;
+use codex_app_server_protocol::BuiltinPluginsListResponse;
 use codex_app_server_protocol::ClientResponsePayload;
 use codex_app_server_protocol::ComputerUseRequirements;
 use codex_app_server_protocol::ConfigBatchWriteParams;
@@ -30,6 +36,14 @@ use codex_app_server_protocol::NetworkRequirements;
 use codex_app_server_protocol::NetworkUnixSocketPermission;
 use codex_app_server_protocol::NewThreadModelDefaults;
 use codex_app_server_protocol::SandboxMode;
+use codex_app_server_protocol::StatuslineGetConfigParams;
+use codex_app_server_protocol::StatuslineGetConfigResponse;
+use codex_app_server_protocol::StatuslineListThemesParams;
+use codex_app_server_protocol::StatuslineListThemesResponse;
+use codex_app_server_protocol::StatuslineSetConfigParams;
+use codex_app_server_protocol::StatuslineSetConfigResponse;
+use codex_app_server_protocol::TranslationTestParams;
+use codex_app_server_protocol::TranslationTestResponse;
 use codex_app_server_protocol::WindowsSandboxSetupMode;
 use codex_config::ConfigRequirementsToml;
 use codex_config::HookEventsToml;
@@ -46,6 +60,7 @@ use codex_plugin::PluginId;
 use codex_protocol::config_types::WebSearchMode;
 use serde_json::json;
 use std::path::PathBuf;
+use tokio::sync::Mutex;
 
 const SUPPORTED_EXPERIMENTAL_FEATURE_ENABLEMENT: &[&str] = &[
     "auth_elicitation",
@@ -62,6 +77,9 @@ pub(crate) struct ConfigRequestProcessor {
     config_manager: ConfigManager,
     thread_manager: Arc<ThreadManager>,
     analytics_events_client: AnalyticsEventsClient,
+    /// Most recent `translation/test` outcome, surfaced by
+    /// `builtinPlugins/list` as the translation health check.
+    last_translation_health: Arc<Mutex<Option<TranslationTestResponse>>>,
 }
 
 impl ConfigRequestProcessor {
@@ -76,6 +94,7 @@ impl ConfigRequestProcessor {
             config_manager,
             thread_manager,
             analytics_events_client,
+            last_translation_health: Arc::new(Mutex::new(None)),
         }
     }
 
@@ -169,6 +188,83 @@ impl ConfigRequestProcessor {
         })
     }
 
+    pub(crate) async fn statusline_get_config(
+        &self,
+        params: StatuslineGetConfigParams,
+    ) -> Result<StatuslineGetConfigResponse, JSONRPCErrorError> {
+        let StatuslineGetConfigParams {} = params;
+        let config = statusline::read_config(self.config_manager.codex_home())
+            .map_err(|err| internal_error(format!("failed to read statusline config: {err}")))?;
+        Ok(StatuslineGetConfigResponse { config })
+    }
+
+    pub(crate) async fn statusline_set_config(
+        &self,
+        params: StatuslineSetConfigParams,
+    ) -> Result<StatuslineSetConfigResponse, JSONRPCErrorError> {
+        let StatuslineSetConfigParams { config } = params;
+        let errors = statusline::validate_config(&config);
+        if !errors.is_empty() {
+            return Ok(StatuslineSetConfigResponse {
+                applied: false,
+                errors,
+            });
+        }
+        statusline::write_config(self.config_manager.codex_home(), &config)
+            .map_err(|err| internal_error(format!("failed to write statusline config: {err}")))?;
+        Ok(StatuslineSetConfigResponse {
+            applied: true,
+            errors: Vec::new(),
+        })
+    }
+
+    pub(crate) async fn statusline_list_themes(
+        &self,
+        params: StatuslineListThemesParams,
+    ) -> Result<StatuslineListThemesResponse, JSONRPCErrorError> {
+        let StatuslineListThemesParams {} = params;
+        let themes = statusline::list_themes(self.config_manager.codex_home())
+            .map_err(|err| internal_error(format!("failed to list statusline themes: {err}")))?;
+        Ok(StatuslineListThemesResponse { themes })
+    }
+
+    pub(crate) async fn translation_test(
+        &self,
+        params: TranslationTestParams,
+    ) -> Result<TranslationTestResponse, JSONRPCErrorError> {
+        let TranslationTestParams {} = params;
+        // Failures are reported in-band so clients can show a structured
+        // result instead of a generic RPC error.
+        let response = translation::run_test_translation(self.config_manager.codex_home()).await;
+        *self.last_translation_health.lock().await = Some(response.clone());
+        Ok(response)
+    }
+
+    pub(crate) async fn builtin_plugins_list(
+        &self,
+        params: BuiltinPluginsListParams,
+    ) -> Result<BuiltinPluginsListResponse, JSONRPCErrorError> {
+        let BuiltinPluginsListParams {} = params;
+        let codex_home = self.config_manager.codex_home();
+        let statusline_settings = statusline::read_config(codex_home)
+            .map_err(|err| internal_error(format!("failed to read statusline config: {err}")))?;
+        let plugins = vec![
+            BuiltinPluginInfo {
+                id: BuiltinPluginId::Statusline,
+                configured: statusline::config_path(codex_home).exists(),
+                settings: statusline_settings,
+                last_health_check: None,
+            },
+            BuiltinPluginInfo {
+                id: BuiltinPluginId::Translation,
+                configured: translation::is_configured(codex_home),
+                settings: translation::settings_summary(codex_home),
+                last_health_check: self.last_translation_health.lock().await.clone(),
+            },
+        ];
+        Ok(BuiltinPluginsListResponse { plugins })
+    }
+
     pub(crate) async fn handle_config_mutation(&self) {
         self.thread_manager.plugins_manager().clear_cache();
         self.thread_manager.skills_service().clear_cache();
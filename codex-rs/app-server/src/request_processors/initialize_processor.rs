@@ -71,9 +71,14 @@ impl InitializeRequestProcessor {
         let experimental_api_enabled = capabilities.experimental_api;
         let request_attestation = capabilities.request_attestation;
         let supports_openai_form_elicitation = capabilities.mcp_server_openai_form_elicitation;
-        let opt_out_notification_methods = capabilities
+        let mut opt_out_notification_methods = capabilities
             .opt_out_notification_methods
             .unwrap_or_default();
+        // `thread/turnCompleted` is opt-in: suppress it unless the client
+        // requested it via the `threadTurnCompletedNotifications` capability.
+        if !capabilities.thread_turn_completed_notifications {
+            opt_out_notification_methods.push("thread/turnCompleted".to_string());
+        }
         let ClientInfo {
             name,
             title: _title,
@@ -1,4 +1,6 @@
 use super::*;
+use codex_app_server_protocol::ThreadTranslationResultNotification;
+use codex_app_server_protocol::TranslationResultKind;
 use codex_protocol::config_types::MultiAgentMode;
 
 pub(super) const THREAD_UNLOADING_DELAY: Duration = Duration::from_secs(30 * 60);
@@ -14,6 +16,7 @@ pub(super) struct ListenerTaskContext {
     pub(super) fallback_model_provider: String,
     pub(super) codex_home: PathBuf,
     pub(super) skills_watcher: Arc<SkillsWatcher>,
+    pub(super) usage_tracker: Arc<UsageTracker>,
 }
 
 struct UnloadingState {
@@ -271,6 +274,7 @@ pub(super) async fn ensure_listener_task_running(
         thread_list_state_permit,
         fallback_model_provider,
         codex_home,
+        usage_tracker,
         ..
     } = listener_task_context;
     let outgoing_for_task = Arc::clone(&outgoing);
@@ -311,10 +315,13 @@ pub(super) async fn ensure_listener_task_running(
                     // Track the event before emitting any typed translations
                     // so thread-local state such as raw event opt-in stays
                     // synchronized with the conversation.
-                    let raw_events_enabled = {
+                    let (raw_events_enabled, translate_reasoning) = {
                         let mut thread_state = thread_state.lock().await;
                         thread_state.track_current_turn_event(&event.id, &event.msg);
-                        thread_state.experimental_raw_events
+                        (
+                            thread_state.experimental_raw_events,
+                            thread_state.translate_reasoning,
+                        )
                     };
                     if matches!(&event.msg, EventMsg::RawResponseItem(_)) && !raw_events_enabled {
                         continue;
@@ -333,13 +340,22 @@ pub(super) async fn ensure_listener_task_running(
                         conversation_id,
                         conversation.clone(),
                         thread_manager.clone(),
-                        thread_outgoing,
+                        thread_outgoing.clone(),
                         thread_state.clone(),
                         thread_watch_manager.clone(),
                         thread_list_state_permit.clone(),
                         fallback_model_provider.clone(),
+                        usage_tracker.clone(),
                     )
                     .await;
+                    if translate_reasoning {
+                        maybe_spawn_reasoning_translation(
+                            &event.msg,
+                            conversation_id,
+                            codex_home.as_path(),
+                            thread_outgoing,
+                        );
+                    }
                 }
                 unloading_watchers_open = unloading_state.wait_for_unloading_trigger() => {
                     if !unloading_watchers_open {
@@ -770,6 +786,65 @@ pub(super) async fn resolve_pending_server_request(
         .await;
 }
 
+/// Translate the bodies of a completed reasoning item through the configured
+/// translation plugin and stream the results as `thread/translationResult`
+/// notifications. The translation runs on a spawned task so a slow provider
+/// never blocks the event loop; results therefore always arrive after the
+/// corresponding `item/completed` notification.
+fn maybe_spawn_reasoning_translation(
+    msg: &EventMsg,
+    conversation_id: ThreadId,
+    codex_home: &Path,
+    outgoing: ThreadScopedOutgoingMessageSender,
+) {
+    let EventMsg::ItemCompleted(event) = msg else {
+        return;
+    };
+    let TurnItem::Reasoning(reasoning) = &event.item else {
+        return;
+    };
+    if !crate::translation::is_configured(codex_home) {
+        return;
+    }
+    let bodies = [
+        (
+            TranslationResultKind::ReasoningSummary,
+            reasoning.summary_text.join("\n\n"),
+        ),
+        (
+            TranslationResultKind::ReasoningContent,
+            reasoning.raw_content.join("\n\n"),
+        ),
+    ];
+    let item_id = reasoning.id.clone();
+    let codex_home = codex_home.to_path_buf();
+    tokio::spawn(async move {
+        for (kind, text) in bodies {
+            if text.is_empty() {
+                continue;
+            }
+            let (translated_text, code) =
+                match crate::translation::translate_text(&codex_home, &text).await {
+                    Ok(translated) => (Some(translated), None),
+                    Err(failure) => {
+                        tracing::warn!("reasoning translation failed: {}", failure.message);
+                        (None, Some(failure.code))
+                    }
+                };
+            let notification = ThreadTranslationResultNotification {
+                thread_id: conversation_id.to_string(),
+                item_id: item_id.clone(),
+                kind,
+                translated_text,
+                code,
+            };
+            outgoing
+                .send_server_notification(ServerNotification::ThreadTranslationResult(notification))
+                .await;
+        }
+    });
+}
+
 pub(super) fn merge_turn_history_with_active_turn(turns: &mut Vec<Turn>, active_turn: Turn) {
     turns.retain(|turn| turn.id != active_turn.id);
     turns.push(active_turn);
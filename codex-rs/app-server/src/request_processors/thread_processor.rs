@@ -392,6 +392,7 @@ pub(crate) struct ThreadRequestProcessor {
     pub(super) log_db: Option<LogDbLayer>,
     pub(super) background_tasks: TaskTracker,
     pub(super) skills_watcher: Arc<SkillsWatcher>,
+    pub(super) usage_tracker: Arc<UsageTracker>,
     pub(super) initial_config_warnings: Arc<Vec<ConfigWarningNotification>>,
 }
 
@@ -424,6 +425,7 @@ impl ThreadRequestProcessor {
         state_db: Option<StateDbHandle>,
         log_db: Option<LogDbLayer>,
         skills_watcher: Arc<SkillsWatcher>,
+        usage_tracker: Arc<UsageTracker>,
         initial_config_warnings: Vec<ConfigWarningNotification>,
     ) -> Self {
         Self {
@@ -443,6 +445,7 @@ impl ThreadRequestProcessor {
             log_db,
             background_tasks: TaskTracker::new(),
             skills_watcher,
+            usage_tracker,
             initial_config_warnings: Arc::new(initial_config_warnings),
         }
     }
@@ -894,6 +897,7 @@ impl ThreadRequestProcessor {
             fallback_model_provider: self.config.model_provider_id.clone(),
             codex_home: self.config.codex_home.to_path_buf(),
             skills_watcher: Arc::clone(&self.skills_watcher),
+            usage_tracker: Arc::clone(&self.usage_tracker),
         }
     }
 
@@ -955,6 +959,7 @@ impl ThreadRequestProcessor {
             selected_capability_roots,
             mock_experimental_field: _mock_experimental_field,
             experimental_raw_events,
+            translate_reasoning,
             personality,
             multi_agent_mode: _multi_agent_mode,
             ephemeral,
@@ -996,6 +1001,7 @@ impl ThreadRequestProcessor {
             fallback_model_provider: self.config.model_provider_id.clone(),
             codex_home: self.config.codex_home.to_path_buf(),
             skills_watcher: Arc::clone(&self.skills_watcher),
+            usage_tracker: Arc::clone(&self.usage_tracker),
         };
         let request_trace = request_context.request_trace();
         let config_manager = self.config_manager.clone();
@@ -1021,6 +1027,7 @@ impl ThreadRequestProcessor {
                 service_name,
                 allow_provider_model_fallback,
                 experimental_raw_events,
+                translate_reasoning,
                 request_trace,
                 initial_config_warnings,
             )
@@ -1098,6 +1105,7 @@ impl ThreadRequestProcessor {
         service_name: Option<String>,
         allow_provider_model_fallback: bool,
         experimental_raw_events: bool,
+        translate_reasoning: bool,
         request_trace: Option<W3cTraceContext>,
         initial_config_warnings: Arc<Vec<ConfigWarningNotification>>,
     ) -> Result<(), JSONRPCErrorError> {
@@ -1295,6 +1303,16 @@ impl ThreadRequestProcessor {
             "thread",
         );
 
+        if translate_reasoning {
+            listener_task_context
+                .thread_state_manager
+                .thread_state(thread_id)
+                .await
+                .lock()
+                .await
+                .set_translate_reasoning(/*enabled*/ true);
+        }
+
         listener_task_context
             .thread_watch_manager
             .upsert_thread_silently(thread.clone())
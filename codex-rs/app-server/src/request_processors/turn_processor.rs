@@ -79,6 +79,7 @@ pub(crate) struct TurnRequestProcessor {
     thread_watch_manager: ThreadWatchManager,
     thread_list_state_permit: Arc<Semaphore>,
     skills_watcher: Arc<SkillsWatcher>,
+    usage_tracker: Arc<UsageTracker>,
 }
 
 fn map_additional_context(
@@ -135,6 +136,7 @@ impl TurnRequestProcessor {
         thread_watch_manager: ThreadWatchManager,
         thread_list_state_permit: Arc<Semaphore>,
         skills_watcher: Arc<SkillsWatcher>,
+        usage_tracker: Arc<UsageTracker>,
     ) -> Self {
         Self {
             auth_manager,
@@ -149,6 +151,7 @@ impl TurnRequestProcessor {
             thread_watch_manager,
             thread_list_state_permit,
             skills_watcher,
+            usage_tracker,
         }
     }
 
@@ -1419,6 +1422,7 @@ impl TurnRequestProcessor {
             fallback_model_provider: self.config.model_provider_id.clone(),
             codex_home: self.config.codex_home.to_path_buf(),
             skills_watcher: Arc::clone(&self.skills_watcher),
+            usage_tracker: Arc::clone(&self.usage_tracker),
         }
     }
 
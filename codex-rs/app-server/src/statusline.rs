@@ -0,0 +1,363 @@
+//! File-level access to the TUI statusline (CxLine) configuration.
+//!
+//! The TUI owns the `CxLineConfig` serde model; the app-server works with the
+//! same `cxline/config.toml` file structurally so GUI clients can read and
+//! replace the config without a dependency on the TUI crate. Validation
+//! mirrors the shape of the serde model and reports per-field errors instead
+//! of failing on the first problem.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+
+use codex_app_server_protocol::StatuslineTheme;
+use codex_app_server_protocol::StatuslineValidationError;
+use codex_utils_json_to_toml::json_to_toml;
+use codex_utils_path::write_atomically;
+use serde_json::Value as JsonValue;
+
+/// Preset themes bundled with the TUI, in picker order. Must stay in sync
+/// with `THEME_NAMES` in the TUI statusline themes module.
+pub const BUILTIN_THEMES: &[&str] = &[
+    "default",
+    "cometix",
+    "minimal",
+    "full",
+    "gruvbox",
+    "nord",
+    "powerline-dark",
+    "powerline-light",
+    "powerline-tokyo-night",
+    "powerline-rose-pine",
+];
+
+const SEGMENT_IDS: &[&str] = &["model", "directory", "git", "context", "usage"];
+const STYLE_MODES: &[&str] = &["plain", "nerd_font", "powerline"];
+const SEPARATOR_BG_MODES: &[&str] = &["none", "previous", "next"];
+const THEME_QUICK_SELECT_MODES: &[&str] = &["alt_digit", "leader"];
+
+/// Path to the statusline config file, `<codex_home>/cxline/config.toml`.
+/// This matches the file the TUI reads under the default `~/.codex` home.
+pub fn config_path(codex_home: &Path) -> PathBuf {
+    codex_home.join("cxline").join("config.toml")
+}
+
+/// Path to the theme directory, `<codex_home>/cxline/themes`.
+pub fn themes_dir(codex_home: &Path) -> PathBuf {
+    codex_home.join("cxline").join("themes")
+}
+
+/// Read the current config as JSON mirroring the `CxLineConfig` serde model.
+/// Returns an empty object when the file does not exist yet; every field of
+/// the serde model is defaulted, so the empty object is the default config.
+pub fn read_config(codex_home: &Path) -> io::Result<JsonValue> {
+    let path = config_path(codex_home);
+    if !path.exists() {
+        return Ok(JsonValue::Object(serde_json::Map::new()));
+    }
+    let content = fs::read_to_string(&path)?;
+    let value: toml::Value = toml::from_str(&content)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))?;
+    serde_json::to_value(value)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))
+}
+
+/// Atomically replace the config file with the given JSON config. Callers
+/// must run [`validate_config`] first; this only performs the write.
+pub fn write_config(codex_home: &Path, config: &JsonValue) -> io::Result<()> {
+    let content = toml::to_string_pretty(&json_to_toml(config.clone()))
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))?;
+    write_atomically(&config_path(codex_home), &content)
+}
+
+/// List bundled preset themes followed by user theme files found on disk.
+pub fn list_themes(codex_home: &Path) -> io::Result<Vec<StatuslineTheme>> {
+    let mut themes: Vec<StatuslineTheme> = BUILTIN_THEMES
+        .iter()
+        .map(|name| StatuslineTheme {
+            name: (*name).to_string(),
+            built_in: true,
+        })
+        .collect();
+
+    let dir = themes_dir(codex_home);
+    let mut user_themes: Vec<String> = Vec::new();
+    if dir.is_dir() {
+        for entry in fs::read_dir(&dir)? {
+            let path = entry?.path();
+            if path.extension().is_none_or(|ext| ext != "toml") {
+                continue;
+            }
+            let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+            // Preset files written by the TUI shadow the bundled themes.
+            if !BUILTIN_THEMES.contains(&name) {
+                user_themes.push(name.to_string());
+            }
+        }
+    }
+    user_themes.sort();
+    themes.extend(user_themes.into_iter().map(|name| StatuslineTheme {
+        name,
+        built_in: false,
+    }));
+    Ok(themes)
+}
+
+/// Validate a replacement config against the `CxLineConfig` serde model,
+/// reporting one error per offending field.
+pub fn validate_config(config: &JsonValue) -> Vec<StatuslineValidationError> {
+    let mut errors = Vec::new();
+    let Some(root) = config.as_object() else {
+        errors.push(error("config", "statusline config must be an object"));
+        return errors;
+    };
+
+    for (key, value) in root {
+        match key.as_str() {
+            "enabled" => require_bool(&mut errors, key, value),
+            "theme" => {
+                if !value.as_str().is_some_and(|theme| !theme.trim().is_empty()) {
+                    errors.push(error(key, "theme must be a non-empty string"));
+                }
+            }
+            "style" => require_one_of(&mut errors, key, value, STYLE_MODES),
+            "separator" => require_string(&mut errors, key, value),
+            "separators" => validate_separators(&mut errors, value),
+            "separator_bg" => validate_separator_bg(&mut errors, value),
+            "theme_quick_select" => {
+                require_one_of(&mut errors, key, value, THEME_QUICK_SELECT_MODES)
+            }
+            "segment_order" => validate_segment_order(&mut errors, value),
+            "enabled_overrides" => validate_segment_id_list(&mut errors, key, value),
+            "segments" => validate_segments(&mut errors, value),
+            _ => errors.push(error(key, "unknown field")),
+        }
+    }
+
+    errors
+}
+
+fn error(field: impl Into<String>, message: impl Into<String>) -> StatuslineValidationError {
+    StatuslineValidationError {
+        field: field.into(),
+        message: message.into(),
+    }
+}
+
+fn require_bool(errors: &mut Vec<StatuslineValidationError>, field: &str, value: &JsonValue) {
+    if !value.is_boolean() {
+        errors.push(error(field, "expected a boolean"));
+    }
+}
+
+fn require_string(errors: &mut Vec<StatuslineValidationError>, field: &str, value: &JsonValue) {
+    if !value.is_string() {
+        errors.push(error(field, "expected a string"));
+    }
+}
+
+fn require_one_of(
+    errors: &mut Vec<StatuslineValidationError>,
+    field: &str,
+    value: &JsonValue,
+    allowed: &[&str],
+) {
+    if !value.as_str().is_some_and(|value| allowed.contains(&value)) {
+        errors.push(error(
+            field,
+            format!("expected one of: {}", allowed.join(", ")),
+        ));
+    }
+}
+
+fn validate_separators(errors: &mut Vec<StatuslineValidationError>, value: &JsonValue) {
+    let Some(separators) = value.as_object() else {
+        errors.push(error("separators", "expected an object"));
+        return;
+    };
+    for (key, value) in separators {
+        let field = format!("separators.{key}");
+        match key.as_str() {
+            "left_cap" | "inner" | "right_cap" => require_string(errors, &field, value),
+            _ => errors.push(error(field, "unknown field")),
+        }
+    }
+}
+
+fn validate_separator_bg(errors: &mut Vec<StatuslineValidationError>, value: &JsonValue) {
+    let valid = match value {
+        JsonValue::String(mode) => SEPARATOR_BG_MODES.contains(&mode.as_str()),
+        _ => is_color(value),
+    };
+    if !valid {
+        errors.push(error(
+            "separator_bg",
+            "expected \"none\", \"previous\", \"next\", or a color",
+        ));
+    }
+}
+
+fn validate_segment_order(errors: &mut Vec<StatuslineValidationError>, value: &JsonValue) {
+    let Some(order) = value.as_array() else {
+        errors.push(error("segment_order", "expected an array of segment ids"));
+        return;
+    };
+    if order.is_empty() {
+        errors.push(error("segment_order", "must list at least one segment"));
+    }
+    let mut seen: Vec<&str> = Vec::new();
+    for (index, entry) in order.iter().enumerate() {
+        let field = format!("segment_order[{index}]");
+        let Some(id) = entry.as_str().filter(|id| SEGMENT_IDS.contains(id)) else {
+            errors.push(error(
+                field,
+                format!("expected one of: {}", SEGMENT_IDS.join(", ")),
+            ));
+            continue;
+        };
+        if seen.contains(&id) {
+            errors.push(error(field, format!("duplicate segment id: {id}")));
+        }
+        seen.push(id);
+    }
+}
+
+fn validate_segment_id_list(
+    errors: &mut Vec<StatuslineValidationError>,
+    field: &str,
+    value: &JsonValue,
+) {
+    let Some(entries) = value.as_array() else {
+        errors.push(error(field, "expected an array of segment ids"));
+        return;
+    };
+    for (index, entry) in entries.iter().enumerate() {
+        if !entry.as_str().is_some_and(|id| SEGMENT_IDS.contains(&id)) {
+            errors.push(error(
+                format!("{field}[{index}]"),
+                format!("expected one of: {}", SEGMENT_IDS.join(", ")),
+            ));
+        }
+    }
+}
+
+fn validate_segments(errors: &mut Vec<StatuslineValidationError>, value: &JsonValue) {
+    let Some(segments) = value.as_object() else {
+        errors.push(error("segments", "expected an object"));
+        return;
+    };
+    for (key, value) in segments {
+        let field = format!("segments.{key}");
+        if !SEGMENT_IDS.contains(&key.as_str()) {
+            errors.push(error(field, "unknown segment"));
+            continue;
+        }
+        validate_segment(errors, &field, value);
+    }
+}
+
+fn validate_segment(
+    errors: &mut Vec<StatuslineValidationError>,
+    segment_field: &str,
+    value: &JsonValue,
+) {
+    let Some(segment) = value.as_object() else {
+        errors.push(error(segment_field, "expected an object"));
+        return;
+    };
+    for (key, value) in segment {
+        let field = format!("{segment_field}.{key}");
+        match key.as_str() {
+            "id" => require_one_of(errors, &field, value, SEGMENT_IDS),
+            "enabled" => require_bool(errors, &field, value),
+            "icon" => validate_icon(errors, &field, value),
+            "colors" => validate_colors(errors, &field, value),
+            "styles" => validate_styles(errors, &field, value),
+            "options" => {
+                if !value.is_object() {
+                    errors.push(error(field, "expected an object"));
+                }
+            }
+            _ => errors.push(error(field, "unknown field")),
+        }
+    }
+}
+
+fn validate_icon(errors: &mut Vec<StatuslineValidationError>, icon_field: &str, value: &JsonValue) {
+    let Some(icon) = value.as_object() else {
+        errors.push(error(icon_field, "expected an object"));
+        return;
+    };
+    for (key, value) in icon {
+        let field = format!("{icon_field}.{key}");
+        match key.as_str() {
+            "plain" | "nerd_font" => require_string(errors, &field, value),
+            _ => errors.push(error(field, "unknown field")),
+        }
+    }
+}
+
+fn validate_colors(
+    errors: &mut Vec<StatuslineValidationError>,
+    colors_field: &str,
+    value: &JsonValue,
+) {
+    let Some(colors) = value.as_object() else {
+        errors.push(error(colors_field, "expected an object"));
+        return;
+    };
+    for (key, value) in colors {
+        let field = format!("{colors_field}.{key}");
+        match key.as_str() {
+            "icon" | "text" | "background" => {
+                if !is_color(value) {
+                    errors.push(error(
+                        field,
+                        "expected a color: {c16}, {c256}, or {r, g, b}",
+                    ));
+                }
+            }
+            _ => errors.push(error(field, "unknown field")),
+        }
+    }
+}
+
+fn validate_styles(
+    errors: &mut Vec<StatuslineValidationError>,
+    styles_field: &str,
+    value: &JsonValue,
+) {
+    let Some(styles) = value.as_object() else {
+        errors.push(error(styles_field, "expected an object"));
+        return;
+    };
+    for (key, value) in styles {
+        let field = format!("{styles_field}.{key}");
+        match key.as_str() {
+            "text_bold" => require_bool(errors, &field, value),
+            _ => errors.push(error(field, "unknown field")),
+        }
+    }
+}
+
+/// A color in the `AnsiColor` untagged serde model: `{c16}`, `{c256}`, or
+/// `{r, g, b}` with components in `0..=255`.
+fn is_color(value: &JsonValue) -> bool {
+    let Some(color) = value.as_object() else {
+        return false;
+    };
+    let is_u8 = |key: &str| {
+        color
+            .get(key)
+            .and_then(JsonValue::as_u64)
+            .is_some_and(|n| n <= 255)
+    };
+    match color.len() {
+        1 => is_u8("c16") || is_u8("c256"),
+        3 => is_u8("r") && is_u8("g") && is_u8("b"),
+        _ => false,
+    }
+}
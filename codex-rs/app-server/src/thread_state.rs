@@ -4,6 +4,7 @@ use codex_app_server_protocol::RequestId;
 use codex_app_server_protocol::ThreadGoal;
 use codex_app_server_protocol::ThreadHistoryBuilder;
 use codex_app_server_protocol::ThreadSettings;
+use codex_app_server_protocol::ThreadTokenUsage;
 use codex_app_server_protocol::Turn;
 use codex_app_server_protocol::TurnError;
 use codex_core::CodexThread;
@@ -72,6 +73,9 @@ pub(crate) struct TurnSummary {
     pub(crate) started_at: Option<i64>,
     pub(crate) command_execution_started: HashSet<String>,
     pub(crate) last_error: Option<TurnError>,
+    /// Thread token usage as of the most recent `TokenCount` event in the
+    /// running turn; carried into `thread/turnCompleted` digests.
+    pub(crate) last_token_usage: Option<ThreadTokenUsage>,
 }
 
 #[derive(Default)]
@@ -82,6 +86,10 @@ pub(crate) struct ThreadState {
     pub(crate) last_terminal_turn_id: Option<String>,
     pub(crate) cancel_tx: Option<oneshot::Sender<()>>,
     pub(crate) experimental_raw_events: bool,
+    /// When true, completed reasoning items are translated through the
+    /// configured translation plugin and streamed as `thread/translationResult`
+    /// notifications.
+    pub(crate) translate_reasoning: bool,
     pub(crate) listener_generation: u64,
     last_thread_settings: Option<ThreadSettings>,
     listener_command_tx: Option<mpsc::UnboundedSender<ThreadListenerCommand>>,
@@ -131,6 +139,10 @@ impl ThreadState {
         self.experimental_raw_events = enabled;
     }
 
+    pub(crate) fn set_translate_reasoning(&mut self, enabled: bool) {
+        self.translate_reasoning = enabled;
+    }
+
     pub(crate) fn listener_command_tx(
         &self,
     ) -> Option<mpsc::UnboundedSender<ThreadListenerCommand>> {
@@ -145,6 +157,11 @@ impl ThreadState {
         if let EventMsg::TurnStarted(payload) = event {
             self.turn_summary.started_at = payload.started_at;
         }
+        if let EventMsg::TokenCount(payload) = event
+            && let Some(info) = payload.info.clone()
+        {
+            self.turn_summary.last_token_usage = Some(info.into());
+        }
         self.current_turn_history.handle_event(event);
         if matches!(event, EventMsg::TurnAborted(_) | EventMsg::TurnComplete(_))
             && !self.current_turn_history.has_active_turn()
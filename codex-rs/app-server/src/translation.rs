@@ -0,0 +1,578 @@
+//! Server-side client for the TUI translation configuration.
+//!
+//! The interactive translator is owned by the TUI (`codex-tui`'s translation
+//! module); the app-server cannot depend on that crate, so this module
+//! mirrors just enough of the provider table and wire protocols to run
+//! one-shot translations against the configured backend: the HTTP endpoint
+//! and direct providers are exercised for real, while daemon and MCP
+//! backends (which need a spawned child) are reported as untestable from
+//! here. Setup wizards use
+//! the resulting `translation/test` response to tell the user whether their
+//! `translation.toml` works before enabling translation, and threads that
+//! opted in stream reasoning translations through [`translate_text`].
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::Duration;
+use std::time::Instant;
+
+use codex_app_server_protocol::TranslationTestErrorCode;
+use codex_app_server_protocol::TranslationTestResponse;
+use serde::Deserialize;
+use serde_json::Value as JsonValue;
+use serde_json::json;
+
+/// Hard cap on the test translation, applied regardless of the configured
+/// timeout so a misconfigured multi-minute timeout cannot hang the RPC.
+const TEST_TIMEOUT_CAP: Duration = Duration::from_secs(10);
+
+/// Default timeout for translation requests (in milliseconds), matching the
+/// TUI translation client.
+const DEFAULT_TIMEOUT_MS: u64 = 30000;
+
+/// Fixed short sample fed through the translator.
+const SAMPLE_TEXT: &str = "Hello! This is a connectivity test.";
+
+/// Mirror of the fields of the TUI's `TranslationConfig` that the test
+/// translation needs. Must stay in sync with the TUI translation config
+/// module.
+#[derive(Debug, Default, Deserialize)]
+struct TranslationConfigFile {
+    #[serde(default = "default_target_language")]
+    target_language: String,
+    #[serde(default = "default_provider")]
+    provider: String,
+    #[serde(default)]
+    api_key: Option<String>,
+    #[serde(default)]
+    model: Option<String>,
+    #[serde(default)]
+    base_url: Option<String>,
+    #[serde(default)]
+    timeout_ms: Option<u64>,
+    /// Supervised daemon backend. Takes precedence over every HTTP path in
+    /// the TUI, but needs a spawned child the app-server cannot supervise.
+    #[serde(default)]
+    daemon_command: Option<Vec<String>>,
+    /// Named reference into the TUI's `[translators.*]` daemon table.
+    #[serde(default, rename = "use")]
+    use_translator: Option<String>,
+    /// Shared MCP server backend; untestable here for the same reason as
+    /// the daemon.
+    #[serde(default)]
+    mcp_server_command: Option<Vec<String>>,
+    /// HTTP endpoint backend, mirrored in full: it is one POST per request.
+    #[serde(default)]
+    http_url: Option<String>,
+    #[serde(default)]
+    http_headers: Option<HashMap<String, String>>,
+}
+
+fn default_target_language() -> String {
+    "zh-CN".to_string()
+}
+
+fn default_provider() -> String {
+    "deepseek".to_string()
+}
+
+/// API protocol spoken by a provider, mirroring the TUI translation provider
+/// module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Protocol {
+    OpenAi,
+    Anthropic,
+    Gemini,
+}
+
+/// Per-provider defaults needed to issue a translation request. Must stay in
+/// sync with the provider table in the TUI translation provider module.
+struct ProviderDefaults {
+    name: &'static str,
+    base_url: &'static str,
+    model: &'static str,
+    protocol: Protocol,
+    requires_api_key: bool,
+}
+
+fn provider_defaults(provider: &str) -> Option<ProviderDefaults> {
+    let (name, base_url, model, protocol, requires_api_key) = match provider.to_lowercase().as_str()
+    {
+        "openai" => (
+            "OpenAI",
+            "https://api.openai.com/v1",
+            "gpt-4o-mini",
+            Protocol::OpenAi,
+            true,
+        ),
+        "anthropic" => (
+            "Anthropic",
+            "https://api.anthropic.com/v1",
+            "claude-3-haiku-20240307",
+            Protocol::Anthropic,
+            true,
+        ),
+        "deepseek" => (
+            "DeepSeek",
+            "https://api.deepseek.com/v1",
+            "deepseek-chat",
+            Protocol::OpenAi,
+            true,
+        ),
+        "moonshot" => (
+            "Moonshot",
+            "https://api.moonshot.cn/v1",
+            "moonshot-v1-8k",
+            Protocol::OpenAi,
+            true,
+        ),
+        "zhipuai" | "zhipu" => (
+            "ZhipuAI",
+            "https://open.bigmodel.cn/api/paas/v4",
+            "glm-4-flash",
+            Protocol::OpenAi,
+            true,
+        ),
+        "qwen" | "dashscope" => (
+            "Qwen",
+            "https://dashscope.aliyuncs.com/compatible-mode/v1",
+            "qwen-turbo",
+            Protocol::OpenAi,
+            true,
+        ),
+        "groq" => (
+            "Groq",
+            "https://api.groq.com/openai/v1",
+            "llama-3.1-8b-instant",
+            Protocol::OpenAi,
+            true,
+        ),
+        "gemini" | "google" => (
+            "Gemini",
+            "https://generativelanguage.googleapis.com/v1beta",
+            "gemini-1.5-flash",
+            Protocol::Gemini,
+            true,
+        ),
+        "mistral" => (
+            "Mistral",
+            "https://api.mistral.ai/v1",
+            "mistral-small-latest",
+            Protocol::OpenAi,
+            true,
+        ),
+        "cohere" => (
+            "Cohere",
+            "https://api.cohere.ai/v1",
+            "command-r",
+            Protocol::OpenAi,
+            true,
+        ),
+        "ollama" => (
+            "Ollama",
+            "http://localhost:11434/v1",
+            "llama3",
+            Protocol::OpenAi,
+            false,
+        ),
+        "openrouter" => (
+            "OpenRouter",
+            "https://openrouter.ai/api/v1",
+            "openai/gpt-4o-mini",
+            Protocol::OpenAi,
+            true,
+        ),
+        "togetherai" | "together" => (
+            "TogetherAI",
+            "https://api.together.xyz/v1",
+            "meta-llama/Llama-3-8b-chat-hf",
+            Protocol::OpenAi,
+            true,
+        ),
+        "perplexity" => (
+            "Perplexity",
+            "https://api.perplexity.ai",
+            "llama-3.1-sonar-small-128k-online",
+            Protocol::OpenAi,
+            true,
+        ),
+        "siliconflow" => (
+            "SiliconFlow",
+            "https://api.siliconflow.cn/v1",
+            "Qwen/Qwen2.5-7B-Instruct",
+            Protocol::OpenAi,
+            true,
+        ),
+        _ => return None,
+    };
+    Some(ProviderDefaults {
+        name,
+        base_url,
+        model,
+        protocol,
+        requires_api_key,
+    })
+}
+
+pub(crate) struct TranslationFailure {
+    pub(crate) code: TranslationTestErrorCode,
+    pub(crate) message: String,
+}
+
+impl TranslationFailure {
+    fn new(code: TranslationTestErrorCode, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+impl From<reqwest::Error> for TranslationFailure {
+    fn from(err: reqwest::Error) -> Self {
+        if err.is_timeout() {
+            Self::new(TranslationTestErrorCode::Timeout, "Translation timeout")
+        } else {
+            Self::new(
+                TranslationTestErrorCode::Network,
+                format!("Network error: {err}"),
+            )
+        }
+    }
+}
+
+/// Whether a `translation.toml` exists under `codex_home`.
+pub(crate) fn is_configured(codex_home: &Path) -> bool {
+    codex_home.join("translation.toml").exists()
+}
+
+/// Sanitized summary of the translation configuration for
+/// `builtinPlugins/list`. The API key is reported as a presence flag, never
+/// as a value; an unreadable config yields an empty object.
+pub(crate) fn settings_summary(codex_home: &Path) -> JsonValue {
+    let Ok(config) = load_config(codex_home) else {
+        return json!({});
+    };
+    json!({
+        "provider": config.provider,
+        "targetLanguage": config.target_language,
+        "model": config.model,
+        "timeoutMs": config.timeout_ms.unwrap_or(DEFAULT_TIMEOUT_MS),
+        "apiKeyConfigured": config.api_key.is_some(),
+    })
+}
+
+/// Run a fixed short translation through the provider configured in
+/// `translation.toml` under `codex_home` and report the outcome. Failures are
+/// reported in-band on the response rather than as RPC errors.
+pub(crate) async fn run_test_translation(codex_home: &Path) -> TranslationTestResponse {
+    let started = Instant::now();
+    let result = run_translation(codex_home, SAMPLE_TEXT, Some(TEST_TIMEOUT_CAP)).await;
+    let latency_ms = u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX);
+    match result {
+        Ok(translated_sample) => TranslationTestResponse {
+            ok: true,
+            latency_ms,
+            translated_sample: Some(translated_sample),
+            code: None,
+            message: None,
+        },
+        Err(failure) => TranslationTestResponse {
+            ok: false,
+            latency_ms,
+            translated_sample: None,
+            code: Some(failure.code),
+            message: Some(failure.message),
+        },
+    }
+}
+
+/// Translate `text` through the provider configured in `translation.toml`
+/// under `codex_home`, honoring the configured timeout.
+pub(crate) async fn translate_text(
+    codex_home: &Path,
+    text: &str,
+) -> Result<String, TranslationFailure> {
+    run_translation(codex_home, text, /*timeout_cap*/ None).await
+}
+
+async fn run_translation(
+    codex_home: &Path,
+    text: &str,
+    timeout_cap: Option<Duration>,
+) -> Result<String, TranslationFailure> {
+    let config = load_config(codex_home)?;
+    let mut timeout = Duration::from_millis(config.timeout_ms.unwrap_or(DEFAULT_TIMEOUT_MS));
+    if let Some(cap) = timeout_cap {
+        timeout = timeout.min(cap);
+    }
+
+    // Mirror the TUI backend dispatch order: daemon, then MCP, then the HTTP
+    // endpoint, then the direct provider client. The first two need a child
+    // process the app-server cannot spawn or supervise, so they are reported
+    // as untestable instead of silently validating a provider path the TUI
+    // would never use.
+    if config.daemon_command.is_some() || config.use_translator.is_some() {
+        return Err(TranslationFailure::new(
+            TranslationTestErrorCode::UnsupportedProvider,
+            "translation.toml configures a daemon backend, which cannot be \
+             exercised from here; run `codex debug translation` to test it",
+        ));
+    }
+    if config.mcp_server_command.is_some() {
+        return Err(TranslationFailure::new(
+            TranslationTestErrorCode::UnsupportedProvider,
+            "translation.toml configures an MCP backend, which cannot be \
+             exercised from here; run `codex debug translation` to test it",
+        ));
+    }
+    if let Some(url) = config.http_url.as_deref().filter(|url| !url.is_empty()) {
+        let request = translate_endpoint(&config, url, text, timeout);
+        return match tokio::time::timeout(timeout, request).await {
+            Ok(result) => result,
+            Err(_) => Err(TranslationFailure::new(
+                TranslationTestErrorCode::Timeout,
+                "Translation timeout",
+            )),
+        };
+    }
+
+    let provider = provider_defaults(&config.provider).ok_or_else(|| {
+        TranslationFailure::new(
+            TranslationTestErrorCode::UnsupportedProvider,
+            format!("Unsupported provider: {}", config.provider),
+        )
+    })?;
+
+    let api_key = config
+        .api_key
+        .as_deref()
+        .filter(|key| !key.is_empty())
+        .map(str::to_string);
+    if provider.requires_api_key && api_key.is_none() {
+        return Err(TranslationFailure::new(
+            TranslationTestErrorCode::ApiKeyNotFound,
+            format!("API key not configured for {}", provider.name),
+        ));
+    }
+
+    let base_url = config
+        .base_url
+        .as_deref()
+        .filter(|url| !url.is_empty())
+        .unwrap_or(provider.base_url)
+        .trim_end_matches('/')
+        .to_string();
+    let model = config
+        .model
+        .as_deref()
+        .filter(|model| !model.is_empty())
+        .unwrap_or(provider.model)
+        .to_string();
+
+    let client = reqwest::Client::builder()
+        .timeout(timeout)
+        .build()
+        .map_err(TranslationFailure::from)?;
+    let prompt = format!(
+        "Translate the following text to {}. \
+         Output only the translation, nothing else.\n\n{text}",
+        config.target_language
+    );
+
+    let request = match provider.protocol {
+        Protocol::OpenAi => {
+            translate_openai(&client, &base_url, &model, api_key.as_deref(), &prompt)
+        }
+        Protocol::Anthropic => {
+            translate_anthropic(&client, &base_url, &model, api_key.as_deref(), &prompt)
+        }
+        Protocol::Gemini => {
+            translate_gemini(&client, &base_url, &model, api_key.as_deref(), &prompt)
+        }
+    };
+    // Belt and braces on top of the per-request client timeout: the deadline
+    // must hold even if the connector stalls in a phase the client timeout
+    // does not cover.
+    match tokio::time::timeout(timeout, request).await {
+        Ok(result) => result,
+        Err(_) => Err(TranslationFailure::new(
+            TranslationTestErrorCode::Timeout,
+            "Translation timeout",
+        )),
+    }
+}
+
+/// Translate through the HTTP endpoint backend: POST the request fields the
+/// endpoint cares about and parse back the daemon response shape, matching
+/// the TUI's endpoint module. Header values of the form `env:NAME` resolve
+/// from the environment at request time and are never logged.
+async fn translate_endpoint(
+    config: &TranslationConfigFile,
+    url: &str,
+    text: &str,
+    timeout: Duration,
+) -> Result<String, TranslationFailure> {
+    let client = reqwest::Client::builder()
+        .timeout(timeout)
+        .build()
+        .map_err(TranslationFailure::from)?;
+    let body = json!({
+        "text": text,
+        "target_language": config.target_language,
+    });
+    let mut request = client.post(url).json(&body);
+    if let Some(headers) = &config.http_headers {
+        for (name, value) in headers {
+            let value = match value.strip_prefix("env:") {
+                Some(variable) => std::env::var(variable).map_err(|_| {
+                    TranslationFailure::new(
+                        TranslationTestErrorCode::InvalidConfig,
+                        format!(
+                            "http_headers value for {name} references an unset \
+                             environment variable"
+                        ),
+                    )
+                })?,
+                None => value.clone(),
+            };
+            request = request.header(name, value);
+        }
+    }
+    let response = into_json(request.send().await?).await?;
+    if let Some(error) = response.get("error").and_then(JsonValue::as_str) {
+        return Err(TranslationFailure::new(
+            TranslationTestErrorCode::Api,
+            format!("Endpoint error: {error}"),
+        ));
+    }
+    response
+        .get("translated")
+        .and_then(JsonValue::as_str)
+        .map(str::to_string)
+        .ok_or_else(|| TranslationFailure::new(TranslationTestErrorCode::Parse, "Empty response"))
+}
+
+fn load_config(codex_home: &Path) -> Result<TranslationConfigFile, TranslationFailure> {
+    let path = codex_home.join("translation.toml");
+    if !path.exists() {
+        return Ok(TranslationConfigFile::default());
+    }
+    let contents = std::fs::read_to_string(&path).map_err(|err| {
+        TranslationFailure::new(
+            TranslationTestErrorCode::InvalidConfig,
+            format!("failed to read {}: {err}", path.display()),
+        )
+    })?;
+    toml::from_str(&contents).map_err(|err| {
+        TranslationFailure::new(
+            TranslationTestErrorCode::InvalidConfig,
+            format!("failed to parse {}: {err}", path.display()),
+        )
+    })
+}
+
+async fn translate_openai(
+    client: &reqwest::Client,
+    base_url: &str,
+    model: &str,
+    api_key: Option<&str>,
+    prompt: &str,
+) -> Result<String, TranslationFailure> {
+    let body = json!({
+        "model": model,
+        "messages": [{"role": "user", "content": prompt}],
+        "temperature": 0.3,
+    });
+    let mut request = client
+        .post(format!("{base_url}/chat/completions"))
+        .json(&body);
+    if let Some(api_key) = api_key {
+        request = request.header("Authorization", format!("Bearer {api_key}"));
+    }
+    let response = into_json(request.send().await?).await?;
+    response
+        .pointer("/choices/0/message/content")
+        .and_then(JsonValue::as_str)
+        .map(str::to_string)
+        .ok_or_else(|| TranslationFailure::new(TranslationTestErrorCode::Parse, "Empty response"))
+}
+
+async fn translate_anthropic(
+    client: &reqwest::Client,
+    base_url: &str,
+    model: &str,
+    api_key: Option<&str>,
+    prompt: &str,
+) -> Result<String, TranslationFailure> {
+    let api_key = api_key.ok_or_else(|| {
+        TranslationFailure::new(
+            TranslationTestErrorCode::ApiKeyNotFound,
+            "API key not configured for Anthropic",
+        )
+    })?;
+    let body = json!({
+        "model": model,
+        "messages": [{"role": "user", "content": prompt}],
+        "max_tokens": 4096,
+    });
+    let response = client
+        .post(format!("{base_url}/messages"))
+        .header("x-api-key", api_key)
+        .header("anthropic-version", "2023-06-01")
+        .json(&body)
+        .send()
+        .await?;
+    let response = into_json(response).await?;
+    response
+        .pointer("/content/0/text")
+        .and_then(JsonValue::as_str)
+        .map(str::to_string)
+        .ok_or_else(|| TranslationFailure::new(TranslationTestErrorCode::Parse, "Empty response"))
+}
+
+async fn translate_gemini(
+    client: &reqwest::Client,
+    base_url: &str,
+    model: &str,
+    api_key: Option<&str>,
+    prompt: &str,
+) -> Result<String, TranslationFailure> {
+    let api_key = api_key.ok_or_else(|| {
+        TranslationFailure::new(
+            TranslationTestErrorCode::ApiKeyNotFound,
+            "API key not configured for Gemini",
+        )
+    })?;
+    let body = json!({
+        "contents": [{"parts": [{"text": prompt}]}],
+    });
+    let response = client
+        .post(format!(
+            "{base_url}/models/{model}:generateContent?key={api_key}"
+        ))
+        .json(&body)
+        .send()
+        .await?;
+    let response = into_json(response).await?;
+    response
+        .pointer("/candidates/0/content/parts/0/text")
+        .and_then(JsonValue::as_str)
+        .map(str::to_string)
+        .ok_or_else(|| TranslationFailure::new(TranslationTestErrorCode::Parse, "Empty response"))
+}
+
+async fn into_json(response: reqwest::Response) -> Result<JsonValue, TranslationFailure> {
+    let status = response.status();
+    if !status.is_success() {
+        let message = response.text().await.unwrap_or_default();
+        return Err(TranslationFailure::new(
+            TranslationTestErrorCode::Api,
+            format!("API error ({}): {message}", status.as_u16()),
+        ));
+    }
+    response
+        .json()
+        .await
+        .map_err(|err| TranslationFailure::new(TranslationTestErrorCode::Parse, err.to_string()))
+}
@@ -0,0 +1,24 @@
+//! Process-wide cache of the most recent rate-limit snapshot.
+//!
+//! Core caches rate limits per thread, but `usage/get` reports the latest
+//! headers seen by any thread; token-count events record their snapshot here
+//! so the endpoint does not have to guess which loaded thread saw rate-limit
+//! headers last.
+
+use codex_protocol::protocol::RateLimitSnapshot;
+use tokio::sync::Mutex;
+
+#[derive(Default)]
+pub(crate) struct UsageTracker {
+    latest_rate_limits: Mutex<Option<RateLimitSnapshot>>,
+}
+
+impl UsageTracker {
+    pub(crate) async fn record_rate_limits(&self, snapshot: RateLimitSnapshot) {
+        *self.latest_rate_limits.lock().await = Some(snapshot);
+    }
+
+    pub(crate) async fn latest_rate_limits(&self) -> Option<RateLimitSnapshot> {
+        self.latest_rate_limits.lock().await.clone()
+    }
+}
@@ -18,7 +18,10 @@ fn preset_to_info(preset: &ModelPreset, priority: i32) -> ModelInfo {
         slug: preset.id.clone(),
         display_name: preset.display_name.clone(),
         description: Some(preset.description.clone()),
-        default_reasoning_level: Some(preset.default_reasoning_effort.clone()),
+        // Non-reasoning presets carry no default effort; keep the cache entry
+        // faithful so list items can omit the reasoning fields.
+        default_reasoning_level: (!preset.supported_reasoning_efforts.is_empty())
+            .then(|| preset.default_reasoning_effort.clone()),
         supported_reasoning_levels: preset.supported_reasoning_efforts.clone(),
         shell_type: ConfigShellToolType::ShellCommand,
         visibility: if preset.show_in_picker {
@@ -35,7 +38,7 @@ fn preset_to_info(preset: &ModelPreset, priority: i32) -> ModelInfo {
         base_instructions: "base instructions".to_string(),
         model_messages: None,
         include_skills_usage_instructions: false,
-        supports_reasoning_summaries: false,
+        supports_reasoning_summaries: preset.supports_reasoning_summaries,
         default_reasoning_summary: ReasoningSummary::Auto,
         support_verbosity: false,
         default_verbosity: None,
@@ -43,10 +46,14 @@ fn preset_to_info(preset: &ModelPreset, priority: i32) -> ModelInfo {
         apply_patch_tool_type: None,
         web_search_tool_type: Default::default(),
         truncation_policy: TruncationPolicyConfig::bytes(/*limit*/ 10_000),
-        supports_parallel_tool_calls: false,
+        supports_parallel_tool_calls: preset.supports_parallel_tool_calls,
         supports_image_detail_original: false,
-        context_window: Some(272_000),
+        context_window: preset.context_window.or(Some(272_000)),
         max_context_window: None,
+        max_output_tokens: preset.max_output_tokens,
+        release_date: preset.release_date.clone(),
+        deprecated: preset.deprecated,
+        deprecation_message: preset.deprecation_message.clone(),
         auto_compact_token_limit: None,
         comp_hash: None,
         effective_context_window_percent: 95,
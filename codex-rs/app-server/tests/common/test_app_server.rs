@@ -16,6 +16,7 @@ use tokio::process::ChildStdout;
 use anyhow::Context;
 use anyhow::ensure;
 use codex_app_server_protocol::AppsListParams;
+use codex_app_server_protocol::BuiltinPluginsListParams;
 use codex_app_server_protocol::CancelLoginAccountParams;
 use codex_app_server_protocol::ClientInfo;
 use codex_app_server_protocol::ClientNotification;
@@ -59,8 +60,11 @@ use codex_app_server_protocol::MarketplaceUpgradeParams;
 use codex_app_server_protocol::McpResourceReadParams;
 use codex_app_server_protocol::McpServerToolCallParams;
 use codex_app_server_protocol::MockExperimentalMethodParams;
+use codex_app_server_protocol::ModelGetParams;
 use codex_app_server_protocol::ModelListParams;
 use codex_app_server_protocol::ModelProviderCapabilitiesReadParams;
+use codex_app_server_protocol::ModelResolveParams;
+use codex_app_server_protocol::ModelsRefreshParams;
 use codex_app_server_protocol::PermissionProfileListParams;
 use codex_app_server_protocol::PluginInstallParams;
 use codex_app_server_protocol::PluginInstalledParams;
@@ -82,6 +86,9 @@ use codex_app_server_protocol::SendAddCreditsNudgeEmailParams;
 use codex_app_server_protocol::ServerRequest;
 use codex_app_server_protocol::SkillsExtraRootsSetParams;
 use codex_app_server_protocol::SkillsListParams;
+use codex_app_server_protocol::StatuslineGetConfigParams;
+use codex_app_server_protocol::StatuslineListThemesParams;
+use codex_app_server_protocol::StatuslineSetConfigParams;
 use codex_app_server_protocol::ThreadArchiveParams;
 use codex_app_server_protocol::ThreadCompactStartParams;
 use codex_app_server_protocol::ThreadDeleteParams;
@@ -109,12 +116,14 @@ use codex_app_server_protocol::ThreadStartParams;
 use codex_app_server_protocol::ThreadTurnsListParams;
 use codex_app_server_protocol::ThreadUnarchiveParams;
 use codex_app_server_protocol::ThreadUnsubscribeParams;
+use codex_app_server_protocol::TranslationTestParams;
 use codex_app_server_protocol::TurnCompletedNotification;
 use codex_app_server_protocol::TurnEnvironmentParams;
 use codex_app_server_protocol::TurnInterruptParams;
 use codex_app_server_protocol::TurnStartParams;
 use codex_app_server_protocol::TurnStartResponse;
 use codex_app_server_protocol::TurnSteerParams;
+use codex_app_server_protocol::UsageGetParams;
 use codex_app_server_protocol::WindowsSandboxSetupStartParams;
 use codex_exec_server::CODEX_EXEC_SERVER_NOISE_AUTH_TOKEN_ENV_VAR;
 use codex_exec_server::CODEX_EXEC_SERVER_NOISE_CHATGPT_ACCOUNT_ID_ENV_VAR;
@@ -655,6 +664,30 @@ impl TestAppServer {
         self.send_request("model/list", params).await
     }
 
+    /// Send a `model/get` JSON-RPC request.
+    pub async fn send_model_get_request(&mut self, params: ModelGetParams) -> anyhow::Result<i64> {
+        let params = Some(serde_json::to_value(params)?);
+        self.send_request("model/get", params).await
+    }
+
+    /// Send a `model/resolve` JSON-RPC request.
+    pub async fn send_model_resolve_request(
+        &mut self,
+        params: ModelResolveParams,
+    ) -> anyhow::Result<i64> {
+        let params = Some(serde_json::to_value(params)?);
+        self.send_request("model/resolve", params).await
+    }
+
+    /// Send a `models/refresh` JSON-RPC request.
+    pub async fn send_models_refresh_request(
+        &mut self,
+        params: ModelsRefreshParams,
+    ) -> anyhow::Result<i64> {
+        let params = Some(serde_json::to_value(params)?);
+        self.send_request("models/refresh", params).await
+    }
+
     /// Send a `modelProvider/capabilities/read` JSON-RPC request.
     pub async fn send_model_provider_capabilities_read_request(
         &mut self,
@@ -1217,6 +1250,57 @@ impl TestAppServer {
         self.send_request("windowsSandbox/setupStart", params).await
     }
 
+    /// Send a `statusline/getConfig` JSON-RPC request.
+    pub async fn send_statusline_get_config_request(
+        &mut self,
+        params: StatuslineGetConfigParams,
+    ) -> anyhow::Result<i64> {
+        let params = Some(serde_json::to_value(params)?);
+        self.send_request("statusline/getConfig", params).await
+    }
+
+    /// Send a `statusline/setConfig` JSON-RPC request.
+    pub async fn send_statusline_set_config_request(
+        &mut self,
+        params: StatuslineSetConfigParams,
+    ) -> anyhow::Result<i64> {
+        let params = Some(serde_json::to_value(params)?);
+        self.send_request("statusline/setConfig", params).await
+    }
+
+    /// Send a `statusline/listThemes` JSON-RPC request.
+    pub async fn send_statusline_list_themes_request(
+        &mut self,
+        params: StatuslineListThemesParams,
+    ) -> anyhow::Result<i64> {
+        let params = Some(serde_json::to_value(params)?);
+        self.send_request("statusline/listThemes", params).await
+    }
+
+    /// Send a `translation/test` JSON-RPC request.
+    pub async fn send_translation_test_request(
+        &mut self,
+        params: TranslationTestParams,
+    ) -> anyhow::Result<i64> {
+        let params = Some(serde_json::to_value(params)?);
+        self.send_request("translation/test", params).await
+    }
+
+    /// Send a `builtinPlugins/list` JSON-RPC request.
+    pub async fn send_builtin_plugins_list_request(
+        &mut self,
+        params: BuiltinPluginsListParams,
+    ) -> anyhow::Result<i64> {
+        let params = Some(serde_json::to_value(params)?);
+        self.send_request("builtinPlugins/list", params).await
+    }
+
+    /// Send a `usage/get` JSON-RPC request.
+    pub async fn send_usage_get_request(&mut self, params: UsageGetParams) -> anyhow::Result<i64> {
+        let params = Some(serde_json::to_value(params)?);
+        self.send_request("usage/get", params).await
+    }
+
     pub async fn send_config_read_request(
         &mut self,
         params: ConfigReadParams,
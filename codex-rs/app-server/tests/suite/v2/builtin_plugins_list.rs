@@ -0,0 +1,146 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use app_test_support::TestAppServer;
+use app_test_support::to_response;
+use app_test_support::write_models_cache;
+use codex_app_server_protocol::BuiltinPluginId;
+use codex_app_server_protocol::BuiltinPluginInfo;
+use codex_app_server_protocol::BuiltinPluginsListParams;
+use codex_app_server_protocol::BuiltinPluginsListResponse;
+use codex_app_server_protocol::JSONRPCResponse;
+use codex_app_server_protocol::RequestId;
+use codex_app_server_protocol::TranslationTestParams;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use tempfile::TempDir;
+use tokio::time::timeout;
+use wiremock::Mock;
+use wiremock::MockServer;
+use wiremock::ResponseTemplate;
+use wiremock::matchers::method;
+use wiremock::matchers::path;
+
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+async fn list_builtin_plugins(mcp: &mut TestAppServer) -> Result<BuiltinPluginsListResponse> {
+    let request_id = mcp
+        .send_builtin_plugins_list_request(BuiltinPluginsListParams::default())
+        .await?;
+    let response: JSONRPCResponse = timeout(
+        DEFAULT_TIMEOUT,
+        mcp.read_stream_until_response_message(RequestId::Integer(request_id)),
+    )
+    .await??;
+    to_response::<BuiltinPluginsListResponse>(response)
+}
+
+fn plugin<'a>(
+    response: &'a BuiltinPluginsListResponse,
+    id: BuiltinPluginId,
+) -> &'a BuiltinPluginInfo {
+    response
+        .plugins
+        .iter()
+        .find(|plugin| plugin.id == id)
+        .expect("plugin entry should be listed")
+}
+
+#[tokio::test]
+async fn builtin_plugins_list_reports_unconfigured_plugins() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    write_models_cache(codex_home.path())?;
+
+    let mut mcp = TestAppServer::builder()
+        .with_codex_home(codex_home.path())
+        .without_auto_env()
+        .build()
+        .await?;
+    timeout(DEFAULT_TIMEOUT, mcp.initialize()).await??;
+
+    let response = list_builtin_plugins(&mut mcp).await?;
+    assert_eq!(response.plugins.len(), 2);
+
+    let statusline = plugin(&response, BuiltinPluginId::Statusline);
+    assert!(!statusline.configured);
+    assert_eq!(statusline.settings, json!({}));
+    assert_eq!(statusline.last_health_check, None);
+
+    // Defaults apply when translation.toml is absent, but the plugin still
+    // reports as unconfigured.
+    let translation = plugin(&response, BuiltinPluginId::Translation);
+    assert!(!translation.configured);
+    assert_eq!(translation.settings["provider"], json!("deepseek"));
+    assert_eq!(translation.settings["targetLanguage"], json!("zh-CN"));
+    assert_eq!(translation.settings["apiKeyConfigured"], json!(false));
+    assert_eq!(translation.last_health_check, None);
+    Ok(())
+}
+
+#[tokio::test]
+async fn builtin_plugins_list_reports_settings_and_health_check() -> Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/chat/completions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "choices": [{"message": {"content": "你好！这是一次连接测试。"}}]
+        })))
+        .mount(&server)
+        .await;
+
+    let codex_home = TempDir::new()?;
+    write_models_cache(codex_home.path())?;
+    let server_uri = server.uri();
+    std::fs::write(
+        codex_home.path().join("translation.toml"),
+        format!(
+            r#"enabled = true
+target_language = "zh-CN"
+provider = "openai"
+api_key = "sk-test"
+base_url = "{server_uri}"
+timeout_ms = 5000
+"#
+        ),
+    )?;
+
+    let mut mcp = TestAppServer::builder()
+        .with_codex_home(codex_home.path())
+        .without_auto_env()
+        .build()
+        .await?;
+    timeout(DEFAULT_TIMEOUT, mcp.initialize()).await??;
+
+    let response = list_builtin_plugins(&mut mcp).await?;
+    let translation = plugin(&response, BuiltinPluginId::Translation);
+    assert!(translation.configured);
+    assert_eq!(translation.settings["provider"], json!("openai"));
+    assert_eq!(translation.settings["timeoutMs"], json!(5000));
+    // The key itself must never appear in the sanitized settings.
+    assert_eq!(translation.settings["apiKeyConfigured"], json!(true));
+    assert!(!translation.settings.to_string().contains("sk-test"));
+    // No health check has run yet in this session.
+    assert_eq!(translation.last_health_check, None);
+
+    let test_id = mcp
+        .send_translation_test_request(TranslationTestParams::default())
+        .await?;
+    timeout(
+        DEFAULT_TIMEOUT,
+        mcp.read_stream_until_response_message(RequestId::Integer(test_id)),
+    )
+    .await??;
+
+    let response = list_builtin_plugins(&mut mcp).await?;
+    let translation = plugin(&response, BuiltinPluginId::Translation);
+    let health = translation
+        .last_health_check
+        .as_ref()
+        .expect("health check should be recorded after translation/test");
+    assert!(health.ok);
+    assert_eq!(
+        health.translated_sample.as_deref(),
+        Some("你好！这是一次连接测试。")
+    );
+    Ok(())
+}
@@ -44,6 +44,7 @@ async fn mock_experimental_method_requires_experimental_api_capability() -> Resu
                 request_attestation: false,
                 opt_out_notification_methods: None,
                 mcp_server_openai_form_elicitation: false,
+                thread_turn_completed_notifications: false,
             }),
         )
         .await?;
@@ -80,6 +81,7 @@ async fn realtime_conversation_start_requires_experimental_api_capability() -> R
                 request_attestation: false,
                 opt_out_notification_methods: None,
                 mcp_server_openai_form_elicitation: false,
+                thread_turn_completed_notifications: false,
             }),
         )
         .await?;
@@ -131,6 +133,7 @@ async fn thread_memory_mode_set_requires_experimental_api_capability() -> Result
                 request_attestation: false,
                 opt_out_notification_methods: None,
                 mcp_server_openai_form_elicitation: false,
+                thread_turn_completed_notifications: false,
             }),
         )
         .await?;
@@ -170,6 +173,7 @@ async fn thread_settings_update_requires_experimental_api_capability() -> Result
                 request_attestation: false,
                 opt_out_notification_methods: None,
                 mcp_server_openai_form_elicitation: false,
+                thread_turn_completed_notifications: false,
             }),
         )
         .await?;
@@ -209,6 +213,7 @@ async fn realtime_webrtc_start_requires_experimental_api_capability() -> Result<
                 request_attestation: false,
                 opt_out_notification_methods: None,
                 mcp_server_openai_form_elicitation: false,
+                thread_turn_completed_notifications: false,
             }),
         )
         .await?;
@@ -263,6 +268,7 @@ async fn thread_start_mock_field_requires_experimental_api_capability() -> Resul
                 request_attestation: false,
                 opt_out_notification_methods: None,
                 mcp_server_openai_form_elicitation: false,
+                thread_turn_completed_notifications: false,
             }),
         )
         .await?;
@@ -305,6 +311,7 @@ async fn thread_start_without_dynamic_tools_allows_without_experimental_api_capa
                 request_attestation: false,
                 opt_out_notification_methods: None,
                 mcp_server_openai_form_elicitation: false,
+                thread_turn_completed_notifications: false,
             }),
         )
         .await?;
@@ -346,6 +353,7 @@ async fn thread_start_granular_approval_policy_requires_experimental_api_capabil
                 request_attestation: false,
                 opt_out_notification_methods: None,
                 mcp_server_openai_form_elicitation: false,
+                thread_turn_completed_notifications: false,
             }),
         )
         .await?;
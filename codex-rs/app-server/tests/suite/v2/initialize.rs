@@ -232,6 +232,7 @@ async fn initialize_opt_out_notification_methods_filters_notifications() -> Resu
                 request_attestation: false,
                 opt_out_notification_methods: Some(vec!["thread/started".to_string()]),
                 mcp_server_openai_form_elicitation: false,
+                thread_turn_completed_notifications: false,
             }),
         ),
     )
@@ -3,6 +3,7 @@ mod analytics;
 mod app_list;
 mod attestation;
 mod auto_env;
+mod builtin_plugins_list;
 mod client_metadata;
 mod collaboration_mode_list;
 #[cfg(unix)]
@@ -35,8 +36,11 @@ mod mcp_server_elicitation;
 mod mcp_server_status;
 mod mcp_tool;
 mod memory_reset;
+mod model_get;
 mod model_list;
 mod model_provider_capabilities_read;
+mod model_resolve;
+mod models_refresh;
 mod output_schema;
 mod permission_profile_list;
 mod plan_item;
@@ -63,6 +67,7 @@ mod selected_capability_stack;
 mod selected_environment;
 mod skills_list;
 mod sleep;
+mod statusline_config;
 mod thread_archive;
 mod thread_delete;
 mod thread_fork;
@@ -79,11 +84,14 @@ mod thread_settings_update;
 mod thread_shell_command;
 mod thread_start;
 mod thread_status;
+mod thread_turn_completed;
 mod thread_unarchive;
 mod thread_unsubscribe;
+mod translation_test;
 mod turn_interrupt;
 mod turn_start;
 mod turn_start_zsh_fork;
 mod turn_steer;
+mod usage_get;
 mod web_search;
 mod windows_sandbox_setup;
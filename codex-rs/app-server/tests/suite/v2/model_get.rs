@@ -0,0 +1,158 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use app_test_support::TestAppServer;
+use app_test_support::to_response;
+use app_test_support::write_models_cache;
+use codex_app_server_protocol::JSONRPCError;
+use codex_app_server_protocol::JSONRPCResponse;
+use codex_app_server_protocol::ModelGetParams;
+use codex_app_server_protocol::ModelGetResponse;
+use codex_app_server_protocol::RequestId;
+use codex_protocol::openai_models::ModelPreset;
+use pretty_assertions::assert_eq;
+use tempfile::TempDir;
+use tokio::time::timeout;
+
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+const NOT_FOUND_ERROR_CODE: i64 = -32004;
+
+async fn started_server() -> Result<(TempDir, TestAppServer)> {
+    let codex_home = TempDir::new()?;
+    write_models_cache(codex_home.path())?;
+    let mut mcp = TestAppServer::builder()
+        .with_codex_home(codex_home.path())
+        .without_auto_env()
+        .build()
+        .await?;
+    timeout(DEFAULT_TIMEOUT, mcp.initialize()).await??;
+    Ok((codex_home, mcp))
+}
+
+fn any_cached_preset() -> ModelPreset {
+    codex_core::test_support::all_model_presets()
+        .iter()
+        .find(|preset| preset.show_in_picker)
+        .cloned()
+        .expect("models cache fixture has at least one visible preset")
+}
+
+#[tokio::test]
+async fn model_get_returns_full_record_by_id() -> Result<()> {
+    let (_codex_home, mut mcp) = started_server().await?;
+    let preset = any_cached_preset();
+
+    let request_id = mcp
+        .send_model_get_request(ModelGetParams {
+            id: preset.id.clone(),
+            config_profile: None,
+        })
+        .await?;
+
+    let response: JSONRPCResponse = timeout(
+        DEFAULT_TIMEOUT,
+        mcp.read_stream_until_response_message(RequestId::Integer(request_id)),
+    )
+    .await??;
+
+    let ModelGetResponse { model } = to_response::<ModelGetResponse>(response)?;
+    assert_eq!(model.id, preset.id);
+    assert_eq!(model.display_name, preset.display_name);
+    assert_eq!(model.hidden, !preset.show_in_picker);
+    assert_eq!(
+        model.upgrade,
+        preset.upgrade.as_ref().map(|upgrade| upgrade.id.clone())
+    );
+    assert_eq!(
+        model.supported_reasoning_efforts.len(),
+        preset.supported_reasoning_efforts.len()
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn model_get_resolves_model_slug_alias() -> Result<()> {
+    let (_codex_home, mut mcp) = started_server().await?;
+    let preset = any_cached_preset();
+
+    // Querying by the model slug returns the same record as the preset id.
+    let request_id = mcp
+        .send_model_get_request(ModelGetParams {
+            id: preset.model.clone(),
+            config_profile: None,
+        })
+        .await?;
+
+    let response: JSONRPCResponse = timeout(
+        DEFAULT_TIMEOUT,
+        mcp.read_stream_until_response_message(RequestId::Integer(request_id)),
+    )
+    .await??;
+
+    let ModelGetResponse { model } = to_response::<ModelGetResponse>(response)?;
+    assert_eq!(model.id, preset.id);
+    assert_eq!(model.model, preset.model);
+    Ok(())
+}
+
+#[tokio::test]
+async fn model_get_unknown_id_returns_not_found() -> Result<()> {
+    let (_codex_home, mut mcp) = started_server().await?;
+
+    let request_id = mcp
+        .send_model_get_request(ModelGetParams {
+            id: "no-such-model".to_string(),
+            config_profile: None,
+        })
+        .await?;
+
+    let error: JSONRPCError = timeout(
+        DEFAULT_TIMEOUT,
+        mcp.read_stream_until_error_message(RequestId::Integer(request_id)),
+    )
+    .await??;
+
+    assert_eq!(error.id, RequestId::Integer(request_id));
+    assert_eq!(error.error.code, NOT_FOUND_ERROR_CODE);
+    assert_eq!(error.error.message, "model not found: no-such-model");
+    Ok(())
+}
+
+#[tokio::test]
+async fn model_get_config_profile_overrides_default_flag() -> Result<()> {
+    let (codex_home, mut mcp) = started_server().await?;
+    // Pick a model that is not the catalog default so the profile override is
+    // observable.
+    let mut presets = codex_core::test_support::all_model_presets().clone();
+    ModelPreset::mark_default_by_picker_visibility(&mut presets);
+    let pinned = presets
+        .iter()
+        .find(|preset| preset.show_in_picker && !preset.is_default)
+        .cloned()
+        .expect("models cache fixture has a non-default visible preset");
+    std::fs::write(
+        codex_home.path().join("work.config.toml"),
+        format!("model = \"{}\"\n", pinned.model),
+    )?;
+
+    let request_id = mcp
+        .send_model_get_request(ModelGetParams {
+            id: pinned.id.clone(),
+            config_profile: Some("work".to_string()),
+        })
+        .await?;
+
+    let response: JSONRPCResponse = timeout(
+        DEFAULT_TIMEOUT,
+        mcp.read_stream_until_response_message(RequestId::Integer(request_id)),
+    )
+    .await??;
+
+    let ModelGetResponse { model } = to_response::<ModelGetResponse>(response)?;
+    assert_eq!(model.id, pinned.id);
+    assert!(
+        model.is_default,
+        "the profile's pinned model should be reported as the default"
+    );
+    Ok(())
+}
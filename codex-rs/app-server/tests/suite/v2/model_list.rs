@@ -7,15 +7,22 @@ use app_test_support::TestAppServer;
 use app_test_support::to_response;
 use app_test_support::write_chatgpt_auth;
 use app_test_support::write_models_cache;
+use app_test_support::write_models_cache_with_models;
 use codex_app_server_protocol::JSONRPCError;
 use codex_app_server_protocol::JSONRPCResponse;
 use codex_app_server_protocol::Model;
+use codex_app_server_protocol::ModelCatalogSource;
+use codex_app_server_protocol::ModelListMeta;
 use codex_app_server_protocol::ModelListParams;
 use codex_app_server_protocol::ModelListResponse;
 use codex_app_server_protocol::ModelServiceTier;
+use codex_app_server_protocol::ModelSortBy;
 use codex_app_server_protocol::ModelUpgradeInfo;
+use codex_app_server_protocol::ModelsRefreshParams;
+use codex_app_server_protocol::ModelsRefreshResponse;
 use codex_app_server_protocol::ReasoningEffortOption;
 use codex_app_server_protocol::RequestId;
+use codex_app_server_protocol::SortDirection;
 use codex_config::types::AuthCredentialsStoreMode;
 use codex_protocol::openai_models::ModelInfo;
 use codex_protocol::openai_models::ModelPreset;
@@ -29,8 +36,9 @@ use wiremock::MockServer;
 
 const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
 const INVALID_REQUEST_ERROR_CODE: i64 = -32600;
+const CURSOR_EXPIRED_ERROR_CODE: i64 = -32005;
 
-fn model_from_preset(preset: &ModelPreset) -> Model {
+fn model_from_preset(preset: &ModelPreset, catalog: &[ModelPreset]) -> Model {
     Model {
         id: preset.id.clone(),
         model: preset.model.clone(),
@@ -41,6 +49,9 @@ fn model_from_preset(preset: &ModelPreset) -> Model {
             model_link: upgrade.model_link.clone(),
             migration_markdown: upgrade.migration_markdown.clone(),
         }),
+        deprecated: preset.deprecated,
+        deprecation_message: preset.deprecation_message.clone(),
+        upgrade_chain: preset.upgrade_chain(catalog),
         availability_nux: preset.availability_nux.clone().map(Into::into),
         display_name: preset.display_name.clone(),
         description: preset.description.clone(),
@@ -53,7 +64,8 @@ fn model_from_preset(preset: &ModelPreset) -> Model {
                 description: preset.description.clone(),
             })
             .collect(),
-        default_reasoning_effort: preset.default_reasoning_effort.clone(),
+        default_reasoning_effort: (!preset.supported_reasoning_efforts.is_empty())
+            .then(|| preset.default_reasoning_effort.clone()),
         input_modalities: preset.input_modalities.clone(),
         // `write_models_cache()` round-trips through a simplified ModelInfo fixture that does not
         // preserve personality placeholders in base instructions, so app-server list results from
@@ -71,6 +83,10 @@ fn model_from_preset(preset: &ModelPreset) -> Model {
             })
             .collect(),
         default_service_tier: preset.default_service_tier.clone(),
+        context_window: preset.context_window,
+        max_output_tokens: preset.max_output_tokens,
+        supports_parallel_tool_calls: preset.supports_parallel_tool_calls,
+        supports_reasoning_summaries: preset.supports_reasoning_summaries,
         is_default: preset.is_default,
     }
 }
@@ -88,10 +104,98 @@ fn expected_visible_models() -> Vec<Model> {
     presets
         .iter()
         .filter(|preset| preset.show_in_picker)
-        .map(model_from_preset)
+        .map(|preset| model_from_preset(preset, &presets))
         .collect()
 }
 
+async fn list_models_with_params(
+    mcp: &mut TestAppServer,
+    params: ModelListParams,
+) -> Result<ModelListResponse> {
+    let request_id = mcp.send_list_models_request(params).await?;
+    let response: JSONRPCResponse = timeout(
+        DEFAULT_TIMEOUT,
+        mcp.read_stream_until_response_message(RequestId::Integer(request_id)),
+    )
+    .await??;
+    to_response::<ModelListResponse>(response)
+}
+
+fn remote_model_with_release_date(
+    slug: &str,
+    priority: i32,
+    release_date: Option<&str>,
+) -> Result<ModelInfo> {
+    let model = serde_json::from_value(json!({
+        "slug": slug,
+        "display_name": slug,
+        "description": "Remote model for app-server model/list sort coverage",
+        "default_reasoning_level": "low",
+        "supported_reasoning_levels": [
+            {"effort": "low", "description": "Low"}
+        ],
+        "shell_type": "shell_command",
+        "visibility": "list",
+        "minimal_client_version": [0, 1, 0],
+        "supported_in_api": true,
+        "priority": priority,
+        "upgrade": null,
+        "base_instructions": "base instructions",
+        "supports_reasoning_summaries": false,
+        "support_verbosity": false,
+        "default_verbosity": null,
+        "apply_patch_tool_type": null,
+        "truncation_policy": {"mode": "bytes", "limit": 10_000},
+        "supports_parallel_tool_calls": false,
+        "supports_image_detail_original": false,
+        "context_window": 272_000,
+        "max_context_window": 272_000,
+        "experimental_supported_tools": [],
+        "release_date": release_date,
+    }))?;
+    Ok(model)
+}
+
+fn remote_model_with_upgrade(
+    slug: &str,
+    priority: i32,
+    upgrade: Option<&str>,
+    deprecated: bool,
+    deprecation_message: Option<&str>,
+) -> Result<ModelInfo> {
+    let model = serde_json::from_value(json!({
+        "slug": slug,
+        "display_name": slug,
+        "description": "Remote model for app-server model/list deprecation coverage",
+        "default_reasoning_level": "low",
+        "supported_reasoning_levels": [
+            {"effort": "low", "description": "Low"}
+        ],
+        "shell_type": "shell_command",
+        "visibility": "list",
+        "minimal_client_version": [0, 1, 0],
+        "supported_in_api": true,
+        "priority": priority,
+        "upgrade": upgrade.map(|target| {
+            json!({"model": target, "migration_markdown": format!("Switch to {target}.")})
+        }),
+        "base_instructions": "base instructions",
+        "supports_reasoning_summaries": false,
+        "support_verbosity": false,
+        "default_verbosity": null,
+        "apply_patch_tool_type": null,
+        "truncation_policy": {"mode": "bytes", "limit": 10_000},
+        "supports_parallel_tool_calls": false,
+        "supports_image_detail_original": false,
+        "context_window": 272_000,
+        "max_context_window": 272_000,
+        "experimental_supported_tools": [],
+        "deprecated": deprecated,
+        "deprecation_message": deprecation_message,
+    }))?;
+    Ok(model)
+}
+
 #[tokio::test]
 async fn list_models_returns_all_models_with_large_limit() -> Result<()> {
     let codex_home = TempDir::new()?;
@@ -109,6 +213,7 @@ async fn list_models_returns_all_models_with_large_limit() -> Result<()> {
             limit: Some(100),
             cursor: None,
             include_hidden: None,
+            ..Default::default()
         })
         .await?;
 
@@ -121,6 +226,7 @@ async fn list_models_returns_all_models_with_large_limit() -> Result<()> {
     let ModelListResponse {
         data: items,
         next_cursor,
+        ..
     } = to_response::<ModelListResponse>(response)?;
 
     let expected_models = expected_visible_models();
@@ -130,6 +236,168 @@ async fn list_models_returns_all_models_with_large_limit() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn list_models_reports_token_limit_metadata_for_default_model() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    write_models_cache(codex_home.path())?;
+    let mut mcp = TestAppServer::builder()
+        .with_codex_home(codex_home.path())
+        .without_auto_env()
+        .build()
+        .await?;
+
+    timeout(DEFAULT_TIMEOUT, mcp.initialize()).await??;
+
+    let request_id = mcp
+        .send_list_models_request(ModelListParams {
+            limit: Some(100),
+            ..Default::default()
+        })
+        .await?;
+
+    let response: JSONRPCResponse = timeout(
+        DEFAULT_TIMEOUT,
+        mcp.read_stream_until_response_message(RequestId::Integer(request_id)),
+    )
+    .await??;
+
+    let ModelListResponse { data: items, .. } = to_response::<ModelListResponse>(response)?;
+    let default_model = items
+        .iter()
+        .find(|item| item.is_default)
+        .expect("cache fixture marks a default model");
+
+    assert!(
+        default_model
+            .context_window
+            .is_some_and(|context_window| context_window > 0),
+        "default model should advertise a context window"
+    );
+    assert!(default_model.supports_parallel_tool_calls);
+    assert!(default_model.supports_reasoning_summaries);
+    // The bundled catalog does not publish per-model output limits yet.
+    assert_eq!(default_model.max_output_tokens, None);
+    Ok(())
+}
+
+#[tokio::test]
+async fn list_models_default_model_advertises_reasoning_efforts() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    write_models_cache(codex_home.path())?;
+    let mut mcp = TestAppServer::builder()
+        .with_codex_home(codex_home.path())
+        .without_auto_env()
+        .build()
+        .await?;
+
+    timeout(DEFAULT_TIMEOUT, mcp.initialize()).await??;
+
+    let response = list_models_with_params(
+        &mut mcp,
+        ModelListParams {
+            limit: Some(100),
+            ..Default::default()
+        },
+    )
+    .await?;
+    let default_model = response
+        .data
+        .iter()
+        .find(|item| item.is_default)
+        .expect("cache fixture marks a default model");
+
+    assert!(
+        !default_model.supported_reasoning_efforts.is_empty(),
+        "default model should advertise its reasoning efforts"
+    );
+    let default_effort = default_model
+        .default_reasoning_effort
+        .as_ref()
+        .expect("default model should advertise a default reasoning effort");
+    assert!(
+        default_model
+            .supported_reasoning_efforts
+            .iter()
+            .any(|option| option.reasoning_effort == *default_effort),
+        "default effort should be one of the supported efforts"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn list_models_non_reasoning_model_omits_reasoning_fields() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    let non_reasoning: ModelInfo = serde_json::from_value(json!({
+        "slug": "computer-use-preview",
+        "display_name": "Computer Use Preview",
+        "description": "Remote model without reasoning support",
+        "default_reasoning_level": null,
+        "supported_reasoning_levels": [],
+        "shell_type": "shell_command",
+        "visibility": "list",
+        "minimal_client_version": [0, 1, 0],
+        "supported_in_api": true,
+        "priority": 0,
+        "upgrade": null,
+        "base_instructions": "base instructions",
+        "supports_reasoning_summaries": false,
+        "support_verbosity": false,
+        "default_verbosity": null,
+        "apply_patch_tool_type": null,
+        "truncation_policy": {"mode": "bytes", "limit": 10_000},
+        "supports_parallel_tool_calls": false,
+        "supports_image_detail_original": false,
+        "context_window": 272_000,
+        "max_context_window": 272_000,
+        "experimental_supported_tools": [],
+    }))?;
+    write_models_cache_with_models(codex_home.path(), vec![non_reasoning])?;
+    let mut mcp = TestAppServer::builder()
+        .with_codex_home(codex_home.path())
+        .without_auto_env()
+        .build()
+        .await?;
+
+    timeout(DEFAULT_TIMEOUT, mcp.initialize()).await??;
+
+    let request_id = mcp
+        .send_list_models_request(ModelListParams {
+            limit: Some(100),
+            ..Default::default()
+        })
+        .await?;
+    let response: JSONRPCResponse = timeout(
+        DEFAULT_TIMEOUT,
+        mcp.read_stream_until_response_message(RequestId::Integer(request_id)),
+    )
+    .await??;
+
+    // Assert against the raw payload: a non-reasoning model must omit the
+    // reasoning fields entirely, not serialize empty placeholders.
+    let raw_item = response
+        .result
+        .get("data")
+        .and_then(|data| data.as_array())
+        .and_then(|items| {
+            items.iter().find(|item| {
+                item.get("id").and_then(|id| id.as_str()) == Some("computer-use-preview")
+            })
+        })
+        .cloned()
+        .expect("non-reasoning model is listed");
+    assert!(raw_item.get("supportedReasoningEfforts").is_none());
+    assert!(raw_item.get("defaultReasoningEffort").is_none());
+
+    let ModelListResponse { data: items, .. } = to_response::<ModelListResponse>(response)?;
+    let item = items
+        .iter()
+        .find(|item| item.id == "computer-use-preview")
+        .expect("non-reasoning model is listed");
+    assert!(item.supported_reasoning_efforts.is_empty());
+    assert_eq!(item.default_reasoning_effort, None);
+    Ok(())
+}
+
 #[tokio::test]
 async fn list_models_includes_hidden_models() -> Result<()> {
     let codex_home = TempDir::new()?;
@@ -147,6 +415,7 @@ async fn list_models_includes_hidden_models() -> Result<()> {
             limit: Some(100),
             cursor: None,
             include_hidden: Some(true),
+            ..Default::default()
         })
         .await?;
 
@@ -159,6 +428,7 @@ async fn list_models_includes_hidden_models() -> Result<()> {
     let ModelListResponse {
         data: items,
         next_cursor,
+        ..
     } = to_response::<ModelListResponse>(response)?;
 
     assert!(items.iter().any(|item| item.hidden));
@@ -237,6 +507,7 @@ openai_base_url = "{server_uri}/v1"
             limit: Some(100),
             cursor: None,
             include_hidden: None,
+            ..Default::default()
         })
         .await?;
 
@@ -249,12 +520,13 @@ openai_base_url = "{server_uri}/v1"
     let ModelListResponse {
         data: items,
         next_cursor,
+        ..
     } = to_response::<ModelListResponse>(response)?;
     let mut expected_presets: Vec<ModelPreset> = vec![remote_model.into()];
     ModelPreset::mark_default_by_picker_visibility(&mut expected_presets);
     let mut expected_items = expected_presets
         .iter()
-        .map(model_from_preset)
+        .map(|preset| model_from_preset(preset, &expected_presets))
         .collect::<Vec<_>>();
     expected_items[0].supported_reasoning_efforts = vec![
         ReasoningEffortOption {
@@ -296,6 +568,7 @@ async fn list_models_pagination_works() -> Result<()> {
     let expected_models = expected_visible_models();
     let mut cursor = None;
     let mut items = Vec::new();
+    let mut expected_meta: Option<ModelListMeta> = None;
 
     for _ in 0..expected_models.len() {
         let request_id = mcp
@@ -303,6 +576,7 @@ async fn list_models_pagination_works() -> Result<()> {
                 limit: Some(1),
                 cursor: cursor.clone(),
                 include_hidden: None,
+                ..Default::default()
             })
             .await?;
 
@@ -315,9 +589,18 @@ async fn list_models_pagination_works() -> Result<()> {
         let ModelListResponse {
             data: page_items,
             next_cursor,
+            meta,
+            ..
         } = to_response::<ModelListResponse>(response)?;
 
         assert_eq!(page_items.len(), 1);
+        // Meta is computed before pagination, so every page reports the
+        // same catalog-wide values.
+        assert_eq!(meta.total as usize, expected_models.len());
+        match &expected_meta {
+            Some(expected_meta) => assert_eq!(&meta, expected_meta),
+            None => expected_meta = Some(meta),
+        }
         items.extend(page_items);
 
         if let Some(next_cursor) = next_cursor {
@@ -351,6 +634,7 @@ async fn list_models_rejects_invalid_cursor() -> Result<()> {
             limit: None,
             cursor: Some("invalid".to_string()),
             include_hidden: None,
+            ..Default::default()
         })
         .await?;
 
@@ -365,3 +649,1058 @@ async fn list_models_rejects_invalid_cursor() -> Result<()> {
     assert_eq!(error.error.message, "invalid cursor: invalid");
     Ok(())
 }
+
+#[tokio::test]
+async fn list_models_filters_by_id_prefix_with_pagination() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    write_models_cache(codex_home.path())?;
+    let mut mcp = TestAppServer::builder()
+        .with_codex_home(codex_home.path())
+        .without_auto_env()
+        .build()
+        .await?;
+
+    timeout(DEFAULT_TIMEOUT, mcp.initialize()).await??;
+
+    let expected_models: Vec<Model> = expected_visible_models()
+        .into_iter()
+        .filter(|model| model.id.starts_with("gpt-5"))
+        .collect();
+    assert!(!expected_models.is_empty());
+
+    // Cursors stay consistent within the filtered view.
+    let mut cursor = None;
+    let mut items = Vec::new();
+    for _ in 0..expected_models.len() {
+        let request_id = mcp
+            .send_list_models_request(ModelListParams {
+                limit: Some(1),
+                cursor: cursor.clone(),
+                id_prefix: Some("gpt-5".to_string()),
+                ..Default::default()
+            })
+            .await?;
+
+        let response: JSONRPCResponse = timeout(
+            DEFAULT_TIMEOUT,
+            mcp.read_stream_until_response_message(RequestId::Integer(request_id)),
+        )
+        .await??;
+
+        let ModelListResponse {
+            data: page_items,
+            next_cursor,
+            meta,
+            ..
+        } = to_response::<ModelListResponse>(response)?;
+
+        assert_eq!(page_items.len(), 1);
+        // Meta is computed before pagination, so every page reports the
+        // same catalog-wide values.
+        assert_eq!(meta.total as usize, expected_models.len());
+        match &expected_meta {
+            Some(expected_meta) => assert_eq!(&meta, expected_meta),
+            None => expected_meta = Some(meta),
+        }
+        items.extend(page_items);
+
+        match next_cursor {
+            Some(next_cursor) => cursor = Some(next_cursor),
+            None => {
+                assert_eq!(items, expected_models);
+                return Ok(());
+            }
+        }
+    }
+
+    panic!(
+        "filtered pagination did not terminate after {} pages",
+        expected_models.len()
+    );
+}
+
+#[tokio::test]
+async fn list_models_filters_compose_with_include_hidden() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    write_models_cache(codex_home.path())?;
+    let mut mcp = TestAppServer::builder()
+        .with_codex_home(codex_home.path())
+        .without_auto_env()
+        .build()
+        .await?;
+
+    timeout(DEFAULT_TIMEOUT, mcp.initialize()).await??;
+
+    let request_id = mcp
+        .send_list_models_request(ModelListParams {
+            limit: Some(100),
+            include_hidden: Some(true),
+            supports_reasoning: Some(true),
+            ..Default::default()
+        })
+        .await?;
+
+    let response: JSONRPCResponse = timeout(
+        DEFAULT_TIMEOUT,
+        mcp.read_stream_until_response_message(RequestId::Integer(request_id)),
+    )
+    .await??;
+
+    let ModelListResponse {
+        data: items,
+        next_cursor,
+        ..
+    } = to_response::<ModelListResponse>(response)?;
+
+    // Hidden models still show up, but only reasoning-capable ones.
+    assert!(items.iter().any(|item| item.hidden));
+    assert!(
+        items
+            .iter()
+            .all(|item| !item.supported_reasoning_efforts.is_empty())
+    );
+    assert!(next_cursor.is_none());
+    Ok(())
+}
+
+#[tokio::test]
+async fn list_models_unknown_provider_returns_empty_list() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    write_models_cache(codex_home.path())?;
+    let mut mcp = TestAppServer::builder()
+        .with_codex_home(codex_home.path())
+        .without_auto_env()
+        .build()
+        .await?;
+
+    timeout(DEFAULT_TIMEOUT, mcp.initialize()).await??;
+
+    let request_id = mcp
+        .send_list_models_request(ModelListParams {
+            provider: Some("not-a-provider".to_string()),
+            ..Default::default()
+        })
+        .await?;
+
+    let response: JSONRPCResponse = timeout(
+        DEFAULT_TIMEOUT,
+        mcp.read_stream_until_response_message(RequestId::Integer(request_id)),
+    )
+    .await??;
+
+    let ModelListResponse {
+        data: items,
+        next_cursor,
+        ..
+    } = to_response::<ModelListResponse>(response)?;
+
+    assert_eq!(items, Vec::<Model>::new());
+    assert!(next_cursor.is_none());
+    Ok(())
+}
+
+#[tokio::test]
+async fn list_models_sorts_by_id_in_both_directions() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    write_models_cache(codex_home.path())?;
+    let mut mcp = TestAppServer::builder()
+        .with_codex_home(codex_home.path())
+        .without_auto_env()
+        .build()
+        .await?;
+
+    timeout(DEFAULT_TIMEOUT, mcp.initialize()).await??;
+
+    let mut expected_models = expected_visible_models();
+    expected_models.sort_by(|a, b| a.id.cmp(&b.id));
+
+    let ascending = list_models_with_params(
+        &mut mcp,
+        ModelListParams {
+            limit: Some(100),
+            sort_by: Some(ModelSortBy::Id),
+            ..Default::default()
+        },
+    )
+    .await?;
+    assert_eq!(ascending.data, expected_models);
+
+    expected_models.reverse();
+    let descending = list_models_with_params(
+        &mut mcp,
+        ModelListParams {
+            limit: Some(100),
+            sort_by: Some(ModelSortBy::Id),
+            order: Some(SortDirection::Desc),
+            ..Default::default()
+        },
+    )
+    .await?;
+    assert_eq!(descending.data, expected_models);
+    Ok(())
+}
+
+#[tokio::test]
+async fn list_models_priority_desc_reverses_catalog_order() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    write_models_cache(codex_home.path())?;
+    let mut mcp = TestAppServer::builder()
+        .with_codex_home(codex_home.path())
+        .without_auto_env()
+        .build()
+        .await?;
+
+    timeout(DEFAULT_TIMEOUT, mcp.initialize()).await??;
+
+    let mut expected_models = expected_visible_models();
+    expected_models.reverse();
+
+    let response = list_models_with_params(
+        &mut mcp,
+        ModelListParams {
+            limit: Some(100),
+            sort_by: Some(ModelSortBy::Priority),
+            order: Some(SortDirection::Desc),
+            ..Default::default()
+        },
+    )
+    .await?;
+    assert_eq!(response.data, expected_models);
+    Ok(())
+}
+
+#[tokio::test]
+async fn list_models_sorts_by_release_date_with_undated_models_last() -> Result<()> {
+    let server = MockServer::start().await;
+    let catalog: Vec<ModelInfo> = vec![
+        remote_model_with_release_date("model-mid", /*priority*/ 0, Some("2024-09-30"))?,
+        remote_model_with_release_date("model-new", /*priority*/ 1, Some("2025-06-01"))?,
+        remote_model_with_release_date("model-old", /*priority*/ 2, Some("2024-01-15"))?,
+        remote_model_with_release_date("model-undated", /*priority*/ 3, None)?,
+    ];
+    mount_models_once(&server, ModelsResponse { models: catalog }).await;
+
+    let codex_home = TempDir::new()?;
+    let server_uri = server.uri();
+    std::fs::write(
+        codex_home.path().join("config.toml"),
+        format!(
+            r#"
+model = "mock-model"
+approval_policy = "never"
+sandbox_mode = "read-only"
+openai_base_url = "{server_uri}/v1"
+"#
+        ),
+    )?;
+    write_chatgpt_auth(
+        codex_home.path(),
+        ChatGptAuthFixture::new("chatgpt-access-token").plan_type("pro"),
+        AuthCredentialsStoreMode::File,
+    )?;
+
+    let mut mcp = TestAppServer::builder()
+        .with_codex_home(codex_home.path())
+        .without_auto_env()
+        .with_env_overrides(&[("OPENAI_API_KEY", None)])
+        .build()
+        .await?;
+    timeout(DEFAULT_TIMEOUT, mcp.initialize()).await??;
+
+    let ascending = list_models_with_params(
+        &mut mcp,
+        ModelListParams {
+            sort_by: Some(ModelSortBy::ReleaseDate),
+            ..Default::default()
+        },
+    )
+    .await?;
+    let ascending_ids: Vec<String> = ascending.data.iter().map(|item| item.id.clone()).collect();
+    assert_eq!(
+        ascending_ids,
+        vec!["model-old", "model-mid", "model-new", "model-undated"]
+    );
+
+    let descending = list_models_with_params(
+        &mut mcp,
+        ModelListParams {
+            sort_by: Some(ModelSortBy::ReleaseDate),
+            order: Some(SortDirection::Desc),
+            ..Default::default()
+        },
+    )
+    .await?;
+    let descending_ids: Vec<String> = descending.data.iter().map(|item| item.id.clone()).collect();
+    // Models without a release date stay last in either direction.
+    assert_eq!(
+        descending_ids,
+        vec!["model-new", "model-mid", "model-old", "model-undated"]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn list_models_cursor_round_trips_within_sort() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    write_models_cache(codex_home.path())?;
+    let mut mcp = TestAppServer::builder()
+        .with_codex_home(codex_home.path())
+        .without_auto_env()
+        .build()
+        .await?;
+
+    timeout(DEFAULT_TIMEOUT, mcp.initialize()).await??;
+
+    let mut expected_models = expected_visible_models();
+    expected_models.sort_by(|a, b| a.id.cmp(&b.id));
+
+    let mut cursor = None;
+    let mut items = Vec::new();
+    for _ in 0..expected_models.len() {
+        let ModelListResponse {
+            data: page_items,
+            next_cursor,
+            ..
+        } = list_models_with_params(
+            &mut mcp,
+            ModelListParams {
+                limit: Some(1),
+                cursor: cursor.clone(),
+                sort_by: Some(ModelSortBy::Id),
+                ..Default::default()
+            },
+        )
+        .await?;
+
+        assert_eq!(page_items.len(), 1);
+        items.extend(page_items);
+
+        match next_cursor {
+            Some(next_cursor) => cursor = Some(next_cursor),
+            None => {
+                assert_eq!(items, expected_models);
+                return Ok(());
+            }
+        }
+    }
+
+    panic!(
+        "sorted pagination did not terminate after {} pages",
+        expected_models.len()
+    );
+}
+
+#[tokio::test]
+async fn list_models_rejects_cursor_from_a_different_sort() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    write_models_cache(codex_home.path())?;
+    let mut mcp = TestAppServer::builder()
+        .with_codex_home(codex_home.path())
+        .without_auto_env()
+        .build()
+        .await?;
+
+    timeout(DEFAULT_TIMEOUT, mcp.initialize()).await??;
+
+    let first_page = list_models_with_params(
+        &mut mcp,
+        ModelListParams {
+            limit: Some(1),
+            sort_by: Some(ModelSortBy::Id),
+            ..Default::default()
+        },
+    )
+    .await?;
+    let cursor = first_page
+        .next_cursor
+        .expect("a single-item page should produce a cursor");
+
+    let request_id = mcp
+        .send_list_models_request(ModelListParams {
+            limit: Some(1),
+            cursor: Some(cursor.clone()),
+            sort_by: Some(ModelSortBy::ReleaseDate),
+            ..Default::default()
+        })
+        .await?;
+
+    let error: JSONRPCError = timeout(
+        DEFAULT_TIMEOUT,
+        mcp.read_stream_until_error_message(RequestId::Integer(request_id)),
+    )
+    .await??;
+
+    assert_eq!(error.id, RequestId::Integer(request_id));
+    assert_eq!(error.error.code, INVALID_REQUEST_ERROR_CODE);
+    assert_eq!(error.error.message, format!("invalid cursor: {cursor}"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn list_models_reports_deprecation_and_full_upgrade_chain() -> Result<()> {
+    let server = MockServer::start().await;
+    let catalog: Vec<ModelInfo> = vec![
+        remote_model_with_upgrade(
+            "model-a",
+            /*priority*/ 0,
+            Some("model-b"),
+            /*deprecated*/ true,
+            Some("model-a is retired; use model-c."),
+        )?,
+        remote_model_with_upgrade("model-b", /*priority*/ 1, Some("model-c"), false, None)?,
+        remote_model_with_upgrade("model-c", /*priority*/ 2, None, false, None)?,
+    ];
+    mount_models_once(&server, ModelsResponse { models: catalog }).await;
+
+    let codex_home = TempDir::new()?;
+    let server_uri = server.uri();
+    std::fs::write(
+        codex_home.path().join("config.toml"),
+        format!(
+            r#"
+model = "mock-model"
+approval_policy = "never"
+sandbox_mode = "read-only"
+openai_base_url = "{server_uri}/v1"
+"#
+        ),
+    )?;
+    write_chatgpt_auth(
+        codex_home.path(),
+        ChatGptAuthFixture::new("chatgpt-access-token").plan_type("pro"),
+        AuthCredentialsStoreMode::File,
+    )?;
+
+    let mut mcp = TestAppServer::builder()
+        .with_codex_home(codex_home.path())
+        .without_auto_env()
+        .with_env_overrides(&[("OPENAI_API_KEY", None)])
+        .build()
+        .await?;
+    timeout(DEFAULT_TIMEOUT, mcp.initialize()).await??;
+
+    let ModelListResponse { data: items, .. } =
+        list_models_with_params(&mut mcp, ModelListParams::default()).await?;
+
+    let model_a = items
+        .iter()
+        .find(|item| item.id == "model-a")
+        .expect("model-a should be listed");
+    assert!(model_a.deprecated);
+    assert_eq!(
+        model_a.deprecation_message.as_deref(),
+        Some("model-a is retired; use model-c.")
+    );
+    // The chain follows upgrade links transitively to the current model.
+    assert_eq!(model_a.upgrade_chain, vec!["model-b", "model-c"]);
+
+    let model_c = items
+        .iter()
+        .find(|item| item.id == "model-c")
+        .expect("model-c should be listed");
+    assert!(!model_c.deprecated);
+    assert_eq!(model_c.deprecation_message, None);
+    assert_eq!(model_c.upgrade_chain, Vec::<String>::new());
+    Ok(())
+}
+
+#[tokio::test]
+async fn list_models_matching_etag_short_circuits() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    write_models_cache(codex_home.path())?;
+    let mut mcp = TestAppServer::builder()
+        .with_codex_home(codex_home.path())
+        .without_auto_env()
+        .build()
+        .await?;
+    timeout(DEFAULT_TIMEOUT, mcp.initialize()).await??;
+
+    let first = list_models_with_params(&mut mcp, ModelListParams::default()).await?;
+    assert!(!first.etag.is_empty());
+    assert!(!first.not_modified);
+    assert!(!first.data.is_empty());
+
+    let second = list_models_with_params(
+        &mut mcp,
+        ModelListParams {
+            if_none_match: Some(first.etag.clone()),
+            ..Default::default()
+        },
+    )
+    .await?;
+    assert!(second.not_modified);
+    assert_eq!(second.etag, first.etag);
+    assert!(second.data.is_empty());
+    assert!(second.next_cursor.is_none());
+    Ok(())
+}
+
+#[tokio::test]
+async fn list_models_include_hidden_changes_etag() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    write_models_cache(codex_home.path())?;
+    let mut mcp = TestAppServer::builder()
+        .with_codex_home(codex_home.path())
+        .without_auto_env()
+        .build()
+        .await?;
+    timeout(DEFAULT_TIMEOUT, mcp.initialize()).await??;
+
+    let visible = list_models_with_params(&mut mcp, ModelListParams::default()).await?;
+    let hidden = list_models_with_params(
+        &mut mcp,
+        ModelListParams {
+            include_hidden: Some(true),
+            ..Default::default()
+        },
+    )
+    .await?;
+    assert_ne!(visible.etag, hidden.etag);
+
+    // An etag from one view must not short-circuit the other.
+    let crossed = list_models_with_params(
+        &mut mcp,
+        ModelListParams {
+            include_hidden: Some(true),
+            if_none_match: Some(visible.etag),
+            ..Default::default()
+        },
+    )
+    .await?;
+    assert!(!crossed.not_modified);
+    assert_eq!(crossed.etag, hidden.etag);
+    assert_eq!(crossed.data, hidden.data);
+    Ok(())
+}
+
+#[tokio::test]
+async fn list_models_etag_changes_when_catalog_refreshes() -> Result<()> {
+    let server = MockServer::start().await;
+    // The startup refresh consumes the initial single-model catalog.
+    mount_models_once(
+        &server,
+        ModelsResponse {
+            models: vec![remote_model_with_release_date("remote-a", 0, None)?],
+        },
+    )
+    .await;
+
+    let codex_home = TempDir::new()?;
+    let server_uri = server.uri();
+    std::fs::write(
+        codex_home.path().join("config.toml"),
+        format!(
+            r#"
+model = "mock-model"
+approval_policy = "never"
+sandbox_mode = "read-only"
+openai_base_url = "{server_uri}/v1"
+"#
+        ),
+    )?;
+    write_chatgpt_auth(
+        codex_home.path(),
+        ChatGptAuthFixture::new("chatgpt-access-token").plan_type("pro"),
+        AuthCredentialsStoreMode::File,
+    )?;
+
+    let mut mcp = TestAppServer::builder()
+        .with_codex_home(codex_home.path())
+        .without_auto_env()
+        .with_env_overrides(&[("OPENAI_API_KEY", None)])
+        .build()
+        .await?;
+    timeout(DEFAULT_TIMEOUT, mcp.initialize()).await??;
+
+    let before = list_models_with_params(&mut mcp, ModelListParams::default()).await?;
+
+    // Refresh against a catalog that gained a model.
+    mount_models_once(
+        &server,
+        ModelsResponse {
+            models: vec![
+                remote_model_with_release_date("remote-a", 0, None)?,
+                remote_model_with_release_date("remote-b", 1, None)?,
+            ],
+        },
+    )
+    .await;
+    let refresh_id = mcp
+        .send_models_refresh_request(ModelsRefreshParams {})
+        .await?;
+    let refresh_resp: JSONRPCResponse = timeout(
+        DEFAULT_TIMEOUT,
+        mcp.read_stream_until_response_message(RequestId::Integer(refresh_id)),
+    )
+    .await??;
+    let refresh: ModelsRefreshResponse = to_response(refresh_resp)?;
+    assert!(refresh.updated);
+
+    // The stale etag no longer matches, so the full refreshed list comes back.
+    let after = list_models_with_params(
+        &mut mcp,
+        ModelListParams {
+            if_none_match: Some(before.etag.clone()),
+            ..Default::default()
+        },
+    )
+    .await?;
+    assert_ne!(after.etag, before.etag);
+    assert!(!after.not_modified);
+    assert_eq!(after.data.len(), before.data.len() + 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn list_models_stale_cursor_after_refresh_returns_cursor_expired() -> Result<()> {
+    let server = MockServer::start().await;
+    // The startup refresh consumes the initial two-model catalog.
+    mount_models_once(
+        &server,
+        ModelsResponse {
+            models: vec![
+                remote_model_with_release_date("remote-a", 0, None)?,
+                remote_model_with_release_date("remote-b", 1, None)?,
+            ],
+        },
+    )
+    .await;
+
+    let codex_home = TempDir::new()?;
+    let server_uri = server.uri();
+    std::fs::write(
+        codex_home.path().join("config.toml"),
+        format!(
+            r#"
+model = "mock-model"
+approval_policy = "never"
+sandbox_mode = "read-only"
+openai_base_url = "{server_uri}/v1"
+"#
+        ),
+    )?;
+    write_chatgpt_auth(
+        codex_home.path(),
+        ChatGptAuthFixture::new("chatgpt-access-token").plan_type("pro"),
+        AuthCredentialsStoreMode::File,
+    )?;
+
+    let mut mcp = TestAppServer::builder()
+        .with_codex_home(codex_home.path())
+        .without_auto_env()
+        .with_env_overrides(&[("OPENAI_API_KEY", None)])
+        .build()
+        .await?;
+    timeout(DEFAULT_TIMEOUT, mcp.initialize()).await??;
+
+    let first_page = list_models_with_params(
+        &mut mcp,
+        ModelListParams {
+            limit: Some(1),
+            ..Default::default()
+        },
+    )
+    .await?;
+    let cursor = first_page
+        .next_cursor
+        .expect("a single-item page should produce a cursor");
+
+    // The catalog refreshes between the two pagination requests.
+    mount_models_once(
+        &server,
+        ModelsResponse {
+            models: vec![
+                remote_model_with_release_date("remote-a", 0, None)?,
+                remote_model_with_release_date("remote-b", 1, None)?,
+                remote_model_with_release_date("remote-c", 2, None)?,
+            ],
+        },
+    )
+    .await;
+    let refresh_id = mcp
+        .send_models_refresh_request(ModelsRefreshParams {})
+        .await?;
+    let refresh_resp: JSONRPCResponse = timeout(
+        DEFAULT_TIMEOUT,
+        mcp.read_stream_until_response_message(RequestId::Integer(refresh_id)),
+    )
+    .await??;
+    let refresh: ModelsRefreshResponse = to_response(refresh_resp)?;
+    assert!(refresh.updated);
+
+    // The cursor references the replaced snapshot; continuing with it could
+    // skip or duplicate entries, so the server rejects it distinctly.
+    let request_id = mcp
+        .send_list_models_request(ModelListParams {
+            limit: Some(1),
+            cursor: Some(cursor),
+            ..Default::default()
+        })
+        .await?;
+    let error: JSONRPCError = timeout(
+        DEFAULT_TIMEOUT,
+        mcp.read_stream_until_error_message(RequestId::Integer(request_id)),
+    )
+    .await??;
+
+    assert_eq!(error.id, RequestId::Integer(request_id));
+    assert_eq!(error.error.code, CURSOR_EXPIRED_ERROR_CODE);
+    assert_eq!(
+        error.error.message,
+        "cursor references a replaced model list snapshot; restart pagination from the first page"
+    );
+
+    // Restarting without a cursor pages cleanly through the new snapshot.
+    let restarted = list_models_with_params(
+        &mut mcp,
+        ModelListParams {
+            limit: Some(1),
+            ..Default::default()
+        },
+    )
+    .await?;
+    assert_ne!(restarted.etag, first_page.etag);
+    Ok(())
+}
+
+#[tokio::test]
+async fn list_models_config_profile_moves_default_flag() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    write_models_cache(codex_home.path())?;
+    let mut mcp = TestAppServer::builder()
+        .with_codex_home(codex_home.path())
+        .without_auto_env()
+        .build()
+        .await?;
+    timeout(DEFAULT_TIMEOUT, mcp.initialize()).await??;
+
+    let baseline = list_models_with_params(
+        &mut mcp,
+        ModelListParams {
+            limit: Some(100),
+            ..Default::default()
+        },
+    )
+    .await?;
+    let catalog_default = baseline
+        .data
+        .iter()
+        .find(|model| model.is_default)
+        .expect("cache fixture marks a default model")
+        .clone();
+    let pinned = baseline
+        .data
+        .iter()
+        .find(|model| !model.is_default)
+        .expect("cache fixture lists more than one model")
+        .clone();
+
+    // Two profiles pinning different models; profile config is read per
+    // request, so writing the files after startup is fine.
+    std::fs::write(
+        codex_home.path().join("work.config.toml"),
+        format!("model = \"{}\"\n", pinned.model),
+    )?;
+    std::fs::write(
+        codex_home.path().join("personal.config.toml"),
+        format!("model = \"{}\"\n", catalog_default.model),
+    )?;
+
+    let work = list_models_with_params(
+        &mut mcp,
+        ModelListParams {
+            limit: Some(100),
+            config_profile: Some("work".to_string()),
+            ..Default::default()
+        },
+    )
+    .await?;
+    let work_defaults: Vec<&Model> = work.data.iter().filter(|model| model.is_default).collect();
+    assert_eq!(work_defaults.len(), 1, "exactly one default per profile");
+    assert_eq!(work_defaults[0].id, pinned.id);
+
+    let personal = list_models_with_params(
+        &mut mcp,
+        ModelListParams {
+            limit: Some(100),
+            config_profile: Some("personal".to_string()),
+            ..Default::default()
+        },
+    )
+    .await?;
+    let personal_default = personal
+        .data
+        .iter()
+        .find(|model| model.is_default)
+        .expect("personal profile should keep a default");
+    assert_eq!(personal_default.id, catalog_default.id);
+
+    // The default flag is part of the serialized list, so the views get
+    // distinct etags.
+    assert_ne!(work.etag, personal.etag);
+    Ok(())
+}
+
+#[tokio::test]
+async fn list_models_rejects_unknown_config_profile() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    write_models_cache(codex_home.path())?;
+    let mut mcp = TestAppServer::builder()
+        .with_codex_home(codex_home.path())
+        .without_auto_env()
+        .build()
+        .await?;
+    timeout(DEFAULT_TIMEOUT, mcp.initialize()).await??;
+
+    let request_id = mcp
+        .send_list_models_request(ModelListParams {
+            config_profile: Some("no-such-profile".to_string()),
+            ..Default::default()
+        })
+        .await?;
+
+    let error: JSONRPCError = timeout(
+        DEFAULT_TIMEOUT,
+        mcp.read_stream_until_error_message(RequestId::Integer(request_id)),
+    )
+    .await??;
+
+    assert_eq!(error.id, RequestId::Integer(request_id));
+    assert_eq!(error.error.code, INVALID_REQUEST_ERROR_CODE);
+    assert_eq!(
+        error.error.message,
+        "config profile not found: no-such-profile"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn list_models_rejects_out_of_range_limits() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    write_models_cache(codex_home.path())?;
+    let mut mcp = TestAppServer::builder()
+        .with_codex_home(codex_home.path())
+        .without_auto_env()
+        .build()
+        .await?;
+    timeout(DEFAULT_TIMEOUT, mcp.initialize()).await??;
+
+    for limit in [0u32, 101] {
+        let request_id = mcp
+            .send_list_models_request(ModelListParams {
+                limit: Some(limit),
+                ..Default::default()
+            })
+            .await?;
+
+        let error: JSONRPCError = timeout(
+            DEFAULT_TIMEOUT,
+            mcp.read_stream_until_error_message(RequestId::Integer(request_id)),
+        )
+        .await??;
+
+        assert_eq!(error.id, RequestId::Integer(request_id));
+        assert_eq!(error.error.code, INVALID_REQUEST_ERROR_CODE);
+        assert_eq!(
+            error.error.message,
+            format!("limit must be between 1 and 100: {limit}")
+        );
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn list_models_exactly_full_last_page_has_no_cursor() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    write_models_cache(codex_home.path())?;
+    let mut mcp = TestAppServer::builder()
+        .with_codex_home(codex_home.path())
+        .without_auto_env()
+        .build()
+        .await?;
+    timeout(DEFAULT_TIMEOUT, mcp.initialize()).await??;
+
+    let expected_models = expected_visible_models();
+    let response = list_models_with_params(
+        &mut mcp,
+        ModelListParams {
+            limit: Some(expected_models.len() as u32),
+            ..Default::default()
+        },
+    )
+    .await?;
+
+    // A page that exactly drains the list must not hand out a cursor, or
+    // clients paginate forever.
+    assert_eq!(response.data, expected_models);
+    assert!(response.next_cursor.is_none());
+    Ok(())
+}
+
+#[tokio::test]
+async fn list_models_default_limit_returns_twenty_item_pages() -> Result<()> {
+    let server = MockServer::start().await;
+    let catalog: Vec<ModelInfo> = (0..25)
+        .map(|index| remote_model_with_release_date(&format!("remote-{index:02}"), index, None))
+        .collect::<Result<_>>()?;
+    mount_models_once(&server, ModelsResponse { models: catalog }).await;
+
+    let codex_home = TempDir::new()?;
+    let server_uri = server.uri();
+    std::fs::write(
+        codex_home.path().join("config.toml"),
+        format!(
+            r#"
+model = "mock-model"
+approval_policy = "never"
+sandbox_mode = "read-only"
+openai_base_url = "{server_uri}/v1"
+"#
+        ),
+    )?;
+    write_chatgpt_auth(
+        codex_home.path(),
+        ChatGptAuthFixture::new("chatgpt-access-token").plan_type("pro"),
+        AuthCredentialsStoreMode::File,
+    )?;
+
+    let mut mcp = TestAppServer::builder()
+        .with_codex_home(codex_home.path())
+        .without_auto_env()
+        .with_env_overrides(&[("OPENAI_API_KEY", None)])
+        .build()
+        .await?;
+    timeout(DEFAULT_TIMEOUT, mcp.initialize()).await??;
+
+    let first = list_models_with_params(&mut mcp, ModelListParams::default()).await?;
+    assert_eq!(first.data.len(), 20);
+    let cursor = first
+        .next_cursor
+        .expect("a partially drained list should produce a cursor");
+
+    let second = list_models_with_params(
+        &mut mcp,
+        ModelListParams {
+            cursor: Some(cursor),
+            ..Default::default()
+        },
+    )
+    .await?;
+    assert_eq!(second.data.len(), 5);
+    assert!(second.next_cursor.is_none());
+    Ok(())
+}
+
+#[tokio::test]
+async fn list_models_missing_cache_reports_builtin_source() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    let mut mcp = TestAppServer::builder()
+        .with_codex_home(codex_home.path())
+        .without_auto_env()
+        .build()
+        .await?;
+
+    timeout(DEFAULT_TIMEOUT, mcp.initialize()).await??;
+
+    let response = list_models_with_params(&mut mcp, ModelListParams::default()).await?;
+
+    assert_eq!(response.meta.source, ModelCatalogSource::Builtin);
+    assert_eq!(response.meta.cache_fetched_at, None);
+    assert!(
+        !response.data.is_empty(),
+        "builtin catalog should still serve models"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn list_models_corrupt_cache_reports_builtin_source() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    std::fs::write(
+        codex_home.path().join("models_cache.json"),
+        "{\"models\": [",
+    )?;
+    let mut mcp = TestAppServer::builder()
+        .with_codex_home(codex_home.path())
+        .without_auto_env()
+        .build()
+        .await?;
+
+    timeout(DEFAULT_TIMEOUT, mcp.initialize()).await??;
+
+    let response = list_models_with_params(&mut mcp, ModelListParams::default()).await?;
+
+    assert_eq!(response.meta.source, ModelCatalogSource::Builtin);
+    assert_eq!(response.meta.cache_fetched_at, None);
+    assert!(
+        !response.data.is_empty(),
+        "an unreadable cache should fall back to the builtin catalog"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn list_models_cached_catalog_reports_cache_source() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    write_models_cache(codex_home.path())?;
+    let mut mcp = TestAppServer::builder()
+        .with_codex_home(codex_home.path())
+        .without_auto_env()
+        .build()
+        .await?;
+
+    timeout(DEFAULT_TIMEOUT, mcp.initialize()).await??;
+
+    let response = list_models_with_params(&mut mcp, ModelListParams::default()).await?;
+
+    assert_eq!(response.meta.source, ModelCatalogSource::Cache);
+    assert!(
+        response.meta.cache_fetched_at.is_some(),
+        "a served cache snapshot should carry its fetch time"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn list_models_meta_counts_hidden_models_regardless_of_include_hidden() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    write_models_cache(codex_home.path())?;
+    let mut mcp = TestAppServer::builder()
+        .with_codex_home(codex_home.path())
+        .without_auto_env()
+        .build()
+        .await?;
+
+    timeout(DEFAULT_TIMEOUT, mcp.initialize()).await??;
+
+    let visible = list_models_with_params(
+        &mut mcp,
+        ModelListParams {
+            limit: Some(100),
+            ..Default::default()
+        },
+    )
+    .await?;
+    let all = list_models_with_params(
+        &mut mcp,
+        ModelListParams {
+            limit: Some(100),
+            include_hidden: Some(true),
+            ..Default::default()
+        },
+    )
+    .await?;
+
+    let hidden_models = all.data.iter().filter(|model| model.hidden).count();
+    assert!(
+        hidden_models > 0,
+        "the cached catalog should contain hidden models"
+    );
+    // `total` reflects what the listing can page through; `hiddenCount`
+    // reports the hidden matches whether or not they are included.
+    assert_eq!(visible.meta.total as usize, visible.data.len());
+    assert_eq!(all.meta.total as usize, all.data.len());
+    assert_eq!(visible.meta.hidden_count as usize, hidden_models);
+    assert_eq!(all.meta.hidden_count as usize, hidden_models);
+    Ok(())
+}
@@ -0,0 +1,170 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use app_test_support::TestAppServer;
+use app_test_support::to_response;
+use app_test_support::write_models_cache;
+use codex_app_server_protocol::JSONRPCError;
+use codex_app_server_protocol::JSONRPCResponse;
+use codex_app_server_protocol::ModelAliasKind;
+use codex_app_server_protocol::ModelResolveParams;
+use codex_app_server_protocol::ModelResolveResponse;
+use codex_app_server_protocol::RequestId;
+use codex_protocol::openai_models::ModelPreset;
+use pretty_assertions::assert_eq;
+use tempfile::TempDir;
+use tokio::time::timeout;
+
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+const INVALID_REQUEST_ERROR_CODE: i64 = -32600;
+const NOT_FOUND_ERROR_CODE: i64 = -32004;
+
+async fn started_server() -> Result<(TempDir, TestAppServer)> {
+    let codex_home = TempDir::new()?;
+    write_models_cache(codex_home.path())?;
+    let mut mcp = TestAppServer::builder()
+        .with_codex_home(codex_home.path())
+        .without_auto_env()
+        .build()
+        .await?;
+    timeout(DEFAULT_TIMEOUT, mcp.initialize()).await??;
+    Ok((codex_home, mcp))
+}
+
+fn any_cached_preset() -> ModelPreset {
+    codex_core::test_support::all_model_presets()
+        .iter()
+        .find(|preset| preset.show_in_picker)
+        .cloned()
+        .expect("models cache fixture has at least one visible preset")
+}
+
+async fn resolve(mcp: &mut TestAppServer, query: &str) -> Result<ModelResolveResponse> {
+    let request_id = mcp
+        .send_model_resolve_request(ModelResolveParams {
+            query: query.to_string(),
+        })
+        .await?;
+    let response: JSONRPCResponse = timeout(
+        DEFAULT_TIMEOUT,
+        mcp.read_stream_until_response_message(RequestId::Integer(request_id)),
+    )
+    .await??;
+    to_response::<ModelResolveResponse>(response)
+}
+
+async fn resolve_err(mcp: &mut TestAppServer, query: &str) -> Result<JSONRPCError> {
+    let request_id = mcp
+        .send_model_resolve_request(ModelResolveParams {
+            query: query.to_string(),
+        })
+        .await?;
+    let error: JSONRPCError = timeout(
+        DEFAULT_TIMEOUT,
+        mcp.read_stream_until_error_message(RequestId::Integer(request_id)),
+    )
+    .await??;
+    Ok(error)
+}
+
+#[tokio::test]
+async fn model_resolve_returns_exact_match() -> Result<()> {
+    let (_codex_home, mut mcp) = started_server().await?;
+    let preset = any_cached_preset();
+
+    let response = resolve(&mut mcp, &preset.id).await?;
+
+    assert_eq!(response.id, preset.id);
+    assert_eq!(response.alias_kind, ModelAliasKind::Exact);
+    assert_eq!(response.model.id, preset.id);
+    assert_eq!(response.model.display_name, preset.display_name);
+    Ok(())
+}
+
+#[tokio::test]
+async fn model_resolve_canonicalizes_family_name() -> Result<()> {
+    let (_codex_home, mut mcp) = started_server().await?;
+    let preset = any_cached_preset();
+
+    // Strip the last dashed segment to get a family name; the fixture ids all
+    // have one (e.g. `gpt-5.6-sol` -> `gpt-5.6`).
+    let family = preset
+        .id
+        .rsplit_once('-')
+        .map(|(family, _)| family.to_string())
+        .expect("fixture preset id has a dashed family segment");
+
+    let response = resolve(&mut mcp, &family).await?;
+
+    assert_eq!(response.alias_kind, ModelAliasKind::FamilyLatest);
+    assert!(
+        response.id.starts_with(&format!("{family}-")),
+        "resolved id {} should belong to family {family}",
+        response.id
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn model_resolve_unique_prefix_matches() -> Result<()> {
+    let (_codex_home, mut mcp) = started_server().await?;
+    let preset = any_cached_preset();
+
+    // Drop the id's last character; if that prefix is unique the resolver
+    // reports a prefix match back to the full id.
+    let prefix = &preset.id[..preset.id.len() - 1];
+    let unique = codex_core::test_support::all_model_presets()
+        .iter()
+        .filter(|candidate| candidate.id.starts_with(prefix))
+        .count()
+        == 1;
+    if !unique {
+        // The bundled catalog can gain colliding ids; skip rather than
+        // asserting on an ambiguous fixture.
+        return Ok(());
+    }
+
+    let response = resolve(&mut mcp, prefix).await?;
+
+    assert_eq!(response.id, preset.id);
+    assert_eq!(response.alias_kind, ModelAliasKind::Prefix);
+    Ok(())
+}
+
+#[tokio::test]
+async fn model_resolve_ambiguous_prefix_is_invalid_request() -> Result<()> {
+    let (_codex_home, mut mcp) = started_server().await?;
+
+    // `gpt-5.` prefixes several ids without sitting on a `-` boundary, so it
+    // is not a family name and cannot silently pick one.
+    let error = resolve_err(&mut mcp, "gpt-5.").await?;
+
+    assert_eq!(error.error.code, INVALID_REQUEST_ERROR_CODE);
+    assert!(
+        error
+            .error
+            .message
+            .starts_with("ambiguous model reference `gpt-5.`"),
+        "unexpected message: {}",
+        error.error.message
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn model_resolve_unknown_input_suggests_closest_ids() -> Result<()> {
+    let (_codex_home, mut mcp) = started_server().await?;
+
+    let error = resolve_err(&mut mcp, "gqt-5.6-sol").await?;
+
+    assert_eq!(error.error.code, NOT_FOUND_ERROR_CODE);
+    assert!(
+        error
+            .error
+            .message
+            .starts_with("model not found: gqt-5.6-sol; closest matches: "),
+        "unexpected message: {}",
+        error.error.message
+    );
+    Ok(())
+}
@@ -0,0 +1,229 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use app_test_support::ChatGptAuthFixture;
+use app_test_support::TestAppServer;
+use app_test_support::to_response;
+use app_test_support::write_chatgpt_auth;
+use codex_app_server_protocol::JSONRPCResponse;
+use codex_app_server_protocol::ModelListParams;
+use codex_app_server_protocol::ModelListResponse;
+use codex_app_server_protocol::ModelsRefreshParams;
+use codex_app_server_protocol::ModelsRefreshResponse;
+use codex_app_server_protocol::RequestId;
+use codex_config::types::AuthCredentialsStoreMode;
+use codex_protocol::openai_models::ModelInfo;
+use codex_protocol::openai_models::ModelsResponse;
+use core_test_support::responses::mount_models_once;
+use core_test_support::responses::mount_models_once_with_delay;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use tempfile::TempDir;
+use tokio::time::timeout;
+use wiremock::MockServer;
+
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+fn remote_model(slug: &str) -> Result<ModelInfo> {
+    let model = serde_json::from_value(json!({
+        "slug": slug,
+        "display_name": slug,
+        "description": "Remote model for app-server models/refresh coverage",
+        "default_reasoning_level": "low",
+        "supported_reasoning_levels": [
+            {"effort": "low", "description": "Low"}
+        ],
+        "shell_type": "shell_command",
+        "visibility": "list",
+        "minimal_client_version": [0, 1, 0],
+        "supported_in_api": true,
+        "priority": 0,
+        "upgrade": null,
+        "base_instructions": "base instructions",
+        "supports_reasoning_summaries": false,
+        "support_verbosity": false,
+        "default_verbosity": null,
+        "apply_patch_tool_type": null,
+        "truncation_policy": {"mode": "bytes", "limit": 10_000},
+        "supports_parallel_tool_calls": false,
+        "supports_image_detail_original": false,
+        "context_window": 272_000,
+        "max_context_window": 272_000,
+        "experimental_supported_tools": [],
+    }))?;
+    Ok(model)
+}
+
+/// Builds a server whose `/models` fetches go to the wiremock `server`; the
+/// initial catalog is consumed by the startup refresh.
+async fn started_server_with_catalog(
+    server: &MockServer,
+    initial_catalog: Vec<ModelInfo>,
+) -> Result<(TempDir, TestAppServer)> {
+    mount_models_once(
+        server,
+        ModelsResponse {
+            models: initial_catalog,
+        },
+    )
+    .await;
+
+    let codex_home = TempDir::new()?;
+    let server_uri = server.uri();
+    std::fs::write(
+        codex_home.path().join("config.toml"),
+        format!(
+            r#"
+model = "mock-model"
+approval_policy = "never"
+sandbox_mode = "read-only"
+openai_base_url = "{server_uri}/v1"
+"#
+        ),
+    )?;
+    write_chatgpt_auth(
+        codex_home.path(),
+        ChatGptAuthFixture::new("chatgpt-access-token").plan_type("pro"),
+        AuthCredentialsStoreMode::File,
+    )?;
+
+    let mut mcp = TestAppServer::builder()
+        .with_codex_home(codex_home.path())
+        .without_auto_env()
+        .with_env_overrides(&[("OPENAI_API_KEY", None)])
+        .build()
+        .await?;
+    timeout(DEFAULT_TIMEOUT, mcp.initialize()).await??;
+    Ok((codex_home, mcp))
+}
+
+#[tokio::test]
+async fn models_refresh_fetches_catalog_and_notifies_on_change() -> Result<()> {
+    let server = MockServer::start().await;
+    let (_codex_home, mut mcp) =
+        started_server_with_catalog(&server, vec![remote_model("remote-a")?]).await?;
+
+    // The refresh fetch sees an extra model compared to the startup catalog.
+    mount_models_once(
+        &server,
+        ModelsResponse {
+            models: vec![remote_model("remote-a")?, remote_model("remote-b")?],
+        },
+    )
+    .await;
+
+    let request_id = mcp
+        .send_models_refresh_request(ModelsRefreshParams {})
+        .await?;
+
+    // The change notification is emitted before the response is sent.
+    timeout(
+        DEFAULT_TIMEOUT,
+        mcp.read_stream_until_notification_message("models/updated"),
+    )
+    .await??;
+
+    let response: JSONRPCResponse = timeout(
+        DEFAULT_TIMEOUT,
+        mcp.read_stream_until_response_message(RequestId::Integer(request_id)),
+    )
+    .await??;
+    let refresh = to_response::<ModelsRefreshResponse>(response)?;
+    assert!(refresh.updated);
+    assert_eq!(refresh.model_count, 2);
+    assert!(chrono::DateTime::parse_from_rfc3339(&refresh.fetched_at).is_ok());
+
+    // Subsequent model/list calls serve the refreshed catalog without
+    // another fetch.
+    let request_id = mcp
+        .send_list_models_request(ModelListParams {
+            limit: Some(100),
+            ..Default::default()
+        })
+        .await?;
+    let response: JSONRPCResponse = timeout(
+        DEFAULT_TIMEOUT,
+        mcp.read_stream_until_response_message(RequestId::Integer(request_id)),
+    )
+    .await??;
+    let ModelListResponse { data: items, .. } = to_response::<ModelListResponse>(response)?;
+    let ids: Vec<&str> = items.iter().map(|item| item.id.as_str()).collect();
+    assert_eq!(ids, vec!["remote-a", "remote-b"]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn models_refresh_reports_unchanged_catalog() -> Result<()> {
+    let server = MockServer::start().await;
+    let (_codex_home, mut mcp) =
+        started_server_with_catalog(&server, vec![remote_model("remote-a")?]).await?;
+
+    // The refresh fetch returns the same catalog the startup refresh cached.
+    mount_models_once(
+        &server,
+        ModelsResponse {
+            models: vec![remote_model("remote-a")?],
+        },
+    )
+    .await;
+
+    let request_id = mcp
+        .send_models_refresh_request(ModelsRefreshParams {})
+        .await?;
+    let response: JSONRPCResponse = timeout(
+        DEFAULT_TIMEOUT,
+        mcp.read_stream_until_response_message(RequestId::Integer(request_id)),
+    )
+    .await??;
+    let refresh = to_response::<ModelsRefreshResponse>(response)?;
+    assert!(!refresh.updated);
+    assert_eq!(refresh.model_count, 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn concurrent_models_refresh_requests_coalesce_into_one_fetch() -> Result<()> {
+    let server = MockServer::start().await;
+    let (_codex_home, mut mcp) =
+        started_server_with_catalog(&server, vec![remote_model("remote-a")?]).await?;
+
+    // Only one response is mounted: a second fetch would fail, so identical
+    // responses prove the two requests shared a single fetch.
+    let refresh_mock = mount_models_once_with_delay(
+        &server,
+        ModelsResponse {
+            models: vec![remote_model("remote-a")?, remote_model("remote-b")?],
+        },
+        Duration::from_millis(500),
+    )
+    .await;
+
+    let first_id = mcp
+        .send_models_refresh_request(ModelsRefreshParams {})
+        .await?;
+    let second_id = mcp
+        .send_models_refresh_request(ModelsRefreshParams {})
+        .await?;
+
+    let first: JSONRPCResponse = timeout(
+        DEFAULT_TIMEOUT,
+        mcp.read_stream_until_response_message(RequestId::Integer(first_id)),
+    )
+    .await??;
+    let second: JSONRPCResponse = timeout(
+        DEFAULT_TIMEOUT,
+        mcp.read_stream_until_response_message(RequestId::Integer(second_id)),
+    )
+    .await??;
+
+    let first = to_response::<ModelsRefreshResponse>(first)?;
+    let second = to_response::<ModelsRefreshResponse>(second)?;
+    assert!(first.updated);
+    assert_eq!(first, second);
+    assert_eq!(
+        refresh_mock.requests().len(),
+        1,
+        "expected the concurrent refreshes to share one /models request"
+    );
+    Ok(())
+}
@@ -0,0 +1,197 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use app_test_support::TestAppServer;
+use app_test_support::to_response;
+use app_test_support::write_models_cache;
+use codex_app_server_protocol::JSONRPCResponse;
+use codex_app_server_protocol::RequestId;
+use codex_app_server_protocol::StatuslineGetConfigParams;
+use codex_app_server_protocol::StatuslineGetConfigResponse;
+use codex_app_server_protocol::StatuslineListThemesParams;
+use codex_app_server_protocol::StatuslineListThemesResponse;
+use codex_app_server_protocol::StatuslineSetConfigParams;
+use codex_app_server_protocol::StatuslineSetConfigResponse;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use tempfile::TempDir;
+use tokio::time::timeout;
+
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+async fn get_config(mcp: &mut TestAppServer) -> Result<StatuslineGetConfigResponse> {
+    let request_id = mcp
+        .send_statusline_get_config_request(StatuslineGetConfigParams::default())
+        .await?;
+    let response: JSONRPCResponse = timeout(
+        DEFAULT_TIMEOUT,
+        mcp.read_stream_until_response_message(RequestId::Integer(request_id)),
+    )
+    .await??;
+    to_response::<StatuslineGetConfigResponse>(response)
+}
+
+async fn set_config(
+    mcp: &mut TestAppServer,
+    config: serde_json::Value,
+) -> Result<StatuslineSetConfigResponse> {
+    let request_id = mcp
+        .send_statusline_set_config_request(StatuslineSetConfigParams { config })
+        .await?;
+    let response: JSONRPCResponse = timeout(
+        DEFAULT_TIMEOUT,
+        mcp.read_stream_until_response_message(RequestId::Integer(request_id)),
+    )
+    .await??;
+    to_response::<StatuslineSetConfigResponse>(response)
+}
+
+#[tokio::test]
+async fn statusline_config_round_trips_through_the_file_on_disk() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    write_models_cache(codex_home.path())?;
+    let mut mcp = TestAppServer::builder()
+        .with_codex_home(codex_home.path())
+        .without_auto_env()
+        .build()
+        .await?;
+
+    timeout(DEFAULT_TIMEOUT, mcp.initialize()).await??;
+
+    // No config file yet: every field of the serde model is defaulted.
+    let initial = get_config(&mut mcp).await?;
+    assert_eq!(initial.config, json!({}));
+
+    let replacement = json!({
+        "enabled": true,
+        "theme": "gruvbox",
+        "style": "powerline",
+        "separator": " │ ",
+        "segment_order": ["directory", "model", "git"],
+        "segments": {
+            "model": {
+                "id": "model",
+                "enabled": false,
+                "colors": {"text": {"c256": 208}},
+                "styles": {"text_bold": true}
+            }
+        }
+    });
+    let applied = set_config(&mut mcp, replacement.clone()).await?;
+    assert!(applied.applied);
+    assert_eq!(applied.errors, vec![]);
+
+    // The config lands in the same file the TUI reads.
+    let config_path = codex_home.path().join("cxline").join("config.toml");
+    let on_disk: toml::Value = toml::from_str(&std::fs::read_to_string(&config_path)?)?;
+    assert_eq!(
+        on_disk.get("theme").and_then(|theme| theme.as_str()),
+        Some("gruvbox")
+    );
+
+    let round_tripped = get_config(&mut mcp).await?;
+    assert_eq!(round_tripped.config, replacement);
+    Ok(())
+}
+
+#[tokio::test]
+async fn statusline_set_config_reports_per_field_errors_without_writing() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    write_models_cache(codex_home.path())?;
+    let mut mcp = TestAppServer::builder()
+        .with_codex_home(codex_home.path())
+        .without_auto_env()
+        .build()
+        .await?;
+
+    timeout(DEFAULT_TIMEOUT, mcp.initialize()).await??;
+
+    let response = set_config(
+        &mut mcp,
+        json!({
+            "theme": "",
+            "style": "fancy",
+            "segment_order": ["model", "model", "bogus"],
+            "typo": true
+        }),
+    )
+    .await?;
+
+    assert!(!response.applied);
+    let mut fields: Vec<String> = response
+        .errors
+        .iter()
+        .map(|error| error.field.clone())
+        .collect();
+    fields.sort();
+    assert_eq!(
+        fields,
+        vec![
+            "segment_order[1]",
+            "segment_order[2]",
+            "style",
+            "theme",
+            "typo"
+        ]
+    );
+    // An invalid replacement must not touch the file.
+    assert!(
+        !codex_home
+            .path()
+            .join("cxline")
+            .join("config.toml")
+            .exists()
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn statusline_list_themes_returns_builtin_and_user_themes() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    write_models_cache(codex_home.path())?;
+    let themes_dir = codex_home.path().join("cxline").join("themes");
+    std::fs::create_dir_all(&themes_dir)?;
+    // Preset files written by the TUI shadow the bundled themes; only
+    // my-theme is a user theme.
+    std::fs::write(themes_dir.join("cometix.toml"), "theme = \"cometix\"\n")?;
+    std::fs::write(themes_dir.join("my-theme.toml"), "theme = \"my-theme\"\n")?;
+
+    let mut mcp = TestAppServer::builder()
+        .with_codex_home(codex_home.path())
+        .without_auto_env()
+        .build()
+        .await?;
+
+    timeout(DEFAULT_TIMEOUT, mcp.initialize()).await??;
+
+    let request_id = mcp
+        .send_statusline_list_themes_request(StatuslineListThemesParams::default())
+        .await?;
+    let response: JSONRPCResponse = timeout(
+        DEFAULT_TIMEOUT,
+        mcp.read_stream_until_response_message(RequestId::Integer(request_id)),
+    )
+    .await??;
+    let StatuslineListThemesResponse { themes } =
+        to_response::<StatuslineListThemesResponse>(response)?;
+
+    let cometix = themes
+        .iter()
+        .find(|theme| theme.name == "cometix")
+        .expect("cometix should be listed");
+    assert!(cometix.built_in);
+    assert_eq!(
+        themes
+            .iter()
+            .filter(|theme| theme.name == "cometix")
+            .count(),
+        1
+    );
+
+    let user_theme = themes
+        .iter()
+        .find(|theme| theme.name == "my-theme")
+        .expect("my-theme should be listed");
+    assert!(!user_theme.built_in);
+    Ok(())
+}
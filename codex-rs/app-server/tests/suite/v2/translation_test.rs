@@ -0,0 +1,367 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::time::Duration;
+
+use anyhow::Result;
+use app_test_support::TestAppServer;
+use app_test_support::to_response;
+use app_test_support::write_mock_responses_config_toml;
+use app_test_support::write_models_cache;
+use codex_app_server_protocol::ItemCompletedNotification;
+use codex_app_server_protocol::JSONRPCNotification;
+use codex_app_server_protocol::JSONRPCResponse;
+use codex_app_server_protocol::RequestId;
+use codex_app_server_protocol::ThreadItem;
+use codex_app_server_protocol::ThreadStartParams;
+use codex_app_server_protocol::ThreadStartResponse;
+use codex_app_server_protocol::ThreadTranslationResultNotification;
+use codex_app_server_protocol::TranslationResultKind;
+use codex_app_server_protocol::TranslationTestErrorCode;
+use codex_app_server_protocol::TranslationTestParams;
+use codex_app_server_protocol::TranslationTestResponse;
+use codex_app_server_protocol::TurnStartParams;
+use codex_app_server_protocol::UserInput as V2UserInput;
+use core_test_support::responses;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use tempfile::TempDir;
+use tokio::time::timeout;
+use wiremock::Mock;
+use wiremock::MockServer;
+use wiremock::ResponseTemplate;
+use wiremock::matchers::method;
+use wiremock::matchers::path;
+
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+fn write_translation_config(codex_home: &Path, base_url: &str, timeout_ms: u64) -> Result<()> {
+    std::fs::write(
+        codex_home.join("translation.toml"),
+        format!(
+            r#"enabled = true
+target_language = "zh-CN"
+provider = "openai"
+api_key = "sk-test"
+base_url = "{base_url}"
+timeout_ms = {timeout_ms}
+"#
+        ),
+    )?;
+    Ok(())
+}
+
+async fn run_translation_test(mcp: &mut TestAppServer) -> Result<TranslationTestResponse> {
+    let request_id = mcp
+        .send_translation_test_request(TranslationTestParams::default())
+        .await?;
+    let response: JSONRPCResponse = timeout(
+        DEFAULT_TIMEOUT,
+        mcp.read_stream_until_response_message(RequestId::Integer(request_id)),
+    )
+    .await??;
+    to_response::<TranslationTestResponse>(response)
+}
+
+#[tokio::test]
+async fn translation_test_reports_sample_and_latency_on_success() -> Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/chat/completions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "choices": [{"message": {"content": "你好！这是一次连接测试。"}}]
+        })))
+        .mount(&server)
+        .await;
+
+    let codex_home = TempDir::new()?;
+    write_models_cache(codex_home.path())?;
+    // A misconfigured ten-minute timeout must not matter: the server answers
+    // promptly and the RPC is capped at ten seconds regardless.
+    write_translation_config(codex_home.path(), &server.uri(), 600_000)?;
+
+    let mut mcp = TestAppServer::builder()
+        .with_codex_home(codex_home.path())
+        .without_auto_env()
+        .build()
+        .await?;
+    timeout(DEFAULT_TIMEOUT, mcp.initialize()).await??;
+
+    let response = run_translation_test(&mut mcp).await?;
+    assert!(response.ok);
+    assert_eq!(
+        response.translated_sample.as_deref(),
+        Some("你好！这是一次连接测试。")
+    );
+    assert_eq!(response.code, None);
+    assert_eq!(response.message, None);
+    Ok(())
+}
+
+#[tokio::test]
+async fn translation_test_times_out_against_a_stalled_provider() -> Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/chat/completions"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_delay(Duration::from_secs(30))
+                .set_body_json(json!({"choices": []})),
+        )
+        .mount(&server)
+        .await;
+
+    let codex_home = TempDir::new()?;
+    write_models_cache(codex_home.path())?;
+    write_translation_config(codex_home.path(), &server.uri(), 250)?;
+
+    let mut mcp = TestAppServer::builder()
+        .with_codex_home(codex_home.path())
+        .without_auto_env()
+        .build()
+        .await?;
+    timeout(DEFAULT_TIMEOUT, mcp.initialize()).await??;
+
+    let response = run_translation_test(&mut mcp).await?;
+    assert!(!response.ok);
+    assert_eq!(response.code, Some(TranslationTestErrorCode::Timeout));
+    assert!(response.latency_ms < 10_000);
+    Ok(())
+}
+
+#[tokio::test]
+async fn translation_test_reports_missing_api_key_without_calling_out() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    write_models_cache(codex_home.path())?;
+    // The default provider requires an API key and none is configured.
+    std::fs::write(
+        codex_home.path().join("translation.toml"),
+        "enabled = true\n",
+    )?;
+
+    let mut mcp = TestAppServer::builder()
+        .with_codex_home(codex_home.path())
+        .without_auto_env()
+        .build()
+        .await?;
+    timeout(DEFAULT_TIMEOUT, mcp.initialize()).await??;
+
+    let response = run_translation_test(&mut mcp).await?;
+    assert!(!response.ok);
+    assert_eq!(
+        response.code,
+        Some(TranslationTestErrorCode::ApiKeyNotFound)
+    );
+    assert_eq!(
+        response.message.as_deref(),
+        Some("API key not configured for DeepSeek")
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn translation_test_exercises_the_http_endpoint_backend() -> Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/translate"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "translated": "你好！这是一次连接测试。"
+        })))
+        .mount(&server)
+        .await;
+
+    let codex_home = TempDir::new()?;
+    write_models_cache(codex_home.path())?;
+    std::fs::write(
+        codex_home.path().join("translation.toml"),
+        format!(
+            r#"enabled = true
+target_language = "zh-CN"
+http_url = "{}/translate"
+"#,
+            server.uri()
+        ),
+    )?;
+
+    let mut mcp = TestAppServer::builder()
+        .with_codex_home(codex_home.path())
+        .without_auto_env()
+        .build()
+        .await?;
+    timeout(DEFAULT_TIMEOUT, mcp.initialize()).await??;
+
+    let response = run_translation_test(&mut mcp).await?;
+    assert!(response.ok);
+    assert_eq!(
+        response.translated_sample.as_deref(),
+        Some("你好！这是一次连接测试。")
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn translation_test_reports_daemon_backends_as_untestable() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    write_models_cache(codex_home.path())?;
+    // A daemon needs a spawned child the app-server cannot supervise; the
+    // test must say so rather than probe a provider the TUI would not use.
+    std::fs::write(
+        codex_home.path().join("translation.toml"),
+        r#"enabled = true
+daemon_command = ["translate.sh"]
+"#,
+    )?;
+
+    let mut mcp = TestAppServer::builder()
+        .with_codex_home(codex_home.path())
+        .without_auto_env()
+        .build()
+        .await?;
+    timeout(DEFAULT_TIMEOUT, mcp.initialize()).await??;
+
+    let response = run_translation_test(&mut mcp).await?;
+    assert!(!response.ok);
+    assert_eq!(
+        response.code,
+        Some(TranslationTestErrorCode::UnsupportedProvider)
+    );
+    assert!(
+        response
+            .message
+            .as_deref()
+            .is_some_and(|message| message.contains("codex debug translation")),
+        "message should point at the testable path: {:?}",
+        response.message
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn translation_test_surfaces_api_errors() -> Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/chat/completions"))
+        .respond_with(ResponseTemplate::new(401).set_body_string("Unauthorized"))
+        .mount(&server)
+        .await;
+
+    let codex_home = TempDir::new()?;
+    write_models_cache(codex_home.path())?;
+    write_translation_config(codex_home.path(), &server.uri(), 5_000)?;
+
+    let mut mcp = TestAppServer::builder()
+        .with_codex_home(codex_home.path())
+        .without_auto_env()
+        .build()
+        .await?;
+    timeout(DEFAULT_TIMEOUT, mcp.initialize()).await??;
+
+    let response = run_translation_test(&mut mcp).await?;
+    assert!(!response.ok);
+    assert_eq!(response.code, Some(TranslationTestErrorCode::Api));
+    assert_eq!(
+        response.message.as_deref(),
+        Some("API error (401): Unauthorized")
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn thread_translation_result_streams_after_reasoning_item() -> Result<()> {
+    let model_server = responses::start_mock_server().await;
+    let body = responses::sse(vec![
+        responses::ev_response_created("resp-1"),
+        responses::ev_reasoning_item("reasoning-1", &["Considering the request"], &[]),
+        responses::ev_assistant_message("msg-1", "Done"),
+        responses::ev_completed("resp-1"),
+    ]);
+    responses::mount_sse_once(&model_server, body).await;
+
+    let translator = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/chat/completions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "choices": [{"message": {"content": "正在考虑这个请求"}}]
+        })))
+        .mount(&translator)
+        .await;
+
+    let codex_home = TempDir::new()?;
+    write_mock_responses_config_toml(
+        codex_home.path(),
+        &model_server.uri(),
+        &BTreeMap::new(),
+        /*auto_compact_limit*/ 100_000,
+        /*requires_openai_auth*/ None,
+        "mock_provider",
+        "compact",
+    )?;
+    write_models_cache(codex_home.path())?;
+    write_translation_config(codex_home.path(), &translator.uri(), 5_000)?;
+
+    let mut mcp = TestAppServer::builder()
+        .with_codex_home(codex_home.path())
+        .build()
+        .await?;
+    timeout(DEFAULT_TIMEOUT, mcp.initialize()).await??;
+
+    let thread_req = mcp
+        .send_thread_start_request_with_auto_env(ThreadStartParams {
+            translate_reasoning: true,
+            ..Default::default()
+        })
+        .await?;
+    let thread_resp: JSONRPCResponse = timeout(
+        DEFAULT_TIMEOUT,
+        mcp.read_stream_until_response_message(RequestId::Integer(thread_req)),
+    )
+    .await??;
+    let ThreadStartResponse { thread, .. } = to_response::<ThreadStartResponse>(thread_resp)?;
+
+    let turn_req = mcp
+        .send_turn_start_request(TurnStartParams {
+            thread_id: thread.id.clone(),
+            input: vec![V2UserInput::Text {
+                text: "Hello".to_string(),
+                text_elements: Vec::new(),
+            }],
+            ..Default::default()
+        })
+        .await?;
+    timeout(
+        DEFAULT_TIMEOUT,
+        mcp.read_stream_until_response_message(RequestId::Integer(turn_req)),
+    )
+    .await??;
+
+    // The reasoning item must complete before its translation arrives; reading
+    // the stream in order proves the ordering.
+    let reasoning_id = loop {
+        let notification: JSONRPCNotification = timeout(
+            DEFAULT_TIMEOUT,
+            mcp.read_stream_until_notification_message("item/completed"),
+        )
+        .await??;
+        let completed: ItemCompletedNotification =
+            serde_json::from_value(notification.params.clone().expect("item/completed params"))?;
+        if let ThreadItem::Reasoning { id, .. } = completed.item {
+            break id;
+        }
+    };
+
+    let notification: JSONRPCNotification = timeout(
+        DEFAULT_TIMEOUT,
+        mcp.read_stream_until_notification_message("thread/translationResult"),
+    )
+    .await??;
+    let result: ThreadTranslationResultNotification = serde_json::from_value(
+        notification
+            .params
+            .expect("thread/translationResult params"),
+    )?;
+    assert_eq!(result.thread_id, thread.id);
+    assert_eq!(result.item_id, reasoning_id);
+    assert_eq!(result.kind, TranslationResultKind::ReasoningSummary);
+    assert_eq!(result.translated_text.as_deref(), Some("正在考虑这个请求"));
+    assert_eq!(result.code, None);
+    Ok(())
+}
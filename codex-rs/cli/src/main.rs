@@ -57,6 +57,7 @@ mod remote_control_cmd;
 #[cfg(target_os = "windows")]
 mod sandbox_setup;
 mod state_db_recovery;
+mod translation_setup;
 #[cfg(not(windows))]
 mod wsl_paths;
 
@@ -166,6 +167,9 @@ enum Subcommand {
     /// Run commands within a Codex-provided sandbox.
     Sandbox(HostSandboxArgs),
 
+    /// Interactive setup wizards for optional Codex features.
+    Setup(SetupCommand),
+
     /// Debugging tools.
     Debug(DebugCommand),
 
@@ -218,6 +222,18 @@ struct CompletionCommand {
     shell: Shell,
 }
 
+#[derive(Debug, Parser)]
+struct SetupCommand {
+    #[command(subcommand)]
+    subcommand: SetupSubcommand,
+}
+
+#[derive(Debug, clap::Subcommand)]
+enum SetupSubcommand {
+    /// Configure reasoning translation with stdin/stdout prompts.
+    Translation(translation_setup::TranslationSetupCommand),
+}
+
 #[derive(Debug, Parser)]
 struct DebugCommand {
     #[command(subcommand)]
@@ -235,6 +251,9 @@ enum DebugSubcommand {
     /// Render the model-visible prompt input list as JSON.
     PromptInput(DebugPromptInputCommand),
 
+    /// Run one translation through the configured reasoning translator.
+    Translation(DebugTranslationCommand),
+
     /// Replay a rollout trace bundle and write reduced state JSON.
     #[clap(hide = true)]
     TraceReduce(DebugTraceReduceCommand),
@@ -273,6 +292,13 @@ struct DebugPromptInputCommand {
     images: Vec<PathBuf>,
 }
 
+#[derive(Debug, Parser)]
+struct DebugTranslationCommand {
+    /// Text to translate.
+    #[arg(value_name = "TEXT", required = true)]
+    text: String,
+}
+
 #[derive(Debug, Parser)]
 struct DebugModelsCommand {
     /// Skip refresh and dump only the bundled catalog shipped with this binary.
@@ -1487,6 +1513,16 @@ async fn cli_main(
                 anyhow::bail!("`codex sandbox` is not supported on this operating system");
             }
         }
+        Some(Subcommand::Setup(SetupCommand { subcommand })) => match subcommand {
+            SetupSubcommand::Translation(setup_cli) => {
+                reject_remote_mode_for_subcommand(
+                    root_remote.as_deref(),
+                    root_remote_auth_token_env.as_deref(),
+                    "setup translation",
+                )?;
+                translation_setup::run(setup_cli)?;
+            }
+        },
         Some(Subcommand::Debug(DebugCommand { subcommand })) => match subcommand {
             DebugSubcommand::Models(cmd) => {
                 reject_remote_mode_for_subcommand(
@@ -1518,6 +1554,14 @@ async fn cli_main(
                 )
                 .await?;
             }
+            DebugSubcommand::Translation(cmd) => {
+                reject_remote_mode_for_subcommand(
+                    root_remote.as_deref(),
+                    root_remote_auth_token_env.as_deref(),
+                    "debug translation",
+                )?;
+                run_debug_translation_command(cmd).await?;
+            }
             DebugSubcommand::TraceReduce(cmd) => {
                 reject_remote_mode_for_subcommand(
                     root_remote.as_deref(),
@@ -1985,6 +2029,32 @@ async fn run_debug_prompt_input_command(
     Ok(())
 }
 
+/// Translate one text through the configured reasoning backend and print
+/// what came back, so translator scripts can be exercised without opening
+/// the TUI. Exits non-zero on failure for use in CI.
+async fn run_debug_translation_command(cmd: DebugTranslationCommand) -> anyhow::Result<()> {
+    let config = codex_tui::TranslationConfig::load();
+    for warning in &config.startup_warnings {
+        eprintln!("warning: translation.toml: {warning}");
+    }
+    match codex_tui::debug_translate(&config, &cmd.text).await {
+        Ok(report) => {
+            println!("backend: {}", report.backend);
+            println!("elapsed: {}ms", report.elapsed.as_millis());
+            if let Some(language) = &report.detected_language {
+                println!("detected_language: {language}");
+            }
+            for (key, value) in &report.metadata {
+                println!("metadata.{key}: {value}");
+            }
+            println!();
+            println!("{}", report.translated);
+            Ok(())
+        }
+        Err(error) => anyhow::bail!("translation failed: {error}"),
+    }
+}
+
 async fn run_debug_models_command(
     cmd: DebugModelsCommand,
     root_config_overrides: CliConfigOverrides,
@@ -2132,6 +2202,7 @@ fn unsupported_subcommand_name_for_strict_config(
         Some(Subcommand::Update) => Some("update"),
         Some(Subcommand::Cloud(_)) => Some("cloud"),
         Some(Subcommand::Sandbox(_)) => Some("sandbox"),
+        Some(Subcommand::Setup(_)) => Some("setup"),
         Some(Subcommand::Debug(_)) => Some("debug"),
         Some(Subcommand::Execpolicy(_)) => Some("execpolicy"),
         Some(Subcommand::Apply(_)) => Some("apply"),
@@ -2823,6 +2894,21 @@ mod tests {
         assert!(cmd.bundled);
     }
 
+    #[test]
+    fn debug_translation_parses_text() {
+        let cli = MultitoolCli::try_parse_from(["codex", "debug", "translation", "hello world"])
+            .expect("parse");
+
+        let Some(Subcommand::Debug(DebugCommand {
+            subcommand: DebugSubcommand::Translation(cmd),
+        })) = cli.subcommand
+        else {
+            panic!("expected debug translation subcommand");
+        };
+
+        assert_eq!(cmd.text, "hello world");
+    }
+
     #[test]
     fn responses_subcommand_is_not_registered() {
         let command = MultitoolCli::command();
@@ -0,0 +1,297 @@
+//! Interactive first-time setup for reasoning translation.
+//!
+//! `codex setup translation` walks through the translator choice, target
+//! language, and request timeout with plain stdin/stdout prompts (no TUI),
+//! shows the resulting TOML for review, and writes `~/.codex/translation.toml`
+//! atomically. An existing config is never overwritten without `--force`.
+
+use std::io::BufRead;
+use std::io::Write;
+use std::path::Path;
+
+use clap::Parser;
+use codex_tui::TranslationConfig;
+use codex_tui::TranslationProviderId as ProviderId;
+
+#[derive(Debug, Parser)]
+pub(crate) struct TranslationSetupCommand {
+    /// Overwrite an existing translation config.
+    #[arg(long, default_value_t = false)]
+    force: bool,
+}
+
+pub(crate) fn run(cmd: TranslationSetupCommand) -> anyhow::Result<()> {
+    let path = TranslationConfig::config_path()
+        .ok_or_else(|| anyhow::anyhow!("cannot determine the translation config path"))?;
+    let stdin = std::io::stdin();
+    let mut input = stdin.lock();
+    let stdout = std::io::stdout();
+    let mut output = stdout.lock();
+    run_wizard(&mut input, &mut output, &path, cmd.force)
+}
+
+fn run_wizard(
+    input: &mut dyn BufRead,
+    output: &mut dyn Write,
+    path: &Path,
+    force: bool,
+) -> anyhow::Result<()> {
+    if path.exists() && !force {
+        anyhow::bail!(
+            "{} already exists; rerun with --force to overwrite it",
+            path.display()
+        );
+    }
+
+    writeln!(output, "Codex reasoning translation setup")?;
+    writeln!(output)?;
+    writeln!(output, "Translator type:")?;
+    writeln!(output, "  1) HTTP provider (OpenAI-compatible and friends)")?;
+    writeln!(output, "  2) translate-shell wrapper daemon")?;
+    writeln!(output, "  3) custom daemon command")?;
+
+    let mut config = TranslationConfig {
+        enabled: true,
+        ..Default::default()
+    };
+
+    match prompt(input, output, "Choice [1]: ")?.as_str() {
+        "" | "1" => configure_http_provider(input, output, &mut config)?,
+        "2" => configure_translate_shell(input, output, &mut config)?,
+        "3" => {
+            let line = prompt(input, output, "Daemon command line: ")?;
+            config.daemon_command = Some(split_command_line(&line)?);
+        }
+        other => anyhow::bail!("unrecognized translator type {other:?}"),
+    }
+
+    let default_language = config.target_language.clone();
+    let language = prompt(
+        input,
+        output,
+        &format!("Target language code [{default_language}]: "),
+    )?;
+    if !language.is_empty() {
+        config.target_language = language;
+    }
+
+    let timeout = prompt(input, output, "Request timeout in milliseconds [30000]: ")?;
+    if !timeout.is_empty() {
+        config.timeout_ms = Some(
+            timeout
+                .parse::<u64>()
+                .map_err(|_| anyhow::anyhow!("timeout must be a whole number of milliseconds"))?,
+        );
+    }
+
+    let rendered = toml::to_string_pretty(&config)?;
+    writeln!(output)?;
+    writeln!(output, "# {}", path.display())?;
+    writeln!(output, "{rendered}")?;
+
+    // The closest thing to a health check the config supports today: an
+    // HTTP provider without its required API key can never translate.
+    if !config.is_valid() {
+        writeln!(
+            output,
+            "Warning: {} requires an API key; translation will fail until one is set.",
+            config.effective_provider()
+        )?;
+    }
+
+    let confirmation = format!("Write to {}? [y/N]: ", path.display());
+    if !prompt_yes_no(input, output, &confirmation)? {
+        writeln!(output, "Aborted; nothing was written.")?;
+        return Ok(());
+    }
+
+    write_config_atomically(path, &rendered)?;
+    writeln!(output, "Wrote {}.", path.display())?;
+    writeln!(output, "Run codex and use /translate to review the status.")?;
+    Ok(())
+}
+
+fn configure_http_provider(
+    input: &mut dyn BufRead,
+    output: &mut dyn Write,
+    config: &mut TranslationConfig,
+) -> anyhow::Result<()> {
+    writeln!(output, "Providers:")?;
+    for provider in ProviderId::ALL {
+        let def = provider.definition();
+        writeln!(output, "  {:<12} {}", provider.as_str(), def.description)?;
+    }
+
+    let default_provider = config.provider.clone();
+    let answer = prompt(input, output, &format!("Provider [{default_provider}]: "))?;
+    let provider = if answer.is_empty() {
+        config.effective_provider()
+    } else {
+        ProviderId::from_str(&answer)
+            .ok_or_else(|| anyhow::anyhow!("unknown provider {answer:?}"))?
+    };
+    config.provider = provider.as_str().to_string();
+
+    let def = provider.definition();
+    if def.requires_api_key {
+        let key = prompt(input, output, "API key (leave empty to add one later): ")?;
+        if !key.is_empty() {
+            config.api_key = Some(key);
+        }
+    }
+
+    let model = prompt(input, output, &format!("Model [{}]: ", def.default_model))?;
+    if !model.is_empty() {
+        config.model = Some(model);
+    }
+    Ok(())
+}
+
+fn configure_translate_shell(
+    input: &mut dyn BufRead,
+    output: &mut dyn Write,
+    config: &mut TranslationConfig,
+) -> anyhow::Result<()> {
+    // translate-shell does not speak the newline-delimited JSON daemon
+    // protocol itself, so the daemon must point at a wrapper around `trans`.
+    let wrapper = prompt(
+        input,
+        output,
+        "Wrapper command for translate-shell [trans-ndjson-wrapper]: ",
+    )?;
+    let wrapper = if wrapper.is_empty() {
+        "trans-ndjson-wrapper".to_string()
+    } else {
+        wrapper
+    };
+    config.daemon_command = Some(split_command_line(&wrapper)?);
+    writeln!(
+        output,
+        "Note: the wrapper must read translation requests as newline-delimited"
+    )?;
+    writeln!(
+        output,
+        "JSON on stdin and answer each one on stdout with the same id."
+    )?;
+    Ok(())
+}
+
+/// Print `text` without a trailing newline and read one trimmed answer line.
+fn prompt(input: &mut dyn BufRead, output: &mut dyn Write, text: &str) -> anyhow::Result<String> {
+    write!(output, "{text}")?;
+    output.flush()?;
+    let mut line = String::new();
+    if input.read_line(&mut line)? == 0 {
+        anyhow::bail!("setup aborted: stdin closed");
+    }
+    Ok(line.trim().to_string())
+}
+
+fn prompt_yes_no(
+    input: &mut dyn BufRead,
+    output: &mut dyn Write,
+    text: &str,
+) -> anyhow::Result<bool> {
+    let answer = prompt(input, output, text)?;
+    Ok(matches!(answer.to_ascii_lowercase().as_str(), "y" | "yes"))
+}
+
+/// Split a daemon command line on whitespace. Quoting is intentionally not
+/// supported; arguments with spaces can be edited into the file afterwards.
+fn split_command_line(line: &str) -> anyhow::Result<Vec<String>> {
+    let parts: Vec<String> = line.split_whitespace().map(str::to_string).collect();
+    if parts.is_empty() {
+        anyhow::bail!("daemon command line must not be empty");
+    }
+    Ok(parts)
+}
+
+/// Write the rendered config next to its final path, then rename it into
+/// place so a crash mid-write never leaves a truncated config behind.
+fn write_config_atomically(path: &Path, content: &str) -> anyhow::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let tmp = path.with_extension("toml.tmp");
+    std::fs::write(&tmp, content)?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&tmp, std::fs::Permissions::from_mode(0o600))?;
+    }
+    std::fs::rename(&tmp, path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::io::Cursor;
+
+    fn run_scripted(path: &Path, force: bool, script: &str) -> anyhow::Result<String> {
+        let mut input = Cursor::new(script.to_string());
+        let mut output = Vec::new();
+        run_wizard(&mut input, &mut output, path, force)
+            .map(|()| String::from_utf8(output).expect("utf8 transcript"))
+    }
+
+    fn read_config(path: &Path) -> TranslationConfig {
+        toml::from_str(&std::fs::read_to_string(path).expect("read config")).expect("parse config")
+    }
+
+    #[test]
+    fn wizard_writes_provider_config_atomically() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("translation.toml");
+
+        // Choice, provider, model, language, timeout, confirmation.
+        let transcript =
+            run_scripted(&path, false, "1\nollama\n\nzh-TW\n8000\ny\n").expect("wizard run");
+        assert!(transcript.contains("enabled = true"), "{transcript}");
+
+        let written = read_config(&path);
+        assert!(written.enabled);
+        assert_eq!(written.provider, "ollama");
+        assert_eq!(written.target_language, "zh-TW");
+        assert_eq!(written.timeout_ms, Some(8000));
+        assert!(!path.with_extension("toml.tmp").exists());
+    }
+
+    #[test]
+    fn wizard_refuses_existing_config_without_force() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("translation.toml");
+        std::fs::write(&path, "enabled = false\n").expect("seed config");
+
+        let err = run_scripted(&path, false, "").expect_err("should refuse to overwrite");
+        assert!(err.to_string().contains("--force"), "{err}");
+        assert_eq!(
+            std::fs::read_to_string(&path).expect("read config"),
+            "enabled = false\n"
+        );
+
+        // Choice, daemon command line, language, timeout, confirmation.
+        run_scripted(&path, true, "3\nmy-daemon --ndjson\nja\n\ny\n").expect("forced run");
+        let written = read_config(&path);
+        assert_eq!(
+            written.daemon_command,
+            Some(vec!["my-daemon".to_string(), "--ndjson".to_string()])
+        );
+        assert_eq!(written.target_language, "ja");
+        assert_eq!(written.timeout_ms, None);
+    }
+
+    #[test]
+    fn wizard_aborts_without_writing_when_not_confirmed() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("translation.toml");
+
+        // Choice, provider, API key, model, language, timeout, confirmation.
+        let transcript =
+            run_scripted(&path, false, "1\ndeepseek\n\n\n\n\nn\n").expect("wizard run");
+        assert!(transcript.contains("requires an API key"), "{transcript}");
+        assert!(transcript.contains("Aborted"), "{transcript}");
+        assert!(!path.exists());
+    }
+}
@@ -93,6 +93,10 @@ async fn models_client_hits_models_endpoint() {
             supports_image_detail_original: false,
             context_window: Some(272_000),
             max_context_window: None,
+            max_output_tokens: None,
+            release_date: None,
+            deprecated: false,
+            deprecation_message: None,
             auto_compact_token_limit: None,
             comp_hash: None,
             effective_context_window_percent: 95,
@@ -111,6 +111,9 @@ pub struct TuiGlobalKeymap {
     pub toggle_fast_mode: Option<KeybindingsSpec>,
     /// Toggle raw scrollback mode for copy-friendly transcript selection.
     pub toggle_raw_output: Option<KeybindingsSpec>,
+    // @cometix: statusline appearance overlay shortcut
+    /// Open the statusline appearance overlay (same as `/statusline config`).
+    pub open_cxline_config: Option<KeybindingsSpec>,
 }
 
 /// Chat context keybindings.
@@ -691,6 +691,12 @@ pub struct Tui {
     #[serde(default = "default_true")]
     pub animations: bool,
 
+    /// Force reduced motion even when `animations` is enabled: spinners render
+    /// as a static glyph and no animation-driven frames are scheduled.
+    /// Defaults to `false`.
+    #[serde(default)]
+    pub reduce_motion: bool,
+
     /// Show startup tooltips in the TUI welcome screen.
     /// Defaults to `true`.
     #[serde(default = "default_true")]
@@ -819,6 +819,7 @@ fn config_toml_deserializes_model_availability_nux() {
         Tui {
             notification_settings: TuiNotificationSettings::default(),
             animations: true,
+            reduce_motion: false,
             show_tooltips: true,
             vim_mode_default: false,
             raw_output_mode: false,
@@ -3658,6 +3659,7 @@ fn tui_config_missing_notifications_field_defaults_to_enabled() {
         Tui {
             notification_settings: TuiNotificationSettings::default(),
             animations: true,
+            reduce_motion: false,
             show_tooltips: true,
             vim_mode_default: false,
             raw_output_mode: false,
@@ -3851,6 +3853,26 @@ async fn runtime_config_resolves_session_picker_view_default_and_override() {
     );
 }
 
+#[tokio::test]
+async fn runtime_config_reduce_motion_disables_animations() {
+    let cfg = Config::load_from_base_config_with_overrides(
+        ConfigToml {
+            tui: Some(Tui {
+                animations: true,
+                reduce_motion: true,
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+        ConfigOverrides::default(),
+        tempdir().expect("tempdir").abs(),
+    )
+    .await
+    .expect("load reduce-motion config");
+
+    assert!(!cfg.animations);
+}
+
 #[tokio::test]
 async fn test_sandbox_config_parsing() {
     let sandbox_full_access = r#"
@@ -740,6 +740,7 @@ pub struct Config {
     pub tui_notifications: TuiNotificationSettings,
 
     /// Enable ASCII animations and shimmer effects in the TUI.
+    /// `false` when animations are disabled or `tui.reduce_motion` is set.
     pub animations: bool,
 
     /// Show startup tooltips in the TUI welcome screen.
@@ -3970,7 +3971,11 @@ impl Config {
                 .as_ref()
                 .map(|t| t.notification_settings.clone())
                 .unwrap_or_default(),
-            animations: cfg.tui.as_ref().map(|t| t.animations).unwrap_or(true),
+            animations: cfg
+                .tui
+                .as_ref()
+                .map(|t| t.animations && !t.reduce_motion)
+                .unwrap_or(true),
             show_tooltips: cfg.tui.as_ref().map(|t| t.show_tooltips).unwrap_or(true),
             model_availability_nux: cfg
                 .tui
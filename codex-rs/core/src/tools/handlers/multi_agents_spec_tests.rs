@@ -33,6 +33,13 @@ fn model_preset(id: &str, show_in_picker: bool) -> ModelPreset {
         availability_nux: None,
         supported_in_api: true,
         input_modalities: Vec::new(),
+        context_window: None,
+        max_output_tokens: None,
+        release_date: None,
+        deprecated: false,
+        deprecation_message: None,
+        supports_parallel_tool_calls: false,
+        supports_reasoning_summaries: false,
     }
 }
 
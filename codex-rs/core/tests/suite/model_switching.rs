@@ -110,6 +110,10 @@ fn test_model_info(
         supports_image_detail_original: false,
         context_window: Some(272_000),
         max_context_window: None,
+        max_output_tokens: None,
+        release_date: None,
+        deprecated: false,
+        deprecation_message: None,
         auto_compact_token_limit: None,
         comp_hash: None,
         effective_context_window_percent: 95,
@@ -938,6 +942,10 @@ async fn model_switch_to_smaller_model_updates_token_context_window() -> Result<
         supports_image_detail_original: false,
         context_window: Some(large_context_window),
         max_context_window: None,
+        max_output_tokens: None,
+        release_date: None,
+        deprecated: false,
+        deprecation_message: None,
         auto_compact_token_limit: None,
         comp_hash: None,
         effective_context_window_percent,
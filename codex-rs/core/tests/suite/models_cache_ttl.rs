@@ -380,6 +380,10 @@ fn test_remote_model(slug: &str, priority: i32) -> ModelInfo {
         supports_image_detail_original: false,
         context_window: Some(272_000),
         max_context_window: None,
+        max_output_tokens: None,
+        release_date: None,
+        deprecated: false,
+        deprecation_message: None,
         auto_compact_token_limit: None,
         comp_hash: None,
         effective_context_window_percent: 95,
@@ -578,6 +578,10 @@ async fn remote_model_friendly_personality_instructions_with_feature() -> anyhow
         supports_image_detail_original: false,
         context_window: Some(128_000),
         max_context_window: None,
+        max_output_tokens: None,
+        release_date: None,
+        deprecated: false,
+        deprecation_message: None,
         auto_compact_token_limit: None,
         comp_hash: None,
         effective_context_window_percent: 95,
@@ -695,6 +699,10 @@ async fn user_turn_personality_remote_model_template_includes_update_message() -
         supports_image_detail_original: false,
         context_window: Some(128_000),
         max_context_window: None,
+        max_output_tokens: None,
+        release_date: None,
+        deprecated: false,
+        deprecation_message: None,
         auto_compact_token_limit: None,
         comp_hash: None,
         effective_context_window_percent: 95,
@@ -503,6 +503,10 @@ async fn remote_models_remote_model_uses_unified_exec() -> Result<()> {
         supports_image_detail_original: false,
         context_window: Some(272_000),
         max_context_window: None,
+        max_output_tokens: None,
+        release_date: None,
+        deprecated: false,
+        deprecation_message: None,
         auto_compact_token_limit: None,
         comp_hash: None,
         effective_context_window_percent: 95,
@@ -757,6 +761,10 @@ async fn remote_models_apply_remote_base_instructions() -> Result<()> {
         supports_image_detail_original: false,
         context_window: Some(272_000),
         max_context_window: None,
+        max_output_tokens: None,
+        release_date: None,
+        deprecated: false,
+        deprecation_message: None,
         auto_compact_token_limit: None,
         comp_hash: None,
         effective_context_window_percent: 95,
@@ -1284,6 +1292,10 @@ fn test_remote_model_with_policy(
         supports_image_detail_original: false,
         context_window: Some(272_000),
         max_context_window: None,
+        max_output_tokens: None,
+        release_date: None,
+        deprecated: false,
+        deprecation_message: None,
         auto_compact_token_limit: None,
         comp_hash: None,
         effective_context_window_percent: 95,
@@ -1665,6 +1665,10 @@ async fn stdio_image_responses_are_sanitized_for_text_only_model() -> anyhow::Re
                 supports_image_detail_original: false,
                 context_window: Some(272_000),
                 max_context_window: None,
+                max_output_tokens: None,
+                release_date: None,
+                deprecated: false,
+                deprecation_message: None,
                 auto_compact_token_limit: None,
                 comp_hash: None,
                 effective_context_window_percent: 95,
@@ -6,6 +6,8 @@ use serde::Serialize;
 use std::io;
 use std::io::ErrorKind;
 use std::path::PathBuf;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
 use std::time::Duration;
 use tokio::fs;
 use tracing::error;
@@ -16,6 +18,9 @@ use tracing::info;
 pub(crate) struct ModelsCacheManager {
     cache_path: PathBuf,
     cache_ttl: Duration,
+    /// Set once an unreadable cache file has been reported, so a corrupt file
+    /// does not spam an error on every catalog access.
+    load_error_logged: AtomicBool,
 }
 
 impl ModelsCacheManager {
@@ -24,6 +29,7 @@ impl ModelsCacheManager {
         Self {
             cache_path,
             cache_ttl,
+            load_error_logged: AtomicBool::new(false),
         }
     }
 
@@ -35,9 +41,14 @@ impl ModelsCacheManager {
             "models cache: attempting load_fresh"
         );
         let cache = match self.load().await {
-            Ok(cache) => cache?,
+            Ok(cache) => {
+                self.load_error_logged.store(false, Ordering::Relaxed);
+                cache?
+            }
             Err(err) => {
-                error!("failed to load models cache: {err}");
+                if !self.load_error_logged.swap(true, Ordering::Relaxed) {
+                    error!("failed to load models cache: {err}");
+                }
                 return None;
             }
         };
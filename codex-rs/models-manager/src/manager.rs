@@ -2,11 +2,14 @@ use super::cache::ModelsCacheManager;
 use crate::collaboration_mode_presets::builtin_collaboration_mode_presets;
 use crate::config::ModelsManagerConfig;
 use crate::model_info;
+use chrono::DateTime;
+use chrono::Utc;
 use codex_http_client::HttpClientFactory;
 use codex_login::AuthManager;
 use codex_protocol::auth::AuthMode;
 use codex_protocol::config_types::CollaborationModeMask;
 use codex_protocol::error::Result as CoreResult;
+use codex_protocol::openai_models::ModelCatalogSource;
 use codex_protocol::openai_models::ModelInfo;
 use codex_protocol::openai_models::ModelPreset;
 use codex_protocol::openai_models::ModelVisibility;
@@ -116,6 +119,14 @@ pub trait ModelsManager: fmt::Debug + Send + Sync {
     /// Returns an error if the internal lock cannot be acquired.
     fn try_get_remote_models(&self) -> Result<Vec<ModelInfo>, TryLockError>;
 
+    /// Report where the active catalog snapshot came from, so clients can tell
+    /// the compiled-in baseline apart from cached data.
+    fn catalog_source(&self) -> ModelsManagerFuture<'_, ModelCatalogSource>;
+
+    /// Report when the active cache snapshot was fetched from the models
+    /// endpoint. None while the compiled-in baseline is being served.
+    fn catalog_fetched_at(&self) -> ModelsManagerFuture<'_, Option<DateTime<Utc>>>;
+
     /// Return the auth manager used for picker filtering.
     fn auth_manager(&self) -> Option<&AuthManager>;
 
@@ -215,6 +226,8 @@ pub type SharedModelsManager = Arc<dyn ModelsManager>;
 pub struct OpenAiModelsManager {
     remote_models: RwLock<Vec<ModelInfo>>,
     etag: RwLock<Option<String>>,
+    catalog_source: RwLock<ModelCatalogSource>,
+    catalog_fetched_at: RwLock<Option<DateTime<Utc>>>,
     cache_manager: ModelsCacheManager,
     endpoint_client: SharedModelsEndpointClient,
     auth_manager: Option<Arc<AuthManager>>,
@@ -240,6 +253,10 @@ impl OpenAiModelsManager {
         Self {
             remote_models: RwLock::new(remote_models),
             etag: RwLock::new(None),
+            // The constructor seeds the catalog from the bundled table; the
+            // source flips to `Cache` once a cache load or fetch succeeds.
+            catalog_source: RwLock::new(ModelCatalogSource::Builtin),
+            catalog_fetched_at: RwLock::new(None),
             cache_manager,
             endpoint_client,
             auth_manager,
@@ -278,6 +295,14 @@ impl ModelsManager for OpenAiModelsManager {
         Ok(self.remote_models.try_read()?.clone())
     }
 
+    fn catalog_source(&self) -> ModelsManagerFuture<'_, ModelCatalogSource> {
+        Box::pin(async move { *self.catalog_source.read().await })
+    }
+
+    fn catalog_fetched_at(&self) -> ModelsManagerFuture<'_, Option<DateTime<Utc>>> {
+        Box::pin(async move { *self.catalog_fetched_at.read().await })
+    }
+
     fn auth_manager(&self) -> Option<&AuthManager> {
         self.auth_manager.as_deref()
     }
@@ -381,6 +406,8 @@ impl OpenAiModelsManager {
             .await?;
         self.apply_remote_models(models.clone()).await;
         *self.etag.write().await = etag.clone();
+        *self.catalog_source.write().await = ModelCatalogSource::Cache;
+        *self.catalog_fetched_at.write().await = Some(Utc::now());
         self.cache_manager
             .persist_cache(&models, etag, client_version)
             .await;
@@ -463,6 +490,8 @@ impl OpenAiModelsManager {
         let models = cache.models.clone();
         *self.etag.write().await = cache.etag.clone();
         self.apply_remote_models(models.clone()).await;
+        *self.catalog_source.write().await = ModelCatalogSource::Cache;
+        *self.catalog_fetched_at.write().await = Some(cache.fetched_at);
         info!(
             models_count = models.len(),
             etag = ?cache.etag,
@@ -529,6 +558,17 @@ impl ModelsManager for StaticModelsManager {
         Ok(self.remote_models.clone())
     }
 
+    fn catalog_source(&self) -> ModelsManagerFuture<'_, ModelCatalogSource> {
+        // An explicitly supplied catalog is authoritative, not the compiled-in
+        // fallback.
+        Box::pin(async { ModelCatalogSource::Cache })
+    }
+
+    fn catalog_fetched_at(&self) -> ModelsManagerFuture<'_, Option<DateTime<Utc>>> {
+        // A static catalog has no backing cache file to date.
+        Box::pin(async { None })
+    }
+
     fn auth_manager(&self) -> Option<&AuthManager> {
         self.auth_manager.as_deref()
     }
@@ -104,6 +104,10 @@ pub fn model_info_from_slug(slug: &str) -> ModelInfo {
         supports_image_detail_original: false,
         context_window: Some(272_000),
         max_context_window: Some(272_000),
+        max_output_tokens: None,
+        release_date: None,
+        deprecated: false,
+        deprecation_message: None,
         auto_compact_token_limit: None,
         comp_hash: None,
         effective_context_window_percent: 95,
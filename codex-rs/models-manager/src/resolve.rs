@@ -0,0 +1,176 @@
+//! Resolution of free-form model references against the active catalog.
+//!
+//! Configs and scripts refer to models by several spellings: the canonical
+//! id, a family name covering dated snapshots, or a shorthand prefix. This
+//! module canonicalizes all of them in one place so the CLI's `-m` handling
+//! and the app server's `model/resolve` endpoint agree on the result.
+
+use codex_protocol::openai_models::ModelPreset;
+
+/// Maximum number of suggestions attached to a failed resolution.
+const MAX_SUGGESTIONS: usize = 3;
+
+/// How a free-form model reference matched a catalog entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModelAliasKind {
+    /// The query equals a model id or slug verbatim.
+    Exact,
+    /// The query names a model family; the newest member was chosen.
+    FamilyLatest,
+    /// The query uniquely prefixes a single model id.
+    Prefix,
+}
+
+/// A resolved model reference: the canonical catalog entry plus how the
+/// query matched it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolvedModel {
+    pub preset: ModelPreset,
+    pub alias_kind: ModelAliasKind,
+}
+
+/// Why a model reference failed to resolve against the catalog.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResolveModelError {
+    /// The query prefixes several ids without naming a family.
+    Ambiguous { candidates: Vec<String> },
+    /// Nothing matched; `suggestions` holds up to three closest ids.
+    NotFound { suggestions: Vec<String> },
+}
+
+/// Resolve a free-form model reference against `catalog`.
+///
+/// Matching is case-insensitive over trimmed input and tries, in order:
+///
+/// 1. an exact id or model-slug match;
+/// 2. a family name: `query` plus a `-` separator prefixes one or more ids,
+///    in which case the newest member wins (release date first, catalog
+///    order as the tiebreak);
+/// 3. a prefix of exactly one id.
+///
+/// A prefix shared by several ids that does not sit on a `-` boundary is
+/// ambiguous rather than silently picking one; an unmatched query reports
+/// the closest ids by shared prefix and edit distance as suggestions.
+pub fn resolve_model(
+    query: &str,
+    catalog: &[ModelPreset],
+) -> Result<ResolvedModel, ResolveModelError> {
+    let query = query.trim().to_ascii_lowercase();
+
+    if let Some(preset) = catalog
+        .iter()
+        .find(|preset| preset.id.eq_ignore_ascii_case(&query))
+        .or_else(|| {
+            catalog
+                .iter()
+                .find(|preset| preset.model.eq_ignore_ascii_case(&query))
+        })
+    {
+        return Ok(ResolvedModel {
+            preset: preset.clone(),
+            alias_kind: ModelAliasKind::Exact,
+        });
+    }
+
+    if !query.is_empty() {
+        let family_prefix = format!("{query}-");
+        if let Some(latest) = latest_family_member(&family_prefix, catalog) {
+            return Ok(ResolvedModel {
+                preset: latest.clone(),
+                alias_kind: ModelAliasKind::FamilyLatest,
+            });
+        }
+
+        let prefix_matches: Vec<&ModelPreset> = catalog
+            .iter()
+            .filter(|preset| preset.id.to_ascii_lowercase().starts_with(&query))
+            .collect();
+        match prefix_matches.as_slice() {
+            [] => {}
+            [only] => {
+                return Ok(ResolvedModel {
+                    preset: (*only).clone(),
+                    alias_kind: ModelAliasKind::Prefix,
+                });
+            }
+            many => {
+                return Err(ResolveModelError::Ambiguous {
+                    candidates: many.iter().map(|preset| preset.id.clone()).collect(),
+                });
+            }
+        }
+    }
+
+    Err(ResolveModelError::NotFound {
+        suggestions: closest_ids(&query, catalog),
+    })
+}
+
+/// Pick the newest member of a family identified by `family_prefix`
+/// (a lowercase family name already terminated by `-`). Release dates are
+/// RFC 3339 dates, so a lexicographic comparison orders them; undated
+/// members lose to dated ones and ties keep catalog order.
+fn latest_family_member<'a>(
+    family_prefix: &str,
+    catalog: &'a [ModelPreset],
+) -> Option<&'a ModelPreset> {
+    let mut best: Option<&ModelPreset> = None;
+    for preset in catalog {
+        if !preset.id.to_ascii_lowercase().starts_with(family_prefix) {
+            continue;
+        }
+        let newer = match best {
+            Some(current) => preset.release_date > current.release_date,
+            None => true,
+        };
+        if newer {
+            best = Some(preset);
+        }
+    }
+    best
+}
+
+/// Rank catalog ids by similarity to `query`: longest shared prefix first,
+/// then smallest edit distance, then the id itself for a stable order.
+fn closest_ids(query: &str, catalog: &[ModelPreset]) -> Vec<String> {
+    let mut scored: Vec<(usize, usize, String)> = catalog
+        .iter()
+        .map(|preset| {
+            let id = preset.id.to_ascii_lowercase();
+            let shared = query
+                .chars()
+                .zip(id.chars())
+                .take_while(|(a, b)| a == b)
+                .count();
+            (shared, edit_distance(query, &id), preset.id.clone())
+        })
+        .collect();
+    scored.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)).then(a.2.cmp(&b.2)));
+    scored
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, _, id)| id)
+        .collect()
+}
+
+/// Levenshtein distance between two short identifier strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (row, a_char) in a.iter().enumerate() {
+        let mut current = vec![row + 1; b.len() + 1];
+        for (col, b_char) in b.iter().enumerate() {
+            let substitution = previous[col] + usize::from(a_char != b_char);
+            current[col + 1] = substitution
+                .min(previous[col + 1] + 1)
+                .min(current[col] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+#[path = "resolve_tests.rs"]
+mod tests;
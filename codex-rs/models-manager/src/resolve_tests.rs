@@ -0,0 +1,117 @@
+use super::*;
+use codex_protocol::openai_models::ModelInfo;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+fn preset(slug: &str, release_date: Option<&str>) -> ModelPreset {
+    let info: ModelInfo = serde_json::from_value(json!({
+        "slug": slug,
+        "display_name": slug,
+        "description": format!("{slug} desc"),
+        "default_reasoning_level": "medium",
+        "supported_reasoning_levels": [{"effort": "medium", "description": "medium"}],
+        "shell_type": "shell_command",
+        "visibility": "list",
+        "minimal_client_version": [0, 1, 0],
+        "supported_in_api": true,
+        "priority": 1,
+        "upgrade": null,
+        "base_instructions": "base instructions",
+        "supports_reasoning_summaries": false,
+        "support_verbosity": false,
+        "default_verbosity": null,
+        "apply_patch_tool_type": null,
+        "truncation_policy": {"mode": "bytes", "limit": 10_000},
+        "supports_parallel_tool_calls": false,
+        "supports_image_detail_original": false,
+        "context_window": 272_000,
+        "max_context_window": 272_000,
+        "experimental_supported_tools": [],
+        "release_date": release_date,
+    }))
+    .expect("valid model");
+    info.into()
+}
+
+fn catalog() -> Vec<ModelPreset> {
+    vec![
+        preset("gpt-5-codex", None),
+        preset("gpt-5-codex-2025-06-01", Some("2025-06-01")),
+        preset("gpt-5-codex-2025-09-15", Some("2025-09-15")),
+        preset("gpt-5-turbo", None),
+        preset("o4-mini", None),
+    ]
+}
+
+#[test]
+fn resolves_exact_id() {
+    let resolved = resolve_model("gpt-5-codex", &catalog()).expect("resolves");
+    assert_eq!(resolved.preset.id, "gpt-5-codex");
+    assert_eq!(resolved.alias_kind, ModelAliasKind::Exact);
+}
+
+#[test]
+fn exact_match_is_case_insensitive_and_trimmed() {
+    let resolved = resolve_model("  GPT-5-Codex ", &catalog()).expect("resolves");
+    assert_eq!(resolved.preset.id, "gpt-5-codex");
+    assert_eq!(resolved.alias_kind, ModelAliasKind::Exact);
+}
+
+#[test]
+fn family_name_resolves_to_latest_member() {
+    // No exact `o4` entry; `o4-mini` is the only family member.
+    let resolved = resolve_model("o4", &catalog()).expect("resolves");
+    assert_eq!(resolved.preset.id, "o4-mini");
+    assert_eq!(resolved.alias_kind, ModelAliasKind::FamilyLatest);
+
+    // `gpt-5` is a family of dated snapshots plus undated entries; the
+    // newest dated snapshot wins.
+    let resolved = resolve_model("gpt-5", &catalog()).expect("resolves");
+    assert_eq!(resolved.preset.id, "gpt-5-codex-2025-09-15");
+    assert_eq!(resolved.alias_kind, ModelAliasKind::FamilyLatest);
+}
+
+#[test]
+fn unique_prefix_resolves() {
+    let resolved = resolve_model("gpt-5-t", &catalog()).expect("resolves");
+    assert_eq!(resolved.preset.id, "gpt-5-turbo");
+    assert_eq!(resolved.alias_kind, ModelAliasKind::Prefix);
+}
+
+#[test]
+fn ambiguous_prefix_lists_candidates() {
+    // `gpt-5-codex-2025` sits on a `-` boundary, so it is a family name and
+    // resolves; `gpt-5-codex-2025-0` does not and prefixes two ids.
+    let err = resolve_model("gpt-5-codex-2025-0", &catalog()).expect_err("ambiguous");
+    assert_eq!(
+        err,
+        ResolveModelError::Ambiguous {
+            candidates: vec![
+                "gpt-5-codex-2025-06-01".to_string(),
+                "gpt-5-codex-2025-09-15".to_string(),
+            ],
+        }
+    );
+}
+
+#[test]
+fn unknown_input_suggests_closest_ids() {
+    let err = resolve_model("gpt-5-codx", &catalog()).expect_err("not found");
+    let ResolveModelError::NotFound { suggestions } = err else {
+        panic!("expected NotFound, got {err:?}");
+    };
+    assert_eq!(suggestions.len(), MAX_SUGGESTIONS);
+    // All suggestions share the `gpt-5-` prefix; the undamaged id is closest.
+    assert_eq!(suggestions[0], "gpt-5-codex");
+}
+
+#[test]
+fn empty_catalog_yields_no_suggestions() {
+    let err = resolve_model("anything", &[]).expect_err("not found");
+    assert_eq!(
+        err,
+        ResolveModelError::NotFound {
+            suggestions: Vec::new(),
+        }
+    );
+}
@@ -237,6 +237,28 @@ pub struct ModelPreset {
     /// Input modalities accepted when composing user turns for this preset.
     #[serde(default = "default_input_modalities")]
     pub input_modalities: Vec<InputModality>,
+    /// Context window in tokens, when the catalog provides one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context_window: Option<i64>,
+    /// Maximum number of output tokens per response, when known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_output_tokens: Option<i64>,
+    /// Release date of the model as an RFC 3339 date, when the catalog
+    /// provides one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release_date: Option<String>,
+    /// Whether the catalog marks this model as deprecated.
+    #[serde(default)]
+    pub deprecated: bool,
+    /// Catalog-provided message describing the deprecation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deprecation_message: Option<String>,
+    /// Whether the model can invoke multiple tools in parallel.
+    #[serde(default)]
+    pub supports_parallel_tool_calls: bool,
+    /// Whether the model emits reasoning summaries.
+    #[serde(default)]
+    pub supports_reasoning_summaries: bool,
 }
 
 /// Visibility of a model in the picker or APIs.
@@ -251,6 +273,20 @@ pub enum ModelVisibility {
     None,
 }
 
+/// Where the active model catalog snapshot came from.
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, TS, JsonSchema, EnumIter, Display,
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum ModelCatalogSource {
+    /// Compiled-in baseline catalog bundled with the binary.
+    Builtin,
+    /// Catalog loaded from the on-disk cache, including data that was just
+    /// fetched from the network and persisted there.
+    Cache,
+}
+
 /// Shell execution capability for a model.
 #[derive(
     Debug,
@@ -392,6 +428,19 @@ pub struct ModelInfo {
     /// Maximum context window allowed for config overrides.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub max_context_window: Option<i64>,
+    /// Maximum number of output tokens the model can produce in one response.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_output_tokens: Option<i64>,
+    /// Release date of the model as an RFC 3339 date, when the catalog
+    /// provides one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release_date: Option<String>,
+    /// Whether the catalog marks this model as deprecated.
+    #[serde(default)]
+    pub deprecated: bool,
+    /// Catalog-provided message describing the deprecation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deprecation_message: Option<String>,
     /// Token threshold for automatic compaction. When omitted, core derives it
     /// from `context_window` (90%). When provided, core clamps it to 90% of the
     /// context window when available.
@@ -569,6 +618,7 @@ pub struct ModelsResponse {
 impl From<ModelInfo> for ModelPreset {
     fn from(info: ModelInfo) -> Self {
         let supports_personality = info.supports_personality();
+        let context_window = info.resolved_context_window();
         ModelPreset {
             id: info.slug.clone(),
             model: info.slug.clone(),
@@ -595,6 +645,13 @@ impl From<ModelInfo> for ModelPreset {
             availability_nux: info.availability_nux,
             supported_in_api: info.supported_in_api,
             input_modalities: info.input_modalities,
+            context_window,
+            max_output_tokens: info.max_output_tokens,
+            release_date: info.release_date,
+            deprecated: info.deprecated,
+            deprecation_message: info.deprecation_message,
+            supports_parallel_tool_calls: info.supports_parallel_tool_calls,
+            supports_reasoning_summaries: info.supports_reasoning_summaries,
         }
     }
 }
@@ -650,6 +707,30 @@ impl ModelPreset {
             default.is_default = true;
         }
     }
+
+    /// Ordered upgrade chain for this preset, ending at the recommended
+    /// current model.
+    ///
+    /// Each link is resolved against `catalog`; a link whose target is not in
+    /// the catalog still ends the chain with that id. A link that would
+    /// revisit an already-seen model (a catalog cycle) ends the chain instead
+    /// of looping.
+    pub fn upgrade_chain(&self, catalog: &[ModelPreset]) -> Vec<String> {
+        let mut chain: Vec<String> = Vec::new();
+        let mut next = self.upgrade.as_ref().map(|upgrade| upgrade.id.clone());
+        while let Some(id) = next {
+            if id == self.id || chain.contains(&id) {
+                break;
+            }
+            next = catalog
+                .iter()
+                .find(|preset| preset.id == id)
+                .and_then(|preset| preset.upgrade.as_ref())
+                .map(|upgrade| upgrade.id.clone());
+            chain.push(id);
+        }
+        chain
+    }
 }
 
 #[cfg(test)]
@@ -689,6 +770,10 @@ mod tests {
             supports_image_detail_original: false,
             context_window: None,
             max_context_window: None,
+            max_output_tokens: None,
+            release_date: None,
+            deprecated: false,
+            deprecation_message: None,
             auto_compact_token_limit: None,
             comp_hash: None,
             effective_context_window_percent: 95,
@@ -1069,6 +1154,7 @@ mod tests {
         let model = ModelInfo {
             context_window: Some(273_000),
             max_context_window: Some(400_000),
+            max_output_tokens: None,
             ..test_model(/*spec*/ None)
         };
 
@@ -1080,6 +1166,7 @@ mod tests {
         let model = ModelInfo {
             context_window: None,
             max_context_window: Some(400_000),
+            max_output_tokens: None,
             ..test_model(/*spec*/ None)
         };
 
@@ -1181,4 +1268,58 @@ mod tests {
 
         assert_eq!(model.service_tier_for_request(/*service_tier*/ None), None);
     }
+
+    fn preset_with_upgrade(id: &str, upgrade: Option<&str>) -> ModelPreset {
+        let mut info = test_model(/*spec*/ None);
+        info.slug = id.to_string();
+        info.upgrade = upgrade.map(|target| ModelInfoUpgrade {
+            model: target.to_string(),
+            migration_markdown: String::new(),
+        });
+        ModelPreset::from(info)
+    }
+
+    #[test]
+    fn upgrade_chain_follows_linear_links() {
+        let catalog = vec![
+            preset_with_upgrade("model-a", Some("model-b")),
+            preset_with_upgrade("model-b", Some("model-c")),
+            preset_with_upgrade("model-c", None),
+        ];
+
+        assert_eq!(
+            catalog[0].upgrade_chain(&catalog),
+            vec!["model-b".to_string(), "model-c".to_string()]
+        );
+        assert_eq!(catalog[2].upgrade_chain(&catalog), Vec::<String>::new());
+    }
+
+    #[test]
+    fn upgrade_chain_ends_at_missing_target() {
+        let catalog = vec![preset_with_upgrade("model-a", Some("model-gone"))];
+
+        assert_eq!(
+            catalog[0].upgrade_chain(&catalog),
+            vec!["model-gone".to_string()]
+        );
+    }
+
+    #[test]
+    fn upgrade_chain_breaks_cycles() {
+        let catalog = vec![
+            preset_with_upgrade("model-a", Some("model-b")),
+            preset_with_upgrade("model-b", Some("model-a")),
+        ];
+
+        assert_eq!(
+            catalog[0].upgrade_chain(&catalog),
+            vec!["model-b".to_string()]
+        );
+
+        let self_referential = vec![preset_with_upgrade("model-a", Some("model-a"))];
+        assert_eq!(
+            self_referential[0].upgrade_chain(&self_referential),
+            Vec::<String>::new()
+        );
+    }
 }
@@ -1293,6 +1293,10 @@ See the Codex keymap documentation for supported actions and examples."
                         self.backtrack_render_pending = false;
                     }
                     self.chat_widget.maybe_post_pending_notification(tui);
+                    // @cometix: keep the statusline alert latch in sync with
+                    // terminal focus (FocusGained schedules a draw)
+                    self.chat_widget
+                        .sync_terminal_focus(tui.is_terminal_focused());
                     if self
                         .chat_widget
                         .handle_paste_burst_tick(tui.frame_requester())
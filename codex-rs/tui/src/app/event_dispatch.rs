@@ -2159,8 +2159,9 @@ impl App {
             }
             AppEvent::OpenCxlineConfig => {
                 let config = self.chat_widget.get_statusline_config();
+                let live_data = self.chat_widget.get_statusline_preview_data();
                 let _ = tui.enter_alt_screen();
-                self.overlay = Some(Overlay::new_cxline(config));
+                self.overlay = Some(Overlay::new_cxline(config, Some(live_data)));
                 tui.frame_requester().schedule_frame();
             }
             AppEvent::OpenTranslateConfig => {
@@ -164,13 +164,24 @@ impl App {
             return;
         }
 
+        // @cometix: configurable shortcut for the statusline appearance overlay;
+        // unbound by default, routed through the same event as `/statusline config`.
+        if app_keymap_shortcuts_available
+            && self.keymap.app.open_cxline_config.is_pressed(key_event)
+        {
+            self.app_event_tx.send(AppEvent::OpenCxlineConfig);
+            return;
+        }
+
         if app_keymap_shortcuts_available && self.keymap.app.open_transcript.is_pressed(key_event) {
             // Enter alternate screen and set viewport to full size.
             let _ = tui.enter_alt_screen();
-            self.overlay = Some(Overlay::new_transcript(
+            let mut overlay = crate::pager_overlay::TranscriptOverlay::new(
                 self.transcript_cells.clone(),
                 self.keymap.pager.clone(),
-            ));
+            );
+            overlay.set_title_translations(self.chat_widget.reasoning_title_translations());
+            self.overlay = Some(Overlay::Transcript(overlay));
             tui.frame_requester().schedule_frame();
             return;
         }
@@ -283,10 +283,12 @@ impl App {
     /// Open transcript overlay (enters alternate screen and shows full transcript).
     pub(crate) fn open_transcript_overlay(&mut self, tui: &mut tui::Tui) {
         let _ = tui.enter_alt_screen();
-        self.overlay = Some(Overlay::new_transcript(
+        let mut overlay = crate::pager_overlay::TranscriptOverlay::new(
             self.transcript_cells.clone(),
             self.keymap.pager.clone(),
-        ));
+        );
+        overlay.set_title_translations(self.chat_widget.reasoning_title_translations());
+        self.overlay = Some(Overlay::Transcript(overlay));
         tui.frame_requester().schedule_frame();
     }
 
@@ -470,6 +472,10 @@ impl App {
                 }
                 self.close_transcript_overlay(tui);
                 tui.frame_requester().schedule_frame();
+            } else if let Some(config) = overlay.cxline_working_config() {
+                // CxLine overlay 未关闭时也同步工作配置，让主界面状态栏实时跟随编辑；
+                // 取消退出会走上面的关闭分支，用保存过的配置覆盖回去
+                self.chat_widget.set_statusline_config(config);
             }
         }
         Ok(())
@@ -288,6 +288,7 @@ impl AppServerSession {
                     cursor: None,
                     limit: None,
                     include_hidden: Some(true),
+                    ..Default::default()
                 },
             })
             .await
@@ -1247,7 +1248,9 @@ fn model_preset_from_api_model(model: ApiModel) -> ModelPreset {
         model: model.model,
         display_name: model.display_name,
         description: model.description,
-        default_reasoning_effort: model.default_reasoning_effort,
+        default_reasoning_effort: model
+            .default_reasoning_effort
+            .unwrap_or(codex_protocol::openai_models::ReasoningEffort::None),
         supported_reasoning_efforts: model
             .supported_reasoning_efforts
             .into_iter()
@@ -1277,6 +1280,13 @@ fn model_preset_from_api_model(model: ApiModel) -> ModelPreset {
         // `model/list` already returns models filtered for the active client/auth context.
         supported_in_api: true,
         input_modalities: model.input_modalities,
+        context_window: model.context_window,
+        max_output_tokens: model.max_output_tokens,
+        release_date: None,
+        deprecated: false,
+        deprecation_message: None,
+        supports_parallel_tool_calls: model.supports_parallel_tool_calls,
+        supports_reasoning_summaries: model.supports_reasoning_summaries,
     }
 }
 
@@ -415,9 +415,14 @@ pub(crate) struct ChatComposer {
     statusline_reasoning_effort: Option<codex_protocol::openai_models::ReasoningEffort>,
     statusline_context_used_tokens: Option<i64>,
     statusline_context_window_size: Option<i64>,
+    statusline_turn_start_tokens: Option<i64>,
     statusline_hourly_rate_limit_percent: Option<f64>,
     statusline_weekly_rate_limit_percent: Option<f64>,
     statusline_weekly_resets_at: Option<String>,
+    statusline_terminal_focused: bool,
+    statusline_alert_missed: u32,
+    /// segment 收集结果的 memo 缓存（渲染走 `&self`，经 RefCell 跨帧持有）
+    statusline_memo: std::cell::RefCell<crate::statusline::SegmentMemo>,
 }
 
 #[derive(Clone, Debug)]
@@ -594,8 +599,12 @@ impl ChatComposer {
             statusline_reasoning_effort: None,
             statusline_context_used_tokens: None,
             statusline_context_window_size: None,
+            statusline_turn_start_tokens: None,
             statusline_hourly_rate_limit_percent: None,
             statusline_weekly_rate_limit_percent: None,
+            statusline_memo: std::cell::RefCell::new(crate::statusline::SegmentMemo::new()),
+            statusline_terminal_focused: true,
+            statusline_alert_missed: 0,
             statusline_weekly_resets_at: None,
         };
         // Apply configuration via the setter to keep side-effects centralized.
@@ -4084,6 +4093,12 @@ impl ChatComposer {
         self.statusline_git_preview = Some(preview);
     }
 
+    /// Alert segment 状态：终端焦点 + 失焦期间错过的事件数
+    pub fn set_statusline_alert(&mut self, terminal_focused: bool, missed_count: u32) {
+        self.statusline_terminal_focused = terminal_focused;
+        self.statusline_alert_missed = missed_count;
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn set_statusline_data(
         &mut self,
@@ -4092,6 +4107,7 @@ impl ChatComposer {
         reasoning_effort: Option<codex_protocol::openai_models::ReasoningEffort>,
         context_used_tokens: Option<i64>,
         context_window_size: Option<i64>,
+        turn_start_tokens: Option<i64>,
         hourly_rate_limit_percent: Option<f64>,
         weekly_rate_limit_percent: Option<f64>,
         weekly_rate_limit_resets_at: Option<String>,
@@ -4101,24 +4117,84 @@ impl ChatComposer {
         self.statusline_reasoning_effort = reasoning_effort;
         self.statusline_context_used_tokens = context_used_tokens;
         self.statusline_context_window_size = context_window_size;
+        self.statusline_turn_start_tokens = turn_start_tokens;
         self.statusline_hourly_rate_limit_percent = hourly_rate_limit_percent;
         self.statusline_weekly_rate_limit_percent = weekly_rate_limit_percent;
         self.statusline_weekly_resets_at = weekly_rate_limit_resets_at;
     }
 
+    /// 当前会话数据的快照（用于配置 Overlay 的实时预览）
+    pub fn get_statusline_preview_data(&self) -> crate::statusline::StatusLinePreviewData {
+        crate::statusline::StatusLinePreviewData {
+            model: self.statusline_model.clone(),
+            cwd: self.statusline_cwd.clone(),
+            reasoning_effort: self.statusline_reasoning_effort.clone(),
+            context_used_tokens: self.statusline_context_used_tokens,
+            context_window_size: self.statusline_context_window_size,
+            hourly_rate_limit_percent: self.statusline_hourly_rate_limit_percent,
+            weekly_rate_limit_percent: self.statusline_weekly_rate_limit_percent,
+            weekly_rate_limit_resets_at: self.statusline_weekly_resets_at.clone(),
+            git_preview: self.statusline_git_preview.clone(),
+        }
+    }
+
     pub fn build_cxline_line(&self) -> ratatui::text::Line<'static> {
-        let ctx = crate::statusline::StatusLineContext {
+        self.build_cxline_line_fitted(u16::MAX)
+    }
+
+    /// 按给定宽度渲染 cxline：放不下时逐个丢弃右侧 segment。
+    /// 每帧用当前区域宽度调用，终端 resize 后立即收缩。
+    /// 收集走 memo 层：上下文未变化的 segment 复用上次结果
+    pub fn build_cxline_line_fitted(&self, max_width: u16) -> ratatui::text::Line<'static> {
+        crate::statusline::build_statusline_memoized(
+            &self.statusline_config,
+            &self.statusline_context(),
+            &mut self.statusline_memo.borrow_mut(),
+        )
+        .render_line_fitted(max_width)
+    }
+
+    /// 当前会话数据的渲染上下文（渲染与点击命中测试共用同一份数据）
+    fn statusline_context(&self) -> crate::statusline::StatusLineContext<'_> {
+        crate::statusline::StatusLineContext {
             model_name: &self.statusline_model,
             cwd: &self.statusline_cwd,
             reasoning_effort: self.statusline_reasoning_effort.clone(),
             context_used_tokens: self.statusline_context_used_tokens,
             context_window_size: self.statusline_context_window_size,
+            turn_start_tokens: self.statusline_turn_start_tokens,
             hourly_rate_limit_percent: self.statusline_hourly_rate_limit_percent,
             weekly_rate_limit_percent: self.statusline_weekly_rate_limit_percent,
             weekly_rate_limit_resets_at: self.statusline_weekly_resets_at.clone(),
             git_preview: self.statusline_git_preview.clone(),
-        };
-        crate::statusline::build_statusline(&self.statusline_config, &ctx).render_line()
+            terminal_focused: self.statusline_terminal_focused,
+            alert_missed_count: self.statusline_alert_missed,
+            locale: self.statusline_config.locale,
+        }
+    }
+
+    /// 点击命中测试：`column` 是相对状态栏行首的列号，`max_width`
+    /// 是本帧渲染用的宽度（两者必须与 `build_cxline_line_fitted` 一致，
+    /// 收缩后被丢弃的 segment 才不会误响应）。
+    /// 状态栏未启用、点到分隔符/空白、或 segment 未配置动作时返回 None
+    pub fn statusline_click_action(
+        &self,
+        column: u16,
+        max_width: u16,
+    ) -> Option<crate::statusline::SegmentClickAction> {
+        if !self.statusline_config.enabled {
+            return None;
+        }
+        let ctx = self.statusline_context();
+        let renderer = crate::statusline::build_statusline(&self.statusline_config, &ctx);
+        let (id, _) = renderer
+            .segment_extents_fitted(max_width)
+            .into_iter()
+            .find(|(_, range)| range.contains(&column))?;
+        match self.statusline_config.get_segment_config(id).on_click {
+            crate::statusline::SegmentClickAction::None => None,
+            action => Some(action),
+        }
     }
 }
 
@@ -4356,6 +4432,21 @@ impl ChatComposer {
                     let available_width =
                         hint_rect.width.saturating_sub(FOOTER_INDENT_COLS as u16) as usize;
                     let status_line_active = uses_passive_footer_status_layout(&footer_props);
+                    // @cometix: re-fit the cxline statusline to the current
+                    // width on every draw, so a terminal resize shrinks it
+                    // segment by segment immediately instead of waiting for
+                    // the next data event. The ellipsis truncation below stays
+                    // as the final guard (it also covers the agent label).
+                    let footer_props = if status_line_active && self.statusline_config.enabled {
+                        FooterProps {
+                            status_line_value: Some(
+                                self.build_cxline_line_fitted(available_width as u16),
+                            ),
+                            ..footer_props
+                        }
+                    } else {
+                        footer_props
+                    };
                     let combined_status_line = if status_line_active {
                         passive_footer_status_line(&footer_props)
                     } else {
@@ -4731,6 +4822,41 @@ mod tests {
         );
     }
 
+    // @cometix: 点击命中测试走配置里的 on_click；未配置动作 / 状态栏关闭时不响应
+    #[test]
+    fn statusline_click_action_resolves_on_click_from_config() {
+        let (mut composer, _rx) = new_test_composer();
+        let mut config = crate::statusline::themes::ThemePresets::get_default();
+        config.segments.model.on_click = crate::statusline::SegmentClickAction::OpenModelPicker;
+        composer.set_statusline_config(config.clone());
+        composer.set_statusline_data(
+            "gpt-5.1-codex".to_string(),
+            PathBuf::from("/tmp"),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+
+        // 第一列落在 Model segment 上
+        assert_eq!(
+            composer.statusline_click_action(0, u16::MAX),
+            Some(crate::statusline::SegmentClickAction::OpenModelPicker)
+        );
+
+        // 行尾落在 Directory segment 上，默认 on_click = none
+        let last_col = composer.build_cxline_line().width() as u16 - 1;
+        assert_eq!(composer.statusline_click_action(last_col, u16::MAX), None);
+
+        // 状态栏关闭时整行不响应
+        config.enabled = false;
+        composer.set_statusline_config(config);
+        assert_eq!(composer.statusline_click_action(0, u16::MAX), None);
+    }
+
     #[test]
     fn footer_flash_overrides_footer_hint_override() {
         let (tx, _rx) = unbounded_channel::<AppEvent>();
@@ -1051,6 +1051,15 @@ impl BottomPane {
         }
     }
 
+    /// Update the reduced-motion preference, including any live status indicator.
+    pub(crate) fn set_animations_enabled(&mut self, enabled: bool) {
+        self.animations_enabled = enabled;
+        if let Some(status) = self.status.as_mut() {
+            status.set_animations_enabled(enabled);
+        }
+        self.request_redraw();
+    }
+
     pub(crate) fn set_context_window(&mut self, percent: Option<i64>, used_tokens: Option<i64>) {
         if self.context_window_percent == percent && self.context_window_used_tokens == used_tokens
         {
@@ -1069,6 +1078,10 @@ impl BottomPane {
         self.composer.get_statusline_config()
     }
 
+    pub(crate) fn get_statusline_preview_data(&self) -> crate::statusline::StatusLinePreviewData {
+        self.composer.get_statusline_preview_data()
+    }
+
     pub(crate) fn set_statusline_config(
         &mut self,
         config: crate::statusline::config::CxLineConfig,
@@ -1076,6 +1089,15 @@ impl BottomPane {
         self.composer.set_statusline_config(config);
     }
 
+    // @cometix: proxy cxline click hit-testing to chat_composer
+    pub(crate) fn statusline_click_action(
+        &self,
+        column: u16,
+        max_width: u16,
+    ) -> Option<crate::statusline::SegmentClickAction> {
+        self.composer.statusline_click_action(column, max_width)
+    }
+
     // @cometix: proxy git preview to chat_composer for cxline
     pub(crate) fn set_statusline_git_preview(
         &mut self,
@@ -1085,6 +1107,13 @@ impl BottomPane {
         self.request_redraw();
     }
 
+    // @cometix: proxy alert latch state to chat_composer
+    pub(crate) fn set_statusline_alert(&mut self, terminal_focused: bool, missed_count: u32) {
+        self.composer
+            .set_statusline_alert(terminal_focused, missed_count);
+        self.request_redraw();
+    }
+
     // @cometix: proxy statusline data to chat_composer
     #[allow(clippy::too_many_arguments)]
     pub(crate) fn set_statusline_data(
@@ -1094,6 +1123,7 @@ impl BottomPane {
         reasoning_effort: Option<codex_protocol::openai_models::ReasoningEffort>,
         context_used_tokens: Option<i64>,
         context_window_size: Option<i64>,
+        turn_start_tokens: Option<i64>,
         hourly_rate_limit_percent: Option<f64>,
         weekly_rate_limit_percent: Option<f64>,
         weekly_rate_limit_resets_at: Option<String>,
@@ -1104,6 +1134,7 @@ impl BottomPane {
             reasoning_effort,
             context_used_tokens,
             context_window_size,
+            turn_start_tokens,
             hourly_rate_limit_percent,
             weekly_rate_limit_percent,
             weekly_rate_limit_resets_at,
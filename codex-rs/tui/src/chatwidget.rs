@@ -560,7 +560,7 @@ pub(crate) struct ChatWidget {
     pending_rate_limit_reset_request_id: Option<u64>,
     pending_rate_limit_reset_hint_request_id: Option<u64>,
     pending_usage_menu_rate_limit_request_id: Option<u64>,
-    pending_rate_limit_reset_hint: Option<PlainHistoryCell>,
+    pending_rate_limit_reset_hint: Option<history_cell::UiNoticeCell>,
     available_rate_limit_reset_credits: Option<i64>,
     next_rate_limit_reset_request_id: u64,
     plan_type: Option<PlanType>,
@@ -751,6 +751,10 @@ pub(crate) struct ChatWidget {
     pub(crate) reasoning_translator: crate::translation::ReasoningTranslator,
     pub(crate) cxline_weekly_resets_at_ts: Option<i64>,
     pub(crate) cxline_git_preview_pending: bool,
+    /// 本回合开始时的已用 token 数（回合结束后清空，用于 cxline 的 show_turn_delta）
+    pub(crate) cxline_turn_start_tokens: Option<i64>,
+    /// 失焦提醒闩锁（Alert segment 的数据源）
+    pub(crate) cxline_alert_latch: crate::statusline::segments::AlertLatch,
 }
 
 #[cfg_attr(not(test), allow(dead_code))]
@@ -1745,6 +1749,18 @@ impl ChatWidget {
         self.add_info_message(message.to_string(), /*hint*/ None);
     }
 
+    pub(crate) fn toggle_reduce_motion_and_notify(&mut self) {
+        self.config.animations = !self.config.animations;
+        self.bottom_pane
+            .set_animations_enabled(self.config.animations);
+        let message = if self.config.animations {
+            "Reduced motion disabled."
+        } else {
+            "Reduced motion enabled."
+        };
+        self.add_info_message(message.to_string(), /*hint*/ None);
+    }
+
     /// True when the UI is in the regular composer state with no running task,
     /// no modal overlay (e.g. approvals or status indicator), and no composer popups.
     /// In this state Esc-Esc backtracking is enabled.
@@ -2051,9 +2067,166 @@ impl ChatWidget {
         self.bottom_pane.get_statusline_config()
     }
 
+    pub(crate) fn get_statusline_preview_data(&self) -> crate::statusline::StatusLinePreviewData {
+        self.bottom_pane.get_statusline_preview_data()
+    }
+
     pub(crate) fn get_translation_config(&self) -> crate::translation::TranslationConfig {
         self.reasoning_translator.config().clone()
     }
+
+    /// Cached reasoning title translations for bilingual transcript rendering.
+    pub(crate) fn reasoning_title_translations(&self) -> std::collections::HashMap<String, String> {
+        self.reasoning_translator.title_translation_cache()
+    }
+
+    /// `/translate status`: report the orchestrator counters and the
+    /// translator daemon's supervision state.
+    pub(crate) fn add_translate_daemon_status_output(&mut self) {
+        let snapshot = self.reasoning_translator.snapshot();
+        let mut lines: Vec<Line<'static>> = vec!["• Translation status".into()];
+        lines.push(
+            format!("  {}", format_translator_snapshot(&snapshot))
+                .dim()
+                .into(),
+        );
+        let daemon_line = if snapshot.daemon_configured {
+            match self.reasoning_translator.daemon_status() {
+                Some(status) => format_daemon_status(&status),
+                None => "Translation daemon is busy; try again.".to_string(),
+            }
+        } else {
+            "No translation daemon configured; set daemon_command in ~/.codex/translation.toml."
+                .to_string()
+        };
+        lines.push(format!("  {daemon_line}").dim().into());
+        let stats = crate::translation::translation_stats();
+        if stats.total > 0 {
+            let mut stats_line = format!(
+                "{} translator calls, {} failed",
+                stats.total, stats.failures
+            );
+            if let (Some(p50), Some(p95)) = (stats.p50_latency, stats.p95_latency) {
+                stats_line.push_str(&format!(
+                    " — p50 {}ms, p95 {}ms",
+                    p50.as_millis(),
+                    p95.as_millis()
+                ));
+            }
+            lines.push(format!("  {stats_line}").dim().into());
+        }
+        let errors = self.reasoning_translator.error_log().len();
+        if errors > 0 {
+            lines.push(
+                format!("  {errors} recent translation errors — see /translate errors")
+                    .dim()
+                    .into(),
+            );
+        }
+        self.add_plain_history_lines(lines);
+    }
+
+    /// `/translate errors`: render the recorded translation failures,
+    /// most recent first.
+    pub(crate) fn add_translate_errors_output(&mut self) {
+        if self.reasoning_translator.error_log().is_empty() {
+            self.add_info_message(
+                "No translation errors recorded.".to_string(),
+                /*hint*/ None,
+            );
+            return;
+        }
+        let mut lines: Vec<Line<'static>> = vec!["• Translation errors (most recent first)".into()];
+        lines.extend(
+            self.reasoning_translator
+                .error_log()
+                .recent()
+                .map(format_translation_error),
+        );
+        self.add_plain_history_lines(lines);
+    }
+
+    /// `/translate errors clear`: drop the recorded translation failures.
+    pub(crate) fn clear_translation_errors(&mut self) {
+        self.reasoning_translator.clear_error_log();
+        self.add_info_message(
+            "Cleared translation error history.".to_string(),
+            /*hint*/ None,
+        );
+    }
+
+    /// `/translate restart`: kill and respawn the translator daemon.
+    pub(crate) fn restart_translation_daemon(&mut self) {
+        if self.reasoning_translator.restart_daemon() {
+            self.add_info_message(
+                "Restarting translation daemon.".to_string(),
+                /*hint*/ None,
+            );
+        } else {
+            self.add_info_message(
+                "No translation daemon configured.".to_string(),
+                Some("Set daemon_command in ~/.codex/translation.toml.".to_string()),
+            );
+        }
+    }
+}
+
+/// One compact `/translate status` line of orchestrator counters.
+fn format_translator_snapshot(
+    snapshot: &crate::translation::TranslationOrchestratorSnapshot,
+) -> String {
+    let mut message = format!(
+        "Translation {}: barrier {}, deferred cells: {}, requests: {}",
+        if snapshot.enabled {
+            "enabled"
+        } else {
+            "disabled"
+        },
+        if snapshot.barrier_active {
+            "active"
+        } else {
+            "idle"
+        },
+        snapshot.deferred_cells,
+        snapshot.requests_started,
+    );
+    message.push_str(&format!(
+        ", cached titles: {}, cached notices: {}",
+        snapshot.title_cache_size, snapshot.notice_cache_size,
+    ));
+    if snapshot.pending_notices > 0 {
+        message.push_str(&format!(", pending notices: {}", snapshot.pending_notices));
+    }
+    message
+}
+
+/// One-line `/translate status` summary of the daemon supervision state.
+fn format_daemon_status(status: &crate::translation::DaemonStatus) -> String {
+    let mut message = format!("Translation daemon: {}", status.state.as_str());
+    if let Some(running_since) = status.running_since {
+        let secs = running_since.elapsed().as_secs();
+        message.push_str(&format!(" (up {}m {}s)", secs / 60, secs % 60));
+    }
+    message.push_str(&format!(", restarts: {}", status.restarts));
+    if let Some(code) = status.last_exit_code {
+        message.push_str(&format!(", last exit code: {code}"));
+    }
+    message
+}
+
+/// One compact `/translate errors` line per recorded failure.
+fn format_translation_error(record: &crate::translation::TranslationErrorRecord) -> Line<'static> {
+    let mut text = format!(
+        "  {} [{}] {}",
+        record.at.format("%H:%M:%S"),
+        record.kind.as_str(),
+        record.message
+    );
+    if let Some(stderr) = &record.stderr_preview {
+        text.push_str(&format!(" — stderr: {stderr}"));
+    }
+    text.push_str(&format!(" (input {:016x})", record.input_hash));
+    text.dim().into()
 }
 
 fn has_websocket_timing_metrics(summary: RuntimeMetricsSummary) -> bool {
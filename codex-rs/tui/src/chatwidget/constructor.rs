@@ -245,8 +245,12 @@ impl ChatWidget {
             reasoning_translator: crate::translation::ReasoningTranslator::default(),
             cxline_weekly_resets_at_ts: None,
             cxline_git_preview_pending: false,
+            cxline_turn_start_tokens: None,
+            cxline_alert_latch: crate::statusline::segments::AlertLatch::new(),
         };
 
+        let model = widget.current_model().to_string();
+        widget.reasoning_translator.set_active_model(&model);
         widget.prefetch_rate_limits();
         if let Some(keymap) = runtime_keymap {
             widget.bottom_pane.set_keymap_bindings(&keymap);
@@ -298,6 +298,74 @@ impl ChatWidget {
         self.transcript.last_agent_markdown.as_deref()
     }
 
+    // @cometix: hit-test a statusline click and dispatch the configured
+    // action. `column` is relative to the start of the statusline row and
+    // `max_width` is the width the row was rendered at, so truncated
+    // segments cannot be clicked. This is the entry point for the mouse
+    // layer once mouse events are wired into the TUI event stream.
+    #[allow(dead_code)]
+    pub(crate) fn handle_statusline_click(&mut self, column: u16, max_width: u16) {
+        use crate::statusline::SegmentClickAction;
+        let Some(action) = self.bottom_pane.statusline_click_action(column, max_width) else {
+            return;
+        };
+        match action {
+            SegmentClickAction::None => {}
+            SegmentClickAction::OpenModelPicker => self.dispatch_command(SlashCommand::Model),
+            SegmentClickAction::ShowUsage => self.dispatch_command(SlashCommand::Usage),
+            SegmentClickAction::Compact => self.dispatch_command(SlashCommand::Compact),
+            SegmentClickAction::CopyCwd => {
+                let cwd = self.bottom_pane.get_statusline_preview_data().cwd;
+                self.copy_statusline_text_with(
+                    "working directory",
+                    &cwd.display().to_string(),
+                    crate::clipboard_copy::copy_to_clipboard,
+                );
+            }
+            SegmentClickAction::CopyGitBranch => {
+                let branch = self
+                    .bottom_pane
+                    .get_statusline_preview_data()
+                    .git_preview
+                    .map(|preview| preview.branch)
+                    .unwrap_or_default();
+                self.copy_statusline_text_with(
+                    "git branch",
+                    &branch,
+                    crate::clipboard_copy::copy_to_clipboard,
+                );
+            }
+        }
+    }
+
+    /// Copy a statusline-derived value to the system clipboard, with an
+    /// injectable backend for testing.
+    pub(super) fn copy_statusline_text_with(
+        &mut self,
+        what: &str,
+        text: &str,
+        copy_fn: impl FnOnce(&str) -> Result<Option<crate::clipboard_copy::ClipboardLease>, String>,
+    ) {
+        if text.is_empty() {
+            self.add_to_history(history_cell::new_error_event(format!("No {what} to copy")));
+            self.request_redraw();
+            return;
+        }
+        match copy_fn(text) {
+            Ok(lease) => {
+                self.clipboard_lease = lease;
+                self.add_to_history(history_cell::new_info_event(
+                    format!("Copied {what} to clipboard"),
+                    /*hint*/ None,
+                ));
+            }
+            Err(error) => self.add_to_history(history_cell::new_error_event(format!(
+                "Copy failed: {error}"
+            ))),
+        }
+        self.request_redraw();
+    }
+
     pub(super) fn show_rename_prompt(&mut self) {
         if !self.ensure_thread_rename_allowed() {
             return;
@@ -4,6 +4,9 @@ use super::*;
 
 impl ChatWidget {
     pub(super) fn notify(&mut self, notification: Notification) {
+        // @cometix: latch the statusline alert even when desktop notifications
+        // for this kind are disabled or unreliable
+        self.note_cxline_alert_event(notification.alert_kind());
         if !notification.allowed_for(&self.config.tui_notifications.notifications) {
             return;
         }
@@ -65,6 +68,18 @@ impl Notification {
         }
     }
 
+    // @cometix: map notifications to statusline alert event kinds
+    pub(super) fn alert_kind(&self) -> crate::statusline::segments::AlertEventKind {
+        use crate::statusline::segments::AlertEventKind;
+        match self {
+            Notification::AgentTurnComplete { .. } => AlertEventKind::TurnComplete,
+            Notification::ExecApprovalRequested { .. }
+            | Notification::EditApprovalRequested { .. }
+            | Notification::ElicitationRequested { .. } => AlertEventKind::Approval,
+            Notification::PlanModePrompt { .. } => AlertEventKind::PlanPrompt,
+        }
+    }
+
     fn type_name(&self) -> &str {
         match self {
             Notification::AgentTurnComplete { .. } => "agent-turn-complete",
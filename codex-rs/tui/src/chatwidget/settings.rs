@@ -269,6 +269,7 @@ impl ChatWidget {
         }
         self.refresh_effective_service_tier();
         self.refresh_model_dependent_surfaces();
+        self.reasoning_translator.set_active_model(model);
     }
 
     pub(crate) fn current_model(&self) -> &str {
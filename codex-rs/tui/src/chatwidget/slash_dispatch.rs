@@ -307,6 +307,9 @@ impl ChatWidget {
             SlashCommand::Vim => {
                 self.toggle_vim_mode_and_notify();
             }
+            SlashCommand::ReduceMotion => {
+                self.toggle_reduce_motion_and_notify();
+            }
             SlashCommand::Keymap => {
                 self.open_keymap_picker();
             }
@@ -687,10 +690,29 @@ impl ChatWidget {
             SlashCommand::Ide => {
                 self.handle_ide_command_args(trimmed);
             }
+            SlashCommand::Translate => {
+                let lowered = trimmed.to_ascii_lowercase();
+                let words: Vec<&str> = lowered.split_whitespace().collect();
+                match words.as_slice() {
+                    ["status"] => self.add_translate_daemon_status_output(),
+                    ["restart"] => self.restart_translation_daemon(),
+                    ["errors"] => self.add_translate_errors_output(),
+                    ["errors", "clear"] => self.clear_translation_errors(),
+                    _ => self.add_error_message(
+                        "Usage: /translate [status|restart|errors [clear]]".to_string(),
+                    ),
+                }
+            }
             SlashCommand::Mcp => match trimmed.to_ascii_lowercase().as_str() {
                 "verbose" => self.add_mcp_output(McpServerStatusDetail::Full),
                 _ => self.add_error_message("Usage: /mcp [verbose]".to_string()),
             },
+            // @cometix: `/statusline config` opens the appearance overlay
+            SlashCommand::Statusline => match trimmed.to_ascii_lowercase().as_str() {
+                "" => self.open_status_line_setup(),
+                "config" => self.app_event_tx.send(AppEvent::OpenCxlineConfig),
+                _ => self.add_error_message("Usage: /statusline [config]".to_string()),
+            },
             SlashCommand::Keymap => match trimmed.to_ascii_lowercase().as_str() {
                 "" => self.open_keymap_picker(),
                 "debug" => {
@@ -1062,6 +1084,7 @@ impl ChatWidget {
             | SlashCommand::Copy
             | SlashCommand::Raw
             | SlashCommand::Vim
+            | SlashCommand::ReduceMotion
             | SlashCommand::Diff
             | SlashCommand::App
             | SlashCommand::Rename
@@ -53,6 +53,18 @@ impl ChatWidget {
         }
     }
 
+    // @cometix: columns available to the bilingual reasoning status header after
+    // the activity indicator and the elapsed/interrupt suffix.
+    pub(super) fn status_header_width_budget(&self) -> usize {
+        // Spinner plus spacing plus "(59m 59s • esc to interrupt)".
+        const STATUS_HEADER_RESERVED_COLS: usize = 32;
+        self.last_rendered_width
+            .get()
+            .filter(|width| *width > 0)
+            .unwrap_or(80)
+            .saturating_sub(STATUS_HEADER_RESERVED_COLS)
+    }
+
     /// Convenience wrapper around [`Self::set_status`];
     /// updates the status indicator header and clears any existing details.
     pub(super) fn set_status_header(&mut self, header: String) {
@@ -134,12 +146,46 @@ impl ChatWidget {
             reasoning_effort,
             used_tokens,
             window_size,
+            self.cxline_turn_start_tokens,
             hourly_percent,
             weekly_percent,
             weekly_resets_at,
         );
     }
 
+    // @cometix: feed terminal focus into the cxline alert latch; regaining
+    // focus clears the latched marker
+    pub(crate) fn sync_terminal_focus(&mut self, focused: bool) {
+        if self.cxline_alert_latch.on_focus_changed(focused) {
+            self.push_cxline_alert();
+        }
+    }
+
+    // @cometix: a session event occurred; latch the alert while unfocused
+    pub(crate) fn note_cxline_alert_event(
+        &mut self,
+        kind: crate::statusline::segments::AlertEventKind,
+    ) {
+        let config = self.bottom_pane.get_statusline_config();
+        let segment = config.get_segment_config(crate::statusline::SegmentId::Alert);
+        if !config.enabled || !segment.enabled {
+            return;
+        }
+        if !crate::statusline::segments::alert::kind_latches(segment, kind) {
+            return;
+        }
+        if self.cxline_alert_latch.on_event() {
+            self.push_cxline_alert();
+        }
+    }
+
+    fn push_cxline_alert(&mut self) {
+        self.bottom_pane.set_statusline_alert(
+            self.cxline_alert_latch.focused(),
+            self.cxline_alert_latch.missed_count(),
+        );
+    }
+
     // @cometix: trigger async git preview for cxline
     fn request_cxline_git_preview(&mut self) {
         if self.cxline_git_preview_pending {
@@ -287,6 +333,11 @@ impl ChatWidget {
             .collect();
         let agents_summary =
             crate::status::compose_agents_summary(&self.config, &self.instruction_source_paths);
+        // @cometix: fork-subsystem sections, built from the same config and
+        // snapshot structs the statusline and translation overlays use so
+        // /status cannot drift from what those features actually do
+        let statusline_section = self.statusline_status_section();
+        let translation_section = self.translation_status_section();
         let (cell, handle) = crate::status::new_status_output_with_rate_limits_handle(
             &self.config,
             self.runtime_model_provider_base_url.as_deref(),
@@ -303,6 +354,8 @@ impl ChatWidget {
             self.model_display_name(),
             collaboration_mode,
             reasoning_effort_override,
+            statusline_section,
+            translation_section,
             agents_summary,
             refreshing_rate_limits,
         );
@@ -312,6 +365,62 @@ impl ChatWidget {
         self.add_to_history(cell);
     }
 
+    // @cometix: "Statusline" section for /status, mirroring the live CxLine
+    // config (theme, style, enabled segments in order, file location).
+    fn statusline_status_section(&self) -> Option<crate::status::StatusLineSectionData> {
+        let cxline = self.bottom_pane.get_statusline_config();
+        if !cxline.enabled {
+            return None;
+        }
+        let style = match cxline.style {
+            crate::statusline::StyleMode::Plain => "plain",
+            crate::statusline::StyleMode::NerdFont => "nerd_font",
+            crate::statusline::StyleMode::Powerline => "powerline",
+        };
+        Some(crate::status::StatusLineSectionData {
+            theme: cxline.theme.clone(),
+            style: style.to_string(),
+            segments: cxline
+                .segment_order
+                .iter()
+                .filter(|id| cxline.get_segment_config(**id).enabled)
+                .map(|id| id.as_str().to_string())
+                .collect(),
+            config_path: crate::statusline::config::CxLineConfig::config_path(),
+        })
+    }
+
+    // @cometix: "Translation" section for /status, derived from the same
+    // orchestrator snapshot `/translate status` reports. Only the daemon
+    // program name is shown; arguments and API keys stay out of history.
+    fn translation_status_section(&self) -> Option<crate::status::TranslationSectionData> {
+        let snapshot = self.reasoning_translator.snapshot();
+        if !snapshot.enabled {
+            return None;
+        }
+        let translation_config = self.reasoning_translator.config();
+        let translator = translation_config
+            .daemon_command
+            .as_ref()
+            .and_then(|command| command.first().cloned())
+            .unwrap_or_else(|| {
+                translation_config
+                    .effective_provider()
+                    .definition()
+                    .name
+                    .to_string()
+            });
+        Some(crate::status::TranslationSectionData {
+            target_language: translation_config.effective_target_language().to_string(),
+            translator,
+            timeout_ms: translation_config.effective_timeout_ms(),
+            title_cache_size: snapshot.title_cache_size,
+            notice_cache_size: snapshot.notice_cache_size,
+            barrier_active: snapshot.barrier_active,
+            deferred_cells: snapshot.deferred_cells,
+        })
+    }
+
     pub(crate) fn finish_status_rate_limit_refresh(
         &mut self,
         request_id: u64,
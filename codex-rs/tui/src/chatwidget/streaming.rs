@@ -9,6 +9,12 @@ impl ChatWidget {
     pub(super) fn restore_reasoning_status_header(&mut self) {
         if let Some(header) = extract_first_bold(&self.reasoning_buffer) {
             self.status_state.terminal_title_status_kind = TerminalTitleStatusKind::Thinking;
+            // @cometix: show the width-fitted bilingual form when the title has
+            // a cached translation
+            let header = self
+                .reasoning_translator
+                .bilingual_status_header(&header, self.status_header_width_budget())
+                .unwrap_or(header);
             self.set_status_header(header);
         } else if self.bottom_pane.is_task_running() {
             self.status_state.terminal_title_status_kind = TerminalTitleStatusKind::Working;
@@ -217,6 +223,12 @@ impl ChatWidget {
         if let Some(header) = extract_first_bold(&self.reasoning_buffer) {
             // Update the shimmer header to the extracted reasoning chunk header.
             self.status_state.terminal_title_status_kind = TerminalTitleStatusKind::Thinking;
+            // @cometix: show the width-fitted bilingual form when the title has
+            // a cached translation
+            let header = self
+                .reasoning_translator
+                .bilingual_status_header(&header, self.status_header_width_budget())
+                .unwrap_or(header);
             self.set_status_header(header);
         } else {
             // Fallback while we don't yet have a bold header: leave existing header as-is.
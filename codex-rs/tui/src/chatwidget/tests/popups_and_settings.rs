@@ -3130,6 +3130,13 @@ async fn model_picker_hides_show_in_picker_false_models_from_cache() {
         availability_nux: None,
         supported_in_api: true,
         input_modalities: default_input_modalities(),
+        context_window: None,
+        max_output_tokens: None,
+        release_date: None,
+        deprecated: false,
+        deprecation_message: None,
+        supports_parallel_tool_calls: false,
+        supports_reasoning_summaries: false,
     };
 
     chat.open_model_popup_with_presets(vec![
@@ -3465,6 +3472,13 @@ async fn single_reasoning_option_skips_selection() {
         availability_nux: None,
         supported_in_api: true,
         input_modalities: default_input_modalities(),
+        context_window: None,
+        max_output_tokens: None,
+        release_date: None,
+        deprecated: false,
+        deprecation_message: None,
+        supports_parallel_tool_calls: false,
+        supports_reasoning_summaries: false,
     };
     chat.open_reasoning_popup(preset);
 
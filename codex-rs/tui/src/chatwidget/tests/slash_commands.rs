@@ -1943,6 +1943,39 @@ async fn slash_copy_stores_clipboard_lease_and_preserves_it_on_failure() {
     );
 }
 
+// @cometix: statusline click copy actions reuse the clipboard lease flow
+#[tokio::test]
+async fn statusline_copy_action_stores_clipboard_lease() {
+    let (mut chat, mut rx, _op_rx) = make_chatwidget_manual(/*model_override*/ None).await;
+
+    chat.copy_statusline_text_with("git branch", "main", |text| {
+        assert_eq!(text, "main");
+        Ok(Some(crate::clipboard_copy::ClipboardLease::test()))
+    });
+
+    assert!(chat.clipboard_lease.is_some());
+    let cells = drain_insert_history(&mut rx);
+    assert_eq!(cells.len(), 1, "expected one success message");
+    let rendered = lines_to_single_string(&cells[0]);
+    assert!(
+        rendered.contains("Copied git branch to clipboard"),
+        "expected success message, got {rendered:?}"
+    );
+
+    // An empty value reports an error without touching the clipboard.
+    chat.copy_statusline_text_with("git branch", "", |_| {
+        unreachable!("clipboard backend should not run for empty text")
+    });
+
+    let cells = drain_insert_history(&mut rx);
+    assert_eq!(cells.len(), 1, "expected one failure message");
+    let rendered = lines_to_single_string(&cells[0]);
+    assert!(
+        rendered.contains("No git branch to copy"),
+        "expected empty-value message, got {rendered:?}"
+    );
+}
+
 #[tokio::test]
 async fn slash_copy_state_is_preserved_during_running_task() {
     let (mut chat, _rx, _op_rx) = make_chatwidget_manual(/*model_override*/ None).await;
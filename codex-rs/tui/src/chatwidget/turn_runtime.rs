@@ -67,6 +67,13 @@ impl ChatWidget {
         }
         self.turn_runtime_metrics = RuntimeMetricsSummary::default();
         self.session_telemetry.reset_runtime_metrics();
+        // @cometix: 记录回合起点的已用 token，供 Context segment 计算本轮增量
+        self.cxline_turn_start_tokens = Some(
+            self.token_info
+                .as_ref()
+                .map(|info| info.last_token_usage.tokens_in_context_window())
+                .unwrap_or(0),
+        );
         self.bottom_pane.clear_quit_shortcut_hint();
         self.quit_shortcut_expires_at = None;
         self.quit_shortcut_key = None;
@@ -82,6 +89,8 @@ impl ChatWidget {
         }
         self.full_reasoning_buffer.clear();
         self.reasoning_buffer.clear();
+        // @cometix: reset the per-turn translation counters for the optional summary cell
+        self.reasoning_translator.begin_turn(self.thread_id);
         self.set_ambient_pet_notification(
             crate::pets::PetNotificationKind::Running,
             /*body*/ None,
@@ -172,11 +181,16 @@ impl ChatWidget {
             self.request_status_line_branch_refresh();
             self.request_status_line_git_summary_refresh();
         }
+        // @cometix: emit the optional translation turn summary now that the
+        // turn's content is in history (it defers behind an active barrier)
+        self.reasoning_translator.finish_turn(&self.app_event_tx);
         // Mark task stopped and request redraw now that all content is in history.
         self.status_state.pending_status_indicator_restore = false;
         self.input_queue.user_turn_pending_start = false;
         self.clear_active_hook_cell();
         self.turn_lifecycle.finish();
+        // @cometix: 回合结束，隐藏本轮 token 增量
+        self.cxline_turn_start_tokens = None;
         self.clear_safety_buffering();
         self.update_task_running_state();
         self.running_commands.clear();
@@ -322,6 +336,8 @@ impl ChatWidget {
         // Reset running state and clear streaming buffers.
         self.input_queue.user_turn_pending_start = false;
         self.turn_lifecycle.finish();
+        // @cometix: 回合结束，隐藏本轮 token 增量
+        self.cxline_turn_start_tokens = None;
         self.update_task_running_state();
         self.running_commands.clear();
         self.suppressed_exec_calls.clear();
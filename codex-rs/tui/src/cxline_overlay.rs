@@ -2,7 +2,10 @@
 // 在主 TUI 的 Overlay 层中运行，不创建独立的 Terminal
 // 参考 CCometixLine 的 UI 设计
 
+use std::collections::VecDeque;
 use std::io::Result;
+use std::time::Duration;
+use std::time::Instant;
 
 use crossterm::event::KeyCode;
 use crossterm::event::KeyEvent;
@@ -29,10 +32,19 @@ use crate::statusline::ColorPicker;
 use crate::statusline::ColorTarget;
 use crate::statusline::IconSelector;
 use crate::statusline::NameInputDialog;
+use crate::statusline::OptionsEditor;
 use crate::statusline::SeparatorEditor;
 use crate::statusline::StatusLineContext;
+use crate::statusline::StatusLinePreviewData;
+use crate::statusline::ThresholdEditor;
 use crate::statusline::config::CxLineConfig;
+use crate::statusline::config::ThemeQuickSelect;
+use crate::statusline::options_editor::OptionKind;
+use crate::statusline::options_editor::option_specs;
 use crate::statusline::segment::SegmentId;
+use crate::statusline::segments::usage::default_icon_thresholds;
+use crate::statusline::segments::usage::icon_thresholds_to_value;
+use crate::statusline::segments::usage::parse_icon_thresholds;
 use crate::statusline::style::AnsiColor;
 use crate::statusline::style::StyleMode;
 use crate::statusline::themes::THEME_NAMES;
@@ -47,7 +59,7 @@ enum Panel {
 }
 
 /// Settings 面板中的字段
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 enum FieldSelection {
     Enabled,
     Icon,
@@ -58,7 +70,218 @@ enum FieldSelection {
     Options,
 }
 
-const FIELD_COUNT: usize = 7;
+/// Settings 面板字段描述符
+/// 新字段只需在 SETTINGS_FIELDS 中注册一处（顺序即显示顺序）
+struct FieldDescriptor {
+    field: FieldSelection,
+    /// 选中该字段时显示在面板底部的一行帮助
+    help: &'static str,
+}
+
+const SETTINGS_FIELDS: &[FieldDescriptor] = &[
+    FieldDescriptor {
+        field: FieldSelection::Enabled,
+        help: "Show or hide this segment in the status line",
+    },
+    FieldDescriptor {
+        field: FieldSelection::Icon,
+        help: "Glyph shown before the segment text (separate per style mode)",
+    },
+    FieldDescriptor {
+        field: FieldSelection::IconColor,
+        help: "Foreground color of the icon glyph",
+    },
+    FieldDescriptor {
+        field: FieldSelection::TextColor,
+        help: "Foreground color of the segment text",
+    },
+    FieldDescriptor {
+        field: FieldSelection::BackgroundColor,
+        help: "Plain/NerdFont: painted behind the text; Powerline: also drives the arrow transitions",
+    },
+    FieldDescriptor {
+        field: FieldSelection::TextStyle,
+        help: "Render the segment text in bold",
+    },
+    FieldDescriptor {
+        field: FieldSelection::Options,
+        help: "Segment-specific options (Enter opens the editor)",
+    },
+];
+
+/// Undo 栈最大深度
+const UNDO_STACK_LIMIT: usize = 50;
+
+/// 状态消息日志容量
+const STATUS_LOG_CAPACITY: usize = 20;
+/// 普通消息的显示时长
+const STATUS_INFO_TTL: Duration = Duration::from_secs(4);
+/// 错误消息显示更久
+const STATUS_ERROR_TTL: Duration = Duration::from_secs(10);
+
+/// 模拟预览宽度的调整步长与边界
+const PREVIEW_WIDTH_STEP: u16 = 10;
+const PREVIEW_WIDTH_MIN: u16 = 20;
+const PREVIEW_WIDTH_MAX: u16 = 300;
+
+/// 状态消息级别
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum StatusLevel {
+    Info,
+    Error,
+}
+
+impl StatusLevel {
+    fn ttl(self) -> Duration {
+        match self {
+            Self::Info => STATUS_INFO_TTL,
+            Self::Error => STATUS_ERROR_TTL,
+        }
+    }
+}
+
+/// 一条带时间戳的状态消息
+#[derive(Debug, Clone)]
+struct StatusEntry {
+    text: String,
+    level: StatusLevel,
+    at: Instant,
+}
+
+/// 退出确认对话框中的选项
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ExitChoice {
+    Save,
+    Discard,
+    Cancel,
+}
+
+const EXIT_CHOICES: &[ExitChoice] = &[ExitChoice::Save, ExitChoice::Discard, ExitChoice::Cancel];
+
+/// 按上下文分组的快捷键表
+/// 底部帮助条与 `?` 速查表共用这一份数据，避免两处漂移
+struct KeyBindingGroup {
+    context: &'static str,
+    bindings: &'static [(&'static str, &'static str)],
+}
+
+const KEY_BINDING_GROUPS: &[KeyBindingGroup] = &[
+    KeyBindingGroup {
+        context: "Main",
+        bindings: &[
+            ("[Tab]", "Switch Panel"),
+            ("[↑↓]", "Select"),
+            ("[Shift+↑↓]", "Reorder"),
+            ("[Enter]", "Toggle/Edit"),
+            // 实际键位由 theme_quick_select 配置决定，渲染时替换
+            ("[Alt+1-9]", "Theme"),
+            ("[P]", "Cycle Theme"),
+            ("[R]", "Reset Theme"),
+            ("[Z]", "Reset Segment"),
+            ("[u]", "Undo"),
+            ("[Ctrl+R/U]", "Redo"),
+            ("[D]", "Live/Demo Data"),
+            ("[E]", "Edit Separator"),
+            ("[W]", "Write Theme"),
+            ("[Ctrl+S]", "Save Theme"),
+            ("[S]", "Save Config"),
+            ("[[/]]", "Preview Width"),
+            ("[\\]", "Reset Width"),
+            ("[M]", "Recent Messages"),
+            ("[?]", "Cheat Sheet"),
+            ("[Esc]", "Quit"),
+        ],
+    },
+    KeyBindingGroup {
+        context: "Color Picker",
+        bindings: &[
+            ("[Tab]", "Cycle mode (16 / 256 / RGB / Recent / Palette)"),
+            ("[↑↓←→]", "Navigate colors"),
+            ("[0-9a-f]", "Type color value / hex"),
+            ("[Backspace]", "Delete input"),
+            ("[Enter]", "Apply color"),
+            ("[Esc]", "Cancel"),
+        ],
+    },
+    KeyBindingGroup {
+        context: "Icon Selector",
+        bindings: &[
+            ("[Tab]", "Toggle plain / nerd-font style"),
+            ("[←→]", "Cycle category"),
+            ("[/]", "Filter by name"),
+            ("[C]", "Custom icon input"),
+            ("[PgUp/PgDn]", "Page through icons"),
+            ("[Enter]", "Apply icon"),
+            ("[Esc]", "Close"),
+        ],
+    },
+    KeyBindingGroup {
+        context: "Separator Editor",
+        bindings: &[
+            ("[Tab]", "Next slot (left cap / inner / right cap)"),
+            ("[↑↓]", "Select preset"),
+            ("[Del]", "Clear slot"),
+            ("[Enter]", "Confirm"),
+            ("[Esc]", "Cancel"),
+        ],
+    },
+    KeyBindingGroup {
+        context: "Name Input",
+        bindings: &[
+            ("[Enter]", "Confirm"),
+            ("[Backspace]", "Delete"),
+            ("[Esc]", "Cancel"),
+        ],
+    },
+    KeyBindingGroup {
+        context: "Options Editor",
+        bindings: &[
+            ("[↑↓]", "Select option"),
+            ("[Enter/Space]", "Toggle / edit"),
+            ("[←→]", "Cycle enum value"),
+            ("[Esc]", "Close"),
+        ],
+    },
+    KeyBindingGroup {
+        context: "Dynamic Icons",
+        bindings: &[
+            ("[↑↓]", "Select row"),
+            ("[Enter]", "Edit icon"),
+            ("[P]", "Edit max percent"),
+            ("[A]", "Add row"),
+            ("[D/Del]", "Delete row"),
+            ("[Esc]", "Close"),
+        ],
+    },
+];
+
+/// 一次可撤销的配置快照（含 segment_order，因其是 CxLineConfig 的一部分）
+#[derive(Debug, Clone)]
+struct UndoEntry {
+    config: CxLineConfig,
+    /// 触发快照的操作描述（撤销时显示在状态行）
+    action: &'static str,
+}
+
+/// NameInputDialog 当前服务的用途
+#[derive(Debug, Clone, PartialEq)]
+enum NameInputPurpose {
+    /// 另存为新主题
+    SaveTheme,
+    /// 编辑字符串/数字类型的 segment option
+    OptionValue { key: &'static str, kind: OptionKind },
+    /// 编辑阈值表选中行的 max_percent
+    ThresholdPercent,
+}
+
+/// 图标选择器当前服务的目标
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum IconTarget {
+    /// 选中 segment 的图标字段
+    SegmentIcon,
+    /// 阈值表编辑器中选中的行
+    ThresholdRow,
+}
 
 /// CxLine 配置 Overlay
 pub(crate) struct CxlineOverlay {
@@ -67,48 +290,91 @@ pub(crate) struct CxlineOverlay {
     original_config: CxLineConfig,
     /// 进入时的主题名称（用于判断主题是否变化）
     original_theme: String,
-    /// Segment 显示顺序
-    segment_order: Vec<SegmentId>,
     selected_segment: usize,
     selected_panel: Panel,
     selected_field: FieldSelection,
     is_done: bool,
-    status_message: Option<String>,
+    /// 状态消息环形日志（最新的在尾部）
+    status_log: VecDeque<StatusEntry>,
     // 对话框组件
     color_picker: ColorPicker,
     icon_selector: IconSelector,
     separator_editor: SeparatorEditor,
     name_input_dialog: NameInputDialog,
+    name_input_purpose: NameInputPurpose,
+    options_editor: OptionsEditor,
+    threshold_editor: ThresholdEditor,
+    /// 图标选择器应用到哪个目标
+    icon_target: IconTarget,
+    // 撤销/重做（保存配置不清空）
+    undo_stack: Vec<UndoEntry>,
+    redo_stack: Vec<UndoEntry>,
+    // 退出确认对话框
+    confirm_exit_open: bool,
+    confirm_exit_selected: usize,
+    /// 进入 overlay 时的实时会话数据快照
+    live_data: Option<StatusLinePreviewData>,
+    /// 预览使用实时数据还是合成演示数据（d 键切换）
+    use_live_data: bool,
+    /// 正在预览（但尚未提交）的主题名称
+    preview_theme: Option<String>,
+    /// leader 模式下已按过 t，等待数字键
+    theme_leader_pending: bool,
+    /// 模拟的预览宽度（None = 实际宽度）
+    preview_width: Option<u16>,
+    /// 最近一次渲染时预览区的实际宽度
+    preview_area_width: u16,
+    /// Settings 面板滚动偏移（字段行）
+    settings_scroll: usize,
+    // 快捷键速查表
+    cheat_sheet_open: bool,
+    cheat_sheet_scroll: usize,
+    /// 最近消息列表对话框
+    message_log_open: bool,
 }
 
 impl CxlineOverlay {
-    pub fn new(config: CxLineConfig) -> Self {
+    pub fn new(config: CxLineConfig, live_data: Option<StatusLinePreviewData>) -> Self {
         let original_theme = config.theme.clone();
         let original_config = config.clone();
+        let use_live_data = live_data.is_some();
         Self {
             config,
             original_config,
             original_theme,
-            segment_order: vec![
-                SegmentId::Model,
-                SegmentId::Directory,
-                SegmentId::Git,
-                SegmentId::Context,
-                SegmentId::Usage,
-            ],
             selected_segment: 0,
             selected_panel: Panel::SegmentList,
             selected_field: FieldSelection::Enabled,
             is_done: false,
-            status_message: None,
+            status_log: VecDeque::new(),
             color_picker: ColorPicker::default(),
             icon_selector: IconSelector::default(),
             separator_editor: SeparatorEditor::default(),
             name_input_dialog: NameInputDialog::default(),
+            name_input_purpose: NameInputPurpose::SaveTheme,
+            options_editor: OptionsEditor::default(),
+            threshold_editor: ThresholdEditor::default(),
+            icon_target: IconTarget::SegmentIcon,
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
+            confirm_exit_open: false,
+            confirm_exit_selected: 0,
+            live_data,
+            use_live_data,
+            preview_theme: None,
+            theme_leader_pending: false,
+            preview_width: None,
+            preview_area_width: 0,
+            settings_scroll: 0,
+            cheat_sheet_open: false,
+            cheat_sheet_scroll: 0,
+            message_log_open: false,
         }
     }
 
-    /// 获取最终配置（只包含主题切换，如果主题真的变化了）
+    /// 获取最终配置
+    /// 基于 original_config（保存时同步），因此已保存的修改会传播到实时状态栏；
+    /// 未保存的修改只在主题切换时生效，其余在退出确认对话框中显式丢弃
     pub fn config(&self) -> CxLineConfig {
         // 只有主题变化时才返回新配置，否则返回原始配置
         if self.config.theme != self.original_theme {
@@ -121,6 +387,12 @@ impl CxlineOverlay {
         }
     }
 
+    /// 编辑中的工作配置（含未保存的修改），用于主界面状态栏的实时预览
+    /// 取消退出时宿主会用 [`Self::config`] 的结果覆盖回去
+    pub fn working_config(&self) -> CxLineConfig {
+        self.config.clone()
+    }
+
     pub fn handle_event(&mut self, tui: &mut tui::Tui, event: TuiEvent) -> Result<()> {
         match event {
             TuiEvent::Key(key_event) => {
@@ -132,6 +404,10 @@ impl CxlineOverlay {
                 tui.draw(u16::MAX, |frame| {
                     self.render(frame.area(), frame.buffer_mut());
                 })?;
+                // 状态消息到期时重绘一帧，让它自动消失
+                if let Some(remaining) = self.status_expiry_in() {
+                    tui.frame_requester().schedule_frame_in(remaining);
+                }
                 Ok(())
             }
             _ => Ok(()),
@@ -144,6 +420,15 @@ impl CxlineOverlay {
         }
 
         // 优先处理对话框事件
+        if self.confirm_exit_open {
+            return self.handle_confirm_exit_key(key_event);
+        }
+        if self.cheat_sheet_open {
+            return self.handle_cheat_sheet_key(key_event);
+        }
+        if self.message_log_open {
+            return self.handle_message_log_key(key_event);
+        }
         if self.color_picker.is_open {
             return self.handle_color_picker_key(key_event);
         }
@@ -156,13 +441,38 @@ impl CxlineOverlay {
         if self.name_input_dialog.is_open {
             return self.handle_name_input_key(key_event);
         }
+        if self.threshold_editor.is_open {
+            return self.handle_threshold_editor_key(key_event);
+        }
+        if self.options_editor.is_open {
+            return self.handle_options_editor_key(key_event);
+        }
+
+        if key_event.modifiers.contains(KeyModifiers::CONTROL) {
+            match key_event.code {
+                // Ctrl+S: 保存为新主题
+                KeyCode::Char('s') => {
+                    self.name_input_purpose = NameInputPurpose::SaveTheme;
+                    self.name_input_dialog
+                        .open("Save as New Theme", "Enter theme name:");
+                    return Ok(());
+                }
+                // Ctrl+R: 重做（R 不带修饰键仍为 Reset Theme）
+                KeyCode::Char('r') => {
+                    self.redo();
+                    return Ok(());
+                }
+                _ => {}
+            }
+        }
 
-        // Ctrl+S: 保存为新主题
-        if key_event.modifiers.contains(KeyModifiers::CONTROL)
-            && let KeyCode::Char('s') = key_event.code
+        // Alt+数字：主题快速选择（alt_digit 模式；裸数字留给字段编辑器）
+        if key_event.modifiers.contains(KeyModifiers::ALT)
+            && let KeyCode::Char(c @ '1'..='9') = key_event.code
         {
-            self.name_input_dialog
-                .open("Save as New Theme", "Enter theme name:");
+            if self.config.theme_quick_select == ThemeQuickSelect::AltDigit {
+                self.switch_to_theme((c as u8 - b'1') as usize);
+            }
             return Ok(());
         }
 
@@ -181,9 +491,39 @@ impl CxlineOverlay {
             }
         }
 
+        // 主题预览中：Enter 提交到工作配置，Esc 放弃预览
+        if self.preview_theme.is_some() {
+            match key_event.code {
+                KeyCode::Enter => {
+                    self.commit_previewed_theme();
+                    return Ok(());
+                }
+                KeyCode::Esc => {
+                    self.cancel_theme_preview();
+                    return Ok(());
+                }
+                _ => {}
+            }
+        }
+
+        // leader 模式：t 之后的下一个数字键选择主题，其他键取消并正常处理
+        if self.theme_leader_pending {
+            self.theme_leader_pending = false;
+            if let KeyCode::Char(c @ '1'..='9') = key_event.code {
+                self.switch_to_theme((c as u8 - b'1') as usize);
+                return Ok(());
+            }
+        }
+
         match key_event.code {
             KeyCode::Esc | KeyCode::Char('q') => {
-                self.is_done = true;
+                if self.is_dirty() {
+                    // 未保存的修改会被丢弃，先确认
+                    self.confirm_exit_open = true;
+                    self.confirm_exit_selected = 0;
+                } else {
+                    self.is_done = true;
+                }
             }
             KeyCode::Up | KeyCode::Char('k') => self.move_selection(-1),
             KeyCode::Down | KeyCode::Char('j') => self.move_selection(1),
@@ -193,18 +533,38 @@ impl CxlineOverlay {
             KeyCode::Right | KeyCode::Char('l') => self.adjust_current(1),
             KeyCode::Char('p') | KeyCode::Char('P') => self.cycle_theme(),
             KeyCode::Char('r') | KeyCode::Char('R') => self.reset_theme(),
+            KeyCode::Char('z') | KeyCode::Char('Z') => self.reset_selected_segment(),
+            KeyCode::Char('[') => {
+                self.adjust_preview_width(-(PREVIEW_WIDTH_STEP as i32));
+            }
+            KeyCode::Char(']') => {
+                self.adjust_preview_width(PREVIEW_WIDTH_STEP as i32);
+            }
+            KeyCode::Char('\\') => {
+                self.preview_width = None;
+                self.status_info("Preview @ actual width");
+            }
+            KeyCode::Char('m') | KeyCode::Char('M') => {
+                self.message_log_open = true;
+            }
+            KeyCode::Char('?') => {
+                self.cheat_sheet_open = true;
+                self.cheat_sheet_scroll = 0;
+            }
+            KeyCode::Char('u') => self.undo(),
+            KeyCode::Char('U') => self.redo(),
+            KeyCode::Char('d') | KeyCode::Char('D') => self.toggle_preview_data(),
             KeyCode::Char('w') | KeyCode::Char('W') => self.write_to_current_theme(),
-            KeyCode::Char('s') | KeyCode::Char('S') => self.save_config(),
+            KeyCode::Char('s') | KeyCode::Char('S') => {
+                self.save_config();
+            }
             KeyCode::Char('e') | KeyCode::Char('E') => self.open_separator_editor(),
-            KeyCode::Char('1') => self.switch_to_theme(0),
-            KeyCode::Char('2') => self.switch_to_theme(1),
-            KeyCode::Char('3') => self.switch_to_theme(2),
-            KeyCode::Char('4') => self.switch_to_theme(3),
-            KeyCode::Char('5') => self.switch_to_theme(4),
-            KeyCode::Char('6') => self.switch_to_theme(5),
-            KeyCode::Char('7') => self.switch_to_theme(6),
-            KeyCode::Char('8') => self.switch_to_theme(7),
-            KeyCode::Char('9') => self.switch_to_theme(8),
+            KeyCode::Char('t') | KeyCode::Char('T')
+                if self.config.theme_quick_select == ThemeQuickSelect::Leader =>
+            {
+                self.theme_leader_pending = true;
+                self.status_info("Theme select: press 1-9");
+            }
             _ => {}
         }
         Ok(())
@@ -269,6 +629,34 @@ impl CxlineOverlay {
                 }
                 _ => {}
             }
+        } else if self.icon_selector.filtering {
+            match key_event.code {
+                KeyCode::Esc => {
+                    self.icon_selector.toggle_filter();
+                }
+                KeyCode::Enter => {
+                    if let Some(icon) = self.icon_selector.get_selected_icon() {
+                        self.apply_icon(icon);
+                    }
+                    self.icon_selector.close();
+                }
+                KeyCode::Up => {
+                    self.icon_selector.move_selection(-1);
+                }
+                KeyCode::Down => {
+                    self.icon_selector.move_selection(1);
+                }
+                KeyCode::Backspace => {
+                    self.icon_selector.backspace();
+                }
+                KeyCode::Char('/') => {
+                    self.icon_selector.toggle_filter();
+                }
+                KeyCode::Char(c) => {
+                    self.icon_selector.input_char(c);
+                }
+                _ => {}
+            }
         } else {
             match key_event.code {
                 KeyCode::Esc => {
@@ -289,6 +677,21 @@ impl CxlineOverlay {
                 KeyCode::Down | KeyCode::Char('j') => {
                     self.icon_selector.move_selection(1);
                 }
+                KeyCode::Left | KeyCode::Char('h') => {
+                    self.icon_selector.cycle_category(-1);
+                }
+                KeyCode::Right | KeyCode::Char('l') => {
+                    self.icon_selector.cycle_category(1);
+                }
+                KeyCode::PageUp => {
+                    self.icon_selector.page(-1);
+                }
+                KeyCode::PageDown => {
+                    self.icon_selector.page(1);
+                }
+                KeyCode::Char('/') => {
+                    self.icon_selector.toggle_filter();
+                }
                 KeyCode::Char('c') | KeyCode::Char('C') => {
                     self.icon_selector.start_custom_input();
                 }
@@ -304,12 +707,20 @@ impl CxlineOverlay {
                 self.separator_editor.close();
             }
             KeyCode::Enter => {
-                let separator = self.separator_editor.get_separator();
-                self.config.separator = separator;
-                self.status_message = Some("Separator updated".to_string());
+                let separators = self.separator_editor.get_separators();
+                self.push_undo("separator change");
+                // 保持旧的单字段同步，供尚未迁移的读取方使用
+                if let Some(inner) = separators.inner.clone() {
+                    self.config.separator = inner;
+                }
+                self.config.separators = separators;
+                self.status_info("Separators updated");
                 self.separator_editor.close();
             }
             KeyCode::Tab => {
+                self.separator_editor.cycle_slot();
+            }
+            KeyCode::Delete => {
                 self.separator_editor.clear_input();
             }
             KeyCode::Up | KeyCode::Char('k') => {
@@ -335,9 +746,19 @@ impl CxlineOverlay {
                 self.name_input_dialog.close();
             }
             KeyCode::Enter => {
-                let name = self.name_input_dialog.get_input().to_string();
-                if !name.is_empty() {
-                    self.save_as_new_theme(&name);
+                let input = self.name_input_dialog.get_input().to_string();
+                if !input.is_empty() {
+                    match self.name_input_purpose.clone() {
+                        NameInputPurpose::SaveTheme => {
+                            self.save_as_new_theme(&input);
+                        }
+                        NameInputPurpose::OptionValue { key, kind } => {
+                            self.apply_option_input(key, &kind, &input);
+                        }
+                        NameInputPurpose::ThresholdPercent => {
+                            self.apply_threshold_percent(&input);
+                        }
+                    }
                 }
                 self.name_input_dialog.close();
             }
@@ -352,16 +773,312 @@ impl CxlineOverlay {
         Ok(())
     }
 
+    fn handle_options_editor_key(&mut self, key_event: KeyEvent) -> Result<()> {
+        match key_event.code {
+            KeyCode::Esc | KeyCode::Char('q') => {
+                self.options_editor.close();
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.options_editor.move_selection(-1);
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.options_editor.move_selection(1);
+            }
+            KeyCode::Enter | KeyCode::Char(' ') => {
+                self.edit_selected_option(1);
+            }
+            KeyCode::Left | KeyCode::Char('h') => {
+                self.edit_selected_option(-1);
+            }
+            KeyCode::Right | KeyCode::Char('l') => {
+                self.edit_selected_option(1);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_threshold_editor_key(&mut self, key_event: KeyEvent) -> Result<()> {
+        match key_event.code {
+            KeyCode::Esc | KeyCode::Char('q') => {
+                self.threshold_editor.close();
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.threshold_editor.move_selection(-1);
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.threshold_editor.move_selection(1);
+            }
+            KeyCode::Enter => {
+                // 用图标选择器编辑选中行的图标
+                let id = self.threshold_editor_segment_id();
+                let style = self.config.style;
+                let colors = self.config.get_segment_config(id).colors.clone();
+                self.icon_target = IconTarget::ThresholdRow;
+                self.icon_selector
+                    .open(style, Self::segment_name(id), &colors);
+            }
+            KeyCode::Char('p') | KeyCode::Char('P') => {
+                self.name_input_purpose = NameInputPurpose::ThresholdPercent;
+                self.name_input_dialog
+                    .open("Edit Threshold", "Enter max percent (0-100):");
+            }
+            KeyCode::Char('a') | KeyCode::Char('A') => {
+                if self.threshold_editor.add_row() {
+                    self.commit_thresholds();
+                } else {
+                    self.status_error("No room between adjacent thresholds");
+                }
+            }
+            KeyCode::Char('d') | KeyCode::Char('D') | KeyCode::Delete => {
+                if self.threshold_editor.remove_selected() {
+                    self.commit_thresholds();
+                } else {
+                    self.status_error("At least one threshold row is required");
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// 阈值表编辑器编辑的 segment（从 options 编辑器打开，目前只有 Usage）
+    fn threshold_editor_segment_id(&self) -> SegmentId {
+        self.options_editor.segment_id
+    }
+
+    /// 把编辑器中的阈值表写回 options（预览行随之更新）
+    fn commit_thresholds(&mut self) {
+        self.push_undo("threshold change");
+        let id = self.threshold_editor_segment_id();
+        let value = icon_thresholds_to_value(&self.threshold_editor.rows);
+        self.config
+            .get_segment_config_mut(id)
+            .options
+            .insert("icon_thresholds".to_string(), value);
+    }
+
+    fn handle_confirm_exit_key(&mut self, key_event: KeyEvent) -> Result<()> {
+        match key_event.code {
+            KeyCode::Esc | KeyCode::Char('c') | KeyCode::Char('C') => {
+                self.confirm_exit_open = false;
+            }
+            KeyCode::Left | KeyCode::Char('h') => {
+                self.confirm_exit_selected = self.confirm_exit_selected.saturating_sub(1);
+            }
+            KeyCode::Right | KeyCode::Char('l') | KeyCode::Tab => {
+                self.confirm_exit_selected =
+                    (self.confirm_exit_selected + 1).min(EXIT_CHOICES.len() - 1);
+            }
+            KeyCode::Char('s') | KeyCode::Char('S') => {
+                self.exit_with_choice(ExitChoice::Save);
+            }
+            KeyCode::Char('d') | KeyCode::Char('D') => {
+                self.exit_with_choice(ExitChoice::Discard);
+            }
+            KeyCode::Enter => {
+                self.exit_with_choice(EXIT_CHOICES[self.confirm_exit_selected]);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_cheat_sheet_key(&mut self, key_event: KeyEvent) -> Result<()> {
+        match key_event.code {
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('?') => {
+                self.cheat_sheet_open = false;
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.cheat_sheet_scroll = self.cheat_sheet_scroll.saturating_sub(1);
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.cheat_sheet_scroll += 1;
+            }
+            KeyCode::PageUp => {
+                self.cheat_sheet_scroll = self.cheat_sheet_scroll.saturating_sub(10);
+            }
+            KeyCode::PageDown => {
+                self.cheat_sheet_scroll += 10;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_message_log_key(&mut self, key_event: KeyEvent) -> Result<()> {
+        match key_event.code {
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('m') | KeyCode::Char('M') => {
+                self.message_log_open = false;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// 按步长调整模拟预览宽度（从当前模拟值或实际宽度出发）
+    fn adjust_preview_width(&mut self, delta: i32) {
+        let base = self
+            .preview_width
+            .unwrap_or(if self.preview_area_width > 0 {
+                self.preview_area_width
+            } else {
+                PREVIEW_WIDTH_MIN + PREVIEW_WIDTH_STEP
+            });
+        let new_width =
+            (base as i32 + delta).clamp(PREVIEW_WIDTH_MIN as i32, PREVIEW_WIDTH_MAX as i32) as u16;
+        self.preview_width = Some(new_width);
+        self.status_info(format!("Preview @ {new_width} cols"));
+    }
+
+    fn status_info(&mut self, text: impl Into<String>) {
+        self.push_status(StatusLevel::Info, text.into());
+    }
+
+    fn status_error(&mut self, text: impl Into<String>) {
+        self.push_status(StatusLevel::Error, text.into());
+    }
+
+    fn push_status(&mut self, level: StatusLevel, text: String) {
+        self.status_log.push_back(StatusEntry {
+            text,
+            level,
+            at: Instant::now(),
+        });
+        while self.status_log.len() > STATUS_LOG_CAPACITY {
+            self.status_log.pop_front();
+        }
+    }
+
+    /// 当前应显示的状态消息（超过 TTL 后自动淡出）
+    fn current_status(&self) -> Option<&StatusEntry> {
+        self.status_log
+            .back()
+            .filter(|entry| entry.at.elapsed() < entry.level.ttl())
+    }
+
+    /// 距当前状态消息淡出还剩的时间（用于安排过期重绘帧）
+    fn status_expiry_in(&self) -> Option<Duration> {
+        self.current_status()
+            .map(|entry| entry.level.ttl().saturating_sub(entry.at.elapsed()))
+    }
+
+    fn exit_with_choice(&mut self, choice: ExitChoice) {
+        self.confirm_exit_open = false;
+        match choice {
+            ExitChoice::Save => {
+                // 保存失败时留在 overlay 中（状态行已有错误信息）
+                if self.save_config() {
+                    self.is_done = true;
+                }
+            }
+            ExitChoice::Discard => {
+                self.is_done = true;
+            }
+            ExitChoice::Cancel => {}
+        }
+    }
+
+    /// 是否有未保存的修改（含 segment 顺序）
+    fn is_dirty(&self) -> bool {
+        self.config != self.original_config
+    }
+
+    /// 按类型编辑当前选中的 option
+    fn edit_selected_option(&mut self, delta: i32) {
+        let Some(spec) = self.options_editor.selected_spec() else {
+            return;
+        };
+        let id = self.options_editor.segment_id;
+
+        match &spec.kind {
+            OptionKind::Bool => {
+                self.push_undo("option change");
+                let segment_config = self.config.get_segment_config_mut(id);
+                let new_value = OptionsEditor::toggle_bool(segment_config, spec.key);
+                self.status_info(format!(
+                    "{} {}",
+                    spec.key,
+                    if new_value { "enabled" } else { "disabled" }
+                ));
+            }
+            OptionKind::Enum(allowed) => {
+                self.push_undo("option change");
+                let segment_config = self.config.get_segment_config_mut(id);
+                let new_value = OptionsEditor::cycle_enum(segment_config, spec.key, allowed, delta);
+                self.status_info(format!("{} = {new_value}", spec.key));
+            }
+            OptionKind::Table => {
+                let rows = self
+                    .config
+                    .get_segment_config(id)
+                    .options
+                    .get(spec.key)
+                    .and_then(parse_icon_thresholds)
+                    .unwrap_or_else(default_icon_thresholds);
+                self.threshold_editor.open(rows);
+            }
+            OptionKind::String | OptionKind::Number => {
+                self.name_input_purpose = NameInputPurpose::OptionValue {
+                    key: spec.key,
+                    kind: spec.kind.clone(),
+                };
+                self.name_input_dialog
+                    .open("Edit Option", &format!("Enter value for {}:", spec.key));
+            }
+        }
+    }
+
+    /// 将输入框中的值写入 option（带类型校验）
+    fn apply_option_input(&mut self, key: &'static str, kind: &OptionKind, input: &str) {
+        let id = self.options_editor.segment_id;
+        let value = match kind {
+            OptionKind::Number => match input.parse::<f64>() {
+                Ok(n) => match serde_json::Number::from_f64(n) {
+                    Some(n) => serde_json::Value::Number(n),
+                    None => {
+                        self.status_error(format!("Invalid number: {input}"));
+                        return;
+                    }
+                },
+                Err(_) => {
+                    self.status_error(format!("Invalid number: {input}"));
+                    return;
+                }
+            },
+            _ => serde_json::Value::String(input.to_string()),
+        };
+
+        self.push_undo("option change");
+        let segment_config = self.config.get_segment_config_mut(id);
+        segment_config.options.insert(key.to_string(), value);
+        self.status_info(format!("{key} = {input}"));
+    }
+
+    /// 校验并应用阈值输入
+    fn apply_threshold_percent(&mut self, input: &str) {
+        match input.parse::<u8>() {
+            Ok(percent) if percent <= 100 => {
+                self.threshold_editor.set_selected_percent(percent);
+                self.commit_thresholds();
+                self.status_info(format!("Threshold set to {percent}%"));
+            }
+            _ => {
+                self.status_error(format!("Invalid percent: {input}"));
+            }
+        }
+    }
+
     fn write_to_current_theme(&mut self) {
         use crate::statusline::themes::ThemePresets;
 
         let current_theme = self.config.theme.clone();
         match ThemePresets::save_theme(&current_theme, &self.config) {
             Ok(_) => {
-                self.status_message = Some(format!("Wrote config to theme: {current_theme}"));
+                self.status_info(format!("Wrote config to theme: {current_theme}"));
             }
             Err(e) => {
-                self.status_message = Some(format!("Failed to write theme: {e}"));
+                self.status_error(format!("Failed to write theme: {e}"));
             }
         }
     }
@@ -375,35 +1092,49 @@ impl CxlineOverlay {
         match ThemePresets::save_theme(theme_name, &new_config) {
             Ok(_) => {
                 self.config.theme = theme_name.to_string();
-                self.status_message = Some(format!("Saved as new theme: {theme_name}"));
+                self.status_info(format!("Saved as new theme: {theme_name}"));
             }
             Err(e) => {
-                self.status_message = Some(format!("Failed to save theme: {e}"));
+                self.status_error(format!("Failed to save theme: {e}"));
             }
         }
     }
 
     fn apply_color(&mut self, color: AnsiColor) {
+        self.push_undo("color change");
+        // 记录到 recent 颜色行
+        self.color_picker.remember_color(color);
+
         let id = self.segment_id_at(self.selected_segment);
         let segment_config = self.config.get_segment_config_mut(id);
 
         match self.color_picker.target_field {
             ColorTarget::IconColor => {
                 segment_config.colors.icon = Some(color);
-                self.status_message = Some("Icon color updated".to_string());
+                self.status_info("Icon color updated");
             }
             ColorTarget::TextColor => {
                 segment_config.colors.text = Some(color);
-                self.status_message = Some("Text color updated".to_string());
+                self.status_info("Text color updated");
             }
             ColorTarget::BackgroundColor => {
                 segment_config.colors.background = Some(color);
-                self.status_message = Some("Background color updated".to_string());
+                self.status_info("Background color updated");
             }
         }
     }
 
     fn apply_icon(&mut self, icon: String) {
+        // 阈值表编辑中时图标落到选中的行
+        if self.icon_target == IconTarget::ThresholdRow {
+            self.icon_target = IconTarget::SegmentIcon;
+            self.threshold_editor.set_selected_icon(icon);
+            self.commit_thresholds();
+            self.status_info("Threshold icon updated");
+            return;
+        }
+
+        self.push_undo("icon change");
         let id = self.segment_id_at(self.selected_segment);
         let style = self.config.style;
         let segment_config = self.config.get_segment_config_mut(id);
@@ -416,11 +1147,29 @@ impl CxlineOverlay {
                 segment_config.icon.nerd_font = icon;
             }
         }
-        self.status_message = Some("Icon updated".to_string());
+        self.status_info("Icon updated");
+    }
+
+    /// 在实时会话数据与合成演示数据之间切换预览
+    fn toggle_preview_data(&mut self) {
+        if self.live_data.is_none() {
+            self.status_info("No live session data available");
+            return;
+        }
+        self.use_live_data = !self.use_live_data;
+        self.status_info(
+            if self.use_live_data {
+                "Preview: live session data"
+            } else {
+                "Preview: demo data"
+            }
+            .to_string(),
+        );
     }
 
     fn open_separator_editor(&mut self) {
-        self.separator_editor.open(&self.config.separator);
+        self.separator_editor
+            .open(&self.config.separators, &self.config.separator);
     }
 
     pub fn is_done(&self) -> bool {
@@ -428,11 +1177,12 @@ impl CxlineOverlay {
     }
 
     fn segment_count(&self) -> usize {
-        self.segment_order.len()
+        self.config.segment_order.len()
     }
 
     fn segment_id_at(&self, index: usize) -> SegmentId {
-        self.segment_order
+        self.config
+            .segment_order
             .get(index)
             .copied()
             .unwrap_or(SegmentId::Model)
@@ -445,6 +1195,7 @@ impl CxlineOverlay {
             SegmentId::Git => "Git",
             SegmentId::Context => "Context Window",
             SegmentId::Usage => "Usage",
+            SegmentId::Alert => "Alert",
         }
     }
 
@@ -459,35 +1210,19 @@ impl CxlineOverlay {
             }
             Panel::Settings => {
                 let current_field = self.field_index();
-                let new_field = (current_field as i32 + delta).clamp(0, FIELD_COUNT as i32 - 1);
-                self.selected_field = self.index_to_field(new_field as usize);
+                let new_field = (current_field as i32 + delta)
+                    .clamp(0, SETTINGS_FIELDS.len() as i32 - 1)
+                    as usize;
+                self.selected_field = SETTINGS_FIELDS[new_field].field;
             }
         }
     }
 
     fn field_index(&self) -> usize {
-        match self.selected_field {
-            FieldSelection::Enabled => 0,
-            FieldSelection::Icon => 1,
-            FieldSelection::IconColor => 2,
-            FieldSelection::TextColor => 3,
-            FieldSelection::BackgroundColor => 4,
-            FieldSelection::TextStyle => 5,
-            FieldSelection::Options => 6,
-        }
-    }
-
-    fn index_to_field(&self, index: usize) -> FieldSelection {
-        match index {
-            0 => FieldSelection::Enabled,
-            1 => FieldSelection::Icon,
-            2 => FieldSelection::IconColor,
-            3 => FieldSelection::TextColor,
-            4 => FieldSelection::BackgroundColor,
-            5 => FieldSelection::TextStyle,
-            6 => FieldSelection::Options,
-            _ => FieldSelection::Enabled,
-        }
+        SETTINGS_FIELDS
+            .iter()
+            .position(|d| d.field == self.selected_field)
+            .unwrap_or(0)
     }
 
     fn switch_panel(&mut self) {
@@ -499,10 +1234,12 @@ impl CxlineOverlay {
 
     fn move_segment_up(&mut self) {
         if self.selected_panel == Panel::SegmentList && self.selected_segment > 0 {
-            self.segment_order
+            self.push_undo("segment reorder");
+            self.config
+                .segment_order
                 .swap(self.selected_segment, self.selected_segment - 1);
             self.selected_segment -= 1;
-            self.status_message = Some("Segment moved up".to_string());
+            self.status_info("Segment moved up");
         }
     }
 
@@ -510,16 +1247,74 @@ impl CxlineOverlay {
         if self.selected_panel == Panel::SegmentList
             && self.selected_segment < self.segment_count() - 1
         {
-            self.segment_order
+            self.push_undo("segment reorder");
+            self.config
+                .segment_order
                 .swap(self.selected_segment, self.selected_segment + 1);
             self.selected_segment += 1;
-            self.status_message = Some("Segment moved down".to_string());
+            self.status_info("Segment moved down");
         }
     }
 
     fn reset_theme(&mut self) {
+        self.push_undo("reset theme");
+        // 清除 enabled 覆盖标记，使主题的 enablement 默认值完整恢复
+        self.config.clear_enabled_overrides();
         self.config.apply_theme(&self.original_theme);
-        self.status_message = Some(format!("Reset to: {}", self.original_theme));
+        self.status_info(format!("Reset to: {}", self.original_theme));
+    }
+
+    /// 只把选中 segment 恢复到当前主题的默认值（enabled 状态保留）
+    fn reset_selected_segment(&mut self) {
+        use crate::statusline::themes::ThemePresets;
+
+        let id = self.segment_id_at(self.selected_segment);
+        let name = Self::segment_name(id);
+        let theme_default = ThemePresets::theme_segment_config(&self.config.theme, id);
+
+        self.push_undo("segment reset");
+        let segment_config = self.config.get_segment_config_mut(id);
+        segment_config.icon = theme_default.icon;
+        segment_config.colors = theme_default.colors;
+        segment_config.styles = theme_default.styles;
+        segment_config.options = theme_default.options;
+        self.status_info(format!("{name} reset to theme defaults"));
+    }
+
+    /// 在每次修改配置前记录快照；新的修改会使 redo 栈失效
+    fn push_undo(&mut self, action: &'static str) {
+        self.undo_stack.push(UndoEntry {
+            config: self.config.clone(),
+            action,
+        });
+        if self.undo_stack.len() > UNDO_STACK_LIMIT {
+            self.undo_stack.remove(0);
+        }
+        self.redo_stack.clear();
+    }
+
+    fn undo(&mut self) {
+        let Some(entry) = self.undo_stack.pop() else {
+            self.status_info("Nothing to undo");
+            return;
+        };
+        self.redo_stack.push(UndoEntry {
+            config: std::mem::replace(&mut self.config, entry.config),
+            action: entry.action,
+        });
+        self.status_info(format!("Undid: {}", entry.action));
+    }
+
+    fn redo(&mut self) {
+        let Some(entry) = self.redo_stack.pop() else {
+            self.status_info("Nothing to redo");
+            return;
+        };
+        self.undo_stack.push(UndoEntry {
+            config: std::mem::replace(&mut self.config, entry.config),
+            action: entry.action,
+        });
+        self.status_info(format!("Redid: {}", entry.action));
     }
 
     fn toggle_current(&mut self) {
@@ -527,10 +1322,12 @@ impl CxlineOverlay {
             Panel::SegmentList => {
                 let id = self.segment_id_at(self.selected_segment);
                 let name = Self::segment_name(id);
+                self.push_undo("segment toggle");
                 let segment_config = self.config.get_segment_config_mut(id);
                 segment_config.enabled = !segment_config.enabled;
                 let enabled = segment_config.enabled;
-                self.status_message = Some(format!(
+                self.config.mark_enabled_override(id);
+                self.status_info(format!(
                     "{} {}",
                     name,
                     if enabled { "enabled" } else { "disabled" }
@@ -552,10 +1349,12 @@ impl CxlineOverlay {
 
         match self.selected_field {
             FieldSelection::Enabled => {
+                self.push_undo("segment toggle");
                 let segment_config = self.config.get_segment_config_mut(id);
                 segment_config.enabled = !segment_config.enabled;
                 let enabled = segment_config.enabled;
-                self.status_message = Some(format!(
+                self.config.mark_enabled_override(id);
+                self.status_info(format!(
                     "{} {}",
                     name,
                     if enabled { "enabled" } else { "disabled" }
@@ -563,66 +1362,99 @@ impl CxlineOverlay {
             }
             FieldSelection::Icon => {
                 let style = self.config.style;
-                self.icon_selector.open(style);
+                let colors = self.config.get_segment_config(id).colors.clone();
+                self.icon_target = IconTarget::SegmentIcon;
+                self.icon_selector.open(style, name, &colors);
             }
             FieldSelection::IconColor => {
-                let current_color = self.config.get_segment_config(id).colors.icon;
+                let colors = &self.config.get_segment_config(id).colors;
+                let (current_color, preview_bg) = (colors.icon, colors.background);
                 self.color_picker
-                    .open(ColorTarget::IconColor, current_color);
+                    .open(ColorTarget::IconColor, current_color, preview_bg);
             }
             FieldSelection::TextColor => {
-                let current_color = self.config.get_segment_config(id).colors.text;
+                let colors = &self.config.get_segment_config(id).colors;
+                let (current_color, preview_bg) = (colors.text, colors.background);
                 self.color_picker
-                    .open(ColorTarget::TextColor, current_color);
+                    .open(ColorTarget::TextColor, current_color, preview_bg);
             }
             FieldSelection::BackgroundColor => {
-                let current_color = self.config.get_segment_config(id).colors.background;
+                let colors = &self.config.get_segment_config(id).colors;
+                let (current_color, preview_bg) = (colors.background, colors.background);
                 self.color_picker
-                    .open(ColorTarget::BackgroundColor, current_color);
+                    .open(ColorTarget::BackgroundColor, current_color, preview_bg);
             }
             FieldSelection::TextStyle => {
+                self.push_undo("style change");
                 let segment_config = self.config.get_segment_config_mut(id);
                 segment_config.styles.text_bold = !segment_config.styles.text_bold;
                 let bold = segment_config.styles.text_bold;
-                self.status_message = Some(format!(
+                self.status_info(format!(
                     "{} bold {}",
                     name,
                     if bold { "enabled" } else { "disabled" }
                 ));
             }
             FieldSelection::Options => {
-                self.status_message = Some("Options editing not yet supported".to_string());
+                if option_specs(id).is_empty() {
+                    self.status_info(format!("{name} has no options"));
+                } else {
+                    self.options_editor.open(id);
+                }
             }
         }
     }
 
     fn cycle_theme(&mut self) {
-        let current_idx = THEME_NAMES
-            .iter()
-            .position(|&t| t == self.config.theme)
-            .unwrap_or(0);
+        let base = self
+            .preview_theme
+            .as_deref()
+            .unwrap_or(self.config.theme.as_str());
+        let current_idx = THEME_NAMES.iter().position(|&t| t == base).unwrap_or(0);
         let new_idx = (current_idx + 1) % THEME_NAMES.len();
-        let new_theme = THEME_NAMES[new_idx];
-        self.config.apply_theme(new_theme);
-        self.status_message = Some(format!("Theme: {new_theme}"));
+        self.preview_theme_at(new_idx);
     }
 
     fn switch_to_theme(&mut self, index: usize) {
         if index < THEME_NAMES.len() {
-            let theme_name = THEME_NAMES[index];
-            self.config.apply_theme(theme_name);
-            self.status_message = Some(format!("Theme: {theme_name}"));
+            self.preview_theme_at(index);
+        }
+    }
+
+    /// 第一阶段：只更新预览行，不动工作配置
+    fn preview_theme_at(&mut self, index: usize) {
+        let theme_name = THEME_NAMES[index];
+        self.preview_theme = Some(theme_name.to_string());
+        self.status_info(format!(
+            "Previewing: {theme_name} (Enter to apply, Esc to cancel)"
+        ));
+    }
+
+    /// 第二阶段：把预览中的主题提交进工作配置
+    fn commit_previewed_theme(&mut self) {
+        if let Some(theme_name) = self.preview_theme.take() {
+            self.push_undo("theme change");
+            self.config.apply_theme(&theme_name);
+            self.status_info(format!("Theme applied: {theme_name}"));
         }
     }
 
-    fn save_config(&mut self) {
+    fn cancel_theme_preview(&mut self) {
+        self.preview_theme = None;
+        self.status_info("Theme preview cancelled");
+    }
+
+    fn save_config(&mut self) -> bool {
         if let Err(e) = self.config.save() {
-            self.status_message = Some(format!("Failed to save: {e}"));
+            self.status_error(format!("Failed to save: {e}"));
+            false
         } else {
-            // 保存成功后更新原始配置，这样 ESC 退出时不会重置
+            // 保存成功后更新原始配置，这样 ESC 退出时不会重置；
+            // config() 基于 original_config，保存过的修改因此能传播到实时状态栏
             self.original_config = self.config.clone();
             self.original_theme = self.config.theme.clone();
-            self.status_message = Some("Configuration saved!".to_string());
+            self.status_info("Configuration saved!");
+            true
         }
     }
 
@@ -671,7 +1503,184 @@ impl CxlineOverlay {
         self.color_picker.render(area, buf);
         self.icon_selector.render(area, buf);
         self.separator_editor.render(area, buf);
+        if self.options_editor.is_open {
+            let segment_config = self
+                .config
+                .get_segment_config(self.options_editor.segment_id);
+            self.options_editor.render(area, buf, segment_config);
+        }
+        self.threshold_editor.render(area, buf);
         self.name_input_dialog.render(area, buf);
+        self.render_confirm_exit(area, buf);
+        self.render_cheat_sheet(area, buf);
+        self.render_message_log(area, buf);
+    }
+
+    fn render_message_log(&self, area: Rect, buf: &mut Buffer) {
+        use crate::statusline::color_picker::centered_rect;
+
+        if !self.message_log_open {
+            return;
+        }
+
+        let popup_area = centered_rect(60, 60, area);
+        ratatui::widgets::Clear.render(popup_area, buf);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title("Recent Messages");
+        let inner = block.inner(popup_area);
+        block.render(popup_area, buf);
+
+        if inner.height < 2 {
+            return;
+        }
+
+        if self.status_log.is_empty() {
+            buf.set_string(
+                inner.x,
+                inner.y,
+                "No messages yet",
+                Style::default().fg(Color::DarkGray),
+            );
+        }
+
+        // 最新的在最上面
+        let visible_rows = (inner.height - 1) as usize;
+        for (idx, entry) in self.status_log.iter().rev().take(visible_rows).enumerate() {
+            let color = match entry.level {
+                StatusLevel::Error => Color::Red,
+                StatusLevel::Info => Color::Gray,
+            };
+            let age = entry.at.elapsed().as_secs();
+            let line = Line::from(vec![
+                Span::styled(
+                    format!("{age:>3}s ago  "),
+                    Style::default().fg(Color::DarkGray),
+                ),
+                Span::styled(entry.text.clone(), Style::default().fg(color)),
+            ]);
+            buf.set_line(inner.x, inner.y + idx as u16, &line, inner.width);
+        }
+
+        buf.set_string(
+            inner.x,
+            inner.y + inner.height - 1,
+            "[Esc/m] Close",
+            Style::default().fg(Color::DarkGray),
+        );
+    }
+
+    fn render_cheat_sheet(&mut self, area: Rect, buf: &mut Buffer) {
+        use crate::statusline::color_picker::centered_rect;
+
+        if !self.cheat_sheet_open {
+            return;
+        }
+
+        let popup_area = centered_rect(70, 85, area);
+        ratatui::widgets::Clear.render(popup_area, buf);
+
+        let block = Block::default().borders(Borders::ALL).title("Key Bindings");
+        let inner = block.inner(popup_area);
+        block.render(popup_area, buf);
+
+        if inner.height < 2 {
+            return;
+        }
+
+        // 展平成行：分组标题 + 绑定行
+        let mut lines: Vec<Line> = Vec::new();
+        for (i, group) in KEY_BINDING_GROUPS.iter().enumerate() {
+            if i > 0 {
+                lines.push(Line::from(""));
+            }
+            lines.push(Line::from(Span::styled(
+                group.context,
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )));
+            for (keys, action) in group.bindings.iter() {
+                // 主题快速选择的键位跟随配置
+                let keys = if *action == "Theme" {
+                    self.theme_quick_select_label()
+                } else {
+                    keys
+                };
+                lines.push(Line::from(vec![
+                    Span::styled(format!("  {keys:<14}"), Style::default().fg(Color::Yellow)),
+                    Span::styled((*action).to_string(), Style::default().fg(Color::Gray)),
+                ]));
+            }
+        }
+
+        let visible_rows = (inner.height - 1) as usize;
+        let max_scroll = lines.len().saturating_sub(visible_rows);
+        self.cheat_sheet_scroll = self.cheat_sheet_scroll.min(max_scroll);
+
+        for (idx, line) in lines
+            .iter()
+            .skip(self.cheat_sheet_scroll)
+            .take(visible_rows)
+            .enumerate()
+        {
+            buf.set_line(inner.x, inner.y + idx as u16, line, inner.width);
+        }
+
+        buf.set_string(
+            inner.x,
+            inner.y + inner.height - 1,
+            "[↑↓/PgUp/PgDn] Scroll  [Esc] Close",
+            Style::default().fg(Color::DarkGray),
+        );
+    }
+
+    fn render_confirm_exit(&self, area: Rect, buf: &mut Buffer) {
+        use crate::statusline::color_picker::centered_rect;
+
+        if !self.confirm_exit_open {
+            return;
+        }
+
+        let popup_area = centered_rect(45, 20, area);
+        ratatui::widgets::Clear.render(popup_area, buf);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title("Unsaved Changes");
+        let inner = block.inner(popup_area);
+        block.render(popup_area, buf);
+
+        buf.set_string(
+            inner.x,
+            inner.y,
+            "You have unsaved changes. Discard them?",
+            Style::default(),
+        );
+
+        let mut spans: Vec<Span> = Vec::new();
+        for (i, choice) in EXIT_CHOICES.iter().enumerate() {
+            if i > 0 {
+                spans.push(Span::raw("   "));
+            }
+            let label = match choice {
+                ExitChoice::Save => "[S]ave & Exit",
+                ExitChoice::Discard => "[D]iscard",
+                ExitChoice::Cancel => "[C]ancel",
+            };
+            let style = if i == self.confirm_exit_selected {
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::REVERSED)
+            } else {
+                Style::default()
+            };
+            spans.push(Span::styled(label, style));
+        }
+        if inner.height > 2 {
+            buf.set_line(inner.x, inner.y + 2, &Line::from(spans), inner.width);
+        }
     }
 
     fn calculate_theme_selector_height(&self, width: u16) -> u16 {
@@ -709,47 +1718,128 @@ impl CxlineOverlay {
         title.render(area, buf);
     }
 
-    fn render_preview(&self, area: Rect, buf: &mut Buffer) {
-        use crate::statusline::renderer::StatusLineRenderer;
+    /// 预览数据上下文（实时数据缺失的字段回退到合成演示值）
+    /// 顶部预览行和 segment 列表的预览 chip 共用
+    fn preview_context(&self) -> StatusLineContext {
+        use codex_protocol::openai_models::ReasoningEffort;
+
+        let live = self.live_data.as_ref().filter(|_| self.use_live_data);
+        let model = live
+            .map(|d| d.model.as_str())
+            .filter(|m| !m.is_empty())
+            .unwrap_or("gpt-5.2-codex");
+        let cwd = live
+            .map(|d| d.cwd.as_path())
+            .filter(|p| !p.as_os_str().is_empty())
+            .unwrap_or_else(|| std::path::Path::new("/home/user/Cxline"));
+
+        let mut ctx = StatusLineContext::new(model, cwd)
+            .with_reasoning_effort(
+                live.and_then(|d| d.reasoning_effort.clone())
+                    .or(Some(ReasoningEffort::Medium)),
+            )
+            .with_context(
+                live.and_then(|d| d.context_used_tokens).or(Some(50000)),
+                live.and_then(|d| d.context_window_size).or(Some(128000)),
+            )
+            .with_rate_limit(
+                live.and_then(|d| d.hourly_rate_limit_percent)
+                    .or(Some(25.0)),
+                live.and_then(|d| d.weekly_rate_limit_percent)
+                    .or(Some(15.0)),
+                live.and_then(|d| d.weekly_rate_limit_resets_at.clone())
+                    .or_else(|| Some("1-28-14".to_string())),
+            )
+            .with_git_preview("main", "✓", 0, 0)
+            // 预览里模拟失焦 + 2 个错过的事件，让 Alert segment 可见
+            .with_alert(/*terminal_focused*/ false, /*missed_count*/ 2)
+            // 预览行的数字格式跟随当前编辑中的 locale 配置
+            .with_locale(self.config.locale);
+        if let Some(git) = live.and_then(|d| d.git_preview.clone()) {
+            ctx.git_preview = Some(git);
+        }
+        ctx
+    }
+
+    /// 收集单个 segment 的预览数据
+    fn collect_segment_data(
+        ctx: &StatusLineContext,
+        id: SegmentId,
+    ) -> Option<crate::statusline::SegmentData> {
         use crate::statusline::segment::Segment;
         use crate::statusline::segments::*;
-        use codex_protocol::openai_models::ReasoningEffort;
 
-        let ctx =
-            StatusLineContext::new("gpt-5.2-codex", std::path::Path::new("/home/user/Cxline"))
-                .with_reasoning_effort(Some(ReasoningEffort::Medium))
-                .with_context(Some(50000), Some(128000))
-                .with_rate_limit(Some(25.0), Some(15.0), Some("1-28-14".to_string()))
-                .with_git_preview("main", "✓", 0, 0);
+        match id {
+            SegmentId::Model => ModelSegment.collect(ctx),
+            SegmentId::Directory => DirectorySegment.collect(ctx),
+            SegmentId::Git => GitSegment.collect(ctx),
+            SegmentId::Context => ContextSegment.collect(ctx),
+            SegmentId::Usage => UsageSegment.collect(ctx),
+            SegmentId::Alert => AlertSegment.collect(ctx),
+        }
+    }
+
+    fn render_preview(&mut self, area: Rect, buf: &mut Buffer) {
+        use crate::statusline::renderer::StatusLineRenderer;
+
+        let ctx = self.preview_context();
+
+        // 主题预览时基于临时配置渲染，工作配置保持不动
+        let preview_config;
+        let config = match &self.preview_theme {
+            Some(theme_name) => {
+                let mut temp = self.config.clone();
+                temp.apply_theme(theme_name);
+                preview_config = temp;
+                &preview_config
+            }
+            None => &self.config,
+        };
 
         // 按 segment_order 顺序构建预览
-        let mut renderer = StatusLineRenderer::new(&self.config);
-        for &segment_id in &self.segment_order {
-            let segment_config = self.config.get_segment_config(segment_id);
+        let mut renderer = StatusLineRenderer::new(config);
+        for &segment_id in &config.segment_order {
+            let segment_config = config.get_segment_config(segment_id);
             if !segment_config.enabled {
                 continue;
             }
 
-            let data = match segment_id {
-                SegmentId::Model => ModelSegment.collect(&ctx),
-                SegmentId::Directory => DirectorySegment.collect(&ctx),
-                SegmentId::Git => GitSegment.collect(&ctx),
-                SegmentId::Context => ContextSegment.collect(&ctx),
-                SegmentId::Usage => UsageSegment.collect(&ctx),
-            };
-
-            if let Some(data) = data {
+            if let Some(data) = Self::collect_segment_data(&ctx, segment_id) {
                 renderer.add_segment(segment_id, data);
             }
         }
 
         let line = renderer.render_line();
 
-        let block = Block::default().borders(Borders::ALL).title("Preview");
+        let title = match self.preview_width {
+            Some(width) => format!("Preview @ {width} cols"),
+            None => "Preview".to_string(),
+        };
+        let block = Block::default().borders(Borders::ALL).title(title);
         let inner = block.inner(area);
         block.render(area, buf);
-
-        buf.set_line(inner.x, inner.y, &line, inner.width);
+        self.preview_area_width = inner.width;
+
+        // 模拟宽度时按该宽度截断渲染，并画出边界标记
+        match self.preview_width {
+            Some(width) => {
+                let sim_width = width.min(inner.width.saturating_sub(1));
+                let fitted =
+                    crate::line_truncation::truncate_line_to_width(line, sim_width as usize);
+                buf.set_line(inner.x, inner.y, &fitted, sim_width);
+                if sim_width < inner.width {
+                    buf.set_string(
+                        inner.x + sim_width,
+                        inner.y,
+                        "▏",
+                        Style::default().fg(Color::DarkGray),
+                    );
+                }
+            }
+            None => {
+                buf.set_line(inner.x, inner.y, &line, inner.width);
+            }
+        }
     }
 
     fn render_theme_selector(&self, area: Rect, buf: &mut Buffer) {
@@ -766,7 +1856,14 @@ impl CxlineOverlay {
 
             for theme in THEME_NAMES.iter() {
                 let is_current = self.config.theme == *theme;
-                let marker = if is_current { "[✓]" } else { "[ ]" };
+                let is_preview = self.preview_theme.as_deref() == Some(*theme);
+                let marker = if is_current {
+                    "[✓]"
+                } else if is_preview {
+                    "[~]"
+                } else {
+                    "[ ]"
+                };
                 let theme_part = format!("{marker} {theme}");
                 let separator_width = if current_line_spans.is_empty() { 0 } else { 2 };
                 let theme_part_len = theme_part.chars().count();
@@ -787,6 +1884,11 @@ impl CxlineOverlay {
                     Style::default()
                         .fg(Color::Green)
                         .add_modifier(Modifier::BOLD)
+                } else if is_preview {
+                    // 预览中但尚未提交的主题
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD)
                 } else {
                     Style::default()
                 };
@@ -807,7 +1909,51 @@ impl CxlineOverlay {
         }
     }
 
+    /// 单个 segment 的预览 chip：图标 + 主文本，使用其配置的颜色
+    /// 禁用的 segment 置灰显示
+    fn segment_chip(
+        &self,
+        ctx: &StatusLineContext,
+        id: SegmentId,
+        max_width: usize,
+    ) -> Line<'static> {
+        use crate::line_truncation::truncate_line_to_width;
+
+        let segment_config = self.config.get_segment_config(id);
+        let Some(data) = Self::collect_segment_data(ctx, id) else {
+            return Line::from(Vec::<Span<'static>>::new());
+        };
+
+        let icon = data
+            .metadata
+            .get("dynamic_icon")
+            .cloned()
+            .unwrap_or_else(|| segment_config.icon.get(self.config.style).to_string());
+        let mut icon_style =
+            Style::default().fg(segment_config.colors.icon_color().unwrap_or(Color::White));
+        let mut text_style =
+            Style::default().fg(segment_config.colors.text_color().unwrap_or(Color::White));
+        if !segment_config.enabled {
+            icon_style = icon_style.add_modifier(Modifier::DIM);
+            text_style = text_style.add_modifier(Modifier::DIM);
+        }
+
+        let line = Line::from(vec![
+            Span::styled(icon, icon_style),
+            Span::raw(" "),
+            Span::styled(data.primary, text_style),
+        ]);
+        truncate_line_to_width(line, max_width)
+    }
+
     fn render_segment_list(&self, area: Rect, buf: &mut Buffer) {
+        use crate::line_truncation::line_width;
+
+        let ctx = self.preview_context();
+        let inner_width = area.width.saturating_sub(2) as usize;
+        // chip 限制在列表宽度的 30% 以内
+        let chip_max_width = inner_width * 3 / 10;
+
         let items: Vec<ListItem> = (0..self.segment_count())
             .map(|i| {
                 let id = self.segment_id_at(i);
@@ -817,14 +1963,26 @@ impl CxlineOverlay {
                 let enabled_marker = if segment_config.enabled { "●" } else { "○" };
                 let name = Self::segment_name(id);
 
-                if is_selected {
-                    ListItem::new(Line::from(vec![
+                let mut spans = if is_selected {
+                    vec![
                         Span::styled("▶ ", Style::default().fg(Color::Cyan)),
                         Span::raw(format!("{enabled_marker} {name}")),
-                    ]))
+                    ]
                 } else {
-                    ListItem::new(format!("  {enabled_marker} {name}"))
+                    vec![Span::raw(format!("  {enabled_marker} {name}"))]
+                };
+
+                // 右对齐的预览 chip（放不下时省略）
+                let chip = self.segment_chip(&ctx, id, chip_max_width);
+                let left_width = line_width(&Line::from(spans.clone()));
+                let chip_width = line_width(&chip);
+                if chip_width > 0 && left_width + chip_width + 1 <= inner_width {
+                    let pad = inner_width - left_width - chip_width;
+                    spans.push(Span::raw(" ".repeat(pad)));
+                    spans.extend(chip.spans);
                 }
+
+                ListItem::new(Line::from(spans))
             })
             .collect();
 
@@ -841,7 +1999,7 @@ impl CxlineOverlay {
         list.render(area, buf);
     }
 
-    fn render_settings(&self, area: Rect, buf: &mut Buffer) {
+    fn render_settings(&mut self, area: Rect, buf: &mut Buffer) {
         let id = self.segment_id_at(self.selected_segment);
         let segment_config = self.config.get_segment_config(id);
         let segment_name = Self::segment_name(id);
@@ -854,83 +2012,47 @@ impl CxlineOverlay {
         // 获取当前图标
         let current_icon = segment_config.icon.get(self.config.style);
 
-        let create_field_line =
-            |field: FieldSelection, spans: Vec<Span<'static>>| -> Line<'static> {
-                let is_selected =
-                    self.selected_panel == Panel::Settings && self.selected_field == field;
-                let mut result_spans = vec![];
-
-                if is_selected {
-                    result_spans.push(Span::styled("▶ ", Style::default().fg(Color::Cyan)));
-                } else {
-                    result_spans.push(Span::raw("  "));
-                }
-
-                result_spans.extend(spans);
-                Line::from(result_spans)
-            };
-
-        let lines = vec![
-            Line::from(format!("{segment_name} Segment").bold()),
-            Line::from(""),
-            create_field_line(
-                FieldSelection::Enabled,
-                vec![Span::raw(format!(
-                    "├─ Enabled: {}",
+        // 每个字段的值展示
+        let value_spans = |field: FieldSelection| -> Vec<Span<'static>> {
+            match field {
+                FieldSelection::Enabled => vec![Span::raw(format!(
+                    "Enabled: {}",
                     if segment_config.enabled { "✓" } else { "✗" }
                 ))],
-            ),
-            create_field_line(
-                FieldSelection::Icon,
-                vec![
-                    Span::raw("├─ Icon: "),
+                FieldSelection::Icon => vec![
+                    Span::raw("Icon: "),
                     Span::styled(current_icon.to_string(), Style::default().fg(icon_color)),
                 ],
-            ),
-            create_field_line(
-                FieldSelection::IconColor,
-                vec![
-                    Span::raw("├─ Icon Color: "),
+                FieldSelection::IconColor => vec![
+                    Span::raw("Icon Color: "),
                     Span::styled("██", Style::default().fg(icon_color)),
                 ],
-            ),
-            create_field_line(
-                FieldSelection::TextColor,
-                vec![
-                    Span::raw("├─ Text Color: "),
+                FieldSelection::TextColor => vec![
+                    Span::raw("Text Color: "),
                     Span::styled("██", Style::default().fg(text_color)),
                 ],
-            ),
-            create_field_line(
-                FieldSelection::BackgroundColor,
-                vec![
-                    Span::raw("├─ Background: "),
+                FieldSelection::BackgroundColor => vec![
+                    Span::raw("Background: "),
                     if let Some(bg) = bg_color {
                         Span::styled("██", Style::default().fg(bg))
                     } else {
                         Span::styled("--", Style::default().fg(Color::DarkGray))
                     },
                 ],
-            ),
-            create_field_line(
-                FieldSelection::TextStyle,
-                vec![Span::raw(format!(
-                    "├─ Bold: {}",
+                FieldSelection::TextStyle => vec![Span::raw(format!(
+                    "Bold: {}",
                     if segment_config.styles.text_bold {
                         "[✓]"
                     } else {
                         "[ ]"
                     }
                 ))],
-            ),
-            create_field_line(
-                FieldSelection::Options,
-                vec![Span::raw(format!(
-                    "└─ Options: {} items",
+                FieldSelection::Options => vec![Span::raw(format!(
+                    "Options: {} items",
                     segment_config.options.len()
                 ))],
-            ),
-        ];
+            }
+        };
 
         let block = Block::default()
             .borders(Borders::ALL)
@@ -940,26 +2062,112 @@ impl CxlineOverlay {
             } else {
                 Style::default()
             });
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        if inner.height == 0 {
+            return;
+        }
+
+        // 布局：标题行 + 空行 + 可滚动字段区 + 空行 + 帮助行
+        let total = SETTINGS_FIELDS.len();
+        let visible_rows = inner.height.saturating_sub(4) as usize;
+        let selected_idx = self.field_index();
+
+        // 保持选中字段可见
+        if selected_idx < self.settings_scroll {
+            self.settings_scroll = selected_idx;
+        }
+        if visible_rows > 0 && selected_idx >= self.settings_scroll + visible_rows {
+            self.settings_scroll = selected_idx + 1 - visible_rows;
+        }
+        let scroll = self
+            .settings_scroll
+            .min(total.saturating_sub(visible_rows.max(1)));
+
+        let mut lines: Vec<Line> = vec![
+            Line::from(format!("{segment_name} Segment").bold()),
+            Line::from(""),
+        ];
+
+        for (i, descriptor) in SETTINGS_FIELDS
+            .iter()
+            .enumerate()
+            .skip(scroll)
+            .take(visible_rows.max(1))
+        {
+            let is_selected =
+                self.selected_panel == Panel::Settings && self.selected_field == descriptor.field;
+            let branch = if i == total - 1 { "└─ " } else { "├─ " };
 
-        let paragraph = Paragraph::new(lines).block(block);
-        paragraph.render(area, buf);
+            let mut spans: Vec<Span> = Vec::new();
+            if is_selected {
+                spans.push(Span::styled("▶ ", Style::default().fg(Color::Cyan)));
+            } else {
+                spans.push(Span::raw("  "));
+            }
+            spans.push(Span::raw(branch));
+            spans.extend(value_spans(descriptor.field));
+            lines.push(Line::from(spans));
+        }
+
+        let paragraph = Paragraph::new(lines);
+        paragraph.render(inner, buf);
+
+        // 滚动条（仅当字段多于可见行时）
+        if visible_rows > 0 && total > visible_rows {
+            let bar_x = inner.x + inner.width - 1;
+            let bar_top = inner.y + 2;
+            let thumb_height = ((visible_rows * visible_rows).div_ceil(total)).max(1);
+            let thumb_top = scroll * visible_rows / total;
+            for row in 0..visible_rows {
+                let symbol = if row >= thumb_top && row < thumb_top + thumb_height {
+                    "█"
+                } else {
+                    "│"
+                };
+                buf.set_string(
+                    bar_x,
+                    bar_top + row as u16,
+                    symbol,
+                    Style::default().fg(Color::DarkGray),
+                );
+            }
+        }
+
+        // 选中字段的帮助行
+        if inner.height >= 2 {
+            let help = SETTINGS_FIELDS[selected_idx].help;
+            buf.set_string(
+                inner.x,
+                inner.y + inner.height - 1,
+                help,
+                Style::default().fg(Color::DarkGray),
+            );
+        }
+    }
+
+    /// 当前主题快速选择键位的帮助标签
+    fn theme_quick_select_label(&self) -> &'static str {
+        match self.config.theme_quick_select {
+            ThemeQuickSelect::AltDigit => "[Alt+1-9]",
+            ThemeQuickSelect::Leader => "[t 1-9]",
+        }
     }
 
     fn render_help(&self, area: Rect, buf: &mut Buffer) {
-        let help_items: Vec<(&str, &str)> = vec![
-            ("[Tab]", "Switch Panel"),
-            ("[↑↓]", "Select"),
-            ("[Shift+↑↓]", "Reorder"),
-            ("[Enter]", "Toggle/Edit"),
-            ("[1-9]", "Theme"),
-            ("[P]", "Cycle Theme"),
-            ("[R]", "Reset Theme"),
-            ("[E]", "Edit Separator"),
-            ("[W]", "Write Theme"),
-            ("[Ctrl+S]", "Save Theme"),
-            ("[S]", "Save Config"),
-            ("[Esc]", "Quit"),
-        ];
+        // 底部帮助条只显示主上下文；完整列表见 ? 速查表
+        let mut help_items: Vec<(&str, &str)> = KEY_BINDING_GROUPS
+            .iter()
+            .find(|g| g.context == "Main")
+            .map(|g| g.bindings.to_vec())
+            .unwrap_or_default();
+        // 主题快速选择的键位跟随配置
+        for item in &mut help_items {
+            if item.1 == "Theme" {
+                item.0 = self.theme_quick_select_label();
+            }
+        }
 
         let block = Block::default().borders(Borders::ALL).title("Help");
         let inner = block.inner(area);
@@ -1004,11 +2212,15 @@ impl CxlineOverlay {
             lines.push(Line::from(current_line_spans));
         }
 
-        // 添加状态消息
-        if let Some(msg) = &self.status_message {
+        // 添加状态消息（错误红色，普通绿色；到期后不再显示）
+        if let Some(entry) = self.current_status() {
+            let color = match entry.level {
+                StatusLevel::Error => Color::Red,
+                StatusLevel::Info => Color::Green,
+            };
             lines.push(Line::from(Span::styled(
-                msg.clone(),
-                Style::default().fg(Color::Green),
+                entry.text.clone(),
+                Style::default().fg(color),
             )));
         }
 
@@ -1020,3 +2232,390 @@ impl CxlineOverlay {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::statusline::themes::ThemePresets;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn overlay_with_options_open(id: SegmentId) -> CxlineOverlay {
+        let mut overlay = CxlineOverlay::new(ThemePresets::get_default(), None);
+        overlay.options_editor.open(id);
+        overlay
+    }
+
+    #[test]
+    fn test_toggle_bool_option_via_key_handler() {
+        let mut overlay = overlay_with_options_open(SegmentId::Git);
+
+        overlay.handle_key_event(key(KeyCode::Enter)).unwrap();
+        let value = overlay.config.segments.git.options["show_ahead_behind"]
+            .as_bool()
+            .unwrap();
+        assert!(value);
+
+        overlay.handle_key_event(key(KeyCode::Enter)).unwrap();
+        let value = overlay.config.segments.git.options["show_ahead_behind"]
+            .as_bool()
+            .unwrap();
+        assert!(!value);
+    }
+
+    #[test]
+    fn test_cycle_enum_option_via_key_handler() {
+        let mut overlay = overlay_with_options_open(SegmentId::Usage);
+
+        // 初始未设置，第一次 → 从 percent 前进到 circle
+        overlay.handle_key_event(key(KeyCode::Right)).unwrap();
+        assert_eq!(
+            overlay.config.segments.usage.options["display_mode"],
+            "circle"
+        );
+
+        overlay.handle_key_event(key(KeyCode::Left)).unwrap();
+        assert_eq!(
+            overlay.config.segments.usage.options["display_mode"],
+            "percent"
+        );
+    }
+
+    #[test]
+    fn test_undo_and_redo_segment_toggle() {
+        let mut overlay = CxlineOverlay::new(ThemePresets::get_default(), None);
+        let id = overlay.segment_id_at(0);
+        let before = overlay.config.get_segment_config(id).enabled;
+
+        // Enter 切换 segment 开关
+        overlay.handle_key_event(key(KeyCode::Enter)).unwrap();
+        assert_eq!(overlay.config.get_segment_config(id).enabled, !before);
+
+        // u 撤销
+        overlay.handle_key_event(key(KeyCode::Char('u'))).unwrap();
+        assert_eq!(overlay.config.get_segment_config(id).enabled, before);
+        assert_eq!(
+            overlay.current_status().map(|e| e.text.as_str()),
+            Some("Undid: segment toggle")
+        );
+
+        // Ctrl+R 重做
+        overlay
+            .handle_key_event(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL))
+            .unwrap();
+        assert_eq!(overlay.config.get_segment_config(id).enabled, !before);
+    }
+
+    #[test]
+    fn test_new_edit_clears_redo_stack() {
+        let mut overlay = CxlineOverlay::new(ThemePresets::get_default(), None);
+
+        overlay.handle_key_event(key(KeyCode::Enter)).unwrap();
+        overlay.handle_key_event(key(KeyCode::Char('u'))).unwrap();
+        assert!(!overlay.redo_stack.is_empty());
+
+        // 新的修改使 redo 失效
+        overlay.handle_key_event(key(KeyCode::Enter)).unwrap();
+        assert!(overlay.redo_stack.is_empty());
+    }
+
+    #[test]
+    fn test_esc_with_unsaved_changes_asks_for_confirmation() {
+        let mut overlay = CxlineOverlay::new(ThemePresets::get_default(), None);
+
+        // 干净状态下 Esc 直接退出
+        overlay.handle_key_event(key(KeyCode::Esc)).unwrap();
+        assert!(overlay.is_done());
+
+        // 有未保存的修改时 Esc 先弹出确认对话框
+        let mut overlay = CxlineOverlay::new(ThemePresets::get_default(), None);
+        overlay.handle_key_event(key(KeyCode::Enter)).unwrap();
+        overlay.handle_key_event(key(KeyCode::Esc)).unwrap();
+        assert!(!overlay.is_done());
+        assert!(overlay.confirm_exit_open);
+
+        // Cancel 留在 overlay 中
+        overlay.handle_key_event(key(KeyCode::Char('c'))).unwrap();
+        assert!(!overlay.is_done());
+        assert!(!overlay.confirm_exit_open);
+
+        // Discard 退出
+        overlay.handle_key_event(key(KeyCode::Esc)).unwrap();
+        overlay.handle_key_event(key(KeyCode::Char('d'))).unwrap();
+        assert!(overlay.is_done());
+    }
+
+    #[test]
+    fn test_theme_preview_is_non_destructive_until_enter() {
+        let mut overlay = CxlineOverlay::new(ThemePresets::get_default(), None);
+        let committed = overlay.config.theme.clone();
+
+        // 数字键只进入预览，不改动工作配置
+        overlay.handle_key_event(key(KeyCode::Char('3'))).unwrap();
+        assert_eq!(overlay.config.theme, committed);
+        assert_eq!(overlay.preview_theme.as_deref(), Some(THEME_NAMES[2]));
+
+        // Esc 取消预览
+        overlay.handle_key_event(key(KeyCode::Esc)).unwrap();
+        assert!(overlay.preview_theme.is_none());
+        assert_eq!(overlay.config.theme, committed);
+        assert!(!overlay.is_done());
+
+        // Enter 提交预览的主题
+        overlay.handle_key_event(key(KeyCode::Char('3'))).unwrap();
+        overlay.handle_key_event(key(KeyCode::Enter)).unwrap();
+        assert!(overlay.preview_theme.is_none());
+        assert_eq!(overlay.config.theme, THEME_NAMES[2]);
+    }
+
+    #[test]
+    fn test_settings_panel_scrolls_to_keep_selection_visible() {
+        let mut overlay = CxlineOverlay::new(ThemePresets::get_default(), None);
+        overlay.selected_panel = Panel::Settings;
+
+        // 移到最后一个字段
+        for _ in 0..SETTINGS_FIELDS.len() {
+            overlay.handle_key_event(key(KeyCode::Down)).unwrap();
+        }
+        assert_eq!(overlay.field_index(), SETTINGS_FIELDS.len() - 1);
+
+        // 面板高度不足以显示全部字段时，滚动偏移跟随选中项
+        let area = Rect::new(0, 0, 40, 8);
+        let mut buf = Buffer::empty(area);
+        overlay.render_settings(area, &mut buf);
+        assert!(overlay.settings_scroll > 0);
+
+        // 回到第一个字段后滚动复位
+        for _ in 0..SETTINGS_FIELDS.len() {
+            overlay.handle_key_event(key(KeyCode::Up)).unwrap();
+        }
+        overlay.render_settings(area, &mut buf);
+        assert_eq!(overlay.settings_scroll, 0);
+    }
+
+    #[test]
+    fn test_reset_selected_segment_restores_theme_defaults() {
+        use crate::statusline::style::AnsiColor;
+
+        let mut overlay = CxlineOverlay::new(ThemePresets::get_default(), None);
+        let id = overlay.segment_id_at(0);
+        let theme_default = ThemePresets::theme_segment_config(&overlay.config.theme, id);
+
+        overlay.config.get_segment_config_mut(id).colors.text = Some(AnsiColor::rgb(1, 2, 3));
+        overlay.handle_key_event(key(KeyCode::Char('z'))).unwrap();
+
+        let segment = overlay.config.get_segment_config(id);
+        assert_eq!(segment.colors, theme_default.colors);
+
+        // 参与撤销栈
+        overlay.handle_key_event(key(KeyCode::Char('u'))).unwrap();
+        assert_eq!(
+            overlay.config.get_segment_config(id).colors.text,
+            Some(AnsiColor::rgb(1, 2, 3))
+        );
+    }
+
+    #[test]
+    fn test_cheat_sheet_takes_input_priority() {
+        let mut overlay = CxlineOverlay::new(ThemePresets::get_default(), None);
+        overlay.handle_key_event(key(KeyCode::Char('?'))).unwrap();
+        assert!(overlay.cheat_sheet_open);
+
+        // 打开期间按键不会落到主界面（选择不变，只滚动）
+        let selected = overlay.selected_segment;
+        overlay.handle_key_event(key(KeyCode::Down)).unwrap();
+        assert_eq!(overlay.selected_segment, selected);
+        assert_eq!(overlay.cheat_sheet_scroll, 1);
+
+        overlay.handle_key_event(key(KeyCode::Esc)).unwrap();
+        assert!(!overlay.cheat_sheet_open);
+        assert!(!overlay.is_done());
+    }
+
+    #[test]
+    fn test_status_log_keeps_history_and_caps_capacity() {
+        let mut overlay = CxlineOverlay::new(ThemePresets::get_default(), None);
+        for i in 0..(STATUS_LOG_CAPACITY + 5) {
+            overlay.status_info(format!("message {i}"));
+        }
+
+        assert_eq!(overlay.status_log.len(), STATUS_LOG_CAPACITY);
+        // 最旧的消息被淘汰，最新的保留
+        assert_eq!(overlay.status_log.front().unwrap().text, "message 5");
+        assert_eq!(
+            overlay.current_status().map(|e| e.text.as_str()),
+            Some("message 24")
+        );
+    }
+
+    #[test]
+    fn test_error_status_has_longer_ttl_than_info() {
+        let mut overlay = CxlineOverlay::new(ThemePresets::get_default(), None);
+
+        // 模拟一条刚过 info TTL 的消息：info 已淡出，error 仍然可见
+        overlay.status_info("saved");
+        overlay.status_log.back_mut().unwrap().at = Instant::now() - STATUS_INFO_TTL;
+        assert!(overlay.current_status().is_none());
+
+        overlay.status_error("Failed to save");
+        overlay.status_log.back_mut().unwrap().at = Instant::now() - STATUS_INFO_TTL;
+        let entry = overlay.current_status().expect("error still visible");
+        assert_eq!(entry.level, StatusLevel::Error);
+    }
+
+    #[test]
+    fn test_message_log_opens_and_takes_input_priority() {
+        let mut overlay = CxlineOverlay::new(ThemePresets::get_default(), None);
+        overlay.status_error("Failed to write theme: disk full");
+        overlay.handle_key_event(key(KeyCode::Char('m'))).unwrap();
+        assert!(overlay.message_log_open);
+
+        // 打开期间主界面按键被拦截
+        let selected = overlay.selected_segment;
+        overlay.handle_key_event(key(KeyCode::Down)).unwrap();
+        assert_eq!(overlay.selected_segment, selected);
+
+        overlay.handle_key_event(key(KeyCode::Esc)).unwrap();
+        assert!(!overlay.message_log_open);
+        assert!(!overlay.is_done());
+    }
+
+    #[test]
+    fn test_preview_width_shrink_grow_and_reset() {
+        let mut overlay = CxlineOverlay::new(ThemePresets::get_default(), None);
+        overlay.preview_area_width = 120;
+
+        overlay.handle_key_event(key(KeyCode::Char('['))).unwrap();
+        assert_eq!(overlay.preview_width, Some(110));
+        overlay.handle_key_event(key(KeyCode::Char('['))).unwrap();
+        assert_eq!(overlay.preview_width, Some(100));
+        assert_eq!(
+            overlay.current_status().map(|e| e.text.as_str()),
+            Some("Preview @ 100 cols")
+        );
+
+        overlay.handle_key_event(key(KeyCode::Char(']'))).unwrap();
+        assert_eq!(overlay.preview_width, Some(110));
+
+        overlay.handle_key_event(key(KeyCode::Char('\\'))).unwrap();
+        assert_eq!(overlay.preview_width, None);
+    }
+
+    #[test]
+    fn test_preview_width_is_clamped() {
+        let mut overlay = CxlineOverlay::new(ThemePresets::get_default(), None);
+        overlay.preview_width = Some(PREVIEW_WIDTH_MIN);
+        overlay.handle_key_event(key(KeyCode::Char('['))).unwrap();
+        assert_eq!(overlay.preview_width, Some(PREVIEW_WIDTH_MIN));
+
+        overlay.preview_width = Some(PREVIEW_WIDTH_MAX);
+        overlay.handle_key_event(key(KeyCode::Char(']'))).unwrap();
+        assert_eq!(overlay.preview_width, Some(PREVIEW_WIDTH_MAX));
+    }
+
+    #[test]
+    fn test_bare_digits_no_longer_switch_themes() {
+        let mut overlay = CxlineOverlay::new(ThemePresets::get_default(), None);
+        let theme_before = overlay.config.theme.clone();
+
+        overlay.handle_key_event(key(KeyCode::Char('2'))).unwrap();
+        assert_eq!(overlay.preview_theme, None);
+        assert_eq!(overlay.config.theme, theme_before);
+    }
+
+    #[test]
+    fn test_alt_digit_previews_theme() {
+        let mut overlay = CxlineOverlay::new(ThemePresets::get_default(), None);
+        let alt_2 = KeyEvent::new(KeyCode::Char('2'), KeyModifiers::ALT);
+        overlay.handle_key_event(alt_2).unwrap();
+        assert_eq!(overlay.preview_theme.as_deref(), Some(THEME_NAMES[1]));
+    }
+
+    #[test]
+    fn test_leader_chord_previews_theme_and_frees_alt() {
+        let mut overlay = CxlineOverlay::new(ThemePresets::get_default(), None);
+        overlay.config.theme_quick_select = ThemeQuickSelect::Leader;
+
+        // leader 模式下 Alt+数字不再生效
+        let alt_2 = KeyEvent::new(KeyCode::Char('2'), KeyModifiers::ALT);
+        overlay.handle_key_event(alt_2).unwrap();
+        assert_eq!(overlay.preview_theme, None);
+
+        overlay.handle_key_event(key(KeyCode::Char('t'))).unwrap();
+        assert!(overlay.theme_leader_pending);
+        overlay.handle_key_event(key(KeyCode::Char('3'))).unwrap();
+        assert_eq!(overlay.preview_theme.as_deref(), Some(THEME_NAMES[2]));
+
+        // 非数字键取消 leader 状态
+        overlay.cancel_theme_preview();
+        overlay.handle_key_event(key(KeyCode::Char('t'))).unwrap();
+        overlay.handle_key_event(key(KeyCode::Down)).unwrap();
+        assert!(!overlay.theme_leader_pending);
+        assert_eq!(overlay.preview_theme, None);
+    }
+
+    #[test]
+    fn test_threshold_editor_opens_from_usage_options() {
+        let mut overlay = overlay_with_options_open(SegmentId::Usage);
+        // 第二个 option 是 icon_thresholds
+        overlay.handle_key_event(key(KeyCode::Down)).unwrap();
+        overlay.handle_key_event(key(KeyCode::Enter)).unwrap();
+
+        assert!(overlay.threshold_editor.is_open);
+        // 未配置时从内置默认表开始
+        assert_eq!(overlay.threshold_editor.rows.len(), 8);
+    }
+
+    #[test]
+    fn test_threshold_percent_edit_persists_to_options() {
+        let mut overlay = overlay_with_options_open(SegmentId::Usage);
+        overlay.handle_key_event(key(KeyCode::Down)).unwrap();
+        overlay.handle_key_event(key(KeyCode::Enter)).unwrap();
+
+        overlay.handle_key_event(key(KeyCode::Char('p'))).unwrap();
+        assert!(overlay.name_input_dialog.is_open);
+        for c in "10".chars() {
+            overlay.handle_key_event(key(KeyCode::Char(c))).unwrap();
+        }
+        overlay.handle_key_event(key(KeyCode::Enter)).unwrap();
+
+        let value = overlay
+            .config
+            .get_segment_config(SegmentId::Usage)
+            .options
+            .get("icon_thresholds")
+            .expect("thresholds persisted to options");
+        let rows = parse_icon_thresholds(value).expect("parsable table");
+        assert_eq!(rows[0].max_percent, 10);
+
+        // 非法输入不改表
+        overlay.handle_key_event(key(KeyCode::Char('p'))).unwrap();
+        for c in "150".chars() {
+            overlay.handle_key_event(key(KeyCode::Char(c))).unwrap();
+        }
+        overlay.handle_key_event(key(KeyCode::Enter)).unwrap();
+        assert_eq!(
+            overlay.current_status().map(|e| e.text.as_str()),
+            Some("Invalid percent: 150")
+        );
+    }
+
+    #[test]
+    fn test_segment_chip_matches_collect_output() {
+        let overlay = CxlineOverlay::new(ThemePresets::get_default(), None);
+        let ctx = overlay.preview_context();
+
+        let chip = overlay.segment_chip(&ctx, SegmentId::Model, 40);
+        let chip_text: String = chip.spans.iter().map(|s| s.content.as_ref()).collect();
+        let data = CxlineOverlay::collect_segment_data(&ctx, SegmentId::Model)
+            .expect("model segment collects in demo context");
+        // chip = 图标 + 空格 + primary
+        assert!(chip_text.ends_with(&data.primary));
+
+        // 超宽 chip 被截断到上限
+        let narrow = overlay.segment_chip(&ctx, SegmentId::Model, 4);
+        assert!(crate::line_truncation::line_width(&narrow) <= 4);
+    }
+}
@@ -200,20 +200,85 @@ impl HistoryCell for DeprecationNoticeCell {
         lines
     }
 }
-pub(crate) fn new_info_event(message: String, hint: Option<String>) -> PlainHistoryCell {
-    let mut line = vec!["• ".dim(), message.into()];
-    if let Some(hint) = hint {
-        line.push(" ".into());
-        line.push(hint.dark_gray());
-    }
-    let lines: Vec<Line<'static>> = vec![line.into()];
-    PlainHistoryCell { lines }
-}
-
-pub(crate) fn new_error_event(message: String) -> PlainHistoryCell {
-    // Use a hair space (U+200A) to create a subtle, near-invisible separation
-    // before the text. VS16 is intentionally omitted to keep spacing tighter
-    // in terminals like Ghostty.
-    let lines: Vec<Line<'static>> = vec![vec![format!("■ {message}").red()].into()];
-    PlainHistoryCell { lines }
+/// Visual treatment for a [`UiNoticeCell`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum UiNoticeStyle {
+    Info,
+    Error,
+}
+
+/// Short informational notice (slash-command output, confirmations, error
+/// notices).
+///
+/// Unlike `PlainHistoryCell`, the message text is kept structured so the
+/// translation orchestrator can recognize these cells at insertion time and
+/// swap in a cached translation when `translate_ui_notices` is enabled.
+#[derive(Debug)]
+pub(crate) struct UiNoticeCell {
+    style: UiNoticeStyle,
+    message: String,
+    hint: Option<String>,
+}
+
+impl UiNoticeCell {
+    /// The English message text, used as the translation lookup key.
+    pub(crate) fn notice_text(&self) -> &str {
+        &self.message
+    }
+
+    /// Whether this notice carries an error message. Error notices are gated
+    /// by `translate_errors` rather than `translate_ui_notices`.
+    pub(crate) fn is_error(&self) -> bool {
+        self.style == UiNoticeStyle::Error
+    }
+
+    /// Replace the displayed message with its translated form.
+    pub(crate) fn set_translated_message(&mut self, translated: String) {
+        self.message = translated;
+    }
+
+    fn lines(&self) -> Vec<Line<'static>> {
+        match self.style {
+            UiNoticeStyle::Info => {
+                let mut line = vec!["• ".dim(), self.message.clone().into()];
+                if let Some(hint) = &self.hint {
+                    line.push(" ".into());
+                    line.push(hint.clone().dark_gray());
+                }
+                vec![line.into()]
+            }
+            UiNoticeStyle::Error => {
+                // Use a hair space (U+200A) to create a subtle, near-invisible
+                // separation before the text. VS16 is intentionally omitted to
+                // keep spacing tighter in terminals like Ghostty.
+                vec![vec![format!("■ {}", self.message).red()].into()]
+            }
+        }
+    }
+}
+
+impl HistoryCell for UiNoticeCell {
+    fn display_lines(&self, _width: u16) -> Vec<Line<'static>> {
+        self.lines()
+    }
+
+    fn raw_lines(&self) -> Vec<Line<'static>> {
+        plain_lines(self.lines())
+    }
+}
+
+pub(crate) fn new_info_event(message: String, hint: Option<String>) -> UiNoticeCell {
+    UiNoticeCell {
+        style: UiNoticeStyle::Info,
+        message,
+        hint,
+    }
+}
+
+pub(crate) fn new_error_event(message: String) -> UiNoticeCell {
+    UiNoticeCell {
+        style: UiNoticeStyle::Error,
+        message,
+        hint: None,
+    }
 }
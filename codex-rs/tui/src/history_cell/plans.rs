@@ -46,7 +46,11 @@ impl HistoryCell for StreamingPlanTailCell {
 /// Render a user‑friendly plan update styled like a checkbox todo list.
 pub(crate) fn new_plan_update(update: UpdatePlanArgs) -> PlanUpdateCell {
     let UpdatePlanArgs { explanation, plan } = update;
-    PlanUpdateCell { explanation, plan }
+    PlanUpdateCell {
+        explanation,
+        plan,
+        translated_explanation: None,
+    }
 }
 
 /// Create a proposed-plan cell that snapshots the session cwd for later markdown rendering.
@@ -161,6 +165,24 @@ impl HistoryCell for ProposedPlanStreamCell {
 pub(crate) struct PlanUpdateCell {
     explanation: Option<String>,
     plan: Vec<PlanItemArg>,
+    /// Translated explanation note, rendered under the step list when the
+    /// translation orchestrator has one cached.
+    translated_explanation: Option<String>,
+}
+
+impl PlanUpdateCell {
+    /// The trimmed explanation note, used as the translation lookup key.
+    pub(crate) fn explanation_text(&self) -> Option<&str> {
+        self.explanation
+            .as_deref()
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+    }
+
+    /// Attach a translated explanation to render under the step list.
+    pub(crate) fn set_translated_explanation(&mut self, translated: String) {
+        self.translated_explanation = Some(translated);
+    }
 }
 
 impl HistoryCell for PlanUpdateCell {
@@ -211,6 +233,9 @@ impl HistoryCell for PlanUpdateCell {
                 indented_lines.extend(render_step(status, step));
             }
         }
+        if let Some(translated) = self.translated_explanation.as_deref() {
+            indented_lines.extend(render_note(translated));
+        }
         lines.extend(prefix_lines(indented_lines, "  └ ".dim(), "    ".into()));
 
         lines
@@ -233,6 +258,9 @@ impl HistoryCell for PlanUpdateCell {
                 lines.push(Line::from(format!("{status:?}: {step}")));
             }
         }
+        if let Some(translated) = self.translated_explanation.as_deref() {
+            lines.extend(raw_lines_from_source(translated));
+        }
         lines
     }
 }
@@ -5,15 +5,23 @@ use super::*;
 pub(crate) fn new_agent_reasoning_translation_block(
     title: Option<String>,
     translated: String,
+    metadata_footer: Option<String>,
 ) -> Box<dyn HistoryCell> {
-    Box::new(AgentReasoningTranslationCell::new(title, translated, false))
+    Box::new(AgentReasoningTranslationCell::new(
+        title,
+        translated,
+        false,
+        metadata_footer,
+    ))
 }
 
 pub(crate) fn new_agent_reasoning_translation_error_block(
     title: Option<String>,
     reason: String,
 ) -> Box<dyn HistoryCell> {
-    Box::new(AgentReasoningTranslationCell::new(title, reason, true))
+    Box::new(AgentReasoningTranslationCell::new(
+        title, reason, true, None,
+    ))
 }
 
 #[derive(Debug)]
@@ -21,14 +29,23 @@ pub(crate) struct AgentReasoningTranslationCell {
     title: Option<String>,
     content: String,
     is_error: bool,
+    /// One-line translator metadata (e.g. "deepl · 312ms") rendered dim
+    /// under the content when `show_translation_metadata` is on.
+    metadata_footer: Option<String>,
 }
 
 impl AgentReasoningTranslationCell {
-    pub(crate) fn new(title: Option<String>, content: String, is_error: bool) -> Self {
+    pub(crate) fn new(
+        title: Option<String>,
+        content: String,
+        is_error: bool,
+        metadata_footer: Option<String>,
+    ) -> Self {
         Self {
             title,
             content,
             is_error,
+            metadata_footer,
         }
     }
 
@@ -68,7 +85,11 @@ impl AgentReasoningTranslationCell {
             return out;
         }
 
-        prefix_lines(styled_md_lines, "  └ ".dim(), "    ".into())
+        let mut out = prefix_lines(styled_md_lines, "  └ ".dim(), "    ".into());
+        if let Some(footer) = &self.metadata_footer {
+            out.push(Line::from(vec!["    ".into(), footer.clone().dark_gray()]));
+        }
+        out
     }
 }
 
@@ -70,6 +70,9 @@ pub(crate) struct AppKeymap {
     pub(crate) toggle_fast_mode: Vec<KeyBinding>,
     /// Toggle raw scrollback mode for copy-friendly transcript selection.
     pub(crate) toggle_raw_output: Vec<KeyBinding>,
+    // @cometix: statusline appearance overlay shortcut
+    /// Open the statusline appearance overlay (same as `/statusline config`).
+    pub(crate) open_cxline_config: Vec<KeyBinding>,
 }
 
 /// Chat-level keybindings evaluated at the app event layer.
@@ -422,6 +425,11 @@ impl RuntimeKeymap {
                 &defaults.app.toggle_raw_output,
                 "tui.keymap.global.toggle_raw_output",
             )?,
+            open_cxline_config: resolve_bindings(
+                keymap.global.open_cxline_config.as_ref(),
+                &defaults.app.open_cxline_config,
+                "tui.keymap.global.open_cxline_config",
+            )?,
         };
 
         let mut chat = ChatKeymap {
@@ -809,6 +817,10 @@ impl RuntimeKeymap {
                 keymap.global.toggle_raw_output.as_ref(),
                 app.toggle_raw_output.as_slice(),
             ),
+            (
+                keymap.global.open_cxline_config.as_ref(),
+                app.open_cxline_config.as_slice(),
+            ),
             (keymap.list.move_up.as_ref(), list_move_up.as_slice()),
             (keymap.list.move_down.as_ref(), list_move_down.as_slice()),
             (keymap.list.accept.as_ref(), list_accept.as_slice()),
@@ -916,6 +928,7 @@ impl RuntimeKeymap {
                 toggle_vim_mode: default_bindings![],
                 toggle_fast_mode: default_bindings![],
                 toggle_raw_output: default_bindings![alt(KeyCode::Char('r'))],
+                open_cxline_config: default_bindings![],
             },
             chat: ChatKeymap {
                 interrupt_turn: default_bindings![plain(KeyCode::Esc)],
@@ -1175,6 +1188,7 @@ impl RuntimeKeymap {
                 ("toggle_vim_mode", self.app.toggle_vim_mode.as_slice()),
                 ("toggle_fast_mode", self.app.toggle_fast_mode.as_slice()),
                 ("toggle_raw_output", self.app.toggle_raw_output.as_slice()),
+                ("open_cxline_config", self.app.open_cxline_config.as_slice()),
                 ("chat.interrupt_turn", self.chat.interrupt_turn.as_slice()),
                 (
                     "chat.decrease_reasoning_effort",
@@ -1218,6 +1232,7 @@ impl RuntimeKeymap {
                 ("toggle_vim_mode", self.app.toggle_vim_mode.as_slice()),
                 ("toggle_fast_mode", self.app.toggle_fast_mode.as_slice()),
                 ("toggle_raw_output", self.app.toggle_raw_output.as_slice()),
+                ("open_cxline_config", self.app.open_cxline_config.as_slice()),
                 ("chat.interrupt_turn", self.chat.interrupt_turn.as_slice()),
                 (
                     "chat.decrease_reasoning_effort",
@@ -1267,6 +1282,7 @@ impl RuntimeKeymap {
                 ("toggle_vim_mode", self.app.toggle_vim_mode.as_slice()),
                 ("toggle_fast_mode", self.app.toggle_fast_mode.as_slice()),
                 ("toggle_raw_output", self.app.toggle_raw_output.as_slice()),
+                ("open_cxline_config", self.app.open_cxline_config.as_slice()),
             ],
             [
                 ("list.move_up", self.list.move_up.as_slice()),
@@ -1341,6 +1357,7 @@ impl RuntimeKeymap {
                 ("toggle_vim_mode", self.app.toggle_vim_mode.as_slice()),
                 ("toggle_fast_mode", self.app.toggle_fast_mode.as_slice()),
                 ("toggle_raw_output", self.app.toggle_raw_output.as_slice()),
+                ("open_cxline_config", self.app.open_cxline_config.as_slice()),
                 (
                     "composer.history_search_previous",
                     self.composer.history_search_previous.as_slice(),
@@ -2831,6 +2848,22 @@ mod tests {
         );
     }
 
+    // @cometix: statusline appearance overlay shortcut
+    #[test]
+    fn open_cxline_config_defaults_to_unbound_and_can_be_bound() {
+        let runtime = RuntimeKeymap::defaults();
+        assert!(runtime.app.open_cxline_config.is_empty());
+
+        let mut keymap = TuiKeymap::default();
+        keymap.global.open_cxline_config = Some(one("alt-s"));
+
+        let runtime = RuntimeKeymap::from_config(&keymap).expect("config should parse");
+        assert_eq!(
+            runtime.app.open_cxline_config,
+            vec![key_hint::alt(KeyCode::Char('s'))]
+        );
+    }
+
     #[test]
     fn default_editor_insert_newline_includes_current_aliases() {
         let runtime = RuntimeKeymap::defaults();
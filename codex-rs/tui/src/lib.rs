@@ -192,6 +192,13 @@ mod transcript_reflow;
 mod translate_overlay;
 #[allow(dead_code, unused_imports, clippy::all)]
 mod translation;
+// @cometix: translation config types used by the `codex setup translation` wizard
+pub use translation::ProviderId as TranslationProviderId;
+pub use translation::TranslationConfig;
+// @cometix: headless debug translation used by `codex debug translation`
+pub use translation::TranslationDebugReport;
+pub use translation::TranslationError;
+pub use translation::debug_translate;
 mod tui;
 mod ui_consts;
 pub(crate) mod update_action;
@@ -15,6 +15,7 @@
 //! recomputed. `ChatWidget` is responsible for producing a key that changes when the active cell
 //! mutates in place or when its transcript output is time-dependent.
 
+use std::collections::HashMap;
 use std::io::Result;
 use std::sync::Arc;
 
@@ -39,6 +40,7 @@ use crossterm::event::KeyEvent;
 use ratatui::buffer::Buffer;
 use ratatui::buffer::Cell;
 use ratatui::layout::Rect;
+use ratatui::style::Modifier;
 use ratatui::style::Style;
 use ratatui::style::Stylize;
 use ratatui::text::Line;
@@ -97,8 +99,21 @@ impl Overlay {
     }
 
     /// 创建 CxLine 配置 Overlay
-    pub(crate) fn new_cxline(config: crate::statusline::config::CxLineConfig) -> Self {
-        Self::Cxline(Box::new(crate::cxline_overlay::CxlineOverlay::new(config)))
+    pub(crate) fn new_cxline(
+        config: crate::statusline::config::CxLineConfig,
+        live_data: Option<crate::statusline::StatusLinePreviewData>,
+    ) -> Self {
+        Self::Cxline(Box::new(crate::cxline_overlay::CxlineOverlay::new(
+            config, live_data,
+        )))
+    }
+
+    /// 如果是 CxLine Overlay，获取编辑中的工作配置（用于主界面实时预览）
+    pub(crate) fn cxline_working_config(&self) -> Option<crate::statusline::config::CxLineConfig> {
+        match self {
+            Overlay::Cxline(o) => Some(o.working_config()),
+            _ => None,
+        }
     }
 
     /// 如果是 CxLine Overlay，获取配置
@@ -430,11 +445,23 @@ impl Renderable for CachedRenderable {
 struct CellRenderable {
     cell: Arc<dyn HistoryCell>,
     style: Style,
+    /// Cached reasoning title translations (original -> translated) used to map
+    /// known bold titles to their bilingual display form.
+    title_translations: Arc<HashMap<String, String>>,
+}
+
+impl CellRenderable {
+    fn lines(&self, width: u16) -> Vec<HyperlinkLine> {
+        map_known_titles(
+            self.cell.transcript_hyperlink_lines(width),
+            &self.title_translations,
+        )
+    }
 }
 
 impl Renderable for CellRenderable {
     fn render(&self, area: Rect, buf: &mut Buffer) {
-        let hyperlink_lines = self.cell.transcript_hyperlink_lines(area.width);
+        let hyperlink_lines = self.lines(area.width);
         let p = Paragraph::new(Text::from(visible_lines(hyperlink_lines.clone())))
             .style(self.style)
             .wrap(Wrap { trim: false });
@@ -443,10 +470,49 @@ impl Renderable for CellRenderable {
     }
 
     fn desired_height(&self, width: u16) -> u16 {
-        self.cell.desired_transcript_height(width)
+        if self.title_translations.is_empty() {
+            return self.cell.desired_transcript_height(width);
+        }
+        // Bilingual titles are wider than the raw cell content, so measure the
+        // mapped lines rather than asking the cell for its own height.
+        Paragraph::new(Text::from(visible_lines(self.lines(width))))
+            .wrap(Wrap { trim: false })
+            .line_count(width)
+            .try_into()
+            .unwrap_or(/*default*/ 0)
     }
 }
 
+/// Map known reasoning titles in bold display spans to their bilingual
+/// display form, already rendered through the configured title template.
+///
+/// This rewrites only the rendered spans: the underlying cell content used by
+/// copy operations is left untouched, and titles missing from the cache (or
+/// non-bold occurrences of the same text) render unchanged.
+fn map_known_titles(
+    lines: Vec<HyperlinkLine>,
+    title_translations: &HashMap<String, String>,
+) -> Vec<HyperlinkLine> {
+    if title_translations.is_empty() {
+        return lines;
+    }
+    lines
+        .into_iter()
+        .map(|mut line| {
+            for span in line.line.spans.iter_mut() {
+                if !span.style.add_modifier.contains(Modifier::BOLD) {
+                    continue;
+                }
+                let title = span.content.as_ref().trim();
+                if let Some(display) = title_translations.get(title) {
+                    span.content = display.clone().into();
+                }
+            }
+            line
+        })
+        .collect()
+}
+
 struct HyperlinkLinesRenderable {
     lines: Vec<HyperlinkLine>,
 }
@@ -477,6 +543,8 @@ pub(crate) struct TranscriptOverlay {
     /// Committed transcript cells (does not include the live tail).
     cells: Vec<Arc<dyn HistoryCell>>,
     highlight_cell: Option<usize>,
+    /// Cached reasoning title translations shared with the cell renderables.
+    title_translations: Arc<HashMap<String, String>>,
     /// Cache key for the render-only live tail appended after committed cells.
     live_tail_key: Option<LiveTailKey>,
     is_done: bool,
@@ -503,23 +571,39 @@ impl TranscriptOverlay {
     /// This overlay does not own the "active cell"; callers may optionally append a live tail via
     /// `sync_live_tail` during draws to reflect in-flight activity.
     pub(crate) fn new(transcript_cells: Vec<Arc<dyn HistoryCell>>, keymap: PagerKeymap) -> Self {
+        let title_translations = Arc::new(HashMap::new());
         Self {
             view: PagerView::new(
-                Self::render_cells(&transcript_cells, /*highlight_cell*/ None),
+                Self::render_cells(
+                    &transcript_cells,
+                    /*highlight_cell*/ None,
+                    &title_translations,
+                ),
                 "T R A N S C R I P T".to_string(),
                 usize::MAX,
                 keymap,
             ),
             cells: transcript_cells,
             highlight_cell: None,
+            title_translations,
             live_tail_key: None,
             is_done: false,
         }
     }
 
+    /// Supply cached reasoning title translations for bilingual title rendering.
+    ///
+    /// Rebuilds the committed renderables so titles that are already on screen
+    /// pick up the mapping immediately.
+    pub(crate) fn set_title_translations(&mut self, title_translations: HashMap<String, String>) {
+        self.title_translations = Arc::new(title_translations);
+        self.rebuild_renderables();
+    }
+
     fn render_cells(
         cells: &[Arc<dyn HistoryCell>],
         highlight_cell: Option<usize>,
+        title_translations: &Arc<HashMap<String, String>>,
     ) -> Vec<Box<dyn Renderable>> {
         cells
             .iter()
@@ -534,11 +618,13 @@ impl TranscriptOverlay {
                         } else {
                             user_message_style()
                         },
+                        title_translations: title_translations.clone(),
                     })) as Box<dyn Renderable>
                 } else {
                     Box::new(CachedRenderable::new(CellRenderable {
                         cell: c.clone(),
                         style: Style::default(),
+                        title_translations: title_translations.clone(),
                     })) as Box<dyn Renderable>
                 };
                 if !c.is_stream_continuation() && i > 0 {
@@ -570,7 +656,8 @@ impl TranscriptOverlay {
         let had_prior_cells = !self.cells.is_empty();
         let tail_renderable = self.take_live_tail_renderable();
         self.cells.push(cell);
-        self.view.renderables = Self::render_cells(&self.cells, self.highlight_cell);
+        self.view.renderables =
+            Self::render_cells(&self.cells, self.highlight_cell, &self.title_translations);
         if let Some(tail) = tail_renderable {
             let tail = if !had_prior_cells
                 && self
@@ -724,7 +811,8 @@ impl TranscriptOverlay {
 
     fn rebuild_renderables(&mut self) {
         let tail_renderable = self.take_live_tail_renderable();
-        self.view.renderables = Self::render_cells(&self.cells, self.highlight_cell);
+        self.view.renderables =
+            Self::render_cells(&self.cells, self.highlight_cell, &self.title_translations);
         if let Some(tail) = tail_renderable {
             self.view.renderables.push(tail);
         }
@@ -1124,6 +1212,30 @@ mod tests {
         }));
     }
 
+    #[test]
+    fn transcript_maps_cached_titles_to_bilingual_form() {
+        let mut titles = HashMap::new();
+        titles.insert("Thinking".to_string(), "思考中".to_string());
+        let lines = vec![
+            HyperlinkLine::new(Line::from(vec!["• ".into(), "Thinking".bold()])),
+            HyperlinkLine::new(Line::from(vec!["• ".into(), "Planning".bold()])),
+            // Same text without the bold title styling must not be rewritten.
+            HyperlinkLine::new(Line::from("Thinking plain text")),
+        ];
+
+        let mapped = map_known_titles(lines, &titles);
+
+        assert_eq!(
+            mapped[0].line.spans[1].content.as_ref(),
+            "Thinking · 思考中"
+        );
+        assert_eq!(mapped[1].line.spans[1].content.as_ref(), "Planning");
+        assert_eq!(
+            mapped[2].line.spans[0].content.as_ref(),
+            "Thinking plain text"
+        );
+    }
+
     #[test]
     fn transcript_overlay_renders_live_tail() {
         let mut overlay = transcript_overlay(vec![Arc::new(TestCell {
@@ -56,6 +56,7 @@ pub enum SlashCommand {
     Cxline,
     Translate,
     Theme,
+    ReduceMotion,
     #[strum(to_string = "pets", serialize = "pet")]
     Pets,
     Mcp,
@@ -113,6 +114,9 @@ impl SlashCommand {
             SlashCommand::Cxline => "configure statusline appearance",
             SlashCommand::Translate => "configure reasoning translation",
             SlashCommand::Theme => "choose a syntax highlighting theme",
+            SlashCommand::ReduceMotion => {
+                "toggle reduced motion (static spinners, no animation frames)"
+            }
             SlashCommand::Pets => "choose or hide the terminal pet",
             SlashCommand::Ps => "list background terminals",
             SlashCommand::Stop => "stop all background terminals",
@@ -172,6 +176,8 @@ impl SlashCommand {
                 | SlashCommand::Btw
                 | SlashCommand::Resume
                 | SlashCommand::SandboxReadRoot
+                | SlashCommand::Statusline
+                | SlashCommand::Translate
         )
     }
 
@@ -236,6 +242,7 @@ impl SlashCommand {
             | SlashCommand::Plugins
             | SlashCommand::Title
             | SlashCommand::Statusline
+            | SlashCommand::ReduceMotion
             | SlashCommand::AutoReview
             | SlashCommand::Feedback
             | SlashCommand::Ide
@@ -298,12 +305,19 @@ mod tests {
         assert!(SlashCommand::Ide.available_during_task());
         assert!(SlashCommand::Title.available_during_task());
         assert!(SlashCommand::Statusline.available_during_task());
+        assert!(SlashCommand::Statusline.supports_inline_args());
         assert!(SlashCommand::Raw.available_during_task());
         assert!(SlashCommand::Raw.available_in_side_conversation());
         assert!(SlashCommand::Raw.supports_inline_args());
         assert!(SlashCommand::App.available_during_task());
     }
 
+    #[test]
+    fn reduce_motion_command_is_available_during_task() {
+        assert_eq!(SlashCommand::ReduceMotion.command(), "reduce-motion");
+        assert!(SlashCommand::ReduceMotion.available_during_task());
+    }
+
     #[test]
     fn auto_review_command_is_approve() {
         assert_eq!(SlashCommand::AutoReview.command(), "approve");
@@ -70,6 +70,41 @@ pub(crate) struct StatusTokenUsageData {
     context_window: Option<StatusContextWindowData>,
 }
 
+// @cometix: /status section for the cometix statusline, fed by the live
+// `CxLineConfig` so the output cannot drift from what actually renders.
+#[derive(Debug, Clone)]
+pub(crate) struct StatusLineSectionData {
+    /// Active theme name.
+    pub(crate) theme: String,
+    /// Style mode as its config spelling (`plain` / `nerd_font` / `powerline`).
+    pub(crate) style: String,
+    /// Enabled segments in display order.
+    pub(crate) segments: Vec<String>,
+    /// On-disk config file, when a config directory could be resolved.
+    pub(crate) config_path: Option<PathBuf>,
+}
+
+// @cometix: /status section for reasoning translation, fed by the same
+// orchestrator snapshot `/translate status` uses. Carries no API key or
+// daemon arguments so secrets never reach the transcript.
+#[derive(Debug, Clone)]
+pub(crate) struct TranslationSectionData {
+    /// Effective target language code.
+    pub(crate) target_language: String,
+    /// Provider display name, or the daemon program name when one is set.
+    pub(crate) translator: String,
+    /// Effective per-request timeout.
+    pub(crate) timeout_ms: u64,
+    /// Cached reasoning-title translations.
+    pub(crate) title_cache_size: usize,
+    /// Cached UI-notice translations.
+    pub(crate) notice_cache_size: usize,
+    /// Whether a barrier is holding back history cells right now.
+    pub(crate) barrier_active: bool,
+    /// History cells deferred behind the active barrier.
+    pub(crate) deferred_cells: usize,
+}
+
 #[derive(Debug)]
 struct StatusRateLimitState {
     rate_limits: StatusRateLimitData,
@@ -118,6 +153,9 @@ struct StatusHistoryCell {
     session_id: Option<String>,
     forked_from: Option<String>,
     token_usage: StatusTokenUsageData,
+    // @cometix: optional fork-subsystem sections
+    statusline: Option<StatusLineSectionData>,
+    translation: Option<TranslationSectionData>,
     rate_limit_state: Arc<RwLock<StatusRateLimitState>>,
 }
 
@@ -191,6 +229,8 @@ pub(crate) fn new_status_output_with_rate_limits(
         model_name,
         collaboration_mode,
         reasoning_effort_override,
+        /*statusline*/ None,
+        /*translation*/ None,
         "<none>".to_string(),
         refreshing_rate_limits,
     )
@@ -214,6 +254,8 @@ pub(crate) fn new_status_output_with_rate_limits_handle(
     model_name: &str,
     collaboration_mode: Option<&str>,
     reasoning_effort_override: Option<Option<ReasoningEffort>>,
+    statusline: Option<StatusLineSectionData>,
+    translation: Option<TranslationSectionData>,
     agents_summary: String,
     refreshing_rate_limits: bool,
 ) -> (CompositeHistoryCell, StatusHistoryHandle) {
@@ -234,6 +276,8 @@ pub(crate) fn new_status_output_with_rate_limits_handle(
         model_name,
         collaboration_mode,
         reasoning_effort_override,
+        statusline,
+        translation,
         agents_summary,
         refreshing_rate_limits,
     );
@@ -262,6 +306,8 @@ impl StatusHistoryCell {
         model_name: &str,
         collaboration_mode: Option<&str>,
         reasoning_effort_override: Option<Option<ReasoningEffort>>,
+        statusline: Option<StatusLineSectionData>,
+        translation: Option<TranslationSectionData>,
         agents_summary: String,
         refreshing_rate_limits: bool,
     ) -> (Self, StatusHistoryHandle) {
@@ -366,6 +412,8 @@ impl StatusHistoryCell {
                 session_id,
                 forked_from,
                 token_usage,
+                statusline,
+                translation,
                 agents_summary,
                 rate_limit_state: rate_limit_state.clone(),
             },
@@ -775,6 +823,24 @@ impl HistoryCell for StatusHistoryCell {
 
         self.collect_rate_limit_labels(&rate_limit_state, &mut seen, &mut labels);
 
+        // @cometix: labels for the fork-subsystem sections
+        if self.statusline.is_some() {
+            for label in ["Theme", "Segments", "Config file"] {
+                push_label(&mut labels, &mut seen, label);
+            }
+        }
+        if self.translation.is_some() {
+            for label in [
+                "Target language",
+                "Translator",
+                "Timeout",
+                "Caches",
+                "Barrier",
+            ] {
+                push_label(&mut labels, &mut seen, label);
+            }
+        }
+
         let formatter = FieldFormatter::from_labels(labels.iter().map(String::as_str));
         let value_width = formatter.value_width(available_inner_width);
 
@@ -863,6 +929,63 @@ impl HistoryCell for StatusHistoryCell {
 
         lines.extend(self.rate_limit_lines(&rate_limit_state, available_inner_width, &formatter));
 
+        // @cometix: fork-subsystem sections, derived from the same snapshot
+        // structs the statusline and translation overlays work with
+        if let Some(statusline) = self.statusline.as_ref() {
+            lines.push(Line::from(Vec::<Span<'static>>::new()));
+            lines.push(Line::from(vec![Span::from("Statusline").bold()]));
+            lines.push(formatter.line(
+                "Theme",
+                vec![
+                    Span::from(statusline.theme.clone()),
+                    Span::from(format!(" ({})", statusline.style)).dim(),
+                ],
+            ));
+            lines
+                .push(formatter.line("Segments", vec![Span::from(statusline.segments.join(", "))]));
+            if let Some(config_path) = statusline.config_path.as_ref() {
+                lines.push(formatter.line(
+                    "Config file",
+                    vec![Span::from(format_directory_display(
+                        config_path,
+                        Some(value_width),
+                    ))],
+                ));
+            }
+        }
+        if let Some(translation) = self.translation.as_ref() {
+            lines.push(Line::from(Vec::<Span<'static>>::new()));
+            lines.push(Line::from(vec![Span::from("Translation").bold()]));
+            lines.push(formatter.line(
+                "Target language",
+                vec![Span::from(translation.target_language.clone())],
+            ));
+            lines.push(formatter.line(
+                "Translator",
+                vec![Span::from(translation.translator.clone())],
+            ));
+            lines.push(formatter.line(
+                "Timeout",
+                vec![Span::from(format!("{} ms", translation.timeout_ms))],
+            ));
+            lines.push(formatter.line(
+                "Caches",
+                vec![Span::from(format!(
+                    "{} titles, {} notices",
+                    translation.title_cache_size, translation.notice_cache_size
+                ))],
+            ));
+            if translation.barrier_active {
+                lines.push(formatter.line(
+                    "Barrier",
+                    vec![Span::from(format!(
+                        "active ({} deferred cells)",
+                        translation.deferred_cells
+                    ))],
+                ));
+            }
+        }
+
         let content_width = lines.iter().map(line_display_width).max().unwrap_or(0);
         let inner_width = content_width.min(available_inner_width);
         let truncated_lines: Vec<Line<'static>> = lines
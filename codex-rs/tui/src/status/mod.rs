@@ -15,6 +15,9 @@ pub(crate) mod remote_connection;
 
 pub(crate) use account::StatusAccountDisplay;
 pub(crate) use card::StatusHistoryHandle;
+// @cometix: /status sections for the fork's statusline and translation
+pub(crate) use card::StatusLineSectionData;
+pub(crate) use card::TranslationSectionData;
 #[cfg(test)]
 pub(crate) use card::new_status_output;
 #[cfg(test)]
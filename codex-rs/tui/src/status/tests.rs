@@ -12,6 +12,8 @@ use crate::legacy_core::config::Config;
 use crate::legacy_core::config::ConfigBuilder;
 use crate::legacy_core::config::PermissionProfileSnapshot;
 use crate::status::StatusAccountDisplay;
+use crate::status::StatusLineSectionData;
+use crate::status::TranslationSectionData;
 use crate::status::remote_connection::RemoteConnectionStatus;
 use crate::test_support::PathBufExt;
 use crate::test_support::test_path_buf;
@@ -734,6 +736,8 @@ async fn status_model_provider_uses_bedrock_runtime_base_url_and_gates_usage_lin
         &model_slug,
         /*collaboration_mode*/ None,
         /*reasoning_effort_override*/ None,
+        /*statusline*/ None,
+        /*translation*/ None,
         "<none>".to_string(),
         /*refreshing_rate_limits*/ false,
     );
@@ -775,6 +779,8 @@ async fn status_model_provider_uses_bedrock_runtime_base_url_and_gates_usage_lin
         &model_slug,
         /*collaboration_mode*/ None,
         /*reasoning_effort_override*/ None,
+        /*statusline*/ None,
+        /*translation*/ None,
         "<none>".to_string(),
         /*refreshing_rate_limits*/ false,
     );
@@ -1565,6 +1571,8 @@ async fn status_snapshot_uses_default_reasoning_when_config_empty() {
         &model_slug,
         /*collaboration_mode*/ None,
         /*reasoning_effort_override*/ Some(Some(ReasoningEffort::Medium)),
+        /*statusline*/ None,
+        /*translation*/ None,
         "<none>".to_string(),
         /*refreshing_rate_limits*/ false,
     );
@@ -2036,3 +2044,91 @@ async fn status_context_window_uses_last_usage() {
         "context line should not use total aggregated tokens, got: {context_line}"
     );
 }
+
+#[tokio::test]
+async fn status_renders_statusline_and_translation_sections_when_configured() {
+    let temp_home = TempDir::new().expect("temp home");
+    let config = test_config(&temp_home).await;
+    let usage = TokenUsage::default();
+    let now = chrono::Local
+        .with_ymd_and_hms(2024, 3, 4, 5, 6, 7)
+        .single()
+        .expect("timestamp");
+    let model_slug = get_model_offline_for_tests(config.model.as_deref());
+
+    let statusline = StatusLineSectionData {
+        theme: "cometix".to_string(),
+        style: "powerline".to_string(),
+        segments: vec!["model".to_string(), "git".to_string(), "usage".to_string()],
+        config_path: None,
+    };
+    let translation = TranslationSectionData {
+        target_language: "zh-CN".to_string(),
+        translator: "DeepSeek".to_string(),
+        timeout_ms: 30000,
+        title_cache_size: 3,
+        notice_cache_size: 1,
+        barrier_active: true,
+        deferred_cells: 2,
+    };
+
+    let (composite, _handle) = new_status_output_with_rate_limits_handle(
+        &config,
+        /*runtime_model_provider_base_url*/ None,
+        /*remote_connection*/ None,
+        /*account_display*/ None,
+        /*token_info*/ None,
+        &usage,
+        &None,
+        /*thread_name*/ None,
+        /*forked_from*/ None,
+        /*rate_limits*/ &[],
+        None,
+        now,
+        &model_slug,
+        /*collaboration_mode*/ None,
+        /*reasoning_effort_override*/ None,
+        Some(statusline),
+        Some(translation),
+        "<none>".to_string(),
+        /*refreshing_rate_limits*/ false,
+    );
+    let rendered = render_lines(&composite.display_lines(/*width*/ 120)).join("\n");
+
+    assert!(rendered.contains("Statusline"), "{rendered}");
+    assert!(rendered.contains("cometix (powerline)"), "{rendered}");
+    assert!(rendered.contains("model, git, usage"), "{rendered}");
+    assert!(rendered.contains("Translation"), "{rendered}");
+    assert!(rendered.contains("zh-CN"), "{rendered}");
+    assert!(rendered.contains("DeepSeek"), "{rendered}");
+    assert!(rendered.contains("30000 ms"), "{rendered}");
+    assert!(rendered.contains("3 titles, 1 notices"), "{rendered}");
+    assert!(rendered.contains("active (2 deferred cells)"), "{rendered}");
+
+    let (composite, _handle) = new_status_output_with_rate_limits_handle(
+        &config,
+        /*runtime_model_provider_base_url*/ None,
+        /*remote_connection*/ None,
+        /*account_display*/ None,
+        /*token_info*/ None,
+        &usage,
+        &None,
+        /*thread_name*/ None,
+        /*forked_from*/ None,
+        /*rate_limits*/ &[],
+        None,
+        now,
+        &model_slug,
+        /*collaboration_mode*/ None,
+        /*reasoning_effort_override*/ None,
+        /*statusline*/ None,
+        /*translation*/ None,
+        "<none>".to_string(),
+        /*refreshing_rate_limits*/ false,
+    );
+    let rendered = render_lines(&composite.display_lines(/*width*/ 120)).join("\n");
+
+    assert!(!rendered.contains("Statusline"), "{rendered}");
+    assert!(!rendered.contains("Translation"), "{rendered}");
+    assert!(!rendered.contains("Target language"), "{rendered}");
+}
@@ -158,6 +158,10 @@ impl StatusIndicatorWidget {
         self.interrupt_binding = binding;
     }
 
+    pub(crate) fn set_animations_enabled(&mut self, enabled: bool) {
+        self.animations_enabled = enabled;
+    }
+
     pub(crate) fn pause_timer(&mut self) {
         self.pause_timer_at(Instant::now());
     }
@@ -415,6 +419,30 @@ mod tests {
         assert!(line.starts_with("Working (0s • esc to interrupt)"));
     }
 
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn reduced_motion_render_schedules_no_frames() {
+        let (tx_raw, _rx) = unbounded_channel::<AppEvent>();
+        let tx = AppEventSender::new(tx_raw);
+        let (draw_tx, mut draw_rx) = tokio::sync::broadcast::channel(16);
+        let mut w = StatusIndicatorWidget::new(
+            tx,
+            crate::tui::FrameRequester::new(draw_tx),
+            /*animations_enabled*/ true,
+        );
+        w.set_animations_enabled(false);
+
+        let mut terminal = Terminal::new(TestBackend::new(80, 2)).expect("terminal");
+        terminal
+            .draw(|f| w.render(f.area(), f.buffer_mut()))
+            .expect("draw");
+
+        tokio::time::advance(Duration::from_millis(100)).await;
+        assert!(
+            draw_rx.try_recv().is_err(),
+            "reduced motion should not schedule animation frames"
+        );
+    }
+
     #[test]
     fn renders_remapped_interrupt_hint() {
         let (tx_raw, _rx) = unbounded_channel::<AppEvent>();
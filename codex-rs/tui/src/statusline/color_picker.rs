@@ -12,6 +12,12 @@ use ratatui::widgets::Clear;
 use ratatui::widgets::Paragraph;
 use ratatui::widgets::Widget;
 
+use serde::Deserialize;
+use serde::Serialize;
+use std::fs;
+use std::path::PathBuf;
+
+use super::config::CxLineConfig;
 use super::style::AnsiColor;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -19,6 +25,190 @@ pub enum ColorPickerMode {
     Basic16,
     Extended256,
     RgbInput,
+    Recent,
+    Palette,
+}
+
+/// Recent 颜色行最多保留的数量
+const MAX_RECENT_COLORS: usize = 8;
+
+/// 命名调色板预设
+pub const PALETTES: &[(&str, &[AnsiColor])] = &[
+    (
+        "solarized",
+        &[
+            AnsiColor::Rgb {
+                r: 0xb5,
+                g: 0x89,
+                b: 0x00,
+            },
+            AnsiColor::Rgb {
+                r: 0xcb,
+                g: 0x4b,
+                b: 0x16,
+            },
+            AnsiColor::Rgb {
+                r: 0xdc,
+                g: 0x32,
+                b: 0x2f,
+            },
+            AnsiColor::Rgb {
+                r: 0xd3,
+                g: 0x36,
+                b: 0x82,
+            },
+            AnsiColor::Rgb {
+                r: 0x6c,
+                g: 0x71,
+                b: 0xc4,
+            },
+            AnsiColor::Rgb {
+                r: 0x26,
+                g: 0x8b,
+                b: 0xd2,
+            },
+            AnsiColor::Rgb {
+                r: 0x2a,
+                g: 0xa1,
+                b: 0x98,
+            },
+            AnsiColor::Rgb {
+                r: 0x85,
+                g: 0x99,
+                b: 0x00,
+            },
+        ],
+    ),
+    (
+        "catppuccin",
+        &[
+            AnsiColor::Rgb {
+                r: 0xf3,
+                g: 0x8b,
+                b: 0xa8,
+            },
+            AnsiColor::Rgb {
+                r: 0xfa,
+                g: 0xb3,
+                b: 0x87,
+            },
+            AnsiColor::Rgb {
+                r: 0xf9,
+                g: 0xe2,
+                b: 0xaf,
+            },
+            AnsiColor::Rgb {
+                r: 0xa6,
+                g: 0xe3,
+                b: 0xa1,
+            },
+            AnsiColor::Rgb {
+                r: 0x94,
+                g: 0xe2,
+                b: 0xd5,
+            },
+            AnsiColor::Rgb {
+                r: 0x89,
+                g: 0xb4,
+                b: 0xfa,
+            },
+            AnsiColor::Rgb {
+                r: 0xb4,
+                g: 0xbe,
+                b: 0xfe,
+            },
+            AnsiColor::Rgb {
+                r: 0xcb,
+                g: 0xa6,
+                b: 0xf7,
+            },
+        ],
+    ),
+    (
+        "gruvbox",
+        &[
+            AnsiColor::Rgb {
+                r: 0xcc,
+                g: 0x24,
+                b: 0x1d,
+            },
+            AnsiColor::Rgb {
+                r: 0x98,
+                g: 0x97,
+                b: 0x1a,
+            },
+            AnsiColor::Rgb {
+                r: 0xd7,
+                g: 0x99,
+                b: 0x21,
+            },
+            AnsiColor::Rgb {
+                r: 0x45,
+                g: 0x85,
+                b: 0x88,
+            },
+            AnsiColor::Rgb {
+                r: 0xb1,
+                g: 0x62,
+                b: 0x86,
+            },
+            AnsiColor::Rgb {
+                r: 0x68,
+                g: 0x9d,
+                b: 0x6a,
+            },
+            AnsiColor::Rgb {
+                r: 0xd6,
+                g: 0x5d,
+                b: 0x0e,
+            },
+            AnsiColor::Rgb {
+                r: 0xeb,
+                g: 0xdb,
+                b: 0xb2,
+            },
+        ],
+    ),
+];
+
+/// Recent 颜色的持久化格式（sidecar 文件）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct RecentColorsFile {
+    #[serde(default)]
+    colors: Vec<AnsiColor>,
+}
+
+/// Recent 颜色文件路径
+fn recent_colors_path() -> Option<PathBuf> {
+    CxLineConfig::config_dir().map(|dir| dir.join("recent_colors.toml"))
+}
+
+/// 从 sidecar 文件加载最近使用的颜色
+fn load_recent_colors() -> Vec<AnsiColor> {
+    let Some(path) = recent_colors_path() else {
+        return Vec::new();
+    };
+    let Ok(content) = fs::read_to_string(&path) else {
+        return Vec::new();
+    };
+    toml::from_str::<RecentColorsFile>(&content)
+        .map(|f| f.colors)
+        .unwrap_or_default()
+}
+
+/// 保存最近使用的颜色到 sidecar 文件
+fn save_recent_colors(colors: &[AnsiColor]) {
+    if let Some(path) = recent_colors_path() {
+        if let Some(parent) = path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        let file = RecentColorsFile {
+            colors: colors.to_vec(),
+        };
+        if let Ok(content) = toml::to_string_pretty(&file) {
+            let _ = fs::write(&path, content);
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -68,6 +258,13 @@ pub struct ColorPicker {
     pub target_field: ColorTarget,
     pub cached_basic_cols: usize,
     pub cached_extended_cols: usize,
+    /// 最近应用过的颜色（最新的在前）
+    pub recent_colors: Vec<AnsiColor>,
+    pub selected_recent: usize,
+    pub selected_palette: usize,
+    pub selected_palette_color: usize,
+    /// 预览底色（当前 segment 的背景色）
+    pub preview_bg: Option<AnsiColor>,
 }
 
 impl Default for ColorPicker {
@@ -82,12 +279,22 @@ impl Default for ColorPicker {
             target_field: ColorTarget::IconColor,
             cached_basic_cols: 8,
             cached_extended_cols: 8,
+            recent_colors: Vec::new(),
+            selected_recent: 0,
+            selected_palette: 0,
+            selected_palette_color: 0,
+            preview_bg: None,
         }
     }
 }
 
 impl ColorPicker {
-    pub fn open(&mut self, target: ColorTarget, current: Option<AnsiColor>) {
+    pub fn open(
+        &mut self,
+        target: ColorTarget,
+        current: Option<AnsiColor>,
+        preview_bg: Option<AnsiColor>,
+    ) {
         self.is_open = true;
         self.target_field = target;
         self.mode = ColorPickerMode::Basic16;
@@ -95,18 +302,51 @@ impl ColorPicker {
         self.selected_extended = 0;
         self.rgb_input = RgbInput::default();
         self.current_color = current;
+        self.recent_colors = load_recent_colors();
+        self.selected_recent = 0;
+        self.selected_palette = 0;
+        self.selected_palette_color = 0;
+        self.preview_bg = preview_bg;
     }
 
     pub fn close(&mut self) {
         self.is_open = false;
     }
 
+    /// 记录一个已应用的颜色到 recent 行并持久化
+    pub fn remember_color(&mut self, color: AnsiColor) {
+        self.recent_colors.retain(|c| *c != color);
+        self.recent_colors.insert(0, color);
+        self.recent_colors.truncate(MAX_RECENT_COLORS);
+        save_recent_colors(&self.recent_colors);
+    }
+
     pub fn cycle_mode(&mut self) {
         self.mode = match self.mode {
             ColorPickerMode::Basic16 => ColorPickerMode::Extended256,
             ColorPickerMode::Extended256 => ColorPickerMode::RgbInput,
-            ColorPickerMode::RgbInput => ColorPickerMode::Basic16,
+            ColorPickerMode::RgbInput => ColorPickerMode::Recent,
+            ColorPickerMode::Recent => ColorPickerMode::Palette,
+            ColorPickerMode::Palette => ColorPickerMode::Basic16,
         };
+        // 进入新模式时同步候选颜色
+        match self.mode {
+            ColorPickerMode::Recent => {
+                if let Some(color) = self.recent_colors.get(self.selected_recent) {
+                    self.current_color = Some(*color);
+                }
+            }
+            ColorPickerMode::Palette => {
+                self.current_color = Some(self.palette_color());
+            }
+            _ => {}
+        }
+    }
+
+    /// 当前选中的调色板颜色
+    fn palette_color(&self) -> AnsiColor {
+        let (_, colors) = PALETTES[self.selected_palette % PALETTES.len()];
+        colors[self.selected_palette_color % colors.len()]
     }
 
     pub fn move_horizontal(&mut self, delta: i32) {
@@ -147,6 +387,28 @@ impl ColorPicker {
                     (RgbField::Hex, false) => RgbField::Blue,
                 };
             }
+            ColorPickerMode::Recent => {
+                if self.recent_colors.is_empty() {
+                    return;
+                }
+                let len = self.recent_colors.len();
+                self.selected_recent = if delta > 0 {
+                    (self.selected_recent + 1) % len
+                } else {
+                    (self.selected_recent + len - 1) % len
+                };
+                self.current_color = Some(self.recent_colors[self.selected_recent]);
+            }
+            ColorPickerMode::Palette => {
+                let (_, colors) = PALETTES[self.selected_palette % PALETTES.len()];
+                let len = colors.len();
+                self.selected_palette_color = if delta > 0 {
+                    (self.selected_palette_color + 1) % len
+                } else {
+                    (self.selected_palette_color + len - 1) % len
+                };
+                self.current_color = Some(self.palette_color());
+            }
         }
     }
 
@@ -197,6 +459,18 @@ impl ColorPicker {
                 self.current_color = Some(AnsiColor::c256(self.selected_extended as u8));
             }
             ColorPickerMode::RgbInput => {}
+            ColorPickerMode::Recent => {}
+            ColorPickerMode::Palette => {
+                let count = PALETTES.len();
+                self.selected_palette = if delta > 0 {
+                    (self.selected_palette + 1) % count
+                } else {
+                    (self.selected_palette + count - 1) % count
+                };
+                let (_, colors) = PALETTES[self.selected_palette];
+                self.selected_palette_color = self.selected_palette_color.min(colors.len() - 1);
+                self.current_color = Some(self.palette_color());
+            }
         }
     }
 
@@ -299,11 +573,21 @@ impl ColorPicker {
         .areas(inner);
 
         // Mode selector
-        let mode_text = match self.mode {
-            ColorPickerMode::Basic16 => "[•] Basic (16)  [ ] Extended (256)  [ ] RGB",
-            ColorPickerMode::Extended256 => "[ ] Basic (16)  [•] Extended (256)  [ ] RGB",
-            ColorPickerMode::RgbInput => "[ ] Basic (16)  [ ] Extended (256)  [•] RGB",
-        };
+        let modes = [
+            (ColorPickerMode::Basic16, "Basic"),
+            (ColorPickerMode::Extended256, "256"),
+            (ColorPickerMode::RgbInput, "RGB"),
+            (ColorPickerMode::Recent, "Recent"),
+            (ColorPickerMode::Palette, "Palette"),
+        ];
+        let mode_text = modes
+            .iter()
+            .map(|(mode, label)| {
+                let marker = if *mode == self.mode { "•" } else { " " };
+                format!("[{marker}] {label}")
+            })
+            .collect::<Vec<_>>()
+            .join("  ");
         Paragraph::new(mode_text)
             .block(Block::default().borders(Borders::ALL).title("Mode"))
             .render(mode_area, buf);
@@ -313,6 +597,8 @@ impl ColorPicker {
             ColorPickerMode::Basic16 => self.render_basic_colors(content_area, buf),
             ColorPickerMode::Extended256 => self.render_extended_colors(content_area, buf),
             ColorPickerMode::RgbInput => self.render_rgb_input(content_area, buf),
+            ColorPickerMode::Recent => self.render_recent_colors(content_area, buf),
+            ColorPickerMode::Palette => self.render_palette(content_area, buf),
         }
 
         // Preview
@@ -489,6 +775,74 @@ impl ColorPicker {
         }
     }
 
+    fn render_recent_colors(&self, area: Rect, buf: &mut Buffer) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title("Recent Colors");
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        if self.recent_colors.is_empty() {
+            buf.set_string(
+                inner.x,
+                inner.y,
+                "No recent colors yet",
+                Style::default().fg(Color::DarkGray),
+            );
+            return;
+        }
+
+        for (i, color) in self.recent_colors.iter().enumerate() {
+            let x = inner.x + (i * 7) as u16;
+            if x >= inner.x + inner.width {
+                break;
+            }
+
+            let is_selected = i == self.selected_recent;
+            let text = if is_selected {
+                "[ ██ ]"
+            } else {
+                "  ██  "
+            };
+            buf.set_string(
+                x,
+                inner.y,
+                text,
+                Style::default().fg(color.to_ratatui_color()),
+            );
+        }
+    }
+
+    fn render_palette(&self, area: Rect, buf: &mut Buffer) {
+        let (name, colors) = PALETTES[self.selected_palette % PALETTES.len()];
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!("Palette: {name} (↑↓ switch)"));
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        for (i, color) in colors.iter().enumerate() {
+            let x = inner.x + (i * 7) as u16;
+            if x >= inner.x + inner.width {
+                break;
+            }
+
+            let is_selected = i == self.selected_palette_color;
+            let text = if is_selected {
+                "[ ██ ]"
+            } else {
+                "  ██  "
+            };
+            buf.set_string(
+                x,
+                inner.y,
+                text,
+                Style::default().fg(color.to_ratatui_color()),
+            );
+        }
+    }
+
     fn render_preview(&self, area: Rect, buf: &mut Buffer) {
         let preview_text = if let Some(color) = &self.current_color {
             match color {
@@ -507,8 +861,14 @@ impl ColorPicker {
             .map(super::style::AnsiColor::to_ratatui_color)
             .unwrap_or(Color::White);
 
+        // 预览色块衬在当前 segment 的背景色上，贴近实际渲染效果
+        let mut style = Style::default().fg(color);
+        if let Some(bg) = self.preview_bg {
+            style = style.bg(bg.to_ratatui_color());
+        }
+
         Paragraph::new(preview_text)
-            .style(Style::default().fg(color))
+            .style(style)
             .block(Block::default().borders(Borders::ALL).title("Preview"))
             .render(area, buf);
     }
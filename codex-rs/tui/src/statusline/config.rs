@@ -1,9 +1,13 @@
 // 状态栏配置
 // 配置文件位置：~/.codex/cxline/config.toml
 
+use super::locale::NumberLocale;
+use super::rules::SegmentRule;
 use super::segment::SegmentId;
 use super::style::ColorConfig;
+use super::style::ColorDepth;
 use super::style::IconConfig;
+use super::style::SeparatorBg;
 use super::style::StyleMode;
 use super::style::TextStyleConfig;
 use super::themes::ThemePresets;
@@ -14,7 +18,7 @@ use std::fs;
 use std::path::PathBuf;
 
 /// 状态栏配置
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CxLineConfig {
     /// 是否启用状态栏
     #[serde(default = "default_true")]
@@ -28,15 +32,59 @@ pub struct CxLineConfig {
     #[serde(default)]
     pub style: StyleMode,
 
+    /// 颜色深度："auto"（跟随终端能力探测）/ "16" / "256" / "truecolor"
+    /// 低深度终端上 RGB / 256 色会降映射到最近的可用颜色
+    #[serde(default)]
+    pub color_depth: ColorDepth,
+
+    /// 数字格式 locale（"en-US" / "de-DE" / "zh-CN"）
+    /// 控制 token 数、百分比等显示的小数点与千位分组分隔符
+    #[serde(default)]
+    pub locale: NumberLocale,
+
     /// 分隔符（仅 Plain/NerdFont 模式使用）
+    /// 旧的单一分隔符字段；separators.inner 未设置时作为回退
     #[serde(default = "default_separator")]
     pub separator: String,
 
+    /// 分位置分隔符（行首 / segment 之间 / 行尾）
+    #[serde(default)]
+    pub separators: SeparatorsConfig,
+
+    /// 分隔符背景策略（仅 Plain/NerdFont 模式使用）
+    #[serde(default)]
+    pub separator_bg: SeparatorBg,
+
+    /// 配置界面的主题快速选择键位
+    #[serde(default)]
+    pub theme_quick_select: ThemeQuickSelect,
+
+    /// Segment 显示顺序
+    #[serde(default = "default_segment_order")]
+    pub segment_order: Vec<SegmentId>,
+
+    /// 用户显式覆盖过 enabled 的 segment（主题切换时保留其状态）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub enabled_overrides: Vec<SegmentId>,
+
     /// 各 segment 配置
     #[serde(default)]
     pub segments: SegmentsConfig,
 }
 
+/// 默认 segment 顺序
+/// Alert 放最前：失焦提醒点亮时最显眼
+pub(crate) fn default_segment_order() -> Vec<SegmentId> {
+    vec![
+        SegmentId::Alert,
+        SegmentId::Model,
+        SegmentId::Directory,
+        SegmentId::Git,
+        SegmentId::Context,
+        SegmentId::Usage,
+    ]
+}
+
 fn default_true() -> bool {
     true
 }
@@ -49,8 +97,55 @@ fn default_separator() -> String {
     " │ ".to_string()
 }
 
+/// 配置界面的主题快速选择键位
+/// alt_digit: Alt+数字；leader: 先按 t 再按数字（裸数字留给字段编辑器）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ThemeQuickSelect {
+    #[default]
+    AltDigit,
+    Leader,
+}
+
+/// segment 的点击动作（鼠标支持接入后由命中测试分发）
+/// 默认 none：不响应点击，行为与没有鼠标支持时一致
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SegmentClickAction {
+    /// 不响应点击
+    #[default]
+    None,
+    /// 打开模型选择弹窗
+    OpenModelPicker,
+    /// 复制当前工作目录到剪贴板
+    CopyCwd,
+    /// 复制当前 git 分支名到剪贴板
+    CopyGitBranch,
+    /// 查看用量 / 限额信息（等价 /usage）
+    ShowUsage,
+    /// 触发 /compact 压缩对话
+    Compact,
+}
+
+/// 分位置分隔符配置
+/// 三个槽位都可选；inner 未设置时回退到旧的单一 separator 字段
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct SeparatorsConfig {
+    /// 行首（第一个 segment 之前）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub left_cap: Option<String>,
+
+    /// 相邻 segment 之间
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inner: Option<String>,
+
+    /// 行尾（最后一个 segment 之后）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub right_cap: Option<String>,
+}
+
 /// 各 segment 的配置
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SegmentsConfig {
     #[serde(default = "SegmentItemConfig::default_model")]
     pub model: SegmentItemConfig,
@@ -66,6 +161,9 @@ pub struct SegmentsConfig {
 
     #[serde(default = "SegmentItemConfig::default_usage")]
     pub usage: SegmentItemConfig,
+
+    #[serde(default = "SegmentItemConfig::default_alert")]
+    pub alert: SegmentItemConfig,
 }
 
 impl Default for SegmentsConfig {
@@ -76,7 +174,7 @@ impl Default for SegmentsConfig {
 }
 
 /// 单个 segment 的配置
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SegmentItemConfig {
     /// Segment ID
     #[serde(default)]
@@ -101,6 +199,14 @@ pub struct SegmentItemConfig {
     /// 自定义选项
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub options: HashMap<String, serde_json::Value>,
+
+    /// 条件样式规则（按序匹配 SegmentData.metadata，命中的第一条覆盖配色）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rules: Vec<SegmentRule>,
+
+    /// 点击动作（见 [`SegmentClickAction`]）
+    #[serde(default)]
+    pub on_click: SegmentClickAction,
 }
 
 impl SegmentItemConfig {
@@ -123,6 +229,10 @@ impl SegmentItemConfig {
     pub fn default_usage() -> Self {
         ThemePresets::get_default().segments.usage
     }
+
+    pub fn default_alert() -> Self {
+        ThemePresets::get_default().segments.alert
+    }
 }
 
 impl Default for CxLineConfig {
@@ -176,7 +286,10 @@ impl CxLineConfig {
 
         match fs::read_to_string(&path) {
             Ok(content) => match toml::from_str::<CxLineConfig>(&content) {
-                Ok(config) => config,
+                Ok(mut config) => {
+                    config.validate_rules();
+                    config
+                }
                 Err(e) => {
                     tracing::warn!("解析 cxline 配置失败: {}, 使用默认配置", e);
                     Self::default()
@@ -210,12 +323,64 @@ impl CxLineConfig {
     }
 
     /// 应用主题
+    /// 主题携带每个 segment 的 enabled 默认值和显示顺序；
+    /// 用户显式覆盖过的 enabled 状态在切换主题时保留
     pub fn apply_theme(&mut self, theme_name: &str) {
         let theme = ThemePresets::get_theme(theme_name);
+        let preserved: Vec<(SegmentId, bool)> = self
+            .enabled_overrides
+            .iter()
+            .map(|&id| (id, self.get_segment_config(id).enabled))
+            .collect();
+
         self.theme = theme_name.to_string();
         self.style = theme.style;
         self.separator = theme.separator;
+        self.separators = theme.separators;
+        self.separator_bg = theme.separator_bg;
         self.segments = theme.segments;
+        self.segment_order = theme.segment_order;
+
+        for (id, enabled) in preserved {
+            self.get_segment_config_mut(id).enabled = enabled;
+        }
+    }
+
+    /// 校验所有 segment 的条件样式规则，丢弃无效规则
+    /// 警告信息带精确路径（如 `segments.git.rules[0]`），其余配置不受影响
+    pub fn validate_rules(&mut self) {
+        for id in default_segment_order() {
+            let segment = self.get_segment_config_mut(id);
+            let mut index = 0;
+            segment.rules.retain(|rule| {
+                let path = format!("segments.{}.rules[{}]", id.as_str(), index);
+                index += 1;
+                match rule.validate() {
+                    Ok(()) => true,
+                    Err(reason) => {
+                        tracing::warn!("cxline 配置 {path} 无效: {reason}, 已忽略该规则");
+                        false
+                    }
+                }
+            });
+        }
+    }
+
+    /// 相邻 segment 之间的分隔符（separators.inner 优先，回退旧字段）
+    pub fn inner_separator(&self) -> &str {
+        self.separators.inner.as_deref().unwrap_or(&self.separator)
+    }
+
+    /// 标记某个 segment 的 enabled 状态为用户显式覆盖
+    pub fn mark_enabled_override(&mut self, id: SegmentId) {
+        if !self.enabled_overrides.contains(&id) {
+            self.enabled_overrides.push(id);
+        }
+    }
+
+    /// 清除所有 enabled 覆盖标记（用于 reset 回主题默认值）
+    pub fn clear_enabled_overrides(&mut self) {
+        self.enabled_overrides.clear();
     }
 
     /// 获取指定 segment 的配置
@@ -226,6 +391,7 @@ impl CxLineConfig {
             SegmentId::Git => &self.segments.git,
             SegmentId::Context => &self.segments.context,
             SegmentId::Usage => &self.segments.usage,
+            SegmentId::Alert => &self.segments.alert,
         }
     }
 
@@ -237,6 +403,7 @@ impl CxLineConfig {
             SegmentId::Git => &mut self.segments.git,
             SegmentId::Context => &mut self.segments.context,
             SegmentId::Usage => &mut self.segments.usage,
+            SegmentId::Alert => &mut self.segments.alert,
         }
     }
 }
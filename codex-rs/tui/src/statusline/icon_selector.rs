@@ -1,4 +1,6 @@
 // 图标选择器组件
+// 图标数据为结构化表（glyph / 名称 / 分类 / 适用样式），
+// 支持分类页签、`/` 增量过滤和 PgUp/PgDn 翻页
 
 use ratatui::buffer::Buffer;
 use ratatui::layout::Constraint;
@@ -7,6 +9,8 @@ use ratatui::layout::Rect;
 use ratatui::style::Color;
 use ratatui::style::Modifier;
 use ratatui::style::Style;
+use ratatui::text::Line;
+use ratatui::text::Span;
 use ratatui::widgets::Block;
 use ratatui::widgets::Borders;
 use ratatui::widgets::Clear;
@@ -14,29 +18,216 @@ use ratatui::widgets::Paragraph;
 use ratatui::widgets::Widget;
 
 use super::color_picker::centered_rect;
+use super::style::ColorConfig;
 use super::style::StyleMode;
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum IconStyle {
     Plain,
     NerdFont,
 }
 
+/// 图标分类（页签）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IconCategory {
+    Git,
+    Files,
+    System,
+    Weather,
+    Shapes,
+}
+
+impl IconCategory {
+    pub const ALL: [IconCategory; 5] = [
+        IconCategory::Git,
+        IconCategory::Files,
+        IconCategory::System,
+        IconCategory::Weather,
+        IconCategory::Shapes,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            IconCategory::Git => "git",
+            IconCategory::Files => "files",
+            IconCategory::System => "system",
+            IconCategory::Weather => "weather",
+            IconCategory::Shapes => "shapes",
+        }
+    }
+}
+
+/// 图标表条目
 #[derive(Debug, Clone)]
-pub struct IconInfo {
-    pub icon: &'static str,
+pub struct IconEntry {
+    pub glyph: &'static str,
     pub name: &'static str,
+    pub category: IconCategory,
+    /// 该图标适用的样式
+    pub style: IconStyle,
+}
+
+/// 每页显示的图标数量（PgUp/PgDn 的步长）
+const PAGE_SIZE: usize = 10;
+
+/// 结构化图标表
+pub const ICON_TABLE: &[IconEntry] = &[
+    // Emoji / Plain
+    icon(
+        "🤖",
+        "Robot (Model)",
+        IconCategory::System,
+        IconStyle::Plain,
+    ),
+    icon("💻", "Laptop", IconCategory::System, IconStyle::Plain),
+    icon("🔧", "Wrench", IconCategory::System, IconStyle::Plain),
+    icon("📊", "Bar Chart", IconCategory::System, IconStyle::Plain),
+    icon("📁", "Folder", IconCategory::Files, IconStyle::Plain),
+    icon("📂", "Open Folder", IconCategory::Files, IconStyle::Plain),
+    icon("🌿", "Branch (Git)", IconCategory::Git, IconStyle::Plain),
+    icon("🌱", "Seedling", IconCategory::Git, IconStyle::Plain),
+    icon("⚡", "Lightning", IconCategory::Weather, IconStyle::Plain),
+    icon("🔥", "Fire", IconCategory::Weather, IconStyle::Plain),
+    icon("☀️", "Sun", IconCategory::Weather, IconStyle::Plain),
+    icon("☁️", "Cloud", IconCategory::Weather, IconStyle::Plain),
+    icon("⭐", "Star", IconCategory::Shapes, IconStyle::Plain),
+    icon("✨", "Sparkles", IconCategory::Shapes, IconStyle::Plain),
+    icon("💎", "Gem", IconCategory::Shapes, IconStyle::Plain),
+    icon("✓", "Check", IconCategory::Shapes, IconStyle::Plain),
+    icon("●", "Circle", IconCategory::Shapes, IconStyle::Plain),
+    icon("▶", "Play", IconCategory::Shapes, IconStyle::Plain),
+    // Nerd Font
+    icon(
+        "\u{e26d}",
+        "Robot (Model)",
+        IconCategory::System,
+        IconStyle::NerdFont,
+    ),
+    icon("\u{f013}", "Cog", IconCategory::System, IconStyle::NerdFont),
+    icon(
+        "\u{f015}",
+        "Home",
+        IconCategory::System,
+        IconStyle::NerdFont,
+    ),
+    icon(
+        "\u{f135}",
+        "Rocket",
+        IconCategory::System,
+        IconStyle::NerdFont,
+    ),
+    icon(
+        "\u{f017}",
+        "Clock",
+        IconCategory::System,
+        IconStyle::NerdFont,
+    ),
+    icon(
+        "\u{f080}",
+        "Bar Chart",
+        IconCategory::System,
+        IconStyle::NerdFont,
+    ),
+    icon(
+        "\u{f49b}",
+        "Chart",
+        IconCategory::System,
+        IconStyle::NerdFont,
+    ),
+    icon(
+        "\u{f0c9}",
+        "List",
+        IconCategory::System,
+        IconStyle::NerdFont,
+    ),
+    icon(
+        "\u{f024b}",
+        "Folder",
+        IconCategory::Files,
+        IconStyle::NerdFont,
+    ),
+    icon(
+        "\u{f07b}",
+        "Folder Open",
+        IconCategory::Files,
+        IconStyle::NerdFont,
+    ),
+    icon("\u{f121}", "Code", IconCategory::Files, IconStyle::NerdFont),
+    icon(
+        "\u{f02a2}",
+        "Git Branch",
+        IconCategory::Git,
+        IconStyle::NerdFont,
+    ),
+    icon(
+        "\u{f126}",
+        "Code Fork",
+        IconCategory::Git,
+        IconStyle::NerdFont,
+    ),
+    icon(
+        "\u{f0e7}",
+        "Lightning",
+        IconCategory::Weather,
+        IconStyle::NerdFont,
+    ),
+    icon(
+        "\u{e30d}",
+        "Sunny",
+        IconCategory::Weather,
+        IconStyle::NerdFont,
+    ),
+    icon(
+        "\u{e318}",
+        "Rain",
+        IconCategory::Weather,
+        IconStyle::NerdFont,
+    ),
+    icon(
+        "\u{e302}",
+        "Cloudy",
+        IconCategory::Weather,
+        IconStyle::NerdFont,
+    ),
+    icon(
+        "\u{f111}",
+        "Circle",
+        IconCategory::Shapes,
+        IconStyle::NerdFont,
+    ),
+];
+
+const fn icon(
+    glyph: &'static str,
+    name: &'static str,
+    category: IconCategory,
+    style: IconStyle,
+) -> IconEntry {
+    IconEntry {
+        glyph,
+        name,
+        category,
+        style,
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct IconSelector {
     pub is_open: bool,
     pub icon_style: IconStyle,
-    pub selected_plain: usize,
-    pub selected_nerd: usize,
+    /// 在过滤后列表中的选中下标
+    pub selected: usize,
+    /// 当前分类页签（None 表示全部）
+    pub category: Option<IconCategory>,
+    /// 增量过滤关键字（`/` 开启）
+    pub filter: String,
+    pub filtering: bool,
     pub custom_input: String,
     pub editing_custom: bool,
     pub current_icon: Option<String>,
+    /// 预览行：当前 segment 的文本与颜色
+    pub preview_text: String,
+    pub preview_colors: ColorConfig,
 }
 
 impl Default for IconSelector {
@@ -44,30 +235,52 @@ impl Default for IconSelector {
         Self {
             is_open: false,
             icon_style: IconStyle::Plain,
-            selected_plain: 0,
-            selected_nerd: 0,
+            selected: 0,
+            category: None,
+            filter: String::new(),
+            filtering: false,
             custom_input: String::new(),
             editing_custom: false,
             current_icon: None,
+            preview_text: String::new(),
+            preview_colors: ColorConfig::default(),
         }
     }
 }
 
 impl IconSelector {
-    pub fn open(&mut self, current_style: StyleMode) {
+    pub fn open(&mut self, current_style: StyleMode, preview_text: &str, colors: &ColorConfig) {
         self.is_open = true;
         self.icon_style = match current_style {
             StyleMode::Plain => IconStyle::Plain,
             StyleMode::NerdFont | StyleMode::Powerline => IconStyle::NerdFont,
         };
+        self.selected = 0;
+        self.category = None;
+        self.filter.clear();
+        self.filtering = false;
         self.editing_custom = false;
         self.custom_input.clear();
+        self.preview_text = preview_text.to_string();
+        self.preview_colors = colors.clone();
         self.update_current_icon();
     }
 
     pub fn close(&mut self) {
         self.is_open = false;
         self.editing_custom = false;
+        self.filtering = false;
+    }
+
+    /// 当前可见的图标（按样式、分类、过滤关键字筛选）
+    pub fn visible_icons(&self) -> Vec<&'static IconEntry> {
+        let filter = self.filter.to_lowercase();
+        ICON_TABLE
+            .iter()
+            .filter(|e| e.style == self.icon_style)
+            .filter(|e| self.category.is_none_or(|c| e.category == c))
+            .filter(|e| filter.is_empty() || e.name.to_lowercase().contains(&filter))
+            .collect()
     }
 
     pub fn toggle_style(&mut self) {
@@ -75,9 +288,32 @@ impl IconSelector {
             IconStyle::Plain => IconStyle::NerdFont,
             IconStyle::NerdFont => IconStyle::Plain,
         };
+        self.selected = 0;
         self.update_current_icon();
     }
 
+    /// 切换分类页签（delta 为 ±1，循环，经过「全部」）
+    pub fn cycle_category(&mut self, delta: i32) {
+        let tabs: Vec<Option<IconCategory>> = std::iter::once(None)
+            .chain(IconCategory::ALL.iter().copied().map(Some))
+            .collect();
+        let current = tabs.iter().position(|c| *c == self.category).unwrap_or(0) as i32;
+        let next = (current + delta).rem_euclid(tabs.len() as i32) as usize;
+        self.category = tabs[next];
+        self.selected = 0;
+        self.update_current_icon();
+    }
+
+    /// 开启/关闭过滤输入
+    pub fn toggle_filter(&mut self) {
+        self.filtering = !self.filtering;
+        if !self.filtering {
+            self.filter.clear();
+            self.selected = 0;
+            self.update_current_icon();
+        }
+    }
+
     pub fn start_custom_input(&mut self) {
         self.editing_custom = true;
         self.custom_input.clear();
@@ -95,12 +331,20 @@ impl IconSelector {
     pub fn input_char(&mut self, c: char) {
         if self.editing_custom {
             self.custom_input.push(c);
+        } else if self.filtering {
+            self.filter.push(c);
+            self.selected = 0;
+            self.update_current_icon();
         }
     }
 
     pub fn backspace(&mut self) {
         if self.editing_custom {
             self.custom_input.pop();
+        } else if self.filtering {
+            self.filter.pop();
+            self.selected = 0;
+            self.update_current_icon();
         }
     }
 
@@ -109,37 +353,24 @@ impl IconSelector {
             return;
         }
 
-        match self.icon_style {
-            IconStyle::Plain => {
-                let icons = get_plain_icons();
-                let new_selection =
-                    (self.selected_plain as i32 + delta).clamp(0, icons.len() as i32 - 1) as usize;
-                self.selected_plain = new_selection;
-            }
-            IconStyle::NerdFont => {
-                let icons = get_nerd_font_icons();
-                let new_selection =
-                    (self.selected_nerd as i32 + delta).clamp(0, icons.len() as i32 - 1) as usize;
-                self.selected_nerd = new_selection;
-            }
+        let count = self.visible_icons().len();
+        if count == 0 {
+            return;
         }
+        self.selected = (self.selected as i32 + delta).clamp(0, count as i32 - 1) as usize;
         self.update_current_icon();
     }
 
+    /// PgUp/PgDn 翻页
+    pub fn page(&mut self, delta: i32) {
+        self.move_selection(delta * PAGE_SIZE as i32);
+    }
+
     fn update_current_icon(&mut self) {
-        match self.icon_style {
-            IconStyle::Plain => {
-                let icons = get_plain_icons();
-                if let Some(icon) = icons.get(self.selected_plain) {
-                    self.current_icon = Some(icon.icon.to_string());
-                }
-            }
-            IconStyle::NerdFont => {
-                let icons = get_nerd_font_icons();
-                if let Some(icon) = icons.get(self.selected_nerd) {
-                    self.current_icon = Some(icon.icon.to_string());
-                }
-            }
+        if let Some(entry) = self.visible_icons().get(self.selected) {
+            self.current_icon = Some(entry.glyph.to_string());
+        } else {
+            self.current_icon = None;
         }
     }
 
@@ -152,7 +383,7 @@ impl IconSelector {
             return;
         }
 
-        let popup_area = centered_rect(55, 65, area);
+        let popup_area = centered_rect(55, 70, area);
         Clear.render(popup_area, buf);
 
         let popup_block = Block::default()
@@ -161,11 +392,20 @@ impl IconSelector {
         let inner = popup_block.inner(popup_area);
         popup_block.render(popup_area, buf);
 
-        let [style_area, list_area, custom_area, help_area] = Layout::vertical([
+        let [
+            style_area,
+            category_area,
+            list_area,
+            preview_area,
+            custom_area,
+            help_area,
+        ] = Layout::vertical([
+            Constraint::Length(3),
             Constraint::Length(3),
             Constraint::Min(8),
             Constraint::Length(3),
             Constraint::Length(3),
+            Constraint::Length(3),
         ])
         .areas(inner);
 
@@ -178,40 +418,85 @@ impl IconSelector {
             .block(Block::default().borders(Borders::ALL).title("Style"))
             .render(style_area, buf);
 
+        // Category tabs
+        let mut tab_spans: Vec<Span> = Vec::new();
+        let all_selected = self.category.is_none();
+        tab_spans.push(Span::styled(
+            "[all]",
+            if all_selected {
+                Style::default()
+                    .fg(Color::Green)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            },
+        ));
+        for cat in IconCategory::ALL {
+            tab_spans.push(Span::raw("  "));
+            let selected = self.category == Some(cat);
+            tab_spans.push(Span::styled(
+                format!("[{}]", cat.name()),
+                if selected {
+                    Style::default()
+                        .fg(Color::Green)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default()
+                },
+            ));
+        }
+        Paragraph::new(Line::from(tab_spans))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Category (←→)"),
+            )
+            .render(category_area, buf);
+
         // Icon list
-        let block = Block::default()
-            .borders(Borders::ALL)
-            .title(match self.icon_style {
-                IconStyle::Plain => "Emoji Icons",
-                IconStyle::NerdFont => "Nerd Font Icons",
-            });
+        let list_title = if self.filtering {
+            format!("Icons (filter: {}_)", self.filter)
+        } else {
+            "Icons".to_string()
+        };
+        let block = Block::default().borders(Borders::ALL).title(list_title);
         let list_inner = block.inner(list_area);
         block.render(list_area, buf);
 
-        let icons = match self.icon_style {
-            IconStyle::Plain => get_plain_icons(),
-            IconStyle::NerdFont => get_nerd_font_icons(),
-        };
-        let selected = match self.icon_style {
-            IconStyle::Plain => self.selected_plain,
-            IconStyle::NerdFont => self.selected_nerd,
-        };
-
+        let icons = self.visible_icons();
         let visible_rows = list_inner.height as usize;
-        let start_idx = selected.saturating_sub(visible_rows / 2);
+        let start_idx = self.selected.saturating_sub(visible_rows / 2);
 
-        for (i, icon_info) in icons.iter().enumerate().skip(start_idx).take(visible_rows) {
+        for (i, entry) in icons.iter().enumerate().skip(start_idx).take(visible_rows) {
             let y = list_inner.y + (i - start_idx) as u16;
-            let is_selected = i == selected;
+            let is_selected = i == self.selected;
             let style = if is_selected {
                 Style::default().add_modifier(Modifier::REVERSED)
             } else {
                 Style::default()
             };
-            let text = format!("{} {}", icon_info.icon, icon_info.name);
+            let text = format!("{} {} ({})", entry.glyph, entry.name, entry.category.name());
             buf.set_string(list_inner.x, y, &text, style);
         }
 
+        // 预览：选中图标 + 当前 segment 文本与颜色
+        let mut preview_spans: Vec<Span> = Vec::new();
+        if let Some(icon) = &self.current_icon {
+            let mut icon_style = Style::default();
+            if let Some(color) = self.preview_colors.icon_color() {
+                icon_style = icon_style.fg(color);
+            }
+            preview_spans.push(Span::styled(format!("{icon} "), icon_style));
+        }
+        let mut text_style = Style::default();
+        if let Some(color) = self.preview_colors.text_color() {
+            text_style = text_style.fg(color);
+        }
+        preview_spans.push(Span::styled(self.preview_text.clone(), text_style));
+        Paragraph::new(Line::from(preview_spans))
+            .block(Block::default().borders(Borders::ALL).title("Preview"))
+            .render(preview_area, buf);
+
         // Custom input
         let custom_text = if self.editing_custom {
             format!("> {} <", self.custom_input)
@@ -231,8 +516,10 @@ impl IconSelector {
         // Help
         let help = if self.editing_custom {
             "[Enter] Confirm  [Esc] Cancel"
+        } else if self.filtering {
+            "[Enter] Select  [/] Stop Filter  [Esc] Cancel"
         } else {
-            "[Enter] Select  [Tab] Switch  [c] Custom  [Esc] Cancel"
+            "[Enter] Select  [Tab] Style  [←→] Category  [/] Filter  [PgUp/PgDn] Page  [c] Custom"
         };
         Paragraph::new(help)
             .block(Block::default().borders(Borders::ALL))
@@ -240,136 +527,58 @@ impl IconSelector {
     }
 }
 
-pub fn get_plain_icons() -> Vec<IconInfo> {
-    vec![
-        IconInfo {
-            icon: "🤖",
-            name: "Robot (Model)",
-        },
-        IconInfo {
-            icon: "💻",
-            name: "Laptop",
-        },
-        IconInfo {
-            icon: "📁",
-            name: "Folder",
-        },
-        IconInfo {
-            icon: "📂",
-            name: "Open Folder",
-        },
-        IconInfo {
-            icon: "📊",
-            name: "Bar Chart",
-        },
-        IconInfo {
-            icon: "🌿",
-            name: "Branch (Git)",
-        },
-        IconInfo {
-            icon: "🌱",
-            name: "Seedling",
-        },
-        IconInfo {
-            icon: "🔧",
-            name: "Wrench",
-        },
-        IconInfo {
-            icon: "⚡",
-            name: "Lightning",
-        },
-        IconInfo {
-            icon: "⭐",
-            name: "Star",
-        },
-        IconInfo {
-            icon: "✨",
-            name: "Sparkles",
-        },
-        IconInfo {
-            icon: "🔥",
-            name: "Fire",
-        },
-        IconInfo {
-            icon: "💎",
-            name: "Gem",
-        },
-        IconInfo {
-            icon: "✓",
-            name: "Check",
-        },
-        IconInfo {
-            icon: "●",
-            name: "Circle",
-        },
-        IconInfo {
-            icon: "▶",
-            name: "Play",
-        },
-    ]
-}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn open_selector(style: StyleMode) -> IconSelector {
+        let mut selector = IconSelector::default();
+        selector.open(style, "main", &ColorConfig::default());
+        selector
+    }
+
+    #[test]
+    fn test_filter_narrows_to_matching_glyph() {
+        let mut selector = open_selector(StyleMode::Plain);
+        selector.toggle_filter();
+        for c in "open".chars() {
+            selector.input_char(c);
+        }
+        let visible = selector.visible_icons();
+        assert_eq!(visible.len(), 1);
+        assert_eq!(visible[0].glyph, "📂");
+        assert_eq!(selector.get_selected_icon(), Some("📂".to_string()));
+    }
+
+    #[test]
+    fn test_category_tab_filters_icons() {
+        let mut selector = open_selector(StyleMode::Plain);
+        selector.category = Some(IconCategory::Git);
+        selector.selected = 0;
+        assert!(
+            selector
+                .visible_icons()
+                .iter()
+                .all(|e| e.category == IconCategory::Git)
+        );
+    }
 
-pub fn get_nerd_font_icons() -> Vec<IconInfo> {
-    vec![
-        IconInfo {
-            icon: "\u{e26d}",
-            name: "Robot (Model)",
-        },
-        IconInfo {
-            icon: "\u{f02a2}",
-            name: "Git Branch",
-        },
-        IconInfo {
-            icon: "\u{f024b}",
-            name: "Folder",
-        },
-        IconInfo {
-            icon: "\u{f07b}",
-            name: "Folder Open",
-        },
-        IconInfo {
-            icon: "\u{f111}",
-            name: "Circle",
-        },
-        IconInfo {
-            icon: "\u{f135}",
-            name: "Rocket",
-        },
-        IconInfo {
-            icon: "\u{f49b}",
-            name: "Chart",
-        },
-        IconInfo {
-            icon: "\u{f0c9}",
-            name: "List",
-        },
-        IconInfo {
-            icon: "\u{f013}",
-            name: "Cog",
-        },
-        IconInfo {
-            icon: "\u{f015}",
-            name: "Home",
-        },
-        IconInfo {
-            icon: "\u{f0e7}",
-            name: "Lightning",
-        },
-        IconInfo {
-            icon: "\u{f121}",
-            name: "Code",
-        },
-        IconInfo {
-            icon: "\u{f126}",
-            name: "Code Fork",
-        },
-        IconInfo {
-            icon: "\u{f017}",
-            name: "Clock",
-        },
-        IconInfo {
-            icon: "\u{f080}",
-            name: "Bar Chart",
-        },
-    ]
+    #[test]
+    fn test_selection_returns_right_glyph_after_move() {
+        let mut selector = open_selector(StyleMode::NerdFont);
+        selector.move_selection(1);
+        let visible = selector.visible_icons();
+        assert_eq!(
+            selector.get_selected_icon(),
+            Some(visible[1].glyph.to_string())
+        );
+    }
+
+    #[test]
+    fn test_paging_clamps_to_list_end() {
+        let mut selector = open_selector(StyleMode::Plain);
+        selector.page(100);
+        let count = selector.visible_icons().len();
+        assert_eq!(selector.selected, count - 1);
+    }
 }
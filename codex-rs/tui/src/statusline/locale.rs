@@ -0,0 +1,117 @@
+// 状态栏数字格式化的 locale 支持
+// 不引入 ICU：只维护一张极小的分隔符表，覆盖配置允许的几个 locale
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// 数字格式 locale（决定小数点与千位分组分隔符）
+/// Context / Usage 等 segment 的数字显示共用这里的格式化方法；
+/// 元数据里的数值仍保持机器格式（`.` 小数点、无分组），供条件规则解析
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum NumberLocale {
+    /// 1,234.5
+    #[default]
+    #[serde(rename = "en-US")]
+    EnUs,
+    /// 1.234,5
+    #[serde(rename = "de-DE")]
+    DeDe,
+    /// 1,234.5（分隔符与 en-US 相同；保留独立条目便于后续分化）
+    #[serde(rename = "zh-CN")]
+    ZhCn,
+}
+
+impl NumberLocale {
+    /// 小数点
+    fn decimal_separator(self) -> char {
+        match self {
+            NumberLocale::DeDe => ',',
+            _ => '.',
+        }
+    }
+
+    /// 千位分组分隔符
+    fn group_separator(self) -> char {
+        match self {
+            NumberLocale::DeDe => '.',
+            _ => ',',
+        }
+    }
+
+    /// 整数按千位分组：1234567 → "1,234,567" / "1.234.567"
+    pub fn format_grouped(self, value: i64) -> String {
+        let digits = value.unsigned_abs().to_string();
+        let mut grouped = String::new();
+        for (i, ch) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i) % 3 == 0 {
+                grouped.push(self.group_separator());
+            }
+            grouped.push(ch);
+        }
+        if value < 0 {
+            format!("-{grouped}")
+        } else {
+            grouped
+        }
+    }
+
+    /// 固定小数位格式化，使用本 locale 的小数点
+    pub fn format_decimal(self, value: f64, precision: usize) -> String {
+        let formatted = format!("{value:.precision$}");
+        match self.decimal_separator() {
+            '.' => formatted,
+            separator => formatted.replace('.', &separator.to_string()),
+        }
+    }
+
+    /// 百分比显示
+    pub fn format_percent(self, percent: f64, precision: usize) -> String {
+        format!("{}%", self.format_decimal(percent, precision))
+    }
+
+    /// token 数量的紧凑显示：500 / 1.5k / 1.5M（小数点随 locale）
+    pub fn format_tokens(self, tokens: i64) -> String {
+        if tokens >= 1_000_000 {
+            format!("{}M", self.format_decimal(tokens as f64 / 1_000_000.0, 1))
+        } else if tokens >= 1_000 {
+            format!("{}k", self.format_decimal(tokens as f64 / 1_000.0, 1))
+        } else {
+            tokens.to_string()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_tokens_en_us() {
+        assert_eq!(NumberLocale::EnUs.format_tokens(500), "500");
+        assert_eq!(NumberLocale::EnUs.format_tokens(1500), "1.5k");
+        assert_eq!(NumberLocale::EnUs.format_tokens(150000), "150.0k");
+        assert_eq!(NumberLocale::EnUs.format_tokens(1500000), "1.5M");
+    }
+
+    #[test]
+    fn test_same_values_across_locales() {
+        // 同一组值在 de-DE / zh-CN 下的显示
+        assert_eq!(NumberLocale::DeDe.format_tokens(1500), "1,5k");
+        assert_eq!(NumberLocale::ZhCn.format_tokens(1500), "1.5k");
+
+        assert_eq!(NumberLocale::DeDe.format_grouped(1234567), "1.234.567");
+        assert_eq!(NumberLocale::ZhCn.format_grouped(1234567), "1,234,567");
+        assert_eq!(NumberLocale::DeDe.format_grouped(-4200), "-4.200");
+
+        assert_eq!(NumberLocale::DeDe.format_percent(42.5, 1), "42,5%");
+        assert_eq!(NumberLocale::ZhCn.format_percent(42.5, 1), "42.5%");
+    }
+
+    #[test]
+    fn test_serde_uses_bcp47_names() {
+        let json = serde_json::to_string(&NumberLocale::DeDe).unwrap();
+        assert_eq!(json, "\"de-DE\"");
+        let parsed: NumberLocale = serde_json::from_str("\"zh-CN\"").unwrap();
+        assert_eq!(parsed, NumberLocale::ZhCn);
+    }
+}
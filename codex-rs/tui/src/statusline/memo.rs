@@ -0,0 +1,208 @@
+// Segment 收集结果 memoization
+// 输入未变化的 segment 直接复用上次收集结果，跳过重复的格式化工作
+
+use std::collections::HashMap;
+use std::hash::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+
+use super::StatusLineContext;
+use super::segment::Segment;
+use super::segment::SegmentData;
+use super::segment::SegmentId;
+
+/// `StatusLineContext` 中可被 segment 声明为依赖的字段
+/// 每个 segment 通过 `Segment::dependencies` 声明自己读取哪些字段，
+/// memo 层只对这些字段求哈希，其余字段变化不会使该 segment 失效
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContextField {
+    ModelName,
+    ReasoningEffort,
+    Cwd,
+    ContextUsedTokens,
+    ContextWindowSize,
+    TurnStartTokens,
+    HourlyRateLimitPercent,
+    WeeklyRateLimitPercent,
+    WeeklyRateLimitResetsAt,
+    GitPreview,
+    TerminalFocused,
+    AlertMissedCount,
+    Locale,
+}
+
+/// 按声明的依赖字段计算上下文输入哈希
+fn hash_dependencies(fields: &[ContextField], ctx: &StatusLineContext) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for field in fields {
+        match field {
+            ContextField::ModelName => ctx.model_name.hash(&mut hasher),
+            // ReasoningEffort 未实现 Hash，经 Debug 文本参与哈希
+            ContextField::ReasoningEffort => {
+                format!("{:?}", ctx.reasoning_effort).hash(&mut hasher)
+            }
+            ContextField::Cwd => ctx.cwd.hash(&mut hasher),
+            ContextField::ContextUsedTokens => ctx.context_used_tokens.hash(&mut hasher),
+            ContextField::ContextWindowSize => ctx.context_window_size.hash(&mut hasher),
+            ContextField::TurnStartTokens => ctx.turn_start_tokens.hash(&mut hasher),
+            ContextField::HourlyRateLimitPercent => ctx
+                .hourly_rate_limit_percent
+                .map(f64::to_bits)
+                .hash(&mut hasher),
+            ContextField::WeeklyRateLimitPercent => ctx
+                .weekly_rate_limit_percent
+                .map(f64::to_bits)
+                .hash(&mut hasher),
+            ContextField::WeeklyRateLimitResetsAt => {
+                ctx.weekly_rate_limit_resets_at.hash(&mut hasher)
+            }
+            ContextField::GitPreview => ctx.git_preview.hash(&mut hasher),
+            ContextField::TerminalFocused => ctx.terminal_focused.hash(&mut hasher),
+            ContextField::AlertMissedCount => ctx.alert_missed_count.hash(&mut hasher),
+            ContextField::Locale => ctx.locale.hash(&mut hasher),
+        }
+    }
+    hasher.finish()
+}
+
+/// 一条 memo 记录：输入哈希 + 当时的收集结果
+#[derive(Debug)]
+struct MemoEntry {
+    input_hash: u64,
+    /// `None` 表示该输入下 segment 主动隐藏，同样可以复用
+    data: Option<SegmentData>,
+}
+
+/// segment 收集结果的 memo 缓存
+/// 由调用方跨帧持有；每帧经 `collect` 收集，输入哈希未变时复用上次结果
+#[derive(Debug, Default)]
+pub struct SegmentMemo {
+    entries: HashMap<SegmentId, MemoEntry>,
+    /// 每个 segment 的缓存命中次数（调试用）
+    hits: HashMap<SegmentId, u64>,
+}
+
+impl SegmentMemo {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 经 memo 层收集一个 segment
+    /// 未声明依赖的 segment（时间/外部状态相关）每次都真正收集，不参与缓存
+    pub fn collect(
+        &mut self,
+        segment: &dyn Segment,
+        ctx: &StatusLineContext,
+    ) -> Option<SegmentData> {
+        let Some(fields) = segment.dependencies() else {
+            return segment.collect(ctx);
+        };
+        let id = segment.id();
+        let input_hash = hash_dependencies(fields, ctx);
+        if let Some(entry) = self.entries.get(&id)
+            && entry.input_hash == input_hash
+        {
+            *self.hits.entry(id).or_insert(0) += 1;
+            return entry.data.clone();
+        }
+        let data = segment.collect(ctx);
+        self.entries.insert(
+            id,
+            MemoEntry {
+                input_hash,
+                data: data.clone(),
+            },
+        );
+        data
+    }
+
+    /// 某 segment 的缓存命中次数（调试用）
+    pub fn hit_count(&self, id: SegmentId) -> u64 {
+        self.hits.get(&id).copied().unwrap_or(0)
+    }
+
+    /// 所有 segment 的命中次数合计（调试用）
+    pub fn total_hits(&self) -> u64 {
+        self.hits.values().sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::statusline::segments::ContextSegment;
+    use crate::statusline::segments::ModelSegment;
+    use std::cell::Cell;
+    use std::path::Path;
+
+    #[test]
+    fn test_field_change_invalidates_only_dependent_segments() {
+        let cwd = Path::new("/tmp");
+        let mut memo = SegmentMemo::new();
+
+        let ctx =
+            StatusLineContext::new("gpt-5.1-codex", cwd).with_context(Some(1_000), Some(128_000));
+        memo.collect(&ModelSegment, &ctx);
+        memo.collect(&ContextSegment, &ctx);
+        // 第二帧输入未变：两个 segment 都命中
+        memo.collect(&ModelSegment, &ctx);
+        memo.collect(&ContextSegment, &ctx);
+        assert_eq!(memo.hit_count(SegmentId::Model), 1);
+        assert_eq!(memo.hit_count(SegmentId::Context), 1);
+
+        // 只有 token 数变化：Model 仍命中，Context 重新收集且拿到新值
+        let ctx =
+            StatusLineContext::new("gpt-5.1-codex", cwd).with_context(Some(4_200), Some(128_000));
+        memo.collect(&ModelSegment, &ctx);
+        let data = memo.collect(&ContextSegment, &ctx).expect("context data");
+        assert_eq!(memo.hit_count(SegmentId::Model), 2);
+        assert_eq!(memo.hit_count(SegmentId::Context), 1);
+        assert_eq!(
+            data.metadata.get("tokens").map(String::as_str),
+            Some("4200")
+        );
+        assert_eq!(memo.total_hits(), 3);
+    }
+
+    #[test]
+    fn test_hidden_result_is_memoized_too() {
+        let mut memo = SegmentMemo::new();
+        // 空模型名：Model segment 主动隐藏
+        let ctx = StatusLineContext::new("", Path::new("/tmp"));
+
+        assert!(memo.collect(&ModelSegment, &ctx).is_none());
+        assert!(memo.collect(&ModelSegment, &ctx).is_none());
+        assert_eq!(memo.hit_count(SegmentId::Model), 1);
+    }
+
+    /// 未声明依赖的 segment（模拟时间相关 segment）
+    struct UncacheableSegment {
+        collect_calls: Cell<u32>,
+    }
+
+    impl Segment for UncacheableSegment {
+        fn collect(&self, _ctx: &StatusLineContext) -> Option<SegmentData> {
+            self.collect_calls.set(self.collect_calls.get() + 1);
+            Some(SegmentData::new("now"))
+        }
+
+        fn id(&self) -> SegmentId {
+            SegmentId::Alert
+        }
+    }
+
+    #[test]
+    fn test_segments_without_dependencies_opt_out() {
+        let mut memo = SegmentMemo::new();
+        let ctx = StatusLineContext::new("gpt-5.1-codex", Path::new("/tmp"));
+        let segment = UncacheableSegment {
+            collect_calls: Cell::new(0),
+        };
+
+        memo.collect(&segment, &ctx);
+        memo.collect(&segment, &ctx);
+        // 输入完全相同也每次真正收集，命中计数保持为零
+        assert_eq!(segment.collect_calls.get(), 2);
+        assert_eq!(memo.hit_count(SegmentId::Alert), 0);
+    }
+}
@@ -4,34 +4,50 @@
 pub mod color_picker;
 pub mod config;
 pub mod icon_selector;
+pub mod locale;
+pub mod memo;
 pub mod name_input;
+pub mod options_editor;
 pub mod renderer;
+pub mod rules;
 pub mod segment;
 pub mod segments;
 pub mod separator_editor;
 pub mod style;
 pub mod themes;
+pub mod threshold_editor;
 
 use std::path::Path;
+use std::time::Duration;
 
 use codex_protocol::openai_models::ReasoningEffort;
 
 pub use color_picker::ColorPicker;
 pub use color_picker::ColorTarget;
 pub use config::CxLineConfig;
+pub use config::SegmentClickAction;
 pub use icon_selector::IconSelector;
+pub use locale::NumberLocale;
+pub use memo::ContextField;
+pub use memo::SegmentMemo;
 pub use name_input::NameInputDialog;
+pub use options_editor::OptionsEditor;
 pub use renderer::StatusLineRenderer;
 pub use renderer::StatusLineWidget;
+pub use rules::RuleCmp;
+pub use rules::SegmentRule;
+pub use segment::AsyncSegment;
 pub use segment::Segment;
 pub use segment::SegmentData;
+pub use segment::SegmentDataCache;
 pub use segment::SegmentId;
 pub use segment::SegmentStyle;
 pub use separator_editor::SeparatorEditor;
 pub use style::StyleMode;
+pub use threshold_editor::ThresholdEditor;
 
 /// Git 预览数据（用于配置页预览）
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct GitPreviewData {
     pub branch: String,
     pub status: String,
@@ -39,6 +55,21 @@ pub struct GitPreviewData {
     pub behind: u32,
 }
 
+/// 状态栏实时数据快照（owned 版本）
+/// 用于把当前会话的数据带进配置 Overlay 的预览行
+#[derive(Debug, Clone, Default)]
+pub struct StatusLinePreviewData {
+    pub model: String,
+    pub cwd: std::path::PathBuf,
+    pub reasoning_effort: Option<ReasoningEffort>,
+    pub context_used_tokens: Option<i64>,
+    pub context_window_size: Option<i64>,
+    pub hourly_rate_limit_percent: Option<f64>,
+    pub weekly_rate_limit_percent: Option<f64>,
+    pub weekly_rate_limit_resets_at: Option<String>,
+    pub git_preview: Option<GitPreviewData>,
+}
+
 /// 状态栏数据上下文
 /// 包含渲染状态栏所需的所有数据
 pub struct StatusLineContext<'a> {
@@ -57,6 +88,10 @@ pub struct StatusLineContext<'a> {
     /// 上下文窗口大小（用于计算使用占比）
     pub context_window_size: Option<i64>,
 
+    /// 本回合开始时的已用 token 数
+    /// chat widget 在回合开始时记录，回合结束后清空（None = 无活跃回合）
+    pub turn_start_tokens: Option<i64>,
+
     /// 5h Rate limit 使用百分比 (用于百分比数字显示)
     pub hourly_rate_limit_percent: Option<f64>,
 
@@ -68,6 +103,15 @@ pub struct StatusLineContext<'a> {
 
     /// Git 预览数据（用于配置页预览，覆盖实际 git 检测）
     pub git_preview: Option<GitPreviewData>,
+
+    /// 终端是否处于聚焦状态（Alert segment 聚焦时隐藏）
+    pub terminal_focused: bool,
+
+    /// 失焦期间错过的事件数（Alert segment 显示用）
+    pub alert_missed_count: u32,
+
+    /// 数字格式 locale（来自 `CxLineConfig.locale`，调用方在构建时带入）
+    pub locale: NumberLocale,
 }
 
 impl<'a> StatusLineContext<'a> {
@@ -78,10 +122,14 @@ impl<'a> StatusLineContext<'a> {
             cwd,
             context_used_tokens: None,
             context_window_size: None,
+            turn_start_tokens: None,
             hourly_rate_limit_percent: None,
             weekly_rate_limit_percent: None,
             weekly_rate_limit_resets_at: None,
             git_preview: None,
+            terminal_focused: true,
+            alert_missed_count: 0,
+            locale: NumberLocale::default(),
         }
     }
 
@@ -108,6 +156,19 @@ impl<'a> StatusLineContext<'a> {
         self
     }
 
+    /// 设置数字格式 locale
+    pub fn with_locale(mut self, locale: NumberLocale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// 设置 Alert 状态（焦点 + 失焦期间错过的事件数）
+    pub fn with_alert(mut self, terminal_focused: bool, missed_count: u32) -> Self {
+        self.terminal_focused = terminal_focused;
+        self.alert_missed_count = missed_count;
+        self
+    }
+
     /// 设置 Git 预览数据（用于配置页预览）
     pub fn with_git_preview(mut self, branch: &str, status: &str, ahead: u32, behind: u32) -> Self {
         self.git_preview = Some(GitPreviewData {
@@ -132,7 +193,7 @@ impl GitPreviewData {
 }
 
 /// 构建状态栏
-/// 收集所有 segment 数据并返回渲染器
+/// 按配置的 segment 顺序收集数据并返回渲染器
 pub fn build_statusline<'a>(
     config: &'a CxLineConfig,
     ctx: &StatusLineContext<'_>,
@@ -141,51 +202,259 @@ pub fn build_statusline<'a>(
 
     let mut renderer = StatusLineRenderer::new(config);
 
-    // Model segment
-    if config.segments.model.enabled {
-        let segment = ModelSegment;
-        if let Some(data) = segment.collect(ctx) {
-            renderer.add_segment(SegmentId::Model, data);
+    for &segment_id in &config.segment_order {
+        if !config.get_segment_config(segment_id).enabled {
+            continue;
         }
-    }
 
-    // Directory segment
-    if config.segments.directory.enabled {
-        let segment = DirectorySegment;
-        if let Some(data) = segment.collect(ctx) {
-            renderer.add_segment(SegmentId::Directory, data);
+        let data = match segment_id {
+            SegmentId::Model => ModelSegment.collect(ctx),
+            SegmentId::Directory => DirectorySegment.collect(ctx),
+            SegmentId::Git => GitSegment.collect(ctx),
+            SegmentId::Context => ContextSegment.collect(ctx),
+            SegmentId::Usage => UsageSegment.collect(ctx),
+            SegmentId::Alert => AlertSegment.collect(ctx),
+        };
+
+        if let Some(data) = data {
+            renderer.add_segment(segment_id, data);
         }
     }
 
-    // Git segment
-    if config.segments.git.enabled {
-        let segment = GitSegment;
-        if let Some(data) = segment.collect(ctx) {
-            renderer.add_segment(SegmentId::Git, data);
+    renderer
+}
+
+/// 构建状态栏（memoized 路径）
+/// 输入哈希未变的 segment 复用上次收集结果；`memo` 由调用方跨帧持有。
+/// 未声明依赖的 segment 每帧照常收集，行为与 `build_statusline` 一致
+pub fn build_statusline_memoized<'a>(
+    config: &'a CxLineConfig,
+    ctx: &StatusLineContext<'_>,
+    memo: &mut SegmentMemo,
+) -> StatusLineRenderer<'a> {
+    use segments::*;
+
+    let mut renderer = StatusLineRenderer::new(config);
+
+    for &segment_id in &config.segment_order {
+        if !config.get_segment_config(segment_id).enabled {
+            continue;
         }
-    }
 
-    // Context segment
-    if config.segments.context.enabled {
-        let segment = ContextSegment;
-        if let Some(data) = segment.collect(ctx) {
-            renderer.add_segment(SegmentId::Context, data);
+        let data = match segment_id {
+            SegmentId::Model => memo.collect(&ModelSegment, ctx),
+            SegmentId::Directory => memo.collect(&DirectorySegment, ctx),
+            SegmentId::Git => memo.collect(&GitSegment, ctx),
+            SegmentId::Context => memo.collect(&ContextSegment, ctx),
+            SegmentId::Usage => memo.collect(&UsageSegment, ctx),
+            SegmentId::Alert => memo.collect(&AlertSegment, ctx),
+        };
+
+        if let Some(data) = data {
+            renderer.add_segment(segment_id, data);
         }
     }
 
-    // Usage segment
-    if config.segments.usage.enabled {
-        let segment = UsageSegment;
-        if let Some(data) = segment.collect(ctx) {
-            renderer.add_segment(SegmentId::Usage, data);
+    renderer
+}
+
+/// 异步收集单个 segment 的超时上限
+/// 超时的 segment 本回合用缓存的上次值顶替，不会卡住整行
+pub const ASYNC_COLLECT_TIMEOUT: Duration = Duration::from_millis(50);
+
+/// 构建状态栏（异步收集路径）
+/// 需要 IO 的 segment 经 `collect_async` 驱动，带超时与上次值回退；
+/// 纯计算 segment 仍走同步 `collect`。`cache` 由调用方跨回合持有
+pub async fn build_statusline_async<'a>(
+    config: &'a CxLineConfig,
+    ctx: &StatusLineContext<'_>,
+    cache: &mut SegmentDataCache,
+) -> StatusLineRenderer<'a> {
+    use segments::*;
+
+    let mut renderer = StatusLineRenderer::new(config);
+
+    for &segment_id in &config.segment_order {
+        if !config.get_segment_config(segment_id).enabled {
+            continue;
+        }
+
+        let data = match segment_id {
+            SegmentId::Git => {
+                collect_with_timeout(&GitSegment, ctx, cache, ASYNC_COLLECT_TIMEOUT).await
+            }
+            SegmentId::Model => ModelSegment.collect(ctx),
+            SegmentId::Directory => DirectorySegment.collect(ctx),
+            SegmentId::Context => ContextSegment.collect(ctx),
+            SegmentId::Usage => UsageSegment.collect(ctx),
+            SegmentId::Alert => AlertSegment.collect(ctx),
+        };
+
+        if let Some(data) = data {
+            renderer.add_segment(segment_id, data);
         }
     }
 
     renderer
 }
 
+/// 以超时驱动一个异步 segment
+/// 成功：更新缓存并返回新值；segment 主动隐藏：清缓存，避免超时回退
+/// 复活已消失的内容；超时：回退到缓存的上次值
+pub async fn collect_with_timeout(
+    segment: &dyn AsyncSegment,
+    ctx: &StatusLineContext<'_>,
+    cache: &mut SegmentDataCache,
+    timeout: Duration,
+) -> Option<SegmentData> {
+    let id = segment.id();
+    match tokio::time::timeout(timeout, segment.collect_async(ctx)).await {
+        Ok(Some(data)) => {
+            cache.insert(id, data.clone());
+            Some(data)
+        }
+        Ok(None) => {
+            cache.remove(id);
+            None
+        }
+        Err(_) => cache.get(id).cloned(),
+    }
+}
+
 /// 异步更新用的 Git 预览数据收集（避免在 render 中执行 git 命令）
 pub(crate) fn collect_git_preview(cwd: &Path) -> Option<GitPreviewData> {
     let segment = segments::GitSegment;
     segment.collect_preview(cwd)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::sync::Arc;
+    use std::sync::atomic::AtomicBool;
+    use std::sync::atomic::Ordering;
+
+    /// 故意拖慢的 segment：`slow` 置位时 sleep 超过宿主超时
+    struct SlowSegment {
+        slow: Arc<AtomicBool>,
+        /// `None` 表示主动隐藏（不 sleep 时生效）
+        data: Option<SegmentData>,
+    }
+
+    impl Segment for SlowSegment {
+        fn collect(&self, _ctx: &StatusLineContext) -> Option<SegmentData> {
+            self.data.clone()
+        }
+
+        fn id(&self) -> SegmentId {
+            SegmentId::Git
+        }
+    }
+
+    impl AsyncSegment for SlowSegment {
+        fn collect_async(
+            &self,
+            _ctx: &StatusLineContext,
+        ) -> Pin<Box<dyn Future<Output = Option<SegmentData>> + Send + 'static>> {
+            let slow = self.slow.load(Ordering::Relaxed);
+            let data = self.data.clone();
+            Box::pin(async move {
+                if slow {
+                    tokio::time::sleep(Duration::from_secs(5)).await;
+                }
+                data
+            })
+        }
+    }
+
+    fn ctx_model<'a>() -> StatusLineContext<'a> {
+        StatusLineContext::new("gpt-5.1-codex", Path::new("/tmp"))
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_timeout_falls_back_to_last_known_value() {
+        let slow = Arc::new(AtomicBool::new(false));
+        let segment = SlowSegment {
+            slow: slow.clone(),
+            data: Some(SegmentData::new("main")),
+        };
+        let mut cache = SegmentDataCache::new();
+        let ctx = ctx_model();
+
+        // 第一回合正常返回并写入缓存
+        let fresh = collect_with_timeout(&segment, &ctx, &mut cache, ASYNC_COLLECT_TIMEOUT)
+            .await
+            .expect("fresh data");
+        assert_eq!(fresh.primary, "main");
+
+        // 第二回合超时：回退到缓存的上次值
+        slow.store(true, Ordering::Relaxed);
+        let fallback = collect_with_timeout(&segment, &ctx, &mut cache, ASYNC_COLLECT_TIMEOUT)
+            .await
+            .expect("cached fallback");
+        assert_eq!(fallback.primary, "main");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_timeout_without_cache_hides_segment() {
+        let segment = SlowSegment {
+            slow: Arc::new(AtomicBool::new(true)),
+            data: Some(SegmentData::new("main")),
+        };
+        let mut cache = SegmentDataCache::new();
+        let ctx = ctx_model();
+
+        let data = collect_with_timeout(&segment, &ctx, &mut cache, ASYNC_COLLECT_TIMEOUT).await;
+        assert!(data.is_none());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_explicit_hide_clears_cached_value() {
+        let slow = Arc::new(AtomicBool::new(false));
+        let mut cache = SegmentDataCache::new();
+        let ctx = ctx_model();
+
+        let visible = SlowSegment {
+            slow: slow.clone(),
+            data: Some(SegmentData::new("main")),
+        };
+        collect_with_timeout(&visible, &ctx, &mut cache, ASYNC_COLLECT_TIMEOUT).await;
+        assert!(cache.get(SegmentId::Git).is_some());
+
+        // segment 主动隐藏：清缓存，之后的超时回合不会复活旧值
+        let hidden = SlowSegment {
+            slow: slow.clone(),
+            data: None,
+        };
+        let data = collect_with_timeout(&hidden, &ctx, &mut cache, ASYNC_COLLECT_TIMEOUT).await;
+        assert!(data.is_none());
+        assert!(cache.get(SegmentId::Git).is_none());
+
+        slow.store(true, Ordering::Relaxed);
+        let visible_again = SlowSegment {
+            slow,
+            data: Some(SegmentData::new("main")),
+        };
+        let data =
+            collect_with_timeout(&visible_again, &ctx, &mut cache, ASYNC_COLLECT_TIMEOUT).await;
+        assert!(data.is_none());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_build_statusline_async_keeps_sync_segments() {
+        let config = themes::ThemePresets::get_default();
+        let mut cache = SegmentDataCache::new();
+        let ctx = ctx_model().with_git_preview("main", "✓", 0, 0);
+
+        let renderer = build_statusline_async(&config, &ctx, &mut cache).await;
+        let ids: Vec<SegmentId> = renderer
+            .render_segments()
+            .iter()
+            .map(|(id, _)| *id)
+            .collect();
+        assert!(ids.contains(&SegmentId::Model));
+        assert!(ids.contains(&SegmentId::Git));
+    }
+}
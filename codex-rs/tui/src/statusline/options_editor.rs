@@ -0,0 +1,255 @@
+// Segment Options 编辑器组件
+// 每个 segment 声明自己的 option 元数据（key / 类型 / 描述），
+// 编辑器按类型提供编辑方式：布尔切换、枚举循环、字符串/数字弹出输入框
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Color;
+use ratatui::style::Modifier;
+use ratatui::style::Style;
+use ratatui::widgets::Block;
+use ratatui::widgets::Borders;
+use ratatui::widgets::Clear;
+use ratatui::widgets::Widget;
+
+use super::color_picker::centered_rect;
+use super::config::SegmentItemConfig;
+use super::segment::SegmentId;
+
+/// Option 值类型
+#[derive(Debug, Clone, PartialEq)]
+pub enum OptionKind {
+    /// 布尔值（Enter 切换）
+    Bool,
+    /// 枚举（←/→ 循环允许值）
+    Enum(&'static [&'static str]),
+    /// 自由字符串（弹出输入框）
+    String,
+    /// 数字（弹出输入框，需能解析为 f64）
+    Number,
+    /// 表格（Enter 打开专用编辑器）
+    Table,
+}
+
+/// 单个 option 的元数据
+#[derive(Debug, Clone)]
+pub struct OptionSpec {
+    pub key: &'static str,
+    pub kind: OptionKind,
+    pub description: &'static str,
+}
+
+/// 各 segment 声明的 option 元数据
+pub fn option_specs(id: SegmentId) -> &'static [OptionSpec] {
+    match id {
+        SegmentId::Model => &[],
+        SegmentId::Directory => &[OptionSpec {
+            key: "style",
+            kind: OptionKind::Enum(&["name", "short", "full"]),
+            description: "路径显示方式：仅目录名 / 缩写路径 / 完整路径",
+        }],
+        SegmentId::Git => &[OptionSpec {
+            key: "show_ahead_behind",
+            kind: OptionKind::Bool,
+            description: "是否显示 ahead/behind 计数",
+        }],
+        SegmentId::Context => &[
+            OptionSpec {
+                key: "show_bar",
+                kind: OptionKind::Bool,
+                description: "是否显示进度条",
+            },
+            OptionSpec {
+                key: "bar_width",
+                kind: OptionKind::Number,
+                description: "进度条宽度（字符数）",
+            },
+        ],
+        SegmentId::Usage => &[
+            OptionSpec {
+                key: "display_mode",
+                kind: OptionKind::Enum(&["percent", "circle", "both"]),
+                description: "使用率显示方式：百分比 / 圆圈图标 / 两者",
+            },
+            OptionSpec {
+                key: "icon_thresholds",
+                kind: OptionKind::Table,
+                description: "动态图标阈值表（使用率 → 图标）",
+            },
+        ],
+        SegmentId::Alert => &[
+            OptionSpec {
+                key: "latch_turn_complete",
+                kind: OptionKind::Bool,
+                description: "回合完成是否点亮提醒",
+            },
+            OptionSpec {
+                key: "latch_approvals",
+                kind: OptionKind::Bool,
+                description: "审批请求是否点亮提醒",
+            },
+            OptionSpec {
+                key: "latch_plan_prompts",
+                kind: OptionKind::Bool,
+                description: "Plan 模式提示是否点亮提醒",
+            },
+        ],
+    }
+}
+
+/// Options 编辑器
+#[derive(Debug, Clone)]
+pub struct OptionsEditor {
+    pub is_open: bool,
+    pub segment_id: SegmentId,
+    pub selected: usize,
+}
+
+impl Default for OptionsEditor {
+    fn default() -> Self {
+        Self {
+            is_open: false,
+            segment_id: SegmentId::Model,
+            selected: 0,
+        }
+    }
+}
+
+impl OptionsEditor {
+    pub fn open(&mut self, id: SegmentId) {
+        self.is_open = true;
+        self.segment_id = id;
+        self.selected = 0;
+    }
+
+    pub fn close(&mut self) {
+        self.is_open = false;
+    }
+
+    pub fn specs(&self) -> &'static [OptionSpec] {
+        option_specs(self.segment_id)
+    }
+
+    pub fn selected_spec(&self) -> Option<&'static OptionSpec> {
+        self.specs().get(self.selected)
+    }
+
+    pub fn move_selection(&mut self, delta: i32) {
+        let count = self.specs().len();
+        if count == 0 {
+            return;
+        }
+        self.selected = (self.selected as i32 + delta).clamp(0, count as i32 - 1) as usize;
+    }
+
+    /// 切换布尔 option，返回新值
+    pub fn toggle_bool(config: &mut SegmentItemConfig, key: &str) -> bool {
+        let current = config
+            .options
+            .get(key)
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+        let new_value = !current;
+        config
+            .options
+            .insert(key.to_string(), serde_json::Value::Bool(new_value));
+        new_value
+    }
+
+    /// 循环枚举 option，返回新值
+    pub fn cycle_enum(
+        config: &mut SegmentItemConfig,
+        key: &str,
+        allowed: &'static [&'static str],
+        delta: i32,
+    ) -> &'static str {
+        let current = config
+            .options
+            .get(key)
+            .and_then(serde_json::Value::as_str)
+            .and_then(|v| allowed.iter().position(|a| *a == v))
+            .unwrap_or(0);
+        let next = (current as i32 + delta).rem_euclid(allowed.len() as i32) as usize;
+        let new_value = allowed[next];
+        config.options.insert(
+            key.to_string(),
+            serde_json::Value::String(new_value.to_string()),
+        );
+        new_value
+    }
+
+    /// 当前 option 值的显示文本
+    pub fn display_value(config: &SegmentItemConfig, spec: &OptionSpec) -> String {
+        match config.options.get(spec.key) {
+            Some(serde_json::Value::Bool(b)) => if *b { "✓" } else { "✗" }.to_string(),
+            Some(serde_json::Value::String(s)) => s.clone(),
+            Some(serde_json::Value::Number(n)) => n.to_string(),
+            Some(serde_json::Value::Array(rows)) => format!("{} rows", rows.len()),
+            Some(other) => other.to_string(),
+            None => "(default)".to_string(),
+        }
+    }
+
+    pub fn render(&self, area: Rect, buf: &mut Buffer, config: &SegmentItemConfig) {
+        if !self.is_open {
+            return;
+        }
+
+        let popup_area = centered_rect(55, 50, area);
+        Clear.render(popup_area, buf);
+
+        let popup_block = Block::default().borders(Borders::ALL).title("Options");
+        let inner = popup_block.inner(popup_area);
+        popup_block.render(popup_area, buf);
+
+        let specs = self.specs();
+        if specs.is_empty() {
+            buf.set_string(
+                inner.x,
+                inner.y,
+                "This segment has no options",
+                Style::default().fg(Color::DarkGray),
+            );
+            return;
+        }
+
+        let mut y = inner.y;
+        for (i, spec) in specs.iter().enumerate() {
+            if y >= inner.y + inner.height.saturating_sub(2) {
+                break;
+            }
+
+            let is_selected = i == self.selected;
+            let marker = if is_selected { "▶ " } else { "  " };
+            let value = Self::display_value(config, spec);
+            let style = if is_selected {
+                Style::default().add_modifier(Modifier::REVERSED)
+            } else {
+                Style::default()
+            };
+            buf.set_string(inner.x, y, format!("{marker}{}: {value}", spec.key), style);
+            y += 1;
+
+            // 选中项下方显示描述
+            if is_selected && y < inner.y + inner.height.saturating_sub(2) {
+                buf.set_string(
+                    inner.x + 4,
+                    y,
+                    spec.description,
+                    Style::default().fg(Color::DarkGray),
+                );
+                y += 1;
+            }
+        }
+
+        // Help
+        if inner.height >= 2 {
+            buf.set_string(
+                inner.x,
+                inner.y + inner.height - 1,
+                "[Enter] Toggle/Edit  [←→] Cycle  [Esc] Close",
+                Style::default().fg(Color::Gray),
+            );
+        }
+    }
+}
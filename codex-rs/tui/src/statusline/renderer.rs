@@ -2,25 +2,38 @@
 // 参考 CCometixLine 的 statusline.rs
 
 use super::config::CxLineConfig;
+use super::rules;
 use super::segment::SegmentData;
 use super::segment::SegmentId;
+use super::segments::usage;
+use super::style::AnsiColor;
+use super::style::ColorDepth;
+use super::style::SeparatorBg;
+use super::style::SeparatorBgMode;
 use super::style::StyleMode;
-use super::style::separators;
+use crate::terminal_palette::effective_stdout_color_level;
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
+use ratatui::style::Color;
 use ratatui::style::Style;
 use ratatui::style::Stylize;
 use ratatui::text::Line;
 use ratatui::text::Span;
 use ratatui::widgets::WidgetRef;
+use std::ops::Range;
 
 /// Powerline 箭头字符
 const POWERLINE_ARROW: &str = "\u{e0b0}";
 
+/// 单回合 token 增量的默认告警阈值（可由 options["turn_delta_warn_tokens"] 覆盖）
+const DEFAULT_TURN_DELTA_WARN_TOKENS: i64 = 20_000;
+
 /// 状态栏渲染器
 pub struct StatusLineRenderer<'a> {
     config: &'a CxLineConfig,
     segments: Vec<(SegmentId, SegmentData)>,
+    /// 已解析的颜色深度（"auto" 在构造时按终端能力探测结果定下来）
+    depth: ColorDepth,
 }
 
 impl<'a> StatusLineRenderer<'a> {
@@ -28,6 +41,7 @@ impl<'a> StatusLineRenderer<'a> {
         Self {
             config,
             segments: Vec::new(),
+            depth: config.color_depth.resolve(effective_stdout_color_level()),
         }
     }
 
@@ -36,6 +50,75 @@ impl<'a> StatusLineRenderer<'a> {
         self.segments.push((id, data));
     }
 
+    /// 按当前颜色深度解析配置颜色；低深度终端降映射到最近的可用颜色
+    fn resolve_color(&self, color: AnsiColor) -> Color {
+        color.to_ratatui_color_at(self.depth)
+    }
+
+    /// 计算 segment 的有效样式（文本色 / 背景色 / 加粗）：
+    /// 先取配置默认值，再按序匹配条件规则，命中的第一条覆盖对应字段
+    fn effective_style(
+        &self,
+        id: SegmentId,
+        data: &SegmentData,
+    ) -> (Option<Color>, Option<Color>, bool) {
+        let segment_config = self.config.get_segment_config(id);
+        let mut text = segment_config.colors.text.map(|c| self.resolve_color(c));
+        let mut bg = segment_config
+            .colors
+            .background
+            .map(|c| self.resolve_color(c));
+        let mut bold = segment_config.styles.text_bold;
+        if let Some(rule) = rules::first_match(&segment_config.rules, &data.metadata) {
+            if let Some(fg) = rule.fg {
+                text = Some(self.resolve_color(fg));
+            }
+            if let Some(rule_bg) = rule.bg {
+                bg = Some(self.resolve_color(rule_bg));
+            }
+            if let Some(rule_bold) = rule.bold {
+                bold = rule_bold;
+            }
+        }
+        (text, bg, bold)
+    }
+
+    /// segment 的有效背景色（含条件规则覆盖），供分隔符/衔接处取色
+    fn effective_bg(&self, id: SegmentId, data: &SegmentData) -> Option<Color> {
+        self.effective_style(id, data).1
+    }
+
+    /// `show_turn_delta` 选项：把本回合 token 增量（元数据 `turn_delta_display`）
+    /// 渲染为附加 span；增量超过告警阈值时改用黄色提示单回合增长过大
+    fn turn_delta_span(
+        &self,
+        id: SegmentId,
+        data: &SegmentData,
+        base_style: Style,
+    ) -> Option<Span<'static>> {
+        let segment_config = self.config.get_segment_config(id);
+        let show = segment_config
+            .options
+            .get("show_turn_delta")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+        if !show {
+            return None;
+        }
+        let display = data.metadata.get("turn_delta_display")?;
+        let delta: i64 = data.metadata.get("turn_delta")?.parse().ok()?;
+        let warn_tokens = segment_config
+            .options
+            .get("turn_delta_warn_tokens")
+            .and_then(serde_json::Value::as_i64)
+            .unwrap_or(DEFAULT_TURN_DELTA_WARN_TOKENS);
+        let mut style = base_style;
+        if delta >= warn_tokens {
+            style = style.fg(Color::Yellow);
+        }
+        Some(Span::styled(format!(" {display}"), style))
+    }
+
     /// 渲染为 Line
     pub fn render_line(&self) -> Line<'static> {
         match self.config.style {
@@ -44,139 +127,374 @@ impl<'a> StatusLineRenderer<'a> {
         }
     }
 
+    /// 在给定宽度内渲染：整行放不下时从右往左丢弃整个 segment，
+    /// 直到放得下或只剩一个 segment
+    ///
+    /// 丢弃只影响本次渲染，收集到的 SegmentData 原样保留，所以这一步
+    /// 足够便宜，可以在每帧按当前终端宽度重跑——resize 后立即收缩，
+    /// 不用等下一个数据事件触发重建
+    pub fn render_line_fitted(&self, max_width: u16) -> Line<'static> {
+        match self.fitted_drop_count(max_width) {
+            0 => self.render_line(),
+            dropped => self.with_trailing_segments_dropped(dropped).render_line(),
+        }
+    }
+
+    /// 计算在 `max_width` 内需要从右往左丢弃的 segment 数量
+    /// （与 `render_line_fitted` 共用同一收缩策略，供命中测试取一致的布局）
+    fn fitted_drop_count(&self, max_width: u16) -> usize {
+        let mut line = self.render_line();
+        let mut dropped = 0;
+        while line.width() > max_width as usize && dropped + 1 < self.segments.len() {
+            dropped += 1;
+            line = self.with_trailing_segments_dropped(dropped).render_line();
+        }
+        dropped
+    }
+
+    /// 丢弃末尾 `count` 个 segment 的渲染器副本（宽度收缩用）
+    fn with_trailing_segments_dropped(&self, count: usize) -> StatusLineRenderer<'a> {
+        let keep = self.segments.len().saturating_sub(count);
+        StatusLineRenderer {
+            config: self.config,
+            segments: self.segments[..keep].to_vec(),
+            depth: self.depth,
+        }
+    }
+
+    /// 每个启用 segment 在渲染行里占据的列区间（半开区间，单位是显示列）
+    /// 衔接 span（cap / 分隔符 / Powerline 箭头）不属于任何 segment，
+    /// 落在其上的列在任何区间里都查不到——点击分隔符不应触发动作
+    pub fn segment_extents(&self) -> Vec<(SegmentId, Range<u16>)> {
+        let joiner = match self.config.style {
+            StyleMode::Powerline => self
+                .config
+                .separators
+                .inner
+                .as_deref()
+                .unwrap_or(POWERLINE_ARROW),
+            _ => self.get_separator(),
+        };
+        let joiner_width = Span::raw(joiner).width() as u16;
+        let mut col = self
+            .config
+            .separators
+            .left_cap
+            .as_deref()
+            .map(|cap| Span::raw(cap).width() as u16)
+            .unwrap_or(0);
+
+        let mut extents = Vec::new();
+        for (i, (id, group)) in self.render_segments().into_iter().enumerate() {
+            if i > 0 {
+                col += joiner_width;
+            }
+            let width: u16 = group.iter().map(|span| span.width() as u16).sum();
+            extents.push((id, col..col + width));
+            col += width;
+        }
+        extents
+    }
+
+    /// `segment_extents` 的限宽版本：先按 `render_line_fitted` 的策略
+    /// 丢弃右侧 segment，再对剩余 segment 计算列区间。
+    /// 被丢弃的 segment 不出现在结果里，点击原来的位置不会误触发
+    pub fn segment_extents_fitted(&self, max_width: u16) -> Vec<(SegmentId, Range<u16>)> {
+        match self.fitted_drop_count(max_width) {
+            0 => self.segment_extents(),
+            dropped => self
+                .with_trailing_segments_dropped(dropped)
+                .segment_extents(),
+        }
+    }
+
+    /// 收集启用的 segment（按添加顺序）
+    fn enabled_segments(&self) -> Vec<(SegmentId, &SegmentData)> {
+        self.segments
+            .iter()
+            .filter(|(id, _)| self.config.get_segment_config(*id).enabled)
+            .map(|(id, data)| (*id, data))
+            .collect()
+    }
+
+    /// 按当前样式模式渲染每个启用 segment 自身的 span 序列（不含 cap / 分隔符 / 箭头）
+    /// 其他组件（底部面板、overlay 预览等）可借此嵌入部分 segment 并保持配色一致；
+    /// `render_line` 即是在这些 span 组之间插入衔接 span 的薄封装
+    pub fn render_segments(&self) -> Vec<(SegmentId, Vec<Span<'static>>)> {
+        self.enabled_segments()
+            .into_iter()
+            .map(|(id, data)| {
+                let spans = match self.config.style {
+                    StyleMode::Powerline => self.powerline_segment_spans(id, data),
+                    _ => self.plain_segment_spans(id, data),
+                };
+                (id, spans)
+            })
+            .collect()
+    }
+
     /// 渲染普通模式（Plain / NerdFont）
+    /// 按「segment - 衔接处 - segment」管线构建：相邻 segment 之间的分隔符
+    /// 背景由 `separator_bg` 策略决定，避免带背景色的相邻 segment 出现色块断层
     fn render_plain(&self) -> Line<'static> {
-        let mut spans: Vec<Span<'static>> = Vec::new();
         let separator = self.get_separator();
-        let mut first = true;
+        let enabled_segments = self.enabled_segments();
+        let groups = self.render_segments();
 
-        for (id, data) in self.segments.iter() {
-            let segment_config = self.config.get_segment_config(*id);
-            if !segment_config.enabled {
-                continue;
-            }
+        let mut spans: Vec<Span<'static>> = Vec::new();
 
-            if !first {
-                spans.push(Span::raw(separator.to_string()).dim());
-            }
-            first = false;
-
-            // 渲染图标
-            let icon = self.get_icon(*id, data);
-            if !icon.is_empty() {
-                let mut icon_style = Style::default();
-                if let Some(color) = segment_config.colors.icon_color() {
-                    icon_style = icon_style.fg(color);
-                }
-                spans.push(Span::styled(format!("{icon} "), icon_style));
-            }
+        // 行首 cap
+        if let Some(cap) = self.config.separators.left_cap.as_deref()
+            && let Some((first_id, first_data)) = enabled_segments.first()
+        {
+            spans.push(self.cap_span(cap, *first_id, first_data));
+        }
 
-            // 渲染主要内容
-            let mut text_style = Style::default();
-            if let Some(color) = segment_config.colors.text_color() {
-                text_style = text_style.fg(color);
-            }
-            if segment_config.styles.text_bold {
-                text_style = text_style.bold();
+        for (i, (_, group)) in groups.into_iter().enumerate() {
+            if i > 0 {
+                spans.push(self.junction_span(
+                    separator,
+                    enabled_segments[i - 1],
+                    enabled_segments[i],
+                ));
             }
-            spans.push(Span::styled(data.primary.clone(), text_style));
+            spans.extend(group);
+        }
 
-            // 渲染次要内容
-            if !data.secondary.is_empty() {
-                spans.push(Span::styled(format!(" {}", data.secondary), text_style));
-            }
+        // 行尾 cap
+        if let Some(cap) = self.config.separators.right_cap.as_deref()
+            && let Some((last_id, last_data)) = enabled_segments.last()
+        {
+            spans.push(self.cap_span(cap, *last_id, last_data));
         }
 
         Line::from(spans)
     }
 
-    /// 渲染 Powerline 模式（带背景色和箭头过渡）
-    fn render_powerline(&self) -> Line<'static> {
-        let mut spans: Vec<Span<'static>> = Vec::new();
+    /// 构建行首/行尾 cap 的 span；背景策略与衔接处一致，唯一的邻居即参照 segment
+    fn cap_span(
+        &self,
+        cap: &str,
+        adjacent: SegmentId,
+        adjacent_data: &SegmentData,
+    ) -> Span<'static> {
+        let bg = match self.config.separator_bg {
+            SeparatorBg::Mode(SeparatorBgMode::None) => None,
+            SeparatorBg::Mode(_) => self.effective_bg(adjacent, adjacent_data),
+            SeparatorBg::Color(color) => Some(self.resolve_color(color)),
+        };
+
+        let mut style = Style::default();
+        if let Some(bg) = bg {
+            style = style.bg(bg);
+        }
+        Span::styled(cap.to_string(), style).dim()
+    }
 
-        // 收集启用的 segment
-        let enabled_segments: Vec<_> = self
-            .segments
-            .iter()
-            .filter(|(id, _)| self.config.get_segment_config(*id).enabled)
-            .collect();
+    /// 构建两个相邻 segment 之间的分隔符 span
+    fn junction_span(
+        &self,
+        separator: &str,
+        prev: (SegmentId, &SegmentData),
+        next: (SegmentId, &SegmentData),
+    ) -> Span<'static> {
+        let bg = match self.config.separator_bg {
+            SeparatorBg::Mode(SeparatorBgMode::None) => None,
+            SeparatorBg::Mode(SeparatorBgMode::Previous) => self.effective_bg(prev.0, prev.1),
+            SeparatorBg::Mode(SeparatorBgMode::Next) => self.effective_bg(next.0, next.1),
+            SeparatorBg::Color(color) => Some(self.resolve_color(color)),
+        };
+
+        let mut style = Style::default();
+        if let Some(bg) = bg {
+            style = style.bg(bg);
+        }
+        Span::styled(separator.to_string(), style).dim()
+    }
 
-        let segment_count = enabled_segments.len();
+    /// 渲染单个 segment 的 span 序列（普通模式）
+    fn plain_segment_spans(&self, id: SegmentId, data: &SegmentData) -> Vec<Span<'static>> {
+        let segment_config = self.config.get_segment_config(id);
+        let (text_color, bg_color, bold) = self.effective_style(id, data);
+        let mut spans = Vec::new();
+
+        // 渲染图标
+        let icon = self.get_icon(id, data);
+        if !icon.is_empty() {
+            let mut icon_style = Style::default();
+            if let Some(color) = segment_config.colors.icon.map(|c| self.resolve_color(c)) {
+                icon_style = icon_style.fg(color);
+            }
+            if let Some(bg) = bg_color {
+                icon_style = icon_style.bg(bg);
+            }
+            spans.push(Span::styled(format!("{icon} "), icon_style));
+        }
 
-        for (i, (id, data)) in enabled_segments.iter().enumerate() {
-            let segment_config = self.config.get_segment_config(*id);
+        // 渲染主要内容
+        let mut text_style = Style::default();
+        if let Some(color) = text_color {
+            text_style = text_style.fg(color);
+        }
+        if let Some(bg) = bg_color {
+            text_style = text_style.bg(bg);
+        }
+        if bold {
+            text_style = text_style.bold();
+        }
+        spans.push(Span::styled(data.primary.clone(), text_style));
 
-            // 获取背景色
-            let bg_color = segment_config.colors.background_color();
-            let text_color = segment_config.colors.text_color();
-            let icon_color = segment_config.colors.icon_color();
+        // 渲染次要内容
+        if !data.secondary.is_empty() {
+            spans.push(Span::styled(format!(" {}", data.secondary), text_style));
+        }
 
-            // 构建 segment 样式
-            let mut segment_style = Style::default();
-            if let Some(bg) = bg_color {
-                segment_style = segment_style.bg(bg);
-            }
-            if let Some(fg) = text_color {
-                segment_style = segment_style.fg(fg);
-            }
-            if segment_config.styles.text_bold {
-                segment_style = segment_style.bold();
-            }
+        // 本回合 token 增量（可选）
+        if let Some(delta_span) = self.turn_delta_span(id, data, text_style) {
+            spans.push(delta_span);
+        }
 
-            // 添加左边距
-            spans.push(Span::styled(" ", segment_style));
+        spans
+    }
 
-            // 渲染图标
-            let icon = self.get_icon(*id, data);
-            if !icon.is_empty() {
-                let mut icon_style = segment_style;
-                if let Some(ic) = icon_color {
-                    icon_style = icon_style.fg(ic);
-                }
-                spans.push(Span::styled(format!("{icon} "), icon_style));
-            }
+    /// 渲染 Powerline 模式（带背景色和箭头过渡）
+    fn render_powerline(&self) -> Line<'static> {
+        let enabled_segments = self.enabled_segments();
+        let groups = self.render_segments();
+        let segment_count = groups.len();
 
-            // 渲染主要内容
-            spans.push(Span::styled(data.primary.clone(), segment_style));
+        let mut spans: Vec<Span<'static>> = Vec::new();
 
-            // 渲染次要内容
-            if !data.secondary.is_empty() {
-                spans.push(Span::styled(format!(" {}", data.secondary), segment_style));
+        // 行首 cap（fg 取第一个 segment 的背景，形成圆角/斜角开头）
+        if let Some(cap) = self.config.separators.left_cap.as_deref()
+            && let Some((first_id, first_data)) = enabled_segments.first()
+        {
+            let mut style = Style::default();
+            if let Some(bg) = self.effective_bg(*first_id, first_data) {
+                style = style.fg(bg);
             }
+            spans.push(Span::styled(cap.to_string(), style));
+        }
+
+        // segment 之间的过渡字形（可由 separators.inner 覆盖）
+        let arrow = self
+            .config
+            .separators
+            .inner
+            .as_deref()
+            .unwrap_or(POWERLINE_ARROW);
 
-            // 添加右边距
-            spans.push(Span::styled(" ", segment_style));
+        for (i, (_, group)) in groups.into_iter().enumerate() {
+            spans.extend(group);
 
             // 添加 Powerline 箭头过渡（最后一个 segment 不需要箭头）
             if i < segment_count - 1 {
-                let next_segment_config = self.config.get_segment_config(enabled_segments[i + 1].0);
-                let next_bg = next_segment_config.colors.background_color();
+                let (curr_id, curr_data) = enabled_segments[i];
+                let (next_id, next_data) = enabled_segments[i + 1];
 
                 let mut arrow_style = Style::default();
-                if let Some(curr_bg) = bg_color {
+                if let Some(curr_bg) = self.effective_bg(curr_id, curr_data) {
                     arrow_style = arrow_style.fg(curr_bg);
                 }
-                if let Some(next_bg_color) = next_bg {
-                    arrow_style = arrow_style.bg(next_bg_color);
+                if let Some(next_bg) = self.effective_bg(next_id, next_data) {
+                    arrow_style = arrow_style.bg(next_bg);
                 }
-                spans.push(Span::styled(POWERLINE_ARROW, arrow_style));
+                spans.push(Span::styled(arrow.to_string(), arrow_style));
+            }
+        }
+
+        // 行尾 cap（fg 取最后一个 segment 的背景）
+        if let Some(cap) = self.config.separators.right_cap.as_deref()
+            && let Some((last_id, last_data)) = enabled_segments.last()
+        {
+            let mut style = Style::default();
+            if let Some(bg) = self.effective_bg(*last_id, last_data) {
+                style = style.fg(bg);
             }
+            spans.push(Span::styled(cap.to_string(), style));
         }
 
         Line::from(spans)
     }
 
-    /// 获取分隔符
-    fn get_separator(&self) -> &'static str {
-        match self.config.style {
-            StyleMode::Powerline => separators::POWERLINE_THIN,
-            _ => separators::SIMPLE,
+    /// 渲染单个 segment 的 span 序列（Powerline 模式，含左右边距，不含箭头）
+    fn powerline_segment_spans(&self, id: SegmentId, data: &SegmentData) -> Vec<Span<'static>> {
+        let segment_config = self.config.get_segment_config(id);
+
+        // 获取配色（含条件规则覆盖）
+        let (text_color, bg_color, bold) = self.effective_style(id, data);
+        let icon_color = segment_config.colors.icon.map(|c| self.resolve_color(c));
+
+        // 构建 segment 样式
+        let mut segment_style = Style::default();
+        if let Some(bg) = bg_color {
+            segment_style = segment_style.bg(bg);
+        }
+        if let Some(fg) = text_color {
+            segment_style = segment_style.fg(fg);
         }
+        if bold {
+            segment_style = segment_style.bold();
+        }
+
+        let mut spans = Vec::new();
+
+        // 添加左边距
+        spans.push(Span::styled(" ", segment_style));
+
+        // 渲染图标
+        let icon = self.get_icon(id, data);
+        if !icon.is_empty() {
+            let mut icon_style = segment_style;
+            if let Some(ic) = icon_color {
+                icon_style = icon_style.fg(ic);
+            }
+            spans.push(Span::styled(format!("{icon} "), icon_style));
+        }
+
+        // 渲染主要内容
+        spans.push(Span::styled(data.primary.clone(), segment_style));
+
+        // 渲染次要内容
+        if !data.secondary.is_empty() {
+            spans.push(Span::styled(format!(" {}", data.secondary), segment_style));
+        }
+
+        // 本回合 token 增量（可选）
+        if let Some(delta_span) = self.turn_delta_span(id, data, segment_style) {
+            spans.push(delta_span);
+        }
+
+        // 添加右边距
+        spans.push(Span::styled(" ", segment_style));
+
+        spans
+    }
+
+    /// 获取 segment 之间的分隔符（separators.inner 优先，回退旧的单字段）
+    fn get_separator(&self) -> &str {
+        self.config.inner_separator()
     }
 
     /// 获取图标
     fn get_icon(&self, id: SegmentId, data: &SegmentData) -> String {
-        // 优先使用动态图标（从元数据）
+        // 用户自定义的阈值表优先于 collect 给出的默认动态图标
+        if let Some(value) = self
+            .config
+            .get_segment_config(id)
+            .options
+            .get("icon_thresholds")
+            && let Some(rows) = usage::parse_icon_thresholds(value)
+            && let Some(percent) = data
+                .metadata
+                .get("weekly_percent")
+                .and_then(|p| p.parse::<f64>().ok())
+            && let Some(icon) = usage::icon_for_percent(&rows, percent)
+        {
+            return icon.to_string();
+        }
+
+        // 其次使用动态图标（从元数据）
         if let Some(dynamic_icon) = data.metadata.get("dynamic_icon") {
             return dynamic_icon.clone();
         }
@@ -214,3 +532,476 @@ impl WidgetRef for StatusLineWidget<'_> {
         buf.set_line(area.x, area.y, &line, area.width);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::statusline::StatusLineContext;
+    use crate::statusline::build_statusline;
+    use crate::statusline::rules::RuleCmp;
+    use crate::statusline::rules::SegmentRule;
+    use crate::statusline::style::AnsiColor;
+    use crate::statusline::style::separators;
+    use crate::statusline::themes::THEME_NAMES;
+    use crate::statusline::themes::ThemePresets;
+    use codex_protocol::openai_models::ReasoningEffort;
+    use ratatui::style::Modifier;
+    use std::path::Path;
+
+    /// 三个带背景色的 segment，用于验证衔接策略
+    fn colored_config() -> CxLineConfig {
+        let mut config = ThemePresets::get_default();
+        config.style = StyleMode::Plain;
+        config.segments.model.colors.background = Some(AnsiColor::rgb(10, 10, 10));
+        config.segments.directory.colors.background = Some(AnsiColor::rgb(20, 20, 20));
+        config.segments.git.colors.background = Some(AnsiColor::rgb(30, 30, 30));
+        config
+    }
+
+    fn render_three(config: &CxLineConfig) -> Line<'static> {
+        let mut renderer = StatusLineRenderer::new(config);
+        renderer.add_segment(SegmentId::Model, SegmentData::new("model"));
+        renderer.add_segment(SegmentId::Directory, SegmentData::new("dir"));
+        renderer.add_segment(SegmentId::Git, SegmentData::new("git"));
+        renderer.render_line()
+    }
+
+    fn separator_bgs(line: &Line<'_>) -> Vec<Option<Color>> {
+        line.spans
+            .iter()
+            .filter(|s| s.content.as_ref() == separators::SIMPLE)
+            .map(|s| s.style.bg)
+            .collect()
+    }
+
+    #[test]
+    fn test_separator_bg_none_is_default() {
+        let config = colored_config();
+        let line = render_three(&config);
+        assert_eq!(separator_bgs(&line), vec![None, None]);
+    }
+
+    #[test]
+    fn test_separator_bg_previous() {
+        let mut config = colored_config();
+        config.separator_bg = SeparatorBg::Mode(SeparatorBgMode::Previous);
+        let line = render_three(&config);
+        assert_eq!(
+            separator_bgs(&line),
+            vec![Some(Color::Rgb(10, 10, 10)), Some(Color::Rgb(20, 20, 20))]
+        );
+    }
+
+    #[test]
+    fn test_separator_bg_next() {
+        let mut config = colored_config();
+        config.separator_bg = SeparatorBg::Mode(SeparatorBgMode::Next);
+        let line = render_three(&config);
+        assert_eq!(
+            separator_bgs(&line),
+            vec![Some(Color::Rgb(20, 20, 20)), Some(Color::Rgb(30, 30, 30))]
+        );
+    }
+
+    #[test]
+    fn test_per_position_separators_in_plain_mode() {
+        let mut config = colored_config();
+        config.separators.left_cap = Some("<".to_string());
+        config.separators.inner = Some(" · ".to_string());
+        config.separators.right_cap = Some(">".to_string());
+        let line = render_three(&config);
+        assert_eq!(line.spans.first().unwrap().content.as_ref(), "<");
+        assert_eq!(line.spans.last().unwrap().content.as_ref(), ">");
+        assert_eq!(
+            line.spans
+                .iter()
+                .filter(|s| s.content.as_ref() == " · ")
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_inner_separator_falls_back_to_legacy_field() {
+        let mut config = colored_config();
+        config.separator = " % ".to_string();
+        config.separators.inner = None;
+        let line = render_three(&config);
+        assert_eq!(
+            line.spans
+                .iter()
+                .filter(|s| s.content.as_ref() == " % ")
+                .count(),
+            2
+        );
+    }
+
+    fn spans_text(spans: &[Span<'_>]) -> String {
+        spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    #[test]
+    fn test_render_segments_returns_per_segment_spans() {
+        let config = colored_config();
+        let mut renderer = StatusLineRenderer::new(&config);
+        renderer.add_segment(SegmentId::Model, SegmentData::new("model"));
+        renderer.add_segment(SegmentId::Git, SegmentData::new("main").with_secondary("✓"));
+
+        let groups = renderer.render_segments();
+        assert_eq!(
+            groups.iter().map(|(id, _)| *id).collect::<Vec<_>>(),
+            vec![SegmentId::Model, SegmentId::Git]
+        );
+
+        // Model: 图标 + 主要内容
+        let (_, model_spans) = &groups[0];
+        assert_eq!(model_spans.len(), 2);
+        assert_eq!(model_spans[0].content.as_ref(), "🤖 ");
+        assert_eq!(model_spans[1].content.as_ref(), "model");
+        assert_eq!(model_spans[1].style.bg, Some(Color::Rgb(10, 10, 10)));
+
+        // Git: 图标 + 主要内容 + 次要内容
+        let (_, git_spans) = &groups[1];
+        assert_eq!(git_spans.len(), 3);
+        assert_eq!(git_spans[1].content.as_ref(), "main");
+        assert_eq!(git_spans[2].content.as_ref(), " ✓");
+        assert_eq!(git_spans[2].style.bg, Some(Color::Rgb(30, 30, 30)));
+    }
+
+    #[test]
+    fn test_fitted_render_drops_trailing_segments_at_narrow_widths() {
+        let config = colored_config();
+        let mut renderer = StatusLineRenderer::new(&config);
+        renderer.add_segment(SegmentId::Model, SegmentData::new("model"));
+        renderer.add_segment(SegmentId::Directory, SegmentData::new("dir"));
+        renderer.add_segment(SegmentId::Git, SegmentData::new("git"));
+
+        // 放得下时与不限宽渲染一致
+        let full = renderer.render_line();
+        let fitted = renderer.render_line_fitted(full.width() as u16);
+        assert_eq!(spans_text(&fitted.spans), spans_text(&full.spans));
+
+        // 同一份收集数据在更窄的宽度下从右往左收缩
+        let narrow = renderer.render_line_fitted(full.width() as u16 - 1);
+        assert!(narrow.width() < full.width());
+        let text = spans_text(&narrow.spans);
+        assert!(text.contains("model"));
+        assert!(!text.contains("git"));
+
+        // 只剩一个 segment 时不再继续丢弃
+        let tiny = renderer.render_line_fitted(1);
+        assert!(spans_text(&tiny.spans).contains("model"));
+    }
+
+    #[test]
+    fn test_segment_extents_exclude_separator_columns() {
+        let config = colored_config();
+        let mut renderer = StatusLineRenderer::new(&config);
+        renderer.add_segment(SegmentId::Model, SegmentData::new("model"));
+        renderer.add_segment(SegmentId::Directory, SegmentData::new("dir"));
+        renderer.add_segment(SegmentId::Git, SegmentData::new("git"));
+
+        // "🤖 model" 占 8 列，" │ " 分隔符占 3 列，依此类推
+        let extents = renderer.segment_extents();
+        assert_eq!(
+            extents,
+            vec![
+                (SegmentId::Model, 0..8),
+                (SegmentId::Directory, 11..17),
+                (SegmentId::Git, 20..26),
+            ]
+        );
+
+        // 区间总宽度与渲染行一致（末尾没有分隔符）
+        assert_eq!(renderer.render_line().width(), 26);
+
+        // 分隔符上的列不属于任何 segment
+        for col in [8, 9, 10, 17, 18, 19] {
+            assert!(
+                !extents.iter().any(|(_, range)| range.contains(&col)),
+                "column {col} should not hit a segment"
+            );
+        }
+    }
+
+    #[test]
+    fn test_segment_extents_account_for_left_cap() {
+        let mut config = colored_config();
+        config.separators.left_cap = Some("<".to_string());
+        let mut renderer = StatusLineRenderer::new(&config);
+        renderer.add_segment(SegmentId::Model, SegmentData::new("model"));
+        renderer.add_segment(SegmentId::Directory, SegmentData::new("dir"));
+
+        let extents = renderer.segment_extents();
+        assert_eq!(
+            extents,
+            vec![(SegmentId::Model, 1..9), (SegmentId::Directory, 12..18)]
+        );
+    }
+
+    #[test]
+    fn test_fitted_extents_drop_truncated_segments() {
+        let config = colored_config();
+        let mut renderer = StatusLineRenderer::new(&config);
+        renderer.add_segment(SegmentId::Model, SegmentData::new("model"));
+        renderer.add_segment(SegmentId::Directory, SegmentData::new("dir"));
+        renderer.add_segment(SegmentId::Git, SegmentData::new("git"));
+
+        let full_width = renderer.render_line().width() as u16;
+
+        // 放得下时与不限宽的区间一致
+        assert_eq!(
+            renderer.segment_extents_fitted(full_width),
+            renderer.segment_extents()
+        );
+
+        // 收缩后被丢弃的 git 不再出现，点击它原来的位置不会命中
+        let narrow = renderer.segment_extents_fitted(full_width - 1);
+        assert_eq!(
+            narrow,
+            vec![(SegmentId::Model, 0..8), (SegmentId::Directory, 11..17)]
+        );
+        assert!(!narrow.iter().any(|(_, range)| range.contains(&20)));
+    }
+
+    #[test]
+    fn test_render_segments_skips_disabled_segments() {
+        let mut config = colored_config();
+        config.segments.directory.enabled = false;
+        let mut renderer = StatusLineRenderer::new(&config);
+        renderer.add_segment(SegmentId::Model, SegmentData::new("model"));
+        renderer.add_segment(SegmentId::Directory, SegmentData::new("dir"));
+        renderer.add_segment(SegmentId::Git, SegmentData::new("git"));
+
+        let groups = renderer.render_segments();
+        assert_eq!(
+            groups.iter().map(|(id, _)| *id).collect::<Vec<_>>(),
+            vec![SegmentId::Model, SegmentId::Git]
+        );
+    }
+
+    #[test]
+    fn test_render_line_is_join_of_render_segments_plain() {
+        let mut config = colored_config();
+        config.separators.left_cap = Some("<".to_string());
+        config.separators.right_cap = Some(">".to_string());
+        let mut renderer = StatusLineRenderer::new(&config);
+        renderer.add_segment(SegmentId::Model, SegmentData::new("model"));
+        renderer.add_segment(SegmentId::Directory, SegmentData::new("dir"));
+        renderer.add_segment(SegmentId::Git, SegmentData::new("git"));
+
+        let joined = format!(
+            "<{}>",
+            renderer
+                .render_segments()
+                .iter()
+                .map(|(_, spans)| spans_text(spans))
+                .collect::<Vec<_>>()
+                .join(config.inner_separator())
+        );
+        assert_eq!(spans_text(&renderer.render_line().spans), joined);
+    }
+
+    #[test]
+    fn test_render_line_is_join_of_render_segments_powerline() {
+        let mut config = colored_config();
+        config.style = StyleMode::Powerline;
+        let mut renderer = StatusLineRenderer::new(&config);
+        renderer.add_segment(SegmentId::Model, SegmentData::new("model"));
+        renderer.add_segment(SegmentId::Directory, SegmentData::new("dir"));
+        renderer.add_segment(SegmentId::Git, SegmentData::new("git"));
+
+        let joined = renderer
+            .render_segments()
+            .iter()
+            .map(|(_, spans)| spans_text(spans))
+            .collect::<Vec<_>>()
+            .join(POWERLINE_ARROW);
+        assert_eq!(spans_text(&renderer.render_line().spans), joined);
+    }
+
+    fn render_context_delta(config: &CxLineConfig, delta: i64) -> Line<'static> {
+        let mut renderer = StatusLineRenderer::new(config);
+        renderer.add_segment(
+            SegmentId::Context,
+            SegmentData::new("3% · 4.2k tokens")
+                .with_metadata("turn_delta", delta.to_string())
+                .with_metadata("turn_delta_display", format!("+{delta}")),
+        );
+        renderer.render_line()
+    }
+
+    #[test]
+    fn test_show_turn_delta_option_renders_colored_delta() {
+        let mut config = colored_config();
+        config
+            .segments
+            .context
+            .options
+            .insert("show_turn_delta".to_string(), serde_json::json!(true));
+        config.segments.context.options.insert(
+            "turn_delta_warn_tokens".to_string(),
+            serde_json::json!(3_000),
+        );
+
+        // 低于阈值：沿用 segment 文本样式
+        let line = render_context_delta(&config, 200);
+        let span = line.spans.last().unwrap();
+        assert_eq!(span.content.as_ref(), " +200");
+        assert_ne!(span.style.fg, Some(Color::Yellow));
+
+        // 达到阈值：黄色告警
+        let line = render_context_delta(&config, 3_200);
+        let span = line.spans.last().unwrap();
+        assert_eq!(span.content.as_ref(), " +3200");
+        assert_eq!(span.style.fg, Some(Color::Yellow));
+    }
+
+    #[test]
+    fn test_turn_delta_hidden_without_option() {
+        let config = colored_config();
+        let line = render_context_delta(&config, 200);
+        assert_eq!(
+            line.spans.last().unwrap().content.as_ref(),
+            "3% · 4.2k tokens"
+        );
+    }
+
+    /// 把 Line 按 span 展开为可读文本，便于 snapshot 对比每个 span 的样式
+    fn describe_line(line: &Line<'_>) -> String {
+        line.spans
+            .iter()
+            .map(|span| {
+                format!(
+                    "{:?} fg={:?} bg={:?} mods={}",
+                    span.content.as_ref(),
+                    span.style.fg,
+                    span.style.bg,
+                    describe_modifiers(span.style.add_modifier)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Modifier 的稳定文本形式：只列出状态栏会用到的 BOLD/DIM，
+    /// 不依赖 ratatui 的 Debug 输出格式
+    fn describe_modifiers(modifier: Modifier) -> String {
+        let mut names = Vec::new();
+        if modifier.contains(Modifier::BOLD) {
+            names.push("BOLD");
+        }
+        if modifier.contains(Modifier::DIM) {
+            names.push("DIM");
+        }
+        if names.is_empty() {
+            "NONE".to_string()
+        } else {
+            names.join("|")
+        }
+    }
+
+    #[test]
+    fn test_git_rule_flips_background_when_behind() {
+        let mut config = ThemePresets::get_default();
+        config.style = StyleMode::Plain;
+        config.segments.git.rules = vec![SegmentRule {
+            key: "behind".to_string(),
+            cmp: RuleCmp::Gt,
+            value: Some("0".to_string()),
+            fg: None,
+            bg: Some(AnsiColor::rgb(120, 40, 40)),
+            bold: None,
+        }];
+
+        let render = |behind: &str| {
+            let mut renderer = StatusLineRenderer::new(&config);
+            renderer.add_segment(
+                SegmentId::Git,
+                SegmentData::new("main")
+                    .with_secondary("✓")
+                    .with_metadata("behind", behind),
+            );
+            describe_line(&renderer.render_line())
+        };
+
+        let in_sync = render("0");
+        let behind = render("3");
+        insta::assert_snapshot!("git_rule_behind_in_sync", in_sync);
+        insta::assert_snapshot!("git_rule_behind_nonzero", behind);
+    }
+
+    /// 全量填充的确定性上下文：git/时间等易变输入全部来自固定的假数据，
+    /// 渲染结果只随配置变化，可安全用于 snapshot
+    fn golden_context() -> StatusLineContext<'static> {
+        StatusLineContext::new("gpt-5.1-codex-max", Path::new("/home/dev/codex"))
+            .with_reasoning_effort(Some(ReasoningEffort::High))
+            .with_context(Some(40_960), Some(128_000))
+            .with_rate_limit(Some(42.0), Some(63.0), Some("Oct 12".to_string()))
+            .with_git_preview("main", "●", 2, 1)
+            .with_alert(false, 3)
+    }
+
+    /// 所有内置主题 × 所有样式模式的渲染黄金样本，
+    /// 防止分隔符间距、颜色扩散、图标回退之类的回归悄悄溜进来
+    #[test]
+    fn test_golden_render_across_themes_and_style_modes() {
+        let ctx = golden_context();
+        for &theme_name in THEME_NAMES {
+            let mut config =
+                ThemePresets::get_builtin(theme_name).expect("builtin theme should exist");
+            for (mode, mode_name) in [
+                (StyleMode::Plain, "plain"),
+                (StyleMode::NerdFont, "nerd_font"),
+                (StyleMode::Powerline, "powerline"),
+            ] {
+                config.style = mode;
+                let described = describe_line(&build_statusline(&config, &ctx).render_line());
+                insta::assert_snapshot!(format!("golden_{theme_name}_{mode_name}"), described);
+            }
+        }
+    }
+
+    #[test]
+    fn test_color_depth_16_downmaps_rgb_backgrounds() {
+        let mut config = colored_config();
+        config.color_depth = ColorDepth::Ansi16;
+        let line = render_three(&config);
+        for span in &line.spans {
+            if let Some(bg) = span.style.bg {
+                assert!(!matches!(bg, Color::Rgb(..)), "真彩色背景未降映射: {bg:?}");
+            }
+        }
+        // rgb(10, 10, 10) 最近的 16 色是黑色
+        let model_bg = line
+            .spans
+            .iter()
+            .find(|s| s.content.as_ref() == "model")
+            .and_then(|s| s.style.bg);
+        assert_eq!(model_bg, Some(Color::Black));
+    }
+
+    #[test]
+    fn test_color_depth_truecolor_keeps_rgb_backgrounds() {
+        let mut config = colored_config();
+        config.color_depth = ColorDepth::TrueColor;
+        let line = render_three(&config);
+        let model_bg = line
+            .spans
+            .iter()
+            .find(|s| s.content.as_ref() == "model")
+            .and_then(|s| s.style.bg);
+        assert_eq!(model_bg, Some(Color::Rgb(10, 10, 10)));
+    }
+
+    #[test]
+    fn test_separator_bg_explicit_color() {
+        let mut config = colored_config();
+        config.separator_bg = SeparatorBg::Color(AnsiColor::rgb(1, 2, 3));
+        let line = render_three(&config);
+        assert_eq!(
+            separator_bgs(&line),
+            vec![Some(Color::Rgb(1, 2, 3)), Some(Color::Rgb(1, 2, 3))]
+        );
+    }
+}
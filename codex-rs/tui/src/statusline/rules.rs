@@ -0,0 +1,193 @@
+// Segment 条件样式规则
+// 按 SegmentData.metadata 中的键值匹配，命中的第一条规则覆盖配色/样式
+// 表达式语言刻意保持极简：一个键、一种比较、一个值
+
+use super::style::AnsiColor;
+use serde::Deserialize;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// 规则的比较方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RuleCmp {
+    /// 字符串相等
+    Eq,
+    /// 数值大于（两侧都按数字解析，解析失败视为不匹配）
+    Gt,
+    /// 数值小于（两侧都按数字解析，解析失败视为不匹配）
+    Lt,
+    /// 子串包含
+    Contains,
+    /// 元数据键不存在（不需要 value）
+    Absent,
+}
+
+impl RuleCmp {
+    /// 该比较方式是否需要 value
+    pub fn requires_value(self) -> bool {
+        !matches!(self, Self::Absent)
+    }
+
+    /// 该比较方式的 value 是否必须是数字
+    pub fn requires_numeric_value(self) -> bool {
+        matches!(self, Self::Gt | Self::Lt)
+    }
+}
+
+/// 单条条件样式规则
+/// 例：`{ key = "behind", cmp = "gt", value = "0", bg = { r = 120, g = 40, b = 40 } }`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SegmentRule {
+    /// 要检查的元数据键名
+    pub key: String,
+
+    /// 比较方式
+    pub cmp: RuleCmp,
+
+    /// 比较值（`absent` 时省略）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+
+    /// 命中时覆盖文本颜色
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fg: Option<AnsiColor>,
+
+    /// 命中时覆盖背景颜色
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bg: Option<AnsiColor>,
+
+    /// 命中时覆盖加粗状态
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bold: Option<bool>,
+}
+
+impl SegmentRule {
+    /// 规则是否命中给定的元数据
+    pub fn matches(&self, metadata: &HashMap<String, String>) -> bool {
+        let actual = metadata.get(&self.key);
+        match self.cmp {
+            RuleCmp::Absent => actual.is_none(),
+            RuleCmp::Eq => {
+                let Some(expected) = self.value.as_deref() else {
+                    return false;
+                };
+                actual.map(String::as_str) == Some(expected)
+            }
+            RuleCmp::Contains => {
+                let Some(expected) = self.value.as_deref() else {
+                    return false;
+                };
+                actual.is_some_and(|v| v.contains(expected))
+            }
+            RuleCmp::Gt | RuleCmp::Lt => {
+                let Some(expected) = self.value.as_deref().and_then(|v| v.parse::<f64>().ok())
+                else {
+                    return false;
+                };
+                let Some(actual) = actual.and_then(|v| v.parse::<f64>().ok()) else {
+                    return false;
+                };
+                match self.cmp {
+                    RuleCmp::Gt => actual > expected,
+                    _ => actual < expected,
+                }
+            }
+        }
+    }
+
+    /// 加载时校验；Err 携带具体原因（不含路径，路径由调用方拼接）
+    pub fn validate(&self) -> Result<(), String> {
+        if self.key.is_empty() {
+            return Err("key 不能为空".to_string());
+        }
+        if self.cmp.requires_value() && self.value.is_none() {
+            return Err(format!("cmp = {:?} 需要 value", self.cmp));
+        }
+        if self.cmp.requires_numeric_value()
+            && let Some(value) = self.value.as_deref()
+            && value.parse::<f64>().is_err()
+        {
+            return Err(format!("cmp = {:?} 的 value 必须是数字，得到 {value:?}"));
+        }
+        if self.fg.is_none() && self.bg.is_none() && self.bold.is_none() {
+            return Err("规则没有任何 fg/bg/bold 覆盖，不会产生效果".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// 按序查找第一条命中的规则
+pub fn first_match<'a>(
+    rules: &'a [SegmentRule],
+    metadata: &HashMap<String, String>,
+) -> Option<&'a SegmentRule> {
+    rules.iter().find(|rule| rule.matches(metadata))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(key: &str, cmp: RuleCmp, value: Option<&str>) -> SegmentRule {
+        SegmentRule {
+            key: key.to_string(),
+            cmp,
+            value: value.map(str::to_string),
+            fg: None,
+            bg: Some(AnsiColor::rgb(1, 2, 3)),
+            bold: None,
+        }
+    }
+
+    fn metadata(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_comparisons() {
+        let md = metadata(&[("behind", "3"), ("branch", "feature/x")]);
+        assert!(rule("behind", RuleCmp::Gt, Some("0")).matches(&md));
+        assert!(!rule("behind", RuleCmp::Gt, Some("3")).matches(&md));
+        assert!(rule("behind", RuleCmp::Lt, Some("10")).matches(&md));
+        assert!(rule("behind", RuleCmp::Eq, Some("3")).matches(&md));
+        assert!(rule("branch", RuleCmp::Contains, Some("feature")).matches(&md));
+        assert!(rule("missing", RuleCmp::Absent, None).matches(&md));
+        assert!(!rule("behind", RuleCmp::Absent, None).matches(&md));
+        // 非数字的一侧不匹配 gt/lt
+        assert!(!rule("branch", RuleCmp::Gt, Some("0")).matches(&md));
+    }
+
+    #[test]
+    fn test_first_match_respects_order() {
+        let md = metadata(&[("behind", "3")]);
+        let rules = vec![
+            rule("behind", RuleCmp::Gt, Some("5")),
+            rule("behind", RuleCmp::Gt, Some("0")),
+            rule("behind", RuleCmp::Gt, Some("1")),
+        ];
+        assert_eq!(first_match(&rules, &md), Some(&rules[1]));
+        assert_eq!(first_match(&rules, &metadata(&[])), None);
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(rule("behind", RuleCmp::Gt, Some("0")).validate().is_ok());
+        assert!(rule("", RuleCmp::Gt, Some("0")).validate().is_err());
+        // 非 absent 比较缺少 value
+        assert!(rule("behind", RuleCmp::Eq, None).validate().is_err());
+        // gt/lt 的 value 必须是数字
+        assert!(
+            rule("behind", RuleCmp::Gt, Some("many"))
+                .validate()
+                .is_err()
+        );
+        // 没有任何覆盖的规则无效
+        let mut no_effect = rule("behind", RuleCmp::Gt, Some("0"));
+        no_effect.bg = None;
+        assert!(no_effect.validate().is_err());
+    }
+}
@@ -3,6 +3,8 @@
 
 use ratatui::style::Color;
 use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
 
 /// Segment 数据，由各 Segment 实现收集后返回
 #[derive(Debug, Clone, Default)]
@@ -76,6 +78,7 @@ pub enum SegmentId {
     Git,
     Context,
     Usage,
+    Alert,
 }
 
 impl SegmentId {
@@ -86,6 +89,7 @@ impl SegmentId {
             Self::Git => "git",
             Self::Context => "context",
             Self::Usage => "usage",
+            Self::Alert => "alert",
         }
     }
 }
@@ -97,4 +101,51 @@ pub trait Segment {
 
     /// 返回 segment ID
     fn id(&self) -> SegmentId;
+
+    /// 本 segment 读取的上下文字段，memo 层据此计算输入哈希
+    /// 默认 `None`：退出 memoization，每帧真正收集
+    /// （依赖时间或外部状态的 segment 保持默认即可）
+    fn dependencies(&self) -> Option<&'static [super::memo::ContextField]> {
+        None
+    }
+}
+
+/// 异步收集的 Segment（需要 IO 的 segment 实现此 trait）
+/// 纯计算 segment 走同步 `Segment::collect`；宿主以短超时驱动本方法，
+/// 超时后回退到上一次成功收集的值，慢 segment 不会卡住整行
+/// future 要求 `'static`：实现方从 ctx 克隆所需数据，不跨 await 借用 ctx
+pub trait AsyncSegment: Segment {
+    /// 异步收集 segment 数据
+    fn collect_async(
+        &self,
+        ctx: &super::StatusLineContext,
+    ) -> Pin<Box<dyn Future<Output = Option<SegmentData>> + Send + 'static>>;
+}
+
+/// 各 segment 上一次成功收集的数据
+/// 异步收集超时的回合用这里的值顶替，等下一回合再取新数据
+#[derive(Debug, Clone, Default)]
+pub struct SegmentDataCache {
+    entries: HashMap<SegmentId, SegmentData>,
+}
+
+impl SegmentDataCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 记录一次成功收集的结果
+    pub fn insert(&mut self, id: SegmentId, data: SegmentData) {
+        self.entries.insert(id, data);
+    }
+
+    /// 取上一次成功收集的结果
+    pub fn get(&self, id: SegmentId) -> Option<&SegmentData> {
+        self.entries.get(&id)
+    }
+
+    /// 丢弃某个 segment 的缓存（segment 主动隐藏时调用）
+    pub fn remove(&mut self, id: SegmentId) {
+        self.entries.remove(&id);
+    }
 }
@@ -0,0 +1,180 @@
+// Alert Segment - 终端失焦时的可视化提醒
+//
+// 桌面通知不可靠时的兜底：失焦期间发生审批请求 / 回合完成等事件，
+// 状态栏点亮一个持续的标记（图标 + 错过的事件数），重新聚焦后清除。
+
+use crate::statusline::StatusLineContext;
+use crate::statusline::config::SegmentItemConfig;
+use crate::statusline::memo::ContextField;
+use crate::statusline::segment::Segment;
+use crate::statusline::segment::SegmentData;
+use crate::statusline::segment::SegmentId;
+
+pub struct AlertSegment;
+
+impl Segment for AlertSegment {
+    fn collect(&self, ctx: &StatusLineContext) -> Option<SegmentData> {
+        // 聚焦时保持隐藏；只有失焦期间积累了事件才显示
+        if ctx.terminal_focused || ctx.alert_missed_count == 0 {
+            return None;
+        }
+        Some(
+            SegmentData::new(format!("{}", ctx.alert_missed_count))
+                .with_metadata("missed_count", format!("{}", ctx.alert_missed_count)),
+        )
+    }
+
+    fn id(&self) -> SegmentId {
+        SegmentId::Alert
+    }
+
+    fn dependencies(&self) -> Option<&'static [ContextField]> {
+        Some(&[
+            ContextField::TerminalFocused,
+            ContextField::AlertMissedCount,
+        ])
+    }
+}
+
+/// 会点亮 Alert segment 的事件类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlertEventKind {
+    /// 回合完成
+    TurnComplete,
+    /// 审批请求（命令执行 / 文件编辑 / MCP elicitation）
+    Approval,
+    /// Plan 模式提示
+    PlanPrompt,
+}
+
+impl AlertEventKind {
+    /// 该事件类型对应的 options 开关键名
+    fn option_key(self) -> &'static str {
+        match self {
+            Self::TurnComplete => "latch_turn_complete",
+            Self::Approval => "latch_approvals",
+            Self::PlanPrompt => "latch_plan_prompts",
+        }
+    }
+}
+
+/// 按 segment 配置判断某类事件是否点亮 Alert（未配置时默认全部点亮）
+pub fn kind_latches(config: &SegmentItemConfig, kind: AlertEventKind) -> bool {
+    config
+        .options
+        .get(kind.option_key())
+        .and_then(serde_json::Value::as_bool)
+        .unwrap_or(true)
+}
+
+/// Alert 闩锁状态机
+///
+/// 失焦期间每个事件加一；重新聚焦时清零。聚焦状态下的事件不计数
+/// （用户正看着屏幕，不需要提醒）。
+#[derive(Debug, Clone)]
+pub struct AlertLatch {
+    focused: bool,
+    missed: u32,
+}
+
+impl Default for AlertLatch {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AlertLatch {
+    pub fn new() -> Self {
+        // 进程启动时终端必然是聚焦的
+        Self {
+            focused: true,
+            missed: 0,
+        }
+    }
+
+    /// 焦点变化；返回是否有状态改变（需要重新推送状态栏数据）
+    pub fn on_focus_changed(&mut self, focused: bool) -> bool {
+        if self.focused == focused {
+            return false;
+        }
+        self.focused = focused;
+        if focused {
+            self.missed = 0;
+        }
+        true
+    }
+
+    /// 发生一个事件；失焦时计数，返回是否点亮了新状态
+    pub fn on_event(&mut self) -> bool {
+        if self.focused {
+            return false;
+        }
+        self.missed = self.missed.saturating_add(1);
+        true
+    }
+
+    pub fn focused(&self) -> bool {
+        self.focused
+    }
+
+    pub fn missed_count(&self) -> u32 {
+        self.missed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn latch_counts_events_only_while_unfocused() {
+        let mut latch = AlertLatch::new();
+        // 聚焦状态下的事件不计数
+        assert!(!latch.on_event());
+        assert_eq!(latch.missed_count(), 0);
+
+        assert!(latch.on_focus_changed(false));
+        assert!(latch.on_event());
+        assert!(latch.on_event());
+        assert_eq!(latch.missed_count(), 2);
+
+        // 重新聚焦清零
+        assert!(latch.on_focus_changed(true));
+        assert_eq!(latch.missed_count(), 0);
+        // 焦点没变时不需要重新推送
+        assert!(!latch.on_focus_changed(true));
+    }
+
+    #[test]
+    fn segment_hides_while_focused_and_shows_missed_count() {
+        let cwd = std::path::Path::new("/tmp");
+        let mut ctx = StatusLineContext::new("model", cwd);
+        assert!(AlertSegment.collect(&ctx).is_none());
+
+        ctx.terminal_focused = false;
+        assert!(AlertSegment.collect(&ctx).is_none());
+
+        ctx.alert_missed_count = 3;
+        let data = AlertSegment.collect(&ctx).expect("latched alert");
+        assert_eq!(data.primary, "3");
+        assert_eq!(
+            data.metadata.get("missed_count").map(String::as_str),
+            Some("3")
+        );
+    }
+
+    #[test]
+    fn options_control_which_event_kinds_latch() {
+        let mut config = SegmentItemConfig::default_alert();
+        // 未配置时默认全部点亮
+        assert!(kind_latches(&config, AlertEventKind::TurnComplete));
+        assert!(kind_latches(&config, AlertEventKind::Approval));
+
+        config.options.insert(
+            "latch_turn_complete".to_string(),
+            serde_json::Value::Bool(false),
+        );
+        assert!(!kind_latches(&config, AlertEventKind::TurnComplete));
+        assert!(kind_latches(&config, AlertEventKind::Approval));
+    }
+}
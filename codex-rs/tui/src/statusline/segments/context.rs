@@ -1,6 +1,8 @@
 // Context Segment - 显示上下文窗口使用情况
 
 use crate::statusline::StatusLineContext;
+use crate::statusline::locale::NumberLocale;
+use crate::statusline::memo::ContextField;
 use crate::statusline::segment::Segment;
 use crate::statusline::segment::SegmentData;
 use crate::statusline::segment::SegmentId;
@@ -18,28 +20,39 @@ impl Segment for ContextSegment {
             _ => None,
         };
 
+        // 本回合新增的 token 数（turn_start_tokens 由 chat widget 在回合开始时记录）
+        // 无活跃回合或增量为零时不产出，保持状态栏安静
+        let turn_delta = match (ctx.turn_start_tokens, ctx.context_used_tokens) {
+            (Some(start), Some(used)) if used > start => Some(used - start),
+            _ => None,
+        };
+
         // 根据数据情况显示
         match (used_percent, ctx.context_used_tokens) {
             (Some(percent), Some(used_tokens)) => {
                 // 格式: {percentage}% · {tokens} tokens
-                let percentage_display = format!("{percent}%");
-                let tokens_display = format!("{} tokens", format_tokens(used_tokens));
+                let percentage_display = ctx.locale.format_percent(percent as f64, 0);
+                let tokens_display = format!("{} tokens", ctx.locale.format_tokens(used_tokens));
                 let display = format!("{percentage_display} · {tokens_display}");
-                Some(
+                Some(with_turn_delta(
                     SegmentData::new(display)
                         .with_metadata("percent", percent.to_string())
                         .with_metadata("tokens", used_tokens.to_string())
                         .with_metadata("type", "full"),
-                )
+                    turn_delta,
+                    ctx.locale,
+                ))
             }
             (None, Some(used_tokens)) => {
                 // 只有 token 数（没有窗口大小，无法计算百分比）
-                let display = format!("{} tokens", format_tokens(used_tokens));
-                Some(
+                let display = format!("{} tokens", ctx.locale.format_tokens(used_tokens));
+                Some(with_turn_delta(
                     SegmentData::new(display)
                         .with_metadata("tokens", used_tokens.to_string())
                         .with_metadata("type", "tokens"),
-                )
+                    turn_delta,
+                    ctx.locale,
+                ))
             }
             _ => {
                 // 没有数据时显示占位符
@@ -56,16 +69,31 @@ impl Segment for ContextSegment {
     fn id(&self) -> SegmentId {
         SegmentId::Context
     }
+
+    fn dependencies(&self) -> Option<&'static [ContextField]> {
+        Some(&[
+            ContextField::ContextUsedTokens,
+            ContextField::ContextWindowSize,
+            ContextField::TurnStartTokens,
+            ContextField::Locale,
+        ])
+    }
 }
 
-/// 格式化 token 数量
-fn format_tokens(tokens: i64) -> String {
-    if tokens >= 1_000_000 {
-        format!("{:.1}M", tokens as f64 / 1_000_000.0)
-    } else if tokens >= 1_000 {
-        format!("{:.1}k", tokens as f64 / 1_000.0)
-    } else {
-        tokens.to_string()
+/// 把本回合 token 增量写入元数据（渲染器按 `show_turn_delta` 选项决定是否显示）
+fn with_turn_delta(
+    data: SegmentData,
+    turn_delta: Option<i64>,
+    locale: NumberLocale,
+) -> SegmentData {
+    match turn_delta {
+        Some(delta) => data
+            .with_metadata("turn_delta", delta.to_string())
+            .with_metadata(
+                "turn_delta_display",
+                format!("+{}", locale.format_tokens(delta)),
+            ),
+        None => data,
     }
 }
 
@@ -74,10 +102,62 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_format_tokens() {
-        assert_eq!(format_tokens(500), "500");
-        assert_eq!(format_tokens(1500), "1.5k");
-        assert_eq!(format_tokens(150000), "150.0k");
-        assert_eq!(format_tokens(1500000), "1.5M");
+    fn test_turn_delta_across_turn_boundaries() {
+        let cwd = std::path::Path::new("/tmp");
+        let collect = |used: Option<i64>, turn_start: Option<i64>| {
+            let mut ctx = StatusLineContext::new("model", cwd).with_context(used, Some(128_000));
+            ctx.turn_start_tokens = turn_start;
+            ContextSegment.collect(&ctx).unwrap()
+        };
+
+        // 无活跃回合：不产出增量
+        assert!(
+            !collect(Some(4_200), None)
+                .metadata
+                .contains_key("turn_delta")
+        );
+        // 回合刚开始：增量为零，隐藏
+        assert!(
+            !collect(Some(1_000), Some(1_000))
+                .metadata
+                .contains_key("turn_delta")
+        );
+        // 同一回合内从 1000 增长到 4200
+        let data = collect(Some(4_200), Some(1_000));
+        assert_eq!(
+            data.metadata.get("turn_delta").map(String::as_str),
+            Some("3200")
+        );
+        assert_eq!(
+            data.metadata.get("turn_delta_display").map(String::as_str),
+            Some("+3.2k")
+        );
+        // 新回合从 4200 重新记录起点：增量复位
+        assert!(
+            !collect(Some(4_200), Some(4_200))
+                .metadata
+                .contains_key("turn_delta")
+        );
+        let data = collect(Some(4_500), Some(4_200));
+        assert_eq!(
+            data.metadata.get("turn_delta_display").map(String::as_str),
+            Some("+300")
+        );
+    }
+
+    #[test]
+    fn test_display_follows_locale() {
+        let cwd = std::path::Path::new("/tmp");
+        let ctx = StatusLineContext::new("model", cwd)
+            .with_context(Some(41_500), Some(128_000))
+            .with_locale(NumberLocale::DeDe);
+        let data = ContextSegment.collect(&ctx).unwrap();
+        assert_eq!(data.primary, "32% · 41,5k tokens");
+
+        // 元数据保持机器格式，供条件规则解析
+        assert_eq!(
+            data.metadata.get("tokens").map(String::as_str),
+            Some("41500")
+        );
     }
 }
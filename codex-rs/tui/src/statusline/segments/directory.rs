@@ -1,6 +1,7 @@
 // Directory Segment - 显示当前工作目录名称
 
 use crate::statusline::StatusLineContext;
+use crate::statusline::memo::ContextField;
 use crate::statusline::segment::Segment;
 use crate::statusline::segment::SegmentData;
 use crate::statusline::segment::SegmentId;
@@ -22,6 +23,10 @@ impl Segment for DirectorySegment {
     fn id(&self) -> SegmentId {
         SegmentId::Directory
     }
+
+    fn dependencies(&self) -> Option<&'static [ContextField]> {
+        Some(&[ContextField::Cwd])
+    }
 }
 
 /// 提取目录名称
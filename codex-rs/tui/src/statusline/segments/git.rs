@@ -2,10 +2,14 @@
 
 use crate::statusline::GitPreviewData;
 use crate::statusline::StatusLineContext;
+use crate::statusline::memo::ContextField;
+use crate::statusline::segment::AsyncSegment;
 use crate::statusline::segment::Segment;
 use crate::statusline::segment::SegmentData;
 use crate::statusline::segment::SegmentId;
+use std::future::Future;
 use std::path::Path;
+use std::pin::Pin;
 use std::process::Command;
 
 pub struct GitSegment;
@@ -56,36 +60,69 @@ impl GitSegment {
     }
 }
 
+/// Format preview data into segment data; shared by the sync and async paths.
+fn data_from_preview(preview: &GitPreviewData) -> Option<SegmentData> {
+    if preview.branch.is_empty() && preview.status.is_empty() {
+        return None;
+    }
+    let primary = preview.branch.clone();
+    let mut parts = Vec::new();
+    parts.push(preview.status.clone());
+    if preview.ahead > 0 {
+        parts.push(format!("↑{}", preview.ahead));
+    }
+    if preview.behind > 0 {
+        parts.push(format!("↓{}", preview.behind));
+    }
+    Some(
+        SegmentData::new(primary)
+            .with_secondary(parts.join(" "))
+            .with_metadata("branch", &preview.branch)
+            .with_metadata("status", &preview.status)
+            .with_metadata("ahead", preview.ahead.to_string())
+            .with_metadata("behind", preview.behind.to_string()),
+    )
+}
+
 impl Segment for GitSegment {
     fn collect(&self, ctx: &StatusLineContext) -> Option<SegmentData> {
         // @cometix: only render from async preview data — never run blocking
         // git commands on the render thread.
         let preview = ctx.git_preview.as_ref()?;
-        if preview.branch.is_empty() && preview.status.is_empty() {
-            return None;
-        }
-        let primary = preview.branch.clone();
-        let mut parts = Vec::new();
-        parts.push(preview.status.clone());
-        if preview.ahead > 0 {
-            parts.push(format!("↑{}", preview.ahead));
-        }
-        if preview.behind > 0 {
-            parts.push(format!("↓{}", preview.behind));
-        }
-        Some(
-            SegmentData::new(primary)
-                .with_secondary(parts.join(" "))
-                .with_metadata("branch", &preview.branch)
-                .with_metadata("status", &preview.status)
-                .with_metadata("ahead", preview.ahead.to_string())
-                .with_metadata("behind", preview.behind.to_string()),
-        )
+        data_from_preview(preview)
     }
 
     fn id(&self) -> SegmentId {
         SegmentId::Git
     }
+
+    fn dependencies(&self) -> Option<&'static [ContextField]> {
+        // 同步路径只读 preview 数据，仓库状态变化总是经 preview 更新传入
+        Some(&[ContextField::GitPreview])
+    }
+}
+
+impl AsyncSegment for GitSegment {
+    /// Prefers preview data when present (config overlay); otherwise runs the
+    /// blocking git commands on the blocking pool so the host's timeout can
+    /// abandon a slow repository without stalling the line.
+    fn collect_async(
+        &self,
+        ctx: &StatusLineContext,
+    ) -> Pin<Box<dyn Future<Output = Option<SegmentData>> + Send + 'static>> {
+        if let Some(preview) = ctx.git_preview.as_ref() {
+            let data = data_from_preview(preview);
+            return Box::pin(async move { data });
+        }
+        let cwd = ctx.cwd.to_path_buf();
+        Box::pin(async move {
+            let preview = tokio::task::spawn_blocking(move || GitSegment.collect_preview(&cwd))
+                .await
+                .ok()
+                .flatten()?;
+            data_from_preview(&preview)
+        })
+    }
 }
 
 // --- internal helpers (blocking, only called from spawn_blocking) ---
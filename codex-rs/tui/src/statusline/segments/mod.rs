@@ -1,11 +1,15 @@
 // Segments 模块入口
 
+pub mod alert;
 mod context;
 mod directory;
 mod git;
 mod model;
-mod usage;
+pub mod usage;
 
+pub use alert::AlertEventKind;
+pub use alert::AlertLatch;
+pub use alert::AlertSegment;
 pub use context::ContextSegment;
 pub use directory::DirectorySegment;
 pub use git::GitSegment;
@@ -1,6 +1,7 @@
 // Model Segment - 显示当前模型名称
 
 use crate::statusline::StatusLineContext;
+use crate::statusline::memo::ContextField;
 use crate::statusline::segment::Segment;
 use crate::statusline::segment::SegmentData;
 use crate::statusline::segment::SegmentId;
@@ -36,6 +37,10 @@ impl Segment for ModelSegment {
     fn id(&self) -> SegmentId {
         SegmentId::Model
     }
+
+    fn dependencies(&self) -> Option<&'static [ContextField]> {
+        Some(&[ContextField::ModelName, ContextField::ReasoningEffort])
+    }
 }
 
 /// Get short suffix for reasoning effort level
@@ -1,6 +1,7 @@
 // Usage Segment - 显示 Rate Limit 使用情况
 
 use crate::statusline::StatusLineContext;
+use crate::statusline::memo::ContextField;
 use crate::statusline::segment::Segment;
 use crate::statusline::segment::SegmentData;
 use crate::statusline::segment::SegmentId;
@@ -15,7 +16,7 @@ impl Segment for UsageSegment {
             .or(ctx.weekly_rate_limit_percent)?;
         let weekly_percent = ctx.weekly_rate_limit_percent.unwrap_or(primary_percent);
 
-        let display = format!("{primary_percent:.0}%");
+        let display = ctx.locale.format_percent(primary_percent, 0);
 
         // 动态图标：根据周限使用率选择不同的圆形切片图标
         let dynamic_icon = get_circle_icon(weekly_percent / 100.0);
@@ -38,6 +39,76 @@ impl Segment for UsageSegment {
     fn id(&self) -> SegmentId {
         SegmentId::Usage
     }
+
+    fn dependencies(&self) -> Option<&'static [ContextField]> {
+        Some(&[
+            ContextField::HourlyRateLimitPercent,
+            ContextField::WeeklyRateLimitPercent,
+            ContextField::WeeklyRateLimitResetsAt,
+            ContextField::Locale,
+        ])
+    }
+}
+
+/// 动态图标阈值表的一行：使用率不超过 max_percent 时使用该图标
+#[derive(Debug, Clone, PartialEq)]
+pub struct IconThreshold {
+    pub max_percent: u8,
+    pub icon: String,
+}
+
+/// 内置的圆形切片阈值表（与 get_circle_icon 一致）
+pub fn default_icon_thresholds() -> Vec<IconThreshold> {
+    [
+        (12, "\u{f0a9e}"),
+        (25, "\u{f0a9f}"),
+        (37, "\u{f0aa0}"),
+        (50, "\u{f0aa1}"),
+        (62, "\u{f0aa2}"),
+        (75, "\u{f0aa3}"),
+        (87, "\u{f0aa4}"),
+        (100, "\u{f0aa5}"),
+    ]
+    .into_iter()
+    .map(|(max_percent, icon)| IconThreshold {
+        max_percent,
+        icon: icon.to_string(),
+    })
+    .collect()
+}
+
+/// 从 options["icon_thresholds"] 解析阈值表；格式非法或为空时返回 None
+pub fn parse_icon_thresholds(value: &serde_json::Value) -> Option<Vec<IconThreshold>> {
+    let rows = value.as_array()?;
+    let mut parsed = Vec::with_capacity(rows.len());
+    for row in rows {
+        let max_percent = row.get("max_percent")?.as_u64()?.min(100) as u8;
+        let icon = row.get("icon")?.as_str()?.to_string();
+        parsed.push(IconThreshold { max_percent, icon });
+    }
+    (!parsed.is_empty()).then_some(parsed)
+}
+
+/// 阈值表序列化回 options 值
+pub fn icon_thresholds_to_value(rows: &[IconThreshold]) -> serde_json::Value {
+    serde_json::Value::Array(
+        rows.iter()
+            .map(|row| {
+                serde_json::json!({
+                    "max_percent": row.max_percent,
+                    "icon": row.icon,
+                })
+            })
+            .collect(),
+    )
+}
+
+/// 按使用率在阈值表中查找图标（表按 max_percent 升序；超出最后一行时用最后一行）
+pub fn icon_for_percent(rows: &[IconThreshold], percent: f64) -> Option<&str> {
+    rows.iter()
+        .find(|row| percent <= row.max_percent as f64)
+        .or_else(|| rows.last())
+        .map(|row| row.icon.as_str())
 }
 
 /// 根据使用率获取圆形切片图标
@@ -60,6 +131,27 @@ fn get_circle_icon(utilization: f64) -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_icon_thresholds_round_trip_and_lookup() {
+        let rows = vec![
+            IconThreshold {
+                max_percent: 50,
+                icon: "a".to_string(),
+            },
+            IconThreshold {
+                max_percent: 100,
+                icon: "b".to_string(),
+            },
+        ];
+        let value = icon_thresholds_to_value(&rows);
+        assert_eq!(parse_icon_thresholds(&value), Some(rows.clone()));
+
+        assert_eq!(icon_for_percent(&rows, 30.0), Some("a"));
+        assert_eq!(icon_for_percent(&rows, 80.0), Some("b"));
+        // 超出最后一行时回退到最后一行
+        assert_eq!(icon_for_percent(&rows[..1], 80.0), Some("a"));
+    }
+
     #[test]
     fn test_get_circle_icon() {
         // 测试边界值
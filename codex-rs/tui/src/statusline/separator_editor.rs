@@ -1,17 +1,23 @@
 // 分隔符编辑器组件
+// 编辑三个槽位：行首 cap / segment 之间 / 行尾 cap（Tab 在槽位间循环）
 
 use ratatui::buffer::Buffer;
 use ratatui::layout::Constraint;
 use ratatui::layout::Layout;
 use ratatui::layout::Rect;
 use ratatui::style::Color;
+use ratatui::style::Modifier;
 use ratatui::style::Style;
+use ratatui::text::Line;
+use ratatui::text::Span;
 use ratatui::widgets::Block;
 use ratatui::widgets::Borders;
 use ratatui::widgets::Clear;
 use ratatui::widgets::Paragraph;
 use ratatui::widgets::Widget;
 
+use super::config::SeparatorsConfig;
+
 #[derive(Debug, Clone)]
 pub struct SeparatorPreset {
     pub name: &'static str,
@@ -19,83 +25,195 @@ pub struct SeparatorPreset {
     pub description: &'static str,
 }
 
+/// 正在编辑的分隔符槽位
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SeparatorSlot {
+    LeftCap,
+    #[default]
+    Inner,
+    RightCap,
+}
+
+const SLOTS: &[SeparatorSlot] = &[
+    SeparatorSlot::LeftCap,
+    SeparatorSlot::Inner,
+    SeparatorSlot::RightCap,
+];
+
+impl SeparatorSlot {
+    fn index(self) -> usize {
+        match self {
+            Self::LeftCap => 0,
+            Self::Inner => 1,
+            Self::RightCap => 2,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::LeftCap => "Left Cap",
+            Self::Inner => "Inner",
+            Self::RightCap => "Right Cap",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct SeparatorEditor {
     pub is_open: bool,
-    pub input: String,
+    /// 当前编辑的槽位
+    pub slot: SeparatorSlot,
+    /// 三个槽位的候选值（按 SeparatorSlot::index 排列）
+    pub inputs: [String; 3],
     pub selected_preset: Option<usize>,
 }
 
 impl SeparatorEditor {
-    pub fn presets() -> Vec<SeparatorPreset> {
-        vec![
-            SeparatorPreset {
-                name: "Pipe",
-                value: " | ",
-                description: "Classic pipe",
-            },
-            SeparatorPreset {
-                name: "Thin",
-                value: " │ ",
-                description: "Thin vertical line",
-            },
-            SeparatorPreset {
-                name: "Arrow",
-                value: "\u{e0b0}",
-                description: "Powerline arrow",
-            },
-            SeparatorPreset {
-                name: "Space",
-                value: "  ",
-                description: "Double space",
-            },
-            SeparatorPreset {
-                name: "Dot",
-                value: " • ",
-                description: "Middle dot",
-            },
-        ]
-    }
-
-    pub fn open(&mut self, current_separator: &str) {
-        self.is_open = true;
-        self.input = current_separator.to_string();
-        self.selected_preset = None;
-
-        let presets = Self::presets();
-        for (i, preset) in presets.iter().enumerate() {
-            if preset.value == current_separator {
-                self.selected_preset = Some(i);
-                break;
-            }
+    /// 当前槽位可用的预设
+    pub fn presets(slot: SeparatorSlot) -> Vec<SeparatorPreset> {
+        match slot {
+            SeparatorSlot::Inner => vec![
+                SeparatorPreset {
+                    name: "Pipe",
+                    value: " | ",
+                    description: "Classic pipe",
+                },
+                SeparatorPreset {
+                    name: "Thin",
+                    value: " │ ",
+                    description: "Thin vertical line",
+                },
+                SeparatorPreset {
+                    name: "Arrow",
+                    value: "\u{e0b0}",
+                    description: "Powerline arrow",
+                },
+                SeparatorPreset {
+                    name: "Thin Arrow",
+                    value: "\u{e0b1}",
+                    description: "Powerline thin arrow",
+                },
+                SeparatorPreset {
+                    name: "Space",
+                    value: "  ",
+                    description: "Double space",
+                },
+                SeparatorPreset {
+                    name: "Dot",
+                    value: " • ",
+                    description: "Middle dot",
+                },
+            ],
+            SeparatorSlot::LeftCap => vec![
+                SeparatorPreset {
+                    name: "None",
+                    value: "",
+                    description: "No leading cap",
+                },
+                SeparatorPreset {
+                    name: "Round",
+                    value: "\u{e0b6}",
+                    description: "Rounded left cap",
+                },
+                SeparatorPreset {
+                    name: "Angle",
+                    value: "\u{e0b2}",
+                    description: "Powerline left arrow",
+                },
+                SeparatorPreset {
+                    name: "Flame",
+                    value: "\u{e0c2}",
+                    description: "Flame left cap",
+                },
+            ],
+            SeparatorSlot::RightCap => vec![
+                SeparatorPreset {
+                    name: "None",
+                    value: "",
+                    description: "No trailing cap",
+                },
+                SeparatorPreset {
+                    name: "Round",
+                    value: "\u{e0b4}",
+                    description: "Rounded right cap",
+                },
+                SeparatorPreset {
+                    name: "Angle",
+                    value: "\u{e0b0}",
+                    description: "Powerline right arrow",
+                },
+                SeparatorPreset {
+                    name: "Flame",
+                    value: "\u{e0c0}",
+                    description: "Flame right cap",
+                },
+            ],
         }
     }
 
+    /// 打开编辑器；inner 槽位未设置时回退到旧的单一 separator
+    pub fn open(&mut self, separators: &SeparatorsConfig, legacy_separator: &str) {
+        self.is_open = true;
+        self.slot = SeparatorSlot::Inner;
+        self.inputs = [
+            separators.left_cap.clone().unwrap_or_default(),
+            separators
+                .inner
+                .clone()
+                .unwrap_or_else(|| legacy_separator.to_string()),
+            separators.right_cap.clone().unwrap_or_default(),
+        ];
+        self.sync_preset_selection();
+    }
+
     pub fn close(&mut self) {
         self.is_open = false;
-        self.input.clear();
+        self.inputs = Default::default();
         self.selected_preset = None;
     }
 
+    /// Tab 在三个槽位间循环
+    pub fn cycle_slot(&mut self) {
+        let next = (self.slot.index() + 1) % SLOTS.len();
+        self.slot = SLOTS[next];
+        self.sync_preset_selection();
+    }
+
+    fn current_input(&self) -> &str {
+        &self.inputs[self.slot.index()]
+    }
+
+    fn current_input_mut(&mut self) -> &mut String {
+        &mut self.inputs[self.slot.index()]
+    }
+
+    /// 当前输入与某个预设一致时高亮该预设
+    fn sync_preset_selection(&mut self) {
+        let current = self.current_input().to_string();
+        self.selected_preset = Self::presets(self.slot)
+            .iter()
+            .position(|p| p.value == current);
+    }
+
     pub fn input_char(&mut self, c: char) {
         if !c.is_control() {
-            self.input.push(c);
+            self.current_input_mut().push(c);
             self.selected_preset = None;
         }
     }
 
     pub fn backspace(&mut self) {
-        self.input.pop();
+        self.current_input_mut().pop();
         self.selected_preset = None;
     }
 
     pub fn clear_input(&mut self) {
-        self.input.clear();
+        self.current_input_mut().clear();
         self.selected_preset = None;
     }
 
     pub fn move_preset_selection(&mut self, delta: i32) {
-        let presets = Self::presets();
+        let presets = Self::presets(self.slot);
         let new_selection = if let Some(current) = self.selected_preset {
             let new_idx = (current as i32 + delta).clamp(0, presets.len() as i32 - 1) as usize;
             Some(new_idx)
@@ -107,12 +225,21 @@ impl SeparatorEditor {
 
         self.selected_preset = new_selection;
         if let Some(idx) = new_selection {
-            self.input = presets[idx].value.to_string();
+            *self.current_input_mut() = presets[idx].value.to_string();
         }
     }
 
-    pub fn get_separator(&self) -> String {
-        self.input.clone()
+    /// 收集三个槽位的结果；空的 cap 槽位视为未设置
+    pub fn get_separators(&self) -> SeparatorsConfig {
+        let slot_value = |idx: usize| -> Option<String> {
+            let value = &self.inputs[idx];
+            (!value.is_empty()).then(|| value.clone())
+        };
+        SeparatorsConfig {
+            left_cap: slot_value(SeparatorSlot::LeftCap.index()),
+            inner: slot_value(SeparatorSlot::Inner.index()),
+            right_cap: slot_value(SeparatorSlot::RightCap.index()),
+        }
     }
 
     pub fn render(&self, area: Rect, buf: &mut Buffer) {
@@ -120,8 +247,8 @@ impl SeparatorEditor {
             return;
         }
 
-        let popup_height = 16;
-        let popup_width = 55;
+        let popup_height = 20;
+        let popup_width = 58;
         let popup_area = Rect {
             x: (area.width.saturating_sub(popup_width)) / 2,
             y: (area.height.saturating_sub(popup_height)) / 2,
@@ -137,31 +264,49 @@ impl SeparatorEditor {
         let inner = popup_block.inner(popup_area);
         popup_block.render(popup_area, buf);
 
-        let [input_area, presets_area, help_area] = Layout::vertical([
-            Constraint::Length(3),
+        let [slots_area, presets_area, preview_area, help_area] = Layout::vertical([
+            Constraint::Length(5),
             Constraint::Min(5),
             Constraint::Length(3),
+            Constraint::Length(3),
         ])
         .areas(inner);
 
-        // Current input
-        Paragraph::new(format!("> {} <", self.input))
-            .style(Style::default().fg(Color::Yellow))
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .title("Current Separator"),
-            )
-            .render(input_area, buf);
-
-        // Presets
+        // 三个槽位（当前编辑的高亮）
+        let slot_lines: Vec<Line> = SLOTS
+            .iter()
+            .map(|&slot| {
+                let is_active = slot == self.slot;
+                let marker = if is_active { "▶ " } else { "  " };
+                let style = if is_active {
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default()
+                };
+                Line::from(Span::styled(
+                    format!(
+                        "{marker}{}: > {} <",
+                        slot.label(),
+                        self.inputs[slot.index()]
+                    ),
+                    style,
+                ))
+            })
+            .collect();
+        Paragraph::new(slot_lines)
+            .block(Block::default().borders(Borders::ALL).title("Slots"))
+            .render(slots_area, buf);
+
+        // 当前槽位的预设
         let block = Block::default()
             .borders(Borders::ALL)
-            .title("Presets (↑↓ to select)");
+            .title(format!("{} Presets (↑↓ to select)", self.slot.label()));
         let presets_inner = block.inner(presets_area);
         block.render(presets_area, buf);
 
-        let presets = Self::presets();
+        let presets = Self::presets(self.slot);
         for (i, preset) in presets.iter().enumerate() {
             let y = presets_inner.y + i as u16;
             if y >= presets_inner.y + presets_inner.height {
@@ -177,9 +322,55 @@ impl SeparatorEditor {
             buf.set_string(presets_inner.x, y, &text, Style::default());
         }
 
+        // 候选分隔符的实时预览
+        let [left, inner_sep, right] = &self.inputs;
+        let preview = format!("{left} Model {inner_sep} ~/dir {inner_sep} main {right}");
+        Paragraph::new(preview)
+            .style(Style::default().fg(Color::Cyan))
+            .block(Block::default().borders(Borders::ALL).title("Preview"))
+            .render(preview_area, buf);
+
         // Help
-        Paragraph::new("[Enter] Confirm  [Esc] Cancel  [Tab] Clear")
+        Paragraph::new("[Tab] Next Slot  [Enter] Confirm  [Esc] Cancel  [Del] Clear")
             .block(Block::default().borders(Borders::ALL))
             .render(help_area, buf);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip_with_legacy_fallback() {
+        let mut editor = SeparatorEditor::default();
+        // 旧配置：只有单一 separator
+        editor.open(&SeparatorsConfig::default(), " │ ");
+        assert_eq!(editor.inputs[SeparatorSlot::Inner.index()], " │ ");
+
+        // 默认空 cap 槽位不会出现在结果中
+        let result = editor.get_separators();
+        assert_eq!(result.left_cap, None);
+        assert_eq!(result.inner.as_deref(), Some(" │ "));
+        assert_eq!(result.right_cap, None);
+    }
+
+    #[test]
+    fn test_tab_cycles_slots_and_edits_are_per_slot() {
+        let mut editor = SeparatorEditor::default();
+        editor.open(&SeparatorsConfig::default(), " | ");
+
+        assert_eq!(editor.slot, SeparatorSlot::Inner);
+        editor.cycle_slot();
+        assert_eq!(editor.slot, SeparatorSlot::RightCap);
+        editor.input_char('>');
+        editor.cycle_slot();
+        assert_eq!(editor.slot, SeparatorSlot::LeftCap);
+        editor.input_char('<');
+
+        let result = editor.get_separators();
+        assert_eq!(result.left_cap.as_deref(), Some("<"));
+        assert_eq!(result.inner.as_deref(), Some(" | "));
+        assert_eq!(result.right_cap.as_deref(), Some(">"));
+    }
+}
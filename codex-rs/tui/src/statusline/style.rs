@@ -1,6 +1,10 @@
 // 状态栏样式定义
 // 参考 CCometixLine 的颜色和样式系统
 
+use crate::color::perceptual_distance;
+use crate::terminal_palette::StdoutColorLevel;
+use crate::terminal_palette::XTERM_COLORS;
+use crate::terminal_palette::best_color_for_level;
 use ratatui::style::Color;
 use serde::Deserialize;
 use serde::Serialize;
@@ -18,6 +22,40 @@ pub enum StyleMode {
     Powerline,
 }
 
+/// 渲染时使用的颜色深度
+/// `auto` 跟随终端能力探测；其余取值强制指定，供探测失误的远程终端手工纠正
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ColorDepth {
+    /// 跟随 stdout 能力探测（COLORTERM / TERM / 终端名启发式）
+    #[default]
+    #[serde(rename = "auto")]
+    Auto,
+    /// 强制 16 色
+    #[serde(rename = "16")]
+    Ansi16,
+    /// 强制 256 色
+    #[serde(rename = "256")]
+    Ansi256,
+    /// 强制 24 位真彩色
+    #[serde(rename = "truecolor")]
+    TrueColor,
+}
+
+impl ColorDepth {
+    /// 把 `auto` 解析为具体深度；显式配置原样返回
+    /// 探测不到能力（非 tty / NO_COLOR）时维持真彩色输出，不做降映射
+    pub fn resolve(self, detected: StdoutColorLevel) -> ColorDepth {
+        match self {
+            ColorDepth::Auto => match detected {
+                StdoutColorLevel::Ansi256 => ColorDepth::Ansi256,
+                StdoutColorLevel::Ansi16 => ColorDepth::Ansi16,
+                StdoutColorLevel::TrueColor | StdoutColorLevel::Unknown => ColorDepth::TrueColor,
+            },
+            other => other,
+        }
+    }
+}
+
 /// ANSI 颜色（支持 16 色、256 色、RGB）
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -75,6 +113,40 @@ impl AnsiColor {
             Self::Rgb { r, g, b } => Color::Rgb(r, g, b),
         }
     }
+
+    /// 按目标颜色深度转换为 ratatui Color
+    /// 超出深度能力的颜色降映射到感知上最近的低深度近似色
+    pub fn to_ratatui_color_at(self, depth: ColorDepth) -> Color {
+        match depth {
+            ColorDepth::Auto | ColorDepth::TrueColor => self.to_ratatui_color(),
+            ColorDepth::Ansi256 => match self {
+                Self::Rgb { r, g, b } => best_color_for_level((r, g, b), StdoutColorLevel::Ansi256),
+                _ => self.to_ratatui_color(),
+            },
+            ColorDepth::Ansi16 => match self {
+                Self::Color16 { .. } => self.to_ratatui_color(),
+                Self::Color256 { c256 } => {
+                    Self::c16(nearest_ansi16(XTERM_COLORS[c256 as usize])).to_ratatui_color()
+                }
+                Self::Rgb { r, g, b } => Self::c16(nearest_ansi16((r, g, b))).to_ratatui_color(),
+            },
+        }
+    }
+}
+
+/// 在标准 16 色中找感知上最近的颜色
+/// 参考 RGB 取 XTERM_COLORS 前 16 项；实际显示随终端主题变化，这里只做近似匹配
+fn nearest_ansi16(target: (u8, u8, u8)) -> u8 {
+    let mut best = 0usize;
+    let mut best_distance = f32::MAX;
+    for (i, rgb) in XTERM_COLORS[..16].iter().enumerate() {
+        let distance = perceptual_distance(*rgb, target);
+        if distance < best_distance {
+            best = i;
+            best_distance = distance;
+        }
+    }
+    best as u8
 }
 
 /// 预定义 16 色常量
@@ -99,8 +171,36 @@ pub mod ansi16 {
     pub const BRIGHT_WHITE: AnsiColor = AnsiColor::Color16 { c16: 15 };
 }
 
+/// 分隔符背景策略的命名模式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SeparatorBgMode {
+    /// 不填充背景（默认行为）
+    #[default]
+    None,
+    /// 使用前一个 segment 的背景色
+    Previous,
+    /// 使用后一个 segment 的背景色
+    Next,
+}
+
+/// 分隔符背景策略（非 Powerline 模式下相邻带背景色 segment 之间的衔接）
+/// 配置值为 "previous" / "next" / "none" 或一个显式颜色
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SeparatorBg {
+    Mode(SeparatorBgMode),
+    Color(AnsiColor),
+}
+
+impl Default for SeparatorBg {
+    fn default() -> Self {
+        Self::Mode(SeparatorBgMode::None)
+    }
+}
+
 /// 图标配置
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 pub struct IconConfig {
     /// 普通模式图标（emoji）
     pub plain: String,
@@ -126,7 +226,7 @@ impl IconConfig {
 }
 
 /// 颜色配置（支持图标、文本、背景独立配色）
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct ColorConfig {
     /// 图标颜色
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -170,7 +270,7 @@ impl ColorConfig {
 }
 
 /// 文本样式配置
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct TextStyleConfig {
     /// 是否加粗
     #[serde(default)]
@@ -247,3 +347,102 @@ pub mod separators {
     /// Powerline 细箭头
     pub const POWERLINE_THIN: &str = "\u{e0b1}";
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_color_depth_serde_round_trip() {
+        #[derive(Serialize, Deserialize)]
+        struct Wrapper {
+            color_depth: ColorDepth,
+        }
+
+        for (raw, expected) in [
+            ("auto", ColorDepth::Auto),
+            ("16", ColorDepth::Ansi16),
+            ("256", ColorDepth::Ansi256),
+            ("truecolor", ColorDepth::TrueColor),
+        ] {
+            let parsed: Wrapper =
+                toml::from_str(&format!("color_depth = \"{raw}\"")).expect("parse");
+            assert_eq!(parsed.color_depth, expected);
+            assert_eq!(
+                toml::to_string(&parsed).expect("serialize"),
+                format!("color_depth = \"{raw}\"\n")
+            );
+        }
+    }
+
+    #[test]
+    fn test_color_depth_resolve_follows_detection_only_for_auto() {
+        assert_eq!(
+            ColorDepth::Auto.resolve(StdoutColorLevel::Ansi16),
+            ColorDepth::Ansi16
+        );
+        assert_eq!(
+            ColorDepth::Auto.resolve(StdoutColorLevel::Ansi256),
+            ColorDepth::Ansi256
+        );
+        // 探测不到能力时维持真彩色，不做降映射
+        assert_eq!(
+            ColorDepth::Auto.resolve(StdoutColorLevel::Unknown),
+            ColorDepth::TrueColor
+        );
+        assert_eq!(
+            ColorDepth::Ansi16.resolve(StdoutColorLevel::TrueColor),
+            ColorDepth::Ansi16
+        );
+    }
+
+    #[test]
+    fn test_downmap_rgb_to_nearest_16() {
+        // 纯色命中亮色系
+        assert_eq!(
+            AnsiColor::rgb(255, 0, 0).to_ratatui_color_at(ColorDepth::Ansi16),
+            Color::LightRed
+        );
+        assert_eq!(
+            AnsiColor::rgb(0, 0, 0).to_ratatui_color_at(ColorDepth::Ansi16),
+            Color::Black
+        );
+        assert_eq!(
+            AnsiColor::rgb(255, 255, 255).to_ratatui_color_at(ColorDepth::Ansi16),
+            Color::Gray
+        );
+        // 暗红更接近 Maroon 而不是亮红
+        assert_eq!(
+            AnsiColor::rgb(120, 10, 10).to_ratatui_color_at(ColorDepth::Ansi16),
+            Color::Red
+        );
+    }
+
+    #[test]
+    fn test_downmap_256_index_to_16_via_palette_rgb() {
+        // 196 = Red1 (255, 0, 0)
+        assert_eq!(
+            AnsiColor::c256(196).to_ratatui_color_at(ColorDepth::Ansi16),
+            Color::LightRed
+        );
+        // 16 色与 256 色在各自深度下原样保留
+        assert_eq!(
+            AnsiColor::c16(4).to_ratatui_color_at(ColorDepth::Ansi16),
+            Color::Blue
+        );
+        assert_eq!(
+            AnsiColor::c256(196).to_ratatui_color_at(ColorDepth::Ansi256),
+            Color::Indexed(196)
+        );
+    }
+
+    #[test]
+    fn test_downmap_rgb_to_256_quantizes_while_truecolor_passes_through() {
+        let quantized = AnsiColor::rgb(12, 34, 56).to_ratatui_color_at(ColorDepth::Ansi256);
+        assert!(matches!(quantized, Color::Indexed(_)), "{quantized:?}");
+        assert_eq!(
+            AnsiColor::rgb(12, 34, 56).to_ratatui_color_at(ColorDepth::TrueColor),
+            Color::Rgb(12, 34, 56)
+        );
+    }
+}
@@ -1,11 +1,18 @@
 // 主题预设系统
 
 use super::config::CxLineConfig;
+use super::config::SegmentClickAction;
 use super::config::SegmentItemConfig;
 use super::config::SegmentsConfig;
+use super::config::SeparatorsConfig;
+use super::config::ThemeQuickSelect;
+use super::config::default_segment_order;
+use super::locale::NumberLocale;
 use super::style::AnsiColor;
 use super::style::ColorConfig;
+use super::style::ColorDepth;
 use super::style::IconConfig;
+use super::style::SeparatorBg;
 use super::style::StyleMode;
 use super::style::TextStyleConfig;
 use super::style::ansi16;
@@ -18,6 +25,7 @@ pub const THEME_NAMES: &[&str] = &[
     "default",
     "cometix",
     "minimal",
+    "full",
     "gruvbox",
     "nord",
     "powerline-dark",
@@ -75,6 +83,14 @@ impl ThemePresets {
         Self::get_builtin(theme_name).unwrap_or_else(Self::get_default)
     }
 
+    /// 获取主题中单个 segment 的默认配置（用于只重置一个 segment）
+    pub fn theme_segment_config(
+        theme_name: &str,
+        id: super::segment::SegmentId,
+    ) -> SegmentItemConfig {
+        Self::get_theme(theme_name).get_segment_config(id).clone()
+    }
+
     /// 保存配置为主题文件
     pub fn save_theme(theme_name: &str, config: &CxLineConfig) -> std::io::Result<()> {
         let themes_dir = Self::themes_dir()
@@ -96,6 +112,7 @@ impl ThemePresets {
             "default" => Some(Self::get_default()),
             "cometix" => Some(Self::get_cometix()),
             "minimal" => Some(Self::get_minimal()),
+            "full" => Some(Self::get_full()),
             "gruvbox" => Some(Self::get_gruvbox()),
             "nord" => Some(Self::get_nord()),
             "powerline-dark" => Some(Self::get_powerline_dark()),
@@ -113,6 +130,13 @@ impl ThemePresets {
             theme: "default".to_string(),
             style: StyleMode::Plain,
             separator: " │ ".to_string(),
+            separators: SeparatorsConfig::default(),
+            theme_quick_select: ThemeQuickSelect::default(),
+            segment_order: default_segment_order(),
+            separator_bg: SeparatorBg::default(),
+            enabled_overrides: Vec::new(),
+            color_depth: ColorDepth::default(),
+            locale: NumberLocale::default(),
             segments: SegmentsConfig {
                 model: SegmentItemConfig {
                     id: super::segment::SegmentId::Model,
@@ -121,6 +145,8 @@ impl ThemePresets {
                     colors: ColorConfig::new(ansi16::BRIGHT_CYAN, ansi16::BRIGHT_CYAN),
                     styles: TextStyleConfig::default(),
                     options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
                 },
                 directory: SegmentItemConfig {
                     id: super::segment::SegmentId::Directory,
@@ -129,6 +155,8 @@ impl ThemePresets {
                     colors: ColorConfig::new(ansi16::BRIGHT_YELLOW, ansi16::BRIGHT_GREEN),
                     styles: TextStyleConfig::default(),
                     options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
                 },
                 git: SegmentItemConfig {
                     id: super::segment::SegmentId::Git,
@@ -137,6 +165,8 @@ impl ThemePresets {
                     colors: ColorConfig::new(ansi16::BRIGHT_BLUE, ansi16::BRIGHT_BLUE),
                     styles: TextStyleConfig::default(),
                     options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
                 },
                 context: SegmentItemConfig {
                     id: super::segment::SegmentId::Context,
@@ -145,6 +175,8 @@ impl ThemePresets {
                     colors: ColorConfig::new(ansi16::BRIGHT_MAGENTA, ansi16::BRIGHT_MAGENTA),
                     styles: TextStyleConfig::default(),
                     options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
                 },
                 usage: SegmentItemConfig {
                     id: super::segment::SegmentId::Usage,
@@ -153,6 +185,18 @@ impl ThemePresets {
                     colors: ColorConfig::new(ansi16::BRIGHT_CYAN, ansi16::BRIGHT_CYAN),
                     styles: TextStyleConfig::default(),
                     options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
+                },
+                alert: SegmentItemConfig {
+                    id: super::segment::SegmentId::Alert,
+                    enabled: true,
+                    icon: IconConfig::new("🔔", "\u{f009a}"),
+                    colors: ColorConfig::new(ansi16::BRIGHT_RED, ansi16::BRIGHT_RED),
+                    styles: TextStyleConfig::default(),
+                    options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
                 },
             },
         }
@@ -165,6 +209,13 @@ impl ThemePresets {
             theme: "cometix".to_string(),
             style: StyleMode::NerdFont,
             separator: " │ ".to_string(),
+            separators: SeparatorsConfig::default(),
+            theme_quick_select: ThemeQuickSelect::default(),
+            segment_order: default_segment_order(),
+            separator_bg: SeparatorBg::default(),
+            enabled_overrides: Vec::new(),
+            color_depth: ColorDepth::default(),
+            locale: NumberLocale::default(),
             segments: SegmentsConfig {
                 model: SegmentItemConfig {
                     id: super::segment::SegmentId::Model,
@@ -173,6 +224,8 @@ impl ThemePresets {
                     colors: ColorConfig::new(ansi16::BRIGHT_CYAN, ansi16::BRIGHT_CYAN),
                     styles: TextStyleConfig { text_bold: true },
                     options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
                 },
                 directory: SegmentItemConfig {
                     id: super::segment::SegmentId::Directory,
@@ -181,6 +234,8 @@ impl ThemePresets {
                     colors: ColorConfig::new(ansi16::BRIGHT_YELLOW, ansi16::BRIGHT_GREEN),
                     styles: TextStyleConfig { text_bold: true },
                     options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
                 },
                 git: SegmentItemConfig {
                     id: super::segment::SegmentId::Git,
@@ -189,6 +244,8 @@ impl ThemePresets {
                     colors: ColorConfig::new(ansi16::BRIGHT_BLUE, ansi16::BRIGHT_BLUE),
                     styles: TextStyleConfig { text_bold: true },
                     options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
                 },
                 context: SegmentItemConfig {
                     id: super::segment::SegmentId::Context,
@@ -197,6 +254,8 @@ impl ThemePresets {
                     colors: ColorConfig::new(ansi16::BRIGHT_MAGENTA, ansi16::BRIGHT_MAGENTA),
                     styles: TextStyleConfig { text_bold: true },
                     options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
                 },
                 usage: SegmentItemConfig {
                     id: super::segment::SegmentId::Usage,
@@ -205,6 +264,18 @@ impl ThemePresets {
                     colors: ColorConfig::new(ansi16::BRIGHT_CYAN, ansi16::BRIGHT_CYAN),
                     styles: TextStyleConfig::default(),
                     options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
+                },
+                alert: SegmentItemConfig {
+                    id: super::segment::SegmentId::Alert,
+                    enabled: true,
+                    icon: IconConfig::new("🔔", "\u{f009a}"),
+                    colors: ColorConfig::new(ansi16::BRIGHT_RED, ansi16::BRIGHT_RED),
+                    styles: TextStyleConfig { text_bold: true },
+                    options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
                 },
             },
         }
@@ -217,6 +288,13 @@ impl ThemePresets {
             theme: "minimal".to_string(),
             style: StyleMode::Plain,
             separator: " │ ".to_string(),
+            separators: SeparatorsConfig::default(),
+            theme_quick_select: ThemeQuickSelect::default(),
+            segment_order: default_segment_order(),
+            separator_bg: SeparatorBg::default(),
+            enabled_overrides: Vec::new(),
+            color_depth: ColorDepth::default(),
+            locale: NumberLocale::default(),
             segments: SegmentsConfig {
                 model: SegmentItemConfig {
                     id: super::segment::SegmentId::Model,
@@ -225,6 +303,8 @@ impl ThemePresets {
                     colors: ColorConfig::new(ansi16::BRIGHT_CYAN, ansi16::BRIGHT_CYAN),
                     styles: TextStyleConfig::default(),
                     options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
                 },
                 directory: SegmentItemConfig {
                     id: super::segment::SegmentId::Directory,
@@ -233,14 +313,18 @@ impl ThemePresets {
                     colors: ColorConfig::new(ansi16::BRIGHT_YELLOW, ansi16::BRIGHT_GREEN),
                     styles: TextStyleConfig::default(),
                     options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
                 },
                 git: SegmentItemConfig {
                     id: super::segment::SegmentId::Git,
-                    enabled: true,
+                    enabled: false,
                     icon: IconConfig::new("※", "\u{f02a2}"),
                     colors: ColorConfig::new(ansi16::BRIGHT_BLUE, ansi16::BRIGHT_BLUE),
                     styles: TextStyleConfig::default(),
                     options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
                 },
                 context: SegmentItemConfig {
                     id: super::segment::SegmentId::Context,
@@ -249,19 +333,46 @@ impl ThemePresets {
                     colors: ColorConfig::new(ansi16::BRIGHT_MAGENTA, ansi16::BRIGHT_MAGENTA),
                     styles: TextStyleConfig::default(),
                     options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
                 },
                 usage: SegmentItemConfig {
                     id: super::segment::SegmentId::Usage,
-                    enabled: true,
+                    enabled: false,
                     icon: IconConfig::new("📊", "\u{f0a9e}"),
                     colors: ColorConfig::new(ansi16::BRIGHT_CYAN, ansi16::BRIGHT_CYAN),
                     styles: TextStyleConfig::default(),
                     options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
+                },
+                alert: SegmentItemConfig {
+                    id: super::segment::SegmentId::Alert,
+                    enabled: true,
+                    icon: IconConfig::new("!", "\u{f009a}"),
+                    colors: ColorConfig::new(ansi16::BRIGHT_RED, ansi16::BRIGHT_RED),
+                    styles: TextStyleConfig::default(),
+                    options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
                 },
             },
         }
     }
 
+    /// Full 主题（启用所有 segment）
+    pub fn get_full() -> CxLineConfig {
+        let mut config = Self::get_default();
+        config.theme = "full".to_string();
+        config.segments.model.enabled = true;
+        config.segments.directory.enabled = true;
+        config.segments.git.enabled = true;
+        config.segments.context.enabled = true;
+        config.segments.usage.enabled = true;
+        config.segments.alert.enabled = true;
+        config
+    }
+
     /// Gruvbox 主题
     pub fn get_gruvbox() -> CxLineConfig {
         let gruvbox_orange = AnsiColor::c256(208);
@@ -273,6 +384,13 @@ impl ThemePresets {
             theme: "gruvbox".to_string(),
             style: StyleMode::NerdFont,
             separator: " │ ".to_string(),
+            separators: SeparatorsConfig::default(),
+            theme_quick_select: ThemeQuickSelect::default(),
+            segment_order: default_segment_order(),
+            separator_bg: SeparatorBg::default(),
+            enabled_overrides: Vec::new(),
+            color_depth: ColorDepth::default(),
+            locale: NumberLocale::default(),
             segments: SegmentsConfig {
                 model: SegmentItemConfig {
                     id: super::segment::SegmentId::Model,
@@ -281,6 +399,8 @@ impl ThemePresets {
                     colors: ColorConfig::new(gruvbox_orange, gruvbox_orange),
                     styles: TextStyleConfig { text_bold: true },
                     options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
                 },
                 directory: SegmentItemConfig {
                     id: super::segment::SegmentId::Directory,
@@ -289,6 +409,8 @@ impl ThemePresets {
                     colors: ColorConfig::new(gruvbox_green, gruvbox_green),
                     styles: TextStyleConfig { text_bold: true },
                     options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
                 },
                 git: SegmentItemConfig {
                     id: super::segment::SegmentId::Git,
@@ -297,6 +419,8 @@ impl ThemePresets {
                     colors: ColorConfig::new(gruvbox_cyan, gruvbox_cyan),
                     styles: TextStyleConfig { text_bold: true },
                     options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
                 },
                 context: SegmentItemConfig {
                     id: super::segment::SegmentId::Context,
@@ -305,6 +429,8 @@ impl ThemePresets {
                     colors: ColorConfig::new(ansi16::MAGENTA, ansi16::MAGENTA),
                     styles: TextStyleConfig { text_bold: true },
                     options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
                 },
                 usage: SegmentItemConfig {
                     id: super::segment::SegmentId::Usage,
@@ -313,6 +439,18 @@ impl ThemePresets {
                     colors: ColorConfig::new(ansi16::BRIGHT_CYAN, ansi16::BRIGHT_CYAN),
                     styles: TextStyleConfig::default(),
                     options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
+                },
+                alert: SegmentItemConfig {
+                    id: super::segment::SegmentId::Alert,
+                    enabled: true,
+                    icon: IconConfig::new("🔔", "\u{f009a}"),
+                    colors: ColorConfig::new(AnsiColor::c256(167), AnsiColor::c256(167)),
+                    styles: TextStyleConfig { text_bold: true },
+                    options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
                 },
             },
         }
@@ -326,12 +464,20 @@ impl ThemePresets {
         let bg_git = AnsiColor::rgb(129, 161, 193);
         let bg_context = AnsiColor::rgb(180, 142, 173);
         let bg_usage = AnsiColor::rgb(235, 203, 139);
+        let bg_alert = AnsiColor::rgb(191, 97, 106);
 
         CxLineConfig {
             enabled: true,
             theme: "nord".to_string(),
             style: StyleMode::Powerline,
             separator: "\u{e0b0}".to_string(),
+            separators: SeparatorsConfig::default(),
+            theme_quick_select: ThemeQuickSelect::default(),
+            segment_order: default_segment_order(),
+            separator_bg: SeparatorBg::default(),
+            enabled_overrides: Vec::new(),
+            color_depth: ColorDepth::default(),
+            locale: NumberLocale::default(),
             segments: SegmentsConfig {
                 model: SegmentItemConfig {
                     id: super::segment::SegmentId::Model,
@@ -340,6 +486,8 @@ impl ThemePresets {
                     colors: ColorConfig::new(nord_polar, nord_polar).with_background(bg_model),
                     styles: TextStyleConfig::default(),
                     options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
                 },
                 directory: SegmentItemConfig {
                     id: super::segment::SegmentId::Directory,
@@ -348,6 +496,8 @@ impl ThemePresets {
                     colors: ColorConfig::new(nord_polar, nord_polar).with_background(bg_dir),
                     styles: TextStyleConfig::default(),
                     options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
                 },
                 git: SegmentItemConfig {
                     id: super::segment::SegmentId::Git,
@@ -356,6 +506,8 @@ impl ThemePresets {
                     colors: ColorConfig::new(nord_polar, nord_polar).with_background(bg_git),
                     styles: TextStyleConfig::default(),
                     options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
                 },
                 context: SegmentItemConfig {
                     id: super::segment::SegmentId::Context,
@@ -364,6 +516,8 @@ impl ThemePresets {
                     colors: ColorConfig::new(nord_polar, nord_polar).with_background(bg_context),
                     styles: TextStyleConfig::default(),
                     options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
                 },
                 usage: SegmentItemConfig {
                     id: super::segment::SegmentId::Usage,
@@ -372,6 +526,18 @@ impl ThemePresets {
                     colors: ColorConfig::new(nord_polar, nord_polar).with_background(bg_usage),
                     styles: TextStyleConfig::default(),
                     options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
+                },
+                alert: SegmentItemConfig {
+                    id: super::segment::SegmentId::Alert,
+                    enabled: true,
+                    icon: IconConfig::new("🔔", "\u{f009a}"),
+                    colors: ColorConfig::new(nord_polar, nord_polar).with_background(bg_alert),
+                    styles: TextStyleConfig::default(),
+                    options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
                 },
             },
         }
@@ -387,12 +553,20 @@ impl ThemePresets {
         let bg_git = AnsiColor::rgb(64, 64, 64);
         let bg_context = AnsiColor::rgb(55, 65, 81);
         let bg_usage = AnsiColor::rgb(45, 50, 59);
+        let bg_alert = AnsiColor::rgb(153, 27, 27);
 
         CxLineConfig {
             enabled: true,
             theme: "powerline-dark".to_string(),
             style: StyleMode::Powerline,
             separator: "\u{e0b0}".to_string(),
+            separators: SeparatorsConfig::default(),
+            theme_quick_select: ThemeQuickSelect::default(),
+            segment_order: default_segment_order(),
+            separator_bg: SeparatorBg::default(),
+            enabled_overrides: Vec::new(),
+            color_depth: ColorDepth::default(),
+            locale: NumberLocale::default(),
             segments: SegmentsConfig {
                 model: SegmentItemConfig {
                     id: super::segment::SegmentId::Model,
@@ -401,6 +575,8 @@ impl ThemePresets {
                     colors: ColorConfig::new(white, white).with_background(bg_model),
                     styles: TextStyleConfig::default(),
                     options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
                 },
                 directory: SegmentItemConfig {
                     id: super::segment::SegmentId::Directory,
@@ -409,6 +585,8 @@ impl ThemePresets {
                     colors: ColorConfig::new(white, white).with_background(bg_dir),
                     styles: TextStyleConfig::default(),
                     options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
                 },
                 git: SegmentItemConfig {
                     id: super::segment::SegmentId::Git,
@@ -417,6 +595,8 @@ impl ThemePresets {
                     colors: ColorConfig::new(white, white).with_background(bg_git),
                     styles: TextStyleConfig::default(),
                     options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
                 },
                 context: SegmentItemConfig {
                     id: super::segment::SegmentId::Context,
@@ -425,6 +605,8 @@ impl ThemePresets {
                     colors: ColorConfig::new(light_gray, light_gray).with_background(bg_context),
                     styles: TextStyleConfig::default(),
                     options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
                 },
                 usage: SegmentItemConfig {
                     id: super::segment::SegmentId::Usage,
@@ -433,6 +615,18 @@ impl ThemePresets {
                     colors: ColorConfig::new(light_gray, light_gray).with_background(bg_usage),
                     styles: TextStyleConfig::default(),
                     options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
+                },
+                alert: SegmentItemConfig {
+                    id: super::segment::SegmentId::Alert,
+                    enabled: true,
+                    icon: IconConfig::new("🔔", "\u{f009a}"),
+                    colors: ColorConfig::new(white, white).with_background(bg_alert),
+                    styles: TextStyleConfig::default(),
+                    options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
                 },
             },
         }
@@ -448,12 +642,20 @@ impl ThemePresets {
         let bg_git = AnsiColor::rgb(79, 179, 217);
         let bg_context = AnsiColor::rgb(107, 114, 128);
         let bg_usage = AnsiColor::rgb(40, 167, 69);
+        let bg_alert = AnsiColor::rgb(220, 53, 69);
 
         CxLineConfig {
             enabled: true,
             theme: "powerline-light".to_string(),
             style: StyleMode::Powerline,
             separator: "\u{e0b0}".to_string(),
+            separators: SeparatorsConfig::default(),
+            theme_quick_select: ThemeQuickSelect::default(),
+            segment_order: default_segment_order(),
+            separator_bg: SeparatorBg::default(),
+            enabled_overrides: Vec::new(),
+            color_depth: ColorDepth::default(),
+            locale: NumberLocale::default(),
             segments: SegmentsConfig {
                 model: SegmentItemConfig {
                     id: super::segment::SegmentId::Model,
@@ -462,6 +664,8 @@ impl ThemePresets {
                     colors: ColorConfig::new(black, black).with_background(bg_model),
                     styles: TextStyleConfig::default(),
                     options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
                 },
                 directory: SegmentItemConfig {
                     id: super::segment::SegmentId::Directory,
@@ -470,6 +674,8 @@ impl ThemePresets {
                     colors: ColorConfig::new(white, white).with_background(bg_dir),
                     styles: TextStyleConfig::default(),
                     options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
                 },
                 git: SegmentItemConfig {
                     id: super::segment::SegmentId::Git,
@@ -478,6 +684,8 @@ impl ThemePresets {
                     colors: ColorConfig::new(white, white).with_background(bg_git),
                     styles: TextStyleConfig::default(),
                     options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
                 },
                 context: SegmentItemConfig {
                     id: super::segment::SegmentId::Context,
@@ -486,6 +694,8 @@ impl ThemePresets {
                     colors: ColorConfig::new(white, white).with_background(bg_context),
                     styles: TextStyleConfig::default(),
                     options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
                 },
                 usage: SegmentItemConfig {
                     id: super::segment::SegmentId::Usage,
@@ -494,6 +704,18 @@ impl ThemePresets {
                     colors: ColorConfig::new(white, white).with_background(bg_usage),
                     styles: TextStyleConfig::default(),
                     options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
+                },
+                alert: SegmentItemConfig {
+                    id: super::segment::SegmentId::Alert,
+                    enabled: true,
+                    icon: IconConfig::new("🔔", "\u{f009a}"),
+                    colors: ColorConfig::new(white, white).with_background(bg_alert),
+                    styles: TextStyleConfig::default(),
+                    options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
                 },
             },
         }
@@ -506,18 +728,27 @@ impl ThemePresets {
         let foam = AnsiColor::rgb(156, 207, 216);
         let subtle = AnsiColor::rgb(224, 222, 244);
         let gold = AnsiColor::rgb(246, 193, 119);
+        let love = AnsiColor::rgb(235, 111, 146);
 
         let bg_model = AnsiColor::rgb(25, 23, 36);
         let bg_dir = AnsiColor::rgb(38, 35, 58);
         let bg_git = AnsiColor::rgb(31, 29, 46);
         let bg_context = AnsiColor::rgb(82, 79, 103);
         let bg_usage = AnsiColor::rgb(35, 33, 54);
+        let bg_alert = AnsiColor::rgb(31, 29, 46);
 
         CxLineConfig {
             enabled: true,
             theme: "powerline-rose-pine".to_string(),
             style: StyleMode::Powerline,
             separator: "\u{e0b0}".to_string(),
+            separators: SeparatorsConfig::default(),
+            theme_quick_select: ThemeQuickSelect::default(),
+            segment_order: default_segment_order(),
+            separator_bg: SeparatorBg::default(),
+            enabled_overrides: Vec::new(),
+            color_depth: ColorDepth::default(),
+            locale: NumberLocale::default(),
             segments: SegmentsConfig {
                 model: SegmentItemConfig {
                     id: super::segment::SegmentId::Model,
@@ -526,6 +757,8 @@ impl ThemePresets {
                     colors: ColorConfig::new(rose, rose).with_background(bg_model),
                     styles: TextStyleConfig::default(),
                     options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
                 },
                 directory: SegmentItemConfig {
                     id: super::segment::SegmentId::Directory,
@@ -534,6 +767,8 @@ impl ThemePresets {
                     colors: ColorConfig::new(iris, iris).with_background(bg_dir),
                     styles: TextStyleConfig::default(),
                     options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
                 },
                 git: SegmentItemConfig {
                     id: super::segment::SegmentId::Git,
@@ -542,6 +777,8 @@ impl ThemePresets {
                     colors: ColorConfig::new(foam, foam).with_background(bg_git),
                     styles: TextStyleConfig::default(),
                     options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
                 },
                 context: SegmentItemConfig {
                     id: super::segment::SegmentId::Context,
@@ -550,6 +787,8 @@ impl ThemePresets {
                     colors: ColorConfig::new(subtle, subtle).with_background(bg_context),
                     styles: TextStyleConfig::default(),
                     options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
                 },
                 usage: SegmentItemConfig {
                     id: super::segment::SegmentId::Usage,
@@ -558,6 +797,18 @@ impl ThemePresets {
                     colors: ColorConfig::new(gold, gold).with_background(bg_usage),
                     styles: TextStyleConfig::default(),
                     options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
+                },
+                alert: SegmentItemConfig {
+                    id: super::segment::SegmentId::Alert,
+                    enabled: true,
+                    icon: IconConfig::new("🔔", "\u{f009a}"),
+                    colors: ColorConfig::new(love, love).with_background(bg_alert),
+                    styles: TextStyleConfig::default(),
+                    options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
                 },
             },
         }
@@ -570,18 +821,27 @@ impl ThemePresets {
         let green = AnsiColor::rgb(195, 232, 141);
         let lavender = AnsiColor::rgb(192, 202, 245);
         let orange = AnsiColor::rgb(224, 175, 104);
+        let red = AnsiColor::rgb(247, 118, 142);
 
         let bg_model = AnsiColor::rgb(25, 27, 41);
         let bg_dir = AnsiColor::rgb(47, 51, 77);
         let bg_git = AnsiColor::rgb(30, 32, 48);
         let bg_context = AnsiColor::rgb(61, 89, 161);
         let bg_usage = AnsiColor::rgb(36, 40, 59);
+        let bg_alert = AnsiColor::rgb(36, 40, 59);
 
         CxLineConfig {
             enabled: true,
             theme: "powerline-tokyo-night".to_string(),
             style: StyleMode::Powerline,
             separator: "\u{e0b0}".to_string(),
+            separators: SeparatorsConfig::default(),
+            theme_quick_select: ThemeQuickSelect::default(),
+            segment_order: default_segment_order(),
+            separator_bg: SeparatorBg::default(),
+            enabled_overrides: Vec::new(),
+            color_depth: ColorDepth::default(),
+            locale: NumberLocale::default(),
             segments: SegmentsConfig {
                 model: SegmentItemConfig {
                     id: super::segment::SegmentId::Model,
@@ -590,6 +850,8 @@ impl ThemePresets {
                     colors: ColorConfig::new(magenta, magenta).with_background(bg_model),
                     styles: TextStyleConfig::default(),
                     options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
                 },
                 directory: SegmentItemConfig {
                     id: super::segment::SegmentId::Directory,
@@ -598,6 +860,8 @@ impl ThemePresets {
                     colors: ColorConfig::new(blue, blue).with_background(bg_dir),
                     styles: TextStyleConfig::default(),
                     options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
                 },
                 git: SegmentItemConfig {
                     id: super::segment::SegmentId::Git,
@@ -606,6 +870,8 @@ impl ThemePresets {
                     colors: ColorConfig::new(green, green).with_background(bg_git),
                     styles: TextStyleConfig::default(),
                     options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
                 },
                 context: SegmentItemConfig {
                     id: super::segment::SegmentId::Context,
@@ -614,6 +880,8 @@ impl ThemePresets {
                     colors: ColorConfig::new(lavender, lavender).with_background(bg_context),
                     styles: TextStyleConfig::default(),
                     options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
                 },
                 usage: SegmentItemConfig {
                     id: super::segment::SegmentId::Usage,
@@ -622,6 +890,18 @@ impl ThemePresets {
                     colors: ColorConfig::new(orange, orange).with_background(bg_usage),
                     styles: TextStyleConfig::default(),
                     options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
+                },
+                alert: SegmentItemConfig {
+                    id: super::segment::SegmentId::Alert,
+                    enabled: true,
+                    icon: IconConfig::new("🔔", "\u{f009a}"),
+                    colors: ColorConfig::new(red, red).with_background(bg_alert),
+                    styles: TextStyleConfig::default(),
+                    options: HashMap::new(),
+                    rules: Vec::new(),
+                    on_click: SegmentClickAction::None,
                 },
             },
         }
@@ -0,0 +1,233 @@
+// Usage 动态图标阈值表编辑器组件
+// 编辑 (max_percent, 图标) 行；行始终按 max_percent 升序且互不重叠
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Color;
+use ratatui::style::Modifier;
+use ratatui::style::Style;
+use ratatui::widgets::Block;
+use ratatui::widgets::Borders;
+use ratatui::widgets::Clear;
+use ratatui::widgets::Widget;
+
+use super::color_picker::centered_rect;
+use super::segments::usage::IconThreshold;
+use super::segments::usage::default_icon_thresholds;
+
+/// 阈值表编辑器
+#[derive(Debug, Clone, Default)]
+pub struct ThresholdEditor {
+    pub is_open: bool,
+    pub rows: Vec<IconThreshold>,
+    pub selected: usize,
+}
+
+impl ThresholdEditor {
+    /// 打开编辑器；空表时从内置默认表开始
+    pub fn open(&mut self, rows: Vec<IconThreshold>) {
+        self.is_open = true;
+        self.rows = if rows.is_empty() {
+            default_icon_thresholds()
+        } else {
+            rows
+        };
+        self.normalize();
+        self.selected = 0;
+    }
+
+    pub fn close(&mut self) {
+        self.is_open = false;
+    }
+
+    pub fn move_selection(&mut self, delta: i32) {
+        if self.rows.is_empty() {
+            return;
+        }
+        self.selected =
+            (self.selected as i32 + delta).clamp(0, self.rows.len() as i32 - 1) as usize;
+    }
+
+    pub fn selected_row(&self) -> Option<&IconThreshold> {
+        self.rows.get(self.selected)
+    }
+
+    /// 在选中行与下一行之间插入新行（取两者阈值的中点），返回是否成功
+    pub fn add_row(&mut self) -> bool {
+        let lower = self.selected_row().map(|row| row.max_percent).unwrap_or(0);
+        let upper = self
+            .rows
+            .get(self.selected + 1)
+            .map(|row| row.max_percent)
+            .unwrap_or(100);
+        let midpoint = lower / 2 + upper / 2;
+        if self.rows.iter().any(|row| row.max_percent == midpoint) {
+            // 相邻阈值之间已没有空位
+            return false;
+        }
+
+        let icon = self
+            .selected_row()
+            .map(|row| row.icon.clone())
+            .unwrap_or_default();
+        self.rows.push(IconThreshold {
+            max_percent: midpoint,
+            icon,
+        });
+        self.normalize();
+        self.selected = self
+            .rows
+            .iter()
+            .position(|row| row.max_percent == midpoint)
+            .unwrap_or(0);
+        true
+    }
+
+    /// 删除选中行（至少保留一行）
+    pub fn remove_selected(&mut self) -> bool {
+        if self.rows.len() <= 1 {
+            return false;
+        }
+        self.rows.remove(self.selected);
+        self.selected = self.selected.min(self.rows.len() - 1);
+        true
+    }
+
+    /// 修改选中行的阈值并重新排序
+    pub fn set_selected_percent(&mut self, percent: u8) {
+        let Some(row) = self.rows.get(self.selected) else {
+            return;
+        };
+        let icon = row.icon.clone();
+        self.rows[self.selected].max_percent = percent.min(100);
+        self.normalize();
+        // 排序后重新定位到被编辑的行
+        self.selected = self
+            .rows
+            .iter()
+            .position(|row| row.max_percent == percent.min(100) && row.icon == icon)
+            .unwrap_or(0);
+    }
+
+    pub fn set_selected_icon(&mut self, icon: String) {
+        if let Some(row) = self.rows.get_mut(self.selected) {
+            row.icon = icon;
+        }
+    }
+
+    /// 保持行按 max_percent 升序且不重叠（相同阈值只保留先出现的行）
+    fn normalize(&mut self) {
+        self.rows.sort_by_key(|row| row.max_percent);
+        self.rows.dedup_by_key(|row| row.max_percent);
+    }
+
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        if !self.is_open {
+            return;
+        }
+
+        let popup_area = centered_rect(45, 60, area);
+        Clear.render(popup_area, buf);
+
+        let popup_block = Block::default()
+            .borders(Borders::ALL)
+            .title("Dynamic Icons");
+        let inner = popup_block.inner(popup_area);
+        popup_block.render(popup_area, buf);
+
+        if inner.height < 2 {
+            return;
+        }
+
+        // 每行：阈值区间 + 图标
+        let mut prev = 0u8;
+        for (i, row) in self.rows.iter().enumerate() {
+            let y = inner.y + i as u16;
+            if y >= inner.y + inner.height - 1 {
+                break;
+            }
+
+            let is_selected = i == self.selected;
+            let marker = if is_selected { "▶ " } else { "  " };
+            let style = if is_selected {
+                Style::default().add_modifier(Modifier::REVERSED)
+            } else {
+                Style::default()
+            };
+            buf.set_string(
+                inner.x,
+                y,
+                format!("{marker}{prev:>3}-{:>3}%  {}", row.max_percent, row.icon),
+                style,
+            );
+            prev = row.max_percent.saturating_add(1);
+        }
+
+        buf.set_string(
+            inner.x,
+            inner.y + inner.height - 1,
+            "[Enter] Icon  [P] Percent  [A] Add  [D] Delete  [Esc] Close",
+            Style::default().fg(Color::Gray),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn editor_with(rows: &[(u8, &str)]) -> ThresholdEditor {
+        let mut editor = ThresholdEditor::default();
+        editor.open(
+            rows.iter()
+                .map(|(max_percent, icon)| IconThreshold {
+                    max_percent: *max_percent,
+                    icon: (*icon).to_string(),
+                })
+                .collect(),
+        );
+        editor
+    }
+
+    #[test]
+    fn test_open_normalizes_order_and_overlaps() {
+        let editor = editor_with(&[(80, "c"), (30, "a"), (80, "d"), (50, "b")]);
+        let percents: Vec<u8> = editor.rows.iter().map(|row| row.max_percent).collect();
+        // 排序且去掉重叠的 80
+        assert_eq!(percents, vec![30, 50, 80]);
+    }
+
+    #[test]
+    fn test_edit_percent_keeps_rows_sorted() {
+        let mut editor = editor_with(&[(30, "a"), (60, "b"), (100, "c")]);
+        editor.selected = 0;
+        editor.set_selected_percent(90);
+
+        let percents: Vec<u8> = editor.rows.iter().map(|row| row.max_percent).collect();
+        assert_eq!(percents, vec![60, 90, 100]);
+        // 选中跟随被编辑的行
+        assert_eq!(editor.selected_row().map(|r| r.icon.as_str()), Some("a"));
+    }
+
+    #[test]
+    fn test_add_and_remove_rows() {
+        let mut editor = editor_with(&[(40, "a"), (100, "b")]);
+        editor.selected = 0;
+        assert!(editor.add_row());
+        assert_eq!(
+            editor
+                .rows
+                .iter()
+                .map(|r| r.max_percent)
+                .collect::<Vec<_>>(),
+            vec![40, 70, 100]
+        );
+
+        assert!(editor.remove_selected());
+        assert_eq!(editor.rows.len(), 2);
+
+        // 最后一行不可删除
+        let mut editor = editor_with(&[(100, "a")]);
+        assert!(!editor.remove_selected());
+    }
+}
@@ -23,6 +23,7 @@ use ratatui::widgets::Borders;
 use ratatui::widgets::Paragraph;
 use ratatui::widgets::Widget;
 
+use crate::translation::HeaderOverflow;
 use crate::translation::ProviderId;
 use crate::translation::TranslationConfig;
 use crate::tui;
@@ -172,6 +173,106 @@ pub(crate) struct TranslateOverlay {
     base_url: String,
     /// Timeout in milliseconds (as string for editing).
     timeout_ms: String,
+    /// Whether code spans and paths are masked before translation (file-only
+    /// setting, preserved across edits).
+    mask_code: bool,
+    /// Whether likely secrets are redacted before translation (file-only
+    /// setting, preserved across edits).
+    redact_secrets: bool,
+    /// Same-language skip threshold (file-only setting, preserved across
+    /// edits).
+    same_language_threshold: Option<f64>,
+    /// Minimum text length before translating (file-only setting, preserved
+    /// across edits).
+    min_chars: Option<usize>,
+    /// Maximum text length sent per request (file-only setting, preserved
+    /// across edits).
+    max_input_chars: Option<usize>,
+    /// Translator invocations allowed per minute (file-only setting,
+    /// preserved across edits).
+    max_requests_per_minute: Option<u32>,
+    /// Failures before the circuit breaker opens (file-only setting,
+    /// preserved across edits).
+    failure_threshold: Option<u32>,
+    /// Circuit breaker cool-down in milliseconds (file-only setting,
+    /// preserved across edits).
+    circuit_reset_ms: Option<u64>,
+    /// Model slugs whose output is never translated (file-only setting,
+    /// preserved across edits).
+    disabled_for_models: Option<Vec<String>>,
+    /// Named translator definitions (file-only setting, preserved across
+    /// edits).
+    translators: Option<std::collections::HashMap<String, crate::translation::TranslatorDef>>,
+    /// Selected translator name (file-only setting, preserved across edits).
+    use_translator: Option<String>,
+    /// Whether built-in UI notices are also translated (file-only setting,
+    /// preserved across edits but not editable from this overlay).
+    translate_ui_notices: bool,
+    /// Whether plan-update notes are translated (file-only setting, preserved
+    /// across edits).
+    translate_plan_updates: bool,
+    /// Whether error messages are translated (file-only setting, preserved
+    /// across edits).
+    translate_errors: bool,
+    /// Whether translator metadata footers are shown (file-only setting,
+    /// preserved across edits).
+    show_translation_metadata: bool,
+    /// Source language code (file-only setting, preserved across edits).
+    source_language: Option<String>,
+    /// Daemon command line (file-only setting, preserved across edits).
+    daemon_command: Option<Vec<String>>,
+    /// Fallback daemon command line (file-only setting, preserved across
+    /// edits).
+    fallback_daemon_command: Option<Vec<String>>,
+    /// Daemon protocol version setting (file-only setting, preserved across
+    /// edits).
+    daemon_schema_version: Option<crate::translation::DaemonSchemaVersion>,
+    /// Daemon idle shutdown period in milliseconds (file-only setting,
+    /// preserved across edits).
+    daemon_idle_timeout_ms: Option<u64>,
+    /// Streaming daemon replies (file-only setting, preserved across
+    /// edits).
+    streaming: bool,
+    /// Preview length for debug records in grapheme clusters (file-only
+    /// setting, preserved across edits).
+    preview_chars: Option<usize>,
+    /// MCP translation server command (file-only setting, preserved across
+    /// edits).
+    mcp_server_command: Option<Vec<String>>,
+    /// MCP translation tool name (file-only setting, preserved across
+    /// edits).
+    mcp_tool: Option<String>,
+    /// Translation HTTP endpoint URL (file-only setting, preserved across
+    /// edits).
+    http_url: Option<String>,
+    /// Extra headers for the HTTP endpoint (file-only setting, preserved
+    /// across edits).
+    http_headers: Option<std::collections::HashMap<String, String>>,
+    /// Glossary table sent to translator daemons (file-only setting,
+    /// preserved across edits).
+    glossary: Option<std::collections::HashMap<String, String>>,
+    /// Per-kind reasoning overrides (file-only setting, preserved across
+    /// edits).
+    reasoning: Option<crate::translation::KindOverrides>,
+    /// Per-kind notice overrides (file-only setting, preserved across edits).
+    notice: Option<crate::translation::KindOverrides>,
+    /// Per-kind error overrides (file-only setting, preserved across edits).
+    error: Option<crate::translation::KindOverrides>,
+    /// Title cache capacity (file-only setting, preserved across edits).
+    title_cache_capacity: Option<usize>,
+    /// Debug log path (file-only setting, preserved across edits).
+    debug_log: Option<std::path::PathBuf>,
+    /// Whether debug-log records carry full text (file-only setting,
+    /// preserved across edits).
+    log_full_text: bool,
+    /// Whether a turn-level summary cell is emitted (file-only setting,
+    /// preserved across edits).
+    turn_summary: bool,
+    /// Bilingual title template (file-only setting, preserved across edits).
+    title_template: Option<String>,
+    /// Bilingual header overflow policy (file-only setting, preserved
+    /// across edits).
+    header_overflow: HeaderOverflow,
     /// Selected target language.
     language: TargetLanguage,
     /// Language selection index.
@@ -224,6 +325,42 @@ impl TranslateOverlay {
             model,
             base_url,
             timeout_ms,
+            mask_code: config.mask_code,
+            redact_secrets: config.redact_secrets,
+            same_language_threshold: config.same_language_threshold,
+            min_chars: config.min_chars,
+            max_input_chars: config.max_input_chars,
+            max_requests_per_minute: config.max_requests_per_minute,
+            failure_threshold: config.failure_threshold,
+            circuit_reset_ms: config.circuit_reset_ms,
+            disabled_for_models: config.disabled_for_models.clone(),
+            translators: config.translators.clone(),
+            use_translator: config.use_translator.clone(),
+            translate_ui_notices: config.translate_ui_notices,
+            translate_plan_updates: config.translate_plan_updates,
+            translate_errors: config.translate_errors,
+            show_translation_metadata: config.show_translation_metadata,
+            source_language: config.source_language.clone(),
+            daemon_command: config.daemon_command.clone(),
+            fallback_daemon_command: config.fallback_daemon_command.clone(),
+            daemon_schema_version: config.daemon_schema_version.clone(),
+            daemon_idle_timeout_ms: config.daemon_idle_timeout_ms,
+            streaming: config.streaming,
+            preview_chars: config.preview_chars,
+            mcp_server_command: config.mcp_server_command.clone(),
+            mcp_tool: config.mcp_tool.clone(),
+            http_url: config.http_url.clone(),
+            http_headers: config.http_headers.clone(),
+            glossary: config.glossary.clone(),
+            reasoning: config.reasoning.clone(),
+            notice: config.notice.clone(),
+            error: config.error.clone(),
+            title_cache_capacity: config.title_cache_capacity,
+            debug_log: config.debug_log.clone(),
+            log_full_text: config.log_full_text,
+            turn_summary: config.turn_summary,
+            title_template: config.title_template.clone(),
+            header_overflow: config.header_overflow,
             language,
             language_index,
             selection: Selection::Enabled,
@@ -262,6 +399,45 @@ impl TranslateOverlay {
                 .parse::<u64>()
                 .ok()
                 .filter(|&ms| ms > 0),
+            mask_code: self.mask_code,
+            redact_secrets: self.redact_secrets,
+            same_language_threshold: self.same_language_threshold,
+            min_chars: self.min_chars,
+            max_input_chars: self.max_input_chars,
+            max_requests_per_minute: self.max_requests_per_minute,
+            failure_threshold: self.failure_threshold,
+            circuit_reset_ms: self.circuit_reset_ms,
+            disabled_for_models: self.disabled_for_models.clone(),
+            translators: self.translators.clone(),
+            use_translator: self.use_translator.clone(),
+            translate_ui_notices: self.translate_ui_notices,
+            translate_plan_updates: self.translate_plan_updates,
+            translate_errors: self.translate_errors,
+            show_translation_metadata: self.show_translation_metadata,
+            source_language: self.source_language.clone(),
+            daemon_command: self.daemon_command.clone(),
+            fallback_daemon_command: self.fallback_daemon_command.clone(),
+            daemon_schema_version: self.daemon_schema_version.clone(),
+            daemon_idle_timeout_ms: self.daemon_idle_timeout_ms,
+            streaming: self.streaming,
+            preview_chars: self.preview_chars,
+            mcp_server_command: self.mcp_server_command.clone(),
+            mcp_tool: self.mcp_tool.clone(),
+            http_url: self.http_url.clone(),
+            http_headers: self.http_headers.clone(),
+            glossary: self.glossary.clone(),
+            reasoning: self.reasoning.clone(),
+            notice: self.notice.clone(),
+            error: self.error.clone(),
+            title_cache_capacity: self.title_cache_capacity,
+            debug_log: self.debug_log.clone(),
+            log_full_text: self.log_full_text,
+            turn_summary: self.turn_summary,
+            title_template: self.title_template.clone(),
+            header_overflow: self.header_overflow,
+            // Overlay edits went through the UI's own validation; any
+            // load-time warnings have been shown by now.
+            startup_warnings: Vec::new(),
         }
     }
 
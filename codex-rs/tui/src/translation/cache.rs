@@ -0,0 +1,271 @@
+//! Process-wide cache of completed translations.
+//!
+//! Identical texts recur within a session — repeated notices, reasoning
+//! sections re-sent after a retry — and each repeat would otherwise pay a
+//! full translator round trip. This bounded LRU remembers recent
+//! translations keyed by kind and input hash, and single-flights concurrent
+//! misses so two simultaneous requests for the same text run the translator
+//! once. Entries are only valid for the language pair they were produced
+//! under; the orchestrator clears the cache when that changes.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::hash::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::num::NonZeroUsize;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+
+use lru::LruCache;
+
+use super::error::TranslationError;
+use super::error_log::TranslationErrorKind;
+
+/// Completed translations retained process-wide.
+const TRANSLATION_CACHE_CAPACITY: usize = 512;
+
+/// The input text is hashed rather than stored, so the cache holds one copy
+/// of each translation and none of the originals.
+type CacheKey = (TranslationErrorKind, u64);
+
+/// Bounded LRU of completed translations, with single-flight deduplication
+/// of concurrent misses.
+#[derive(Debug)]
+pub(crate) struct TranslationCache {
+    entries: Mutex<LruCache<CacheKey, String>>,
+    /// Per-key gates, each held by the caller currently translating that key.
+    inflight: Mutex<HashMap<CacheKey, Arc<tokio::sync::Mutex<()>>>>,
+}
+
+impl TranslationCache {
+    fn new(capacity: usize) -> Self {
+        let capacity = NonZeroUsize::new(capacity).unwrap_or(NonZeroUsize::MIN);
+        Self {
+            entries: Mutex::new(LruCache::new(capacity)),
+            inflight: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// The process-wide cache instance.
+    pub(crate) fn shared() -> &'static TranslationCache {
+        static CACHE: OnceLock<TranslationCache> = OnceLock::new();
+        CACHE.get_or_init(|| TranslationCache::new(TRANSLATION_CACHE_CAPACITY))
+    }
+
+    fn key(kind: TranslationErrorKind, text: &str) -> CacheKey {
+        let mut hasher = DefaultHasher::new();
+        text.hash(&mut hasher);
+        (kind, hasher.finish())
+    }
+
+    fn lookup(&self, key: &CacheKey) -> Option<String> {
+        self.entries
+            .lock()
+            .expect("translation cache poisoned")
+            .get(key)
+            .cloned()
+    }
+
+    fn store(&self, key: CacheKey, translated: String) {
+        self.entries
+            .lock()
+            .expect("translation cache poisoned")
+            .put(key, translated);
+    }
+
+    /// Drop every entry. Called when the target or source language changes,
+    /// which invalidates all cached translations at once.
+    pub(crate) fn clear(&self) {
+        self.entries
+            .lock()
+            .expect("translation cache poisoned")
+            .clear();
+    }
+
+    /// Return the cached translation for `text`, or run `translate` and
+    /// remember its result. Concurrent calls for the same text are collapsed:
+    /// the first becomes the leader and runs `translate`, the rest wait and
+    /// read the cached result. Failures are not cached, so a waiter that
+    /// finds no entry after the leader finishes translates for itself.
+    pub(crate) async fn get_or_translate<F, Fut>(
+        &self,
+        kind: TranslationErrorKind,
+        text: &str,
+        translate: F,
+    ) -> Result<String, TranslationError>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<String, TranslationError>>,
+    {
+        let key = Self::key(kind, text);
+        let mut translate = Some(translate);
+        loop {
+            if let Some(hit) = self.lookup(&key) {
+                return Ok(hit);
+            }
+            // Become the leader for this key, or pick up the current
+            // leader's gate to wait on.
+            let leader_or_gate = {
+                let mut inflight = self.inflight.lock().expect("translation cache poisoned");
+                match inflight.get(&key) {
+                    Some(gate) => Err(Arc::clone(gate)),
+                    None => {
+                        let gate = Arc::new(tokio::sync::Mutex::new(()));
+                        let guard = gate
+                            .clone()
+                            .try_lock_owned()
+                            .expect("freshly created gate is unlocked");
+                        inflight.insert(key, gate);
+                        Ok(guard)
+                    }
+                }
+            };
+            match leader_or_gate {
+                Ok(_guard) => {
+                    let translate = translate.take().expect("leader runs at most once");
+                    let result = translate().await;
+                    if let Ok(translated) = &result {
+                        self.store(key, translated.clone());
+                    }
+                    self.inflight
+                        .lock()
+                        .expect("translation cache poisoned")
+                        .remove(&key);
+                    return result;
+                }
+                Err(gate) => {
+                    // Wait for the leader to finish, then re-check the cache.
+                    drop(gate.lock().await);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+    use std::time::Duration;
+
+    const KIND: TranslationErrorKind = TranslationErrorKind::Reasoning;
+
+    #[tokio::test]
+    async fn repeated_texts_translate_once_until_cleared() {
+        let cache = TranslationCache::new(8);
+        let calls = AtomicUsize::new(0);
+        let translate = || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Ok::<_, TranslationError>("译文".to_string())
+        };
+
+        let first = cache.get_or_translate(KIND, "hello", translate).await;
+        assert_eq!(first.unwrap(), "译文");
+        let second = cache.get_or_translate(KIND, "hello", translate).await;
+        assert_eq!(second.unwrap(), "译文");
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        // The same text under a different kind is a distinct entry.
+        let other = cache
+            .get_or_translate(TranslationErrorKind::UiNotice, "hello", translate)
+            .await;
+        assert_eq!(other.unwrap(), "译文");
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+        cache.clear();
+        let after_clear = cache.get_or_translate(KIND, "hello", translate).await;
+        assert_eq!(after_clear.unwrap(), "译文");
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn concurrent_requests_for_the_same_text_translate_once() {
+        let cache = Arc::new(TranslationCache::new(8));
+        let calls = Arc::new(AtomicUsize::new(0));
+        let release = Arc::new(tokio::sync::Notify::new());
+
+        let leader = {
+            let cache = Arc::clone(&cache);
+            let calls = Arc::clone(&calls);
+            let release = Arc::clone(&release);
+            tokio::spawn(async move {
+                cache
+                    .get_or_translate(KIND, "hello", || async {
+                        calls.fetch_add(1, Ordering::SeqCst);
+                        release.notified().await;
+                        Ok("译文".to_string())
+                    })
+                    .await
+            })
+        };
+        // Let the leader start translating, pile on a second request for the
+        // same text, and only then let the leader finish.
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        let follower = {
+            let cache = Arc::clone(&cache);
+            let calls = Arc::clone(&calls);
+            tokio::spawn(async move {
+                cache
+                    .get_or_translate(KIND, "hello", || async {
+                        calls.fetch_add(1, Ordering::SeqCst);
+                        Ok("译文".to_string())
+                    })
+                    .await
+            })
+        };
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        release.notify_one();
+
+        assert_eq!(leader.await.expect("join").unwrap(), "译文");
+        assert_eq!(follower.await.expect("join").unwrap(), "译文");
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn failures_are_not_cached() {
+        let cache = TranslationCache::new(8);
+        let failed = cache
+            .get_or_translate(KIND, "hello", || async {
+                Err::<String, _>(TranslationError::Timeout)
+            })
+            .await;
+        assert!(failed.is_err());
+
+        let retried = cache
+            .get_or_translate(KIND, "hello", || async {
+                Ok::<_, TranslationError>("译文".to_string())
+            })
+            .await;
+        assert_eq!(retried.unwrap(), "译文");
+    }
+
+    #[tokio::test]
+    async fn capacity_bounds_the_cache() {
+        let cache = TranslationCache::new(2);
+        let calls = AtomicUsize::new(0);
+        let translate = || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Ok::<_, TranslationError>("译文".to_string())
+        };
+
+        for text in ["one", "two", "three"] {
+            cache.get_or_translate(KIND, text, translate).await.unwrap();
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+        // "one" was evicted to make room for "three"; "two" survived.
+        cache
+            .get_or_translate(KIND, "two", translate)
+            .await
+            .unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        cache
+            .get_or_translate(KIND, "one", translate)
+            .await
+            .unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 4);
+    }
+}
@@ -0,0 +1,185 @@
+//! Circuit breaker for a persistently failing translator.
+//!
+//! A misconfigured translator that slips past the startup health check —
+//! or breaks mid-session — would otherwise spawn one doomed process per
+//! reasoning block and wait out the timeout every time. After
+//! `failure_threshold` consecutive failures the breaker opens and requests
+//! fail fast with [`TranslationError::CircuitOpen`]; once `circuit_reset_ms`
+//! passes, a single probe request is let through (half-open) and its outcome
+//! decides whether the breaker closes again or re-opens for another
+//! cool-down.
+
+use std::sync::LazyLock;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+use super::error::TranslationError;
+
+/// The one breaker all translations share, like the cache and the rate
+/// limiter: a broken translator is broken for every kind.
+static BREAKER: LazyLock<Mutex<CircuitBreaker>> = LazyLock::new(Mutex::default);
+
+#[derive(Debug)]
+enum State {
+    /// Requests pass; counts failures since the last success.
+    Closed { consecutive_failures: u32 },
+    /// Requests fail fast until the cool-down deadline.
+    Open { until: Instant },
+    /// One probe request is in flight; everything else fails fast.
+    HalfOpen,
+}
+
+#[derive(Debug)]
+pub(super) struct CircuitBreaker {
+    state: State,
+}
+
+impl Default for CircuitBreaker {
+    fn default() -> Self {
+        Self {
+            state: State::Closed {
+                consecutive_failures: 0,
+            },
+        }
+    }
+}
+
+impl CircuitBreaker {
+    /// Admit or reject a request. An open breaker whose cool-down has passed
+    /// admits the caller as the half-open probe.
+    fn admit(&mut self) -> Result<(), TranslationError> {
+        match self.state {
+            State::Closed { .. } => Ok(()),
+            State::Open { until } => {
+                if Instant::now() < until {
+                    return Err(TranslationError::CircuitOpen);
+                }
+                self.state = State::HalfOpen;
+                Ok(())
+            }
+            State::HalfOpen => Err(TranslationError::CircuitOpen),
+        }
+    }
+
+    /// A request completed successfully: close the breaker.
+    fn record_success(&mut self) {
+        if matches!(self.state, State::HalfOpen) {
+            tracing::info!("translator recovered; circuit breaker closed");
+        }
+        self.state = State::Closed {
+            consecutive_failures: 0,
+        };
+    }
+
+    /// A request failed: count it, and open the breaker when the streak
+    /// reaches `threshold` (or immediately when the half-open probe fails).
+    fn record_failure(&mut self, threshold: u32, reset: Duration) {
+        let streak = match self.state {
+            State::Closed {
+                consecutive_failures,
+            } => consecutive_failures + 1,
+            State::HalfOpen => threshold,
+            State::Open { .. } => return,
+        };
+        if streak >= threshold.max(1) {
+            tracing::warn!(
+                streak,
+                reset_ms = reset.as_millis() as u64,
+                "translator failing repeatedly; circuit breaker opened"
+            );
+            self.state = State::Open {
+                until: Instant::now() + reset,
+            };
+        } else {
+            self.state = State::Closed {
+                consecutive_failures: streak,
+            };
+        }
+    }
+}
+
+/// Admit or reject a request against the process-wide breaker.
+pub(crate) fn admit() -> Result<(), TranslationError> {
+    BREAKER.lock().expect("circuit breaker lock").admit()
+}
+
+/// Record a successful invocation against the process-wide breaker.
+pub(crate) fn record_success() {
+    BREAKER
+        .lock()
+        .expect("circuit breaker lock")
+        .record_success();
+}
+
+/// Record a failed invocation against the process-wide breaker.
+pub(crate) fn record_failure(threshold: u32, reset: Duration) {
+    BREAKER
+        .lock()
+        .expect("circuit breaker lock")
+        .record_failure(threshold, reset);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RESET: Duration = Duration::from_secs(30);
+
+    #[tokio::test(start_paused = true)]
+    async fn opens_after_the_failure_threshold() {
+        let mut breaker = CircuitBreaker::default();
+        for _ in 0..2 {
+            breaker.admit().expect("closed");
+            breaker.record_failure(3, RESET);
+        }
+        // Two failures: still closed.
+        breaker.admit().expect("below the threshold");
+        breaker.record_failure(3, RESET);
+
+        // Third consecutive failure: open, fail fast.
+        assert!(matches!(
+            breaker.admit(),
+            Err(TranslationError::CircuitOpen)
+        ));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn success_resets_the_failure_streak() {
+        let mut breaker = CircuitBreaker::default();
+        breaker.record_failure(3, RESET);
+        breaker.record_failure(3, RESET);
+        breaker.record_success();
+        breaker.record_failure(3, RESET);
+        breaker.record_failure(3, RESET);
+        breaker.admit().expect("streak restarted after a success");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn half_open_admits_one_probe_and_its_outcome_decides() {
+        let mut breaker = CircuitBreaker::default();
+        for _ in 0..3 {
+            breaker.record_failure(3, RESET);
+        }
+        assert!(breaker.admit().is_err());
+
+        // Cool-down passed: exactly one probe goes through.
+        tokio::time::advance(RESET).await;
+        breaker.admit().expect("half-open probe");
+        assert!(matches!(
+            breaker.admit(),
+            Err(TranslationError::CircuitOpen)
+        ));
+
+        // A failed probe re-opens for another full cool-down.
+        breaker.record_failure(3, RESET);
+        assert!(breaker.admit().is_err());
+        tokio::time::advance(RESET).await;
+        breaker.admit().expect("second probe");
+
+        // A successful probe closes the breaker for good.
+        breaker.record_success();
+        breaker.admit().expect("closed again");
+    }
+}
@@ -3,6 +3,7 @@
 //! This module provides the HTTP client for making translation requests
 //! to various LLM providers.
 
+use std::collections::HashMap;
 use std::time::Duration;
 
 use reqwest::Client;
@@ -10,13 +11,12 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use super::config::TranslationConfig;
+use super::daemon::TranslatedText;
 use super::error::TranslationError;
+use super::error_log::TranslationErrorKind;
 use super::provider::Protocol;
 use super::provider::ProviderDef;
 
-/// Default timeout for translation requests (in milliseconds).
-const DEFAULT_TIMEOUT_MS: u64 = 30000;
-
 /// Translation client.
 pub struct TranslationClient {
     client: Client,
@@ -29,8 +29,20 @@ pub struct TranslationClient {
 }
 
 impl TranslationClient {
-    /// Create a new translation client from configuration.
-    pub fn from_config(config: &TranslationConfig) -> Result<Self, TranslationError> {
+    /// Create a translation client for one kind of translation, honoring its
+    /// per-kind timeout override.
+    pub(crate) fn from_config_for_kind(
+        config: &TranslationConfig,
+        kind: TranslationErrorKind,
+    ) -> Result<Self, TranslationError> {
+        let timeout = Duration::from_millis(config.effective_timeout_ms_for(kind));
+        Self::with_timeout(config, timeout)
+    }
+
+    fn with_timeout(
+        config: &TranslationConfig,
+        timeout: Duration,
+    ) -> Result<Self, TranslationError> {
         let provider_id = config.effective_provider();
         let provider = provider_id.definition();
 
@@ -42,7 +54,6 @@ impl TranslationClient {
 
         let base_url = config.effective_base_url(provider).to_string();
         let model = config.effective_model(provider).to_string();
-        let timeout = Duration::from_millis(config.timeout_ms.unwrap_or(DEFAULT_TIMEOUT_MS));
 
         let client = Client::builder()
             .timeout(timeout)
@@ -59,19 +70,28 @@ impl TranslationClient {
         })
     }
 
-    /// Translate text to the target language.
+    /// Translate text to the target language. When a source language is
+    /// given it is named in the prompt; otherwise the model infers it.
     pub async fn translate(
         &self,
         text: &str,
         target_lang: &str,
-    ) -> Result<String, TranslationError> {
-        let prompt = build_translation_prompt(text, target_lang);
-
-        match self.provider.protocol {
-            Protocol::OpenAI => self.call_openai_compatible(&prompt).await,
-            Protocol::Anthropic => self.call_anthropic(&prompt).await,
-            Protocol::Gemini => self.call_gemini(&prompt).await,
-        }
+        source_lang: Option<&str>,
+    ) -> Result<TranslatedText, TranslationError> {
+        let prompt = build_translation_prompt(text, target_lang, source_lang);
+
+        let text = match self.provider.protocol {
+            Protocol::OpenAI => self.call_openai_compatible(&prompt).await?,
+            Protocol::Anthropic => self.call_anthropic(&prompt).await?,
+            Protocol::Gemini => self.call_gemini(&prompt).await?,
+        };
+        // Chat-completion providers translate without reporting which source
+        // language they saw or any metadata; only daemons can fill these in.
+        Ok(TranslatedText {
+            text,
+            detected_language: None,
+            metadata: HashMap::new(),
+        })
     }
 
     /// Get the timeout duration.
@@ -228,10 +248,16 @@ impl TranslationClient {
     }
 }
 
-/// Build the translation prompt.
-fn build_translation_prompt(text: &str, target_lang: &str) -> String {
+/// Build the translation prompt. The `"auto"` source sentinel is a wire
+/// concept for daemons; a chat model detects the source anyway, so it is
+/// left out of the prompt.
+fn build_translation_prompt(text: &str, target_lang: &str, source_lang: Option<&str>) -> String {
+    let from = source_lang
+        .filter(|lang| !lang.eq_ignore_ascii_case("auto"))
+        .map(|lang| format!(" from {lang}"))
+        .unwrap_or_default();
     format!(
-        "Translate the following text to {target_lang}. \
+        "Translate the following text{from} to {target_lang}. \
          Keep the original formatting (markdown, code blocks, etc.). \
          Output only the translation, nothing else.\n\n{text}"
     )
@@ -337,9 +363,23 @@ mod tests {
 
     #[test]
     fn build_prompt() {
-        let prompt = build_translation_prompt("Hello, world!", "Chinese");
+        let prompt = build_translation_prompt("Hello, world!", "Chinese", None);
         assert!(prompt.contains("Chinese"));
         assert!(prompt.contains("Hello, world!"));
         assert!(prompt.contains("markdown"));
+        assert!(!prompt.contains("from"));
+    }
+
+    #[test]
+    fn build_prompt_names_configured_source_language() {
+        let prompt = build_translation_prompt("Hello, world!", "de-DE", Some("en"));
+        assert!(prompt.contains("from en to de-DE"));
+    }
+
+    #[test]
+    fn build_prompt_omits_auto_source_sentinel() {
+        let prompt = build_translation_prompt("Hello, world!", "de-DE", Some("auto"));
+        assert!(!prompt.contains("from"));
+        assert!(prompt.contains("to de-DE"));
     }
 }
@@ -4,9 +4,14 @@
 
 use serde::Deserialize;
 use serde::Serialize;
+use std::collections::HashMap;
 use std::fs;
+use std::num::NonZeroUsize;
 use std::path::PathBuf;
 
+use super::daemon::DAEMON_SCHEMA_VERSION_MAX;
+use super::daemon::SchemaVersionRequest;
+use super::error_log::TranslationErrorKind;
 use super::provider::ProviderDef;
 use super::provider::ProviderId;
 
@@ -14,6 +19,36 @@ use super::provider::ProviderId;
 #[allow(dead_code)]
 const DEFAULT_TIMEOUT_MS: u64 = 30000;
 
+/// Default timeout for error-message translations. Deliberately short so a
+/// slow translator can never noticeably delay an error from being useful.
+const DEFAULT_ERROR_TIMEOUT_MS: u64 = 2000;
+
+/// Default capacity of the reasoning-title translation cache.
+const DEFAULT_TITLE_CACHE_CAPACITY: NonZeroUsize = NonZeroUsize::new(256).unwrap();
+
+/// Most glossary entries a config may carry; larger tables are dropped with a
+/// warning at load time since every entry rides along on every request line.
+const MAX_GLOSSARY_ENTRIES: usize = 200;
+
+/// Default template for bilingual title rendering.
+pub(crate) const DEFAULT_TITLE_TEMPLATE: &str = "{original} · {translated}";
+
+/// Default fraction of script-bearing characters that must already be in the
+/// target language's script before translation is skipped.
+const DEFAULT_SAME_LANGUAGE_THRESHOLD: f64 = 0.5;
+
+/// Default minimum length (in characters) before a reasoning body is worth a
+/// translator round trip. Other kinds default to no minimum: notices are
+/// short by nature and errors should translate even when terse.
+const DEFAULT_REASONING_MIN_CHARS: usize = 40;
+
+/// Default number of consecutive failures that opens the circuit breaker.
+const DEFAULT_FAILURE_THRESHOLD: u32 = 3;
+
+/// Default cool-down (in milliseconds) before an open circuit breaker lets a
+/// probe request through.
+const DEFAULT_CIRCUIT_RESET_MS: u64 = 30_000;
+
 /// Translation configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TranslationConfig {
@@ -25,6 +60,13 @@ pub struct TranslationConfig {
     #[serde(default = "default_target_language")]
     pub target_language: String,
 
+    /// Source language code (e.g., "en"). Carried on translator requests so
+    /// a daemon can route by language pair; when unset the translator infers
+    /// the source language, as it always has. The `"auto"` spelling asks the
+    /// daemon to detect the source and report it back.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_language: Option<String>,
+
     /// Provider identifier (e.g., "deepseek", "openai").
     #[serde(default = "default_provider")]
     pub provider: String,
@@ -44,10 +86,477 @@ pub struct TranslationConfig {
     /// Timeout in milliseconds.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub timeout_ms: Option<u64>,
+
+    /// Maximum number of reasoning-title translations kept for bilingual
+    /// rendering; least-recently-displayed titles are evicted beyond this.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title_cache_capacity: Option<usize>,
+
+    /// Whether to shield inline code, fenced blocks, URLs, and file paths
+    /// from the translator behind opaque placeholders. On by default;
+    /// set `mask_code = false` to send reasoning text verbatim. Also accepted
+    /// under the `protect_code_blocks` spelling.
+    #[serde(default = "default_mask_code", alias = "protect_code_blocks")]
+    pub mask_code: bool,
+
+    /// Whether to replace likely secrets (AWS keys, GitHub tokens, private
+    /// key blocks, `Bearer` headers, long base64 runs) with `[REDACTED]`
+    /// before text is sent to the translator. Off by default; the transcript
+    /// always renders the original text. The patterns are deliberately
+    /// aggressive, so long hashes may be redacted too.
+    #[serde(default)]
+    pub redact_secrets: bool,
+
+    /// Fraction (0.0 to 1.0) of script-bearing characters that must already
+    /// be in the target language's script for translation to be skipped
+    /// (default 0.5). Text the model wrote in the target language would only
+    /// translate to a near-duplicate block.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub same_language_threshold: Option<f64>,
+
+    /// Minimum text length (in characters) before the translator is invoked,
+    /// for every kind unless overridden per kind. Defaults to 40 for
+    /// reasoning bodies and 0 for everything else; texts below the threshold
+    /// are shown untranslated with no barrier or history cell.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_chars: Option<usize>,
+
+    /// Maximum text length (in characters) serialized into a translation
+    /// request. Longer input is cut on a paragraph boundary with a
+    /// "truncated for translation" marker, and the request carries
+    /// `truncated: true` so the translator knows. The transcript keeps the
+    /// untruncated original. Unset means no cap.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_input_chars: Option<usize>,
+
+    /// Maximum translator invocations per minute, shared across every
+    /// translation kind in the process. When the budget is exhausted a
+    /// request briefly waits for the next slot if it fits within the kind's
+    /// timeout, and is silently skipped otherwise. Unset means no limit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_requests_per_minute: Option<u32>,
+
+    /// Consecutive translator failures before the circuit breaker opens and
+    /// further requests fail fast without spawning anything (default 3).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub failure_threshold: Option<u32>,
+
+    /// Cool-down in milliseconds after the circuit breaker opens; once it
+    /// passes, a single probe request is let through and its outcome decides
+    /// whether the breaker closes again (default 30000).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub circuit_reset_ms: Option<u64>,
+
+    /// Model slugs whose output is never translated ("gpt-5.1-codex-mini");
+    /// `*` globs are supported ("*-mini"). Useful for models that already
+    /// reason in the target language. Checked against the model driving the
+    /// conversation, so `/model` switches take effect immediately.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disabled_for_models: Option<Vec<String>>,
+
+    /// Whether to also translate short built-in UI notices
+    /// (slash-command output, confirmations, error notices).
+    #[serde(default)]
+    pub translate_ui_notices: bool,
+
+    /// Whether to also translate plan-update notes ("I will now refactor X,
+    /// then run tests") shown in the transcript.
+    #[serde(default)]
+    pub translate_plan_updates: bool,
+
+    /// Whether to also translate error messages surfaced to the user
+    /// (stream errors, sandbox denials). The original error is always shown
+    /// unchanged when the translation is not ready or fails.
+    #[serde(default)]
+    pub translate_errors: bool,
+
+    /// Whether to render a dim one-line footer under each translated
+    /// reasoning block with the metadata the translator reported (engine,
+    /// latency, ...). Off by default; blocks without metadata never get a
+    /// footer.
+    #[serde(default)]
+    pub show_translation_metadata: bool,
+
+    /// Command line for a long-running translator daemon. When set,
+    /// translations go through the daemon over newline-delimited JSON
+    /// instead of per-request HTTP calls.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub daemon_command: Option<Vec<String>>,
+
+    /// Named translator definitions (`[translators.<name>]` tables), each a
+    /// command line plus optional timeout. Keeps several translator scripts
+    /// (fast, quality, offline, ...) configured side by side; `use` picks
+    /// one without editing command arrays.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub translators: Option<HashMap<String, TranslatorDef>>,
+
+    /// Name of the translator definition used by default (`use = "fast"`).
+    /// An explicit `daemon_command` at the same scope wins over it.
+    #[serde(default, rename = "use", skip_serializing_if = "Option::is_none")]
+    pub use_translator: Option<String>,
+
+    /// Command line for a second translator daemon tried when the one from
+    /// `daemon_command` fails. Applies to every kind, including kinds whose
+    /// `daemon_command` is overridden per kind.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fallback_daemon_command: Option<Vec<String>>,
+
+    /// Command line for a Model Context Protocol server that serves
+    /// translations through one of its tools. Used together with `mcp_tool`;
+    /// each request becomes a `tools/call` round trip instead of an NDJSON
+    /// daemon line. A configured daemon command wins over the MCP backend,
+    /// and combining the two at the top level is rejected at load time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mcp_server_command: Option<Vec<String>>,
+
+    /// Name of the MCP tool called for each translation. The tool receives
+    /// `text`, `target_language`, and — when configured — `source_language`
+    /// and `glossary` as arguments, and must answer with the translated text
+    /// as its text content.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mcp_tool: Option<String>,
+
+    /// URL of a translation HTTP endpoint. When set, each request POSTs the
+    /// daemon request JSON (without the `id`) and expects the daemon
+    /// response JSON back, so a local translation microservice needs no
+    /// wrapper script. The kind's `timeout_ms` applies per request; daemon
+    /// and MCP backends win over this one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http_url: Option<String>,
+
+    /// Extra headers sent to `http_url` (`[http_headers]` table). A value of
+    /// the form `env:NAME` is resolved from the environment at request time,
+    /// so tokens never sit verbatim in the config file or in logs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http_headers: Option<HashMap<String, String>>,
+
+    /// Term -> translation pairs sent to translator daemons with each request
+    /// (`[glossary]` table), for product names and jargon that must translate
+    /// consistently. HTTP providers do not receive the glossary.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub glossary: Option<HashMap<String, String>>,
+
+    /// Daemon protocol version: `1` (the default), `2`, or `"auto"`.
+    /// Anything above 1 probes each daemon's capabilities once per process
+    /// and speaks the highest version both sides support; version-1 request
+    /// lines stay byte-identical to unversioned ones.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub daemon_schema_version: Option<DaemonSchemaVersion>,
+
+    /// Stop an idle translator daemon after this many milliseconds without a
+    /// request; the next request respawns it on demand. Unset keeps daemons
+    /// alive for the whole session.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub daemon_idle_timeout_ms: Option<u64>,
+
+    /// Opt-in streaming mode for daemon replies: request lines carry
+    /// `streaming: true` and the command answers with NDJSON chunk lines
+    /// (`{"id":N,"chunk":"...","done":false}`) closed by a final `done: true`
+    /// line, so long translations surface piece by piece. Daemons that
+    /// ignore the flag keep answering in the single-line shape.
+    #[serde(default)]
+    pub streaming: bool,
+
+    /// Backend overrides for reasoning translations (`[reasoning]` table).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reasoning: Option<KindOverrides>,
+
+    /// Backend overrides for UI-notice translations (`[notice]` table).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notice: Option<KindOverrides>,
+
+    /// Backend overrides for error-message translations (`[error]` table).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<KindOverrides>,
+
+    /// Append a JSONL debug record per translator request to this path:
+    /// request metadata, timing, and the response or error. The log rotates
+    /// once it grows past a size cap.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub debug_log: Option<PathBuf>,
+
+    /// Whether debug-log records carry the full request/response text instead
+    /// of a hashed, truncated preview. Off by default so the log can be
+    /// shared without leaking code.
+    #[serde(default)]
+    pub log_full_text: bool,
+
+    /// Length, in grapheme clusters, of the text previews embedded in
+    /// debug-log records and error reports (default 120). Truncation counts
+    /// graphemes rather than chars, so combined emoji and ZWJ sequences are
+    /// never split, and every preview ends with the total original size.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preview_chars: Option<usize>,
+
+    /// Whether to emit a compact summary cell at the end of each turn:
+    /// how many reasoning blocks were translated, failed, or skipped, the
+    /// total translation time, and the failure reasons with their codes.
+    #[serde(default)]
+    pub turn_summary: bool,
+
+    /// Template for bilingual title rendering, with `{original}` and
+    /// `{translated}` placeholders (default `"{original} · {translated}"`).
+    /// The shortcut value `"translated_only"` drops the original entirely.
+    /// Templates with unknown placeholders are rejected at load time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title_template: Option<String>,
+
+    /// How the bilingual status header degrades when it exceeds the width
+    /// available to it.
+    #[serde(default)]
+    pub header_overflow: HeaderOverflow,
+
+    /// Warnings produced while loading and sanitizing this configuration:
+    /// deprecated keys, out-of-bounds values, ignored backends. Not part of
+    /// the file itself; collected here so callers can show them to the user
+    /// instead of burying them in the tracing log.
+    #[serde(skip)]
+    pub startup_warnings: Vec<String>,
+}
+
+/// Backend overrides for one kind of translation. Lets a fast local daemon
+/// serve short UI notices while a slower, higher-quality command handles
+/// reasoning blocks. Absent values fall back to the top-level settings.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KindOverrides {
+    /// Whether this kind is translated at all (default true). `false`
+    /// switches the kind off while keeping its `daemon_command` and the rest
+    /// of its table in place, so it can be re-enabled without retyping a
+    /// carefully quoted argv.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+
+    /// Daemon command line for this kind only.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub daemon_command: Option<Vec<String>>,
+
+    /// Name of the translator definition to use for this kind only
+    /// (`use = "quality"`). An explicit `daemon_command` in the same table
+    /// wins over it.
+    #[serde(default, rename = "use", skip_serializing_if = "Option::is_none")]
+    pub use_translator: Option<String>,
+
+    /// Timeout in milliseconds for this kind only.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_ms: Option<u64>,
+
+    /// Extra glossary entries for this kind only, merged over the top-level
+    /// `[glossary]` table; entries here win on conflicting terms.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub glossary: Option<HashMap<String, String>>,
+
+    /// Minimum text length (in characters) for this kind only.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_chars: Option<usize>,
+
+    /// Model slugs for which this kind is not translated, replacing the
+    /// top-level `disabled_for_models` list for this kind.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disabled_for_models: Option<Vec<String>>,
+}
+
+/// One named translator definition under `[translators.<name>]`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TranslatorDef {
+    /// Command line for this translator daemon.
+    pub command: Vec<String>,
+
+    /// Timeout in milliseconds when this translator is selected. An explicit
+    /// `timeout_ms` at the selecting scope wins over it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_ms: Option<u64>,
+}
+
+/// Daemon protocol version setting: a pinned number or the `"auto"` keyword.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum DaemonSchemaVersion {
+    /// Negotiate up to this version; `1` skips the probe entirely.
+    Pinned(u64),
+    /// `"auto"`: negotiate the highest version this build supports. Other
+    /// strings are treated as version 1.
+    Keyword(String),
+}
+
+/// Fallback for a bilingual status header that does not fit the header area.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HeaderOverflow {
+    /// Show only the translated title.
+    #[default]
+    Translated,
+    /// Show only the original title.
+    Original,
+    /// Truncate the bilingual form on a character boundary with an ellipsis.
+    Truncate,
 }
 
+/// Target language used when locale detection fails.
+const FALLBACK_TARGET_LANGUAGE: &str = "zh-CN";
+
+/// Default target language: derived from the process locale, since most
+/// users who configure a translator never set `target_language` explicitly.
 fn default_target_language() -> String {
-    "zh-CN".to_string()
+    detected_target_language().to_string()
+}
+
+/// Detect the target language from the process locale, once per process.
+///
+/// Detection reads `LC_ALL` then `LANG` (POSIX precedence). Windows has no
+/// reliable locale environment variables, so detection usually fails there
+/// and the fallback applies. The detected value is logged once.
+fn detected_target_language() -> &'static str {
+    static DETECTED: std::sync::OnceLock<String> = std::sync::OnceLock::new();
+    DETECTED.get_or_init(|| {
+        let locale = std::env::var("LC_ALL")
+            .or_else(|_| std::env::var("LANG"))
+            .unwrap_or_default();
+        match parse_locale_to_bcp47(&locale) {
+            Some(language) => {
+                tracing::info!(
+                    target_language = %language,
+                    "detected translation target language from locale"
+                );
+                language
+            }
+            None => FALLBACK_TARGET_LANGUAGE.to_string(),
+        }
+    })
+}
+
+/// Map a POSIX locale string (e.g. `en_US.UTF-8`) to a BCP-47 code.
+///
+/// Returns `None` for empty and non-language locales (`C`, `POSIX`).
+fn parse_locale_to_bcp47(locale: &str) -> Option<String> {
+    // Strip the encoding suffix (`.UTF-8`) and modifier (`@euro`).
+    let base = locale.split(['.', '@']).next().unwrap_or_default();
+    if base.is_empty() || base.eq_ignore_ascii_case("C") || base.eq_ignore_ascii_case("POSIX") {
+        return None;
+    }
+    let mut parts = base.split('_');
+    let language = parts.next()?.to_ascii_lowercase();
+    if language.is_empty() || !language.chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    match parts.next() {
+        Some(region) if !region.is_empty() => {
+            Some(format!("{language}-{}", region.to_ascii_uppercase()))
+        }
+        _ => Some(language),
+    }
+}
+
+/// Check a title template: every `{...}` placeholder must be `{original}`
+/// or `{translated}`, and `{translated}` must appear. Returns a description
+/// of the first problem, or `None` for a valid template.
+fn title_template_error(template: &str) -> Option<String> {
+    let mut rest = template;
+    let mut has_translated = false;
+    while let Some(start) = rest.find('{') {
+        let tail = &rest[start..];
+        let Some(end) = tail.find('}') else {
+            return Some("unclosed '{' placeholder".to_string());
+        };
+        match &tail[..=end] {
+            "{original}" => {}
+            "{translated}" => has_translated = true,
+            other => return Some(format!("unknown placeholder {other}")),
+        }
+        rest = &tail[end + 1..];
+    }
+    if has_translated {
+        None
+    } else {
+        Some("missing the {translated} placeholder".to_string())
+    }
+}
+
+/// Record one load-time warning: traced immediately, and kept on the config
+/// (`startup_warnings`) so the UI can show it to the user.
+fn note(warnings: &mut Vec<String>, message: String) {
+    tracing::warn!("{message}");
+    warnings.push(message);
+}
+
+/// Scan the raw file for deprecated keys before typed parsing erases the
+/// spelling the user wrote, warning once per table they appear in.
+fn deprecation_warnings(content: &str) -> Vec<String> {
+    let mut warnings = Vec::new();
+    let Ok(root) = toml::from_str::<toml::Table>(content) else {
+        return warnings;
+    };
+    if root.contains_key("protect_code_blocks") {
+        note(
+            &mut warnings,
+            "`protect_code_blocks` is deprecated, rename it to `mask_code`".to_string(),
+        );
+    }
+    for table in ["reasoning", "notice", "error"] {
+        if let Some(section) = root.get(table).and_then(toml::Value::as_table)
+            && section.contains_key("protect_code_blocks")
+        {
+            note(
+                &mut warnings,
+                format!(
+                    "`protect_code_blocks` in [{table}] has no effect; \
+                     set `mask_code` at the top level instead"
+                ),
+            );
+        }
+    }
+    warnings
+}
+
+/// Drop a glossary table exceeding [`MAX_GLOSSARY_ENTRIES`], with a warning
+/// naming the offending table.
+fn cap_glossary(
+    warnings: &mut Vec<String>,
+    table: &str,
+    glossary: &mut Option<HashMap<String, String>>,
+) {
+    if let Some(entries) = glossary
+        && entries.len() > MAX_GLOSSARY_ENTRIES
+    {
+        note(
+            warnings,
+            format!(
+                "[{table}] has {} entries (max {MAX_GLOSSARY_ENTRIES}), ignoring it",
+                entries.len()
+            ),
+        );
+        *glossary = None;
+    }
+}
+
+/// Match a model slug against a `disabled_for_models` pattern, where each
+/// `*` matches any run of characters (including none). A pattern without
+/// `*` must match the whole slug.
+fn model_glob_matches(pattern: &str, model: &str) -> bool {
+    if !pattern.contains('*') {
+        return pattern == model;
+    }
+    let mut segments = pattern.split('*');
+    let head = segments.next().unwrap_or_default();
+    let Some(mut rest) = model.strip_prefix(head) else {
+        return false;
+    };
+    let mut segments: Vec<&str> = segments.collect();
+    let tail = if pattern.ends_with('*') {
+        None
+    } else {
+        segments.pop()
+    };
+    for segment in segments {
+        let Some(found) = rest.find(segment) else {
+            return false;
+        };
+        rest = &rest[found + segment.len()..];
+    }
+    tail.is_none_or(|tail| rest.ends_with(tail))
+}
+
+fn default_mask_code() -> bool {
+    true
 }
 
 fn default_provider() -> String {
@@ -59,11 +568,48 @@ impl Default for TranslationConfig {
         Self {
             enabled: false,
             target_language: default_target_language(),
+            source_language: None,
             provider: default_provider(),
             api_key: None,
             model: None,
             base_url: None,
             timeout_ms: None,
+            title_cache_capacity: None,
+            mask_code: true,
+            redact_secrets: false,
+            same_language_threshold: None,
+            min_chars: None,
+            max_input_chars: None,
+            max_requests_per_minute: None,
+            failure_threshold: None,
+            circuit_reset_ms: None,
+            disabled_for_models: None,
+            translate_ui_notices: false,
+            translate_plan_updates: false,
+            translate_errors: false,
+            show_translation_metadata: false,
+            daemon_command: None,
+            translators: None,
+            use_translator: None,
+            fallback_daemon_command: None,
+            mcp_server_command: None,
+            mcp_tool: None,
+            http_url: None,
+            http_headers: None,
+            glossary: None,
+            daemon_schema_version: None,
+            daemon_idle_timeout_ms: None,
+            streaming: false,
+            reasoning: None,
+            notice: None,
+            error: None,
+            debug_log: None,
+            log_full_text: false,
+            preview_chars: None,
+            turn_summary: false,
+            title_template: None,
+            header_overflow: HeaderOverflow::Translated,
+            startup_warnings: Vec::new(),
         }
     }
 }
@@ -86,19 +632,177 @@ impl TranslationConfig {
 
         match fs::read_to_string(&path) {
             Ok(content) => match toml::from_str::<TranslationConfig>(&content) {
-                Ok(config) => config,
+                Ok(mut config) => {
+                    config.startup_warnings = deprecation_warnings(&content);
+                    config.sanitized()
+                }
                 Err(e) => {
-                    tracing::warn!("Failed to parse translation config: {}, using default", e);
-                    Self::default()
+                    let mut config = Self::default();
+                    note(
+                        &mut config.startup_warnings,
+                        format!("failed to parse translation config: {e}, using the default"),
+                    );
+                    config
                 }
             },
             Err(e) => {
-                tracing::warn!("Failed to read translation config: {}, using default", e);
-                Self::default()
+                let mut config = Self::default();
+                note(
+                    &mut config.startup_warnings,
+                    format!("failed to read translation config: {e}, using the default"),
+                );
+                config
             }
         }
     }
 
+    /// Drop out-of-bounds settings from a freshly parsed config, warning
+    /// about each, so one oversized table does not reject the whole file.
+    fn sanitized(mut self) -> Self {
+        // Deprecation notices pushed by `load` stay at the front.
+        let mut warnings = std::mem::take(&mut self.startup_warnings);
+        if let Some(template) = &self.title_template
+            && template != "translated_only"
+            && let Some(error) = title_template_error(template)
+        {
+            note(
+                &mut warnings,
+                format!("invalid title_template {template:?} ({error}), using the default"),
+            );
+            self.title_template = None;
+        }
+        if let Some(threshold) = self.same_language_threshold
+            && !(0.0..=1.0).contains(&threshold)
+        {
+            note(
+                &mut warnings,
+                format!(
+                    "same_language_threshold {threshold} must be between 0.0 and 1.0, \
+                     using the default"
+                ),
+            );
+            self.same_language_threshold = None;
+        }
+        if self.max_requests_per_minute == Some(0) {
+            note(
+                &mut warnings,
+                "max_requests_per_minute must be at least 1, disabling the limit".to_string(),
+            );
+            self.max_requests_per_minute = None;
+        }
+        if self.failure_threshold == Some(0) {
+            note(
+                &mut warnings,
+                "failure_threshold must be at least 1, using the default".to_string(),
+            );
+            self.failure_threshold = None;
+        }
+        if self.daemon_idle_timeout_ms == Some(0) {
+            note(
+                &mut warnings,
+                "daemon_idle_timeout_ms must be at least 1, disabling idle shutdown".to_string(),
+            );
+            self.daemon_idle_timeout_ms = None;
+        }
+        if self.streaming && self.daemon_command.is_none() && self.use_translator.is_none() {
+            note(
+                &mut warnings,
+                "streaming requires a daemon command, ignoring it".to_string(),
+            );
+            self.streaming = false;
+        }
+        if self.preview_chars == Some(0) {
+            note(
+                &mut warnings,
+                "preview_chars must be at least 1, using the default".to_string(),
+            );
+            self.preview_chars = None;
+        }
+        if self.mcp_server_command.is_some() != self.mcp_tool.is_some() {
+            note(
+                &mut warnings,
+                "mcp_server_command and mcp_tool must be set together, \
+                 ignoring the MCP backend"
+                    .to_string(),
+            );
+            self.mcp_server_command = None;
+            self.mcp_tool = None;
+        }
+        if self.mcp_server_command.is_some()
+            && (self.daemon_command.is_some() || self.use_translator.is_some())
+        {
+            note(
+                &mut warnings,
+                "mcp_server_command cannot be combined with daemon_command or `use`, \
+                 ignoring the MCP backend"
+                    .to_string(),
+            );
+            self.mcp_server_command = None;
+            self.mcp_tool = None;
+        }
+        if self.http_url.is_some()
+            && (self.daemon_command.is_some()
+                || self.use_translator.is_some()
+                || self.mcp_server_command.is_some())
+        {
+            note(
+                &mut warnings,
+                "http_url cannot be combined with another translation backend, ignoring it"
+                    .to_string(),
+            );
+            self.http_url = None;
+        }
+        let mut known: Vec<String> = self
+            .translators
+            .iter()
+            .flat_map(HashMap::keys)
+            .cloned()
+            .collect();
+        known.sort_unstable();
+        let available = if known.is_empty() {
+            "none".to_string()
+        } else {
+            known.join(", ")
+        };
+        let mut check_use =
+            |warnings: &mut Vec<String>, scope: &str, reference: &mut Option<String>| {
+                if let Some(name) = reference
+                    && !known.contains(name)
+                {
+                    note(
+                        warnings,
+                        format!(
+                            "unknown translator \"{name}\" in {scope} `use` \
+                             (available: {available}), ignoring it"
+                        ),
+                    );
+                    *reference = None;
+                }
+            };
+        check_use(&mut warnings, "top-level", &mut self.use_translator);
+        for (scope, overrides) in [
+            ("[reasoning]", &mut self.reasoning),
+            ("[notice]", &mut self.notice),
+            ("[error]", &mut self.error),
+        ] {
+            if let Some(overrides) = overrides {
+                check_use(&mut warnings, scope, &mut overrides.use_translator);
+            }
+        }
+        cap_glossary(&mut warnings, "glossary", &mut self.glossary);
+        for (table, overrides) in [
+            ("reasoning.glossary", &mut self.reasoning),
+            ("notice.glossary", &mut self.notice),
+            ("error.glossary", &mut self.error),
+        ] {
+            if let Some(overrides) = overrides {
+                cap_glossary(&mut warnings, table, &mut overrides.glossary);
+            }
+        }
+        self.startup_warnings = warnings;
+        self
+    }
+
     /// Save configuration to file.
     pub fn save(&self) -> std::io::Result<()> {
         let Some(path) = Self::config_path() else {
@@ -129,10 +833,25 @@ impl TranslationConfig {
         Ok(())
     }
 
-    /// Check if translation is enabled.
-    #[allow(dead_code)]
-    pub fn should_translate(&self) -> bool {
-        self.enabled
+    /// Get the effective target language, resolving the explicit `"auto"`
+    /// spelling to the locale-detected language.
+    pub fn effective_target_language(&self) -> &str {
+        if self.target_language.eq_ignore_ascii_case("auto") {
+            detected_target_language()
+        } else {
+            &self.target_language
+        }
+    }
+
+    /// Get the effective source language, treating an empty string as unset
+    /// and normalizing the `"auto"` detection sentinel to lowercase.
+    pub fn effective_source_language(&self) -> Option<&str> {
+        let lang = self.source_language.as_deref().filter(|l| !l.is_empty())?;
+        if lang.eq_ignore_ascii_case("auto") {
+            Some("auto")
+        } else {
+            Some(lang)
+        }
     }
 
     /// Get the effective provider ID.
@@ -167,6 +886,220 @@ impl TranslationConfig {
         self.timeout_ms.unwrap_or(DEFAULT_TIMEOUT_MS)
     }
 
+    /// Per-kind overrides table for `kind`, if configured.
+    fn kind_overrides(&self, kind: TranslationErrorKind) -> Option<&KindOverrides> {
+        match kind {
+            TranslationErrorKind::Reasoning => self.reasoning.as_ref(),
+            TranslationErrorKind::UiNotice => self.notice.as_ref(),
+            TranslationErrorKind::ErrorMessage => self.error.as_ref(),
+            // Plan updates have no dedicated override table; they use the
+            // top-level settings.
+            TranslationErrorKind::PlanUpdate => None,
+        }
+    }
+
+    /// Get the daemon command for `kind`: the per-kind override when present,
+    /// the top-level `daemon_command` otherwise.
+    pub(crate) fn daemon_command_for(&self, kind: TranslationErrorKind) -> Option<&[String]> {
+        self.kind_overrides(kind)
+            .and_then(|overrides| overrides.daemon_command.as_deref())
+            .or_else(|| {
+                self.selected_translator_for(kind)
+                    .map(|def| def.command.as_slice())
+            })
+            .or(self.daemon_command.as_deref())
+            .filter(|command| !command.is_empty())
+    }
+
+    /// The named translator definition that supplies the daemon command for
+    /// `kind`, when one does: an explicit `daemon_command` at the selecting
+    /// scope shadows the `use` reference, and per-kind settings win over the
+    /// top level. Unknown names were already cleared at load time.
+    fn selected_translator_for(&self, kind: TranslationErrorKind) -> Option<&TranslatorDef> {
+        let overrides = self.kind_overrides(kind);
+        if overrides.is_some_and(|overrides| overrides.daemon_command.is_some()) {
+            return None;
+        }
+        if let Some(name) = overrides.and_then(|overrides| overrides.use_translator.as_deref()) {
+            return self.translator_def(name);
+        }
+        if self.daemon_command.is_some() {
+            return None;
+        }
+        self.use_translator
+            .as_deref()
+            .and_then(|name| self.translator_def(name))
+    }
+
+    /// Look up a `[translators.<name>]` definition.
+    fn translator_def(&self, name: &str) -> Option<&TranslatorDef> {
+        self.translators.as_ref()?.get(name)
+    }
+
+    /// Get the glossary for `kind`: the top-level `[glossary]` table with the
+    /// per-kind entries merged over it (per-kind terms win). Returns `None`
+    /// when neither table has entries, so no `glossary` field is serialized.
+    pub(crate) fn glossary_for(
+        &self,
+        kind: TranslationErrorKind,
+    ) -> Option<HashMap<String, String>> {
+        let per_kind = self
+            .kind_overrides(kind)
+            .and_then(|overrides| overrides.glossary.as_ref());
+        let mut merged = self.glossary.clone().unwrap_or_default();
+        if let Some(per_kind) = per_kind {
+            merged.extend(per_kind.iter().map(|(k, v)| (k.clone(), v.clone())));
+        }
+        if merged.is_empty() {
+            None
+        } else {
+            Some(merged)
+        }
+    }
+
+    /// Resolve `daemon_schema_version` into the version policy for daemons:
+    /// unset, `1`, or an unknown keyword mean plain version 1; a higher pin
+    /// or `"auto"` enables the capabilities probe.
+    pub(crate) fn daemon_schema_version_request(&self) -> SchemaVersionRequest {
+        match &self.daemon_schema_version {
+            None => SchemaVersionRequest::V1,
+            Some(DaemonSchemaVersion::Pinned(version)) if *version <= 1 => SchemaVersionRequest::V1,
+            Some(DaemonSchemaVersion::Pinned(version)) => SchemaVersionRequest::Negotiate {
+                cap: (*version).min(DAEMON_SCHEMA_VERSION_MAX),
+            },
+            Some(DaemonSchemaVersion::Keyword(keyword)) if keyword.eq_ignore_ascii_case("auto") => {
+                SchemaVersionRequest::Negotiate {
+                    cap: DAEMON_SCHEMA_VERSION_MAX,
+                }
+            }
+            Some(DaemonSchemaVersion::Keyword(_)) => SchemaVersionRequest::V1,
+        }
+    }
+
+    /// The MCP backend, when both halves are configured: the server command
+    /// line and the tool name. A daemon command resolved for a kind takes
+    /// precedence over this at dispatch.
+    pub(crate) fn mcp_backend(&self) -> Option<(&[String], &str)> {
+        let command = self
+            .mcp_server_command
+            .as_ref()
+            .filter(|command| !command.is_empty())?;
+        let tool = self.mcp_tool.as_deref()?;
+        Some((command.as_slice(), tool))
+    }
+
+    /// Get the effective timeout for `kind`: the per-kind override when
+    /// present, the top-level `timeout_ms` otherwise, then the default.
+    pub(crate) fn effective_timeout_ms_for(&self, kind: TranslationErrorKind) -> u64 {
+        let overridden = self
+            .kind_overrides(kind)
+            .and_then(|overrides| overrides.timeout_ms);
+        // Error messages deliberately ignore the top-level timeout: only an
+        // explicit `[error]` override can make them wait longer than their
+        // short default.
+        if kind == TranslationErrorKind::ErrorMessage {
+            return overridden.unwrap_or(DEFAULT_ERROR_TIMEOUT_MS);
+        }
+        overridden
+            // The timeout of the translator definition serving this kind,
+            // so a "fast" translator can carry its own short deadline.
+            .or_else(|| {
+                self.selected_translator_for(kind)
+                    .and_then(|def| def.timeout_ms)
+            })
+            .or(self.timeout_ms)
+            .unwrap_or(DEFAULT_TIMEOUT_MS)
+    }
+
+    /// Get the effective minimum text length for `kind`: the per-kind
+    /// override when present, the top-level `min_chars` otherwise, then the
+    /// per-kind default.
+    pub(crate) fn effective_min_chars_for(&self, kind: TranslationErrorKind) -> usize {
+        let default = match kind {
+            TranslationErrorKind::Reasoning => DEFAULT_REASONING_MIN_CHARS,
+            _ => 0,
+        };
+        self.kind_overrides(kind)
+            .and_then(|overrides| overrides.min_chars)
+            .or(self.min_chars)
+            .unwrap_or(default)
+    }
+
+    /// Whether `text` is long enough to be worth a translator round trip for
+    /// `kind`. Below the threshold callers show the original untranslated,
+    /// with no barrier and no history cell.
+    pub(crate) fn should_translate(&self, kind: TranslationErrorKind, text: &str) -> bool {
+        text.chars().count() >= self.effective_min_chars_for(kind)
+    }
+
+    /// Get the effective bilingual title template, resolving the
+    /// `"translated_only"` shortcut and falling back to the default for
+    /// invalid templates.
+    pub(crate) fn effective_title_template(&self) -> &str {
+        match self.title_template.as_deref() {
+            Some("translated_only") => "{translated}",
+            Some(template) if title_template_error(template).is_none() => template,
+            _ => DEFAULT_TITLE_TEMPLATE,
+        }
+    }
+
+    /// Get the effective same-language skip threshold, falling back to the
+    /// default for unset or out-of-range values.
+    pub(crate) fn effective_same_language_threshold(&self) -> f64 {
+        match self.same_language_threshold {
+            Some(threshold) if (0.0..=1.0).contains(&threshold) => threshold,
+            _ => DEFAULT_SAME_LANGUAGE_THRESHOLD,
+        }
+    }
+
+    /// Whether `kind` is translated at all: the per-kind `enabled` override
+    /// when present, `true` otherwise.
+    pub(crate) fn is_kind_enabled(&self, kind: TranslationErrorKind) -> bool {
+        self.kind_overrides(kind)
+            .and_then(|overrides| overrides.enabled)
+            .unwrap_or(true)
+    }
+
+    /// Whether translating `kind` is disabled for `model`. Patterns come
+    /// from the per-kind override when present, the top-level
+    /// `disabled_for_models` list otherwise, and support `*` globs.
+    pub(crate) fn is_translation_disabled_for_model(
+        &self,
+        kind: TranslationErrorKind,
+        model: &str,
+    ) -> bool {
+        let patterns = self
+            .kind_overrides(kind)
+            .and_then(|overrides| overrides.disabled_for_models.as_ref())
+            .or(self.disabled_for_models.as_ref());
+        patterns.is_some_and(|patterns| {
+            patterns
+                .iter()
+                .any(|pattern| model_glob_matches(pattern, model))
+        })
+    }
+
+    /// Get the effective number of consecutive failures that opens the
+    /// circuit breaker.
+    pub(crate) fn effective_failure_threshold(&self) -> u32 {
+        self.failure_threshold.unwrap_or(DEFAULT_FAILURE_THRESHOLD)
+    }
+
+    /// Get the effective cool-down before an open circuit breaker lets a
+    /// probe request through.
+    pub(crate) fn effective_circuit_reset_ms(&self) -> u64 {
+        self.circuit_reset_ms.unwrap_or(DEFAULT_CIRCUIT_RESET_MS)
+    }
+
+    /// Get the effective title-translation cache capacity. A configured
+    /// capacity of `0` falls back to the default rather than disabling
+    /// bilingual titles entirely.
+    pub fn effective_title_cache_capacity(&self) -> NonZeroUsize {
+        self.title_cache_capacity
+            .and_then(NonZeroUsize::new)
+            .unwrap_or(DEFAULT_TITLE_CACHE_CAPACITY)
+    }
+
     /// Check if API key is configured.
     #[allow(dead_code)]
     pub fn has_api_key(&self) -> bool {
@@ -186,33 +1119,53 @@ impl TranslationConfig {
 mod tests {
     use super::*;
 
-    #[test]
-    fn translation_config_should_translate() {
-        let config = TranslationConfig {
-            enabled: true,
-            target_language: "zh-CN".to_string(),
-            ..Default::default()
-        };
-
-        assert!(config.should_translate());
-
-        let disabled = TranslationConfig {
-            enabled: false,
-            ..config
-        };
-        assert!(!disabled.should_translate());
-    }
-
     #[test]
     fn translation_config_serialization() {
         let config = TranslationConfig {
             enabled: true,
             target_language: "ja".to_string(),
+            source_language: None,
             provider: "deepseek".to_string(),
             api_key: Some("sk-test123".to_string()),
             model: Some("deepseek-chat".to_string()),
             base_url: None,
             timeout_ms: Some(15000),
+            title_cache_capacity: None,
+            mask_code: false,
+            redact_secrets: false,
+            same_language_threshold: None,
+            min_chars: None,
+            max_input_chars: None,
+            max_requests_per_minute: None,
+            failure_threshold: None,
+            circuit_reset_ms: None,
+            disabled_for_models: None,
+            translate_ui_notices: true,
+            translate_plan_updates: false,
+            translate_errors: false,
+            show_translation_metadata: false,
+            daemon_command: None,
+            translators: None,
+            use_translator: None,
+            fallback_daemon_command: None,
+            mcp_server_command: None,
+            mcp_tool: None,
+            http_url: None,
+            http_headers: None,
+            glossary: None,
+            daemon_schema_version: None,
+            daemon_idle_timeout_ms: None,
+            streaming: false,
+            reasoning: None,
+            notice: None,
+            error: None,
+            debug_log: None,
+            log_full_text: false,
+            preview_chars: None,
+            turn_summary: false,
+            title_template: None,
+            header_overflow: HeaderOverflow::Translated,
+            startup_warnings: Vec::new(),
         };
 
         let toml_str = toml::to_string(&config).unwrap();
@@ -224,6 +1177,708 @@ mod tests {
         assert_eq!(parsed.api_key, config.api_key);
         assert_eq!(parsed.model, config.model);
         assert_eq!(parsed.timeout_ms, config.timeout_ms);
+        assert_eq!(parsed.mask_code, config.mask_code);
+        assert_eq!(parsed.translate_ui_notices, config.translate_ui_notices);
+    }
+
+    #[test]
+    fn translation_config_daemon_schema_version_resolves() {
+        let parsed: TranslationConfig = toml::from_str("enabled = true").unwrap();
+        assert_eq!(
+            parsed.daemon_schema_version_request(),
+            SchemaVersionRequest::V1
+        );
+
+        let parsed: TranslationConfig = toml::from_str("daemon_schema_version = 1").unwrap();
+        assert_eq!(
+            parsed.daemon_schema_version_request(),
+            SchemaVersionRequest::V1
+        );
+
+        let parsed: TranslationConfig = toml::from_str("daemon_schema_version = 2").unwrap();
+        assert_eq!(
+            parsed.daemon_schema_version_request(),
+            SchemaVersionRequest::Negotiate { cap: 2 }
+        );
+
+        // "auto" tracks the highest version this build supports; an unknown
+        // keyword degrades to plain version 1.
+        let parsed: TranslationConfig = toml::from_str("daemon_schema_version = \"auto\"").unwrap();
+        assert_eq!(
+            parsed.daemon_schema_version_request(),
+            SchemaVersionRequest::Negotiate {
+                cap: DAEMON_SCHEMA_VERSION_MAX
+            }
+        );
+        let parsed: TranslationConfig =
+            toml::from_str("daemon_schema_version = \"newest\"").unwrap();
+        assert_eq!(
+            parsed.daemon_schema_version_request(),
+            SchemaVersionRequest::V1
+        );
+    }
+
+    #[test]
+    fn translation_config_translate_ui_notices_defaults_off() {
+        let parsed: TranslationConfig = toml::from_str("enabled = true").unwrap();
+        assert!(!parsed.translate_ui_notices);
+    }
+
+    #[test]
+    fn translation_config_mask_code_defaults_on() {
+        let parsed: TranslationConfig = toml::from_str("enabled = true").unwrap();
+        assert!(parsed.mask_code);
+
+        let parsed: TranslationConfig = toml::from_str("mask_code = false").unwrap();
+        assert!(!parsed.mask_code);
+
+        // The alternative spelling is accepted too.
+        let parsed: TranslationConfig = toml::from_str("protect_code_blocks = false").unwrap();
+        assert!(!parsed.mask_code);
+    }
+
+    #[test]
+    fn translation_config_title_cache_capacity_defaults_and_clamps() {
+        let config = TranslationConfig::default();
+        assert_eq!(config.effective_title_cache_capacity().get(), 256);
+
+        let config = TranslationConfig {
+            title_cache_capacity: Some(16),
+            ..Default::default()
+        };
+        assert_eq!(config.effective_title_cache_capacity().get(), 16);
+
+        // Zero would make the cache unconstructible; fall back to the default.
+        let config = TranslationConfig {
+            title_cache_capacity: Some(0),
+            ..Default::default()
+        };
+        assert_eq!(config.effective_title_cache_capacity().get(), 256);
+    }
+
+    #[test]
+    fn translation_config_source_language_defaults_to_unset() {
+        let parsed: TranslationConfig = toml::from_str("enabled = true").unwrap();
+        assert_eq!(parsed.effective_source_language(), None);
+
+        let parsed: TranslationConfig = toml::from_str(r#"source_language = "ja-JP""#).unwrap();
+        assert_eq!(parsed.effective_source_language(), Some("ja-JP"));
+
+        // An empty string means "not configured", not an empty language code.
+        let parsed: TranslationConfig = toml::from_str(r#"source_language = """#).unwrap();
+        assert_eq!(parsed.effective_source_language(), None);
+
+        // The detection sentinel is carried on the wire in canonical form.
+        let parsed: TranslationConfig = toml::from_str(r#"source_language = "Auto""#).unwrap();
+        assert_eq!(parsed.effective_source_language(), Some("auto"));
+    }
+
+    #[test]
+    fn translation_config_kind_overrides_merge_precedence() {
+        let config: TranslationConfig = toml::from_str(
+            r#"
+daemon_command = ["slow-daemon"]
+timeout_ms = 10000
+
+[notice]
+daemon_command = ["fast-daemon"]
+timeout_ms = 2000
+"#,
+        )
+        .unwrap();
+
+        // No [reasoning] table: both settings fall back to the base values.
+        assert_eq!(
+            config.daemon_command_for(TranslationErrorKind::Reasoning),
+            Some(&["slow-daemon".to_string()][..])
+        );
+        assert_eq!(
+            config.effective_timeout_ms_for(TranslationErrorKind::Reasoning),
+            10000
+        );
+
+        // [notice] overrides both.
+        assert_eq!(
+            config.daemon_command_for(TranslationErrorKind::UiNotice),
+            Some(&["fast-daemon".to_string()][..])
+        );
+        assert_eq!(
+            config.effective_timeout_ms_for(TranslationErrorKind::UiNotice),
+            2000
+        );
+
+        // A partial override keeps the base value for the rest.
+        let config: TranslationConfig = toml::from_str(
+            r#"
+timeout_ms = 10000
+
+[reasoning]
+timeout_ms = 60000
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.daemon_command_for(TranslationErrorKind::Reasoning),
+            None
+        );
+        assert_eq!(
+            config.effective_timeout_ms_for(TranslationErrorKind::Reasoning),
+            60000
+        );
+        assert_eq!(
+            config.effective_timeout_ms_for(TranslationErrorKind::UiNotice),
+            10000
+        );
+
+        // Nothing configured anywhere: the built-in default applies.
+        let config = TranslationConfig::default();
+        assert_eq!(
+            config.effective_timeout_ms_for(TranslationErrorKind::Reasoning),
+            DEFAULT_TIMEOUT_MS
+        );
+    }
+
+    #[test]
+    fn translation_config_glossary_merges_per_kind_over_top_level() {
+        let config: TranslationConfig = toml::from_str(
+            r#"
+[glossary]
+agent = "智能体"
+thread = "线程"
+
+[notice]
+[notice.glossary]
+agent = "代理"
+"#,
+        )
+        .unwrap();
+
+        // No [reasoning.glossary]: the top-level table applies as-is.
+        let reasoning = config
+            .glossary_for(TranslationErrorKind::Reasoning)
+            .unwrap();
+        assert_eq!(reasoning.get("agent").map(String::as_str), Some("智能体"));
+        assert_eq!(reasoning.len(), 2);
+
+        // [notice.glossary] wins on "agent" but keeps "thread".
+        let notice = config.glossary_for(TranslationErrorKind::UiNotice).unwrap();
+        assert_eq!(notice.get("agent").map(String::as_str), Some("代理"));
+        assert_eq!(notice.get("thread").map(String::as_str), Some("线程"));
+
+        // No glossary anywhere: nothing is sent.
+        let config = TranslationConfig::default();
+        assert_eq!(config.glossary_for(TranslationErrorKind::Reasoning), None);
+    }
+
+    #[test]
+    fn translation_config_oversized_glossary_is_dropped_not_fatal() {
+        let entries: String = (0..=MAX_GLOSSARY_ENTRIES)
+            .map(|i| format!("term{i} = \"译{i}\"\n"))
+            .collect();
+        let config: TranslationConfig =
+            toml::from_str(&format!("enabled = true\n\n[glossary]\n{entries}")).unwrap();
+        let config = config.sanitized();
+
+        // The rest of the config survives; only the glossary is dropped.
+        assert!(config.enabled);
+        assert_eq!(config.glossary_for(TranslationErrorKind::Reasoning), None);
+
+        // A table exactly at the cap is kept.
+        let entries: String = (0..MAX_GLOSSARY_ENTRIES)
+            .map(|i| format!("term{i} = \"译{i}\"\n"))
+            .collect();
+        let config: TranslationConfig = toml::from_str(&format!("[glossary]\n{entries}")).unwrap();
+        let config = config.sanitized();
+        let glossary = config
+            .glossary_for(TranslationErrorKind::Reasoning)
+            .unwrap();
+        assert_eq!(glossary.len(), MAX_GLOSSARY_ENTRIES);
+    }
+
+    #[test]
+    fn translation_config_error_timeout_stays_short_unless_overridden() {
+        // The top-level timeout must not slow error display down.
+        let config: TranslationConfig = toml::from_str("timeout_ms = 30000").unwrap();
+        assert_eq!(
+            config.effective_timeout_ms_for(TranslationErrorKind::ErrorMessage),
+            DEFAULT_ERROR_TIMEOUT_MS
+        );
+
+        // Only an explicit [error] table can lengthen it.
+        let config: TranslationConfig = toml::from_str(
+            r#"
+timeout_ms = 30000
+
+[error]
+timeout_ms = 4000
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.effective_timeout_ms_for(TranslationErrorKind::ErrorMessage),
+            4000
+        );
+    }
+
+    #[test]
+    fn translation_config_title_template_resolves_and_validates() {
+        let config = TranslationConfig::default();
+        assert_eq!(config.effective_title_template(), DEFAULT_TITLE_TEMPLATE);
+
+        let config: TranslationConfig =
+            toml::from_str(r#"title_template = "{translated} ({original})""#).unwrap();
+        assert_eq!(
+            config.effective_title_template(),
+            "{translated} ({original})"
+        );
+
+        // The shortcut drops the original entirely.
+        let config: TranslationConfig =
+            toml::from_str(r#"title_template = "translated_only""#).unwrap();
+        assert_eq!(config.effective_title_template(), "{translated}");
+
+        // Invalid templates are dropped at load with a warning; resolution
+        // falls back to the default either way.
+        for (template, problem) in [
+            ("{original} - {translation}", "unknown placeholder"),
+            ("{original} only", "missing"),
+            ("broken {orig", "unclosed"),
+        ] {
+            let error = title_template_error(template).expect("invalid template");
+            assert!(error.contains(problem), "{template}: {error}");
+
+            let config: TranslationConfig =
+                toml::from_str(&format!("title_template = {template:?}")).unwrap();
+            let config = config.sanitized();
+            assert_eq!(config.title_template, None);
+            assert_eq!(config.effective_title_template(), DEFAULT_TITLE_TEMPLATE);
+        }
+    }
+
+    #[test]
+    fn translation_config_same_language_threshold_validates_range() {
+        let config = TranslationConfig::default();
+        assert_eq!(config.effective_same_language_threshold(), 0.5);
+
+        let config: TranslationConfig = toml::from_str("same_language_threshold = 0.8").unwrap();
+        assert_eq!(config.effective_same_language_threshold(), 0.8);
+
+        // Out-of-range values are dropped at load with a warning.
+        let config: TranslationConfig = toml::from_str("same_language_threshold = 1.5").unwrap();
+        let config = config.sanitized();
+        assert_eq!(config.same_language_threshold, None);
+        assert_eq!(config.effective_same_language_threshold(), 0.5);
+    }
+
+    #[test]
+    fn translation_config_rejects_a_zero_rate_limit() {
+        let config: TranslationConfig = toml::from_str("max_requests_per_minute = 30").unwrap();
+        assert_eq!(config.sanitized().max_requests_per_minute, Some(30));
+
+        // A zero budget would block every translation; drop it with a warning.
+        let config: TranslationConfig = toml::from_str("max_requests_per_minute = 0").unwrap();
+        assert_eq!(config.sanitized().max_requests_per_minute, None);
+    }
+
+    #[test]
+    fn translation_config_rejects_a_zero_idle_timeout() {
+        let config: TranslationConfig = toml::from_str("daemon_idle_timeout_ms = 60000").unwrap();
+        assert_eq!(config.sanitized().daemon_idle_timeout_ms, Some(60_000));
+
+        // A zero timeout would stop the daemon before it serves anything;
+        // drop it with a warning.
+        let config: TranslationConfig = toml::from_str("daemon_idle_timeout_ms = 0").unwrap();
+        assert_eq!(config.sanitized().daemon_idle_timeout_ms, None);
+    }
+
+    #[test]
+    fn translation_config_rejects_a_zero_preview_length() {
+        let config: TranslationConfig = toml::from_str("preview_chars = 40").unwrap();
+        assert_eq!(config.sanitized().preview_chars, Some(40));
+
+        // A zero-length preview would record nothing but size suffixes.
+        let config: TranslationConfig = toml::from_str("preview_chars = 0").unwrap();
+        assert_eq!(config.sanitized().preview_chars, None);
+    }
+
+    #[test]
+    fn translation_config_rejects_streaming_without_a_daemon() {
+        let config: TranslationConfig = toml::from_str(
+            r#"
+streaming = true
+daemon_command = ["translate.sh"]
+"#,
+        )
+        .unwrap();
+        assert!(config.sanitized().streaming);
+
+        // Only the daemon protocol has a chunked reply shape; the other
+        // backends ignore the flag, so drop it with a warning.
+        let config: TranslationConfig = toml::from_str("streaming = true").unwrap();
+        assert!(!config.sanitized().streaming);
+    }
+
+    #[test]
+    fn translation_config_resolves_the_mcp_backend() {
+        let config: TranslationConfig = toml::from_str(
+            r#"
+mcp_server_command = ["uvx", "translator-mcp"]
+mcp_tool = "translate"
+"#,
+        )
+        .unwrap();
+        let config = config.sanitized();
+        let (command, tool) = config.mcp_backend().expect("mcp backend");
+        assert_eq!(command, ["uvx", "translator-mcp"]);
+        assert_eq!(tool, "translate");
+
+        // Half a backend is no backend: both halves are cleared with a
+        // warning.
+        let config: TranslationConfig =
+            toml::from_str(r#"mcp_server_command = ["uvx", "translator-mcp"]"#).unwrap();
+        let config = config.sanitized();
+        assert_eq!(config.mcp_backend(), None);
+        assert_eq!(config.mcp_tool, None);
+    }
+
+    #[test]
+    fn translation_config_rejects_mcp_combined_with_a_command() {
+        let config: TranslationConfig = toml::from_str(
+            r#"
+daemon_command = ["translate.sh"]
+mcp_server_command = ["uvx", "translator-mcp"]
+mcp_tool = "translate"
+"#,
+        )
+        .unwrap();
+        // The backend choice is ambiguous; the daemon command wins and the
+        // MCP settings are dropped with a warning.
+        let config = config.sanitized();
+        assert_eq!(config.mcp_backend(), None);
+        assert_eq!(
+            config.daemon_command.as_deref(),
+            Some(["translate.sh".to_string()].as_slice())
+        );
+    }
+
+    #[test]
+    fn translation_config_rejects_http_combined_with_another_backend() {
+        let config: TranslationConfig = toml::from_str(
+            r#"
+daemon_command = ["translate.sh"]
+http_url = "http://localhost:8080/translate"
+
+[http_headers]
+Authorization = "env:TRANSLATOR_TOKEN"
+"#,
+        )
+        .unwrap();
+        // The daemon wins the ambiguous backend choice; the endpoint URL is
+        // dropped with a warning, though its headers survive an edit.
+        let config = config.sanitized();
+        assert_eq!(config.http_url, None);
+        assert_eq!(
+            config.daemon_command.as_deref(),
+            Some(["translate.sh".to_string()].as_slice())
+        );
+        assert_eq!(
+            config
+                .http_headers
+                .as_ref()
+                .and_then(|headers| headers.get("Authorization"))
+                .map(String::as_str),
+            Some("env:TRANSLATOR_TOKEN")
+        );
+    }
+
+    #[test]
+    fn sanitize_collects_its_warnings_on_the_config() {
+        let config: TranslationConfig = toml::from_str(
+            r#"
+streaming = true
+preview_chars = 0
+"#,
+        )
+        .unwrap();
+        let config = config.sanitized();
+        // Each rejected setting leaves a warning on the config so the UI can
+        // show it, instead of the problem living only in the tracing log.
+        assert_eq!(config.startup_warnings.len(), 2);
+        assert!(config.startup_warnings[0].contains("streaming"));
+        assert!(config.startup_warnings[1].contains("preview_chars"));
+        assert!(!config.streaming);
+        assert_eq!(config.preview_chars, None);
+    }
+
+    #[test]
+    fn deprecated_protect_code_blocks_warns_once_per_table() {
+        let warnings = deprecation_warnings(
+            r#"
+protect_code_blocks = false
+
+[reasoning]
+protect_code_blocks = true
+"#,
+        );
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[0].contains("rename it to `mask_code`"));
+        assert!(warnings[1].contains("[reasoning]"));
+
+        assert_eq!(
+            deprecation_warnings("mask_code = false\n"),
+            Vec::<String>::new()
+        );
+    }
+
+    #[test]
+    fn startup_warnings_survive_sanitize_ahead_of_its_own() {
+        let content = "protect_code_blocks = false\nstreaming = true\n";
+        let mut config: TranslationConfig = toml::from_str(content).unwrap();
+        // `load` records deprecation notices before sanitizing; sanitize must
+        // keep them in front of the warnings it adds itself.
+        config.startup_warnings = deprecation_warnings(content);
+        let config = config.sanitized();
+        assert_eq!(config.startup_warnings.len(), 2);
+        assert!(config.startup_warnings[0].contains("protect_code_blocks"));
+        assert!(config.startup_warnings[1].contains("streaming"));
+    }
+
+    #[test]
+    fn translation_config_disables_translation_per_model() {
+        let config: TranslationConfig =
+            toml::from_str(r#"disabled_for_models = ["gpt-5.1-codex-mini", "qwen*"]"#).unwrap();
+        let kind = TranslationErrorKind::Reasoning;
+        // Exact match covers the full slug, not a prefix.
+        assert!(config.is_translation_disabled_for_model(kind, "gpt-5.1-codex-mini"));
+        assert!(!config.is_translation_disabled_for_model(kind, "gpt-5.1-codex"));
+        // Glob match.
+        assert!(config.is_translation_disabled_for_model(kind, "qwen3-coder"));
+        assert!(!config.is_translation_disabled_for_model(kind, "gpt-5.1"));
+
+        // A per-kind list replaces the top-level one for that kind only.
+        let config: TranslationConfig = toml::from_str(
+            r#"
+disabled_for_models = ["*-mini"]
+
+[reasoning]
+disabled_for_models = ["deepseek-*"]
+"#,
+        )
+        .unwrap();
+        assert!(config.is_translation_disabled_for_model(kind, "deepseek-v3"));
+        assert!(!config.is_translation_disabled_for_model(kind, "gpt-5.1-codex-mini"));
+        assert!(config.is_translation_disabled_for_model(
+            TranslationErrorKind::UiNotice,
+            "gpt-5.1-codex-mini"
+        ));
+    }
+
+    #[test]
+    fn translation_config_kind_enabled_flag_defaults_to_true() {
+        let config = TranslationConfig::default();
+        assert!(config.is_kind_enabled(TranslationErrorKind::Reasoning));
+
+        let config: TranslationConfig = toml::from_str(
+            r#"
+[reasoning]
+enabled = false
+daemon_command = ["/usr/local/bin/translate", "--fast"]
+"#,
+        )
+        .unwrap();
+        // The kind is off, but its carefully quoted command stays in the
+        // table for when it is switched back on.
+        assert!(!config.is_kind_enabled(TranslationErrorKind::Reasoning));
+        assert!(
+            config
+                .daemon_command_for(TranslationErrorKind::Reasoning)
+                .is_some()
+        );
+        assert!(config.is_kind_enabled(TranslationErrorKind::UiNotice));
+    }
+
+    #[test]
+    fn translation_config_resolves_named_translators() {
+        let config: TranslationConfig = toml::from_str(
+            r#"
+use = "fast"
+
+[translators.fast]
+command = ["/opt/translators/fast.sh"]
+timeout_ms = 1500
+
+[translators.quality]
+command = ["/opt/translators/quality.sh", "--model", "large"]
+
+[reasoning]
+use = "quality"
+"#,
+        )
+        .unwrap()
+        .sanitized();
+
+        // The per-kind selection wins over the top-level one.
+        assert_eq!(
+            config.daemon_command_for(TranslationErrorKind::Reasoning),
+            Some(
+                [
+                    "/opt/translators/quality.sh".to_string(),
+                    "--model".into(),
+                    "large".into()
+                ]
+                .as_slice()
+            )
+        );
+        assert_eq!(
+            config.daemon_command_for(TranslationErrorKind::UiNotice),
+            Some(["/opt/translators/fast.sh".to_string()].as_slice())
+        );
+        // A selected translator carries its own timeout; kinds resolving to
+        // a definition without one fall back as usual.
+        assert_eq!(
+            config.effective_timeout_ms_for(TranslationErrorKind::UiNotice),
+            1500
+        );
+        assert_eq!(
+            config.effective_timeout_ms_for(TranslationErrorKind::Reasoning),
+            DEFAULT_TIMEOUT_MS
+        );
+
+        // An explicit command at the same scope shadows the reference.
+        let config: TranslationConfig = toml::from_str(
+            r#"
+use = "fast"
+daemon_command = ["/usr/local/bin/translate"]
+
+[translators.fast]
+command = ["/opt/translators/fast.sh"]
+"#,
+        )
+        .unwrap()
+        .sanitized();
+        assert_eq!(
+            config.daemon_command_for(TranslationErrorKind::Reasoning),
+            Some(["/usr/local/bin/translate".to_string()].as_slice())
+        );
+    }
+
+    #[test]
+    fn translation_config_clears_unknown_translator_names() {
+        let config: TranslationConfig = toml::from_str(
+            r#"
+use = "offline"
+
+[translators.fast]
+command = ["/opt/translators/fast.sh"]
+
+[reasoning]
+use = "quality"
+"#,
+        )
+        .unwrap()
+        .sanitized();
+
+        // Both bad references are dropped with a warning naming the
+        // available definitions; nothing resolves to a daemon.
+        assert_eq!(config.use_translator, None);
+        assert_eq!(config.reasoning.as_ref().unwrap().use_translator, None);
+        assert_eq!(
+            config.daemon_command_for(TranslationErrorKind::Reasoning),
+            None
+        );
+    }
+
+    #[test]
+    fn model_glob_matching_handles_inner_stars() {
+        assert!(model_glob_matches("gpt-*-mini", "gpt-5.1-codex-mini"));
+        assert!(!model_glob_matches("gpt-*-mini", "gpt-5.1-codex"));
+        assert!(model_glob_matches("*", "anything"));
+    }
+
+    #[test]
+    fn translation_config_min_chars_resolves_per_kind() {
+        let config = TranslationConfig::default();
+        assert_eq!(
+            config.effective_min_chars_for(TranslationErrorKind::Reasoning),
+            40
+        );
+        assert_eq!(
+            config.effective_min_chars_for(TranslationErrorKind::UiNotice),
+            0
+        );
+        assert!(config.should_translate(TranslationErrorKind::UiNotice, "ok"));
+        assert!(!config.should_translate(TranslationErrorKind::Reasoning, "too short"));
+
+        // The top-level setting covers every kind; a per-kind override wins.
+        let config: TranslationConfig = toml::from_str(
+            r#"
+min_chars = 10
+
+[reasoning]
+min_chars = 3
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.effective_min_chars_for(TranslationErrorKind::Reasoning),
+            3
+        );
+        assert_eq!(
+            config.effective_min_chars_for(TranslationErrorKind::UiNotice),
+            10
+        );
+        assert!(config.should_translate(TranslationErrorKind::Reasoning, "four"));
+    }
+
+    #[test]
+    fn translation_config_header_overflow_parses_all_policies() {
+        let parsed: TranslationConfig = toml::from_str("enabled = true").unwrap();
+        assert_eq!(parsed.header_overflow, HeaderOverflow::Translated);
+
+        let parsed: TranslationConfig = toml::from_str(r#"header_overflow = "original""#).unwrap();
+        assert_eq!(parsed.header_overflow, HeaderOverflow::Original);
+
+        let parsed: TranslationConfig = toml::from_str(r#"header_overflow = "truncate""#).unwrap();
+        assert_eq!(parsed.header_overflow, HeaderOverflow::Truncate);
+    }
+
+    #[test]
+    fn locale_parsing_maps_common_values_to_bcp47() {
+        assert_eq!(
+            parse_locale_to_bcp47("en_US.UTF-8"),
+            Some("en-US".to_string())
+        );
+        assert_eq!(
+            parse_locale_to_bcp47("zh_CN.UTF-8"),
+            Some("zh-CN".to_string())
+        );
+        assert_eq!(parse_locale_to_bcp47("ko_KR"), Some("ko-KR".to_string()));
+        assert_eq!(
+            parse_locale_to_bcp47("de_DE@euro"),
+            Some("de-DE".to_string())
+        );
+        assert_eq!(parse_locale_to_bcp47("ja"), Some("ja".to_string()));
+        assert_eq!(parse_locale_to_bcp47("C"), None);
+        assert_eq!(parse_locale_to_bcp47("C.UTF-8"), None);
+        assert_eq!(parse_locale_to_bcp47("POSIX"), None);
+        assert_eq!(parse_locale_to_bcp47(""), None);
+    }
+
+    #[test]
+    fn auto_target_language_resolves_to_detected_locale() {
+        let config = TranslationConfig {
+            target_language: "auto".to_string(),
+            ..Default::default()
+        };
+        // Detection depends on the test environment; "auto" must never leak
+        // through as the literal language code.
+        assert_ne!(config.effective_target_language(), "auto");
+
+        let explicit = TranslationConfig {
+            target_language: "ko-KR".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(explicit.effective_target_language(), "ko-KR");
     }
 
     #[test]
@@ -0,0 +1,1835 @@
+//! Long-running translator daemon with crash supervision.
+//!
+//! When `daemon_command` is configured, translation requests are sent to a
+//! persistent child process over newline-delimited JSON instead of one HTTP
+//! request per call. The supervisor tracks consecutive crashes, applies an
+//! escalating restart backoff (1s, 5s, 30s), and opens the circuit after
+//! repeated failures so a broken daemon (e.g. a bad model file) cannot burn
+//! CPU in an endless respawn loop. A configurable idle timeout
+//! (`daemon_idle_timeout_ms`) stops a daemon nobody is using; the next
+//! request respawns it on demand. Opt-in streaming (`streaming = true`)
+//! lets a daemon answer with NDJSON chunk lines so long translations
+//! surface piece by piece instead of after the whole reply is buffered.
+
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::process::Stdio;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+
+use codex_protocol::ThreadId;
+use serde::Deserialize;
+use serde::Serialize;
+use tokio::io::AsyncBufReadExt;
+use tokio::io::AsyncReadExt;
+use tokio::io::AsyncWriteExt;
+use tokio::io::BufReader;
+use tokio::process::Child;
+use tokio::process::ChildStdin;
+use tokio::process::ChildStdout;
+
+use super::error::TranslationError;
+use super::error_log::preview;
+
+/// Escalating delays between automatic restarts.
+const RESTART_BACKOFF: [Duration; 3] = [
+    Duration::from_secs(1),
+    Duration::from_secs(5),
+    Duration::from_secs(30),
+];
+
+/// Consecutive crashes tolerated before the circuit opens. Each tolerated
+/// crash consumes one slot of [`RESTART_BACKOFF`].
+const MAX_CONSECUTIVE_CRASHES: u32 = RESTART_BACKOFF.len() as u32;
+
+/// Trailing stderr lines retained for crash diagnostics.
+const STDERR_TAIL_LINES: usize = 5;
+
+/// Grapheme cap on the joined stderr preview. Deliberately generous: the one
+/// line of translator stderr that explains a crash is often near the end,
+/// and cutting it helps nobody.
+const STDERR_PREVIEW_GRAPHEMES: usize = 500;
+
+/// Upper bound on one daemon response line, shared with the MCP backend. A
+/// tool that dumps unbounded output to stdout (a stack trace, a progress
+/// bar) would otherwise grow the read buffer without limit. An overlong line
+/// leaves the stream mid-line, so it is handled like a crash: the child is
+/// killed and respawned.
+pub(super) const MAX_RESPONSE_BYTES: usize = 1024 * 1024;
+
+/// Highest daemon protocol version this build can speak. Version 2 adds the
+/// optional `detected_language` and version-stamped request lines.
+pub(crate) const DAEMON_SCHEMA_VERSION_MAX: u64 = 2;
+
+/// Protocol version to use with a daemon, resolved from the
+/// `daemon_schema_version` config setting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SchemaVersionRequest {
+    /// Speak version 1 without probing: request lines stay byte-identical to
+    /// those emitted before versioning existed.
+    V1,
+    /// Probe the daemon's capabilities once per child process and speak the
+    /// highest version both sides support, capped at `cap`.
+    Negotiate { cap: u64 },
+}
+
+/// Supervision state of the daemon process.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DaemonState {
+    /// Not started yet (or stopped manually).
+    Stopped,
+    /// Child process is alive.
+    Running,
+    /// Crashed; waiting out the restart backoff.
+    Backoff,
+    /// Too many consecutive crashes; respawning is suspended until a manual
+    /// `/translate restart`.
+    CircuitOpen,
+}
+
+impl DaemonState {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Stopped => "stopped",
+            Self::Running => "running",
+            Self::Backoff => "backoff",
+            Self::CircuitOpen => "circuit open",
+        }
+    }
+}
+
+/// Point-in-time daemon status for `/translate status`.
+#[derive(Debug, Clone)]
+pub(crate) struct DaemonStatus {
+    pub(crate) state: DaemonState,
+    /// When the current child was spawned, if running.
+    pub(crate) running_since: Option<Instant>,
+    /// Automatic restarts performed since the daemon was first started.
+    pub(crate) restarts: u64,
+    /// Exit code of the most recent crash, if any.
+    pub(crate) last_exit_code: Option<i32>,
+}
+
+/// Pure supervision state machine, kept separate from process I/O so the
+/// restart policy can be tested without spawning anything.
+#[derive(Debug)]
+struct DaemonSupervisor {
+    state: DaemonState,
+    running_since: Option<Instant>,
+    restarts: u64,
+    consecutive_crashes: u32,
+    last_exit_code: Option<i32>,
+}
+
+impl DaemonSupervisor {
+    fn new() -> Self {
+        Self {
+            state: DaemonState::Stopped,
+            running_since: None,
+            restarts: 0,
+            consecutive_crashes: 0,
+            last_exit_code: None,
+        }
+    }
+
+    /// Record a successful spawn. Counts as a restart unless this is the
+    /// first start after construction or a manual reset.
+    fn on_started(&mut self, is_restart: bool) {
+        self.state = DaemonState::Running;
+        self.running_since = Some(Instant::now());
+        if is_restart {
+            self.restarts = self.restarts.saturating_add(1);
+        }
+    }
+
+    /// Record a request served successfully: the daemon is healthy, so the
+    /// consecutive-crash counter starts over.
+    fn on_request_ok(&mut self) {
+        self.consecutive_crashes = 0;
+    }
+
+    /// Record a crash. Returns the delay to wait before the next automatic
+    /// respawn, or `None` when the circuit opens.
+    fn on_exit(&mut self, exit_code: Option<i32>) -> Option<Duration> {
+        self.running_since = None;
+        self.last_exit_code = exit_code;
+        self.consecutive_crashes = self.consecutive_crashes.saturating_add(1);
+        if self.consecutive_crashes > MAX_CONSECUTIVE_CRASHES {
+            self.state = DaemonState::CircuitOpen;
+            return None;
+        }
+        self.state = DaemonState::Backoff;
+        let index = (self.consecutive_crashes as usize - 1).min(RESTART_BACKOFF.len() - 1);
+        Some(RESTART_BACKOFF[index])
+    }
+
+    /// Idle shutdown: the child was stopped on purpose, not by a crash, so
+    /// the crash streak and the backoff state are left untouched.
+    fn on_idle_stop(&mut self) {
+        self.state = DaemonState::Stopped;
+        self.running_since = None;
+    }
+
+    /// Manual restart: forgive past crashes and close the circuit.
+    fn reset(&mut self) {
+        self.state = DaemonState::Stopped;
+        self.running_since = None;
+        self.consecutive_crashes = 0;
+        self.last_exit_code = None;
+    }
+
+    fn status(&self) -> DaemonStatus {
+        DaemonStatus {
+            state: self.state,
+            running_since: self.running_since,
+            restarts: self.restarts,
+            last_exit_code: self.last_exit_code,
+        }
+    }
+}
+
+/// Per-request inputs shared by every request shape, resolved from
+/// configuration by the orchestrator.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct TranslateOptions<'a> {
+    pub(crate) target_language: &'a str,
+    pub(crate) source_language: Option<&'a str>,
+    /// Term -> translation pairs the translator should honor.
+    pub(crate) glossary: Option<&'a HashMap<String, String>>,
+    /// Conversation coordinates, when the caller knows them.
+    pub(crate) context: TranslateContext,
+    /// Whether `text` was cut at `max_input_chars` before being handed over.
+    pub(crate) truncated: bool,
+    /// Slug of the model that produced the text, for translators that tune
+    /// their prompt per model; `None` when the caller does not know it.
+    pub(crate) model: Option<&'a str>,
+}
+
+/// Where a request sits in the conversation, for translators that keep
+/// per-thread context (e.g. consistent terminology within a thread). Both
+/// fields are best effort: one-shot callers such as the health probe leave
+/// them unset.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct TranslateContext {
+    /// Thread the translated text belongs to.
+    pub(crate) thread_id: Option<ThreadId>,
+    /// Zero-based index of the user turn within that thread.
+    pub(crate) turn_index: Option<u64>,
+}
+
+/// One request line sent to the daemon.
+#[derive(Debug, Serialize)]
+struct DaemonRequest<'a> {
+    id: u64,
+    text: &'a str,
+    target_language: &'a str,
+    /// Omitted entirely when no source language is configured, so daemons
+    /// written against the original request shape see an unchanged line.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source_language: Option<&'a str>,
+    /// Term -> translation pairs from the `[glossary]` config tables;
+    /// omitted when no glossary is configured.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    glossary: Option<&'a HashMap<String, String>>,
+    /// Conversation coordinates for translators that keep per-thread
+    /// context; omitted when unknown.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    thread_id: Option<ThreadId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    turn_index: Option<u64>,
+    /// Set when `text` was cut at `max_input_chars`; omitted otherwise so
+    /// untruncated request lines are unchanged.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    truncated: bool,
+    /// Set when `streaming = true` is configured, announcing that chunked
+    /// reply lines are accepted; omitted otherwise so non-streaming request
+    /// lines are unchanged.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    streaming: bool,
+    /// Slug of the model that produced the text; omitted when unknown so
+    /// strict translator parsers written against the original shape keep
+    /// working.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    model: Option<&'a str>,
+    /// Present only at negotiated version 2 and above, so version-1 lines
+    /// stay byte-identical.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    schema_version: Option<u64>,
+}
+
+/// One batch request line sent to the daemon: several texts translated in a
+/// single exchange, so one turn's worth of sections does not pay a process
+/// round trip per text.
+#[derive(Debug, Serialize)]
+struct DaemonBatchRequest<'a> {
+    id: u64,
+    texts: &'a [&'a str],
+    target_language: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source_language: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    glossary: Option<&'a HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    thread_id: Option<ThreadId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    turn_index: Option<u64>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    truncated: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    model: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    schema_version: Option<u64>,
+}
+
+/// Reply to the `{"kind":"capabilities"}` probe sent when schema negotiation
+/// is enabled.
+#[derive(Debug, Deserialize)]
+struct CapabilitiesResponse {
+    /// Highest protocol version the daemon supports; absent means 1.
+    #[serde(default)]
+    schema_version: Option<u64>,
+}
+
+/// One response line read back from the daemon. The schema is additive-only:
+/// unknown fields are ignored and new fields default when absent, so old
+/// daemons and old Codex builds interoperate in both directions.
+#[derive(Debug, Deserialize)]
+struct DaemonResponse {
+    id: u64,
+    #[serde(default)]
+    translated: Option<String>,
+    #[serde(default)]
+    error: Option<String>,
+    /// Source language the daemon detected, for `source_language = "auto"`.
+    #[serde(default)]
+    detected_language: Option<String>,
+    /// Batch replies carry one entry per requested text, in request order.
+    #[serde(default)]
+    texts: Option<Vec<String>>,
+    /// Protocol version the daemon answered at; absent means 1.
+    #[serde(default)]
+    schema_version: Option<u64>,
+    /// Free-form metadata about how the translation was produced (engine,
+    /// latency, daemon-side cache hits, ...). Keys are not interpreted here;
+    /// they are carried verbatim for display.
+    #[serde(default)]
+    metadata: Option<HashMap<String, String>>,
+}
+
+/// One NDJSON line read back in streaming mode: zero or more lines carrying
+/// a `chunk`, then a final `done: true` line that may also carry the trailing
+/// chunk, the detected language, and metadata. Daemons that ignore the
+/// request's `streaming` flag answer in the single-line shape instead, which
+/// parses here through the `translated` field.
+#[derive(Debug, Deserialize)]
+struct StreamChunk {
+    id: u64,
+    #[serde(default)]
+    chunk: Option<String>,
+    #[serde(default)]
+    done: bool,
+    #[serde(default)]
+    error: Option<String>,
+    /// Present when a non-streaming daemon answered the whole translation in
+    /// one line.
+    #[serde(default)]
+    translated: Option<String>,
+    #[serde(default)]
+    detected_language: Option<String>,
+    #[serde(default)]
+    schema_version: Option<u64>,
+    #[serde(default)]
+    metadata: Option<HashMap<String, String>>,
+}
+
+/// A completed translation: the translated text plus the source language the
+/// translator detected, when it reported one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct TranslatedText {
+    pub(crate) text: String,
+    pub(crate) detected_language: Option<String>,
+    /// Translator-reported metadata, verbatim; empty when none was reported.
+    pub(crate) metadata: HashMap<String, String>,
+}
+
+/// Strip a leading UTF-8 BOM and ANSI CSI/OSC escape sequences from
+/// translator output, returning the cleaned text and whether anything was
+/// removed. Some translator tools write a BOM or color codes around their
+/// JSON; without this, such lines surface as parse errors with an unhelpful
+/// preview.
+fn sanitize_daemon_output(raw: &str) -> (String, bool) {
+    let (text, bom_stripped) = match raw.strip_prefix('\u{feff}') {
+        Some(stripped) => (stripped, true),
+        None => (raw, false),
+    };
+    if !text.contains('\u{1b}') {
+        return (text.to_string(), bom_stripped);
+    }
+
+    let mut cleaned = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(ch) = chars.next() {
+        if ch != '\u{1b}' {
+            cleaned.push(ch);
+            continue;
+        }
+        match chars.peek() {
+            // CSI: parameter/intermediate bytes 0x20-0x3F, one final byte
+            // 0x40-0x7E.
+            Some('[') => {
+                chars.next();
+                for c in chars.by_ref() {
+                    if ('\u{40}'..='\u{7e}').contains(&c) {
+                        break;
+                    }
+                }
+            }
+            // OSC: terminated by BEL or the two-byte ST (ESC \).
+            Some(']') => {
+                chars.next();
+                while let Some(c) = chars.next() {
+                    if c == '\u{07}' {
+                        break;
+                    }
+                    if c == '\u{1b}' {
+                        if chars.peek() == Some(&'\\') {
+                            chars.next();
+                        }
+                        break;
+                    }
+                }
+            }
+            // A bare ESC is dropped; it can never be part of valid JSON.
+            _ => {}
+        }
+    }
+    (cleaned, true)
+}
+
+/// A supervised translator daemon process.
+#[derive(Debug)]
+pub(crate) struct TranslationDaemon {
+    command: Vec<String>,
+    child: Option<Child>,
+    stdin: Option<ChildStdin>,
+    stdout: Option<BufReader<ChildStdout>>,
+    next_request_id: u64,
+    ever_started: bool,
+    /// When the most recent request was handed to the child, for the idle
+    /// shutdown check.
+    last_used: Instant,
+    /// Earliest time an automatic respawn is allowed, while in backoff.
+    restart_not_before: Option<Instant>,
+    /// Last few stderr lines from the current child, fed by a reader task.
+    stderr_tail: Arc<Mutex<VecDeque<String>>>,
+    /// Whether we already warned about stripping a BOM / ANSI escapes from
+    /// this daemon's output (the warning is logged once per daemon).
+    warned_dirty_output: bool,
+    /// Protocol version policy from configuration.
+    schema_request: SchemaVersionRequest,
+    /// Version negotiated with the current child; `None` until the first
+    /// request after a spawn runs the capabilities probe.
+    negotiated_schema: Option<u64>,
+    supervisor: DaemonSupervisor,
+}
+
+impl TranslationDaemon {
+    pub(crate) fn new(command: Vec<String>) -> Self {
+        Self {
+            command,
+            child: None,
+            stdin: None,
+            stdout: None,
+            next_request_id: 0,
+            ever_started: false,
+            last_used: Instant::now(),
+            restart_not_before: None,
+            stderr_tail: Arc::new(Mutex::new(VecDeque::new())),
+            warned_dirty_output: false,
+            schema_request: SchemaVersionRequest::V1,
+            negotiated_schema: None,
+            supervisor: DaemonSupervisor::new(),
+        }
+    }
+
+    /// Set the protocol version policy (defaults to plain version 1).
+    pub(crate) fn with_schema_version(mut self, request: SchemaVersionRequest) -> Self {
+        self.schema_request = request;
+        self
+    }
+
+    pub(crate) fn status(&self) -> DaemonStatus {
+        self.supervisor.status()
+    }
+
+    /// Trailing stderr lines from the most recent child, joined on one line
+    /// and capped grapheme-safely, for error reporting. `None` when nothing
+    /// was written.
+    pub(crate) fn stderr_preview(&self) -> Option<String> {
+        let tail = self.stderr_tail.lock().ok()?;
+        if tail.is_empty() {
+            return None;
+        }
+        let joined = tail.iter().cloned().collect::<Vec<_>>().join(" | ");
+        Some(preview(&joined, STDERR_PREVIEW_GRAPHEMES))
+    }
+
+    /// Kill the current child (if any), forget past crashes, and spawn a
+    /// fresh one. Used by `/translate restart` and as the only way out of the
+    /// circuit-open state.
+    pub(crate) async fn restart(&mut self) -> Result<(), TranslationError> {
+        self.kill_child().await;
+        self.supervisor.reset();
+        self.restart_not_before = None;
+        self.spawn()
+    }
+
+    /// Stop the child once it has served no request for at least `idle`,
+    /// freeing its resources; the next request respawns it on demand. Not a
+    /// crash: no backoff is armed and the crash streak is untouched.
+    pub(crate) fn shutdown_if_idle(&mut self, idle: Duration) {
+        if self.supervisor.state != DaemonState::Running || self.last_used.elapsed() < idle {
+            return;
+        }
+        if let Some(mut child) = self.child.take() {
+            let _ = child.start_kill();
+        }
+        self.stdin = None;
+        self.stdout = None;
+        self.supervisor.on_idle_stop();
+        tracing::debug!(
+            idle_ms = %idle.as_millis(),
+            "translation daemon stopped after idle period"
+        );
+    }
+
+    /// Translate one text through the daemon.
+    pub(crate) async fn translate(
+        &mut self,
+        text: &str,
+        options: TranslateOptions<'_>,
+    ) -> Result<TranslatedText, TranslationError> {
+        self.last_used = Instant::now();
+        self.ensure_running()?;
+        let schema_version = self.negotiate_schema().await?;
+
+        let id = self.next_request_id;
+        self.next_request_id = self.next_request_id.wrapping_add(1);
+        let request = DaemonRequest {
+            id,
+            text,
+            target_language: options.target_language,
+            source_language: options.source_language,
+            glossary: options.glossary,
+            thread_id: options.context.thread_id,
+            turn_index: options.context.turn_index,
+            truncated: options.truncated,
+            streaming: false,
+            model: options.model,
+            schema_version: (schema_version > 1).then_some(schema_version),
+        };
+        let mut line =
+            serde_json::to_string(&request).map_err(|e| TranslationError::Parse(e.to_string()))?;
+        line.push('\n');
+
+        match self.exchange(&line).await {
+            Ok(response) => {
+                if response.id != id {
+                    self.handle_crash().await;
+                    return Err(TranslationError::Daemon(format!(
+                        "response id {} does not match request id {id}",
+                        response.id
+                    )));
+                }
+                Self::check_schema_version(schema_version, response.schema_version)?;
+                if let Some(error) = response.error {
+                    return Err(TranslationError::Daemon(error));
+                }
+                let Some(translated) = response.translated else {
+                    return Err(TranslationError::Daemon(
+                        "response has neither translated text nor error".to_string(),
+                    ));
+                };
+                // The translated text itself can carry the same junk as the
+                // response line when the tool colors its output per field.
+                let (translated, stripped) = sanitize_daemon_output(&translated);
+                if stripped {
+                    self.warn_dirty_output_once();
+                }
+                self.supervisor.on_request_ok();
+                Ok(TranslatedText {
+                    text: translated,
+                    detected_language: response.detected_language,
+                    metadata: response.metadata.unwrap_or_default(),
+                })
+            }
+            Err(e) => {
+                // A failed write or EOF means the child is gone.
+                self.handle_crash().await;
+                Err(e)
+            }
+        }
+    }
+
+    /// Translate one text in streaming mode: the request carries
+    /// `streaming: true` and the daemon answers with NDJSON chunk lines
+    /// (`{"id":N,"chunk":"...","done":false}`) closed by a final `done: true`
+    /// line, so long bodies surface paragraph by paragraph instead of after
+    /// the whole translation is buffered.
+    ///
+    /// `on_chunk` sees the accumulated translation so far (not the delta), so
+    /// callers render snapshots and a chain fallback can restart the stream
+    /// transparently. The accumulated text is capped at [`MAX_RESPONSE_BYTES`]
+    /// like a single response line. A stream that ends without `done: true`
+    /// violated the protocol and fails with [`TranslationError::Parse`];
+    /// daemons that ignore the `streaming` flag and answer in the single-line
+    /// shape are served transparently.
+    pub(crate) async fn translate_streaming(
+        &mut self,
+        text: &str,
+        options: TranslateOptions<'_>,
+        on_chunk: &mut (dyn FnMut(&str) + Send),
+    ) -> Result<TranslatedText, TranslationError> {
+        self.last_used = Instant::now();
+        self.ensure_running()?;
+        let schema_version = self.negotiate_schema().await?;
+
+        let id = self.next_request_id;
+        self.next_request_id = self.next_request_id.wrapping_add(1);
+        let request = DaemonRequest {
+            id,
+            text,
+            target_language: options.target_language,
+            source_language: options.source_language,
+            glossary: options.glossary,
+            thread_id: options.context.thread_id,
+            turn_index: options.context.turn_index,
+            truncated: options.truncated,
+            streaming: true,
+            model: options.model,
+            schema_version: (schema_version > 1).then_some(schema_version),
+        };
+        let mut line =
+            serde_json::to_string(&request).map_err(|e| TranslationError::Parse(e.to_string()))?;
+        line.push('\n');
+        if let Err(e) = self.write_line(&line).await {
+            self.handle_crash().await;
+            return Err(e);
+        }
+
+        let mut total = String::new();
+        let mut streamed = false;
+        loop {
+            let response_line = match self.read_response_line().await {
+                Ok(line) => line,
+                Err(e) => {
+                    // The stream is mid-response; the child cannot be reused.
+                    self.handle_crash().await;
+                    // EOF after chunks were streamed means the daemon never
+                    // sent its `done: true` line, which is a protocol
+                    // violation rather than an exit between requests.
+                    return Err(if streamed {
+                        TranslationError::Parse(
+                            "stream ended without a final done:true line".to_string(),
+                        )
+                    } else {
+                        e
+                    });
+                }
+            };
+            let parsed: StreamChunk = match serde_json::from_str(&response_line) {
+                Ok(parsed) => parsed,
+                Err(e) => {
+                    self.handle_crash().await;
+                    return Err(TranslationError::Parse(e.to_string()));
+                }
+            };
+            if parsed.id != id {
+                self.handle_crash().await;
+                return Err(TranslationError::Daemon(format!(
+                    "response id {} does not match request id {id}",
+                    parsed.id
+                )));
+            }
+            Self::check_schema_version(schema_version, parsed.schema_version)?;
+            if let Some(error) = parsed.error {
+                return Err(TranslationError::Daemon(error));
+            }
+            // A non-streaming daemon ignored the flag and answered the whole
+            // translation in one classic line; serve it as such.
+            if !streamed && !parsed.done && parsed.chunk.is_none() {
+                let Some(translated) = parsed.translated else {
+                    return Err(TranslationError::Daemon(
+                        "response has neither translated text nor error".to_string(),
+                    ));
+                };
+                let (translated, stripped) = sanitize_daemon_output(&translated);
+                if stripped {
+                    self.warn_dirty_output_once();
+                }
+                self.supervisor.on_request_ok();
+                return Ok(TranslatedText {
+                    text: translated,
+                    detected_language: parsed.detected_language,
+                    metadata: parsed.metadata.unwrap_or_default(),
+                });
+            }
+            if let Some(chunk) = parsed.chunk {
+                let (chunk, stripped) = sanitize_daemon_output(&chunk);
+                if stripped {
+                    self.warn_dirty_output_once();
+                }
+                if total.len() + chunk.len() > MAX_RESPONSE_BYTES {
+                    // Abandoning mid-stream leaves the pipe desynchronized;
+                    // treated like an oversized response line.
+                    self.handle_crash().await;
+                    return Err(TranslationError::Daemon(format!(
+                        "streamed response exceeds {MAX_RESPONSE_BYTES} bytes"
+                    )));
+                }
+                total.push_str(&chunk);
+                streamed = true;
+                on_chunk(&total);
+            }
+            if parsed.done {
+                if total.is_empty() {
+                    return Err(TranslationError::Daemon(
+                        "stream finished without any chunk text".to_string(),
+                    ));
+                }
+                self.supervisor.on_request_ok();
+                return Ok(TranslatedText {
+                    text: total,
+                    detected_language: parsed.detected_language,
+                    metadata: parsed.metadata.unwrap_or_default(),
+                });
+            }
+        }
+    }
+
+    /// Translate several texts in one daemon exchange.
+    ///
+    /// Batch-aware daemons reply with a `texts` array matching the request
+    /// length and order. Daemons that predate batching answer the line in the
+    /// single-text shape instead; those are served with one [`Self::translate`]
+    /// call per text, so batching stays a pure optimization. A batch reply
+    /// whose length does not match the request cannot be trusted and fails
+    /// with [`TranslationError::BatchLengthMismatch`] rather than misassigning
+    /// translations.
+    #[allow(dead_code)]
+    pub(crate) async fn translate_batch(
+        &mut self,
+        texts: &[&str],
+        options: TranslateOptions<'_>,
+    ) -> Result<Vec<TranslatedText>, TranslationError> {
+        if texts.is_empty() {
+            return Ok(Vec::new());
+        }
+        self.last_used = Instant::now();
+        self.ensure_running()?;
+        let schema_version = self.negotiate_schema().await?;
+
+        let id = self.next_request_id;
+        self.next_request_id = self.next_request_id.wrapping_add(1);
+        let request = DaemonBatchRequest {
+            id,
+            texts,
+            target_language: options.target_language,
+            source_language: options.source_language,
+            glossary: options.glossary,
+            thread_id: options.context.thread_id,
+            turn_index: options.context.turn_index,
+            truncated: options.truncated,
+            model: options.model,
+            schema_version: (schema_version > 1).then_some(schema_version),
+        };
+        let mut line =
+            serde_json::to_string(&request).map_err(|e| TranslationError::Parse(e.to_string()))?;
+        line.push('\n');
+
+        let response = match self.exchange(&line).await {
+            Ok(response) => response,
+            Err(e) => {
+                self.handle_crash().await;
+                return Err(e);
+            }
+        };
+        if response.id != id {
+            self.handle_crash().await;
+            return Err(TranslationError::Daemon(format!(
+                "response id {} does not match request id {id}",
+                response.id
+            )));
+        }
+        Self::check_schema_version(schema_version, response.schema_version)?;
+        if let Some(error) = response.error {
+            return Err(TranslationError::Daemon(error));
+        }
+        let Some(translated_texts) = response.texts else {
+            // A single-text reply means the daemon predates batching; its one
+            // translation cannot be mapped onto several inputs, so re-send the
+            // texts one request at a time.
+            if response.translated.is_none() {
+                return Err(TranslationError::Daemon(
+                    "response has neither texts nor translated text nor error".to_string(),
+                ));
+            }
+            let mut results = Vec::with_capacity(texts.len());
+            for text in texts {
+                results.push(self.translate(text, options).await?);
+            }
+            return Ok(results);
+        };
+        if translated_texts.len() != texts.len() {
+            return Err(TranslationError::BatchLengthMismatch {
+                expected: texts.len(),
+                got: translated_texts.len(),
+            });
+        }
+        self.supervisor.on_request_ok();
+        let metadata = response.metadata.unwrap_or_default();
+        let mut results = Vec::with_capacity(translated_texts.len());
+        for translated in translated_texts {
+            let (text, stripped) = sanitize_daemon_output(&translated);
+            if stripped {
+                self.warn_dirty_output_once();
+            }
+            results.push(TranslatedText {
+                text,
+                detected_language: response.detected_language.clone(),
+                metadata: metadata.clone(),
+            });
+        }
+        Ok(results)
+    }
+
+    /// The protocol version to speak with the current child, probing its
+    /// capabilities once per spawn when negotiation is enabled. A daemon
+    /// whose probe reply cannot be parsed is assumed to speak version 1; a
+    /// failed write or EOF means the child is gone and is handled like any
+    /// other crash.
+    async fn negotiate_schema(&mut self) -> Result<u64, TranslationError> {
+        if let Some(version) = self.negotiated_schema {
+            return Ok(version);
+        }
+        let version = match self.schema_request {
+            SchemaVersionRequest::V1 => 1,
+            SchemaVersionRequest::Negotiate { cap } => {
+                let reply = match self.exchange_line("{\"kind\":\"capabilities\"}\n").await {
+                    Ok(reply) => reply,
+                    Err(e) => {
+                        self.handle_crash().await;
+                        return Err(e);
+                    }
+                };
+                let theirs = serde_json::from_str::<CapabilitiesResponse>(&reply)
+                    .ok()
+                    .and_then(|capabilities| capabilities.schema_version)
+                    .unwrap_or(1);
+                theirs.min(cap).min(DAEMON_SCHEMA_VERSION_MAX).max(1)
+            }
+        };
+        self.negotiated_schema = Some(version);
+        Ok(version)
+    }
+
+    /// Fail when a response claims a newer protocol version than the request
+    /// was sent at; anything at or below what we sent is fine.
+    fn check_schema_version(sent: u64, received: Option<u64>) -> Result<(), TranslationError> {
+        match received {
+            Some(received) if received > sent => {
+                Err(TranslationError::SchemaVersionMismatch { sent, received })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Write one request line and read one response line.
+    async fn exchange(&mut self, line: &str) -> Result<DaemonResponse, TranslationError> {
+        let response_line = self.exchange_line(line).await?;
+        serde_json::from_str(&response_line).map_err(|e| TranslationError::Parse(e.to_string()))
+    }
+
+    /// Write one line and read one sanitized reply line.
+    async fn exchange_line(&mut self, line: &str) -> Result<String, TranslationError> {
+        self.write_line(line).await?;
+        self.read_response_line().await
+    }
+
+    /// Write one request line to the child's stdin.
+    async fn write_line(&mut self, line: &str) -> Result<(), TranslationError> {
+        let stdin = self
+            .stdin
+            .as_mut()
+            .ok_or_else(|| TranslationError::Daemon("daemon stdin closed".to_string()))?;
+        stdin
+            .write_all(line.as_bytes())
+            .await
+            .map_err(|e| TranslationError::Daemon(format!("write failed: {e}")))?;
+        stdin
+            .flush()
+            .await
+            .map_err(|e| TranslationError::Daemon(format!("flush failed: {e}")))
+    }
+
+    /// Read one sanitized reply line from the child's stdout.
+    async fn read_response_line(&mut self) -> Result<String, TranslationError> {
+        let stdout = self
+            .stdout
+            .as_mut()
+            .ok_or_else(|| TranslationError::Daemon("daemon stdout closed".to_string()))?;
+        let mut response_line = String::new();
+        let read = stdout
+            .take(MAX_RESPONSE_BYTES as u64 + 1)
+            .read_line(&mut response_line)
+            .await
+            .map_err(|e| TranslationError::Daemon(format!("read failed: {e}")))?;
+        if read == 0 {
+            return Err(TranslationError::Daemon("daemon exited".to_string()));
+        }
+        if read > MAX_RESPONSE_BYTES {
+            return Err(TranslationError::Daemon(format!(
+                "response line exceeds {MAX_RESPONSE_BYTES} bytes"
+            )));
+        }
+        let (response_line, stripped) = sanitize_daemon_output(&response_line);
+        if stripped {
+            self.warn_dirty_output_once();
+        }
+        Ok(response_line)
+    }
+
+    /// One-time warning naming the translator command so tool authors can
+    /// stop emitting a BOM or ANSI escapes in the first place.
+    fn warn_dirty_output_once(&mut self) {
+        if self.warned_dirty_output {
+            return;
+        }
+        self.warned_dirty_output = true;
+        tracing::warn!(
+            command = %self.command.join(" "),
+            "translator daemon output contained a BOM or ANSI escapes; \
+             stripped before parsing — please fix the tool"
+        );
+    }
+
+    /// Make sure a child is alive, honoring the circuit breaker and backoff.
+    fn ensure_running(&mut self) -> Result<(), TranslationError> {
+        match self.supervisor.state {
+            DaemonState::Running => Ok(()),
+            DaemonState::CircuitOpen => Err(TranslationError::Daemon(
+                "daemon disabled after repeated crashes; run /translate restart".to_string(),
+            )),
+            DaemonState::Backoff => {
+                if let Some(not_before) = self.restart_not_before
+                    && Instant::now() < not_before
+                {
+                    return Err(TranslationError::Daemon(
+                        "daemon restarting; request dropped during backoff".to_string(),
+                    ));
+                }
+                self.spawn()
+            }
+            DaemonState::Stopped => self.spawn(),
+        }
+    }
+
+    fn spawn(&mut self) -> Result<(), TranslationError> {
+        let Some((program, args)) = self.command.split_first() else {
+            return Err(TranslationError::InvalidConfig(
+                "daemon_command is empty".to_string(),
+            ));
+        };
+        let mut child = tokio::process::Command::new(program)
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| TranslationError::Daemon(format!("failed to spawn daemon: {e}")))?;
+        self.stdin = child.stdin.take();
+        self.stdout = child.stdout.take().map(BufReader::new);
+        if let Ok(mut tail) = self.stderr_tail.lock() {
+            tail.clear();
+        }
+        if let Some(stderr) = child.stderr.take() {
+            let tail = Arc::clone(&self.stderr_tail);
+            tokio::spawn(async move {
+                let mut lines = BufReader::new(stderr).lines();
+                while let Ok(Some(line)) = lines.next_line().await {
+                    if let Ok(mut tail) = tail.lock() {
+                        if tail.len() == STDERR_TAIL_LINES {
+                            tail.pop_front();
+                        }
+                        tail.push_back(line);
+                    }
+                }
+            });
+        }
+        self.child = Some(child);
+        self.restart_not_before = None;
+        // Each child negotiates for itself: a restart may pick up an updated
+        // translator that speaks a different version.
+        self.negotiated_schema = None;
+        let is_restart = self.ever_started;
+        self.ever_started = true;
+        self.supervisor.on_started(is_restart);
+        Ok(())
+    }
+
+    /// Reap the dead child and arm the backoff (or open the circuit).
+    async fn handle_crash(&mut self) {
+        let exit_code = match self.child.take() {
+            Some(mut child) => match child.try_wait() {
+                Ok(Some(status)) => status.code(),
+                _ => {
+                    let _ = child.kill().await;
+                    None
+                }
+            },
+            None => None,
+        };
+        self.stdin = None;
+        self.stdout = None;
+        match self.supervisor.on_exit(exit_code) {
+            Some(backoff) => {
+                self.restart_not_before = Some(Instant::now() + backoff);
+                tracing::warn!(
+                    exit_code = ?exit_code,
+                    backoff_ms = %backoff.as_millis(),
+                    "translation daemon crashed, restarting after backoff"
+                );
+            }
+            None => {
+                tracing::warn!(
+                    exit_code = ?exit_code,
+                    "translation daemon crashed too many times, circuit open"
+                );
+            }
+        }
+    }
+
+    async fn kill_child(&mut self) {
+        if let Some(mut child) = self.child.take() {
+            let _ = child.kill().await;
+        }
+        self.stdin = None;
+        self.stdout = None;
+    }
+}
+
+/// A primary translator daemon plus an optional fallback tried when the
+/// primary fails, so one flaky command does not take translation down. The
+/// chain remembers which member served the most recent success and tries it
+/// first; when every member fails, the error names each command with its
+/// failure so the chain can be debugged end to end.
+#[derive(Debug)]
+pub(crate) struct DaemonChain {
+    primary: TranslationDaemon,
+    fallback: Option<TranslationDaemon>,
+    /// Whether the fallback served the most recent success after the primary
+    /// failed; when set, later requests try the fallback first.
+    prefer_fallback: bool,
+}
+
+impl DaemonChain {
+    pub(crate) fn new(command: Vec<String>, fallback_command: Option<Vec<String>>) -> Self {
+        Self {
+            primary: TranslationDaemon::new(command),
+            fallback: fallback_command.map(TranslationDaemon::new),
+            prefer_fallback: false,
+        }
+    }
+
+    /// Set the protocol version policy for every member of the chain.
+    pub(crate) fn with_schema_version(mut self, request: SchemaVersionRequest) -> Self {
+        self.primary = self.primary.with_schema_version(request);
+        self.fallback = self
+            .fallback
+            .map(|daemon| daemon.with_schema_version(request));
+        self
+    }
+
+    /// Supervision status of the primary daemon (`/translate status`).
+    pub(crate) fn status(&self) -> DaemonStatus {
+        self.primary.status()
+    }
+
+    /// Stderr preview for error reporting: the primary's when it wrote
+    /// anything, the fallback's otherwise.
+    pub(crate) fn stderr_preview(&self) -> Option<String> {
+        self.primary
+            .stderr_preview()
+            .or_else(|| self.fallback.as_ref()?.stderr_preview())
+    }
+
+    /// Restart every member of the chain and prefer the primary again.
+    pub(crate) async fn restart(&mut self) -> Result<(), TranslationError> {
+        self.prefer_fallback = false;
+        let primary = self.primary.restart().await;
+        let fallback = match self.fallback.as_mut() {
+            Some(daemon) => daemon.restart().await,
+            None => Ok(()),
+        };
+        primary.and(fallback)
+    }
+
+    /// Apply the idle shutdown to every member of the chain.
+    pub(crate) fn shutdown_if_idle(&mut self, idle: Duration) {
+        self.primary.shutdown_if_idle(idle);
+        if let Some(fallback) = self.fallback.as_mut() {
+            fallback.shutdown_if_idle(idle);
+        }
+    }
+
+    /// Translate one text, walking the chain until a member succeeds.
+    pub(crate) async fn translate(
+        &mut self,
+        text: &str,
+        options: TranslateOptions<'_>,
+    ) -> Result<TranslatedText, TranslationError> {
+        let prefer_fallback = self.prefer_fallback;
+        let Some(fallback) = self.fallback.as_mut() else {
+            return self.primary.translate(text, options).await;
+        };
+        let (first, second) = if prefer_fallback {
+            (fallback, &mut self.primary)
+        } else {
+            (&mut self.primary, fallback)
+        };
+
+        let first_error = match first.translate(text, options).await {
+            Ok(translated) => return Ok(translated),
+            Err(e) => e,
+        };
+        let first_command = first.command.join(" ");
+        let second_command = second.command.join(" ");
+        match second.translate(text, options).await {
+            Ok(translated) => {
+                // The other member carried the request; try it first next
+                // time instead of failing over on every call.
+                self.prefer_fallback = !prefer_fallback;
+                Ok(translated)
+            }
+            Err(second_error) => Err(TranslationError::Daemon(format!(
+                "all translator commands failed: \
+                 [{first_command}] {first_error}; [{second_command}] {second_error}"
+            ))),
+        }
+    }
+
+    /// Streaming counterpart of [`Self::translate`]. `on_chunk` sees
+    /// accumulated snapshots, so a member that dies mid-stream is replaced
+    /// cleanly: the fallback's first snapshot overwrites whatever the failed
+    /// member managed to stream.
+    pub(crate) async fn translate_streaming(
+        &mut self,
+        text: &str,
+        options: TranslateOptions<'_>,
+        on_chunk: &mut (dyn FnMut(&str) + Send),
+    ) -> Result<TranslatedText, TranslationError> {
+        let prefer_fallback = self.prefer_fallback;
+        let Some(fallback) = self.fallback.as_mut() else {
+            return self
+                .primary
+                .translate_streaming(text, options, on_chunk)
+                .await;
+        };
+        let (first, second) = if prefer_fallback {
+            (fallback, &mut self.primary)
+        } else {
+            (&mut self.primary, fallback)
+        };
+
+        let first_error = match first.translate_streaming(text, options, on_chunk).await {
+            Ok(translated) => return Ok(translated),
+            Err(e) => e,
+        };
+        let first_command = first.command.join(" ");
+        let second_command = second.command.join(" ");
+        match second.translate_streaming(text, options, on_chunk).await {
+            Ok(translated) => {
+                self.prefer_fallback = !prefer_fallback;
+                Ok(translated)
+            }
+            Err(second_error) => Err(TranslationError::Daemon(format!(
+                "all translator commands failed: \
+                 [{first_command}] {first_error}; [{second_command}] {second_error}"
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Options used by most daemon tests: translate to Chinese with no source
+    /// language and no glossary.
+    fn zh() -> TranslateOptions<'static> {
+        TranslateOptions {
+            target_language: "zh-CN",
+            ..TranslateOptions::default()
+        }
+    }
+
+    #[test]
+    fn supervisor_backoff_escalates_then_opens_circuit() {
+        let mut supervisor = DaemonSupervisor::new();
+        supervisor.on_started(/*is_restart*/ false);
+
+        assert_eq!(supervisor.on_exit(Some(1)), Some(Duration::from_secs(1)));
+        assert_eq!(supervisor.on_exit(Some(1)), Some(Duration::from_secs(5)));
+        assert_eq!(supervisor.on_exit(Some(1)), Some(Duration::from_secs(30)));
+        assert_eq!(supervisor.on_exit(Some(1)), None);
+        assert_eq!(supervisor.status().state, DaemonState::CircuitOpen);
+        assert_eq!(supervisor.status().last_exit_code, Some(1));
+    }
+
+    #[test]
+    fn supervisor_successful_request_resets_crash_count() {
+        let mut supervisor = DaemonSupervisor::new();
+        supervisor.on_started(/*is_restart*/ false);
+        supervisor.on_exit(Some(1));
+        supervisor.on_started(/*is_restart*/ true);
+        supervisor.on_request_ok();
+
+        assert_eq!(supervisor.on_exit(Some(1)), Some(Duration::from_secs(1)));
+        assert_eq!(supervisor.status().restarts, 1);
+    }
+
+    #[test]
+    fn supervisor_manual_reset_closes_circuit() {
+        let mut supervisor = DaemonSupervisor::new();
+        supervisor.on_started(/*is_restart*/ false);
+        for _ in 0..4 {
+            supervisor.on_exit(Some(2));
+        }
+        assert_eq!(supervisor.status().state, DaemonState::CircuitOpen);
+
+        supervisor.reset();
+        assert_eq!(supervisor.status().state, DaemonState::Stopped);
+        assert_eq!(supervisor.status().last_exit_code, None);
+    }
+
+    #[test]
+    fn request_line_carries_configured_languages() {
+        let request = DaemonRequest {
+            id: 1,
+            text: "hello",
+            target_language: "ja-JP",
+            source_language: Some("en"),
+            glossary: None,
+            thread_id: None,
+            turn_index: None,
+            truncated: false,
+            streaming: false,
+            model: None,
+            schema_version: None,
+        };
+        let line = serde_json::to_string(&request).expect("serialize");
+        assert!(line.contains("\"target_language\":\"ja-JP\""));
+        assert!(line.contains("\"source_language\":\"en\""));
+
+        // Without a configured source language the field is absent, not null,
+        // so daemons written against the original shape keep working. The
+        // same holds for the schema version at version 1.
+        let request = DaemonRequest {
+            id: 2,
+            text: "hello",
+            target_language: "zh-CN",
+            source_language: None,
+            glossary: None,
+            thread_id: None,
+            turn_index: None,
+            truncated: false,
+            streaming: false,
+            model: None,
+            schema_version: None,
+        };
+        let line = serde_json::to_string(&request).expect("serialize");
+        assert!(!line.contains("source_language"));
+        assert!(!line.contains("glossary"));
+        assert!(!line.contains("thread_id"));
+        assert!(!line.contains("turn_index"));
+        assert!(!line.contains("truncated"));
+        assert!(!line.contains("streaming"));
+        assert!(!line.contains("model"));
+        assert!(!line.contains("schema_version"));
+    }
+
+    #[test]
+    fn request_line_carries_the_model_when_known() {
+        let request = DaemonRequest {
+            id: 7,
+            text: "hello",
+            target_language: "zh-CN",
+            source_language: None,
+            glossary: None,
+            thread_id: None,
+            turn_index: None,
+            truncated: false,
+            streaming: false,
+            model: Some("gpt-5.1-codex"),
+            schema_version: None,
+        };
+        let line = serde_json::to_string(&request).expect("serialize");
+        assert!(line.contains("\"model\":\"gpt-5.1-codex\""));
+    }
+
+    #[test]
+    fn request_line_carries_glossary_when_configured() {
+        let glossary = HashMap::from([("agent".to_string(), "智能体".to_string())]);
+        let request = DaemonRequest {
+            id: 5,
+            text: "the agent replied",
+            target_language: "zh-CN",
+            source_language: None,
+            glossary: Some(&glossary),
+            thread_id: None,
+            turn_index: None,
+            truncated: false,
+            streaming: false,
+            model: None,
+            schema_version: None,
+        };
+        let line = serde_json::to_string(&request).expect("serialize");
+        assert!(line.contains("\"glossary\":{\"agent\":\"智能体\"}"));
+    }
+
+    #[test]
+    fn request_line_carries_conversation_context_when_known() {
+        let thread_id =
+            ThreadId::from_string("00000000-0000-0000-0000-00000000abcd").expect("valid thread id");
+        let request = DaemonRequest {
+            id: 6,
+            text: "hello",
+            target_language: "zh-CN",
+            source_language: None,
+            glossary: None,
+            thread_id: Some(thread_id),
+            turn_index: Some(3),
+            truncated: false,
+            streaming: false,
+            model: None,
+            schema_version: None,
+        };
+        let line = serde_json::to_string(&request).expect("serialize");
+        assert!(line.contains("\"thread_id\":\"00000000-0000-0000-0000-00000000abcd\""));
+        assert!(line.contains("\"turn_index\":3"));
+    }
+
+    #[test]
+    fn batch_request_line_carries_texts_in_order() {
+        let request = DaemonBatchRequest {
+            id: 3,
+            texts: &["first", "second"],
+            target_language: "zh-CN",
+            source_language: None,
+            glossary: None,
+            thread_id: None,
+            turn_index: None,
+            truncated: false,
+            model: None,
+            schema_version: None,
+        };
+        let line = serde_json::to_string(&request).expect("serialize");
+        assert!(line.contains("\"texts\":[\"first\",\"second\"]"));
+        assert!(!line.contains("source_language"));
+    }
+
+    #[test]
+    fn response_line_without_detected_language_still_parses() {
+        // The original response shape, as emitted by daemons that predate
+        // language detection.
+        let response: DaemonResponse =
+            serde_json::from_str("{\"id\":1,\"translated\":\"好\"}").expect("parse");
+        assert_eq!(response.translated.as_deref(), Some("好"));
+        assert_eq!(response.detected_language, None);
+    }
+
+    #[test]
+    fn response_line_carries_detected_language_and_ignores_unknown_fields() {
+        let line = "{\"id\":2,\"translated\":\"好\",\"detected_language\":\"en\",\"extra\":true}";
+        let response: DaemonResponse = serde_json::from_str(line).expect("parse");
+        assert_eq!(response.translated.as_deref(), Some("好"));
+        assert_eq!(response.detected_language.as_deref(), Some("en"));
+    }
+
+    #[test]
+    fn response_line_metadata_is_optional_and_kept_verbatim() {
+        // Missing metadata parses exactly as before.
+        let response: DaemonResponse =
+            serde_json::from_str("{\"id\":1,\"translated\":\"好\"}").expect("parse");
+        assert_eq!(response.metadata, None);
+
+        // Keys are not interpreted: unknown ones survive untouched.
+        let line = "{\"id\":2,\"translated\":\"好\",\
+                    \"metadata\":{\"engine\":\"deepl\",\"x-custom\":\"312ms\"}}";
+        let response: DaemonResponse = serde_json::from_str(line).expect("parse");
+        let metadata = response.metadata.expect("metadata");
+        assert_eq!(metadata.get("engine").map(String::as_str), Some("deepl"));
+        assert_eq!(metadata.get("x-custom").map(String::as_str), Some("312ms"));
+    }
+
+    #[test]
+    fn sanitize_strips_bom_before_parsing() {
+        let (cleaned, stripped) =
+            sanitize_daemon_output("\u{feff}{\"id\":1,\"translated\":\"好\"}");
+        assert!(stripped);
+        let response: DaemonResponse = serde_json::from_str(&cleaned).expect("parse");
+        assert_eq!(response.id, 1);
+        assert_eq!(response.translated.as_deref(), Some("好"));
+    }
+
+    #[test]
+    fn sanitize_strips_csi_and_osc_escapes() {
+        let wrapped = "\u{1b}[32m{\"id\":2,\"translated\":\"好\"}\u{1b}[0m";
+        let (cleaned, stripped) = sanitize_daemon_output(wrapped);
+        assert!(stripped);
+        assert_eq!(cleaned, "{\"id\":2,\"translated\":\"好\"}");
+
+        let with_osc = "\u{1b}]0;title\u{07}{\"id\":3,\"error\":\"boom\"}";
+        let (cleaned, stripped) = sanitize_daemon_output(with_osc);
+        assert!(stripped);
+        let response: DaemonResponse = serde_json::from_str(&cleaned).expect("parse");
+        assert_eq!(response.error.as_deref(), Some("boom"));
+    }
+
+    #[test]
+    fn sanitize_handles_bom_and_escapes_together() {
+        let combined = "\u{feff}\u{1b}[1m{\"id\":4,\"translated\":\"好\"}\u{1b}[0m";
+        let (cleaned, stripped) = sanitize_daemon_output(combined);
+        assert!(stripped);
+        assert_eq!(cleaned, "{\"id\":4,\"translated\":\"好\"}");
+    }
+
+    #[test]
+    fn sanitize_leaves_clean_output_untouched() {
+        let clean = "{\"id\":5,\"translated\":\"好\"}";
+        assert_eq!(sanitize_daemon_output(clean), (clean.to_string(), false));
+    }
+
+    #[test]
+    fn sanitize_cannot_rescue_invalid_json() {
+        let (cleaned, stripped) = sanitize_daemon_output("\u{1b}[31mnot json\u{1b}[0m");
+        assert!(stripped);
+        assert!(serde_json::from_str::<DaemonResponse>(&cleaned).is_err());
+    }
+
+    /// Stub daemon: echoes the request id with a fixed translation, exiting
+    /// with code 7 after serving a configurable number of requests.
+    #[cfg(unix)]
+    fn stub_daemon_script(dir: &std::path::Path, serve: u32) -> std::path::PathBuf {
+        use std::os::unix::fs::PermissionsExt;
+        let path = dir.join("stub-daemon.sh");
+        let script = format!(
+            r#"#!/bin/sh
+served=0
+while read line; do
+  id=$(printf '%s' "$line" | sed 's/.*"id":\([0-9]*\).*/\1/')
+  printf '{{"id":%s,"translated":"译文"}}\n' "$id"
+  served=$((served + 1))
+  [ "$served" -ge {serve} ] && exit 7
+done
+exit 7
+"#
+        );
+        std::fs::write(&path, script).expect("write stub daemon");
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755))
+            .expect("chmod stub daemon");
+        path
+    }
+
+    /// Stub batch daemon: always replies with a fixed two-element `texts`
+    /// array, regardless of how many texts were requested.
+    #[cfg(unix)]
+    fn stub_batch_daemon_script(dir: &std::path::Path) -> std::path::PathBuf {
+        use std::os::unix::fs::PermissionsExt;
+        let path = dir.join("stub-batch-daemon.sh");
+        let script = r#"#!/bin/sh
+while read line; do
+  id=$(printf '%s' "$line" | sed 's/.*"id":\([0-9]*\).*/\1/')
+  printf '{"id":%s,"texts":["译一","译二"]}\n' "$id"
+done
+"#;
+        std::fs::write(&path, script).expect("write stub daemon");
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755))
+            .expect("chmod stub daemon");
+        path
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn batch_translates_in_one_exchange_and_rejects_length_mismatch() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let script = stub_batch_daemon_script(dir.path());
+        let mut daemon = TranslationDaemon::new(vec![script.to_string_lossy().into_owned()]);
+
+        let translated = daemon
+            .translate_batch(&["hello", "world"], zh())
+            .await
+            .expect("batch translate");
+        assert_eq!(translated.len(), 2);
+        assert_eq!(translated[0].text, "译一");
+        assert_eq!(translated[1].text, "译二");
+
+        // The stub always answers two texts, so a three-text batch must fail
+        // instead of pairing translations with the wrong inputs.
+        let error = daemon
+            .translate_batch(&["a", "b", "c"], zh())
+            .await
+            .expect_err("length mismatch");
+        assert!(matches!(
+            error,
+            TranslationError::BatchLengthMismatch {
+                expected: 3,
+                got: 2
+            }
+        ));
+        assert_eq!(daemon.status().state, DaemonState::Running);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn batch_falls_back_to_per_item_for_single_text_daemons() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let script = stub_daemon_script(dir.path(), /*serve*/ 4);
+        let mut daemon = TranslationDaemon::new(vec![script.to_string_lossy().into_owned()]);
+
+        // The stub answers the batch line in the single-text shape, which
+        // triggers one follow-up request per text.
+        let translated = daemon
+            .translate_batch(&["hello", "world"], zh())
+            .await
+            .expect("fallback translate");
+        assert_eq!(translated.len(), 2);
+        assert!(translated.iter().all(|t| t.text == "译文"));
+    }
+
+    /// Stub daemon speaking schema version 2: answers the capabilities probe
+    /// with `caps_version` and stamps every translation reply with
+    /// `reply_version`.
+    #[cfg(unix)]
+    fn stub_v2_daemon_script(
+        dir: &std::path::Path,
+        caps_version: u64,
+        reply_version: u64,
+    ) -> std::path::PathBuf {
+        use std::os::unix::fs::PermissionsExt;
+        let path = dir.join("stub-v2-daemon.sh");
+        let script = format!(
+            r#"#!/bin/sh
+read probe
+printf '{{"schema_version":{caps_version}}}\n'
+while read line; do
+  id=$(printf '%s' "$line" | sed 's/.*"id":\([0-9]*\).*/\1/')
+  printf '{{"id":%s,"translated":"译文","schema_version":{reply_version}}}\n' "$id"
+done
+"#
+        );
+        std::fs::write(&path, script).expect("write stub daemon");
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755))
+            .expect("chmod stub daemon");
+        path
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn schema_negotiation_probes_once_and_speaks_v2() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let script = stub_v2_daemon_script(dir.path(), /*caps*/ 2, /*reply*/ 2);
+        let mut daemon = TranslationDaemon::new(vec![script.to_string_lossy().into_owned()])
+            .with_schema_version(SchemaVersionRequest::Negotiate {
+                cap: DAEMON_SCHEMA_VERSION_MAX,
+            });
+
+        // The stub answers the probe exactly once; if a second translate
+        // re-probed, the ids would desynchronize and this would fail.
+        let first = daemon.translate("hello", zh()).await.expect("translate");
+        assert_eq!(first.text, "译文");
+        assert_eq!(daemon.negotiated_schema, Some(2));
+        let second = daemon.translate("world", zh()).await.expect("translate");
+        assert_eq!(second.text, "译文");
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn schema_negotiation_falls_back_to_v1_for_old_daemons() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        // This stub predates versioning: it garbles the probe reply, which
+        // negotiation reads as "speaks version 1".
+        let script = stub_daemon_script(dir.path(), /*serve*/ 10);
+        let mut daemon = TranslationDaemon::new(vec![script.to_string_lossy().into_owned()])
+            .with_schema_version(SchemaVersionRequest::Negotiate {
+                cap: DAEMON_SCHEMA_VERSION_MAX,
+            });
+
+        let translated = daemon.translate("hello", zh()).await.expect("translate");
+        assert_eq!(translated.text, "译文");
+        assert_eq!(daemon.negotiated_schema, Some(1));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn schema_mismatch_when_the_reply_version_exceeds_the_request() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        // Claims version 9 in the probe (negotiated down to 2), then stamps
+        // replies with version 9 anyway.
+        let script = stub_v2_daemon_script(dir.path(), /*caps*/ 9, /*reply*/ 9);
+        let mut daemon = TranslationDaemon::new(vec![script.to_string_lossy().into_owned()])
+            .with_schema_version(SchemaVersionRequest::Negotiate {
+                cap: DAEMON_SCHEMA_VERSION_MAX,
+            });
+
+        let error = daemon
+            .translate("hello", zh())
+            .await
+            .expect_err("version mismatch");
+        assert!(matches!(
+            error,
+            TranslationError::SchemaVersionMismatch {
+                sent: 2,
+                received: 9
+            }
+        ));
+    }
+
+    /// Stub daemon that exits immediately without serving anything.
+    #[cfg(unix)]
+    fn failing_daemon_script(dir: &std::path::Path, name: &str) -> std::path::PathBuf {
+        use std::os::unix::fs::PermissionsExt;
+        let path = dir.join(name);
+        std::fs::write(&path, "#!/bin/sh\nexit 3\n").expect("write stub daemon");
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755))
+            .expect("chmod stub daemon");
+        path
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn chain_falls_back_when_the_primary_fails_and_remembers_it() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let primary = failing_daemon_script(dir.path(), "broken.sh");
+        let fallback = stub_daemon_script(dir.path(), /*serve*/ 10);
+        let mut chain = DaemonChain::new(
+            vec![primary.to_string_lossy().into_owned()],
+            Some(vec![fallback.to_string_lossy().into_owned()]),
+        );
+
+        let translated = chain.translate("hello", zh()).await.expect("fallback");
+        assert_eq!(translated.text, "译文");
+        assert!(chain.prefer_fallback);
+
+        // The fallback is now tried first, so the dead primary (sitting in
+        // backoff) no longer costs a failed attempt per request.
+        let translated = chain.translate("world", zh()).await.expect("fallback");
+        assert_eq!(translated.text, "译文");
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn chain_aggregates_the_errors_when_every_member_fails() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let primary = failing_daemon_script(dir.path(), "broken-primary.sh");
+        let fallback = failing_daemon_script(dir.path(), "broken-fallback.sh");
+        let mut chain = DaemonChain::new(
+            vec![primary.to_string_lossy().into_owned()],
+            Some(vec![fallback.to_string_lossy().into_owned()]),
+        );
+
+        let error = chain
+            .translate("hello", zh())
+            .await
+            .expect_err("both members fail");
+        let message = error.to_string();
+        assert!(message.contains("all translator commands failed"));
+        assert!(message.contains("broken-primary.sh"));
+        assert!(message.contains("broken-fallback.sh"));
+        assert!(!chain.prefer_fallback);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn daemon_translates_then_reports_crash_and_restarts() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let script = stub_daemon_script(dir.path(), /*serve*/ 2);
+        let mut daemon = TranslationDaemon::new(vec![script.to_string_lossy().into_owned()]);
+
+        assert_eq!(daemon.translate("hello", zh()).await.unwrap().text, "译文");
+        assert_eq!(daemon.translate("world", zh()).await.unwrap().text, "译文");
+        assert_eq!(daemon.status().state, DaemonState::Running);
+
+        // Third request hits the exited child: the crash is recorded and the
+        // supervisor arms the backoff.
+        assert!(daemon.translate("again", zh()).await.is_err());
+        let status = daemon.status();
+        assert_eq!(status.state, DaemonState::Backoff);
+        assert_eq!(status.last_exit_code, Some(7));
+
+        // During backoff, requests are dropped without respawning.
+        assert!(daemon.translate("backoff", zh()).await.is_err());
+        assert_eq!(daemon.status().state, DaemonState::Backoff);
+
+        // A manual restart spawns a fresh child immediately.
+        daemon.restart().await.expect("restart");
+        assert_eq!(daemon.status().state, DaemonState::Running);
+        assert_eq!(daemon.translate("fresh", zh()).await.unwrap().text, "译文");
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn idle_daemon_is_stopped_and_respawns_on_demand() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let script = stub_daemon_script(dir.path(), /*serve*/ 10);
+        let mut daemon = TranslationDaemon::new(vec![script.to_string_lossy().into_owned()]);
+
+        assert_eq!(daemon.translate("hello", zh()).await.unwrap().text, "译文");
+        assert_eq!(daemon.status().state, DaemonState::Running);
+
+        // A daemon that has not been idle for the period yet is left alone.
+        daemon.shutdown_if_idle(Duration::from_secs(3600));
+        assert_eq!(daemon.status().state, DaemonState::Running);
+
+        // Past the idle period the child is stopped — not crashed, so no
+        // backoff is armed and no exit code is recorded.
+        daemon.shutdown_if_idle(Duration::ZERO);
+        let status = daemon.status();
+        assert_eq!(status.state, DaemonState::Stopped);
+        assert_eq!(status.last_exit_code, None);
+
+        // The next request respawns the child transparently.
+        assert_eq!(daemon.translate("again", zh()).await.unwrap().text, "译文");
+        assert_eq!(daemon.status().state, DaemonState::Running);
+    }
+
+    /// Stub daemon whose reply line blows well past [`MAX_RESPONSE_BYTES`].
+    #[cfg(unix)]
+    fn oversized_daemon_script(dir: &std::path::Path) -> std::path::PathBuf {
+        use std::os::unix::fs::PermissionsExt;
+        let path = dir.join("oversized-daemon.sh");
+        let script = r#"#!/bin/sh
+read line
+printf '{"id":0,"translated":"'
+head -c 1200000 /dev/zero | tr '\0' 'a'
+printf '"}\n'
+"#;
+        std::fs::write(&path, script).expect("write stub daemon");
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755))
+            .expect("chmod stub daemon");
+        path
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn oversized_response_line_is_rejected_and_counts_as_a_crash() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let script = oversized_daemon_script(dir.path());
+        let mut daemon = TranslationDaemon::new(vec![script.to_string_lossy().into_owned()]);
+
+        let error = daemon
+            .translate("hello", zh())
+            .await
+            .expect_err("oversized response");
+        assert!(matches!(error, TranslationError::Daemon(_)));
+        assert!(error.to_string().contains("exceeds"));
+        // The stream was abandoned mid-line, so the child is treated as
+        // crashed and the backoff is armed.
+        assert_eq!(daemon.status().state, DaemonState::Backoff);
+    }
+
+    /// Stub streaming daemon: answers a `streaming: true` request with two
+    /// chunk lines and a closing `done: true` line.
+    #[cfg(unix)]
+    fn streaming_daemon_script(dir: &std::path::Path) -> std::path::PathBuf {
+        use std::os::unix::fs::PermissionsExt;
+        let path = dir.join("streaming-daemon.sh");
+        let script = r#"#!/bin/sh
+while read line; do
+  id=$(printf '%s' "$line" | sed 's/.*"id":\([0-9]*\).*/\1/')
+  printf '{"id":%s,"chunk":"你","done":false}\n' "$id"
+  printf '{"id":%s,"chunk":"好","done":false}\n' "$id"
+  printf '{"id":%s,"done":true,"detected_language":"en"}\n' "$id"
+done
+"#;
+        std::fs::write(&path, script).expect("write stub daemon");
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755))
+            .expect("chmod stub daemon");
+        path
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn streaming_daemon_surfaces_snapshots_then_the_final_text() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let script = streaming_daemon_script(dir.path());
+        let mut daemon = TranslationDaemon::new(vec![script.to_string_lossy().into_owned()]);
+
+        let mut snapshots: Vec<String> = Vec::new();
+        let translated = daemon
+            .translate_streaming("hello", zh(), &mut |snapshot| {
+                snapshots.push(snapshot.to_string());
+            })
+            .await
+            .expect("translated");
+        // Snapshots accumulate; the final text equals the last snapshot.
+        assert_eq!(snapshots, vec!["你".to_string(), "你好".to_string()]);
+        assert_eq!(translated.text, "你好");
+        assert_eq!(translated.detected_language.as_deref(), Some("en"));
+        assert_eq!(daemon.status().state, DaemonState::Running);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn streaming_serves_single_line_daemons_transparently() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        // This daemon ignores the `streaming` flag and answers the classic
+        // single-line shape; the whole translation arrives at once.
+        let script = stub_daemon_script(dir.path(), 4);
+        let mut daemon = TranslationDaemon::new(vec![script.to_string_lossy().into_owned()]);
+
+        let mut snapshots: Vec<String> = Vec::new();
+        let translated = daemon
+            .translate_streaming("hello", zh(), &mut |snapshot| {
+                snapshots.push(snapshot.to_string());
+            })
+            .await
+            .expect("translated");
+        assert!(snapshots.is_empty());
+        assert_eq!(translated.text, "译文");
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn stream_without_a_final_done_line_is_a_protocol_error() {
+        use std::os::unix::fs::PermissionsExt;
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("truncated-stream.sh");
+        // Streams one chunk, then exits without ever sending `done: true`.
+        let script = r#"#!/bin/sh
+read line
+id=$(printf '%s' "$line" | sed 's/.*"id":\([0-9]*\).*/\1/')
+printf '{"id":%s,"chunk":"你","done":false}\n' "$id"
+exit 0
+"#;
+        std::fs::write(&path, script).expect("write stub daemon");
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755))
+            .expect("chmod stub daemon");
+        let mut daemon = TranslationDaemon::new(vec![path.to_string_lossy().into_owned()]);
+
+        let mut snapshots: Vec<String> = Vec::new();
+        let error = daemon
+            .translate_streaming("hello", zh(), &mut |snapshot| {
+                snapshots.push(snapshot.to_string());
+            })
+            .await
+            .expect_err("truncated stream");
+        assert!(matches!(error, TranslationError::Parse(_)));
+        assert!(error.to_string().contains("done:true"));
+        // The chunks that did arrive were surfaced before the failure.
+        assert_eq!(snapshots, vec!["你".to_string()]);
+        assert_eq!(daemon.status().state, DaemonState::Backoff);
+    }
+}
@@ -0,0 +1,311 @@
+//! Optional JSONL debug log of translator requests and responses.
+//!
+//! When a translation comes back wrong, the transcript only shows the final
+//! result. With `debug_log` set in `translation.toml`, every request appends
+//! one JSON record with request metadata, timing, and the response or error.
+//! Text fields are hashed and truncated unless `log_full_text = true`, so the
+//! log can be shared without leaking code. Logging is best effort: IO errors
+//! are logged and dropped, and never block or fail a translation.
+
+use std::hash::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use serde::Deserialize;
+use serde::Serialize;
+use tokio::io::AsyncWriteExt;
+
+use super::config::TranslationConfig;
+use super::error::TranslationError;
+use super::error_log::DEFAULT_PREVIEW_CHARS;
+use super::error_log::TranslationErrorKind;
+use super::error_log::preview;
+
+/// Rotate the log once it grows past this size; one previous file is kept.
+const MAX_LOG_BYTES: u64 = 10 * 1024 * 1024;
+
+/// One logged request/response pair.
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) struct DebugLogRecord {
+    /// When the record was written (RFC 3339, local time).
+    pub(super) at: String,
+    /// What kind of translation this was (`reasoning`, `notice`).
+    pub(super) kind: String,
+    pub(super) provider: String,
+    pub(super) target_language: String,
+    /// Hash of the request text, to correlate retries of the same content
+    /// without retaining the content itself.
+    pub(super) text_hash: String,
+    pub(super) text_chars: usize,
+    /// The request text: a truncated preview, or verbatim with
+    /// `log_full_text = true`.
+    pub(super) text: String,
+    /// Translator output on success, truncated like the request text.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(super) response: Option<String>,
+    /// Error display on failure.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(super) error: Option<String>,
+    pub(super) duration_ms: u64,
+}
+
+/// Appender for the translator debug log.
+#[derive(Debug, Clone)]
+pub(super) struct TranslationDebugLog {
+    path: PathBuf,
+    log_full_text: bool,
+    /// Preview length in grapheme clusters (`preview_chars`, default 120).
+    preview_chars: usize,
+}
+
+impl TranslationDebugLog {
+    /// Build from config; `None` when `debug_log` is unset.
+    pub(super) fn from_config(config: &TranslationConfig) -> Option<Self> {
+        Some(Self {
+            path: config.debug_log.clone()?,
+            log_full_text: config.log_full_text,
+            preview_chars: config.preview_chars.unwrap_or(DEFAULT_PREVIEW_CHARS),
+        })
+    }
+
+    /// Append one record for a finished request. Best effort: any failure is
+    /// logged and dropped.
+    pub(super) async fn record(
+        &self,
+        config: &TranslationConfig,
+        kind: TranslationErrorKind,
+        text: &str,
+        result: &Result<String, TranslationError>,
+        duration: Duration,
+    ) {
+        let (response, error) = match result {
+            Ok(translated) => (Some(self.loggable_text(translated)), None),
+            Err(e) => (None, Some(e.to_string())),
+        };
+        let mut hasher = DefaultHasher::new();
+        text.hash(&mut hasher);
+        let record = DebugLogRecord {
+            at: chrono::Local::now().to_rfc3339(),
+            kind: kind.as_str().to_string(),
+            provider: config.provider.clone(),
+            target_language: config.effective_target_language().to_string(),
+            text_hash: format!("{:016x}", hasher.finish()),
+            text_chars: text.chars().count(),
+            text: self.loggable_text(text),
+            response,
+            error,
+            duration_ms: duration.as_millis() as u64,
+        };
+        self.append(&record).await;
+    }
+
+    /// The full text with `log_full_text`, a grapheme-safe truncated preview
+    /// with the total original size otherwise.
+    fn loggable_text(&self, text: &str) -> String {
+        if self.log_full_text {
+            return text.to_string();
+        }
+        preview(text, self.preview_chars)
+    }
+
+    async fn append(&self, record: &DebugLogRecord) {
+        let json = match serde_json::to_string(record) {
+            Ok(json) => json,
+            Err(e) => {
+                tracing::warn!(error = %e, "failed to serialize translation debug-log record");
+                return;
+            }
+        };
+
+        if let Some(parent) = self.path.parent()
+            && let Err(e) = tokio::fs::create_dir_all(parent).await
+        {
+            tracing::warn!(error = %e, "failed to create translation debug-log directory");
+            return;
+        }
+        self.maybe_rotate().await;
+
+        let file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .await;
+        match file {
+            Ok(mut file) => {
+                if let Err(e) = file.write_all(format!("{json}\n").as_bytes()).await {
+                    tracing::warn!(error = %e, "failed to write translation debug log");
+                }
+            }
+            Err(e) => tracing::warn!(error = %e, "failed to open translation debug log"),
+        }
+    }
+
+    /// Move an oversized log aside (replacing any previous rotation) so the
+    /// active file stays bounded.
+    async fn maybe_rotate(&self) {
+        let Ok(metadata) = tokio::fs::metadata(&self.path).await else {
+            return;
+        };
+        if metadata.len() < MAX_LOG_BYTES {
+            return;
+        }
+        let mut rotated = self.path.as_os_str().to_owned();
+        rotated.push(".1");
+        if let Err(e) = tokio::fs::rename(&self.path, PathBuf::from(rotated)).await {
+            tracing::warn!(error = %e, "failed to rotate translation debug log");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config_with_log(path: PathBuf, log_full_text: bool) -> TranslationConfig {
+        TranslationConfig {
+            enabled: true,
+            debug_log: Some(path),
+            log_full_text,
+            ..Default::default()
+        }
+    }
+
+    fn read_records(path: &PathBuf) -> Vec<DebugLogRecord> {
+        std::fs::read_to_string(path)
+            .expect("debug log should exist")
+            .lines()
+            .map(|line| serde_json::from_str(line).expect("record should parse"))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn records_success_with_truncated_text_by_default() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("debug.jsonl");
+        let config = config_with_log(path.clone(), false);
+        let log = TranslationDebugLog::from_config(&config).expect("logger");
+
+        let text = "secret ".repeat(40);
+        let result = Ok("translated ".repeat(40));
+        log.record(
+            &config,
+            TranslationErrorKind::Reasoning,
+            &text,
+            &result,
+            Duration::from_millis(250),
+        )
+        .await;
+
+        let records = read_records(&path);
+        assert_eq!(records.len(), 1);
+        let record = &records[0];
+        assert_eq!(record.kind, "reasoning");
+        assert_eq!(record.text_chars, text.chars().count());
+        assert_eq!(record.duration_ms, 250);
+        assert!(record.error.is_none());
+        // Truncated previews only: the full texts must be absent, and each
+        // preview reports the total original size.
+        assert_ne!(record.text, text);
+        assert!(record.text.ends_with("… (280 B total)"), "{}", record.text);
+        let response = record.response.as_deref().expect("response");
+        assert!(response.ends_with("B total)"), "{response}");
+    }
+
+    #[tokio::test]
+    async fn preview_chars_overrides_the_default_preview_length() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("debug.jsonl");
+        let config = TranslationConfig {
+            preview_chars: Some(6),
+            ..config_with_log(path.clone(), false)
+        };
+        let log = TranslationDebugLog::from_config(&config).expect("logger");
+
+        log.record(
+            &config,
+            TranslationErrorKind::Reasoning,
+            "secret secret secret",
+            &Ok("done".to_string()),
+            Duration::ZERO,
+        )
+        .await;
+
+        let records = read_records(&path);
+        assert_eq!(records[0].text, "secret… (20 B total)");
+        // Short texts still pass through untouched.
+        assert_eq!(records[0].response.as_deref(), Some("done"));
+    }
+
+    #[tokio::test]
+    async fn records_failure_with_error_and_no_response() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("debug.jsonl");
+        let config = config_with_log(path.clone(), false);
+        let log = TranslationDebugLog::from_config(&config).expect("logger");
+
+        let result = Err(TranslationError::Parse("bad json".to_string()));
+        log.record(
+            &config,
+            TranslationErrorKind::UiNotice,
+            "notice text",
+            &result,
+            Duration::from_millis(10),
+        )
+        .await;
+
+        let records = read_records(&path);
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].kind, "notice");
+        assert!(records[0].response.is_none());
+        assert_eq!(records[0].error.as_deref(), Some("Parse error: bad json"));
+    }
+
+    #[tokio::test]
+    async fn log_full_text_records_text_verbatim() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("debug.jsonl");
+        let config = config_with_log(path.clone(), true);
+        let log = TranslationDebugLog::from_config(&config).expect("logger");
+
+        let text = "secret ".repeat(40);
+        log.record(
+            &config,
+            TranslationErrorKind::Reasoning,
+            &text,
+            &Ok("done".to_string()),
+            Duration::ZERO,
+        )
+        .await;
+
+        let records = read_records(&path);
+        assert_eq!(records[0].text, text);
+        assert_eq!(records[0].response.as_deref(), Some("done"));
+    }
+
+    #[tokio::test]
+    async fn oversized_log_rotates_before_appending() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("debug.jsonl");
+        let config = config_with_log(path.clone(), false);
+        let log = TranslationDebugLog::from_config(&config).expect("logger");
+
+        // A sparse file at the cap stands in for an organically grown log.
+        let file = std::fs::File::create(&path).expect("create");
+        file.set_len(MAX_LOG_BYTES).expect("set_len");
+        drop(file);
+
+        log.record(
+            &config,
+            TranslationErrorKind::Reasoning,
+            "text",
+            &Ok("done".to_string()),
+            Duration::ZERO,
+        )
+        .await;
+
+        assert!(dir.path().join("debug.jsonl.1").exists());
+        assert_eq!(read_records(&path).len(), 1);
+    }
+}
@@ -0,0 +1,247 @@
+//! HTTP endpoint translation backend.
+//!
+//! `http_url` points translation at a microservice: each request POSTs the
+//! daemon request JSON (without the `id` — HTTP pairs requests and responses
+//! itself) and expects the daemon response JSON back, so a local translation
+//! service needs no wrapper script that just curls it. Header values of the
+//! form `env:NAME` are resolved from the environment at request time; the
+//! resolved values are never logged.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::daemon::MAX_RESPONSE_BYTES;
+use super::daemon::TranslateOptions;
+use super::daemon::TranslatedText;
+use super::error::TranslationError;
+
+/// One request POSTed to the endpoint: the daemon request line minus the
+/// `id`, with the same omit-when-absent rules so simple services only ever
+/// see the fields they care about.
+#[derive(Debug, Serialize)]
+struct EndpointRequest<'a> {
+    text: &'a str,
+    target_language: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source_language: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    glossary: Option<&'a HashMap<String, String>>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    truncated: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    model: Option<&'a str>,
+}
+
+/// The endpoint's reply, in the daemon response shape minus the `id`.
+#[derive(Debug, Deserialize)]
+struct EndpointResponse {
+    #[serde(default)]
+    translated: Option<String>,
+    #[serde(default)]
+    error: Option<String>,
+    #[serde(default)]
+    detected_language: Option<String>,
+    #[serde(default)]
+    metadata: Option<HashMap<String, String>>,
+}
+
+/// Resolve one configured header value, reading `env:NAME` references from
+/// the environment so tokens never sit verbatim in the config file. `None`
+/// when the referenced variable is unset.
+fn resolve_header_value(value: &str) -> Option<String> {
+    match value.strip_prefix("env:") {
+        Some(name) => std::env::var(name).ok(),
+        None => Some(value.to_string()),
+    }
+}
+
+/// Translate one text through the configured HTTP endpoint.
+pub(super) async fn translate_via_endpoint(
+    url: &str,
+    headers: Option<&HashMap<String, String>>,
+    timeout: Duration,
+    text: &str,
+    options: TranslateOptions<'_>,
+) -> Result<TranslatedText, TranslationError> {
+    let request = EndpointRequest {
+        text,
+        target_language: options.target_language,
+        source_language: options.source_language,
+        glossary: options.glossary,
+        truncated: options.truncated,
+        model: options.model,
+    };
+    let client = reqwest::Client::builder()
+        .timeout(timeout)
+        .build()
+        .map_err(TranslationError::Network)?;
+    let mut req = client.post(url).json(&request);
+    if let Some(headers) = headers {
+        for (name, value) in headers {
+            let Some(value) = resolve_header_value(value) else {
+                return Err(TranslationError::InvalidConfig(format!(
+                    "http_headers value for {name} references an unset environment variable"
+                )));
+            };
+            req = req.header(name, value);
+        }
+    }
+    let mut response = req.send().await?;
+    let status = response.status().as_u16();
+    if !response.status().is_success() {
+        let message = read_capped_body(&mut response).await.unwrap_or_default();
+        return Err(TranslationError::Api { status, message });
+    }
+    let body = read_capped_body(&mut response).await?;
+    let parsed: EndpointResponse =
+        serde_json::from_str(&body).map_err(|e| TranslationError::Parse(e.to_string()))?;
+    if let Some(error) = parsed.error {
+        return Err(TranslationError::Api {
+            status,
+            message: error,
+        });
+    }
+    let Some(translated) = parsed.translated else {
+        return Err(TranslationError::Parse(
+            "response has neither translated text nor error".to_string(),
+        ));
+    };
+    Ok(TranslatedText {
+        text: translated,
+        detected_language: parsed.detected_language,
+        metadata: parsed.metadata.unwrap_or_default(),
+    })
+}
+
+/// Read the response body, failing once it exceeds the shared response size
+/// cap instead of buffering an unbounded reply.
+async fn read_capped_body(response: &mut reqwest::Response) -> Result<String, TranslationError> {
+    let mut body: Vec<u8> = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        if body.len() + chunk.len() > MAX_RESPONSE_BYTES {
+            return Err(TranslationError::Parse(format!(
+                "response body exceeds {MAX_RESPONSE_BYTES} bytes"
+            )));
+        }
+        body.extend_from_slice(&chunk);
+    }
+    String::from_utf8(body).map_err(|e| TranslationError::Parse(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::Mock;
+    use wiremock::MockServer;
+    use wiremock::ResponseTemplate;
+    use wiremock::matchers::body_partial_json;
+    use wiremock::matchers::method;
+    use wiremock::matchers::path;
+
+    fn zh() -> TranslateOptions<'static> {
+        TranslateOptions {
+            target_language: "zh-CN",
+            ..TranslateOptions::default()
+        }
+    }
+
+    async fn call(
+        server: &MockServer,
+        timeout: Duration,
+    ) -> Result<TranslatedText, TranslationError> {
+        translate_via_endpoint(
+            &format!("{}/translate", server.uri()),
+            None,
+            timeout,
+            "hello",
+            zh(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn endpoint_round_trips_the_daemon_shapes() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/translate"))
+            .and(body_partial_json(serde_json::json!({
+                "text": "hello",
+                "target_language": "zh-CN",
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "translated": "译文",
+                "detected_language": "en",
+            })))
+            .mount(&server)
+            .await;
+
+        let translated = call(&server, Duration::from_secs(5))
+            .await
+            .expect("translate");
+        assert_eq!(translated.text, "译文");
+        assert_eq!(translated.detected_language.as_deref(), Some("en"));
+    }
+
+    #[tokio::test]
+    async fn endpoint_maps_server_errors_onto_the_api_variant() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(500).set_body_string("boom"))
+            .mount(&server)
+            .await;
+
+        let error = call(&server, Duration::from_secs(5))
+            .await
+            .expect_err("500");
+        assert!(matches!(
+            error,
+            TranslationError::Api { status: 500, ref message } if message == "boom"
+        ));
+    }
+
+    #[tokio::test]
+    async fn endpoint_times_out_like_every_other_backend() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_secs(30)))
+            .mount(&server)
+            .await;
+
+        let error = call(&server, Duration::from_millis(200))
+            .await
+            .expect_err("timeout");
+        assert!(matches!(error, TranslationError::Timeout));
+    }
+
+    #[tokio::test]
+    async fn endpoint_rejects_a_body_that_is_not_json() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("not json"))
+            .mount(&server)
+            .await;
+
+        let error = call(&server, Duration::from_secs(5))
+            .await
+            .expect_err("bad json");
+        assert!(matches!(error, TranslationError::Parse(_)));
+    }
+
+    #[test]
+    fn header_values_resolve_env_references_without_logging_them() {
+        // Plain values pass through untouched.
+        assert_eq!(
+            resolve_header_value("Bearer token").as_deref(),
+            Some("Bearer token")
+        );
+        // An unset variable resolves to nothing rather than the reference
+        // text, so a typo cannot silently send "env:..." to the service.
+        assert_eq!(
+            resolve_header_value("env:CODEX_TRANSLATION_TEST_UNSET_VAR"),
+            None
+        );
+    }
+}
@@ -2,6 +2,9 @@
 
 use std::fmt;
 
+use serde::Deserialize;
+use serde::Serialize;
+
 /// Translation error.
 #[derive(Debug)]
 pub enum TranslationError {
@@ -20,6 +23,16 @@ pub enum TranslationError {
     /// Translation request timed out.
     Timeout,
 
+    /// The process-wide `max_requests_per_minute` budget is exhausted and
+    /// the wait for the next slot would exceed the caller's budget. The
+    /// orchestrator skips the block silently instead of showing an error.
+    RateLimited { retry_after_ms: u64 },
+
+    /// The circuit breaker is open after repeated consecutive failures;
+    /// nothing was spawned. The orchestrator shows at most one notice per
+    /// session for this instead of an error cell per block.
+    CircuitOpen,
+
     /// Provider not supported.
     #[allow(dead_code)]
     UnsupportedProvider(String),
@@ -27,24 +40,176 @@ pub enum TranslationError {
     /// Invalid configuration.
     #[allow(dead_code)]
     InvalidConfig(String),
+
+    /// Translator daemon failure (crash, protocol error, or circuit open).
+    Daemon(String),
+
+    /// The daemon answered with a protocol version newer than the one the
+    /// request was sent at.
+    SchemaVersionMismatch { sent: u64, received: u64 },
+
+    /// A batch response carried a different number of texts than the request,
+    /// so translations cannot be matched back to their inputs.
+    #[allow(dead_code)]
+    BatchLengthMismatch { expected: usize, got: usize },
+}
+
+impl TranslationError {
+    /// The serializable snapshot of this error that crosses the orchestrator
+    /// channel.
+    pub(crate) fn failure(&self) -> TranslationFailure {
+        self.into()
+    }
 }
 
 impl fmt::Display for TranslationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Delegate so the log message and the UI snapshot cannot drift apart.
+        self.failure().fmt(f)
+    }
+}
+
+/// Serializable snapshot of a [`TranslationError`].
+///
+/// The error enum itself cannot derive serde because [`reqwest::Error`] is a
+/// live handle, so results crossing the orchestrator channel carry this form
+/// instead: a stable `kind` discriminant plus the structured fields UI
+/// surfaces need to pick tailored advice. Display output is identical to the
+/// original error's.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub(crate) enum TranslationFailure {
+    ApiKeyNotFound { provider: String },
+    Network { message: String },
+    Api { status: u16, message: String },
+    Parse { message: String },
+    Timeout,
+    RateLimited { retry_after_ms: u64 },
+    CircuitOpen,
+    UnsupportedProvider { provider: String },
+    InvalidConfig { message: String },
+    Daemon { message: String },
+    SchemaVersionMismatch { sent: u64, received: u64 },
+    BatchLengthMismatch { expected: usize, got: usize },
+}
+
+impl TranslationFailure {
+    /// Stable discriminant name, matching the serialized `kind` tag. Used as
+    /// the outcome label on metrics events.
+    pub(crate) fn kind_str(&self) -> &'static str {
+        match self {
+            Self::ApiKeyNotFound { .. } => "api_key_not_found",
+            Self::Network { .. } => "network",
+            Self::Api { .. } => "api",
+            Self::Parse { .. } => "parse",
+            Self::Timeout => "timeout",
+            Self::RateLimited { .. } => "rate_limited",
+            Self::CircuitOpen => "circuit_open",
+            Self::UnsupportedProvider { .. } => "unsupported_provider",
+            Self::InvalidConfig { .. } => "invalid_config",
+            Self::Daemon { .. } => "daemon",
+            Self::SchemaVersionMismatch { .. } => "schema_version_mismatch",
+            Self::BatchLengthMismatch { .. } => "batch_length_mismatch",
+        }
+    }
+
+    /// One-line remediation advice for the error history cell, for the kinds
+    /// where a config or script change is the likely fix.
+    pub(crate) fn hint(&self) -> Option<&'static str> {
+        match self {
+            Self::Timeout => Some("consider increasing timeout_ms"),
+            Self::Parse { .. } => Some("the translator may have printed non-JSON to stdout"),
+            Self::ApiKeyNotFound { .. } => Some("set api_key in translation.toml"),
+            Self::SchemaVersionMismatch { .. } => {
+                Some("set daemon_schema_version to a version the daemon supports")
+            }
+            _ => None,
+        }
+    }
+}
+
+impl From<&TranslationError> for TranslationFailure {
+    fn from(error: &TranslationError) -> Self {
+        match error {
+            TranslationError::ApiKeyNotFound(provider) => Self::ApiKeyNotFound {
+                provider: provider.clone(),
+            },
+            TranslationError::Network(e) => Self::Network {
+                message: e.to_string(),
+            },
+            TranslationError::Api { status, message } => Self::Api {
+                status: *status,
+                message: message.clone(),
+            },
+            TranslationError::Parse(msg) => Self::Parse {
+                message: msg.clone(),
+            },
+            TranslationError::Timeout => Self::Timeout,
+            TranslationError::RateLimited { retry_after_ms } => Self::RateLimited {
+                retry_after_ms: *retry_after_ms,
+            },
+            TranslationError::CircuitOpen => Self::CircuitOpen,
+            TranslationError::UnsupportedProvider(provider) => Self::UnsupportedProvider {
+                provider: provider.clone(),
+            },
+            TranslationError::InvalidConfig(msg) => Self::InvalidConfig {
+                message: msg.clone(),
+            },
+            TranslationError::Daemon(msg) => Self::Daemon {
+                message: msg.clone(),
+            },
+            TranslationError::SchemaVersionMismatch { sent, received } => {
+                Self::SchemaVersionMismatch {
+                    sent: *sent,
+                    received: *received,
+                }
+            }
+            TranslationError::BatchLengthMismatch { expected, got } => Self::BatchLengthMismatch {
+                expected: *expected,
+                got: *got,
+            },
+        }
+    }
+}
+
+impl fmt::Display for TranslationFailure {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::ApiKeyNotFound(provider) => {
+            Self::ApiKeyNotFound { provider } => {
                 write!(f, "API key not configured for {provider}")
             }
-            Self::Network(e) => write!(f, "Network error: {e}"),
+            Self::Network { message } => write!(f, "Network error: {message}"),
             Self::Api { status, message } => {
                 write!(f, "API error ({status}): {message}")
             }
-            Self::Parse(msg) => write!(f, "Parse error: {msg}"),
+            Self::Parse { message } => write!(f, "Parse error: {message}"),
             Self::Timeout => write!(f, "Translation timeout"),
-            Self::UnsupportedProvider(provider) => {
+            Self::RateLimited { retry_after_ms } => {
+                write!(
+                    f,
+                    "Translation rate limited; next slot in {retry_after_ms}ms"
+                )
+            }
+            Self::CircuitOpen => {
+                write!(f, "Translation suspended after repeated failures")
+            }
+            Self::UnsupportedProvider { provider } => {
                 write!(f, "Unsupported provider: {provider}")
             }
-            Self::InvalidConfig(msg) => write!(f, "Invalid configuration: {msg}"),
+            Self::InvalidConfig { message } => write!(f, "Invalid configuration: {message}"),
+            Self::Daemon { message } => write!(f, "Translation daemon error: {message}"),
+            Self::SchemaVersionMismatch { sent, received } => {
+                write!(
+                    f,
+                    "Daemon replied at schema version {received} to a version {sent} request"
+                )
+            }
+            Self::BatchLengthMismatch { expected, got } => {
+                write!(
+                    f,
+                    "Batch translation returned {got} texts for {expected} inputs"
+                )
+            }
         }
     }
 }
@@ -87,4 +252,38 @@ mod tests {
         assert!(err.to_string().contains("401"));
         assert!(err.to_string().contains("Unauthorized"));
     }
+
+    #[test]
+    fn failure_serializes_with_a_stable_kind_tag() {
+        let failure = TranslationError::Api {
+            status: 429,
+            message: "slow down".to_string(),
+        }
+        .failure();
+        let json = serde_json::to_string(&failure).expect("serialize failure");
+        assert_eq!(json, r#"{"kind":"api","status":429,"message":"slow down"}"#);
+        let parsed: TranslationFailure = serde_json::from_str(&json).expect("parse failure");
+        assert_eq!(parsed, failure);
+
+        let json =
+            serde_json::to_string(&TranslationError::Timeout.failure()).expect("serialize timeout");
+        assert_eq!(json, r#"{"kind":"timeout"}"#);
+    }
+
+    #[test]
+    fn failure_hints_point_at_the_likely_fix() {
+        let hint = TranslationError::Timeout.failure().hint().expect("hint");
+        assert!(hint.contains("timeout_ms"));
+
+        let hint = TranslationError::Parse("expected value".to_string())
+            .failure()
+            .hint()
+            .expect("hint");
+        assert!(hint.contains("non-JSON"));
+
+        let failure = TranslationError::Daemon("exit code 1".to_string()).failure();
+        assert_eq!(failure.hint(), None);
+        // The snapshot renders exactly like the error it was taken from.
+        assert_eq!(failure.to_string(), "Translation daemon error: exit code 1");
+    }
 }
@@ -0,0 +1,203 @@
+//! Bounded in-memory history of translation failures.
+//!
+//! Error cells scroll out of the transcript quickly, and suppressed failures
+//! (UI-notice translations fail silently by design) never reach it at all.
+//! The orchestrator records every failure here so `/translate errors` can
+//! reconstruct what went wrong without trawling logs. Only the most recent
+//! [`MAX_RECORDED_ERRORS`] entries are kept.
+
+use std::collections::VecDeque;
+use std::hash::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use super::error::TranslationError;
+
+/// Maximum number of failures retained.
+const MAX_RECORDED_ERRORS: usize = 20;
+
+/// Default preview length when `preview_chars` is unset.
+pub(super) const DEFAULT_PREVIEW_CHARS: usize = 120;
+
+/// Grapheme-safe text preview for debug records and error reports: the first
+/// `max_graphemes` grapheme clusters, then an ellipsis and the total original
+/// size. Counting graphemes rather than chars keeps combined emoji and ZWJ
+/// sequences intact; the size suffix tells the reader how much was cut.
+pub(super) fn preview(text: &str, max_graphemes: usize) -> String {
+    match text.grapheme_indices(true).nth(max_graphemes) {
+        Some((cut, _)) => format!("{}… ({} total)", &text[..cut], human_size(text.len())),
+        None => text.to_string(),
+    }
+}
+
+/// Human-readable byte size for preview suffixes ("312 B", "4.2 KB").
+fn human_size(bytes: usize) -> String {
+    if bytes < 1024 {
+        format!("{bytes} B")
+    } else if bytes < 1024 * 1024 {
+        format!("{:.1} KB", bytes as f64 / 1024.0)
+    } else {
+        format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+    }
+}
+
+/// What kind of translation failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum TranslationErrorKind {
+    /// Agent reasoning content behind the barrier.
+    Reasoning,
+    /// A short built-in UI notice (these failures are otherwise suppressed).
+    UiNotice,
+    /// A plan-update note ("I will now refactor X, then run tests").
+    PlanUpdate,
+    /// An error message surfaced to the user (stream errors, sandbox
+    /// denials).
+    ErrorMessage,
+}
+
+impl TranslationErrorKind {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Reasoning => "reasoning",
+            Self::UiNotice => "notice",
+            Self::PlanUpdate => "plan",
+            Self::ErrorMessage => "error",
+        }
+    }
+}
+
+/// One recorded translation failure.
+#[derive(Debug, Clone)]
+pub(crate) struct TranslationErrorRecord {
+    /// When the failure was recorded.
+    pub(crate) at: chrono::DateTime<chrono::Local>,
+    pub(crate) kind: TranslationErrorKind,
+    /// HTTP status for API errors; absent for other failure modes.
+    pub(crate) code: Option<u16>,
+    pub(crate) message: String,
+    /// Tail of the daemon's stderr at crash time, when one was captured.
+    pub(crate) stderr_preview: Option<String>,
+    /// Hash of the input text, to correlate retries of the same content
+    /// without retaining the content itself.
+    pub(crate) input_hash: u64,
+}
+
+impl TranslationErrorRecord {
+    pub(crate) fn new(
+        kind: TranslationErrorKind,
+        error: &TranslationError,
+        input: &str,
+        stderr_preview: Option<String>,
+    ) -> Self {
+        let code = match error {
+            TranslationError::Api { status, .. } => Some(*status),
+            _ => None,
+        };
+        let mut hasher = DefaultHasher::new();
+        input.hash(&mut hasher);
+        Self {
+            at: chrono::Local::now(),
+            kind,
+            code,
+            message: error.to_string(),
+            stderr_preview,
+            input_hash: hasher.finish(),
+        }
+    }
+}
+
+/// Ring buffer of the most recent translation failures.
+#[derive(Debug, Default)]
+pub(crate) struct TranslationErrorLog {
+    entries: VecDeque<TranslationErrorRecord>,
+}
+
+impl TranslationErrorLog {
+    /// Record a failure, evicting the oldest entry once full.
+    pub(crate) fn record(&mut self, record: TranslationErrorRecord) {
+        if self.entries.len() == MAX_RECORDED_ERRORS {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(record);
+    }
+
+    /// Recorded failures, most recent first.
+    pub(crate) fn recent(&self) -> impl Iterator<Item = &TranslationErrorRecord> {
+        self.entries.iter().rev()
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(message: &str) -> TranslationErrorRecord {
+        TranslationErrorRecord::new(
+            TranslationErrorKind::Reasoning,
+            &TranslationError::Parse(message.to_string()),
+            message,
+            None,
+        )
+    }
+
+    #[test]
+    fn log_keeps_most_recent_entries_newest_first() {
+        let mut log = TranslationErrorLog::default();
+        for i in 0..25 {
+            log.record(record(&format!("error {i}")));
+        }
+
+        assert_eq!(log.len(), MAX_RECORDED_ERRORS);
+        let messages: Vec<&str> = log.recent().map(|r| r.message.as_str()).collect();
+        assert_eq!(messages[0], "Parse error: error 24");
+        assert_eq!(messages.last(), Some(&"Parse error: error 5"));
+
+        log.clear();
+        assert!(log.is_empty());
+    }
+
+    #[test]
+    fn record_captures_api_status_and_input_hash() {
+        let error = TranslationError::Api {
+            status: 429,
+            message: "rate limited".to_string(),
+        };
+        let a = TranslationErrorRecord::new(TranslationErrorKind::UiNotice, &error, "input", None);
+        let b = TranslationErrorRecord::new(TranslationErrorKind::UiNotice, &error, "input", None);
+        let c = TranslationErrorRecord::new(TranslationErrorKind::UiNotice, &error, "other", None);
+
+        assert_eq!(a.code, Some(429));
+        assert_eq!(a.input_hash, b.input_hash);
+        assert_ne!(a.input_hash, c.input_hash);
+    }
+
+    #[test]
+    fn preview_cuts_on_grapheme_clusters_and_reports_the_total_size() {
+        // Short texts pass through untouched, with no suffix.
+        assert_eq!(preview("short", 10), "short");
+
+        // A family emoji is one grapheme built from several chars joined by
+        // ZWJs; a preview boundary must never land inside it.
+        let families = "👩‍👩‍👧‍👦".repeat(4);
+        let cut = preview(&families, 2);
+        assert!(cut.starts_with("👩‍👩‍👧‍👦👩‍👩‍👧‍👦…"));
+        assert!(cut.ends_with("B total)"));
+
+        let big = "a".repeat(4300);
+        assert_eq!(preview(&big, 4), "aaaa… (4.2 KB total)");
+    }
+}
@@ -0,0 +1,263 @@
+//! Lazy translator health check.
+//!
+//! A misconfigured translator (wrong command path, dead endpoint) should be
+//! reported once, up front, instead of as one error cell per reasoning
+//! block. The first time translation is about to be used, a tiny probe
+//! request is sent with a short timeout; on failure the orchestrator shows a
+//! one-time warning and disables translation for the rest of the session.
+//! The check is also callable on its own so scripts and debug surfaces can
+//! exercise a translator config without opening a conversation.
+
+use std::time::Duration;
+use std::time::Instant;
+
+use super::client::TranslationClient;
+use super::config::TranslationConfig;
+use super::daemon::DaemonChain;
+use super::daemon::TranslateContext;
+use super::daemon::TranslateOptions;
+use super::endpoint;
+use super::error::TranslationError;
+use super::error_log::TranslationErrorKind;
+use super::mcp::McpClient;
+
+/// Text sent as the probe request. Short enough that any working translator
+/// answers immediately; the translation itself is discarded.
+const PROBE_TEXT: &str = "ping";
+
+/// Deadline for the probe. Deliberately short: a health check that hangs is
+/// itself a failure worth reporting.
+const PROBE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Outcome of a successful health check.
+#[derive(Debug, Clone)]
+pub(crate) struct TranslatorInfo {
+    /// The backend that served the probe: the daemon command line, or
+    /// `provider/model` for HTTP providers.
+    pub(crate) backend: String,
+    /// Probe round-trip time.
+    pub(crate) elapsed: Duration,
+}
+
+/// Send a tiny probe translation through the backend the reasoning kind
+/// resolves to, with a short timeout.
+///
+/// Daemon probes spawn their own short-lived child rather than touching the
+/// orchestrator's supervised daemon, so a failed probe cannot consume one of
+/// its crash-backoff slots; the child exits on stdin EOF when dropped.
+pub(crate) async fn check_translator(
+    config: &TranslationConfig,
+) -> Result<TranslatorInfo, TranslationError> {
+    let kind = TranslationErrorKind::Reasoning;
+    let started = Instant::now();
+    let options = TranslateOptions {
+        target_language: config.effective_target_language(),
+        source_language: config.effective_source_language(),
+        glossary: None,
+        context: TranslateContext::default(),
+        truncated: false,
+        model: None,
+    };
+    let probe = async {
+        if let Some(command) = config.daemon_command_for(kind) {
+            let mut daemon =
+                DaemonChain::new(command.to_vec(), config.fallback_daemon_command.clone())
+                    .with_schema_version(config.daemon_schema_version_request());
+            daemon.translate(PROBE_TEXT, options).await
+        } else if let Some((command, tool)) = config.mcp_backend() {
+            // Like daemon probes, MCP probes spawn their own short-lived
+            // server rather than touching the shared client.
+            let mut client = McpClient::start(command.to_vec()).await?;
+            client.call_translate(tool, PROBE_TEXT, &options).await
+        } else if let Some(url) = config.http_url.as_deref() {
+            endpoint::translate_via_endpoint(
+                url,
+                config.http_headers.as_ref(),
+                PROBE_TIMEOUT,
+                PROBE_TEXT,
+                options,
+            )
+            .await
+        } else {
+            let client = TranslationClient::from_config_for_kind(config, kind)?;
+            client
+                .translate(PROBE_TEXT, options.target_language, options.source_language)
+                .await
+        }
+    };
+    match tokio::time::timeout(PROBE_TIMEOUT, probe).await {
+        Ok(Ok(_)) => Ok(TranslatorInfo {
+            backend: backend_description(config),
+            elapsed: started.elapsed(),
+        }),
+        Ok(Err(error)) => Err(error),
+        Err(_) => Err(TranslationError::Timeout),
+    }
+}
+
+/// Everything a one-shot debug translation learned, for
+/// `codex debug translation`.
+#[derive(Debug)]
+pub struct TranslationDebugReport {
+    /// The backend that served the request: the daemon command line, or
+    /// `provider/model` for HTTP providers.
+    pub backend: String,
+    /// The translated text.
+    pub translated: String,
+    /// Source language the translator detected, when it reported one.
+    pub detected_language: Option<String>,
+    /// Translator-reported metadata, sorted by key.
+    pub metadata: Vec<(String, String)>,
+    /// Request round-trip time.
+    pub elapsed: Duration,
+}
+
+/// Translate `text` once through the backend the reasoning kind resolves to,
+/// honoring its configured timeout and glossary, and report what came back.
+///
+/// This is the headless path behind `codex debug translation`: it exercises
+/// the same config resolution as the TUI without opening a conversation, so
+/// translator scripts can be tested from a shell or in CI.
+pub async fn debug_translate(
+    config: &TranslationConfig,
+    text: &str,
+) -> Result<TranslationDebugReport, TranslationError> {
+    let kind = TranslationErrorKind::Reasoning;
+    let started = Instant::now();
+    let glossary = config.glossary_for(kind);
+    let options = TranslateOptions {
+        target_language: config.effective_target_language(),
+        source_language: config.effective_source_language(),
+        glossary: glossary.as_ref(),
+        context: TranslateContext::default(),
+        truncated: false,
+        model: None,
+    };
+    let timeout = Duration::from_millis(config.effective_timeout_ms_for(kind));
+    let translate = async {
+        if let Some(command) = config.daemon_command_for(kind) {
+            let mut daemon =
+                DaemonChain::new(command.to_vec(), config.fallback_daemon_command.clone())
+                    .with_schema_version(config.daemon_schema_version_request());
+            daemon.translate(text, options).await
+        } else if let Some((command, tool)) = config.mcp_backend() {
+            let mut client = McpClient::start(command.to_vec()).await?;
+            client.call_translate(tool, text, &options).await
+        } else if let Some(url) = config.http_url.as_deref() {
+            endpoint::translate_via_endpoint(
+                url,
+                config.http_headers.as_ref(),
+                timeout,
+                text,
+                options,
+            )
+            .await
+        } else {
+            let client = TranslationClient::from_config_for_kind(config, kind)?;
+            client
+                .translate(text, options.target_language, options.source_language)
+                .await
+        }
+    };
+    let translated = match tokio::time::timeout(timeout, translate).await {
+        Ok(result) => result?,
+        Err(_) => return Err(TranslationError::Timeout),
+    };
+    let mut metadata: Vec<(String, String)> = translated.metadata.into_iter().collect();
+    metadata.sort();
+    Ok(TranslationDebugReport {
+        backend: backend_description(config),
+        translated: translated.text,
+        detected_language: translated.detected_language,
+        metadata,
+        elapsed: started.elapsed(),
+    })
+}
+
+/// Human-readable name of the backend a probe exercises.
+fn backend_description(config: &TranslationConfig) -> String {
+    if let Some(command) = config.daemon_command_for(TranslationErrorKind::Reasoning) {
+        return command.join(" ");
+    }
+    if let Some((command, tool)) = config.mcp_backend() {
+        return format!("mcp tool {tool} via {}", command.join(" "));
+    }
+    if let Some(url) = config.http_url.as_deref() {
+        return format!("http endpoint {url}");
+    }
+    let provider = config.effective_provider().definition();
+    format!("{}/{}", provider.name, config.effective_model(provider))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(unix)]
+    fn script_with(dir: &std::path::Path, name: &str, body: &str) -> std::path::PathBuf {
+        use std::os::unix::fs::PermissionsExt;
+        let path = dir.join(name);
+        std::fs::write(&path, body).expect("write stub daemon");
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755))
+            .expect("chmod stub daemon");
+        path
+    }
+
+    fn daemon_config(command: &std::path::Path) -> TranslationConfig {
+        TranslationConfig {
+            enabled: true,
+            target_language: "zh-CN".to_string(),
+            daemon_command: Some(vec![command.to_string_lossy().into_owned()]),
+            ..Default::default()
+        }
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn health_check_passes_against_a_working_daemon() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let script = script_with(
+            dir.path(),
+            "healthy.sh",
+            r#"#!/bin/sh
+while read line; do
+  id=$(printf '%s' "$line" | sed 's/.*"id":\([0-9]*\).*/\1/')
+  printf '{"id":%s,"translated":"好"}\n' "$id"
+done
+"#,
+        );
+
+        let info = check_translator(&daemon_config(&script))
+            .await
+            .expect("healthy");
+        assert!(info.backend.ends_with("healthy.sh"));
+        assert!(info.elapsed < PROBE_TIMEOUT);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn health_check_reports_a_broken_command_precisely() {
+        let config = daemon_config(std::path::Path::new("/nonexistent/translator"));
+        let error = check_translator(&config).await.expect_err("broken command");
+        assert!(error.to_string().contains("failed to spawn daemon"));
+    }
+
+    // Paused time auto-advances while the runtime waits on the child's
+    // stdout, so the probe deadline fires without a two-second real wait.
+    #[cfg(unix)]
+    #[tokio::test(start_paused = true)]
+    async fn health_check_times_out_on_a_hung_daemon() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        // Reads the probe and never answers.
+        let script = script_with(
+            dir.path(),
+            "hung.sh",
+            "#!/bin/sh\nwhile read line; do :; done\n",
+        );
+
+        let error = check_translator(&daemon_config(&script))
+            .await
+            .expect_err("hung daemon");
+        assert!(matches!(error, TranslationError::Timeout));
+    }
+}
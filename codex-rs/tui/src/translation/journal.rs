@@ -0,0 +1,154 @@
+//! Write-ahead journal for history cells deferred behind a translation barrier.
+//!
+//! Cells held back while a translation is in flight exist only in memory; if
+//! the process dies (OOM, closed terminal) before the barrier flushes, their
+//! output silently vanishes from the transcript. The orchestrator mirrors the
+//! deferred queue into this journal the moment cells are deferred and rewrites
+//! it as the queue drains, so a later session can recover anything that never
+//! reached the screen.
+
+use std::fs;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Display state of a journaled cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum JournalEntryStatus {
+    /// Deferred behind a barrier and not yet shown.
+    PendingDisplay,
+}
+
+/// One journaled cell, in deferral order.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub(crate) struct JournalEntry {
+    /// Position within the deferred queue at write time.
+    pub(crate) seq: u64,
+    pub(crate) status: JournalEntryStatus,
+    /// Copy-friendly transcript lines of the deferred cell. This is the
+    /// stable serialization: styling is dropped, text is kept verbatim.
+    pub(crate) lines: Vec<String>,
+}
+
+/// Journal mirroring the orchestrator's deferred-cell queue on disk.
+#[derive(Debug)]
+pub(crate) struct DeferredCellJournal {
+    path: PathBuf,
+}
+
+impl DeferredCellJournal {
+    pub(crate) fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    /// Journal at the default location under `~/.codex`.
+    pub(crate) fn at_default_path() -> Option<Self> {
+        let home = dirs::home_dir()?;
+        Some(Self::new(
+            home.join(".codex")
+                .join("translation")
+                .join("deferred-cells.jsonl"),
+        ))
+    }
+
+    /// Rewrite the journal to match the current deferred queue. An empty
+    /// queue removes the file. Journaling is best effort: failures are logged
+    /// and never interrupt the translation flow.
+    pub(crate) fn record(&self, cells: &[Vec<String>]) {
+        if cells.is_empty() {
+            if self.path.exists()
+                && let Err(e) = fs::remove_file(&self.path)
+            {
+                tracing::warn!(error = %e, "failed to clear deferred-cell journal");
+            }
+            return;
+        }
+
+        let mut content = String::new();
+        for (seq, lines) in cells.iter().enumerate() {
+            let entry = JournalEntry {
+                seq: seq as u64,
+                status: JournalEntryStatus::PendingDisplay,
+                lines: lines.clone(),
+            };
+            match serde_json::to_string(&entry) {
+                Ok(json) => {
+                    content.push_str(&json);
+                    content.push('\n');
+                }
+                Err(e) => {
+                    tracing::warn!(error = %e, "failed to serialize deferred-cell journal entry");
+                    return;
+                }
+            }
+        }
+
+        if let Some(parent) = self.path.parent()
+            && let Err(e) = fs::create_dir_all(parent)
+        {
+            tracing::warn!(error = %e, "failed to create deferred-cell journal directory");
+            return;
+        }
+        if let Err(e) = fs::write(&self.path, content) {
+            tracing::warn!(error = %e, "failed to write deferred-cell journal");
+        }
+    }
+
+    /// Read back journaled cells left by a previous process and delete the
+    /// file. Unparsable lines are skipped; entries come back in seq order.
+    pub(crate) fn recover(&self) -> Vec<JournalEntry> {
+        let Ok(content) = fs::read_to_string(&self.path) else {
+            return Vec::new();
+        };
+        if let Err(e) = fs::remove_file(&self.path) {
+            tracing::warn!(error = %e, "failed to remove recovered deferred-cell journal");
+        }
+        let mut entries: Vec<JournalEntry> = content
+            .lines()
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect();
+        entries.sort_by_key(|entry| entry.seq);
+        entries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn journal_round_trips_deferred_cells() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let journal = DeferredCellJournal::new(dir.path().join("deferred.jsonl"));
+
+        journal.record(&[
+            vec!["• first".to_string()],
+            vec!["line one".to_string(), "line two".to_string()],
+        ]);
+
+        let entries = journal.recover();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].status, JournalEntryStatus::PendingDisplay);
+        assert_eq!(entries[0].lines, vec!["• first"]);
+        assert_eq!(entries[1].lines, vec!["line one", "line two"]);
+
+        // Recovery consumes the journal.
+        assert!(journal.recover().is_empty());
+    }
+
+    #[test]
+    fn recording_an_empty_queue_clears_the_journal() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("deferred.jsonl");
+        let journal = DeferredCellJournal::new(path.clone());
+
+        journal.record(&[vec!["pending".to_string()]]);
+        assert!(path.exists());
+
+        journal.record(&[]);
+        assert!(!path.exists());
+        assert!(journal.recover().is_empty());
+    }
+}
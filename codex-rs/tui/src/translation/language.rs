@@ -0,0 +1,135 @@
+//! Cheap source-language heuristic used to skip no-op translations.
+//!
+//! The model occasionally reasons in the target language already; spawning
+//! the translator then only costs latency and produces a near-duplicate
+//! block. This module classifies text by writing system (no dictionaries,
+//! no external crates): it counts codepoints belonging to a recognizable
+//! script and compares the fraction in the target language's script against
+//! a configurable threshold.
+
+/// Writing systems the heuristic can tell apart. Deliberately coarse: the
+/// goal is "already in the target language?", not language identification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Script {
+    /// CJK unified ideographs (Chinese hanzi, Japanese kanji).
+    Han,
+    /// Japanese hiragana and katakana.
+    Kana,
+    Hangul,
+    Cyrillic,
+    Arabic,
+    Latin,
+}
+
+/// Scripts a language is expected to be written in. Unknown languages get an
+/// empty slice, which never matches, so translation proceeds.
+fn scripts_for_language(language: &str) -> &'static [Script] {
+    let primary = language.split(['-', '_']).next().unwrap_or(language);
+    match primary.to_ascii_lowercase().as_str() {
+        "zh" => &[Script::Han],
+        "ja" => &[Script::Han, Script::Kana],
+        "ko" => &[Script::Hangul],
+        "ru" | "uk" | "bg" | "sr" => &[Script::Cyrillic],
+        "ar" | "fa" | "ur" => &[Script::Arabic],
+        "en" | "es" | "fr" | "de" | "it" | "pt" | "nl" | "pl" | "tr" | "vi" | "id" => {
+            &[Script::Latin]
+        }
+        _ => &[],
+    }
+}
+
+/// Script of a single codepoint, or `None` for digits, punctuation,
+/// whitespace, and anything else that carries no language signal.
+fn script_of(c: char) -> Option<Script> {
+    match c as u32 {
+        0x4E00..=0x9FFF | 0x3400..=0x4DBF | 0xF900..=0xFAFF => Some(Script::Han),
+        0x3040..=0x30FF => Some(Script::Kana),
+        0x1100..=0x11FF | 0xAC00..=0xD7AF => Some(Script::Hangul),
+        0x0400..=0x04FF => Some(Script::Cyrillic),
+        0x0600..=0x06FF => Some(Script::Arabic),
+        // ASCII letters plus Latin-1 and Latin Extended accented forms.
+        0x00C0..=0x024F => Some(Script::Latin),
+        _ if c.is_ascii_alphabetic() => Some(Script::Latin),
+        _ => None,
+    }
+}
+
+/// Whether `text` already looks like it is written in `target_language`.
+///
+/// `threshold` is the fraction of script-bearing characters that must belong
+/// to the target's script. Text without any script-bearing characters never
+/// matches.
+pub(crate) fn looks_like_target_language(
+    text: &str,
+    target_language: &str,
+    threshold: f64,
+) -> bool {
+    let target_scripts = scripts_for_language(target_language);
+    if target_scripts.is_empty() {
+        return false;
+    }
+    let mut total = 0usize;
+    let mut matching = 0usize;
+    for c in text.chars() {
+        let Some(script) = script_of(c) else {
+            continue;
+        };
+        total += 1;
+        if target_scripts.contains(&script) {
+            matching += 1;
+        }
+    }
+    if total == 0 {
+        return false;
+    }
+    matching as f64 / total as f64 >= threshold
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chinese_text_matches_a_chinese_target() {
+        assert!(looks_like_target_language(
+            "先读取配置再运行测试",
+            "zh-CN",
+            0.5
+        ));
+    }
+
+    #[test]
+    fn english_text_does_not_match_a_chinese_target() {
+        assert!(!looks_like_target_language(
+            "Read the config loader before running tests",
+            "zh-CN",
+            0.5
+        ));
+    }
+
+    #[test]
+    fn mixed_text_compares_against_the_threshold() {
+        // Four han characters and the four letters of "wasm": exactly half
+        // of the script-bearing characters are in the target script.
+        let text = "检查编译 wasm";
+        assert!(looks_like_target_language(text, "zh-CN", 0.5));
+        assert!(!looks_like_target_language(text, "zh-CN", 0.6));
+    }
+
+    #[test]
+    fn kana_counts_for_japanese_but_not_chinese() {
+        let text = "テストを実行します";
+        assert!(looks_like_target_language(text, "ja-JP", 0.5));
+        assert!(!looks_like_target_language(text, "zh-CN", 0.5));
+    }
+
+    #[test]
+    fn unknown_target_language_never_matches() {
+        assert!(!looks_like_target_language("whatever text", "tlh", 0.0));
+    }
+
+    #[test]
+    fn digits_and_punctuation_carry_no_signal() {
+        assert!(!looks_like_target_language("1234 ... 5678", "zh-CN", 0.5));
+    }
+}
@@ -0,0 +1,322 @@
+//! Placeholder masking for translation-hostile spans.
+//!
+//! Translators mangle inline code, fenced blocks, URLs, and file paths when
+//! they appear inside reasoning bodies. Before sending text to the
+//! translator, those spans are replaced with opaque placeholders (⟦1⟧, ⟦2⟧…)
+//! and substituted back into the translated output. The brackets are rare
+//! enough that translators pass them through untouched; when one still drops
+//! a placeholder, the caller falls back to translating the unmasked text.
+//! Placeholder numbering starts above any ⟦N⟧ token already present in the
+//! source, so text that mentions a placeholder literally round-trips intact.
+
+/// Text with protected spans replaced by placeholders.
+#[derive(Debug)]
+pub(super) struct MaskedSpans {
+    pub(super) masked: String,
+    /// Original span contents, in placeholder order.
+    pub(super) placeholders: Vec<String>,
+    /// Number of the first placeholder. Usually 1, but numbering starts above
+    /// any ⟦N⟧ token already present in the source, so a literal placeholder
+    /// in the text can never be confused with one this module introduced.
+    base: usize,
+}
+
+impl MaskedSpans {
+    /// Whether anything was masked.
+    pub(super) fn is_masked(&self) -> bool {
+        !self.placeholders.is_empty()
+    }
+
+    fn token(&self, index: usize) -> String {
+        placeholder(self.base + index + 1)
+    }
+
+    /// Substitute the original spans back into a translated text.
+    ///
+    /// Returns the restored text and the number of placeholders the
+    /// translator dropped or mangled; any missing placeholder means the
+    /// restored text is incomplete and the caller should fall back to an
+    /// unmasked translation.
+    pub(super) fn restore(&self, translated: &str) -> (String, usize) {
+        let mut restored = translated.to_string();
+        let mut missing = 0usize;
+        for (i, original) in self.placeholders.iter().enumerate() {
+            let token = self.token(i);
+            if restored.contains(&token) {
+                restored = restored.replace(&token, original);
+            } else {
+                missing += 1;
+            }
+        }
+        (restored, missing)
+    }
+}
+
+fn placeholder(index: usize) -> String {
+    format!("⟦{index}⟧")
+}
+
+/// Mask fenced code blocks, inline code spans, URLs, and path-like tokens.
+pub(super) fn mask_protected_spans(text: &str) -> MaskedSpans {
+    let base = literal_placeholder_max(text);
+    let mut placeholders = Numbered {
+        spans: Vec::new(),
+        base,
+    };
+    let masked = mask_fenced_blocks(text, &mut placeholders);
+    let masked = mask_inline_code(&masked, &mut placeholders);
+    let masked = mask_urls_and_paths(&masked, &mut placeholders);
+    MaskedSpans {
+        masked,
+        placeholders: placeholders.spans,
+        base,
+    }
+}
+
+/// Protected spans collected during masking, numbered from `base + 1`.
+struct Numbered {
+    spans: Vec<String>,
+    base: usize,
+}
+
+impl Numbered {
+    /// Record a protected span and return its placeholder token.
+    fn push(&mut self, span: String) -> String {
+        self.spans.push(span);
+        placeholder(self.base + self.spans.len())
+    }
+}
+
+/// Highest N among ⟦N⟧ tokens already present in the source text, or 0.
+fn literal_placeholder_max(text: &str) -> usize {
+    let mut max = 0usize;
+    for chunk in text.split('⟦').skip(1) {
+        if let Some((digits, _)) = chunk.split_once('⟧')
+            && !digits.is_empty()
+            && digits.chars().all(|c| c.is_ascii_digit())
+            && let Ok(n) = digits.parse::<usize>()
+        {
+            max = max.max(n);
+        }
+    }
+    max
+}
+
+/// Length of the backtick run opening a fence on this line, if any.
+fn fence_length(line: &str) -> Option<usize> {
+    let run = line.trim_start().chars().take_while(|&c| c == '`').count();
+    (run >= 3).then_some(run)
+}
+
+/// Whether this line closes a fence opened by `open_len` backticks. Per
+/// CommonMark the closing fence is at least as long as the opening one and
+/// carries no info string, so shorter fences nested inside a longer one
+/// (```` around ```) stay part of the block.
+fn closes_fence(line: &str, open_len: usize) -> bool {
+    let trimmed = line.trim();
+    trimmed.len() >= open_len && trimmed.chars().all(|c| c == '`')
+}
+
+/// Mask whole fenced code blocks, including fences indented inside lists.
+fn mask_fenced_blocks(text: &str, placeholders: &mut Numbered) -> String {
+    let mut out_lines: Vec<String> = Vec::new();
+    let mut block: Option<(usize, Vec<&str>)> = None;
+    for line in text.split('\n') {
+        match block.as_mut() {
+            None => match fence_length(line) {
+                Some(open_len) => block = Some((open_len, vec![line])),
+                None => out_lines.push(line.to_string()),
+            },
+            Some((open_len, lines)) => {
+                lines.push(line);
+                if closes_fence(line, *open_len) {
+                    let (_, lines) = block.take().unwrap_or_default();
+                    out_lines.push(placeholders.push(lines.join("\n")));
+                }
+            }
+        }
+    }
+    // An unterminated fence runs to the end of the text.
+    if let Some((_, lines)) = block {
+        out_lines.push(placeholders.push(lines.join("\n")));
+    }
+    out_lines.join("\n")
+}
+
+/// Mask inline code spans. Per CommonMark, a span opened by a run of N
+/// backticks closes at the next run of exactly N backticks, which is how
+/// nested backticks (`` `code` ``) are written.
+fn mask_inline_code(text: &str, placeholders: &mut Numbered) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::with_capacity(text.len());
+    let mut i = 0usize;
+    while i < chars.len() {
+        if chars[i] != '`' {
+            out.push(chars[i]);
+            i += 1;
+            continue;
+        }
+        let open_len = run_length(&chars, i, '`');
+        // Find a closing run of exactly the same length.
+        let mut j = i + open_len;
+        let close = loop {
+            if j >= chars.len() {
+                break None;
+            }
+            if chars[j] == '`' {
+                let len = run_length(&chars, j, '`');
+                if len == open_len {
+                    break Some(j);
+                }
+                j += len;
+            } else {
+                j += 1;
+            }
+        };
+        match close {
+            Some(close) => {
+                let span: String = chars[i..close + open_len].iter().collect();
+                out.push_str(&placeholders.push(span));
+                i = close + open_len;
+            }
+            None => {
+                // Unmatched backticks are left as ordinary text.
+                out.extend(&chars[i..i + open_len]);
+                i += open_len;
+            }
+        }
+    }
+    out
+}
+
+fn run_length(chars: &[char], start: usize, ch: char) -> usize {
+    chars[start..].iter().take_while(|&&c| c == ch).count()
+}
+
+/// Mask whitespace-delimited URL and path-like tokens.
+fn mask_urls_and_paths(text: &str, placeholders: &mut Numbered) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find(|c: char| !c.is_whitespace()) {
+        let (ws, tail) = rest.split_at(start);
+        out.push_str(ws);
+        let end = tail.find(char::is_whitespace).unwrap_or(tail.len());
+        let (token, tail) = tail.split_at(end);
+        if is_protected_token(token) {
+            out.push_str(&placeholders.push(token.to_string()));
+        } else {
+            out.push_str(token);
+        }
+        rest = tail;
+    }
+    out.push_str(rest);
+    out
+}
+
+fn is_protected_token(token: &str) -> bool {
+    if token.starts_with('⟦') {
+        // Already a placeholder.
+        return false;
+    }
+    if token.starts_with("http://") || token.starts_with("https://") {
+        return true;
+    }
+    // Path-like: a separator somewhere, or a home/relative path prefix.
+    token.contains('/')
+        || token.contains('\\')
+        || token.starts_with("~/")
+        || token.starts_with("./")
+        || token.starts_with("../")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn masks_inline_code_with_nested_backticks() {
+        let masked = mask_protected_spans("run `cargo test --workspace` or `` `quoted` `` now");
+
+        assert_eq!(masked.masked, "run ⟦1⟧ or ⟦2⟧ now");
+        assert_eq!(masked.placeholders[0], "`cargo test --workspace`");
+        assert_eq!(masked.placeholders[1], "`` `quoted` ``");
+    }
+
+    #[test]
+    fn masks_fences_inside_lists() {
+        let text = "- step one\n  ```sh\n  cargo build\n  ```\n- step two";
+        let masked = mask_protected_spans(text);
+
+        assert_eq!(masked.masked, "- step one\n⟦1⟧\n- step two");
+        assert_eq!(masked.placeholders[0], "  ```sh\n  cargo build\n  ```");
+    }
+
+    #[test]
+    fn masks_urls_and_paths_but_not_plain_words() {
+        let masked =
+            mask_protected_spans("see https://example.com/docs and src/main.rs but not words");
+
+        assert_eq!(masked.masked, "see ⟦1⟧ and ⟦2⟧ but not words");
+        assert_eq!(
+            masked.placeholders,
+            vec!["https://example.com/docs", "src/main.rs"]
+        );
+    }
+
+    #[test]
+    fn restore_round_trips_and_counts_dropped_placeholders() {
+        let masked = mask_protected_spans("run `a` then `b` done");
+        assert_eq!(masked.masked, "run ⟦1⟧ then ⟦2⟧ done");
+
+        let (restored, missing) = masked.restore("运行 ⟦1⟧ 然后 ⟦2⟧ 完成");
+        assert_eq!(restored, "运行 `a` 然后 `b` 完成");
+        assert_eq!(missing, 0);
+
+        // A placeholder-dropping translator loses ⟦2⟧.
+        let (_, missing) = masked.restore("运行 ⟦1⟧ 完成");
+        assert_eq!(missing, 1);
+    }
+
+    #[test]
+    fn nested_fences_round_trip_as_one_block() {
+        // A four-backtick fence quoting a three-backtick fence is one block;
+        // the inner fence must not close it.
+        let text = "before\n````md\n```sh\ncargo build\n```\n````\nafter";
+        let masked = mask_protected_spans(text);
+
+        assert_eq!(masked.masked, "before\n⟦1⟧\nafter");
+        assert_eq!(
+            masked.placeholders,
+            vec!["````md\n```sh\ncargo build\n```\n````"]
+        );
+        let (restored, missing) = masked.restore(&masked.masked);
+        assert_eq!(restored, text);
+        assert_eq!(missing, 0);
+    }
+
+    #[test]
+    fn unterminated_fence_round_trips_to_end_of_text() {
+        let text = "prose\n```sh\ncargo build";
+        let masked = mask_protected_spans(text);
+
+        assert_eq!(masked.masked, "prose\n⟦1⟧");
+        assert_eq!(masked.placeholders, vec!["```sh\ncargo build"]);
+        let (restored, missing) = masked.restore(&masked.masked);
+        assert_eq!(restored, text);
+        assert_eq!(missing, 0);
+    }
+
+    #[test]
+    fn literal_placeholders_in_the_source_survive_masking() {
+        // The source text talks about the token ⟦1⟧ itself; fresh
+        // placeholders are numbered above it, so restore leaves it alone.
+        let text = "the token ⟦1⟧ marks `code` spans";
+        let masked = mask_protected_spans(text);
+
+        assert_eq!(masked.masked, "the token ⟦1⟧ marks ⟦2⟧ spans");
+        assert_eq!(masked.placeholders, vec!["`code`"]);
+        let (restored, missing) = masked.restore(&masked.masked);
+        assert_eq!(restored, text);
+        assert_eq!(missing, 0);
+    }
+}
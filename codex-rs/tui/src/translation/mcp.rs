@@ -0,0 +1,336 @@
+//! MCP-tool-backed translation.
+//!
+//! `mcp_server_command` plus `mcp_tool` point translation at a Model Context
+//! Protocol server instead of a script speaking the NDJSON daemon protocol:
+//! the server is spawned once, initialized, and every translation becomes one
+//! `tools/call` round trip. Only the slice of MCP needed here — JSON-RPC
+//! requests over stdio lines — is implemented, so a translation tool written
+//! against any MCP SDK works without a wrapper script.
+
+use std::collections::HashMap;
+use std::process::Stdio;
+use std::sync::LazyLock;
+
+use serde_json::Value;
+use serde_json::json;
+use tokio::io::AsyncBufReadExt;
+use tokio::io::AsyncReadExt;
+use tokio::io::AsyncWriteExt;
+use tokio::io::BufReader;
+use tokio::process::Child;
+use tokio::process::ChildStdin;
+use tokio::process::ChildStdout;
+
+use super::daemon::MAX_RESPONSE_BYTES;
+use super::daemon::TranslateOptions;
+use super::daemon::TranslatedText;
+use super::error::TranslationError;
+
+/// MCP protocol revision sent in the `initialize` request.
+const PROTOCOL_VERSION: &str = "2025-06-18";
+
+/// How many unrelated lines (log notifications, server-side requests) are
+/// skipped while waiting for a response before the server is considered
+/// broken.
+const MAX_SKIPPED_LINES: usize = 64;
+
+/// Process-wide MCP client, shared across translation kinds the way the
+/// daemon chain is: one server child serves the whole session. Rebuilt on
+/// demand when the configured command changes or after a failed exchange.
+static SHARED: LazyLock<tokio::sync::Mutex<Option<McpClient>>> =
+    LazyLock::new(|| tokio::sync::Mutex::new(None));
+
+/// Translate one text through the shared MCP server, spawning or respawning
+/// it as needed.
+pub(super) async fn translate_via_shared(
+    command: &[String],
+    tool: &str,
+    text: &str,
+    options: TranslateOptions<'_>,
+) -> Result<TranslatedText, TranslationError> {
+    let mut slot = SHARED.lock().await;
+    if slot.as_ref().is_none_or(|client| client.command != command) {
+        *slot = Some(McpClient::start(command.to_vec()).await?);
+    }
+    let client = slot.as_mut().expect("client ensured above");
+    let result = client.call_translate(tool, text, &options).await;
+    if result.is_err() {
+        // The stream may be desynchronized after a failed exchange; drop the
+        // child (killed on drop) so the next request starts clean.
+        *slot = None;
+    }
+    result
+}
+
+/// A spawned MCP server with the `initialize` handshake completed.
+#[derive(Debug)]
+pub(super) struct McpClient {
+    command: Vec<String>,
+    /// Held for its kill-on-drop behavior; never waited on directly.
+    _child: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+    next_id: u64,
+}
+
+impl McpClient {
+    /// Spawn the server and run the `initialize` handshake.
+    pub(super) async fn start(command: Vec<String>) -> Result<Self, TranslationError> {
+        let Some((program, args)) = command.split_first() else {
+            return Err(TranslationError::InvalidConfig(
+                "mcp_server_command is empty".to_string(),
+            ));
+        };
+        let mut child = tokio::process::Command::new(program)
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(|e| TranslationError::Daemon(format!("failed to spawn MCP server: {e}")))?;
+        let stdin = child
+            .stdin
+            .take()
+            .ok_or_else(|| TranslationError::Daemon("MCP server stdin closed".to_string()))?;
+        let stdout = child
+            .stdout
+            .take()
+            .map(BufReader::new)
+            .ok_or_else(|| TranslationError::Daemon("MCP server stdout closed".to_string()))?;
+        let mut client = Self {
+            command,
+            _child: child,
+            stdin,
+            stdout,
+            next_id: 0,
+        };
+        client
+            .request(
+                "initialize",
+                json!({
+                    "protocolVersion": PROTOCOL_VERSION,
+                    "capabilities": {},
+                    "clientInfo": {
+                        "name": "codex-translation",
+                        "version": env!("CARGO_PKG_VERSION"),
+                    },
+                }),
+            )
+            .await?;
+        client.notify("notifications/initialized").await?;
+        Ok(client)
+    }
+
+    /// Call `tool` with the request fields as arguments and map its text
+    /// content back into the daemon response shape. Tool-reported errors and
+    /// empty results fail the same way empty daemon responses do.
+    pub(super) async fn call_translate(
+        &mut self,
+        tool: &str,
+        text: &str,
+        options: &TranslateOptions<'_>,
+    ) -> Result<TranslatedText, TranslationError> {
+        let mut arguments = json!({
+            "text": text,
+            "target_language": options.target_language,
+        });
+        if let Some(source) = options.source_language {
+            arguments["source_language"] = json!(source);
+        }
+        if let Some(glossary) = options.glossary {
+            arguments["glossary"] = json!(glossary);
+        }
+        let result = self
+            .request("tools/call", json!({"name": tool, "arguments": arguments}))
+            .await?;
+        let content: String = result["content"]
+            .as_array()
+            .map(|parts| {
+                parts
+                    .iter()
+                    .filter_map(|part| part["text"].as_str())
+                    .collect()
+            })
+            .unwrap_or_default();
+        if result["isError"].as_bool() == Some(true) {
+            return Err(TranslationError::Daemon(if content.is_empty() {
+                "tool reported an error".to_string()
+            } else {
+                content
+            }));
+        }
+        if content.is_empty() {
+            return Err(TranslationError::Daemon(
+                "tool result has no text content".to_string(),
+            ));
+        }
+        Ok(TranslatedText {
+            text: content,
+            detected_language: None,
+            metadata: HashMap::new(),
+        })
+    }
+
+    /// Send one JSON-RPC request and read its response, skipping unrelated
+    /// lines (log notifications, server-side requests) the server may
+    /// interleave.
+    async fn request(&mut self, method: &str, params: Value) -> Result<Value, TranslationError> {
+        let id = self.next_id;
+        self.next_id = self.next_id.wrapping_add(1);
+        let request = json!({"jsonrpc": "2.0", "id": id, "method": method, "params": params});
+        self.write_line(&request).await?;
+        for _ in 0..MAX_SKIPPED_LINES {
+            let mut line = String::new();
+            let read = (&mut self.stdout)
+                .take(MAX_RESPONSE_BYTES as u64 + 1)
+                .read_line(&mut line)
+                .await
+                .map_err(|e| TranslationError::Daemon(format!("read failed: {e}")))?;
+            if read == 0 {
+                return Err(TranslationError::Daemon("MCP server exited".to_string()));
+            }
+            if read > MAX_RESPONSE_BYTES {
+                return Err(TranslationError::Daemon(format!(
+                    "response line exceeds {MAX_RESPONSE_BYTES} bytes"
+                )));
+            }
+            let message: Value =
+                serde_json::from_str(&line).map_err(|e| TranslationError::Parse(e.to_string()))?;
+            // Responses carry our id and no method; everything else is the
+            // server talking about something we did not ask.
+            if message.get("method").is_some() || message["id"].as_u64() != Some(id) {
+                continue;
+            }
+            if let Some(error) = message.get("error") {
+                let reason = error["message"].as_str().unwrap_or("unknown error");
+                return Err(TranslationError::Daemon(format!("MCP error: {reason}")));
+            }
+            return Ok(message["result"].clone());
+        }
+        Err(TranslationError::Daemon(format!(
+            "no response to {method} within {MAX_SKIPPED_LINES} lines"
+        )))
+    }
+
+    /// Send one JSON-RPC notification (no response expected).
+    async fn notify(&mut self, method: &str) -> Result<(), TranslationError> {
+        self.write_line(&json!({"jsonrpc": "2.0", "method": method}))
+            .await
+    }
+
+    async fn write_line(&mut self, message: &Value) -> Result<(), TranslationError> {
+        let mut line = message.to_string();
+        line.push('\n');
+        self.stdin
+            .write_all(line.as_bytes())
+            .await
+            .map_err(|e| TranslationError::Daemon(format!("write failed: {e}")))?;
+        self.stdin
+            .flush()
+            .await
+            .map_err(|e| TranslationError::Daemon(format!("flush failed: {e}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn zh() -> TranslateOptions<'static> {
+        TranslateOptions {
+            target_language: "zh-CN",
+            ..TranslateOptions::default()
+        }
+    }
+
+    /// Stub MCP server: answers the handshake, then serves `tools/call` with
+    /// a fixed translation, preceded by a log notification so the client's
+    /// line skipping is exercised on every call.
+    #[cfg(unix)]
+    fn stub_mcp_server_script(dir: &std::path::Path) -> std::path::PathBuf {
+        use std::os::unix::fs::PermissionsExt;
+        let path = dir.join("stub-mcp-server.sh");
+        let script = r#"#!/bin/sh
+while read line; do
+  id=$(printf '%s' "$line" | sed 's/.*"id":\([0-9]*\).*/\1/')
+  case "$line" in
+    *'"method":"initialize"'*)
+      printf '{"jsonrpc":"2.0","id":%s,"result":{}}\n' "$id" ;;
+    *'"method":"tools/call"'*)
+      printf '{"jsonrpc":"2.0","method":"notifications/message","params":{}}\n'
+      result='{"content":[{"type":"text","text":"译文"}]}'
+      printf '{"jsonrpc":"2.0","id":%s,"result":%s}\n' "$id" "$result" ;;
+  esac
+done
+"#;
+        std::fs::write(&path, script).expect("write stub server");
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755))
+            .expect("chmod stub server");
+        path
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn tool_call_round_trips_through_a_stub_server() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let script = stub_mcp_server_script(dir.path());
+        let mut client = McpClient::start(vec![script.to_string_lossy().into_owned()])
+            .await
+            .expect("handshake");
+
+        let translated = client
+            .call_translate("translate", "hello", &zh())
+            .await
+            .expect("translate");
+        assert_eq!(translated.text, "译文");
+
+        // Request ids stay matched across calls despite the interleaved
+        // notifications.
+        let translated = client
+            .call_translate("translate", "world", &zh())
+            .await
+            .expect("translate again");
+        assert_eq!(translated.text, "译文");
+    }
+
+    /// Stub MCP server whose tool always reports an error result.
+    #[cfg(unix)]
+    fn failing_tool_server_script(dir: &std::path::Path) -> std::path::PathBuf {
+        use std::os::unix::fs::PermissionsExt;
+        let path = dir.join("failing-tool-server.sh");
+        let script = r#"#!/bin/sh
+while read line; do
+  id=$(printf '%s' "$line" | sed 's/.*"id":\([0-9]*\).*/\1/')
+  case "$line" in
+    *'"method":"initialize"'*)
+      printf '{"jsonrpc":"2.0","id":%s,"result":{}}\n' "$id" ;;
+    *'"method":"tools/call"'*)
+      result='{"content":[{"type":"text","text":"model unavailable"}],"isError":true}'
+      printf '{"jsonrpc":"2.0","id":%s,"result":%s}\n' "$id" "$result" ;;
+  esac
+done
+"#;
+        std::fs::write(&path, script).expect("write stub server");
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755))
+            .expect("chmod stub server");
+        path
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn tool_error_results_surface_as_daemon_errors() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let script = failing_tool_server_script(dir.path());
+        let mut client = McpClient::start(vec![script.to_string_lossy().into_owned()])
+            .await
+            .expect("handshake");
+
+        let error = client
+            .call_translate("translate", "hello", &zh())
+            .await
+            .expect_err("tool error");
+        assert!(matches!(error, TranslationError::Daemon(_)));
+        assert!(error.to_string().contains("model unavailable"));
+    }
+}
@@ -6,13 +6,49 @@
 //!   translation results appear immediately after original content
 //! - `TranslationClient` - HTTP client for translation APIs
 //! - `ProviderId` - Supported LLM provider identifiers
+//! - `TranslationDaemon` - Supervised long-running translator process
+//! - `TranslationCache` - Process-wide LRU of completed translations
+//! - `check_translator` - One-shot health probe for the configured backend
+//! - `TranslationErrorLog` - Bounded history of recent translation failures
+//! - `translation_stats` - Process-wide invocation counters and latency
+//!   percentiles
 
+mod cache;
+mod circuit_breaker;
 mod client;
 mod config;
+mod daemon;
+mod debug_log;
+mod endpoint;
 mod error;
+mod error_log;
+mod health;
+mod journal;
+mod language;
+mod masking;
+mod mcp;
 mod orchestrator;
 mod provider;
+mod rate_limit;
+mod redaction;
+mod stats;
 
-pub(crate) use config::TranslationConfig;
+pub(crate) use config::DaemonSchemaVersion;
+pub(crate) use config::HeaderOverflow;
+pub(crate) use config::KindOverrides;
+pub use config::TranslationConfig;
+pub use config::TranslatorDef;
+pub(crate) use daemon::DaemonStatus;
+pub use error::TranslationError;
+pub(crate) use error_log::TranslationErrorRecord;
+pub use health::TranslationDebugReport;
+#[allow(unused_imports)]
+pub(crate) use health::TranslatorInfo;
+#[allow(unused_imports)]
+pub(crate) use health::check_translator;
+pub use health::debug_translate;
 pub(crate) use orchestrator::ReasoningTranslator;
-pub(crate) use provider::ProviderId;
+pub(crate) use orchestrator::TranslationOrchestratorSnapshot;
+pub use provider::ProviderId;
+pub use stats::TranslationStats;
+pub use stats::translation_stats;
@@ -3,19 +3,49 @@
 //! This module implements a barrier mechanism to ensure translation results
 //! appear immediately after their corresponding reasoning content in the UI.
 
+use std::collections::HashMap;
+use std::collections::HashSet;
 use std::collections::VecDeque;
+use std::sync::Arc;
 use std::time::Duration;
 use std::time::Instant;
 
 use codex_protocol::ThreadId;
+use lru::LruCache;
+use ratatui::style::Stylize;
 
+use super::cache::TranslationCache;
+use super::circuit_breaker;
 use super::client::TranslationClient;
+use super::config::HeaderOverflow;
 use super::config::TranslationConfig;
+use super::daemon::DaemonChain;
+use super::daemon::DaemonStatus;
+use super::daemon::TranslateContext;
+use super::daemon::TranslateOptions;
+use super::daemon::TranslatedText;
+use super::debug_log::TranslationDebugLog;
+use super::endpoint;
+use super::error::TranslationFailure;
+use super::error_log::TranslationErrorKind;
+use super::error_log::TranslationErrorLog;
+use super::error_log::TranslationErrorRecord;
+use super::health;
+use super::health::TranslatorInfo;
+use super::journal::DeferredCellJournal;
+use super::language;
+use super::masking;
+use super::mcp;
+use super::rate_limit;
+use super::redaction;
+use super::stats;
 use crate::app_event::AppEvent;
 use crate::app_event_sender::AppEventSender;
 use crate::history_cell;
 use crate::history_cell::HistoryCell;
 use crate::tui::FrameRequester;
+use unicode_width::UnicodeWidthChar;
+use unicode_width::UnicodeWidthStr;
 
 /// Default maximum wait time for translation (in milliseconds).
 const DEFAULT_TRANSLATION_MAX_WAIT_MS: u64 = 5000;
@@ -31,6 +61,39 @@ struct TranslationBarrier {
     title: Option<String>,
     max_wait: Duration,
     deadline: Instant,
+    /// When the barrier was raised, for per-turn timing.
+    started: Instant,
+    /// Latest streamed snapshot of the translation in progress, kept so a
+    /// deadline that fires mid-stream can salvage the partial text instead
+    /// of showing a bare timeout error.
+    partial: Option<String>,
+}
+
+/// Per-turn translation counters backing the optional end-of-turn summary
+/// cell (`turn_summary = true`).
+///
+/// Keyed by the thread the turn started on, so results from a replaced
+/// thread cannot leak into the next turn's summary. Reset on each new user
+/// turn.
+#[derive(Debug, Default)]
+struct TurnTranslationStats {
+    thread_id: Option<ThreadId>,
+    translated: usize,
+    failed: usize,
+    /// Reasoning blocks that could not start because a barrier was already
+    /// active (only one translation runs at a time).
+    skipped: usize,
+    /// Wall time spent waiting on translations; timeouts contribute their
+    /// full `max_wait`.
+    total_time: Duration,
+    /// Failure reasons, in order, including codes where the error has one.
+    failures: Vec<String>,
+}
+
+impl TurnTranslationStats {
+    fn is_empty(&self) -> bool {
+        self.translated == 0 && self.failed == 0 && self.skipped == 0
+    }
 }
 
 #[derive(Debug)]
@@ -40,7 +103,15 @@ pub(super) struct TranslationResult {
     /// Original title (e.g., "Thinking") for error display.
     title: Option<String>,
     translated: Option<String>,
-    error: Option<String>,
+    /// Structured failure snapshot; `None` on success. Carried in typed form
+    /// so the error cell can attach advice specific to the failure kind.
+    error: Option<TranslationFailure>,
+    /// Translator-reported metadata for the optional footer; empty when the
+    /// translator reported none (or the result came from the cache).
+    metadata: HashMap<String, String>,
+    /// Whether this is a streamed snapshot of a translation still underway.
+    /// Partial results update the barrier instead of resolving it.
+    partial: bool,
 }
 
 impl TranslationResult {
@@ -49,7 +120,7 @@ impl TranslationResult {
         thread_id: ThreadId,
         title: Option<String>,
         translated: Option<String>,
-        error: Option<String>,
+        error: Option<TranslationFailure>,
     ) -> Self {
         Self {
             request_id,
@@ -57,8 +128,28 @@ impl TranslationResult {
             title,
             translated,
             error,
+            metadata: HashMap::new(),
+            partial: false,
         }
     }
+
+    /// A streamed snapshot: the accumulated translation so far, not final.
+    fn partial_snapshot(request_id: u64, thread_id: ThreadId, translated: String) -> Self {
+        Self {
+            request_id,
+            thread_id,
+            title: None,
+            translated: Some(translated),
+            error: None,
+            metadata: HashMap::new(),
+            partial: true,
+        }
+    }
+
+    fn with_metadata(mut self, metadata: HashMap<String, String>) -> Self {
+        self.metadata = metadata;
+        self
+    }
 }
 
 #[derive(Debug)]
@@ -72,15 +163,103 @@ pub(crate) struct ReasoningTranslator {
     deferred_history_cells: VecDeque<Box<dyn HistoryCell>>,
     /// Sequence number for binding async results to current barrier.
     translation_seq: u64,
+    /// Cached title translations (original -> translated), e.g. "Thinking" -> "思考中".
+    /// Populated as translations complete so transcript views can render known
+    /// titles bilingually without re-requesting translation. Bounded: the
+    /// least-recently-displayed title is evicted once the configured capacity
+    /// is reached.
+    title_translation_cache: LruCache<String, String>,
+    /// Cached UI-notice translations keyed by the placeholder-masked English
+    /// text. Notices repeat verbatim constantly, so a hit rewrites the cell
+    /// synchronously at insertion time.
+    notice_translation_cache: HashMap<String, String>,
+    /// Masked notice texts with an in-flight background translation, to avoid
+    /// spawning duplicate requests before the first one lands.
+    notice_translations_pending: HashSet<String>,
     /// Channel for receiving translation results.
     results_tx: tokio::sync::mpsc::UnboundedSender<TranslationResult>,
     results_rx: tokio::sync::mpsc::UnboundedReceiver<TranslationResult>,
+    /// Channel for receiving background notice translations (masked text,
+    /// translation). `None` marks a failed attempt so it can be retried later.
+    notice_results_tx: tokio::sync::mpsc::UnboundedSender<(String, Option<String>)>,
+    notice_results_rx: tokio::sync::mpsc::UnboundedReceiver<(String, Option<String>)>,
+    /// Channel for failure records reported by background translation tasks.
+    error_records_tx: tokio::sync::mpsc::UnboundedSender<TranslationErrorRecord>,
+    error_records_rx: tokio::sync::mpsc::UnboundedReceiver<TranslationErrorRecord>,
+    /// Channel for the result of the lazy translator health check.
+    health_tx: tokio::sync::mpsc::UnboundedSender<Result<TranslatorInfo, String>>,
+    health_rx: tokio::sync::mpsc::UnboundedReceiver<Result<TranslatorInfo, String>>,
+    /// Whether the health check has been started this session. Reset when the
+    /// config changes or translation is re-enabled, so a fixed config gets a
+    /// fresh probe.
+    health_checked: bool,
+    /// Warnings the config loader collected (deprecated keys, ignored
+    /// settings), not yet shown. Drained into one warning cell each on the
+    /// next draw tick, so problems in `translation.toml` are visible in the
+    /// transcript instead of only in the tracing log.
+    startup_warnings_pending: Vec<String>,
+    /// Slug of the model currently driving the conversation, checked against
+    /// `disabled_for_models` before reasoning translation starts. Updated by
+    /// the chat widget, so `/model` switches take effect immediately.
+    active_model: Option<String>,
+    /// Whether the one-per-session circuit breaker notice has been shown.
+    /// Repeated failures open the breaker on every subsequent block; only
+    /// the first skip is worth a warning cell.
+    circuit_notice_shown: bool,
+    /// Bounded history of recent translation failures (`/translate errors`).
+    error_log: TranslationErrorLog,
+    /// Counters for the optional end-of-turn summary cell.
+    turn_stats: TurnTranslationStats,
+    /// Zero-based index of the current user turn, attached to translation
+    /// requests so translators can keep per-turn context. `None` until the
+    /// first turn starts.
+    turn_index: Option<u64>,
+    /// Supervised translator daemon for reasoning translations, present when
+    /// a daemon command resolves for that kind. Shared with spawned
+    /// translation tasks.
+    daemon: Option<Arc<tokio::sync::Mutex<DaemonChain>>>,
+    /// Daemon for UI-notice translations. Shares the reasoning daemon unless
+    /// the `[notice]` table overrides the command.
+    notice_daemon: Option<Arc<tokio::sync::Mutex<DaemonChain>>>,
+    /// Daemon for error-message translations. Shares an existing daemon
+    /// unless the `[error]` table overrides the command.
+    error_daemon: Option<Arc<tokio::sync::Mutex<DaemonChain>>>,
+    /// Write-ahead journal mirroring the deferred queue so cells survive a
+    /// process kill mid-barrier.
+    journal: Option<DeferredCellJournal>,
+    /// Whether a previous process's journal has been replayed yet.
+    journal_recovered: bool,
 }
 
 pub(crate) struct OnTranslationResult {
     pub(crate) needs_redraw: bool,
 }
 
+/// Cheap point-in-time view of the orchestrator's counters and flags.
+///
+/// Carries no cell contents, so status surfaces (`/translate status`, the
+/// statusline, app-server health info) can hold it without borrowing the
+/// orchestrator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct TranslationOrchestratorSnapshot {
+    /// Whether translation is currently enabled.
+    pub(crate) enabled: bool,
+    /// Whether a barrier is holding back history cells right now.
+    pub(crate) barrier_active: bool,
+    /// History cells deferred behind the active barrier.
+    pub(crate) deferred_cells: usize,
+    /// Cached title translations.
+    pub(crate) title_cache_size: usize,
+    /// Cached UI-notice translations.
+    pub(crate) notice_cache_size: usize,
+    /// UI-notice translations still in flight.
+    pub(crate) pending_notices: usize,
+    /// Reasoning translation requests started this session.
+    pub(crate) requests_started: u64,
+    /// Whether a supervised translator daemon is configured.
+    pub(crate) daemon_configured: bool,
+}
+
 impl Default for ReasoningTranslator {
     fn default() -> Self {
         // Default to disabled, will be enabled when translation config is set
@@ -99,25 +278,244 @@ impl ReasoningTranslator {
 
     /// Create from configuration.
     pub(crate) fn from_config(config: TranslationConfig) -> Self {
+        let config_warnings = config.startup_warnings.clone();
         let (results_tx, results_rx) = tokio::sync::mpsc::unbounded_channel();
+        let (notice_results_tx, notice_results_rx) = tokio::sync::mpsc::unbounded_channel();
+        let (error_records_tx, error_records_rx) = tokio::sync::mpsc::unbounded_channel();
+        let (health_tx, health_rx) = tokio::sync::mpsc::unbounded_channel();
         let enabled = config.enabled;
+        let title_cache_capacity = config.effective_title_cache_capacity();
+        let (daemon, notice_daemon, error_daemon) = Self::build_daemons(&config);
         Self {
             enabled,
             config,
             translation_barrier: None,
             deferred_history_cells: VecDeque::new(),
             translation_seq: 0,
+            title_translation_cache: LruCache::new(title_cache_capacity),
+            notice_translation_cache: HashMap::new(),
+            notice_translations_pending: HashSet::new(),
             results_tx,
             results_rx,
+            notice_results_tx,
+            notice_results_rx,
+            error_records_tx,
+            error_records_rx,
+            health_tx,
+            health_rx,
+            health_checked: false,
+            startup_warnings_pending: config_warnings,
+            active_model: None,
+            circuit_notice_shown: false,
+            error_log: TranslationErrorLog::default(),
+            turn_stats: TurnTranslationStats::default(),
+            turn_index: None,
+            daemon,
+            notice_daemon,
+            error_daemon,
+            journal: DeferredCellJournal::at_default_path(),
+            journal_recovered: false,
+        }
+    }
+
+    /// Point the journal at a test-controlled path.
+    #[cfg(test)]
+    fn set_journal_for_tests(&mut self, journal: DeferredCellJournal) {
+        self.journal = Some(journal);
+    }
+
+    /// Mirror the deferred queue into the write-ahead journal.
+    fn sync_journal(&self) {
+        let Some(journal) = self.journal.as_ref() else {
+            return;
+        };
+        let cells: Vec<Vec<String>> = self
+            .deferred_history_cells
+            .iter()
+            .map(|cell| cell.raw_lines().iter().map(ToString::to_string).collect())
+            .collect();
+        journal.record(&cells);
+    }
+
+    /// Replay cells a previous process journaled but never displayed.
+    ///
+    /// The journal stores plain transcript text, so recovered cells come back
+    /// unstyled, in their original deferral order.
+    fn recover_journaled_cells(&mut self) -> Vec<Box<dyn HistoryCell>> {
+        let Some(journal) = self.journal.as_ref() else {
+            return Vec::new();
+        };
+        journal
+            .recover()
+            .into_iter()
+            .map(|entry| {
+                let lines = entry
+                    .lines
+                    .into_iter()
+                    .map(ratatui::text::Line::from)
+                    .collect();
+                Box::new(history_cell::PlainHistoryCell::new(lines)) as Box<dyn HistoryCell>
+            })
+            .collect()
+    }
+
+    /// Build the per-kind daemons. Kinds whose resolved command is identical
+    /// share one daemon, so one child process serves all of them by default.
+    #[allow(clippy::type_complexity)]
+    fn build_daemons(
+        config: &TranslationConfig,
+    ) -> (
+        Option<Arc<tokio::sync::Mutex<DaemonChain>>>,
+        Option<Arc<tokio::sync::Mutex<DaemonChain>>>,
+        Option<Arc<tokio::sync::Mutex<DaemonChain>>>,
+    ) {
+        let new_daemon = |command: &[String]| {
+            Arc::new(tokio::sync::Mutex::new(
+                DaemonChain::new(command.to_vec(), config.fallback_daemon_command.clone())
+                    .with_schema_version(config.daemon_schema_version_request()),
+            ))
+        };
+        let reasoning_command = config.daemon_command_for(TranslationErrorKind::Reasoning);
+        let notice_command = config.daemon_command_for(TranslationErrorKind::UiNotice);
+        let error_command = config.daemon_command_for(TranslationErrorKind::ErrorMessage);
+        let reasoning = reasoning_command.map(new_daemon);
+        let notice = if notice_command == reasoning_command {
+            reasoning.clone()
+        } else {
+            notice_command.map(new_daemon)
+        };
+        let error = if error_command == reasoning_command {
+            reasoning.clone()
+        } else if error_command == notice_command {
+            notice.clone()
+        } else {
+            error_command.map(new_daemon)
+        };
+        if let Some(idle) = config.daemon_idle_timeout_ms.map(Duration::from_millis) {
+            // One reaper task per distinct daemon process. Each task holds a
+            // weak reference, so a config change that rebuilds the daemons
+            // lets the old reapers exit on their next tick. Checking every
+            // `idle` keeps the task trivial; a child is stopped at most two
+            // idle periods after its last request.
+            let mut seen: Vec<&Arc<tokio::sync::Mutex<DaemonChain>>> = Vec::new();
+            for daemon in [&reasoning, &notice, &error].into_iter().flatten() {
+                if seen.iter().any(|known| Arc::ptr_eq(known, daemon)) {
+                    continue;
+                }
+                seen.push(daemon);
+                let weak = Arc::downgrade(daemon);
+                tokio::spawn(async move {
+                    loop {
+                        tokio::time::sleep(idle).await;
+                        let Some(daemon) = weak.upgrade() else { return };
+                        daemon.lock().await.shutdown_if_idle(idle);
+                    }
+                });
+            }
+        }
+        (reasoning, notice, error)
+    }
+
+    /// The daemon serving `kind`, when one is configured for it.
+    fn daemon_for(
+        &self,
+        kind: TranslationErrorKind,
+    ) -> Option<Arc<tokio::sync::Mutex<DaemonChain>>> {
+        match kind {
+            TranslationErrorKind::Reasoning => self.daemon.clone(),
+            // Plan updates are notice-sized texts and ride the notice daemon.
+            TranslationErrorKind::UiNotice | TranslationErrorKind::PlanUpdate => {
+                self.notice_daemon.clone()
+            }
+            TranslationErrorKind::ErrorMessage => self.error_daemon.clone(),
         }
     }
 
     /// Update configuration.
     pub(crate) fn update_config(&mut self, config: TranslationConfig) {
         self.enabled = config.enabled;
+        let glossary_changed = [
+            TranslationErrorKind::Reasoning,
+            TranslationErrorKind::UiNotice,
+            TranslationErrorKind::PlanUpdate,
+            TranslationErrorKind::ErrorMessage,
+        ]
+        .iter()
+        .any(|&kind| config.glossary_for(kind) != self.config.glossary_for(kind));
+        if config.target_language != self.config.target_language
+            || config.source_language != self.config.source_language
+            || glossary_changed
+        {
+            // Cached translations are only valid for the language pair and
+            // glossary they were produced under.
+            TranslationCache::shared().clear();
+        }
+        if config.daemon_command != self.config.daemon_command
+            || config.translators != self.config.translators
+            || config.use_translator != self.config.use_translator
+            || config.fallback_daemon_command != self.config.fallback_daemon_command
+            || config.daemon_schema_version != self.config.daemon_schema_version
+            || config.daemon_idle_timeout_ms != self.config.daemon_idle_timeout_ms
+            || config.reasoning != self.config.reasoning
+            || config.notice != self.config.notice
+            || config.error != self.config.error
+        {
+            let (daemon, notice_daemon, error_daemon) = Self::build_daemons(&config);
+            self.daemon = daemon;
+            self.notice_daemon = notice_daemon;
+            self.error_daemon = error_daemon;
+        }
+        // An edited config may have fixed whatever the health check caught.
+        self.health_checked = false;
+        self.startup_warnings_pending = config.startup_warnings.clone();
         self.config = config;
     }
 
+    /// Record the model now driving the conversation, so `disabled_for_models`
+    /// applies to the very next reasoning block after a `/model` switch.
+    pub(crate) fn set_active_model(&mut self, model: &str) {
+        self.active_model = Some(model.to_string());
+    }
+
+    /// Latest supervision status of the translator daemon.
+    ///
+    /// Returns `None` when no daemon is configured or when the daemon is busy
+    /// serving a request (the status is refreshed on the next call).
+    pub(crate) fn daemon_status(&self) -> Option<DaemonStatus> {
+        let daemon = self.daemon.as_ref()?;
+        daemon.try_lock().ok().map(|daemon| daemon.status())
+    }
+
+    /// Kill and respawn the translator daemons (`/translate restart`).
+    /// Returns false when no daemon is configured.
+    pub(crate) fn restart_daemon(&self) -> bool {
+        // Kinds can share one daemon process; restart each process only once.
+        let mut daemons: Vec<Arc<tokio::sync::Mutex<DaemonChain>>> = Vec::new();
+        for daemon in [
+            self.daemon.clone(),
+            self.notice_daemon.clone(),
+            self.error_daemon.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            if !daemons.iter().any(|known| Arc::ptr_eq(known, &daemon)) {
+                daemons.push(daemon);
+            }
+        }
+        if daemons.is_empty() {
+            return false;
+        }
+        for daemon in daemons {
+            tokio::spawn(async move {
+                if let Err(e) = daemon.lock().await.restart().await {
+                    tracing::warn!(error = %e, "translation daemon restart failed");
+                }
+            });
+        }
+        true
+    }
+
     /// Get current configuration.
     #[allow(dead_code)]
     pub(crate) fn config(&self) -> &TranslationConfig {
@@ -127,6 +525,10 @@ impl ReasoningTranslator {
     /// Set whether translation is enabled.
     #[allow(dead_code)]
     pub(crate) fn set_enabled(&mut self, enabled: bool) {
+        if enabled && !self.enabled {
+            // Re-enabling after a failed health check earns a fresh probe.
+            self.health_checked = false;
+        }
         self.enabled = enabled;
         self.config.enabled = enabled;
     }
@@ -137,6 +539,131 @@ impl ReasoningTranslator {
         self.enabled
     }
 
+    /// Snapshot of cached title translations, keyed by the original title
+    /// and valued with the rendered bilingual display form, so transcript
+    /// views can substitute titles without knowing the template.
+    pub(crate) fn title_translation_cache(&self) -> HashMap<String, String> {
+        let template = self.config.effective_title_template();
+        self.title_translation_cache
+            .iter()
+            .map(|(original, translated)| {
+                (
+                    original.clone(),
+                    render_title_template(template, original, translated),
+                )
+            })
+            .collect()
+    }
+
+    /// Remember a completed title translation, evicting the least recently
+    /// displayed title once the cache is full. Eviction is the hook where a
+    /// persistent title cache would flush its entry, should one land.
+    fn remember_title_translation(&mut self, original: String, translated: String) {
+        self.title_translation_cache.put(original, translated);
+    }
+
+    /// Width-aware bilingual status header for a reasoning title, when a
+    /// cached translation exists. `max_width` is the columns available to
+    /// the header itself, not the full status line.
+    ///
+    /// Takes `&mut self` because a hit marks the title as recently displayed
+    /// so it is not the next eviction candidate. The lookup itself borrows
+    /// `title` and allocates nothing.
+    pub(crate) fn bilingual_status_header(
+        &mut self,
+        title: &str,
+        max_width: usize,
+    ) -> Option<String> {
+        if !self.enabled {
+            return None;
+        }
+        let translated = self.title_translation_cache.get(title)?;
+        Some(fit_bilingual_header(
+            title,
+            translated,
+            self.config.effective_title_template(),
+            max_width,
+            self.config.header_overflow,
+        ))
+    }
+
+    /// Point-in-time counters and flags for status reporting.
+    pub(crate) fn snapshot(&self) -> TranslationOrchestratorSnapshot {
+        TranslationOrchestratorSnapshot {
+            enabled: self.enabled,
+            barrier_active: self.translation_barrier.is_some(),
+            deferred_cells: self.deferred_history_cells.len(),
+            title_cache_size: self.title_translation_cache.len(),
+            notice_cache_size: self.notice_translation_cache.len(),
+            pending_notices: self.notice_translations_pending.len(),
+            requests_started: self.translation_seq,
+            daemon_configured: self.daemon.is_some()
+                || self.notice_daemon.is_some()
+                || self.error_daemon.is_some(),
+        }
+    }
+
+    /// Reset the per-turn summary counters at the start of a new user turn
+    /// and advance the turn index attached to translation requests.
+    pub(crate) fn begin_turn(&mut self, thread_id: Option<ThreadId>) {
+        self.turn_index = Some(self.turn_index.map_or(0, |index| index + 1));
+        self.turn_stats = TurnTranslationStats {
+            thread_id,
+            ..Default::default()
+        };
+    }
+
+    /// Fold one completed translation into the turn counters, ignoring
+    /// results for threads the current turn was not started on.
+    fn record_turn_result(
+        &mut self,
+        thread_id: ThreadId,
+        elapsed: Duration,
+        failure: Option<String>,
+    ) {
+        if self.turn_stats.thread_id != Some(thread_id) {
+            return;
+        }
+        self.turn_stats.total_time += elapsed;
+        match failure {
+            Some(reason) => {
+                self.turn_stats.failed += 1;
+                self.turn_stats.failures.push(reason);
+            }
+            None => self.turn_stats.translated += 1,
+        }
+    }
+
+    /// Emit the end-of-turn summary cell, when `turn_summary = true` and the
+    /// turn had any translation activity, and reset the counters.
+    ///
+    /// The summary goes through the usual deferral path, so when a barrier is
+    /// still holding the last translation it appears after that result.
+    pub(crate) fn finish_turn(&mut self, app_event_tx: &AppEventSender) {
+        let stats = std::mem::take(&mut self.turn_stats);
+        if !self.enabled || !self.config.turn_summary || stats.is_empty() {
+            return;
+        }
+
+        let mut header = format!(
+            "• Translation summary: {} translated, {} failed",
+            stats.translated, stats.failed
+        );
+        if stats.skipped > 0 {
+            header.push_str(&format!(", {} skipped", stats.skipped));
+        }
+        header.push_str(&format!(" in {}ms", stats.total_time.as_millis()));
+
+        let mut lines: Vec<ratatui::text::Line<'static>> = vec![header.into()];
+        for failure in &stats.failures {
+            lines.push(format!("  {failure}").dim().into());
+        }
+        self.emit_history_cell(
+            app_event_tx,
+            Box::new(history_cell::PlainHistoryCell::new(lines)),
+        );
+    }
+
     /// Start translation for reasoning content.
     /// Returns true if translation was started.
     pub(crate) fn maybe_translate_reasoning(
@@ -151,6 +678,21 @@ impl ReasoningTranslator {
         let Some(thread_id) = thread_id else {
             return false;
         };
+        // `[reasoning] enabled = false` switches the kind off while keeping
+        // its command and other settings in the config file.
+        if !self.config.is_kind_enabled(TranslationErrorKind::Reasoning) {
+            return false;
+        }
+        // Some models already reason in the user's language; their output is
+        // configured to stay untranslated.
+        if let Some(model) = self.active_model.as_deref()
+            && self
+                .config
+                .is_translation_disabled_for_model(TranslationErrorKind::Reasoning, model)
+        {
+            tracing::debug!(model, "reasoning translation disabled for this model");
+            return false;
+        }
 
         // Extract title (e.g., "Thinking") for error display
         let title = extract_first_bold(&full_reasoning);
@@ -162,29 +704,112 @@ impl ReasoningTranslator {
         if body.trim().is_empty() {
             return false;
         }
+        // Short bodies are not worth a translator round trip. No barrier is
+        // created, so the sequence number does not advance.
+        if !self
+            .config
+            .should_translate(TranslationErrorKind::Reasoning, &body)
+        {
+            return false;
+        }
+        // The model occasionally reasons in the target language already;
+        // translating then only produces a near-duplicate block. No barrier
+        // is created, so the sequence number does not advance.
+        if language::looks_like_target_language(
+            &body,
+            self.config.effective_target_language(),
+            self.config.effective_same_language_threshold(),
+        ) {
+            tracing::debug!("reasoning already looks like the target language; skipping");
+            return false;
+        }
+
+        self.maybe_start_health_check(frame_requester.clone());
 
         // Begin barrier to ensure translation follows original content
         let Some(request_id) =
             self.begin_barrier(thread_id, title.clone(), frame_requester.clone())
         else {
+            // A barrier is already holding another translation; this block
+            // stays untranslated, which the turn summary reports as skipped.
+            if self.turn_stats.thread_id == Some(thread_id) {
+                self.turn_stats.skipped += 1;
+            }
             return false;
         };
 
         let result_tx = self.results_tx.clone();
+        let error_records_tx = self.error_records_tx.clone();
         let config = self.config.clone();
+        let daemon = self.daemon_for(TranslationErrorKind::Reasoning);
+        let context = TranslateContext {
+            thread_id: Some(thread_id),
+            turn_index: self.turn_index,
+        };
+        // The model driving the conversation, for translators that tune
+        // their prompt per model; follows `/model` switches immediately.
+        let model = self.active_model.clone();
         // Translate the full reasoning (header + body) so translator can produce bilingual output
         let full_reasoning_owned = full_reasoning;
 
+        // Streaming daemons surface partial text while the translation is
+        // underway: each snapshot is forwarded to the results channel, where
+        // it refreshes the barrier deadline and is kept for timeout salvage.
+        let partial_tx = (self.config.streaming && daemon.is_some()).then(|| {
+            let (partial_tx, mut partial_rx) = tokio::sync::mpsc::unbounded_channel::<String>();
+            let result_tx = self.results_tx.clone();
+            let frame_requester = frame_requester.clone();
+            tokio::spawn(async move {
+                while let Some(snapshot) = partial_rx.recv().await {
+                    let msg = TranslationResult::partial_snapshot(request_id, thread_id, snapshot);
+                    if result_tx.send(msg).is_err() {
+                        break;
+                    }
+                    frame_requester.schedule_frame();
+                }
+            });
+            partial_tx
+        });
+
         // Spawn async translation task
         tokio::spawn(async move {
-            let result = Self::do_translate(&config, &full_reasoning_owned).await;
+            let result = Self::translate_with_masking(
+                &config,
+                daemon.clone(),
+                &full_reasoning_owned,
+                context,
+                model,
+                partial_tx,
+            )
+            .await;
 
             let msg = match result {
-                Ok(translated) => {
-                    TranslationResult::new(request_id, thread_id, title, Some(translated), None)
-                }
+                Ok(translated) => TranslationResult::new(
+                    request_id,
+                    thread_id,
+                    title,
+                    Some(translated.text),
+                    None,
+                )
+                .with_metadata(translated.metadata),
                 Err(e) => {
-                    TranslationResult::new(request_id, thread_id, title, None, Some(e.to_string()))
+                    // Rate-limit and circuit-breaker skips never reached the
+                    // translator; keep them out of `/translate errors`.
+                    if !matches!(
+                        e,
+                        super::error::TranslationError::RateLimited { .. }
+                            | super::error::TranslationError::CircuitOpen
+                    ) {
+                        Self::report_translation_error(
+                            &error_records_tx,
+                            daemon.as_ref(),
+                            TranslationErrorKind::Reasoning,
+                            &e,
+                            &full_reasoning_owned,
+                        )
+                        .await;
+                    }
+                    TranslationResult::new(request_id, thread_id, title, None, Some(e.failure()))
                 }
             };
 
@@ -195,13 +820,257 @@ impl ReasoningTranslator {
         true
     }
 
-    /// Perform the actual translation.
+    /// Probe the configured translator once, the first time translation is
+    /// about to be used. The probe runs alongside the first translation; a
+    /// failure surfaces as a one-time warning via [`Self::drain_results`] and
+    /// disables further attempts for the session.
+    fn maybe_start_health_check(&mut self, frame_requester: FrameRequester) {
+        if self.health_checked {
+            return;
+        }
+        self.health_checked = true;
+        let config = self.config.clone();
+        let health_tx = self.health_tx.clone();
+        tokio::spawn(async move {
+            let result = health::check_translator(&config).await;
+            let _ = health_tx.send(result.map_err(|e| e.to_string()));
+            frame_requester.schedule_frame();
+        });
+    }
+
+    /// Translate reasoning text with code-span masking.
+    ///
+    /// When `mask_code` is on, inline code, fenced blocks, URLs, and
+    /// path-like tokens are replaced with placeholders before translation and
+    /// restored afterwards, so translators cannot mangle them. If the
+    /// translator drops a placeholder the restored text would be incomplete;
+    /// in that case a warning is logged and the unmasked text is translated
+    /// as a fallback.
+    async fn translate_with_masking(
+        config: &TranslationConfig,
+        daemon: Option<Arc<tokio::sync::Mutex<DaemonChain>>>,
+        text: &str,
+        context: TranslateContext,
+        model: Option<String>,
+        partial_tx: Option<tokio::sync::mpsc::UnboundedSender<String>>,
+    ) -> Result<TranslatedText, super::error::TranslationError> {
+        let kind = TranslationErrorKind::Reasoning;
+        if !config.mask_code {
+            return Self::do_translate(config, daemon, kind, text, context, model, partial_tx)
+                .await;
+        }
+        let masked = masking::mask_protected_spans(text);
+        if !masked.is_masked() {
+            return Self::do_translate(config, daemon, kind, text, context, model, partial_tx)
+                .await;
+        }
+        // Streamed snapshots of masked text would show raw placeholders, so
+        // partial updates are suppressed until the translation completes and
+        // the placeholders are restored.
+        let mut translated = Self::do_translate(
+            config,
+            daemon.clone(),
+            kind,
+            &masked.masked,
+            context,
+            model.clone(),
+            None,
+        )
+        .await?;
+        let (restored, missing) = masked.restore(&translated.text);
+        if missing == 0 {
+            translated.text = restored;
+            return Ok(translated);
+        }
+        tracing::warn!(
+            missing,
+            total = masked.placeholders.len(),
+            "translator dropped placeholders; falling back to an unmasked translation"
+        );
+        Self::do_translate(config, daemon, kind, text, context, model, partial_tx).await
+    }
+
+    /// Record a translation failure in the bounded error log, attaching the
+    /// daemon's stderr tail for daemon failures.
+    async fn report_translation_error(
+        error_records_tx: &tokio::sync::mpsc::UnboundedSender<TranslationErrorRecord>,
+        daemon: Option<&Arc<tokio::sync::Mutex<DaemonChain>>>,
+        kind: TranslationErrorKind,
+        error: &super::error::TranslationError,
+        input: &str,
+    ) {
+        let stderr_preview = match (daemon, error) {
+            (Some(daemon), super::error::TranslationError::Daemon(_)) => {
+                daemon.lock().await.stderr_preview()
+            }
+            _ => None,
+        };
+        let record = TranslationErrorRecord::new(kind, error, input, stderr_preview);
+        let _ = error_records_tx.send(record);
+    }
+
+    /// Perform the actual translation, consulting the process-wide cache so
+    /// repeated texts never re-run the translator within a session. Cache
+    /// hits carry no detected language or metadata, only the text. On a
+    /// miss, when `debug_log` is configured, a request/response record is
+    /// appended for the actual translator exchange (cache hits are not
+    /// logged). Logging is best effort and never fails the translation.
     async fn do_translate(
         config: &TranslationConfig,
+        daemon: Option<Arc<tokio::sync::Mutex<DaemonChain>>>,
+        kind: TranslationErrorKind,
         text: &str,
-    ) -> Result<String, super::error::TranslationError> {
-        let client = TranslationClient::from_config(config)?;
-        client.translate(text, &config.target_language).await
+        context: TranslateContext,
+        model: Option<String>,
+        partial_tx: Option<tokio::sync::mpsc::UnboundedSender<String>>,
+    ) -> Result<TranslatedText, super::error::TranslationError> {
+        // Cap oversized input (huge diffs echoed into reasoning) before it is
+        // serialized into the child's stdin; the transcript keeps the
+        // untruncated original.
+        let truncated_text;
+        let (text, truncated) = match config
+            .max_input_chars
+            .and_then(|cap| truncate_for_translation(text, cap))
+        {
+            Some(cut) => {
+                tracing::debug!(
+                    original_chars = text.chars().count(),
+                    sent_chars = cut.chars().count(),
+                    "input exceeds max_input_chars; truncated for translation"
+                );
+                truncated_text = cut;
+                (truncated_text.as_str(), true)
+            }
+            None => (text, false),
+        };
+        // Redaction applies only to what is sent to the translator (and, by
+        // extension, the cache key and debug log); the transcript always
+        // renders the original text.
+        let redacted;
+        let text = if config.redact_secrets {
+            redacted = redaction::redact_secrets(text);
+            redacted.as_str()
+        } else {
+            text
+        };
+        let mut extras: Option<(Option<String>, HashMap<String, String>)> = None;
+        let extras_out = &mut extras;
+        let translated_text = TranslationCache::shared()
+            .get_or_translate(kind, text, || async {
+                // A persistently failing translator trips the breaker; fail
+                // fast instead of spawning more doomed processes.
+                circuit_breaker::admit()?;
+                // Meter real translator invocations; cache hits are free.
+                if let Some(limit) = config.max_requests_per_minute {
+                    let max_wait = Duration::from_millis(config.effective_timeout_ms_for(kind));
+                    rate_limit::acquire(limit, max_wait).await?;
+                }
+                let started = Instant::now();
+                let result = Self::dispatch_translate(
+                    config,
+                    daemon,
+                    kind,
+                    text,
+                    context,
+                    truncated,
+                    model.as_deref(),
+                    partial_tx,
+                )
+                .await;
+                match &result {
+                    Ok(_) => circuit_breaker::record_success(),
+                    Err(_) => circuit_breaker::record_failure(
+                        config.effective_failure_threshold(),
+                        Duration::from_millis(config.effective_circuit_reset_ms()),
+                    ),
+                }
+                let failure = result.as_ref().err().map(|e| e.failure());
+                stats::record(kind, failure.as_ref(), started.elapsed());
+                if let Ok(translated) = &result
+                    && let Some(language) = translated.detected_language.as_deref()
+                {
+                    tracing::debug!(%language, "translator detected source language");
+                }
+                let result = result.map(|translated| {
+                    *extras_out = Some((translated.detected_language, translated.metadata));
+                    translated.text
+                });
+                if let Some(debug_log) = TranslationDebugLog::from_config(config) {
+                    debug_log
+                        .record(config, kind, text, &result, started.elapsed())
+                        .await;
+                }
+                result
+            })
+            .await?;
+        let (detected_language, metadata) = extras.unwrap_or_default();
+        Ok(TranslatedText {
+            text: translated_text,
+            detected_language,
+            metadata,
+        })
+    }
+
+    /// Route the request to the supervised daemon when one is configured,
+    /// then the shared MCP tool, then the HTTP endpoint, and the direct
+    /// provider client otherwise.
+    #[allow(clippy::too_many_arguments)]
+    async fn dispatch_translate(
+        config: &TranslationConfig,
+        daemon: Option<Arc<tokio::sync::Mutex<DaemonChain>>>,
+        kind: TranslationErrorKind,
+        text: &str,
+        context: TranslateContext,
+        truncated: bool,
+        model: Option<&str>,
+        partial_tx: Option<tokio::sync::mpsc::UnboundedSender<String>>,
+    ) -> Result<TranslatedText, super::error::TranslationError> {
+        let glossary = config.glossary_for(kind);
+        let options = TranslateOptions {
+            target_language: config.effective_target_language(),
+            source_language: config.effective_source_language(),
+            glossary: glossary.as_ref(),
+            context,
+            truncated,
+            model,
+        };
+        if let Some(daemon) = daemon {
+            if config.streaming
+                && let Some(partial_tx) = partial_tx
+            {
+                let mut on_chunk = |snapshot: &str| {
+                    let _ = partial_tx.send(snapshot.to_string());
+                };
+                return daemon
+                    .lock()
+                    .await
+                    .translate_streaming(text, options, &mut on_chunk)
+                    .await;
+            }
+            return daemon.lock().await.translate(text, options).await;
+        }
+        if let Some((command, tool)) = config.mcp_backend() {
+            return mcp::translate_via_shared(command, tool, text, options).await;
+        }
+        if let Some(url) = config.http_url.as_deref() {
+            let timeout = Duration::from_millis(config.effective_timeout_ms_for(kind));
+            return endpoint::translate_via_endpoint(
+                url,
+                config.http_headers.as_ref(),
+                timeout,
+                text,
+                options,
+            )
+            .await;
+        }
+        let client = TranslationClient::from_config_for_kind(config, kind)?;
+        client
+            .translate(
+                text,
+                config.effective_target_language(),
+                config.effective_source_language(),
+            )
+            .await
     }
 
     /// Drain pending translation results.
@@ -221,6 +1090,37 @@ impl ReasoningTranslator {
             needs_redraw: false,
         };
 
+        while let Ok(result) = self.health_rx.try_recv() {
+            match result {
+                Ok(info) => tracing::info!(
+                    backend = %info.backend,
+                    elapsed_ms = %info.elapsed.as_millis(),
+                    "translator health check passed"
+                ),
+                Err(reason) => {
+                    // Release any barrier the concurrent first translation is
+                    // holding so deferred cells are not stuck behind a
+                    // translator that will never answer.
+                    self.translation_barrier = None;
+                    self.flush_deferred_cells(
+                        active_thread_id,
+                        app_event_tx,
+                        frame_requester.clone(),
+                    );
+                    self.enabled = false;
+                    app_event_tx.send(AppEvent::InsertHistoryCell(Box::new(
+                        history_cell::new_warning_event(format!(
+                            "Translator health check failed: {reason}. Translation is \
+                             disabled for this session; fix the configuration and run \
+                             /translate on to retry."
+                        )),
+                    )));
+                    out.needs_redraw = true;
+                    return out;
+                }
+            }
+        }
+
         loop {
             match self.results_rx.try_recv() {
                 Ok(msg) => {
@@ -253,6 +1153,8 @@ impl ReasoningTranslator {
             title,
             translated,
             error,
+            metadata,
+            partial,
         } = msg;
 
         // Validate barrier is still active and matches
@@ -272,10 +1174,39 @@ impl ReasoningTranslator {
             };
         }
 
+        if partial {
+            // A streamed snapshot: keep it for timeout salvage and push the
+            // deadline out, since each chunk proves the translator is alive
+            // and still working. The deadline then measures silence rather
+            // than total streaming time.
+            let max_wait = barrier.max_wait;
+            if let Some(barrier) = self.translation_barrier.as_mut() {
+                barrier.partial = translated;
+                barrier.deadline = Instant::now()
+                    .checked_add(max_wait)
+                    .unwrap_or_else(Instant::now);
+            }
+            frame_requester.schedule_frame_in(max_wait);
+            return OnTranslationResult {
+                needs_redraw: false,
+            };
+        }
+
+        let elapsed = barrier.started.elapsed();
         // Release barrier before inserting content
         self.translation_barrier = None;
 
         if let Some(translated) = translated {
+            self.record_turn_result(thread_id, elapsed, None);
+            // Remember the translated title so transcript views can map known
+            // titles to their bilingual form later.
+            if let Some(original) = title.as_deref()
+                && let Some(translated_title) = extract_first_bold(&translated)
+                && translated_title != original
+            {
+                self.remember_title_translation(original.to_string(), translated_title);
+            }
+
             // Extract body for display; translated content already contains the title
             // (e.g., "**思考中**\n内容...")
             let translated_body = extract_reasoning_body(&translated)
@@ -283,6 +1214,11 @@ impl ReasoningTranslator {
                 .trim()
                 .to_string();
 
+            let footer = self
+                .config
+                .show_translation_metadata
+                .then(|| metadata_footer(&metadata))
+                .flatten();
             self.emit_history_cell(
                 app_event_tx,
                 history_cell::new_agent_reasoning_translation_block(
@@ -292,18 +1228,52 @@ impl ReasoningTranslator {
                     } else {
                         translated_body
                     },
+                    footer,
                 ),
             );
+        } else if matches!(error, Some(TranslationFailure::CircuitOpen)) {
+            // The breaker already decided nothing should be spawned; show a
+            // single warning for the session, then skip silently.
+            if self.turn_stats.thread_id == Some(thread_id) {
+                self.turn_stats.skipped += 1;
+            }
+            if !self.circuit_notice_shown {
+                self.circuit_notice_shown = true;
+                app_event_tx.send(AppEvent::InsertHistoryCell(Box::new(
+                    history_cell::new_warning_event(
+                        "Translation disabled after repeated failures; it will be \
+                         retried automatically after a cool-down."
+                            .to_string(),
+                    ),
+                )));
+            }
+        } else if let Some(TranslationFailure::RateLimited { retry_after_ms }) = error {
+            // Rate limiting is expected back-pressure, not a translator
+            // fault: skip the block silently instead of raising an error
+            // cell for every reasoning section past the budget.
+            if self.turn_stats.thread_id == Some(thread_id) {
+                self.turn_stats.skipped += 1;
+            }
+            tracing::debug!(retry_after_ms, "translation skipped: rate limit reached");
         } else {
-            let reason = error.unwrap_or_else(|| "unknown error".to_string());
+            let reason = error
+                .as_ref()
+                .map_or_else(|| "unknown error".to_string(), ToString::to_string);
+            self.record_turn_result(thread_id, elapsed, Some(reason.clone()));
             tracing::warn!(
                 title = title.as_deref().unwrap_or("unknown"),
                 error = %reason,
                 "translation failed"
             );
+            // Attach kind-specific advice so a misconfiguration can be fixed
+            // without digging through logs.
+            let displayed = match error.as_ref().and_then(TranslationFailure::hint) {
+                Some(hint) => format!("{reason} ({hint})"),
+                None => reason,
+            };
             self.emit_history_cell(
                 app_event_tx,
-                history_cell::new_agent_reasoning_translation_error_block(title, reason),
+                history_cell::new_agent_reasoning_translation_error_block(title, displayed),
             );
         }
 
@@ -330,7 +1300,10 @@ impl ReasoningTranslator {
         }
 
         let title = barrier.title.clone();
-        let max_wait_ms = barrier.max_wait.as_millis();
+        let max_wait = barrier.max_wait;
+        let max_wait_ms = max_wait.as_millis();
+        let barrier_thread_id = barrier.thread_id;
+        let partial = barrier.partial.clone();
 
         // Release barrier
         self.translation_barrier = None;
@@ -342,14 +1315,34 @@ impl ReasoningTranslator {
             "translation timeout, barrier released"
         );
 
-        // Insert error block with title
-        self.emit_history_cell(
-            app_event_tx,
-            history_cell::new_agent_reasoning_translation_error_block(
-                title,
-                format!("Translation timeout ({max_wait_ms}ms)"),
-            ),
-        );
+        let reason = format!("Translation timeout ({max_wait_ms}ms)");
+        // The request never landed, so the full max wait counts as time spent.
+        self.record_turn_result(barrier_thread_id, max_wait, Some(reason.clone()));
+
+        if let Some(partial) = partial {
+            // A streaming translator got partway before going silent; the
+            // chunks that did arrive beat a bare timeout error.
+            let body = extract_reasoning_body(&partial)
+                .unwrap_or_else(|| partial.clone())
+                .trim()
+                .to_string();
+            self.emit_history_cell(
+                app_event_tx,
+                history_cell::new_agent_reasoning_translation_block(
+                    None,
+                    if body.is_empty() { partial } else { body },
+                    Some(format!(
+                        "partial: translator went silent after {max_wait_ms}ms"
+                    )),
+                ),
+            );
+        } else {
+            // Insert error block with title
+            self.emit_history_cell(
+                app_event_tx,
+                history_cell::new_agent_reasoning_translation_error_block(title, reason),
+            );
+        }
 
         self.flush_deferred_cells(active_thread_id, app_event_tx, frame_requester);
         true
@@ -359,25 +1352,180 @@ impl ReasoningTranslator {
     pub(crate) fn emit_history_cell(
         &mut self,
         app_event_tx: &AppEventSender,
-        cell: Box<dyn HistoryCell>,
+        mut cell: Box<dyn HistoryCell>,
     ) {
+        self.maybe_translate_notice(&mut cell);
+        self.maybe_translate_plan_update(&mut cell);
         if self.translation_barrier.is_some() {
             self.deferred_history_cells.push_back(cell);
+            self.sync_journal();
         } else {
             app_event_tx.send(AppEvent::InsertHistoryCell(cell));
         }
     }
 
+    /// Rewrite a UI-notice cell with a cached translation, or start a
+    /// background translation on a cache miss.
+    ///
+    /// Notices are never delayed: a miss leaves the English text in place and
+    /// only warms the cache for the next occurrence. Any failure is silent.
+    fn maybe_translate_notice(&mut self, cell: &mut Box<dyn HistoryCell>) {
+        if !self.enabled {
+            return;
+        }
+        let Some(notice) = cell
+            .as_any_mut()
+            .downcast_mut::<history_cell::UiNoticeCell>()
+        else {
+            return;
+        };
+        // Error notices have their own opt-in flag and translation kind so
+        // they get the short error timeout.
+        let kind = if notice.is_error() {
+            TranslationErrorKind::ErrorMessage
+        } else {
+            TranslationErrorKind::UiNotice
+        };
+        let wanted = match kind {
+            TranslationErrorKind::ErrorMessage => self.config.translate_errors,
+            _ => self.config.translate_ui_notices,
+        };
+        if !wanted || !self.config.is_kind_enabled(kind) {
+            return;
+        }
+        if !self.config.should_translate(kind, notice.notice_text()) {
+            return;
+        }
+
+        let (masked, literals) = protect_notice_literals(notice.notice_text());
+        if let Some(translated) = self.notice_translation_cache.get(&masked) {
+            notice.set_translated_message(restore_notice_literals(translated, &literals));
+            return;
+        }
+        if !self.notice_translations_pending.insert(masked.clone()) {
+            return;
+        }
+        self.spawn_notice_translation(masked, kind);
+    }
+
+    /// Rewrite a plan-update cell's explanation note with a cached
+    /// translation, or start a background translation on a cache miss.
+    ///
+    /// Plan updates follow the notice discipline: a miss leaves the English
+    /// note in place and warms the cache for the next update. They share the
+    /// notice cache, so an active barrier never blocks on them — deferred
+    /// cells drain first and the translation lands whenever it is ready.
+    fn maybe_translate_plan_update(&mut self, cell: &mut Box<dyn HistoryCell>) {
+        if !self.enabled || !self.config.translate_plan_updates {
+            return;
+        }
+        let Some(plan) = cell
+            .as_any_mut()
+            .downcast_mut::<history_cell::PlanUpdateCell>()
+        else {
+            return;
+        };
+        let Some(explanation) = plan.explanation_text().map(str::to_string) else {
+            return;
+        };
+        if !self
+            .config
+            .should_translate(TranslationErrorKind::PlanUpdate, &explanation)
+        {
+            return;
+        }
+
+        let (masked, literals) = protect_notice_literals(&explanation);
+        if let Some(translated) = self.notice_translation_cache.get(&masked) {
+            plan.set_translated_explanation(restore_notice_literals(translated, &literals));
+            return;
+        }
+        if !self.notice_translations_pending.insert(masked.clone()) {
+            return;
+        }
+        self.spawn_notice_translation(masked, TranslationErrorKind::PlanUpdate);
+    }
+
+    /// Spawn a background notice-style translation of `masked`, reporting the
+    /// result on the notice channel. Failures are suppressed from the
+    /// transcript but recorded for `/translate errors`.
+    fn spawn_notice_translation(&self, masked: String, kind: TranslationErrorKind) {
+        let notice_tx = self.notice_results_tx.clone();
+        let error_records_tx = self.error_records_tx.clone();
+        let config = self.config.clone();
+        let daemon = self.daemon_for(kind);
+        tokio::spawn(async move {
+            // Notices are not tied to a conversation, so no context rides
+            // along with them.
+            let context = TranslateContext::default();
+            let result =
+                Self::do_translate(&config, daemon.clone(), kind, &masked, context, None, None)
+                    .await;
+            let translated = match result {
+                Ok(translated) => Some(translated.text),
+                Err(e) => {
+                    // Suppressed from the transcript, but still recorded so
+                    // `/translate errors` can surface it.
+                    tracing::debug!(error = %e, kind = kind.as_str(), "translation failed");
+                    Self::report_translation_error(
+                        &error_records_tx,
+                        daemon.as_ref(),
+                        kind,
+                        &e,
+                        &masked,
+                    )
+                    .await;
+                    None
+                }
+            };
+            let _ = notice_tx.send((masked, translated));
+        });
+    }
+
+    /// Drain failure records reported by background translation tasks.
+    fn drain_error_records(&mut self) {
+        while let Ok(record) = self.error_records_rx.try_recv() {
+            self.error_log.record(record);
+        }
+    }
+
+    /// Recorded translation failures for `/translate errors`, after folding in
+    /// any records still queued from background tasks.
+    pub(crate) fn error_log(&mut self) -> &TranslationErrorLog {
+        self.drain_error_records();
+        &self.error_log
+    }
+
+    /// Drop the recorded failures (`/translate errors clear`).
+    pub(crate) fn clear_error_log(&mut self) {
+        self.drain_error_records();
+        self.error_log.clear();
+    }
+
+    /// Drain completed background notice translations into the cache.
+    fn drain_notice_results(&mut self) {
+        while let Ok((masked, translated)) = self.notice_results_rx.try_recv() {
+            self.notice_translations_pending.remove(&masked);
+            if let Some(translated) = translated {
+                self.notice_translation_cache
+                    .insert(masked, translated.trim().to_string());
+            }
+        }
+    }
+
     /// Emit a history cell and potentially start translation.
     pub(crate) fn emit_history_cell_with_translation_hook(
         &mut self,
         app_event_tx: &AppEventSender,
         active_thread_id: Option<ThreadId>,
         frame_requester: FrameRequester,
-        cell: Box<dyn HistoryCell>,
+        mut cell: Box<dyn HistoryCell>,
     ) {
+        self.maybe_translate_notice(&mut cell);
+        self.maybe_translate_plan_update(&mut cell);
         if self.translation_barrier.is_some() {
             self.deferred_history_cells.push_back(cell);
+            self.sync_journal();
             return;
         }
 
@@ -401,18 +1549,40 @@ impl ReasoningTranslator {
         app_event_tx: &AppEventSender,
         frame_requester: FrameRequester,
     ) -> OnTranslationResult {
+        // Config-load warnings surface even when the bad config left
+        // translation disabled; that is exactly when the user needs them.
+        let had_warnings = !self.startup_warnings_pending.is_empty();
+        for warning in std::mem::take(&mut self.startup_warnings_pending) {
+            app_event_tx.send(AppEvent::InsertHistoryCell(Box::new(
+                history_cell::new_warning_event(format!("translation.toml: {warning}")),
+            )));
+        }
+
         if !self.enabled {
             return OnTranslationResult {
-                needs_redraw: false,
+                needs_redraw: had_warnings,
             };
         }
 
+        // Replay cells a killed predecessor left journaled, before anything
+        // from this session, so resumed transcripts keep their order.
+        if !self.journal_recovered {
+            self.journal_recovered = true;
+            for cell in self.recover_journaled_cells() {
+                self.emit_history_cell(app_event_tx, cell);
+            }
+        }
+
+        self.drain_notice_results();
+        self.drain_error_records();
+
         let mut result =
             self.drain_results(active_thread_id, app_event_tx, frame_requester.clone());
 
         if self.maybe_flush_timeout(active_thread_id, app_event_tx, frame_requester) {
             result.needs_redraw = true;
         }
+        result.needs_redraw |= had_warnings;
 
         result
     }
@@ -449,6 +1619,8 @@ impl ReasoningTranslator {
                 }
             }
         }
+        // Reconcile the journal with whatever is still deferred (or clear it).
+        self.sync_journal();
     }
 
     fn begin_barrier(
@@ -476,6 +1648,8 @@ impl ReasoningTranslator {
             title,
             max_wait,
             deadline,
+            started: Instant::now(),
+            partial: None,
         });
 
         // Schedule a frame for timeout handling
@@ -503,6 +1677,150 @@ impl ReasoningTranslator {
     }
 }
 
+/// Replace numbers and path-like tokens in a notice with opaque placeholders.
+///
+/// The masked text doubles as the cache key, so notices that differ only in a
+/// count or a path share one cached translation. Returns the masked text and
+/// the protected tokens in placeholder order.
+/// Format translator-reported metadata as a one-line footer: the values,
+/// joined in key order, e.g. `{"engine": "deepl", "latency": "312ms"}`
+/// becomes `deepl · 312ms`. Keys are not shown; translators are expected to
+/// report display-ready values.
+fn metadata_footer(metadata: &HashMap<String, String>) -> Option<String> {
+    let mut entries: Vec<(&str, &str)> = metadata
+        .iter()
+        .map(|(key, value)| (key.as_str(), value.trim()))
+        .filter(|(_, value)| !value.is_empty())
+        .collect();
+    if entries.is_empty() {
+        return None;
+    }
+    entries.sort_by_key(|(key, _)| *key);
+    Some(
+        entries
+            .into_iter()
+            .map(|(_, value)| value)
+            .collect::<Vec<_>>()
+            .join(" · "),
+    )
+}
+
+fn protect_notice_literals(text: &str) -> (String, Vec<String>) {
+    let mut literals = Vec::new();
+    let mut masked = String::with_capacity(text.len());
+    let mut first = true;
+    for token in text.split(' ') {
+        if !first {
+            masked.push(' ');
+        }
+        first = false;
+        let protected = token.contains('/')
+            || token.contains('\\')
+            || token.starts_with('~')
+            || token.chars().any(|c| c.is_ascii_digit());
+        if protected {
+            masked.push_str(&format!("⟦{}⟧", literals.len()));
+            literals.push(token.to_string());
+        } else {
+            masked.push_str(token);
+        }
+    }
+    (masked, literals)
+}
+
+/// Substitute protected tokens back into a translated notice.
+///
+/// Placeholders the translator dropped or mangled are left as-is; the result
+/// is still readable and the next cache hit gets another chance.
+fn restore_notice_literals(translated: &str, literals: &[String]) -> String {
+    let mut restored = translated.to_string();
+    for (i, literal) in literals.iter().enumerate() {
+        restored = restored.replace(&format!("⟦{i}⟧"), literal);
+    }
+    restored
+}
+
+/// Render a bilingual title template, substituting the `{original}` and
+/// `{translated}` placeholders.
+pub(crate) fn render_title_template(template: &str, original: &str, translated: &str) -> String {
+    template
+        .replace("{original}", original)
+        .replace("{translated}", translated)
+}
+
+/// Marker appended to translator input cut at `max_input_chars`.
+const TRUNCATION_MARKER: &str = "… [truncated for translation]";
+
+/// Cut `text` down for translation so marker and content together stay
+/// within `max_chars` characters, preferring the last paragraph boundary
+/// before the cap so the translator is not handed half a sentence. Returns
+/// `None` when the text is already within the cap.
+fn truncate_for_translation(text: &str, max_chars: usize) -> Option<String> {
+    // Within the cap: nothing to do. `nth` avoids walking a multi-megabyte
+    // body to the end just to learn it is oversized.
+    text.char_indices().nth(max_chars)?;
+    let budget = max_chars.saturating_sub(TRUNCATION_MARKER.chars().count() + 2);
+    let cut_byte = text
+        .char_indices()
+        .nth(budget)
+        .map(|(index, _)| index)
+        .unwrap_or(0);
+    let head = &text[..cut_byte];
+    let head = match head.rfind("\n\n") {
+        Some(boundary) if boundary > 0 => &head[..boundary],
+        _ => head,
+    };
+    Some(format!("{}\n\n{TRUNCATION_MARKER}", head.trim_end()))
+}
+
+/// Width-aware bilingual header: use the full bilingual form when it fits
+/// `max_width` columns, otherwise degrade per the configured overflow policy.
+///
+/// Widths are display columns (CJK characters are two columns wide), and
+/// truncation always cuts on character boundaries so no glyph is split at
+/// the edge of the header area.
+pub(crate) fn fit_bilingual_header(
+    original: &str,
+    translated: &str,
+    template: &str,
+    max_width: usize,
+    overflow: HeaderOverflow,
+) -> String {
+    let bilingual = render_title_template(template, original, translated);
+    if UnicodeWidthStr::width(bilingual.as_str()) <= max_width {
+        return bilingual;
+    }
+    let preferred = match overflow {
+        HeaderOverflow::Translated => translated,
+        HeaderOverflow::Original => original,
+        HeaderOverflow::Truncate => bilingual.as_str(),
+    };
+    if UnicodeWidthStr::width(preferred) <= max_width {
+        return preferred.to_string();
+    }
+    truncate_on_char_boundary(preferred, max_width)
+}
+
+/// Truncate to at most `max_width` display columns, ending with an ellipsis.
+fn truncate_on_char_boundary(text: &str, max_width: usize) -> String {
+    if max_width == 0 {
+        return String::new();
+    }
+    let budget = max_width - 1;
+    let mut out = String::new();
+    let mut used = 0usize;
+    for ch in text.chars() {
+        let char_width = UnicodeWidthChar::width(ch).unwrap_or(0);
+        if used + char_width > budget {
+            break;
+        }
+        out.push(ch);
+        used += char_width;
+    }
+    out.push('…');
+    out
+}
+
 /// Extract the first bold text (e.g., "Thinking" from "**Thinking**").
 fn extract_first_bold(s: &str) -> Option<String> {
     let bytes = s.as_bytes();
@@ -549,3 +1867,864 @@ fn extract_reasoning_body(full_reasoning: &str) -> Option<String> {
         Some(body.to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn protect_notice_literals_masks_numbers_and_paths() {
+        let (masked, literals) = protect_notice_literals("Saved 3 files to /tmp/out in 120ms");
+
+        assert_eq!(masked, "Saved ⟦0⟧ files to ⟦1⟧ in ⟦2⟧");
+        assert_eq!(literals, vec!["3", "/tmp/out", "120ms"]);
+    }
+
+    #[test]
+    fn restore_notice_literals_round_trips() {
+        let original = "Model set to gpt-5 for ~/project";
+        let (masked, literals) = protect_notice_literals(original);
+
+        assert_eq!(restore_notice_literals(&masked, &literals), original);
+    }
+
+    #[test]
+    fn protect_notice_literals_leaves_plain_text_alone() {
+        let (masked, literals) = protect_notice_literals("Configuration saved");
+
+        assert_eq!(masked, "Configuration saved");
+        assert!(literals.is_empty());
+    }
+
+    #[test]
+    fn snapshot_reflects_config_and_starts_at_zero() {
+        let translator = ReasoningTranslator::from_config(TranslationConfig {
+            enabled: true,
+            ..Default::default()
+        });
+
+        let snapshot = translator.snapshot();
+        assert_eq!(
+            snapshot,
+            TranslationOrchestratorSnapshot {
+                enabled: true,
+                barrier_active: false,
+                deferred_cells: 0,
+                title_cache_size: 0,
+                notice_cache_size: 0,
+                pending_notices: 0,
+                requests_started: 0,
+                daemon_configured: false,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn journaled_deferred_cells_survive_a_kill_mid_barrier() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let journal_path = dir.path().join("deferred.jsonl");
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let app_event_tx = AppEventSender::new(tx);
+
+        let mut translator = ReasoningTranslator::from_config(TranslationConfig {
+            enabled: true,
+            ..Default::default()
+        });
+        translator.set_journal_for_tests(DeferredCellJournal::new(journal_path.clone()));
+        translator
+            .begin_barrier(
+                ThreadId::new(),
+                Some("Thinking".to_string()),
+                FrameRequester::test_dummy(),
+            )
+            .expect("barrier");
+
+        translator.emit_history_cell(
+            &app_event_tx,
+            Box::new(history_cell::new_info_event("first".to_string(), None)),
+        );
+        translator.emit_history_cell(
+            &app_event_tx,
+            Box::new(history_cell::new_info_event("second".to_string(), None)),
+        );
+        // Nothing reached the history while the barrier was up.
+        assert!(rx.try_recv().is_err());
+        let snapshot = translator.snapshot();
+        assert!(snapshot.barrier_active);
+        assert_eq!(snapshot.deferred_cells, 2);
+
+        // The "kill": the deferred queue dies with the process.
+        drop(translator);
+
+        let mut resumed = ReasoningTranslator::from_config(TranslationConfig {
+            enabled: true,
+            ..Default::default()
+        });
+        resumed.set_journal_for_tests(DeferredCellJournal::new(journal_path.clone()));
+        let recovered = resumed.recover_journaled_cells();
+        let texts: Vec<String> = recovered
+            .iter()
+            .map(|cell| {
+                cell.raw_lines()
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            })
+            .collect();
+        assert_eq!(texts, vec!["• first", "• second"]);
+        // Recovery consumed the journal.
+        assert!(!journal_path.exists());
+    }
+
+    #[tokio::test]
+    async fn turn_summary_reports_successes_and_timeouts() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let app_event_tx = AppEventSender::new(tx);
+
+        let mut translator = ReasoningTranslator::from_config(TranslationConfig {
+            enabled: true,
+            turn_summary: true,
+            timeout_ms: Some(5000),
+            ..Default::default()
+        });
+        translator
+            .set_journal_for_tests(DeferredCellJournal::new(dir.path().join("deferred.jsonl")));
+        let thread_id = ThreadId::new();
+        translator.begin_turn(Some(thread_id));
+
+        // Two translations that complete normally.
+        for translated in ["**思考中**\n第一段", "**思考中**\n第二段"] {
+            let request_id = translator
+                .begin_barrier(
+                    thread_id,
+                    Some("Thinking".to_string()),
+                    FrameRequester::test_dummy(),
+                )
+                .expect("barrier");
+            translator.on_translation_completed(
+                TranslationResult::new(
+                    request_id,
+                    thread_id,
+                    Some("Thinking".to_string()),
+                    Some(translated.to_string()),
+                    None,
+                ),
+                Some(thread_id),
+                &app_event_tx,
+                FrameRequester::test_dummy(),
+            );
+        }
+
+        // A third translation whose result never lands: expire its barrier.
+        translator
+            .begin_barrier(
+                thread_id,
+                Some("Thinking".to_string()),
+                FrameRequester::test_dummy(),
+            )
+            .expect("barrier");
+        if let Some(barrier) = translator.translation_barrier.as_mut() {
+            barrier.deadline = Instant::now();
+        }
+        assert!(translator.maybe_flush_timeout(
+            Some(thread_id),
+            &app_event_tx,
+            FrameRequester::test_dummy(),
+        ));
+
+        translator.finish_turn(&app_event_tx);
+
+        let mut cells = Vec::new();
+        while let Ok(event) = rx.try_recv() {
+            if let AppEvent::InsertHistoryCell(cell) = event {
+                cells.push(cell);
+            }
+        }
+        // Two translation blocks, one timeout error block, then the summary.
+        assert_eq!(cells.len(), 4);
+        let summary: Vec<String> = cells[3]
+            .raw_lines()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert!(
+            summary[0].starts_with("• Translation summary: 2 translated, 1 failed in "),
+            "unexpected header: {}",
+            summary[0]
+        );
+        assert_eq!(summary[1], "  Translation timeout (5000ms)");
+
+        // The counters were consumed; a quiet follow-up turn emits nothing.
+        translator.begin_turn(Some(thread_id));
+        translator.finish_turn(&app_event_tx);
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn failed_health_check_warns_once_and_disables_translation() {
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let app_event_tx = AppEventSender::new(tx);
+
+        let mut translator = ReasoningTranslator::from_config(TranslationConfig {
+            enabled: true,
+            ..Default::default()
+        });
+        translator
+            .health_tx
+            .send(Err("daemon failed to spawn".to_string()))
+            .expect("send health result");
+
+        let result = translator.drain_results(None, &app_event_tx, FrameRequester::test_dummy());
+        assert!(result.needs_redraw);
+        assert!(!translator.is_enabled());
+
+        let AppEvent::InsertHistoryCell(cell) = rx.try_recv().expect("warning cell") else {
+            panic!("expected a history cell");
+        };
+        let text: String = cell
+            .raw_lines()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert!(text.contains("Translator health check failed"));
+        assert!(text.contains("daemon failed to spawn"));
+
+        // Disabled: later drains do nothing and warn nothing.
+        let result = translator.drain_results(None, &app_event_tx, FrameRequester::test_dummy());
+        assert!(!result.needs_redraw);
+        assert!(rx.try_recv().is_err());
+
+        // Re-enabling forgives the failure and allows a fresh probe.
+        translator.set_enabled(true);
+        assert!(!translator.health_checked);
+    }
+
+    #[test]
+    fn fit_bilingual_header_degrades_by_policy_and_width() {
+        use super::super::config::DEFAULT_TITLE_TEMPLATE;
+
+        let template = DEFAULT_TITLE_TEMPLATE;
+        let original = "Exploring the codebase";
+        let translated = "探索代码库";
+
+        // 22 + 3 + 10 columns: the full bilingual form fits at 40.
+        assert_eq!(
+            fit_bilingual_header(
+                original,
+                translated,
+                template,
+                40,
+                HeaderOverflow::Translated
+            ),
+            "Exploring the codebase · 探索代码库"
+        );
+        // At 30 columns the bilingual form overflows; each policy picks a side.
+        assert_eq!(
+            fit_bilingual_header(
+                original,
+                translated,
+                template,
+                30,
+                HeaderOverflow::Translated
+            ),
+            "探索代码库"
+        );
+        assert_eq!(
+            fit_bilingual_header(original, translated, template, 30, HeaderOverflow::Original),
+            "Exploring the codebase"
+        );
+        assert_eq!(
+            fit_bilingual_header(original, translated, template, 30, HeaderOverflow::Truncate),
+            "Exploring the codebase · 探索…"
+        );
+        // Narrow fallbacks cut CJK text on character boundaries, never mid-glyph.
+        assert_eq!(
+            fit_bilingual_header(
+                original,
+                translated,
+                template,
+                7,
+                HeaderOverflow::Translated
+            ),
+            "探索代…"
+        );
+        assert_eq!(
+            fit_bilingual_header(
+                original,
+                translated,
+                template,
+                5,
+                HeaderOverflow::Translated
+            ),
+            "探索…"
+        );
+    }
+
+    #[test]
+    fn bilingual_status_header_requires_a_cached_translation() {
+        let mut translator = ReasoningTranslator::from_config(TranslationConfig {
+            enabled: true,
+            ..Default::default()
+        });
+        assert_eq!(translator.bilingual_status_header("Thinking", 40), None);
+
+        translator.remember_title_translation("Thinking".to_string(), "思考中".to_string());
+        assert_eq!(
+            translator
+                .bilingual_status_header("Thinking", 40)
+                .as_deref(),
+            Some("Thinking · 思考中")
+        );
+        // Too narrow for the bilingual form: the default policy keeps only
+        // the translated title.
+        assert_eq!(
+            translator
+                .bilingual_status_header("Thinking", 10)
+                .as_deref(),
+            Some("思考中")
+        );
+    }
+
+    #[test]
+    fn custom_title_template_shapes_headers_and_cached_titles() {
+        let mut translator = ReasoningTranslator::from_config(TranslationConfig {
+            enabled: true,
+            title_template: Some("「{translated}」{original}".to_string()),
+            ..Default::default()
+        });
+        translator
+            .remember_title_translation("深く考える".to_string(), "Thinking hard".to_string());
+        assert_eq!(
+            translator
+                .bilingual_status_header("深く考える", 40)
+                .as_deref(),
+            Some("「Thinking hard」深く考える")
+        );
+        assert_eq!(
+            translator
+                .title_translation_cache()
+                .get("深く考える")
+                .map(String::as_str),
+            Some("「Thinking hard」深く考える")
+        );
+    }
+
+    #[tokio::test]
+    async fn target_language_reasoning_skips_translation_without_a_barrier() {
+        let mut translator = ReasoningTranslator::from_config(TranslationConfig {
+            enabled: true,
+            target_language: "zh-CN".to_string(),
+            ..Default::default()
+        });
+        let started = translator.maybe_translate_reasoning(
+            Some(ThreadId::new()),
+            "**思考中**\n推理内容已经是中文，再翻译只会得到重复的段落，因此协调器直接跳过这一段，不再生成屏障。"
+                .to_string(),
+            FrameRequester::test_dummy(),
+        );
+        assert!(!started);
+        assert!(!translator.snapshot().barrier_active);
+    }
+
+    #[tokio::test]
+    async fn short_reasoning_skips_translation_without_advancing_the_sequence() {
+        let mut translator = ReasoningTranslator::from_config(TranslationConfig {
+            enabled: true,
+            target_language: "zh-CN".to_string(),
+            ..Default::default()
+        });
+        let started = translator.maybe_translate_reasoning(
+            Some(ThreadId::new()),
+            "**Thinking**\nShort note.".to_string(),
+            FrameRequester::test_dummy(),
+        );
+        assert!(!started);
+        // No barrier was created, so the request sequence did not advance and
+        // later results still match their request ids.
+        let snapshot = translator.snapshot();
+        assert!(!snapshot.barrier_active);
+        assert_eq!(snapshot.requests_started, 0);
+    }
+
+    #[tokio::test]
+    async fn reasoning_from_a_disabled_model_is_not_translated() {
+        let mut translator = ReasoningTranslator::from_config(TranslationConfig {
+            enabled: true,
+            target_language: "zh-CN".to_string(),
+            disabled_for_models: Some(vec!["*-mini".to_string()]),
+            ..Default::default()
+        });
+        let body = "**Thinking**\nA reasoning body comfortably past the minimum length threshold."
+            .to_string();
+
+        translator.set_active_model("gpt-5.1-codex-mini");
+        let started = translator.maybe_translate_reasoning(
+            Some(ThreadId::new()),
+            body.clone(),
+            FrameRequester::test_dummy(),
+        );
+        assert!(!started);
+        assert!(!translator.snapshot().barrier_active);
+
+        // Switching models mid-session re-enables translation immediately.
+        translator.set_active_model("gpt-5.1-codex");
+        let started = translator.maybe_translate_reasoning(
+            Some(ThreadId::new()),
+            body,
+            FrameRequester::test_dummy(),
+        );
+        assert!(started);
+    }
+
+    #[tokio::test]
+    async fn disabled_reasoning_kind_skips_translation_despite_a_command() {
+        let mut translator = ReasoningTranslator::from_config(TranslationConfig {
+            enabled: true,
+            target_language: "zh-CN".to_string(),
+            daemon_command: Some(vec!["/usr/local/bin/translate".to_string()]),
+            reasoning: Some(super::super::config::KindOverrides {
+                enabled: Some(false),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+        let started = translator.maybe_translate_reasoning(
+            Some(ThreadId::new()),
+            "**Thinking**\nA reasoning body comfortably past the minimum length threshold."
+                .to_string(),
+            FrameRequester::test_dummy(),
+        );
+        assert!(!started);
+        assert!(!translator.snapshot().barrier_active);
+    }
+
+    #[test]
+    fn truncate_for_translation_prefers_paragraph_boundaries() {
+        let text = "first paragraph.\n\nsecond paragraph.\n\nthird paragraph runs long.";
+        let cut = truncate_for_translation(text, 50).expect("over the cap");
+        assert_eq!(cut, format!("first paragraph.\n\n{TRUNCATION_MARKER}"));
+        assert!(cut.chars().count() <= 50);
+
+        // No paragraph boundary before the cap: hard cut on a character
+        // boundary, never mid-glyph.
+        let text = "就".repeat(100);
+        let cut = truncate_for_translation(&text, 40).expect("over the cap");
+        assert_eq!(cut, format!("{}\n\n{TRUNCATION_MARKER}", "就".repeat(9)));
+
+        // Within the cap: untouched.
+        assert_eq!(truncate_for_translation("short", 50), None);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn oversized_input_is_truncated_before_reaching_the_daemon() {
+        use std::os::unix::fs::PermissionsExt;
+        let dir = tempfile::tempdir().expect("tempdir");
+        let capture = dir.path().join("request.jsonl");
+        let script = dir.path().join("capture-daemon.sh");
+        let body = format!(
+            r#"#!/bin/sh
+while read line; do
+  printf '%s\n' "$line" >> {capture}
+  id=$(printf '%s' "$line" | sed 's/.*"id":\([0-9]*\).*/\1/')
+  printf '{{"id":%s,"translated":"译文"}}\n' "$id"
+done
+"#,
+            capture = capture.display()
+        );
+        std::fs::write(&script, body).expect("write stub daemon");
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755))
+            .expect("chmod stub daemon");
+
+        let config = TranslationConfig {
+            enabled: true,
+            target_language: "zh-CN".to_string(),
+            daemon_command: Some(vec![script.to_string_lossy().into_owned()]),
+            max_input_chars: Some(1_000),
+            ..Default::default()
+        };
+        let daemon = Arc::new(tokio::sync::Mutex::new(DaemonChain::new(
+            vec![script.to_string_lossy().into_owned()],
+            None,
+        )));
+        // A multi-megabyte body; only the first ~1000 characters may leave
+        // the process.
+        let huge = "A paragraph of reasoning echoing a huge diff.\n\n".repeat(50_000);
+
+        let translated = ReasoningTranslator::do_translate(
+            &config,
+            Some(daemon),
+            TranslationErrorKind::Reasoning,
+            &huge,
+            TranslateContext::default(),
+            None,
+            None,
+        )
+        .await
+        .expect("translated");
+        assert_eq!(translated.text, "译文");
+
+        let line = std::fs::read_to_string(&capture).expect("captured request");
+        assert!(line.len() < 4096, "child received {} bytes", line.len());
+        assert!(line.contains("[truncated for translation]"));
+        assert!(line.contains("\"truncated\":true"));
+    }
+
+    // Stats are process-wide, so other tests may record invocations
+    // concurrently; assert on deltas and lower bounds only.
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn translation_stats_accumulate_across_invocations() {
+        use std::os::unix::fs::PermissionsExt;
+        let dir = tempfile::tempdir().expect("tempdir");
+        let script = dir.path().join("daemon.sh");
+        std::fs::write(
+            &script,
+            r#"#!/bin/sh
+while read line; do
+  id=$(printf '%s' "$line" | sed 's/.*"id":\([0-9]*\).*/\1/')
+  printf '{"id":%s,"translated":"译文"}\n' "$id"
+done
+"#,
+        )
+        .expect("write stub daemon");
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755))
+            .expect("chmod stub daemon");
+
+        let config = TranslationConfig {
+            enabled: true,
+            target_language: "zh-CN".to_string(),
+            daemon_command: Some(vec![script.to_string_lossy().into_owned()]),
+            ..Default::default()
+        };
+        let daemon = Arc::new(tokio::sync::Mutex::new(DaemonChain::new(
+            vec![script.to_string_lossy().into_owned()],
+            None,
+        )));
+
+        let before = stats::translation_stats();
+        // Distinct texts so every call reaches the translator rather than
+        // the process-wide cache.
+        for text in ["stats sample one", "stats sample two", "stats sample three"] {
+            ReasoningTranslator::do_translate(
+                &config,
+                Some(daemon.clone()),
+                TranslationErrorKind::Reasoning,
+                text,
+                TranslateContext::default(),
+                None,
+                None,
+            )
+            .await
+            .expect("translated");
+        }
+        let broken = Arc::new(tokio::sync::Mutex::new(DaemonChain::new(
+            vec!["/nonexistent/translator".to_string()],
+            None,
+        )));
+        ReasoningTranslator::do_translate(
+            &config,
+            Some(broken),
+            TranslationErrorKind::Reasoning,
+            "stats sample four",
+            TranslateContext::default(),
+            None,
+            None,
+        )
+        .await
+        .expect_err("broken daemon");
+
+        let after = stats::translation_stats();
+        assert!(
+            after.total >= before.total + 4,
+            "expected at least four more invocations: {before:?} -> {after:?}"
+        );
+        assert!(
+            after.failures >= before.failures + 1,
+            "expected at least one more failure: {before:?} -> {after:?}"
+        );
+        assert!(after.p50_latency.is_some());
+        assert!(after.p95_latency.is_some());
+    }
+
+    #[test]
+    fn translated_only_template_drops_the_original_title() {
+        let mut translator = ReasoningTranslator::from_config(TranslationConfig {
+            enabled: true,
+            title_template: Some("translated_only".to_string()),
+            ..Default::default()
+        });
+        translator.remember_title_translation("Thinking".to_string(), "思考中".to_string());
+        assert_eq!(
+            translator
+                .bilingual_status_header("Thinking", 40)
+                .as_deref(),
+            Some("思考中")
+        );
+        assert_eq!(
+            translator
+                .title_translation_cache()
+                .get("Thinking")
+                .map(String::as_str),
+            Some("思考中")
+        );
+    }
+
+    #[test]
+    fn title_cache_evicts_least_recently_displayed_title() {
+        let mut translator = ReasoningTranslator::from_config(TranslationConfig {
+            enabled: true,
+            title_cache_capacity: Some(2),
+            ..Default::default()
+        });
+        translator.remember_title_translation("Thinking".to_string(), "思考中".to_string());
+        translator.remember_title_translation("Exploring".to_string(), "探索中".to_string());
+
+        // Displaying "Thinking" marks it recently used, so inserting a third
+        // title evicts "Exploring" instead.
+        assert!(translator.bilingual_status_header("Thinking", 40).is_some());
+        translator.remember_title_translation("Planning".to_string(), "规划中".to_string());
+
+        assert_eq!(translator.bilingual_status_header("Exploring", 40), None);
+        assert!(translator.bilingual_status_header("Thinking", 40).is_some());
+        assert!(translator.bilingual_status_header("Planning", 40).is_some());
+        assert_eq!(translator.snapshot().title_cache_size, 2);
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn pending_barrier_requests_only_the_timeout_frame() {
+        use tokio_util::time::FutureExt;
+
+        let mut translator = ReasoningTranslator::from_config(TranslationConfig {
+            enabled: true,
+            timeout_ms: Some(50),
+            ..Default::default()
+        });
+
+        let (draw_tx, mut draw_rx) = tokio::sync::broadcast::channel(16);
+        translator
+            .begin_barrier(ThreadId::new(), None, FrameRequester::new(draw_tx))
+            .expect("barrier");
+
+        // Under reduced motion nothing animates while the barrier is pending,
+        // so no frame should arrive before the timeout deadline.
+        tokio::time::advance(Duration::from_millis(20)).await;
+        let early = draw_rx.recv().timeout(Duration::from_millis(10)).await;
+        assert!(early.is_err(), "frame requested before timeout deadline");
+
+        // The single timeout-handling frame fires at the deadline.
+        tokio::time::advance(Duration::from_millis(40)).await;
+        let timeout_frame = draw_rx
+            .recv()
+            .timeout(Duration::from_millis(50))
+            .await
+            .expect("timed out waiting for the timeout frame");
+        assert!(timeout_frame.is_ok(), "broadcast closed unexpectedly");
+
+        // No further frames are scheduled for the pending barrier.
+        let extra = draw_rx.recv().timeout(Duration::from_millis(100)).await;
+        assert!(extra.is_err(), "unexpected extra frame requested");
+    }
+
+    #[tokio::test]
+    async fn failing_error_translation_leaves_the_original_error_untouched() {
+        let mut translator = ReasoningTranslator::from_config(TranslationConfig {
+            enabled: true,
+            translate_errors: true,
+            // DeepSeek requires an API key, so the background translation
+            // fails immediately.
+            ..Default::default()
+        });
+
+        let message = "stream error: connection reset";
+        let mut cell: Box<dyn HistoryCell> =
+            Box::new(history_cell::new_error_event(message.to_string()));
+        translator.maybe_translate_notice(&mut cell);
+
+        // The cell is available right away with the English text intact; the
+        // cache miss only warmed the cache in the background.
+        let raw: Vec<String> = cell.raw_lines().iter().map(ToString::to_string).collect();
+        assert!(
+            raw.iter().any(|line| line.contains(message)),
+            "original error text must survive a translation miss: {raw:?}"
+        );
+        assert_eq!(translator.snapshot().pending_notices, 1);
+    }
+
+    #[tokio::test]
+    async fn plan_update_explanation_uses_cached_notice_translation() {
+        let mut translator = ReasoningTranslator::from_config(TranslationConfig {
+            enabled: true,
+            translate_plan_updates: true,
+            ..Default::default()
+        });
+        let explanation = "I will refactor the parser";
+        let (masked, _) = protect_notice_literals(explanation);
+        translator
+            .notice_translation_cache
+            .insert(masked, "我将重构解析器".to_string());
+
+        let update = codex_protocol::plan_tool::UpdatePlanArgs {
+            explanation: Some(explanation.to_string()),
+            plan: vec![],
+        };
+        let mut cell: Box<dyn HistoryCell> = Box::new(history_cell::new_plan_update(update));
+        translator.maybe_translate_plan_update(&mut cell);
+
+        let raw: Vec<String> = cell.raw_lines().iter().map(ToString::to_string).collect();
+        assert!(
+            raw.iter().any(|line| line.contains("我将重构解析器")),
+            "translated note should render inside the plan cell: {raw:?}"
+        );
+    }
+
+    /// Drive one completed translation carrying metadata through the barrier
+    /// and return the raw lines of the emitted translation block.
+    fn translation_block_lines(show_translation_metadata: bool) -> Vec<String> {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let app_event_tx = AppEventSender::new(tx);
+
+        let mut translator = ReasoningTranslator::from_config(TranslationConfig {
+            enabled: true,
+            show_translation_metadata,
+            ..Default::default()
+        });
+        translator
+            .set_journal_for_tests(DeferredCellJournal::new(dir.path().join("deferred.jsonl")));
+        let thread_id = ThreadId::new();
+        translator.begin_turn(Some(thread_id));
+
+        let request_id = translator
+            .begin_barrier(
+                thread_id,
+                Some("Thinking".to_string()),
+                FrameRequester::test_dummy(),
+            )
+            .expect("barrier");
+        let metadata = HashMap::from([
+            ("engine".to_string(), "deepl".to_string()),
+            ("latency".to_string(), "312ms".to_string()),
+        ]);
+        translator.on_translation_completed(
+            TranslationResult::new(
+                request_id,
+                thread_id,
+                Some("Thinking".to_string()),
+                Some("**思考中**\n正文".to_string()),
+                None,
+            )
+            .with_metadata(metadata),
+            Some(thread_id),
+            &app_event_tx,
+            FrameRequester::test_dummy(),
+        );
+
+        let mut lines = Vec::new();
+        while let Ok(event) = rx.try_recv() {
+            if let AppEvent::InsertHistoryCell(cell) = event {
+                lines.extend(cell.raw_lines().iter().map(ToString::to_string));
+            }
+        }
+        lines
+    }
+
+    #[tokio::test]
+    async fn metadata_footer_renders_when_enabled_and_not_by_default() {
+        // Values joined in key order: "engine" sorts before "latency".
+        let lines = translation_block_lines(/*show_translation_metadata*/ true);
+        assert!(
+            lines.iter().any(|line| line.contains("deepl · 312ms")),
+            "expected a metadata footer: {lines:?}"
+        );
+
+        let lines = translation_block_lines(/*show_translation_metadata*/ false);
+        assert!(
+            !lines.iter().any(|line| line.contains("deepl")),
+            "footer must stay hidden by default: {lines:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn config_load_warnings_surface_once_as_warning_cells() {
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let app_event_tx = AppEventSender::new(tx);
+        // A bad config typically leaves translation disabled; the warnings
+        // explaining why must surface anyway.
+        let mut translator = ReasoningTranslator::from_config(TranslationConfig {
+            enabled: false,
+            startup_warnings: vec!["streaming requires a daemon command, ignoring it".to_string()],
+            ..Default::default()
+        });
+
+        let result = translator.on_draw_tick(None, &app_event_tx, FrameRequester::test_dummy());
+        assert!(result.needs_redraw);
+        let mut lines = Vec::new();
+        while let Ok(event) = rx.try_recv() {
+            if let AppEvent::InsertHistoryCell(cell) = event {
+                lines.extend(cell.raw_lines().iter().map(ToString::to_string));
+            }
+        }
+        assert!(
+            lines
+                .iter()
+                .any(|line| line.contains("translation.toml: streaming requires")),
+            "expected a warning cell: {lines:?}"
+        );
+
+        // Shown once; the next tick stays quiet.
+        let result = translator.on_draw_tick(None, &app_event_tx, FrameRequester::test_dummy());
+        assert!(!result.needs_redraw);
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn error_cell_appends_advice_for_the_failure_kind() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let app_event_tx = AppEventSender::new(tx);
+        let mut translator = ReasoningTranslator::from_config(TranslationConfig {
+            enabled: true,
+            ..Default::default()
+        });
+        translator
+            .set_journal_for_tests(DeferredCellJournal::new(dir.path().join("deferred.jsonl")));
+        let thread_id = ThreadId::new();
+        translator.begin_turn(Some(thread_id));
+
+        let request_id = translator
+            .begin_barrier(
+                thread_id,
+                Some("Thinking".to_string()),
+                FrameRequester::test_dummy(),
+            )
+            .expect("barrier");
+        translator.on_translation_completed(
+            TranslationResult::new(
+                request_id,
+                thread_id,
+                Some("Thinking".to_string()),
+                None,
+                Some(super::super::error::TranslationError::Timeout.failure()),
+            ),
+            Some(thread_id),
+            &app_event_tx,
+            FrameRequester::test_dummy(),
+        );
+
+        let mut lines = Vec::new();
+        while let Ok(event) = rx.try_recv() {
+            if let AppEvent::InsertHistoryCell(cell) = event {
+                lines.extend(cell.raw_lines().iter().map(ToString::to_string));
+            }
+        }
+        assert!(
+            lines.iter().any(|line| line.contains("Translation timeout")
+                && line.contains("consider increasing timeout_ms")),
+            "error cell should carry the timeout hint: {lines:?}"
+        );
+    }
+}
@@ -0,0 +1,157 @@
+//! Process-wide translator rate limiting.
+//!
+//! Translators that proxy to paid APIs can be hit dozens of times a minute
+//! by rapid-fire reasoning sections. When `max_requests_per_minute` is set,
+//! a token bucket shared by every translation kind in the process meters
+//! invocations: a request with no token left waits for the next one when it
+//! is close, and is rejected with [`TranslationError::RateLimited`] — which
+//! the orchestrator treats as a silent skip — when it is not.
+
+use std::sync::LazyLock;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+use super::error::TranslationError;
+
+/// The one bucket all translations share, like the cache and stats. `None`
+/// until the first rate-limited request initializes it.
+static BUCKET: LazyLock<Mutex<Option<Bucket>>> = LazyLock::new(Mutex::default);
+
+#[derive(Debug)]
+struct Bucket {
+    /// Tokens currently available; fractional between refills.
+    tokens: f64,
+    /// When tokens were last credited.
+    refilled: Instant,
+    /// Requests per minute the bucket refills at; burst capacity equals it.
+    limit: u32,
+}
+
+impl Bucket {
+    fn full(limit: u32) -> Self {
+        Self {
+            tokens: f64::from(limit),
+            refilled: Instant::now(),
+            limit,
+        }
+    }
+
+    /// Credit tokens for the time since the last refill, adopting a changed
+    /// limit without granting a fresh burst.
+    fn refill(&mut self, limit: u32) {
+        let now = Instant::now();
+        self.tokens += now.duration_since(self.refilled).as_secs_f64() * f64::from(limit) / 60.0;
+        self.tokens = self.tokens.min(f64::from(limit));
+        self.refilled = now;
+        self.limit = limit;
+    }
+
+    /// How long until a full token is available at the current limit.
+    fn time_to_next_token(&self) -> Duration {
+        Duration::from_secs_f64((1.0 - self.tokens).max(0.0) * 60.0 / f64::from(self.limit))
+    }
+}
+
+/// Take one invocation slot from the process-wide bucket, waiting up to
+/// `max_wait` for the next token when none is left.
+pub(crate) async fn acquire(limit: u32, max_wait: Duration) -> Result<(), TranslationError> {
+    acquire_from(&BUCKET, limit, max_wait).await
+}
+
+/// [`acquire`] against an explicit bucket, so tests get their own state
+/// instead of racing over the process-wide one.
+async fn acquire_from(
+    bucket: &Mutex<Option<Bucket>>,
+    limit: u32,
+    max_wait: Duration,
+) -> Result<(), TranslationError> {
+    let limit = limit.max(1);
+    let deadline = Instant::now() + max_wait;
+    loop {
+        // Waiters race for refilled tokens, so re-check after every sleep.
+        let wait = {
+            let mut bucket = bucket.lock().expect("rate limit lock");
+            let bucket = bucket.get_or_insert_with(|| Bucket::full(limit));
+            bucket.refill(limit);
+            if bucket.tokens >= 1.0 {
+                bucket.tokens -= 1.0;
+                return Ok(());
+            }
+            bucket.time_to_next_token()
+        };
+        let now = Instant::now();
+        if now + wait > deadline {
+            return Err(TranslationError::RateLimited {
+                retry_after_ms: wait.as_millis() as u64,
+            });
+        }
+        tokio::time::sleep(wait).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Paused time makes the bucket deterministic: it only refills when the
+    // test advances the clock (or sleeps, which auto-advances it).
+
+    #[tokio::test(start_paused = true)]
+    async fn burst_up_to_the_limit_then_rejects_with_retry_hint() {
+        let bucket = Mutex::new(None);
+        for _ in 0..5 {
+            acquire_from(&bucket, 5, Duration::ZERO)
+                .await
+                .expect("within burst");
+        }
+
+        let error = acquire_from(&bucket, 5, Duration::ZERO)
+            .await
+            .expect_err("budget exhausted");
+        match error {
+            TranslationError::RateLimited { retry_after_ms } => {
+                // One token every 60s/5 = 12s.
+                assert_eq!(retry_after_ms, 12_000);
+            }
+            other => panic!("expected RateLimited, got {other}"),
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn waits_for_the_next_token_when_it_fits_the_budget() {
+        let bucket = Mutex::new(None);
+        for _ in 0..5 {
+            acquire_from(&bucket, 5, Duration::ZERO)
+                .await
+                .expect("within burst");
+        }
+
+        let before = Instant::now();
+        acquire_from(&bucket, 5, Duration::from_secs(30))
+            .await
+            .expect("waited for a token");
+        assert_eq!(before.elapsed(), Duration::from_secs(12));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn tokens_refill_over_time() {
+        let bucket = Mutex::new(None);
+        for _ in 0..5 {
+            acquire_from(&bucket, 5, Duration::ZERO)
+                .await
+                .expect("within burst");
+        }
+
+        tokio::time::advance(Duration::from_secs(60)).await;
+        for _ in 0..5 {
+            acquire_from(&bucket, 5, Duration::ZERO)
+                .await
+                .expect("refilled");
+        }
+        acquire_from(&bucket, 5, Duration::ZERO)
+            .await
+            .expect_err("burst capacity is capped at the limit");
+    }
+}
@@ -0,0 +1,106 @@
+//! Process-wide translation invocation metrics.
+//!
+//! Every real translator invocation — daemon or HTTP, cache hits excluded —
+//! is counted and timed here. Each one is also emitted as a structured
+//! `tracing` event under the `codex_translation_metrics` target, tagged by
+//! translation kind and outcome, so any telemetry subscriber on the tracing
+//! pipeline can build counters and latency histograms from it. A cheap
+//! in-process snapshot with percentile latencies backs `/translate status`.
+
+use std::collections::VecDeque;
+use std::sync::LazyLock;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use super::error::TranslationFailure;
+use super::error_log::TranslationErrorKind;
+
+/// Number of recent latency samples kept for the percentile estimates. Old
+/// samples are dropped first, so a long session reports recent behaviour
+/// rather than averaging over hours.
+const LATENCY_SAMPLES: usize = 512;
+
+/// Counters shared by every translator in the process, mirroring the
+/// process-wide cache: translations from any conversation contribute.
+static STATS: LazyLock<Mutex<StatsInner>> = LazyLock::new(Mutex::default);
+
+#[derive(Debug, Default)]
+struct StatsInner {
+    total: u64,
+    failures: u64,
+    latencies: VecDeque<Duration>,
+}
+
+/// Point-in-time summary of translator invocations in this process.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TranslationStats {
+    /// Translator invocations since startup, including failed ones.
+    pub total: u64,
+    /// Invocations that returned an error.
+    pub failures: u64,
+    /// Median latency over the recent sample window; `None` before the
+    /// first invocation completes.
+    pub p50_latency: Option<Duration>,
+    /// 95th-percentile latency over the recent sample window.
+    pub p95_latency: Option<Duration>,
+}
+
+/// Record one completed translator invocation and emit the matching
+/// telemetry event.
+pub(crate) fn record(
+    kind: TranslationErrorKind,
+    failure: Option<&TranslationFailure>,
+    elapsed: Duration,
+) {
+    let outcome = failure.map_or("success", TranslationFailure::kind_str);
+    tracing::debug!(
+        target: "codex_translation_metrics",
+        kind = kind.as_str(),
+        outcome,
+        latency_ms = elapsed.as_millis() as u64,
+        "translator invocation"
+    );
+    let mut stats = STATS.lock().expect("translation stats lock");
+    stats.total += 1;
+    if failure.is_some() {
+        stats.failures += 1;
+    }
+    if stats.latencies.len() == LATENCY_SAMPLES {
+        stats.latencies.pop_front();
+    }
+    stats.latencies.push_back(elapsed);
+}
+
+/// Snapshot the process-wide counters, for `/translate status`.
+pub fn translation_stats() -> TranslationStats {
+    let stats = STATS.lock().expect("translation stats lock");
+    let mut samples: Vec<Duration> = stats.latencies.iter().copied().collect();
+    samples.sort_unstable();
+    TranslationStats {
+        total: stats.total,
+        failures: stats.failures,
+        p50_latency: percentile(&samples, 50),
+        p95_latency: percentile(&samples, 95),
+    }
+}
+
+/// Nearest-rank percentile over sorted samples.
+fn percentile(sorted: &[Duration], pct: usize) -> Option<Duration> {
+    if sorted.is_empty() {
+        return None;
+    }
+    Some(sorted[(sorted.len() - 1) * pct / 100])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn percentile_uses_nearest_rank() {
+        let samples: Vec<Duration> = (1..=100).map(Duration::from_millis).collect();
+        assert_eq!(percentile(&samples, 50), Some(Duration::from_millis(50)));
+        assert_eq!(percentile(&samples, 95), Some(Duration::from_millis(95)));
+        assert_eq!(percentile(&[], 50), None);
+    }
+}
@@ -689,6 +689,11 @@ impl Tui {
         output
     }
 
+    /// Whether the terminal currently has focus, per FocusGained/FocusLost.
+    pub fn is_terminal_focused(&self) -> bool {
+        self.terminal_focused.load(Ordering::Relaxed)
+    }
+
     /// Emit a desktop notification now if the terminal is unfocused.
     /// Returns true if a notification was posted.
     pub fn notify(&mut self, message: impl AsRef<str>) -> bool {